@@ -17,6 +17,22 @@ pub struct LaunchConfig {
     pub wal_directory: String,
     pub wal_segment_size: u32,
     pub wal_extension: String,
+
+    // 해시 집계의 그룹 테이블이 메모리에서 쓸 수 있는 최대 크기 (바이트). 넘으면 디스크로 내보냅니다.
+    #[serde(default = "default_aggregate_memory_limit")]
+    pub aggregate_memory_limit: usize,
+
+    // 트랜잭션 블록 하나가 커밋 전까지 행 버퍼에 쌓을 수 있는 최대 크기 (바이트). 넘으면 명령이 실패합니다.
+    #[serde(default = "default_transaction_memory_limit")]
+    pub transaction_memory_limit: usize,
+}
+
+fn default_aggregate_memory_limit() -> usize {
+    1024 * 1024 * 64 // 64MB
+}
+
+fn default_transaction_memory_limit() -> usize {
+    1024 * 1024 * 256 // 256MB
 }
 
 #[allow(clippy::derivable_impls)]
@@ -40,6 +56,8 @@ impl std::default::Default for LaunchConfig {
                 .to_string(),
             wal_segment_size: 1024 * 1024 * 16, // 16MB 세그먼트 사이즈
             wal_extension: DEFAULT_WAL_EXTENSION.to_string(),
+            aggregate_memory_limit: default_aggregate_memory_limit(),
+            transaction_memory_limit: default_transaction_memory_limit(),
         }
     }
 }
@@ -121,9 +139,11 @@ mod tests {
 
     #[test]
     fn with_base_path_overrides_loaded_storage_directories() {
-        let mut config = LaunchConfig::default();
-        config.data_directory = "/var/lib/rrdb/data".to_string();
-        config.wal_directory = "/var/lib/rrdb/wal".to_string();
+        let config = LaunchConfig {
+            data_directory: "/var/lib/rrdb/data".to_string(),
+            wal_directory: "/var/lib/rrdb/wal".to_string(),
+            ..Default::default()
+        };
 
         let config = config.with_base_path("/tmp/rrdb");
         let base_path = absolute_path(PathBuf::from("/tmp/rrdb"));
@@ -34,7 +34,7 @@ impl DBEngine {
                     let mut from_path = base_path.clone();
                     let mut to_path = base_path.clone();
 
-                    from_path.push(from_database_name);
+                    from_path.push(&from_database_name);
                     to_path.push(to_database_name.clone());
 
                     // 디렉터리명 변경
@@ -53,34 +53,33 @@ impl DBEngine {
                         }
                     }
 
+                    self.invalidate_database_function_configs(&from_database_name)
+                        .await;
+
                     // config data 파일 내용 변경
                     let mut config_path = to_path.clone();
                     config_path.push("database.config");
 
                     match tokio::fs::read(&config_path).await {
-                        Ok(data) => {
-                            match encoder.decode::<DatabaseSchema>(data.as_slice()) {
-                                Ok(mut database_config) => {
-                                    database_config.database_name = to_database_name;
-                                    if let Err(_error) = tokio::fs::write(
-                                        config_path,
-                                        encoder.encode(database_config),
-                                    )
-                                    .await
-                                    {
-                                        return Err(ExecuteError::wrap(
-                                            "failed to write database config",
-                                        ));
-                                    }
-                                }
-                                Err(error) => {
-                                    return Err(ExecuteError::wrap(format!(
-                                        "invalid config data: {}",
-                                        error
-                                    )));
+                        Ok(data) => match encoder.decode::<DatabaseSchema>(data.as_slice()) {
+                            Ok(mut database_config) => {
+                                database_config.database_name = to_database_name;
+                                if let Err(_error) =
+                                    tokio::fs::write(config_path, encoder.encode(database_config))
+                                        .await
+                                {
+                                    return Err(ExecuteError::wrap(
+                                        "failed to write database config",
+                                    ));
                                 }
                             }
-                        }
+                            Err(error) => {
+                                return Err(ExecuteError::wrap(format!(
+                                    "invalid config data: {}",
+                                    error
+                                )));
+                            }
+                        },
                         Err(error) => match error.kind() {
                             IOErrorKind::NotFound => {
                                 return Err(ExecuteError::wrap("database not found"));
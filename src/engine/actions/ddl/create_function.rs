@@ -0,0 +1,97 @@
+use std::io::ErrorKind as IOErrorKind;
+
+use crate::engine::DBEngine;
+use crate::engine::ast::ddl::create_function::CreateFunctionQuery;
+use crate::engine::ast::types::BuiltInFunction;
+use crate::engine::encoder::schema_encoder::StorageEncoder;
+use crate::engine::schema::function::FunctionSchema;
+use crate::engine::types::{
+    ExecuteColumn, ExecuteColumnType, ExecuteField, ExecuteResult, ExecuteRow,
+};
+use crate::errors;
+use crate::errors::execute_error::ExecuteError;
+
+impl DBEngine {
+    pub async fn create_function(
+        &self,
+        query: CreateFunctionQuery,
+    ) -> errors::Result<ExecuteResult> {
+        let encoder = StorageEncoder::new();
+
+        let database_name = query
+            .function
+            .database_name
+            .clone()
+            .ok_or_else(|| ExecuteError::wrap("no database selected".to_string()))?;
+        let function_name = query.function.function_name.clone();
+
+        // 함수명도 디렉토리명이 됩니다. (#255)
+        crate::engine::path_identifier::validate_path_identifier(&database_name, "database name")?;
+        crate::engine::path_identifier::validate_path_identifier(&function_name, "function name")?;
+
+        // 호출은 내장 함수를 먼저 찾으므로 같은 이름의 함수는 부를 수 없습니다.
+        if BuiltInFunction::lookup(&function_name).is_some() {
+            return Err(ExecuteError::wrap(format!(
+                "function {} is a built-in function and cannot be redefined",
+                function_name
+            )));
+        }
+
+        let database_path = self.get_data_directory().join(&database_name);
+
+        if !tokio::fs::try_exists(&database_path).await.unwrap_or(false) {
+            return Err(ExecuteError::wrap("database not found".to_string()));
+        }
+
+        let function_info: FunctionSchema = query.clone().into();
+        self.check_function_return_type(&function_info).await?;
+
+        // functions 경로는 처음 함수를 만들 때 생성합니다.
+        let function_path = database_path.join("functions").join(&function_name);
+
+        if let Err(error) = tokio::fs::create_dir_all(function_path.parent().unwrap()).await {
+            return Err(ExecuteError::wrap(error.to_string()));
+        }
+
+        if let Err(error) = tokio::fs::create_dir(&function_path).await {
+            match error.kind() {
+                IOErrorKind::AlreadyExists if query.or_replace => {}
+                IOErrorKind::AlreadyExists => {
+                    return Err(ExecuteError::wrap(format!(
+                        "function {} already exists",
+                        function_name
+                    )));
+                }
+                _ => {
+                    return Err(ExecuteError::wrap("function create failed".to_string()));
+                }
+            }
+        }
+
+        let config_path = function_path.join("function.config");
+
+        if let Err(error) =
+            tokio::fs::write(&config_path, encoder.encode(function_info.clone())).await
+        {
+            return Err(ExecuteError::wrap(error.to_string()));
+        }
+
+        self.function_config_cache
+            .write()
+            .await
+            .insert(function_info.function.clone(), function_info);
+
+        Ok(ExecuteResult::new(
+            vec![ExecuteColumn {
+                name: "desc".into(),
+                data_type: ExecuteColumnType::String,
+            }],
+            vec![ExecuteRow {
+                fields: vec![ExecuteField::String(format!(
+                    "function created: {}",
+                    function_name
+                ))],
+            }],
+        ))
+    }
+}
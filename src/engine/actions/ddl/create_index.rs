@@ -4,7 +4,7 @@ use crate::engine::DBEngine;
 use crate::engine::SharedWALManager;
 use crate::engine::actions::index::{qualified_index_name, row_index_key};
 use crate::engine::ast::ddl::create_index::CreateIndexQuery;
-use crate::engine::index::{IndexEntry, IndexMeta};
+use crate::engine::index::{IndexEntry, IndexMeta, NULL_KEY};
 use crate::engine::types::{
     ExecuteColumn, ExecuteColumnType, ExecuteField, ExecuteResult, ExecuteRow,
 };
@@ -118,7 +118,7 @@ impl DBEngine {
 
         for (location, row) in &rows {
             if let Some(key) = row_index_key(row, &column_name) {
-                if query.is_unique && !seen_keys.insert(key.clone()) {
+                if query.is_unique && key != NULL_KEY && !seen_keys.insert(key.clone()) {
                     // 고유 제약 위반: 생성한 인덱스를 롤백
                     let _ = self.index_manager.drop_index(&index_name).await;
                     return Err(ExecuteError::wrap(format!(
@@ -179,6 +179,7 @@ mod tests {
     use crate::engine::ast::dml::plan::select::select_plan::SelectPlanItem;
     use crate::engine::ast::types::TableName;
     use crate::engine::ast::{DDLStatement, DMLStatement, SQLStatement};
+    use crate::engine::index::NULL_KEY;
     use crate::engine::optimizer::predule::Optimizer;
     use crate::engine::parser::predule::{Parser, ParserContext};
     use crate::engine::types::{ExecuteField, ExecuteResult};
@@ -455,9 +456,13 @@ mod tests {
         );
 
         // 삭제된 행은 더 이상 인덱스로 조회되지 않아야 함
-        let deleted = execute_sql(&engine, wal.clone(), "select score from users where id = 1;")
-            .await
-            .unwrap();
+        let deleted = execute_sql(
+            &engine,
+            wal.clone(),
+            "select score from users where id = 1;",
+        )
+        .await
+        .unwrap();
         assert_eq!(deleted.rows.len(), 0);
 
         // row index가 이동하지 않으므로 남은 행은 계속 정확히 조회되어야 함
@@ -589,6 +594,272 @@ mod tests {
         assert_eq!(result.rows[0].fields[0], ExecuteField::Integer(1000));
     }
 
+    #[tokio::test]
+    async fn like_prefix_uses_index_range_and_returns_correct_rows() {
+        let (engine, wal) = build_test_engine("like_prefix_index_scan").await;
+
+        for sql in [
+            "create database rrdb;",
+            "create table users (id integer primary key, name varchar(100));",
+            "create index users_name_idx on users (name);",
+        ] {
+            execute_sql(&engine, wal.clone(), sql).await.unwrap();
+        }
+
+        let values = (1..=600)
+            .map(|i| format!("({}, 'user{:04}')", i, i))
+            .collect::<Vec<_>>()
+            .join(", ");
+        execute_sql(
+            &engine,
+            wal.clone(),
+            &format!("insert into users (id, name) values {};", values),
+        )
+        .await
+        .unwrap();
+
+        let mut parser =
+            Parser::with_string("select id from users where name like 'user05%';".to_string())
+                .unwrap();
+        let statement = parser
+            .parse(ParserContext::default().set_default_database("rrdb".to_string()))
+            .unwrap()
+            .remove(0);
+
+        let query = match statement {
+            SQLStatement::DML(DMLStatement::SelectQuery(query)) => query,
+            other => panic!("expected select query, got {:?}", other),
+        };
+
+        let context = engine.build_optimizer_context(&users_table()).await;
+        let optimizer = Optimizer::with_context(context);
+        let plan = optimizer.optimize_select(query).await.unwrap();
+
+        match &plan.list[0] {
+            SelectPlanItem::From(from) => match &from.scan {
+                ScanType::IndexScan(index_scan) => {
+                    assert_eq!(index_scan.index_name, "rrdb.users_name_idx");
+                    assert_eq!(index_scan.start_key.as_deref(), Some("S:user05"));
+                    assert_eq!(index_scan.end_key.as_deref(), Some("S:user06"));
+                }
+                other => panic!("expected IndexScan, got {:?}", other),
+            },
+            other => panic!("expected From plan, got {:?}", other),
+        }
+
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select id from users where name like 'user05%' order by id;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(result.rows.len(), 100);
+        assert_eq!(result.rows[0].fields[0], ExecuteField::Integer(500));
+        assert_eq!(result.rows[99].fields[0], ExecuteField::Integer(599));
+
+        // 접두사 뒤의 조건은 필터에서 걸러짐
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select id from users where name like 'user05_7' order by id;",
+        )
+        .await
+        .unwrap();
+        let ids = result
+            .rows
+            .iter()
+            .map(|row| row.fields[0].clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ids,
+            (0..10)
+                .map(|i| ExecuteField::Integer(507 + i * 10))
+                .collect::<Vec<_>>()
+        );
+
+        // ILIKE는 인덱스 없이 대소문자를 무시하고 비교
+        let result = execute_sql(
+            &engine,
+            wal,
+            "select id from users where name ilike 'USER000_';",
+        )
+        .await
+        .unwrap();
+        assert_eq!(result.rows.len(), 9);
+    }
+
+    #[tokio::test]
+    async fn in_list_uses_multi_key_index_lookup() {
+        let (engine, wal) = build_test_engine("in_list_index_scan").await;
+        setup_users_table(&engine, wal.clone()).await;
+
+        // 키 세 개를 임의 접근하는 비용이 풀 스캔보다 싸지도록 충분한 행을 입력
+
+        let values = (1..=2000)
+            .map(|i| format!("({}, {})", i, i * 10))
+            .collect::<Vec<_>>()
+            .join(", ");
+        execute_sql(
+            &engine,
+            wal.clone(),
+            &format!("insert into users (id, score) values {};", values),
+        )
+        .await
+        .unwrap();
+
+        let mut parser = Parser::with_string(
+            "select score from users where id in (300, 5, null, 9999, 5);".to_string(),
+        )
+        .unwrap();
+        let statement = parser
+            .parse(ParserContext::default().set_default_database("rrdb".to_string()))
+            .unwrap()
+            .remove(0);
+
+        let query = match statement {
+            SQLStatement::DML(DMLStatement::SelectQuery(query)) => query,
+            other => panic!("expected select query, got {:?}", other),
+        };
+
+        let context = engine.build_optimizer_context(&users_table()).await;
+        let optimizer = Optimizer::with_context(context);
+        let plan = optimizer.optimize_select(query).await.unwrap();
+
+        match &plan.list[0] {
+            SelectPlanItem::From(from) => match &from.scan {
+                ScanType::IndexScan(index_scan) => {
+                    assert_eq!(index_scan.index_name, "rrdb.users_pkey");
+                    assert_eq!(index_scan.in_keys.len(), 3);
+                }
+                other => panic!("expected IndexScan, got {:?}", other),
+            },
+            other => panic!("expected From plan, got {:?}", other),
+        }
+
+        let result = execute_sql(
+            &engine,
+            wal,
+            "select score from users where id in (300, 5, null, 9999, 5) order by score;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            result
+                .rows
+                .iter()
+                .map(|row| row.fields[0].clone())
+                .collect::<Vec<_>>(),
+            vec![ExecuteField::Integer(50), ExecuteField::Integer(3000)]
+        );
+    }
+
+    #[tokio::test]
+    async fn is_null_uses_null_key_and_unique_index_allows_many_nulls() {
+        let (engine, wal) = build_test_engine("is_null_index_scan").await;
+        setup_users_table(&engine, wal.clone()).await;
+
+        let values = (1..=2000)
+            .map(|i| {
+                if i % 1000 == 0 {
+                    format!("({}, null)", i)
+                } else {
+                    format!("({}, {})", i, i * 10)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        execute_sql(
+            &engine,
+            wal.clone(),
+            &format!("insert into users (id, score) values {};", values),
+        )
+        .await
+        .unwrap();
+
+        // NULL은 서로 같지 않으므로 유니크 인덱스 생성과 이후 입력 모두 허용
+        execute_sql(
+            &engine,
+            wal.clone(),
+            "create unique index users_score_uniq on users (score);",
+        )
+        .await
+        .unwrap();
+        execute_sql(
+            &engine,
+            wal.clone(),
+            "insert into users (id, score) values (2001, null);",
+        )
+        .await
+        .unwrap();
+
+        let mut parser =
+            Parser::with_string("select id from users where score is null;".to_string()).unwrap();
+        let statement = parser
+            .parse(ParserContext::default().set_default_database("rrdb".to_string()))
+            .unwrap()
+            .remove(0);
+
+        let query = match statement {
+            SQLStatement::DML(DMLStatement::SelectQuery(query)) => query,
+            other => panic!("expected select query, got {:?}", other),
+        };
+
+        let context = engine.build_optimizer_context(&users_table()).await;
+        let optimizer = Optimizer::with_context(context);
+        let plan = optimizer.optimize_select(query).await.unwrap();
+
+        match &plan.list[0] {
+            SelectPlanItem::From(from) => match &from.scan {
+                ScanType::IndexScan(index_scan) => {
+                    assert_eq!(index_scan.index_name, "rrdb.users_score_uniq");
+                    assert_eq!(index_scan.eq_key, Some(NULL_KEY.to_string()));
+                }
+                other => panic!("expected IndexScan, got {:?}", other),
+            },
+            other => panic!("expected From plan, got {:?}", other),
+        }
+
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select id from users where score is null order by id;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            result
+                .rows
+                .iter()
+                .map(|row| row.fields[0].clone())
+                .collect::<Vec<_>>(),
+            [1000, 2000, 2001]
+                .into_iter()
+                .map(ExecuteField::Integer)
+                .collect::<Vec<_>>()
+        );
+
+        // 범위 조건은 인덱스에 들어간 NULL 키를 읽지 않음
+        let result = execute_sql(
+            &engine,
+            wal,
+            "select id from users where score > 19950 order by id;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            result
+                .rows
+                .iter()
+                .map(|row| row.fields[0].clone())
+                .collect::<Vec<_>>(),
+            [1996, 1997, 1998, 1999]
+                .into_iter()
+                .map(ExecuteField::Integer)
+                .collect::<Vec<_>>()
+        );
+    }
+
     #[tokio::test]
     async fn wal_replay_reconstructs_insert_after_simulated_crash() {
         let (engine, wal) = build_test_engine("wal_replay_insert").await;
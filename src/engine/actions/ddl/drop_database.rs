@@ -29,6 +29,8 @@ impl DBEngine {
         self.statistics_manager
             .invalidate_database(&database_name)
             .await;
+        self.invalidate_database_function_configs(&database_name)
+            .await;
 
         if let Err(error) = tokio::fs::remove_dir_all(database_path.clone()).await {
             match error.kind() {
@@ -0,0 +1,148 @@
+use std::io::ErrorKind as IOErrorKind;
+
+use crate::engine::DBEngine;
+use crate::engine::ast::ddl::drop_function::DropFunctionQuery;
+use crate::engine::types::{
+    ExecuteColumn, ExecuteColumnType, ExecuteField, ExecuteResult, ExecuteRow,
+};
+use crate::errors;
+use crate::errors::execute_error::ExecuteError;
+
+impl DBEngine {
+    pub async fn drop_function(&self, query: DropFunctionQuery) -> errors::Result<ExecuteResult> {
+        let function = query.function;
+
+        let database_name = function.database_name.clone().unwrap_or_default();
+        crate::engine::path_identifier::validate_path_identifier(&database_name, "database name")?;
+        crate::engine::path_identifier::validate_path_identifier(
+            &function.function_name,
+            "function name",
+        )?;
+
+        self.invalidate_function_config_cache(&function).await;
+
+        // 인자 타입 목록이 주어졌다면 정의와 같아야 합니다.
+        // (함수가 없는 경우는 아래에서 DROP TABLE과 같이 처리합니다)
+        if let Some(parameter_types) = &query.parameter_types
+            && let Ok(function_config) = self.get_function_config(&function).await
+            && parameter_types != &function_config.parameter_types()
+        {
+            return Err(ExecuteError::wrap(format!(
+                "function {}({}) does not exist",
+                function.function_name,
+                parameter_types
+                    .iter()
+                    .cloned()
+                    .map(String::from)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )));
+        }
+
+        let function_path = self
+            .get_data_directory()
+            .join(database_name)
+            .join("functions")
+            .join(&function.function_name);
+
+        if let Err(error) = tokio::fs::remove_dir_all(function_path).await {
+            match error.kind() {
+                IOErrorKind::NotFound => {
+                    if !query.if_exists {
+                        return Err(ExecuteError::wrap(format!(
+                            "function {} does not exist",
+                            function.function_name
+                        )));
+                    }
+                }
+                _ => {
+                    return Err(ExecuteError::wrap("function drop failed".to_string()));
+                }
+            }
+        }
+
+        Ok(ExecuteResult::new(
+            vec![ExecuteColumn {
+                name: "desc".into(),
+                data_type: ExecuteColumnType::String,
+            }],
+            vec![ExecuteRow {
+                fields: vec![ExecuteField::String(format!(
+                    "function dropped: {}",
+                    function.function_name
+                ))],
+            }],
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::config::launch_config::LaunchConfig;
+    use crate::engine::DBEngine;
+    use crate::engine::ast::ddl::drop_function::DropFunctionQuery;
+    use crate::engine::ast::types::{DataType, UserDefinedFunction};
+    use crate::engine::types::ExecuteField;
+
+    async fn build_engine(name: &str) -> DBEngine {
+        let base_path = PathBuf::from("target/test_drop_function").join(name);
+        if base_path.exists() {
+            tokio::fs::remove_dir_all(&base_path).await.unwrap();
+        }
+
+        let config = LaunchConfig::default_for_base_path(&base_path);
+        tokio::fs::create_dir_all(
+            PathBuf::from(&config.data_directory)
+                .join("rrdb")
+                .join("functions"),
+        )
+        .await
+        .unwrap();
+
+        DBEngine::new(config)
+    }
+
+    fn missing_function() -> UserDefinedFunction {
+        UserDefinedFunction {
+            database_name: Some("rrdb".to_string()),
+            function_name: "missing".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn drop_function_if_exists_succeeds_when_function_is_missing() {
+        let engine = build_engine("if_exists_missing").await;
+
+        // 인자 타입 목록이 있어도 DROP TABLE IF EXISTS와 같은 결과를 돌려줍니다.
+        for query in [
+            DropFunctionQuery::builder()
+                .set_function(missing_function())
+                .set_if_exists(true),
+            DropFunctionQuery::builder()
+                .set_function(missing_function())
+                .set_parameter_types(vec![DataType::Int])
+                .set_if_exists(true),
+        ] {
+            let result = engine.drop_function(query).await.unwrap();
+
+            assert_eq!(
+                result.rows[0].fields,
+                vec![ExecuteField::String("function dropped: missing".into())]
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn drop_function_fails_when_function_is_missing() {
+        let engine = build_engine("missing").await;
+
+        let error = engine
+            .drop_function(DropFunctionQuery::builder().set_function(missing_function()))
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.to_string(), "function missing does not exist");
+    }
+}
@@ -0,0 +1,220 @@
+use crate::engine::DBEngine;
+use crate::engine::SharedWALManager;
+use crate::engine::ast::ddl::truncate_table::TruncateTableQuery;
+use crate::engine::ast::types::TableName;
+use crate::engine::transaction::StatementContext;
+use crate::engine::types::{
+    ExecuteColumn, ExecuteColumnType, ExecuteField, ExecuteResult, ExecuteRow,
+};
+use crate::engine::wal::types::EntryType;
+use crate::errors;
+use crate::errors::execute_error::ExecuteError;
+
+impl DBEngine {
+    /// 행을 하나씩 tombstone으로 바꾸는 DELETE와 달리, 세그먼트와 인덱스 파일을 통째로
+    /// 빈 파일로 교체합니다.
+    ///
+    /// 테이블에는 identity 컬럼이 없고 row index는 항상 0부터 다시 시작하므로,
+    /// RESTART IDENTITY와 CONTINUE IDENTITY의 결과는 같습니다.
+    pub async fn truncate_table(
+        &self,
+        query: TruncateTableQuery,
+        wal_manager: SharedWALManager,
+    ) -> errors::Result<ExecuteResult> {
+        // 일부 테이블만 비우는 일이 없도록 모든 테이블을 먼저 확인합니다.
+        let mut tables: Vec<TableName> = vec![];
+        for table in &query.tables {
+            self.get_table_config_cached(table.clone()).await?;
+
+            if !tables.contains(table) {
+                tables.push(table.clone());
+            }
+        }
+
+        self.ensure_indices_loaded().await?;
+
+        // 다른 트랜잭션이 잠근 행이 있으면 그 트랜잭션이 끝날 때까지 기다립니다.
+        // 테이블 락은 이 트랜잭션이 끝날 때 풀리므로, 그 전에는 비운 테이블의 행 번호를
+        // 다시 쓰는 새 행을 다른 트랜잭션이 잠그지 못합니다.
+        if let Some(transaction_id) =
+            StatementContext::current().and_then(|context| context.transaction_id)
+        {
+            for table in &tables {
+                self.transaction_manager
+                    .lock_manager()
+                    .lock_table(transaction_id, table)
+                    .await?;
+            }
+        }
+
+        let _guard = self.row_storage_lock.lock().await;
+
+        // WAL-first: 행/인덱스 파일을 교체하기 전에 먼저 durable하게 기록합니다.
+        let wal_payload =
+            bincode::serialize(&query).map_err(|error| ExecuteError::wrap(error.to_string()))?;
+        let transaction_id = self.transaction_manager.writer_transaction_id();
+        wal_manager
+            .lock()
+            .await
+            .append_record(EntryType::TruncateTable, Some(wal_payload), transaction_id)
+            .await?;
+
+        self.truncate_tables_locked(&tables).await?;
+
+        Ok(ExecuteResult::new(
+            vec![ExecuteColumn {
+                name: "desc".into(),
+                data_type: ExecuteColumnType::String,
+            }],
+            vec![ExecuteRow {
+                fields: vec![ExecuteField::String(format!(
+                    "table truncated: {}",
+                    tables
+                        .iter()
+                        .map(|table| table.table_name.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ))],
+            }],
+        ))
+    }
+
+    /// crash recovery 중에 WAL에 기록된 TRUNCATE를 다시 적용합니다. 이미 빈 테이블을
+    /// 다시 비워도 결과는 같고, 그 사이에 삭제된 테이블은 건너뜁니다.
+    pub(crate) async fn truncate_table_replay(
+        &self,
+        query: TruncateTableQuery,
+    ) -> errors::Result<()> {
+        self.ensure_indices_loaded().await?;
+
+        let mut tables = vec![];
+        for table in query.tables {
+            match self.get_table_config_cached(table.clone()).await {
+                Ok(_) => tables.push(table),
+                Err(error) => log::debug!(
+                    "skipping TRUNCATE replay for {}: {}",
+                    table.table_name,
+                    error
+                ),
+            }
+        }
+
+        let _guard = self.row_storage_lock.lock().await;
+        self.truncate_tables_locked(&tables).await
+    }
+
+    // 행 세그먼트, 인덱스 파일, 통계를 빈 테이블 상태로 되돌립니다.
+    // 호출자가 `row_storage_lock`을 쥐고 있어야 합니다.
+    async fn truncate_tables_locked(&self, tables: &[TableName]) -> errors::Result<()> {
+        for table in tables {
+            self.truncate_table_rows_locked(table).await?;
+
+            for meta in self.table_index_metas(table).await {
+                self.index_manager
+                    .replace_entries(&meta.index_name, vec![])
+                    .await?;
+            }
+
+            self.statistics_manager.record_truncate(table).await;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::engine::actions::test_support::{
+        execute_sql, setup_users_table, user_ids, users_table,
+    };
+    use crate::engine::schema::row::TableDataFieldType;
+
+    #[tokio::test]
+    async fn truncate_empties_rows_and_indexes() {
+        let (engine, wal) =
+            setup_users_table("target/test_truncate_table/empties_rows_and_indexes").await;
+        engine.flush_row_buffers().await.unwrap();
+
+        execute_sql(
+            &engine,
+            wal.clone(),
+            "a",
+            "truncate table users restart identity;",
+        )
+        .await
+        .unwrap();
+
+        assert!(user_ids(&engine).await.is_empty());
+        assert_eq!(engine.next_row_index(&users_table()).await.unwrap(), 0);
+        assert!(
+            engine
+                .index_manager
+                .scan_all("rrdb.users_pkey")
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        // 기존 키를 다시 넣을 수 있고, row index는 0부터 다시 시작합니다.
+        execute_sql(
+            &engine,
+            wal.clone(),
+            "a",
+            "insert into users (id, name) values (2, 'c');",
+        )
+        .await
+        .unwrap();
+        engine.flush_row_buffers().await.unwrap();
+
+        assert_eq!(
+            user_ids(&engine).await,
+            vec![(0, TableDataFieldType::Integer(2))]
+        );
+        let entries = engine
+            .index_manager
+            .scan_all("rrdb.users_pkey")
+            .await
+            .unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].row_path, "0");
+
+        let error = execute_sql(&engine, wal.clone(), "a", "truncate users, missing;")
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("table not found"), "{error}");
+        assert_eq!(user_ids(&engine).await.len(), 1);
+    }
+
+    /// 디스크에 TRUNCATE 이후의 행이 이미 있는 상태에서 replay해도, TRUNCATE 이전의
+    /// INSERT가 다시 적용되어 unique 검사에 걸리거나 행이 되살아나지 않습니다.
+    #[tokio::test]
+    async fn truncate_replay_skips_rows_written_before_it() {
+        let (engine, wal) = setup_users_table("target/test_truncate_table/replay").await;
+
+        for sql in [
+            "truncate users;",
+            "insert into users (id, name) values (1, 'c');",
+        ] {
+            execute_sql(&engine, wal.clone(), "a", sql)
+                .await
+                .unwrap_or_else(|error| panic!("{sql} failed: {error}"));
+        }
+
+        let entries = wal.lock().await.pending_entries().to_vec();
+        engine.replay_wal(&entries).await.unwrap();
+
+        assert_eq!(
+            user_ids(&engine).await,
+            vec![(0, TableDataFieldType::Integer(1))]
+        );
+        assert_eq!(
+            engine
+                .index_manager
+                .scan_all("rrdb.users_pkey")
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+}
@@ -0,0 +1,607 @@
+//! 해시 집계 연산자 (GROUP BY와 집계 함수 처리)
+//!
+//! 입력 행마다 그룹 키와 집계 인자를 계산해서 그룹별 누산기에 반영합니다.
+//! 입력은 스트림으로 받아 한 행씩 처리하므로, 스캔 결과를 모두 모아둘 필요가 없습니다.
+//! 그룹마다 보관하는 것은 그룹 키와, SELECT 목록/HAVING/ORDER BY가 참조하는 집계의 누산기뿐입니다.
+//!
+//! 그룹 테이블이 메모리 한도(aggregate_memory_limit)를 넘으면, 테이블에 아직 없는 그룹의 입력은
+//! 키 해시로 나눈 파티션 파일에 내보냅니다. 이미 있는 그룹의 입력은 계속 메모리에서 처리합니다.
+//! 메모리의 그룹을 모두 출력한 뒤에 파티션 파일을 하나씩 같은 방식으로 다시 집계합니다.
+
+use std::collections::HashMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::io::ErrorKind as IOErrorKind;
+use std::path::{Path, PathBuf};
+use std::pin::pin;
+
+use futures::{Stream, TryStreamExt};
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncReadExt, AsyncWriteExt, BufReader, BufWriter};
+
+use crate::engine::DBEngine;
+use crate::engine::aggregate::{Accumulator, estimated_size};
+use crate::engine::ast::dml::expressions::call::CallExpression;
+use crate::engine::ast::dml::parts::group_by::GroupByItem;
+use crate::engine::ast::dml::plan::select::aggregate::AggregatePlan;
+use crate::engine::ast::types::{SQLExpression, TableName};
+use crate::engine::expression::ReduceContext;
+use crate::engine::schema::row::{TableDataField, TableDataFieldType, TableDataRow};
+use crate::errors;
+use crate::errors::execute_error::ExecuteError;
+
+/// 집계 결과 필드가 속하는 가상 테이블 이름
+const AGGREGATE_TABLE_NAME: &str = "?aggregate?";
+
+/// 한 번에 나누는 파티션 수
+const SPILL_PARTITION_COUNT: u64 = 8;
+
+/// 파티션을 다시 나누는 최대 깊이. 이보다 깊으면 메모리 한도를 무시하고 집계합니다.
+const MAX_SPILL_DEPTH: usize = 4;
+
+/// 집계 인자를 평가할 때 필요한 컨텍스트
+#[derive(Debug, Default, Clone)]
+pub(crate) struct AggregateContext {
+    pub table_alias_map: HashMap<String, TableName>,
+    /// 상관 서브쿼리 안의 집계라면 바깥 쿼리의 컨텍스트
+    pub outer: Option<Box<ReduceContext>>,
+}
+
+/// 집계 연산자 이후의 행에서 집계 결과가 담기는 필드 이름 (호출식의 SQL 표기)
+fn aggregate_field_name(call: &CallExpression) -> String {
+    call.to_string()
+}
+
+/// 집계 연산자가 계산해 둔 집계 결과를 행에서 찾습니다.
+pub(crate) fn find_aggregate_value(
+    call: &CallExpression,
+    row: &TableDataRow,
+) -> Option<TableDataFieldType> {
+    let field_name = aggregate_field_name(call);
+
+    row.fields
+        .iter()
+        .find(|field| {
+            field.table_name.table_name == AGGREGATE_TABLE_NAME && field.column_name == field_name
+        })
+        .map(|field| field.data.clone())
+}
+
+/// 그룹 테이블에 넣을 입력 행 하나 (그룹 키와 집계별 인자 값)
+/// 파티션 파일에는 이 형태로 기록되므로, 다시 읽을 때는 표현식을 평가하지 않습니다.
+#[derive(Serialize, Deserialize, Debug)]
+struct AggregateInput {
+    key: Vec<TableDataField>,
+    arguments: Vec<Vec<TableDataFieldType>>,
+}
+
+impl DBEngine {
+    /// 해시 집계를 수행해서 그룹마다 한 행을 반환합니다.
+    ///
+    /// 결과 행은 그룹 키 필드 뒤에 집계 결과 필드가 붙은 형태이며,
+    /// 이후 단계에서 집계 호출은 find_aggregate_value로 이 값을 읽습니다.
+    pub(crate) async fn hash_aggregate(
+        &self,
+        rows: impl Stream<Item = errors::Result<TableDataRow>>,
+        plan: &AggregatePlan,
+        context: &AggregateContext,
+    ) -> errors::Result<Vec<TableDataRow>> {
+        // 결과 필드 이름이 SELECT 목록의 표현식과 맞도록, 서브쿼리는 프로젝션과 같은 방식으로 값으로 바꿉니다.
+        let mut aggregates = vec![];
+        for call in &plan.aggregates {
+            let SQLExpression::FunctionCall(call) = self
+                .materialize_subqueries(SQLExpression::FunctionCall(call.clone()))
+                .await?
+            else {
+                unreachable!("함수 호출은 함수 호출로 유지됩니다");
+            };
+
+            check_arity(&call)?;
+            aggregates.push(call);
+        }
+
+        let spill_directory = PathBuf::from(&self.config.data_directory).join("temp");
+        let mut table = GroupTable::new(&aggregates, self.config.aggregate_memory_limit, 0);
+
+        let mut rows = pin!(rows);
+        while let Some(row) = rows.try_next().await? {
+            let key = group_key(&row, &plan.group_by_items, context)?;
+
+            let reduce_context = ReduceContext {
+                row: Some(row),
+                table_alias_map: context.table_alias_map.clone(),
+                config_columns: vec![],
+                outer: context.outer.clone(),
+            };
+
+            let mut arguments = Vec::with_capacity(aggregates.len());
+            for call in &aggregates {
+                let mut values = Vec::with_capacity(call.arguments.len());
+                for argument in &call.arguments {
+                    values.push(
+                        self.reduce_expression(argument.clone(), reduce_context.clone())
+                            .await?,
+                    );
+                }
+                arguments.push(values);
+            }
+
+            table
+                .insert(AggregateInput { key, arguments }, &spill_directory)
+                .await?;
+        }
+
+        let (mut result, partitions) = table.finish();
+
+        for partition in partitions {
+            result.append(&mut Box::pin(self.aggregate_spilled(partition, &aggregates, 1)).await?);
+        }
+
+        // GROUP BY 없는 집계는 입력이 없어도 한 행을 냅니다. (COUNT는 0, 나머지는 NULL)
+        if plan.group_by_items.is_empty() && result.is_empty() {
+            let table = GroupTable::new(&aggregates, usize::MAX, 0);
+            result.push(table.group_row(vec![], table.new_accumulators()));
+        }
+
+        Ok(result)
+    }
+
+    /// 파티션 파일에 내보낸 입력을 다시 집계합니다. 여기서도 넘치면 한 단계 더 나눕니다.
+    async fn aggregate_spilled(
+        &self,
+        mut partition: SpillFile,
+        aggregates: &[CallExpression],
+        depth: usize,
+    ) -> errors::Result<Vec<TableDataRow>> {
+        let spill_directory = partition.path.parent().unwrap().to_path_buf();
+        let mut table = GroupTable::new(aggregates, self.config.aggregate_memory_limit, depth);
+
+        let mut reader = partition.reader().await?;
+        while let Some(input) = read_input(&mut reader).await? {
+            table.insert(input, &spill_directory).await?;
+        }
+
+        // 다 읽은 파티션 파일은 바로 지웁니다.
+        drop(reader);
+        drop(partition);
+
+        let (mut result, partitions) = table.finish();
+
+        for partition in partitions {
+            result.append(
+                &mut Box::pin(self.aggregate_spilled(partition, aggregates, depth + 1)).await?,
+            );
+        }
+
+        Ok(result)
+    }
+}
+
+pub(crate) fn check_arity(call: &CallExpression) -> errors::Result<()> {
+    let Some(aggregate) = call.function.as_aggregate() else {
+        return Ok(());
+    };
+
+    let (min_arguments, max_arguments) = aggregate.arity();
+
+    if call.arguments.len() < min_arguments || call.arguments.len() > max_arguments {
+        return Err(ExecuteError::wrap(format!(
+            "{} function takes {} parameter(s).",
+            aggregate.name(),
+            max_arguments
+        )));
+    }
+
+    Ok(())
+}
+
+/// 행에서 GROUP BY 항목에 해당하는 필드들을 찾아 그룹 키로 만듭니다.
+fn group_key(
+    row: &TableDataRow,
+    group_by_items: &[GroupByItem],
+    context: &AggregateContext,
+) -> errors::Result<Vec<TableDataField>> {
+    group_by_items
+        .iter()
+        .map(|group_by_item| {
+            let column = &group_by_item.item;
+
+            let mut candidates = row.fields.iter().filter(|field| {
+                field.column_name == column.column_name
+                    && match &column.table_name {
+                        Some(table_name) => match context.table_alias_map.get(table_name) {
+                            // alias가 있으면
+                            Some(table_name) => *table_name == field.table_name,
+                            // 없으면 자체 테이블명 비교
+                            None => *table_name == field.table_name.table_name,
+                        },
+                        None => true,
+                    }
+            });
+
+            match (candidates.next(), candidates.next()) {
+                (Some(field), None) => Ok(field.clone()),
+                (Some(_), Some(_)) => Err(ExecuteError::wrap(format!(
+                    "column select '{:?}' is ambiguous",
+                    column
+                ))),
+                (None, _) => Err(ExecuteError::wrap(format!(
+                    "column select '{:?}' not exists",
+                    column
+                ))),
+            }
+        })
+        .collect()
+}
+
+/// 그룹 키별 누산기를 보관하는 해시 테이블
+struct GroupTable<'a> {
+    aggregates: &'a [CallExpression],
+    groups: HashMap<Vec<TableDataField>, Vec<Accumulator>>,
+    memory_usage: usize,
+    memory_limit: usize,
+    depth: usize,
+    partitions: Vec<Option<SpillFile>>,
+}
+
+impl<'a> GroupTable<'a> {
+    fn new(aggregates: &'a [CallExpression], memory_limit: usize, depth: usize) -> Self {
+        Self {
+            aggregates,
+            groups: HashMap::new(),
+            memory_usage: 0,
+            memory_limit,
+            depth,
+            partitions: vec![],
+        }
+    }
+
+    fn new_accumulators(&self) -> Vec<Accumulator> {
+        self.aggregates
+            .iter()
+            .map(|call| {
+                let aggregate = call
+                    .function
+                    .as_aggregate()
+                    .expect("집계 계획에는 집계 함수만 들어갑니다");
+
+                Accumulator::new(aggregate, call.distinct)
+            })
+            .collect()
+    }
+
+    async fn insert(
+        &mut self,
+        input: AggregateInput,
+        spill_directory: &Path,
+    ) -> errors::Result<()> {
+        if let Some(accumulators) = self.groups.get_mut(&input.key) {
+            let mut grown = 0;
+
+            for (accumulator, arguments) in accumulators.iter_mut().zip(input.arguments) {
+                if accumulator.retains_input() {
+                    grown += arguments.iter().map(estimated_size).sum::<usize>();
+                }
+                accumulator.update(arguments)?;
+            }
+
+            self.memory_usage += grown;
+            return Ok(());
+        }
+
+        let group_size = input
+            .key
+            .iter()
+            .map(|field| estimated_size(&field.data))
+            .sum::<usize>()
+            + std::mem::size_of::<Accumulator>() * self.aggregates.len();
+
+        // 그룹이 하나도 없을 때는 한도와 관계없이 받아야 진행이 보장됩니다.
+        if !self.groups.is_empty()
+            && self.depth < MAX_SPILL_DEPTH
+            && self.memory_usage + group_size > self.memory_limit
+        {
+            return self.spill(input, spill_directory).await;
+        }
+
+        let mut accumulators = self.new_accumulators();
+        for (accumulator, arguments) in accumulators.iter_mut().zip(input.arguments) {
+            if accumulator.retains_input() {
+                self.memory_usage += arguments.iter().map(estimated_size).sum::<usize>();
+            }
+            accumulator.update(arguments)?;
+        }
+
+        self.memory_usage += group_size;
+        self.groups.insert(input.key, accumulators);
+
+        Ok(())
+    }
+
+    async fn spill(&mut self, input: AggregateInput, spill_directory: &Path) -> errors::Result<()> {
+        if self.partitions.is_empty() {
+            self.partitions = (0..SPILL_PARTITION_COUNT).map(|_| None).collect();
+        }
+
+        // 깊이마다 다른 해시를 써야 같은 파티션에 모였던 키들이 다시 나뉩니다.
+        let mut hasher = DefaultHasher::new();
+        self.depth.hash(&mut hasher);
+        input.key.hash(&mut hasher);
+        let index = (hasher.finish() % SPILL_PARTITION_COUNT) as usize;
+
+        let partition = match &mut self.partitions[index] {
+            Some(partition) => partition,
+            partition @ None => partition.insert(SpillFile::create(spill_directory).await?),
+        };
+
+        partition.write(&input).await
+    }
+
+    fn group_row(&self, key: Vec<TableDataField>, accumulators: Vec<Accumulator>) -> TableDataRow {
+        let mut fields = key;
+
+        fields.extend(
+            self.aggregates
+                .iter()
+                .zip(accumulators)
+                .map(|(call, accumulator)| TableDataField {
+                    table_name: TableName::new(None, AGGREGATE_TABLE_NAME.into()),
+                    column_name: aggregate_field_name(call),
+                    data: accumulator.finish(),
+                }),
+        );
+
+        TableDataRow { fields }
+    }
+
+    /// 메모리의 그룹을 결과 행으로 만들고, 내보낸 파티션 파일 목록을 함께 반환합니다.
+    fn finish(mut self) -> (Vec<TableDataRow>, Vec<SpillFile>) {
+        let groups = std::mem::take(&mut self.groups);
+        let partitions = std::mem::take(&mut self.partitions);
+
+        let rows = groups
+            .into_iter()
+            .map(|(key, accumulators)| self.group_row(key, accumulators))
+            .collect();
+
+        (rows, partitions.into_iter().flatten().collect())
+    }
+}
+
+/// 그룹 테이블에서 내보낸 입력을 담는 임시 파일. 다 읽으면(또는 오류로 중단되면) 삭제됩니다.
+struct SpillFile {
+    path: PathBuf,
+    writer: Option<BufWriter<tokio::fs::File>>,
+}
+
+impl SpillFile {
+    async fn create(directory: &Path) -> errors::Result<Self> {
+        tokio::fs::create_dir_all(directory)
+            .await
+            .map_err(|error| ExecuteError::wrap(error.to_string()))?;
+
+        let path = directory.join(format!("aggregate-{}.spill", uuid::Uuid::new_v4()));
+        let file = tokio::fs::File::create(&path)
+            .await
+            .map_err(|error| ExecuteError::wrap(error.to_string()))?;
+
+        Ok(Self {
+            path,
+            writer: Some(BufWriter::new(file)),
+        })
+    }
+
+    // 길이(u32, little endian) + bincode 본문
+    async fn write(&mut self, input: &AggregateInput) -> errors::Result<()> {
+        let Some(writer) = &mut self.writer else {
+            unreachable!("다 쓴 파티션 파일에는 더 쓰지 않습니다");
+        };
+
+        let encoded =
+            bincode::serialize(input).map_err(|error| ExecuteError::wrap(error.to_string()))?;
+
+        writer
+            .write_u32_le(encoded.len() as u32)
+            .await
+            .map_err(|error| ExecuteError::wrap(error.to_string()))?;
+        writer
+            .write_all(&encoded)
+            .await
+            .map_err(|error| ExecuteError::wrap(error.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn reader(&mut self) -> errors::Result<BufReader<tokio::fs::File>> {
+        if let Some(mut writer) = self.writer.take() {
+            writer
+                .flush()
+                .await
+                .map_err(|error| ExecuteError::wrap(error.to_string()))?;
+        }
+
+        let file = tokio::fs::File::open(&self.path)
+            .await
+            .map_err(|error| ExecuteError::wrap(error.to_string()))?;
+
+        Ok(BufReader::new(file))
+    }
+}
+
+impl Drop for SpillFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+async fn read_input(
+    reader: &mut BufReader<tokio::fs::File>,
+) -> errors::Result<Option<AggregateInput>> {
+    let length = match reader.read_u32_le().await {
+        Ok(length) => length as usize,
+        Err(error) if error.kind() == IOErrorKind::UnexpectedEof => return Ok(None),
+        Err(error) => return Err(ExecuteError::wrap(error.to_string())),
+    };
+
+    let mut encoded = vec![0; length];
+    reader
+        .read_exact(&mut encoded)
+        .await
+        .map_err(|error| ExecuteError::wrap(error.to_string()))?;
+
+    bincode::deserialize(&encoded)
+        .map(Some)
+        .map_err(|error| ExecuteError::wrap(error.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::stream;
+
+    use super::*;
+    use crate::config::launch_config::LaunchConfig;
+    use crate::engine::ast::types::{AggregateFunction, SelectColumn};
+
+    fn column(name: &str) -> SQLExpression {
+        SelectColumn::new(None, name.into()).into()
+    }
+
+    fn call(
+        function: AggregateFunction,
+        arguments: Vec<SQLExpression>,
+        distinct: bool,
+    ) -> CallExpression {
+        CallExpression {
+            function: function.into(),
+            arguments,
+            distinct,
+        }
+    }
+
+    fn input_rows() -> Vec<TableDataRow> {
+        let table_name = TableName::new(None, "t".into());
+
+        (0..500)
+            .map(|i| TableDataRow {
+                fields: vec![
+                    TableDataField {
+                        table_name: table_name.clone(),
+                        column_name: "k".into(),
+                        data: TableDataFieldType::Integer(i % 37),
+                    },
+                    TableDataField {
+                        table_name: table_name.clone(),
+                        column_name: "v".into(),
+                        data: TableDataFieldType::Integer(i % 5),
+                    },
+                ],
+            })
+            .collect()
+    }
+
+    async fn aggregate_with_limit(
+        test_name: &str,
+        memory_limit: usize,
+    ) -> (Vec<Vec<TableDataFieldType>>, PathBuf) {
+        let base_path = PathBuf::from("target").join(test_name);
+        if base_path.exists() {
+            tokio::fs::remove_dir_all(&base_path).await.unwrap();
+        }
+
+        let mut config = LaunchConfig::default_for_base_path(&base_path);
+        config.aggregate_memory_limit = memory_limit;
+        let spill_directory = PathBuf::from(&config.data_directory).join("temp");
+        let engine = DBEngine::new(config);
+
+        let plan = AggregatePlan {
+            group_by_items: vec![GroupByItem {
+                item: SelectColumn::new(None, "k".into()),
+            }],
+            aggregates: vec![
+                call(AggregateFunction::Count, vec![], false),
+                call(AggregateFunction::Sum, vec![column("v")], false),
+                call(AggregateFunction::Count, vec![column("v")], true),
+            ],
+        };
+
+        let rows = engine
+            .hash_aggregate(
+                stream::iter(input_rows().into_iter().map(Ok)),
+                &plan,
+                &AggregateContext::default(),
+            )
+            .await
+            .unwrap();
+
+        let mut groups = rows
+            .into_iter()
+            .map(|row| row.fields.into_iter().map(|field| field.data).collect())
+            .collect::<Vec<Vec<_>>>();
+        groups.sort_by(|lhs, rhs| lhs.partial_cmp(rhs).unwrap());
+
+        (groups, spill_directory)
+    }
+
+    #[tokio::test]
+    async fn spilled_aggregation_matches_in_memory_aggregation() {
+        let (in_memory, _) =
+            aggregate_with_limit("test_hash_aggregate_in_memory", usize::MAX).await;
+        let (spilled, spill_directory) = aggregate_with_limit("test_hash_aggregate_spill", 1).await;
+
+        assert_eq!(in_memory.len(), 37);
+        assert_eq!(
+            in_memory[0],
+            vec![
+                TableDataFieldType::Integer(0),
+                TableDataFieldType::Integer(14),
+                TableDataFieldType::Integer(27),
+                TableDataFieldType::Integer(5),
+            ]
+        );
+        assert_eq!(spilled, in_memory);
+
+        // 한도가 1바이트면 첫 그룹 외에는 모두 내보내야 하고, 다 읽은 파일은 지워져야 합니다.
+        let mut entries = tokio::fs::read_dir(&spill_directory).await.unwrap();
+        assert!(entries.next_entry().await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn aggregate_without_group_by_returns_one_row_for_empty_input() {
+        let engine = DBEngine::new(LaunchConfig::default());
+        let count = call(AggregateFunction::Count, vec![], false);
+        let sum = call(AggregateFunction::Sum, vec![column("v")], false);
+
+        let plan = AggregatePlan {
+            group_by_items: vec![],
+            aggregates: vec![count.clone(), sum.clone()],
+        };
+
+        let rows = engine
+            .hash_aggregate(stream::empty(), &plan, &AggregateContext::default())
+            .await
+            .unwrap();
+
+        assert_eq!(rows.len(), 1);
+        assert_eq!(
+            find_aggregate_value(&count, &rows[0]),
+            Some(TableDataFieldType::Integer(0))
+        );
+        assert_eq!(
+            find_aggregate_value(&sum, &rows[0]),
+            Some(TableDataFieldType::Null)
+        );
+
+        // GROUP BY가 있으면 입력이 없을 때 그룹도 없습니다.
+        let plan = AggregatePlan {
+            group_by_items: vec![GroupByItem {
+                item: SelectColumn::new(None, "k".into()),
+            }],
+            ..plan
+        };
+        let rows = engine
+            .hash_aggregate(stream::empty(), &plan, &AggregateContext::default())
+            .await
+            .unwrap();
+
+        assert!(rows.is_empty());
+    }
+}
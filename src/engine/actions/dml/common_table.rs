@@ -0,0 +1,389 @@
+//! WITH 절 (Common Table Expression) 실행
+//!
+//! WITH 절의 테이블은 본문보다 먼저 선언 순서대로 한 번씩 실행해서, 결과 행을 엔진의
+//! 테이블 레지스트리에 바인딩 이름으로 등록해 둡니다. 본문과 뒤따르는 WITH 쿼리가 그 테이블을
+//! 읽으면 테이블 스키마 조회와 풀 스캔이 레지스트리의 결과를 돌려주므로, 여러 번 참조해도
+//! 쿼리는 다시 실행되지 않습니다. 본문이 끝나면 (실패해도) 등록을 해제합니다.
+//!
+//! WITH RECURSIVE의 자기 참조 쿼리는 `비재귀 항 UNION [ALL] 재귀 항` 형태여야 하며,
+//! 비재귀 항의 결과에서 시작해 직전 단계에서 새로 나온 행만 자기 자신으로 보이게 하고
+//! 재귀 항을 반복 실행합니다. 새 행이 나오지 않으면 끝납니다.
+//! UNION은 이미 나온 행을 버리므로 순환 데이터도 끝나지만, UNION ALL로 순환하면
+//! 끝나지 않으므로 반복 횟수에 상한을 둡니다.
+
+use std::collections::HashSet;
+use std::future::Future;
+
+use crate::engine::DBEngine;
+use crate::engine::actions::dml::set_operation::result_values;
+use crate::engine::ast::dml::parts::set_operation::SetOperator;
+use crate::engine::ast::dml::parts::with::{CommonTableExpression, WithClause};
+use crate::engine::ast::dml::select::SelectQuery;
+use crate::engine::ast::types::TableName;
+use crate::engine::expression::ReduceContext;
+use crate::engine::schema::row::{TableDataField, TableDataFieldType, TableDataRow};
+use crate::engine::schema::table::TableSchema;
+use crate::engine::types::ExecuteColumn;
+use crate::errors;
+use crate::errors::execute_error::ExecuteError;
+
+/// 재귀 쿼리의 최대 반복 횟수
+pub const MAX_RECURSIVE_ITERATIONS: usize = 1000;
+
+/// 등록된 WITH 절 테이블 (스키마와 결과 행)
+#[derive(Clone, Debug)]
+pub(crate) struct CommonTable {
+    pub schema: TableSchema,
+    pub rows: Vec<TableDataRow>,
+}
+
+impl DBEngine {
+    /// WITH 절의 테이블들을 실행해 등록한 뒤 본문을 실행합니다.
+    pub(crate) async fn with_common_tables<T>(
+        &self,
+        with_clause: Option<WithClause>,
+        outer: Option<ReduceContext>,
+        body: impl Future<Output = errors::Result<T>>,
+    ) -> errors::Result<T> {
+        let Some(with_clause) = with_clause else {
+            return body.await;
+        };
+
+        let mut bindings = vec![];
+
+        let result = async {
+            for common_table in with_clause.common_tables {
+                bindings.push(common_table.binding.clone());
+
+                let (schema, rows) =
+                    Box::pin(self.materialize_common_table(common_table, outer.clone())).await?;
+                self.register_common_table(schema, rows).await;
+            }
+
+            body.await
+        }
+        .await;
+
+        let mut common_tables = self.common_tables.write().await;
+        for binding in &bindings {
+            common_tables.remove(binding);
+        }
+
+        result
+    }
+
+    /// 결과 행 없이 스키마만 등록합니다. (결과 컬럼 조회용)
+    pub(crate) async fn with_common_table_schemas<T>(
+        &self,
+        with_clause: Option<WithClause>,
+        body: impl Future<Output = errors::Result<T>>,
+    ) -> errors::Result<T> {
+        let Some(with_clause) = with_clause else {
+            return body.await;
+        };
+
+        let mut bindings = vec![];
+
+        let result = async {
+            for common_table in with_clause.common_tables {
+                bindings.push(common_table.binding.clone());
+
+                // 재귀 쿼리의 컬럼은 비재귀 항이 정합니다.
+                let query = match common_table.query.set_operation {
+                    Some(set_operation)
+                        if common_table.query.references_table(&common_table.binding) =>
+                    {
+                        *set_operation.left
+                    }
+                    _ => *common_table.query,
+                };
+
+                let columns = Box::pin(self.describe_select_columns(query)).await?;
+                let columns = rename_columns(&common_table.name, &common_table.columns, columns)?;
+                let schema = TableSchema::derived(common_table.binding, &columns);
+
+                self.register_common_table(schema, vec![]).await;
+            }
+
+            body.await
+        }
+        .await;
+
+        let mut common_tables = self.common_tables.write().await;
+        for binding in &bindings {
+            common_tables.remove(binding);
+        }
+
+        result
+    }
+
+    pub(crate) async fn common_table(&self, table_name: &TableName) -> errors::Result<CommonTable> {
+        self.common_tables
+            .read()
+            .await
+            .get(table_name)
+            .cloned()
+            .ok_or_else(|| {
+                ExecuteError::wrap(format!(
+                    "WITH query '{}' is not available here",
+                    table_name.table_name
+                ))
+            })
+    }
+
+    async fn register_common_table(&self, schema: TableSchema, rows: Vec<TableDataRow>) {
+        self.common_tables
+            .write()
+            .await
+            .insert(schema.table.clone(), CommonTable { schema, rows });
+    }
+
+    /// WITH 절 테이블 하나를 실행해 (스키마, 행)을 만듭니다.
+    async fn materialize_common_table(
+        &self,
+        common_table: CommonTableExpression,
+        outer: Option<ReduceContext>,
+    ) -> errors::Result<(TableSchema, Vec<TableDataRow>)> {
+        if common_table.query.references_table(&common_table.binding) {
+            return self.materialize_recursive(common_table, outer).await;
+        }
+
+        let result = self.select_with_outer(*common_table.query, outer).await?;
+        let columns = rename_columns(&common_table.name, &common_table.columns, result.columns)?;
+
+        let rows = result
+            .rows
+            .into_iter()
+            .map(|row| row.fields.into_iter().map(|field| field.into()).collect())
+            .collect();
+
+        Ok(common_table_rows(common_table.binding, columns, rows))
+    }
+
+    /// 재귀 쿼리를 고정점에 도달할 때까지 반복 실행합니다.
+    async fn materialize_recursive(
+        &self,
+        common_table: CommonTableExpression,
+        outer: Option<ReduceContext>,
+    ) -> errors::Result<(TableSchema, Vec<TableDataRow>)> {
+        let CommonTableExpression {
+            name,
+            binding,
+            columns,
+            query,
+        } = common_table;
+
+        let set_operation = match query.set_operation {
+            Some(set_operation)
+                if set_operation.operator == SetOperator::Union
+                    && !set_operation.left.references_table(&binding)
+                    && query.order_by_clause.is_none()
+                    && query.limit.is_none()
+                    && query.offset.is_none() =>
+            {
+                set_operation
+            }
+            _ => {
+                return Err(ExecuteError::wrap(format!(
+                    "recursive query '{}' does not have the form non-recursive-term UNION [ALL] recursive-term",
+                    name
+                )));
+            }
+        };
+
+        let distinct = !set_operation.all;
+
+        let left = self
+            .select_with_outer(*set_operation.left, outer.clone())
+            .await?;
+        let result_columns = rename_columns(&name, &columns, left.columns.clone())?;
+
+        let mut seen = HashSet::new();
+        let mut rows = dedup_rows(result_values(left, &result_columns), distinct, &mut seen);
+        let mut working = rows.clone();
+
+        let mut iteration = 0;
+
+        while !working.is_empty() {
+            if iteration == MAX_RECURSIVE_ITERATIONS {
+                return Err(ExecuteError::wrap(format!(
+                    "recursive query '{}' did not finish within {} iterations. check the data for a cycle or use UNION instead of UNION ALL",
+                    name, MAX_RECURSIVE_ITERATIONS
+                )));
+            }
+            iteration += 1;
+
+            // 재귀 항에서는 직전 단계에서 새로 나온 행만 보입니다.
+            let (schema, working_rows) =
+                common_table_rows(binding.clone(), result_columns.clone(), working);
+            self.register_common_table(schema, working_rows).await;
+
+            let right = self
+                .select_with_outer((*set_operation.right).clone(), outer.clone())
+                .await?;
+
+            if right.columns.len() != result_columns.len() {
+                return Err(ExecuteError::wrap(format!(
+                    "each UNION query of recursive query '{}' must have the same number of columns",
+                    name
+                )));
+            }
+
+            working = dedup_rows(result_values(right, &result_columns), distinct, &mut seen);
+            rows.extend(working.iter().cloned());
+        }
+
+        Ok(common_table_rows(binding, result_columns, rows))
+    }
+}
+
+/// 실행할 때마다 WITH 절 테이블에 새 바인딩을 붙입니다.
+/// 같은 쿼리가 (상관 서브쿼리처럼) 여러 번 동시에 실행되어도 등록된 결과가 섞이지 않습니다.
+pub(crate) fn rebind_common_tables(query: &mut SelectQuery) {
+    let Some(mut with_clause) = query.with_clause.take() else {
+        return;
+    };
+
+    for index in 0..with_clause.common_tables.len() {
+        let from = with_clause.common_tables[index].binding.clone();
+        let to = CommonTableExpression::new_binding(&with_clause.common_tables[index].name);
+
+        for common_table in &mut with_clause.common_tables[index..] {
+            common_table.query.rename_table(&from, &to);
+        }
+        query.rename_table(&from, &to);
+
+        with_clause.common_tables[index].binding = to;
+    }
+
+    query.with_clause = Some(with_clause);
+}
+
+/// WITH name (column, ...)의 컬럼 목록이 있다면 결과 컬럼 이름을 바꿉니다.
+fn rename_columns(
+    name: &str,
+    names: &[String],
+    mut columns: Vec<ExecuteColumn>,
+) -> errors::Result<Vec<ExecuteColumn>> {
+    if names.is_empty() {
+        return Ok(columns);
+    }
+
+    if names.len() != columns.len() {
+        return Err(ExecuteError::wrap(format!(
+            "WITH query '{}' has {} columns available but {} columns specified",
+            name,
+            columns.len(),
+            names.len()
+        )));
+    }
+
+    for (column, name) in columns.iter_mut().zip(names) {
+        column.name = name.clone();
+    }
+
+    Ok(columns)
+}
+
+/// UNION이라면 이미 나온 행과 같은 행을 버립니다.
+fn dedup_rows(
+    rows: Vec<Vec<TableDataFieldType>>,
+    distinct: bool,
+    seen: &mut HashSet<Vec<TableDataFieldType>>,
+) -> Vec<Vec<TableDataFieldType>> {
+    if !distinct {
+        return rows;
+    }
+
+    rows.into_iter()
+        .filter(|row| seen.insert(row.clone()))
+        .collect()
+}
+
+/// 값 목록을 WITH 절 테이블의 행으로 바꿉니다.
+fn common_table_rows(
+    binding: TableName,
+    columns: Vec<ExecuteColumn>,
+    rows: Vec<Vec<TableDataFieldType>>,
+) -> (TableSchema, Vec<TableDataRow>) {
+    let rows = rows
+        .into_iter()
+        .map(|values| TableDataRow {
+            fields: values
+                .into_iter()
+                .zip(&columns)
+                .map(|(data, column)| TableDataField {
+                    table_name: binding.clone(),
+                    column_name: column.name.clone(),
+                    data,
+                })
+                .collect(),
+        })
+        .collect();
+
+    (TableSchema::derived(binding, &columns), rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::ast::dml::parts::from::FromTarget;
+    use crate::engine::ast::dml::parts::with::WithClause;
+
+    #[test]
+    fn rebind_common_tables_renames_every_reference() {
+        let binding = CommonTableExpression::new_binding("t");
+
+        let common_table_query = SelectQuery::builder()
+            .set_from_table(TableName::new(None, "users".into()))
+            .build();
+        let body = SelectQuery::builder()
+            .set_from_table(binding.clone())
+            .set_from_alias("t".into())
+            .build();
+
+        let mut query = body.set_with(WithClause {
+            recursive: false,
+            common_tables: vec![CommonTableExpression {
+                name: "t".into(),
+                binding: binding.clone(),
+                columns: vec![],
+                query: Box::new(common_table_query),
+            }],
+        });
+
+        rebind_common_tables(&mut query);
+
+        let rebound = query.with_clause.as_ref().unwrap().common_tables[0]
+            .binding
+            .clone();
+
+        assert_ne!(rebound, binding);
+        assert!(rebound.is_common_table());
+        assert!(query.references_table(&rebound));
+        assert!(!query.references_table(&binding));
+        assert_eq!(
+            query.from_table.unwrap().from,
+            FromTarget::Table(rebound.clone())
+        );
+    }
+
+    #[test]
+    fn dedup_rows_keeps_only_unseen_rows_for_union() {
+        let mut seen = HashSet::new();
+        let row = |value| vec![TableDataFieldType::Integer(value)];
+
+        assert_eq!(
+            dedup_rows(vec![row(1), row(1), row(2)], true, &mut seen),
+            vec![row(1), row(2)]
+        );
+        assert_eq!(
+            dedup_rows(vec![row(2), row(3)], true, &mut seen),
+            vec![row(3)]
+        );
+        assert_eq!(
+            dedup_rows(vec![row(3), row(3)], false, &mut seen),
+            vec![row(3), row(3)]
+        );
+    }
+}
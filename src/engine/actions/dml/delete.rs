@@ -4,15 +4,16 @@ use futures::future::join_all;
 
 use crate::engine::actions::index::row_index_key;
 use crate::engine::ast::dml::delete::DeleteQuery;
+use crate::engine::ast::dml::parts::locking::RowLockMode;
 use crate::engine::ast::dml::plan::delete::delete_plan::DeletePlanItem;
 use crate::engine::ast::dml::plan::select::scan::ScanType;
 use crate::engine::expression::ReduceContext;
 use crate::engine::optimizer::predule::Optimizer;
-use crate::engine::schema::row::TableDataFieldType;
+use crate::engine::schema::row::{TableDataFieldType, TableDataRow};
 use crate::engine::types::{
     ExecuteColumn, ExecuteColumnType, ExecuteField, ExecuteResult, ExecuteRow,
 };
-use crate::engine::wal::types::EntryType;
+use crate::engine::wal::types::{DeleteRowsWALPayload, EntryType};
 use crate::engine::{DBEngine, SharedWALManager};
 use crate::errors;
 use crate::errors::execute_error::ExecuteError;
@@ -30,23 +31,103 @@ impl DBEngine {
     /// Re-applies a previously WAL-logged DELETE during crash recovery
     /// replay. Identical to `delete()` but skips the WAL append (the
     /// operation is already durably recorded in the WAL being replayed).
-    pub(crate) async fn delete_replay(&self, query: DeleteQuery) -> errors::Result<ExecuteResult> {
+    pub(crate) async fn delete_replay(
+        &self,
+        mut query: DeleteQuery,
+    ) -> errors::Result<ExecuteResult> {
+        // replay 결과는 버려지므로 RETURNING은 계산하지 않습니다.
+        query.returning = None;
+
         self.delete_internal(query, None).await
     }
 
+    /// WAL replay용 DELETE ... USING.
+    ///
+    /// 기록된 행 위치 중 아직 살아 있는 행만 삭제하므로, 이미 반영된 항목을 다시
+    /// 적용해도 결과가 같습니다.
+    pub(crate) async fn delete_rows_replay(
+        &self,
+        payload: DeleteRowsWALPayload,
+    ) -> errors::Result<()> {
+        let DeleteRowsWALPayload {
+            table_name,
+            row_indexes,
+        } = payload;
+
+        self.ensure_indices_loaded().await?;
+        let index_metas = self.table_index_metas(&table_name).await;
+
+        let live_rows = {
+            let _guard = self.row_storage_lock.lock().await;
+            let stored_rows = self.read_table_rows_locked(&table_name).await?;
+
+            row_indexes
+                .into_iter()
+                .filter_map(|row_index| {
+                    stored_rows
+                        .get(row_index)
+                        .cloned()
+                        .flatten()
+                        .map(|row| (row_index, row))
+                })
+                .collect::<HashMap<_, _>>()
+        };
+
+        if live_rows.is_empty() {
+            return Ok(());
+        }
+
+        self.delete_table_rows(&table_name, live_rows.keys().copied().collect())
+            .await?;
+
+        for (row_index, row) in &live_rows {
+            for meta in &index_metas {
+                if let Some(key) = row_index_key(row, &meta.column_name) {
+                    self.index_manager
+                        .remove(&meta.index_name, &key, &row_index.to_string())
+                        .await?;
+                }
+            }
+        }
+
+        self.statistics_manager
+            .record_delete(&table_name, live_rows.len())
+            .await;
+
+        Ok(())
+    }
+
     async fn delete_internal(
         &self,
         query: DeleteQuery,
         wal_manager: Option<SharedWALManager>,
     ) -> errors::Result<ExecuteResult> {
-        let table = query.from_table.as_ref().unwrap().table.clone();
+        let target = query.from_table.clone().unwrap();
+        let table = target.table.clone();
+
+        // USING 절이 있으면 조인 결과에 따라 대상 행이 달라지므로, 쿼리 대신
+        // 결정된 행 위치를 WAL에 기록합니다.
+        let has_sources = !query.using_tables.is_empty();
+
+        // RETURNING 절은 데이터를 바꾸기 전에 검증해 둡니다.
+        let returning = match &query.returning {
+            Some(returning) => {
+                let targets = std::iter::once(target.clone())
+                    .chain(query.using_tables.iter().cloned())
+                    .collect::<Vec<_>>();
+
+                Some(self.prepare_returning(&targets, returning).await?)
+            }
+            None => None,
+        };
 
         // WAL-first: 쿼리를 실행/소비하기 전에 페이로드를 미리 직렬화합니다.
         let wal_payload = match &wal_manager {
-            Some(_) => Some(
-                bincode::serialize(&query).map_err(|error| ExecuteError::wrap(error.to_string()))?,
+            Some(_) if !has_sources => Some(
+                bincode::serialize(&query)
+                    .map_err(|error| ExecuteError::wrap(error.to_string()))?,
             ),
-            None => None,
+            _ => None,
         };
 
         // 최적화 작업 (대상 테이블의 인덱스/통계로 컨텍스트 구성)
@@ -88,8 +169,9 @@ impl DBEngine {
                     }
                 }
                 // 필터링 처리
-                DeletePlanItem::Filter(filter) => {
-                    let total_count = rows.len();
+                DeletePlanItem::Filter(mut filter) => {
+                    filter.expression = self.materialize_subqueries(filter.expression).await?;
+
                     let futures = rows.iter().cloned().map(|(path, row)| {
                         let table_alias_map = table_alias_map.clone();
                         let filter = filter.clone();
@@ -98,7 +180,7 @@ impl DBEngine {
                                 row: Some(row.to_owned()),
                                 table_alias_map,
                                 config_columns: vec![],
-                                total_count,
+                                outer: None,
                             };
 
                             let condition = self
@@ -126,9 +208,18 @@ impl DBEngine {
                         .map(|(path, row, _)| (path, row))
                         .collect();
                 }
+                // USING 테이블 조인 처리
+                DeletePlanItem::Join(join) => {
+                    rows = self
+                        .join_target_rows(rows, &join, &mut table_alias_map, &mut table_infos)
+                        .await?;
+                }
             }
         }
 
+        // 결합한 행의 앞부분이 대상 테이블의 행입니다.
+        let target_width = table_infos[0].columns.len();
+
         self.ensure_indices_loaded().await?;
         let index_metas = self.table_index_metas(&table).await;
 
@@ -137,10 +228,17 @@ impl DBEngine {
         let mut index_removals: Vec<(String, String, String)> = vec![];
 
         for (location, row) in &rows {
-            row_indexes.insert(location.row_index);
+            // USING 테이블의 여러 행과 매칭되더라도 대상 행은 한 번만 삭제합니다.
+            if !row_indexes.insert(location.row_index) {
+                continue;
+            }
+
+            let row = TableDataRow {
+                fields: row.fields[..target_width].to_vec(),
+            };
 
             for meta in &index_metas {
-                if let Some(key) = row_index_key(row, &meta.column_name) {
+                if let Some(key) = row_index_key(&row, &meta.column_name) {
                     index_removals.push((
                         meta.index_name.clone(),
                         key,
@@ -150,15 +248,37 @@ impl DBEngine {
             }
         }
 
+        // FOR UPDATE / FOR SHARE로 잠근 행은 그 트랜잭션이 끝날 때까지 기다립니다.
+        self.lock_target_rows(&table, row_indexes.iter().copied(), RowLockMode::Update)
+            .await?;
+
         let affected_rows = row_indexes.len();
 
         if !row_indexes.is_empty() {
             // WAL-first: 먼저 durable하게 기록한 뒤 실제 데이터/인덱스를 변경합니다.
             if let Some(wal_manager) = &wal_manager {
+                let (entry_type, wal_payload) = if has_sources {
+                    let payload = DeleteRowsWALPayload {
+                        table_name: table.clone(),
+                        row_indexes: row_indexes.iter().copied().collect(),
+                    };
+
+                    (
+                        EntryType::DeleteRows,
+                        Some(
+                            bincode::serialize(&payload)
+                                .map_err(|error| ExecuteError::wrap(error.to_string()))?,
+                        ),
+                    )
+                } else {
+                    (EntryType::Delete, wal_payload)
+                };
+
+                let transaction_id = self.transaction_manager.writer_transaction_id();
                 wal_manager
                     .lock()
                     .await
-                    .append_record(EntryType::Delete, wal_payload, None)
+                    .append_record(entry_type, wal_payload, transaction_id)
                     .await?;
             }
 
@@ -174,6 +294,20 @@ impl DBEngine {
                 .await;
         }
 
+        if let Some(returning) = returning {
+            // 삭제된 행은 삭제 전의 값으로 돌려줍니다.
+            let mut returned_rows = vec![];
+            let mut returned_indexes = HashSet::new();
+
+            for (location, row) in rows {
+                if returned_indexes.insert(location.row_index) {
+                    returned_rows.push(row);
+                }
+            }
+
+            return self.project_returning(returning, returned_rows).await;
+        }
+
         Ok(ExecuteResult::with_affected_rows(
             vec![ExecuteColumn {
                 name: "desc".into(),
@@ -0,0 +1,566 @@
+//! 사용자 정의 SQL 함수 호출
+//!
+//! 쿼리를 실행하기 전에 사용자 정의 함수 호출을 찾아 정의를 불러옵니다.
+//! 본문이 FROM 절 없는 표현식 하나라면 호출 위치에 본문을 그대로 펼쳐 넣고(인라이닝),
+//! 그 외에는 실행 중에 인자 값을 채운 본문을 스칼라 서브쿼리로 실행합니다.
+//!
+//! 인자는 매개변수 타입으로, 결과는 반환 타입으로 변환합니다.
+//! 본문에서 매개변수는 이름, $n, 혹은 함수명.매개변수명으로 참조하며 같은 이름의 컬럼보다 우선합니다.
+
+use std::collections::HashMap;
+
+use crate::engine::DBEngine;
+use crate::engine::ast::dml::expressions::cast::CastExpression;
+use crate::engine::ast::dml::expressions::subquery::SubqueryExpression;
+use crate::engine::ast::dml::insert::InsertData;
+use crate::engine::ast::dml::parts::on_conflict::{OnConflictAction, OnConflictClause};
+use crate::engine::ast::dml::parts::returning::ReturningClause;
+use crate::engine::ast::dml::parts::select_item::SelectKind;
+use crate::engine::ast::dml::parts::with::WithClause;
+use crate::engine::ast::dml::select::SelectQuery;
+use crate::engine::ast::types::{
+    BuiltInFunction, DataType, Function, SQLExpression, ScalarFunction, SelectColumn,
+    UserDefinedFunction,
+};
+use crate::engine::ast::{DMLStatement, SQLStatement};
+use crate::engine::expression::ReduceContext;
+use crate::engine::parser::predule::Parser;
+use crate::engine::schema::function::FunctionSchema;
+use crate::engine::schema::row::TableDataFieldType;
+use crate::engine::types::ExecuteColumnType;
+use crate::errors;
+use crate::errors::execute_error::ExecuteError;
+use crate::errors::type_error::TypeError;
+
+impl DBEngine {
+    // 문장 안의 사용자 정의 함수 호출을 해석합니다.
+    // 펼쳐 넣은 본문에 다른 함수 호출이 있을 수 있으므로 더 펼칠 것이 없을 때까지 반복합니다.
+    pub(crate) async fn resolve_functions(
+        &self,
+        statement: &mut SQLStatement,
+    ) -> errors::Result<()> {
+        loop {
+            let functions = collect_statement_functions(statement)?;
+
+            if functions.is_empty() {
+                return Ok(());
+            }
+
+            let mut schemas = HashMap::new();
+            for function in functions {
+                if schemas.contains_key(&function) {
+                    continue;
+                }
+
+                self.check_function_cycle(&function, &mut vec![]).await?;
+
+                let schema = self.get_function_config_cached(&function).await?;
+                schemas.insert(function, schema);
+            }
+
+            let mut inlined = false;
+            visit_statement_expressions(statement, &mut |expression| {
+                expression.visit_mut(&mut |expression| {
+                    if let Some(body) = inline_function_call(expression, &schemas)? {
+                        *expression = body;
+                        inlined = true;
+                    }
+
+                    Ok(())
+                })
+            })?;
+
+            if !inlined {
+                return Ok(());
+            }
+        }
+    }
+
+    // 본문이 자기 자신을 (다른 함수를 거쳐서라도) 호출하면 끝나지 않으므로 거부합니다.
+    async fn check_function_cycle(
+        &self,
+        function: &UserDefinedFunction,
+        path: &mut Vec<UserDefinedFunction>,
+    ) -> errors::Result<()> {
+        if path.contains(function) {
+            let cycle = path
+                .iter()
+                .chain(std::iter::once(function))
+                .map(|function| function.function_name.as_str())
+                .collect::<Vec<_>>()
+                .join(" -> ");
+
+            return Err(ExecuteError::wrap(format!(
+                "recursive function calls are not supported: {}",
+                cycle
+            )));
+        }
+
+        let schema = self.get_function_config_cached(function).await?;
+        let body = Parser::parse_function_body(&schema.body, function.database_name.clone())?;
+
+        path.push(function.clone());
+        for callee in collect_statement_functions(&mut body.into())? {
+            Box::pin(self.check_function_cycle(&callee, path)).await?;
+        }
+        path.pop();
+
+        Ok(())
+    }
+
+    // 펼쳐 넣지 못한 호출은 인자 값을 채운 본문을 실행합니다.
+    // 본문은 호출한 쿼리의 행을 보지 않으며, 결과의 첫 행이 반환값입니다. (PostgreSQL과 동일)
+    pub(crate) async fn call_user_defined_function(
+        &self,
+        function: &UserDefinedFunction,
+        arguments: Vec<TableDataFieldType>,
+    ) -> errors::Result<TableDataFieldType> {
+        let schema = self.get_function_config_cached(function).await?;
+        check_argument_count(&schema, arguments.len())?;
+
+        let arguments = arguments
+            .into_iter()
+            .zip(&schema.parameters)
+            .map(|(argument, parameter)| {
+                argument
+                    .cast_to(&parameter.data_type)
+                    .map(SQLExpression::from)
+            })
+            .collect::<errors::Result<Vec<_>>>()?;
+
+        let mut body: SQLStatement =
+            Parser::parse_function_body(&schema.body, function.database_name.clone())?.into();
+
+        visit_statement_expressions(&mut body, &mut |expression| {
+            expression.visit_mut(&mut |expression| {
+                if let SQLExpression::SelectColumn(column) = expression
+                    && let Some(index) = parameter_reference(&schema, column)
+                {
+                    *expression = arguments[index].clone();
+                }
+
+                Ok(())
+            })
+        })?;
+
+        self.resolve_functions(&mut body).await?;
+
+        let SQLStatement::DML(DMLStatement::SelectQuery(query)) = body else {
+            unreachable!("function body is always a SELECT query")
+        };
+
+        let values = Box::pin(
+            self.reduce_subquery_values(SubqueryExpression::Select(Box::new(query)), None),
+        )
+        .await?;
+
+        values
+            .into_iter()
+            .next()
+            .unwrap_or(TableDataFieldType::Null)
+            .cast_to(&schema.return_type)
+    }
+
+    // CREATE FUNCTION에서 본문의 결과 타입이 반환 타입으로 변환될 수 있는지 검사합니다.
+    // 아직 없는 함수나 테이블을 참조해 타입을 알 수 없으면 호출할 때 검사합니다.
+    pub(crate) async fn check_function_return_type(
+        &self,
+        schema: &FunctionSchema,
+    ) -> errors::Result<()> {
+        let query =
+            Parser::parse_function_body(&schema.body, schema.function.database_name.clone())?;
+
+        // 문자열 상수는 값을 변환해 봐야 알 수 있습니다. ('42'는 integer가 될 수 있습니다)
+        if let Some(SQLExpression::String(value)) = single_expression_body(query.clone()) {
+            TableDataFieldType::String(value).cast_to(&schema.return_type)?;
+            return Ok(());
+        }
+
+        let mut body: SQLStatement = query.into();
+
+        // 매개변수는 그 타입의 NULL로 바꿔 타입만 남깁니다.
+        visit_statement_expressions(&mut body, &mut |expression| {
+            expression.visit_mut(&mut |expression| {
+                if let SQLExpression::SelectColumn(column) = expression
+                    && let Some(index) = parameter_reference(schema, column)
+                {
+                    *expression = CastExpression {
+                        expression: SQLExpression::Null,
+                        data_type: schema.parameters[index].data_type.clone(),
+                    }
+                    .into();
+                }
+
+                Ok(())
+            })
+        })?;
+
+        for function in collect_statement_functions(&mut body)? {
+            if function == schema.function
+                || self.get_function_config_cached(&function).await.is_err()
+            {
+                return Ok(());
+            }
+        }
+
+        let SQLStatement::DML(DMLStatement::SelectQuery(query)) = body else {
+            unreachable!("function body is always a SELECT query")
+        };
+        let expression = SQLExpression::Subquery(SubqueryExpression::Select(Box::new(query)));
+
+        if self.cache_subquery_schemas(&expression).await.is_err() {
+            return Ok(());
+        }
+
+        let Ok(body_type) = self.reduce_type(expression, ReduceContext::default()).await else {
+            return Ok(());
+        };
+
+        if !converts_to(&body_type, &schema.return_type) {
+            return Err(TypeError::wrap(format!(
+                "return type mismatch in function declared to return {}: body returns {}",
+                String::from(schema.return_type.clone()),
+                body_type.name()
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+// 값을 보지 않고도 변환이 성공하는 타입인지 여부 (TableDataFieldType::cast_to 기준)
+fn converts_to(from: &ExecuteColumnType, to: &DataType) -> bool {
+    match (from, to) {
+        (ExecuteColumnType::Null, _) | (_, DataType::Varchar(_)) => true,
+        (ExecuteColumnType::Integer, _) => true,
+        (ExecuteColumnType::Float, data_type) => {
+            matches!(data_type, DataType::Int | DataType::Float)
+        }
+        (ExecuteColumnType::Bool, data_type) => {
+            matches!(data_type, DataType::Int | DataType::Boolean)
+        }
+        (ExecuteColumnType::String | ExecuteColumnType::Array(_), _) => false,
+    }
+}
+
+pub(crate) fn check_argument_count(
+    schema: &FunctionSchema,
+    argument_count: usize,
+) -> errors::Result<()> {
+    if schema.parameters.len() != argument_count {
+        return Err(ExecuteError::wrap(format!(
+            "function {} takes {} arguments, but {} were given",
+            schema.signature(),
+            schema.parameters.len(),
+            argument_count
+        )));
+    }
+
+    Ok(())
+}
+
+// 본문의 컬럼 참조가 매개변수를 가리키면 몇번째 인자인지 반환합니다.
+fn parameter_reference(schema: &FunctionSchema, column: &SelectColumn) -> Option<usize> {
+    match &column.table_name {
+        None => schema.parameter_index(&column.column_name),
+        Some(table_name) if table_name == &schema.function.function_name => {
+            schema.parameter_index(&column.column_name)
+        }
+        Some(_) => None,
+    }
+}
+
+// 호출이 펼쳐 넣을 수 있는 함수라면 인자를 채운 본문 표현식을 반환합니다.
+fn inline_function_call(
+    expression: &SQLExpression,
+    schemas: &HashMap<UserDefinedFunction, FunctionSchema>,
+) -> errors::Result<Option<SQLExpression>> {
+    let SQLExpression::FunctionCall(call) = expression else {
+        return Ok(None);
+    };
+
+    let Function::UserDefined(function) = &call.function else {
+        return Ok(None);
+    };
+
+    let Some(schema) = schemas.get(function) else {
+        return Ok(None);
+    };
+
+    check_argument_count(schema, call.arguments.len())?;
+
+    let body = Parser::parse_function_body(&schema.body, function.database_name.clone())?;
+
+    let Some(mut body) = single_expression_body(body) else {
+        return Ok(None);
+    };
+
+    // FROM 절이 없으므로 매개변수가 아닌 컬럼은 찾을 곳이 없습니다.
+    let mut references = vec![0; schema.parameters.len()];
+    body.visit_mut(&mut |expression| {
+        if let SQLExpression::SelectColumn(column) = expression {
+            match parameter_reference(schema, column) {
+                Some(index) => references[index] += 1,
+                None => {
+                    return Err(ExecuteError::wrap(format!(
+                        "column \"{}\" does not exist",
+                        column.column_name
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    })?;
+
+    // 여러 번 참조되는 인자를 복사하면 결과가 달라지는 경우(난수, 서브쿼리 등)에는 펼치지 않습니다.
+    let duplicates_volatile_argument = references
+        .iter()
+        .zip(&call.arguments)
+        .any(|(count, argument)| *count > 1 && is_volatile(argument));
+
+    if duplicates_volatile_argument {
+        return Ok(None);
+    }
+
+    let arguments = call
+        .arguments
+        .iter()
+        .zip(&schema.parameters)
+        .map(|(argument, parameter)| -> SQLExpression {
+            CastExpression {
+                expression: argument.clone(),
+                data_type: parameter.data_type.clone(),
+            }
+            .into()
+        })
+        .collect::<Vec<_>>();
+
+    body.visit_mut(&mut |expression| {
+        if let SQLExpression::SelectColumn(column) = expression
+            && let Some(index) = parameter_reference(schema, column)
+        {
+            *expression = arguments[index].clone();
+        }
+
+        Ok(())
+    })?;
+
+    Ok(Some(
+        CastExpression {
+            expression: body,
+            data_type: schema.return_type.clone(),
+        }
+        .into(),
+    ))
+}
+
+// SELECT 표현식 하나로만 된 본문이라면 그 표현식을 반환합니다.
+fn single_expression_body(query: SelectQuery) -> Option<SQLExpression> {
+    let SelectQuery {
+        mut select_items,
+        distinct: None,
+        has_aggregate: false,
+        from_table: None,
+        join_clause,
+        where_clause: None,
+        order_by_clause: None,
+        group_by_clause: None,
+        having_clause: None,
+        limit: None,
+        offset: None,
+        set_operation: None,
+        with_clause: None,
+        locking_clause: None,
+    } = query
+    else {
+        return None;
+    };
+
+    if !join_clause.is_empty() || select_items.len() != 1 {
+        return None;
+    }
+
+    let SelectKind::SelectItem(item) = select_items.remove(0) else {
+        return None;
+    };
+
+    item.item.filter(|expression| {
+        !expression.has_subquery() && !expression.has_aggregate() && !expression.has_window()
+    })
+}
+
+// 평가할 때마다 결과가 달라질 수 있는 표현식인지 여부
+fn is_volatile(expression: &SQLExpression) -> bool {
+    if expression.has_subquery() {
+        return true;
+    }
+
+    let mut volatile = false;
+    let _ = expression.clone().visit_mut(&mut |expression| {
+        if let SQLExpression::FunctionCall(call) = expression {
+            volatile |= matches!(
+                call.function,
+                Function::BuiltIn(BuiltInFunction::Scalar(ScalarFunction::Random))
+                    | Function::UserDefined(_)
+            );
+        }
+
+        Ok(())
+    });
+
+    volatile
+}
+
+fn collect_statement_functions(
+    statement: &mut SQLStatement,
+) -> errors::Result<Vec<UserDefinedFunction>> {
+    let mut functions = vec![];
+
+    visit_statement_expressions(statement, &mut |expression| {
+        expression.visit_mut(&mut |expression| {
+            if let SQLExpression::FunctionCall(call) = expression
+                && let Function::UserDefined(function) = &call.function
+            {
+                functions.push(function.clone());
+            }
+
+            Ok(())
+        })
+    })?;
+
+    Ok(functions)
+}
+
+// 문장 안의 모든 최상위 표현식을 방문합니다. (WITH 절과 서브쿼리 안의 쿼리 포함)
+fn visit_statement_expressions(
+    statement: &mut SQLStatement,
+    visit: &mut impl FnMut(&mut SQLExpression) -> errors::Result<()>,
+) -> errors::Result<()> {
+    match statement {
+        SQLStatement::DML(DMLStatement::SelectQuery(query)) => {
+            visit_query_expressions(query, visit)
+        }
+        SQLStatement::DML(DMLStatement::InsertQuery(query)) => {
+            visit_with_clause_expressions(&mut query.with_clause, visit)?;
+
+            match &mut query.data {
+                InsertData::Select(query) => visit_query_expressions(query, visit),
+                InsertData::Values(values) => {
+                    for expression in values
+                        .iter_mut()
+                        .flat_map(|value| value.list.iter_mut())
+                        .flatten()
+                    {
+                        visit_expression(expression, visit)?;
+                    }
+
+                    Ok(())
+                }
+                InsertData::None => Ok(()),
+            }?;
+
+            if let Some(OnConflictClause {
+                action:
+                    OnConflictAction::DoUpdate {
+                        update_items,
+                        where_clause,
+                    },
+                ..
+            }) = &mut query.on_conflict
+            {
+                for item in update_items {
+                    visit_expression(&mut item.value, visit)?;
+                }
+
+                if let Some(where_clause) = where_clause {
+                    visit_expression(&mut where_clause.expression, visit)?;
+                }
+            }
+
+            visit_returning_expressions(&mut query.returning, visit)
+        }
+        SQLStatement::DML(DMLStatement::UpdateQuery(query)) => {
+            visit_with_clause_expressions(&mut query.with_clause, visit)?;
+
+            for item in &mut query.update_items {
+                visit_expression(&mut item.value, visit)?;
+            }
+
+            if let Some(where_clause) = &mut query.where_clause {
+                visit_expression(&mut where_clause.expression, visit)?;
+            }
+
+            visit_returning_expressions(&mut query.returning, visit)
+        }
+        SQLStatement::DML(DMLStatement::DeleteQuery(query)) => {
+            visit_with_clause_expressions(&mut query.with_clause, visit)?;
+
+            if let Some(where_clause) = &mut query.where_clause {
+                visit_expression(&mut where_clause.expression, visit)?;
+            }
+
+            visit_returning_expressions(&mut query.returning, visit)
+        }
+        _ => Ok(()),
+    }
+}
+
+fn visit_returning_expressions(
+    returning: &mut Option<ReturningClause>,
+    visit: &mut impl FnMut(&mut SQLExpression) -> errors::Result<()>,
+) -> errors::Result<()> {
+    for select_kind in returning
+        .iter_mut()
+        .flat_map(|returning| returning.select_items.iter_mut())
+    {
+        if let SelectKind::SelectItem(item) = select_kind
+            && let Some(expression) = &mut item.item
+        {
+            visit_expression(expression, visit)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn visit_with_clause_expressions(
+    with_clause: &mut Option<WithClause>,
+    visit: &mut impl FnMut(&mut SQLExpression) -> errors::Result<()>,
+) -> errors::Result<()> {
+    for common_table in with_clause
+        .iter_mut()
+        .flat_map(|with_clause| with_clause.common_tables.iter_mut())
+    {
+        visit_query_expressions(&mut common_table.query, visit)?;
+    }
+
+    Ok(())
+}
+
+fn visit_query_expressions(
+    query: &mut SelectQuery,
+    visit: &mut impl FnMut(&mut SQLExpression) -> errors::Result<()>,
+) -> errors::Result<()> {
+    query.visit_queries_mut(&mut |query| {
+        for expression in query.expressions_mut() {
+            visit(expression)?;
+        }
+
+        Ok(())
+    })
+}
+
+// 쿼리 밖의 표현식(INSERT 값, UPDATE SET 등)과 그 안의 서브쿼리를 방문합니다.
+fn visit_expression(
+    expression: &mut SQLExpression,
+    visit: &mut impl FnMut(&mut SQLExpression) -> errors::Result<()>,
+) -> errors::Result<()> {
+    visit(expression)?;
+
+    for subquery in expression.get_subquery_list_mut() {
+        visit_query_expressions(subquery.query_mut(), visit)?;
+    }
+
+    Ok(())
+}
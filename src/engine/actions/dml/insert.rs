@@ -2,8 +2,10 @@ use std::collections::HashSet;
 
 use crate::engine::actions::index::row_index_key;
 use crate::engine::ast::dml::insert::{InsertData, InsertQuery};
+use crate::engine::ast::dml::parts::insert_values::InsertValue;
 use crate::engine::ast::types::SQLExpression;
-use crate::engine::schema::row::{TableDataField, TableDataRow};
+use crate::engine::index::NULL_KEY;
+use crate::engine::schema::row::{TableDataField, TableDataFieldType, TableDataRow};
 use crate::engine::types::{
     ExecuteColumn, ExecuteColumnType, ExecuteField, ExecuteResult, ExecuteRow,
 };
@@ -24,7 +26,13 @@ impl DBEngine {
     /// Re-applies a previously WAL-logged INSERT during crash recovery
     /// replay. Identical to `insert()` but skips the WAL append (the
     /// operation is already durably recorded in the WAL being replayed).
-    pub(crate) async fn insert_replay(&self, query: InsertQuery) -> errors::Result<ExecuteResult> {
+    pub(crate) async fn insert_replay(
+        &self,
+        mut query: InsertQuery,
+    ) -> errors::Result<ExecuteResult> {
+        // replay 결과는 버려지므로 RETURNING은 계산하지 않습니다.
+        query.returning = None;
+
         self.insert_internal(query, None).await
     }
 
@@ -76,12 +84,26 @@ impl DBEngine {
         query: InsertQuery,
         wal_manager: Option<SharedWALManager>,
     ) -> errors::Result<ExecuteResult> {
+        // INSERT ... SELECT는 SELECT 결과를 VALUES로 바꿔서 처리합니다.
+        // WAL에도 바뀐 VALUES가 기록되므로, replay는 SELECT를 다시 실행하지 않고
+        // 원래 INSERT된 값을 그대로 다시 씁니다.
+        let query = self.resolve_insert_select(query).await?;
+
         let into_table = query.into_table.as_ref().unwrap();
 
         let table_name = into_table.clone().table_name;
 
         let table_config = self.get_table_config_cached(into_table.clone()).await?;
 
+        // RETURNING 절은 데이터를 바꾸기 전에 검증해 둡니다.
+        let returning = match &query.returning {
+            Some(returning) => Some(
+                self.prepare_returning(&[into_table.clone().into()], returning)
+                    .await?,
+            ),
+            None => None,
+        };
+
         // 입력된 컬럼
         let input_columns_set: HashSet<String> = HashSet::from_iter(query.columns.iter().cloned());
 
@@ -119,13 +141,12 @@ impl DBEngine {
                         // `query.columns`는 SQL에서 그대로 온 값이라 스키마에 없는
                         // 이름이 들어올 수 있습니다. 파서는 값 개수만 확인하고
                         // 컬럼의 존재 여부는 모릅니다 (#260).
-                        let column_config_info =
-                            columns_map.get(column_name).ok_or_else(|| {
-                                ExecuteError::wrap(format!(
-                                    "column '{}' does not exist on table '{}'",
-                                    column_name, table_name
-                                ))
-                            })?;
+                        let column_config_info = columns_map.get(column_name).ok_or_else(|| {
+                            ExecuteError::wrap(format!(
+                                "column '{}' does not exist on table '{}'",
+                                column_name, table_name
+                            ))
+                        })?;
 
                         let default_value = match &column_config_info.default {
                             Some(default) => default.to_owned(),
@@ -233,12 +254,39 @@ impl DBEngine {
                 self.ensure_indices_loaded().await?;
                 let index_metas = self.table_index_metas(into_table).await;
 
+                // ON CONFLICT 절이 있으면 충돌한 행을 건너뛰거나 수정합니다.
+                if let Some(on_conflict) = query.on_conflict.clone() {
+                    let affected_rows = self
+                        .insert_on_conflict(into_table, on_conflict, rows, index_metas, wal_manager)
+                        .await?;
+
+                    if let Some(returning) = returning {
+                        return self.project_returning(returning, affected_rows).await;
+                    }
+
+                    return Ok(ExecuteResult::with_affected_rows(
+                        vec![ExecuteColumn {
+                            name: "desc".into(),
+                            data_type: ExecuteColumnType::String,
+                        }],
+                        vec![ExecuteRow {
+                            fields: vec![ExecuteField::String(format!(
+                                "inserted into {}",
+                                table_name
+                            ))],
+                        }],
+                        affected_rows.len(),
+                    ));
+                }
+
                 // 고유 인덱스 사전 검증 (기존 데이터 + 배치 내 중복)
                 for meta in index_metas.iter().filter(|meta| meta.is_unique) {
                     let mut batch_keys = HashSet::new();
 
                     for row in &rows {
-                        if let Some(key) = row_index_key(row, &meta.column_name) {
+                        if let Some(key) = row_index_key(row, &meta.column_name)
+                            && key != NULL_KEY
+                        {
                             let duplicated = !self
                                 .index_manager
                                 .get(&meta.index_name, &key)
@@ -262,6 +310,7 @@ impl DBEngine {
                 // WAL은 행 위치가 확정된 직후, 아직 row storage 락을 쥔 상태에서
                 // 기록합니다. 그래야 기록된 start_row_index가 실제로 이 INSERT가
                 // 차지한 범위와 일치하고, replay가 멱등해집니다 (#236).
+                let transaction_id = self.transaction_manager.writer_transaction_id();
                 let start_index = self
                     .append_table_rows_with_reservation(into_table, &rows, |start_row_index| {
                         let wal_manager = wal_manager.clone();
@@ -282,7 +331,7 @@ impl DBEngine {
                             wal_manager
                                 .lock()
                                 .await
-                                .append_record(EntryType::Insert, Some(wal_payload), None)
+                                .append_record(EntryType::Insert, Some(wal_payload), transaction_id)
                                 .await
                         }
                     })
@@ -337,6 +386,10 @@ impl DBEngine {
                     .record_insert(into_table, affected_rows)
                     .await;
 
+                if let Some(returning) = returning {
+                    return self.project_returning(returning, rows).await;
+                }
+
                 return Ok(ExecuteResult::with_affected_rows(
                     vec![ExecuteColumn {
                         name: "desc".into(),
@@ -351,12 +404,14 @@ impl DBEngine {
                     affected_rows,
                 ));
             }
-            InsertData::Select(_select) => {
-                todo!("아직 미구현")
-            }
+            InsertData::Select(_) => unreachable!("resolved into VALUES above"),
             InsertData::None => {}
         }
 
+        if let Some(returning) = returning {
+            return self.project_returning(returning, vec![]).await;
+        }
+
         Ok(ExecuteResult::with_affected_rows(
             vec![ExecuteColumn {
                 name: "desc".into(),
@@ -371,6 +426,98 @@ impl DBEngine {
             0,
         ))
     }
+
+    /// INSERT ... SELECT의 SELECT를 실행하고, 결과 행을 대상 컬럼 타입으로 변환한
+    /// VALUES 목록으로 바꿉니다. 그 외의 INSERT는 그대로 반환합니다.
+    async fn resolve_insert_select(&self, query: InsertQuery) -> errors::Result<InsertQuery> {
+        let InsertQuery {
+            into_table,
+            columns,
+            data,
+            with_clause,
+            on_conflict,
+            returning,
+        } = query;
+
+        let select = match data {
+            InsertData::Select(select) => select,
+            data => {
+                return Ok(InsertQuery {
+                    into_table,
+                    columns,
+                    data,
+                    with_clause,
+                    on_conflict,
+                    returning,
+                });
+            }
+        };
+
+        let table_config = self
+            .get_table_config_cached(into_table.clone().unwrap())
+            .await?;
+        let columns_map = table_config.get_columns_map();
+
+        let data_types = columns
+            .iter()
+            .map(|column_name| {
+                columns_map
+                    .get(column_name)
+                    .map(|column| column.data_type.clone())
+                    .ok_or_else(|| {
+                        ExecuteError::wrap(format!(
+                            "column '{}' does not exist on table '{}'",
+                            column_name, table_config.table.table_name
+                        ))
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let result = self.select(*select).await?;
+
+        // SELECT * 처럼 파서가 개수를 알 수 없는 경우가 있어 실행 결과로 다시 확인합니다.
+        if result.columns.len() != columns.len() {
+            return Err(ExecuteError::wrap(
+                "The number of values in insert and the number of columns do not match.",
+            ));
+        }
+
+        let values = result
+            .rows
+            .into_iter()
+            .map(|row| {
+                let list = row
+                    .fields
+                    .into_iter()
+                    .zip(data_types.iter().zip(&columns))
+                    .map(|(field, (data_type, column_name))| {
+                        // SELECT 결과의 NULL은 기본값이 아니라 NULL 그대로 입력합니다.
+                        TableDataFieldType::from(field)
+                            .assign_to(data_type)
+                            .map(|data| Some(data.into()))
+                            .ok_or_else(|| {
+                                ExecuteError::wrap(format!(
+                                    "column '{}' is of type {} but expression is of another type",
+                                    column_name,
+                                    String::from(data_type.clone())
+                                ))
+                            })
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok::<InsertValue, errors::Errors>(InsertValue { list })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(InsertQuery {
+            into_table,
+            columns,
+            data: InsertData::Values(values),
+            with_clause,
+            on_conflict,
+            returning,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -381,8 +528,11 @@ mod tests {
     use tokio::sync::Mutex;
 
     use crate::config::launch_config::LaunchConfig;
-    use crate::engine::ast::types::TableName;
+    use crate::engine::ast::dml::insert::InsertData;
+    use crate::engine::ast::types::{SQLExpression, TableName};
+    use crate::engine::ast::{DMLStatement, SQLStatement};
     use crate::engine::parser::predule::{Parser, ParserContext};
+    use crate::engine::schema::row::{TableDataFieldType, TableDataRow};
     use crate::engine::types::ExecuteResult;
     use crate::engine::wal::endec::implements::bincode::{BincodeDecoder, BincodeEncoder};
     use crate::engine::wal::manager::builder::WALBuilder;
@@ -553,4 +703,346 @@ mod tests {
             "index entry count must match live row count after rollback"
         );
     }
+
+    async fn setup_insert_select_tables(test_name: &str) -> (DBEngine, SharedWALManager) {
+        let (engine, wal) = build_test_engine(test_name).await;
+
+        for sql in [
+            "create database rrdb;",
+            "create table src (id integer, ratio float, name varchar(100));",
+            "create table users (id integer primary key, score integer, label varchar(100) not null);",
+            "insert into src (id, ratio, name) values (1, 1.4, 'a');",
+            "insert into src (id, ratio, name) values (2, 2.6, null);",
+            "insert into src (id, ratio, name) values (3, null, 'c');",
+        ] {
+            execute_sql(&engine, wal.clone(), sql)
+                .await
+                .unwrap_or_else(|error| panic!("{sql} failed: {error}"));
+        }
+
+        (engine, wal)
+    }
+
+    fn user_rows<L>(rows: Vec<(L, TableDataRow)>) -> Vec<Vec<TableDataFieldType>> {
+        let mut rows = rows
+            .into_iter()
+            .map(|(_, row)| row.fields.into_iter().map(|field| field.data).collect())
+            .collect::<Vec<Vec<_>>>();
+        rows.sort_by(|l, r| l.partial_cmp(r).unwrap());
+        rows
+    }
+
+    #[tokio::test]
+    async fn insert_select_coerces_rows_to_target_column_types() {
+        let (engine, wal) = setup_insert_select_tables("insert_select_coerce").await;
+
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "insert into users (id, score, label) select id, ratio, id from src;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(result.affected_rows, Some(3));
+
+        use TableDataFieldType::{Integer, Null, String};
+        assert_eq!(
+            user_rows(engine.full_scan(users_table()).await.unwrap()),
+            vec![
+                vec![Integer(1), Integer(1), String("1".into())],
+                vec![Integer(2), Integer(3), String("2".into())],
+                vec![Integer(3), Null, String("3".into())],
+            ]
+        );
+
+        // 결과가 없으면 아무 것도 입력하지 않습니다.
+        let result = execute_sql(
+            &engine,
+            wal,
+            "insert into users (id, label) select id, name from src where id > 100;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(result.affected_rows, Some(0));
+    }
+
+    #[tokio::test]
+    async fn insert_select_applies_not_null_type_and_unique_checks() {
+        let (engine, wal) = setup_insert_select_tables("insert_select_constraints").await;
+
+        for sql in [
+            // NULL이 NOT NULL 컬럼으로 들어감
+            "insert into users (id, label) select id, name from src;",
+            // 문자열은 정수 컬럼에 할당할 수 없음
+            "insert into users (id, label) select name, name from src where id = 1;",
+            // SELECT 결과 안에서 primary key 중복
+            "insert into users (id, label) select 7, 'x' from src;",
+            // 컬럼 개수 불일치 (* 는 실행 시점에 확인)
+            "insert into users (id) select * from src;",
+        ] {
+            assert!(
+                execute_sql(&engine, wal.clone(), sql).await.is_err(),
+                "{sql} must be rejected"
+            );
+        }
+
+        assert!(
+            engine.full_scan(users_table()).await.unwrap().is_empty(),
+            "a rejected insert must not leave a row behind"
+        );
+
+        // 기존 행과의 primary key 중복
+        execute_sql(
+            &engine,
+            wal.clone(),
+            "insert into users (id, label) values (1, 'x');",
+        )
+        .await
+        .unwrap();
+        assert!(
+            execute_sql(
+                &engine,
+                wal,
+                "insert into users (id, label) select id, 'y' from src where id = 1;",
+            )
+            .await
+            .is_err()
+        );
+    }
+
+    /// WAL에는 SELECT가 아닌 결과 값이 기록되어야, 원본 테이블이 바뀐 뒤에도
+    /// replay가 원래 INSERT된 행을 그대로 재현합니다.
+    #[tokio::test]
+    async fn insert_select_is_logged_as_resolved_values() {
+        let (engine, wal) = setup_insert_select_tables("insert_select_wal_values").await;
+
+        let mut parser = Parser::with_string(
+            "insert into users (id, label) select id, 'copied' from src where id < 3;".to_string(),
+        )
+        .unwrap();
+        let statement = parser
+            .parse(ParserContext::default().set_default_database("rrdb".to_string()))
+            .unwrap()
+            .remove(0);
+        let SQLStatement::DML(DMLStatement::InsertQuery(query)) = statement else {
+            panic!("not an insert query");
+        };
+
+        let resolved = engine.resolve_insert_select(query).await.unwrap();
+
+        let InsertData::Values(values) = &resolved.data else {
+            panic!("expected VALUES, got {:?}", resolved.data);
+        };
+        assert_eq!(
+            values
+                .iter()
+                .map(|value| value.list.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                vec![
+                    Some(SQLExpression::Integer(1)),
+                    Some(SQLExpression::String("copied".into()))
+                ],
+                vec![
+                    Some(SQLExpression::Integer(2)),
+                    Some(SQLExpression::String("copied".into()))
+                ],
+            ]
+        );
+
+        // 원본이 비어도 기록된 값으로는 같은 행이 입력됩니다.
+        execute_sql(&engine, wal, "delete from src;").await.unwrap();
+        engine.insert_replay(resolved).await.unwrap();
+        assert_eq!(engine.full_scan(users_table()).await.unwrap().len(), 2);
+    }
+
+    async fn setup_counters_table(test_name: &str) -> (DBEngine, SharedWALManager) {
+        let (engine, wal) = build_test_engine(test_name).await;
+
+        for sql in [
+            "create database rrdb;",
+            "create table counters (id integer primary key, hits integer, name varchar(100));",
+            "insert into counters (id, hits, name) values (1, 1, 'a'), (2, 1, 'b');",
+        ] {
+            execute_sql(&engine, wal.clone(), sql)
+                .await
+                .unwrap_or_else(|error| panic!("{sql} failed: {error}"));
+        }
+
+        (engine, wal)
+    }
+
+    fn counters_table() -> TableName {
+        TableName::new(Some("rrdb".to_string()), "counters".to_string())
+    }
+
+    fn counter(id: i64, hits: i64, name: &str) -> Vec<TableDataFieldType> {
+        vec![
+            TableDataFieldType::Integer(id),
+            TableDataFieldType::Integer(hits),
+            TableDataFieldType::String(name.into()),
+        ]
+    }
+
+    #[tokio::test]
+    async fn insert_on_conflict_do_nothing_skips_conflicting_rows() {
+        let (engine, wal) = setup_counters_table("on_conflict_do_nothing").await;
+
+        // 기존 행과의 충돌과 배치 안의 충돌 모두 건너뜁니다.
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "insert into counters (id, hits, name) values (1, 5, 'x'), (3, 1, 'c'), (3, 9, 'd') on conflict (id) do nothing;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(result.affected_rows, Some(1));
+
+        // 대상 컬럼이 없으면 모든 고유 인덱스에서 충돌을 확인합니다.
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "insert into counters (id, hits, name) values (2, 7, 'y') on conflict do nothing;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(result.affected_rows, Some(0));
+
+        assert_eq!(
+            user_rows(engine.full_scan(counters_table()).await.unwrap()),
+            vec![counter(1, 1, "a"), counter(2, 1, "b"), counter(3, 1, "c")]
+        );
+    }
+
+    #[tokio::test]
+    async fn insert_on_conflict_do_update_uses_excluded_row() {
+        let (engine, wal) = setup_counters_table("on_conflict_do_update").await;
+
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "insert into counters (id, hits, name) values (1, 5, 'x'), (3, 1, 'c') on conflict (id) do update set hits = hits + excluded.hits, name = excluded.name;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(result.affected_rows, Some(2));
+
+        // WHERE 조건을 만족하지 않으면 수정하지 않습니다.
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "insert into counters (id, hits, name) values (2, 5, 'y') on conflict (id) do update set hits = 0 where excluded.hits > 100;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(result.affected_rows, Some(0));
+
+        assert_eq!(
+            user_rows(engine.full_scan(counters_table()).await.unwrap()),
+            vec![counter(1, 6, "x"), counter(2, 1, "b"), counter(3, 1, "c")]
+        );
+
+        // 고유 키를 바꾸면 인덱스도 함께 바뀝니다.
+        execute_sql(
+            &engine,
+            wal.clone(),
+            "insert into counters (id, hits, name) values (3, 0, 'z') on conflict (id) do update set id = 30;",
+        )
+        .await
+        .unwrap();
+
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "insert into counters (id, hits, name) values (30, 0, 'z'), (3, 0, 'new') on conflict (id) do nothing;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(result.affected_rows, Some(1));
+
+        assert_eq!(
+            user_rows(engine.full_scan(counters_table()).await.unwrap()),
+            vec![
+                counter(1, 6, "x"),
+                counter(2, 1, "b"),
+                counter(3, 0, "new"),
+                counter(30, 1, "c"),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn insert_on_conflict_reports_invalid_usage() {
+        let (engine, wal) = setup_counters_table("on_conflict_errors").await;
+
+        for (sql, expected) in [
+            (
+                "insert into counters (id, hits, name) values (1, 1, 'a'), (1, 2, 'b') on conflict (id) do update set hits = excluded.hits;",
+                "cannot affect row a second time",
+            ),
+            (
+                "insert into counters (id, hits, name) values (1, 1, 'a') on conflict (hits) do nothing;",
+                "there is no unique index matching the ON CONFLICT specification",
+            ),
+            (
+                "insert into counters (id, hits, name) values (1, 1, 'a') on conflict (id) do update set id = 2;",
+                "duplicate key value violates unique index on column 'id'",
+            ),
+            (
+                "insert into counters (id, hits, name) values (1, 1, 'a') on conflict (id) do update set hits = 'many';",
+                "column 'hits' is of type integer but expression is of another type",
+            ),
+            (
+                "insert into counters (id, hits, name) values (1, 1, 'a') on conflict (id) do update set missing = 1;",
+                "column 'missing' does not exist on table 'counters'",
+            ),
+        ] {
+            let error = execute_sql(&engine, wal.clone(), sql).await.unwrap_err();
+            assert!(error.to_string().contains(expected), "{sql}: {error}");
+        }
+
+        // 실패한 문장은 아무것도 바꾸지 않습니다.
+        assert_eq!(
+            user_rows(engine.full_scan(counters_table()).await.unwrap()),
+            vec![counter(1, 1, "a"), counter(2, 1, "b")]
+        );
+    }
+
+    /// UPSERT는 결정된 결과를 WAL에 남기므로, 이미 반영된 상태에서 replay해도
+    /// `hits = hits + excluded.hits`가 다시 더해지지 않습니다.
+    #[tokio::test]
+    async fn insert_on_conflict_replay_is_idempotent() {
+        let (engine, wal) = setup_counters_table("on_conflict_replay").await;
+
+        execute_sql(
+            &engine,
+            wal.clone(),
+            "insert into counters (id, hits, name) values (1, 5, 'a'), (3, 1, 'c') on conflict (id) do update set hits = hits + excluded.hits;",
+        )
+        .await
+        .unwrap();
+
+        let expected = vec![counter(1, 6, "a"), counter(2, 1, "b"), counter(3, 1, "c")];
+        assert_eq!(
+            user_rows(engine.full_scan(counters_table()).await.unwrap()),
+            expected
+        );
+
+        let entries = wal.lock().await.pending_entries().to_vec();
+        engine.replay_wal(&entries).await.unwrap();
+
+        assert_eq!(
+            user_rows(engine.full_scan(counters_table()).await.unwrap()),
+            expected
+        );
+        assert_eq!(
+            engine
+                .index_manager
+                .scan_all("rrdb.counters_pkey")
+                .await
+                .unwrap()
+                .len(),
+            3
+        );
+    }
 }
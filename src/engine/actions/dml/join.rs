@@ -0,0 +1,791 @@
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+
+use futures::future::join_all;
+
+use crate::engine::DBEngine;
+use crate::engine::actions::dml::scan::RowLocation;
+use crate::engine::ast::dml::expressions::operators::BinaryOperator;
+use crate::engine::ast::dml::parts::join::JoinType;
+use crate::engine::ast::dml::plan::select::join::{JoinPlan, JoinScanType};
+use crate::engine::ast::types::{Column, SQLExpression, SelectColumn, TableName};
+use crate::engine::expression::ReduceContext;
+use crate::engine::optimizer::predule::collect_conjuncts;
+use crate::engine::schema::row::{TableDataField, TableDataFieldType, TableDataRow};
+use crate::engine::schema::table::TableSchema;
+use crate::errors;
+use crate::errors::type_error::TypeError;
+
+/// 조인 실행 시 필요한 양쪽 입력의 컬럼 구성
+///
+/// 좌측은 지금까지 누적된 행(FROM 테이블 + 앞선 조인 테이블)이고,
+/// 우측은 이번 조인 대상 테이블입니다. 외부 조인의 NULL 채움과
+/// ON 절 컬럼이 어느 쪽을 가리키는지 판별하는 데 사용합니다.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct JoinContext {
+    pub table_alias_map: HashMap<String, TableName>,
+    pub left_columns: Vec<(TableName, Column)>,
+    pub right_columns: Vec<(TableName, Column)>,
+    /// 상관 서브쿼리 안의 조인이라면 바깥 쿼리의 컨텍스트
+    pub outer: Option<Box<ReduceContext>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum JoinSide {
+    Left,
+    Right,
+}
+
+/// ON 절을 등가 조인 키와 나머지 조건으로 분해한 결과
+///
+/// keys는 (좌측 표현식, 우측 표현식) 쌍이며, 해시/머지 조인은 keys로
+/// 후보를 찾고 residual로 최종 판정합니다.
+#[derive(Debug, Default)]
+struct JoinCondition {
+    keys: Vec<(SQLExpression, SQLExpression)>,
+    residual: Vec<SQLExpression>,
+}
+
+impl DBEngine {
+    /// 조인 계획에 따라 좌측 누적 행과 우측 테이블 행을 결합합니다.
+    ///
+    /// 실제 매칭은 match_join_pairs가 (좌측 인덱스, 우측 인덱스) 쌍으로
+    /// 돌려주고, 외부 조인의 NULL 채움은 여기서 한 번에 처리합니다.
+    pub(crate) async fn join_rows(
+        &self,
+        left_rows: Vec<TableDataRow>,
+        right_rows: Vec<TableDataRow>,
+        join: &JoinPlan,
+        context: &JoinContext,
+    ) -> errors::Result<Vec<TableDataRow>> {
+        let pairs = self
+            .match_join_pairs(&left_rows, &right_rows, join, context)
+            .await?;
+
+        Ok(assemble_joined_rows(
+            left_rows,
+            right_rows,
+            pairs,
+            &join.join_type,
+            context,
+        ))
+    }
+
+    /// ON 절을 만족하는 (좌측 인덱스, 우측 인덱스) 쌍을 정렬해 돌려줍니다.
+    ///
+    /// join_scan_type에 맞는 연산자를 고르며, 조인 종류(외부/세미 조인)는 보지 않습니다.
+    pub(crate) async fn match_join_pairs(
+        &self,
+        left_rows: &[TableDataRow],
+        right_rows: &[TableDataRow],
+        join: &JoinPlan,
+        context: &JoinContext,
+    ) -> errors::Result<Vec<(usize, usize)>> {
+        let condition = split_join_condition(join, context);
+
+        let mut pairs = match join.join_scan_type {
+            JoinScanType::Hash if !condition.keys.is_empty() => {
+                self.hash_join(left_rows, right_rows, &condition, context)
+                    .await?
+            }
+            JoinScanType::Merge if !condition.keys.is_empty() => {
+                self.merge_join(left_rows, right_rows, &condition, context)
+                    .await?
+            }
+            // 등가 조건이 없으면 해시/머지 조인을 쓸 수 없으므로 중첩 루프로 대체합니다.
+            _ => {
+                self.nested_loop_join(left_rows, right_rows, join.filter.as_ref(), context)
+                    .await?
+            }
+        };
+
+        pairs.sort_unstable();
+
+        Ok(pairs)
+    }
+
+    /// UPDATE ... FROM / DELETE ... USING: 대상 행 위치를 유지한 채 FROM 테이블을 결합합니다.
+    ///
+    /// 결합한 행은 대상 테이블 컬럼이 앞에 오며, 결과는 대상 행 순서를 따릅니다.
+    /// 우측 테이블의 alias와 스키마는 이후 단계에서 쓸 수 있도록 함께 등록합니다.
+    pub(crate) async fn join_target_rows(
+        &self,
+        rows: Vec<(RowLocation, TableDataRow)>,
+        join: &JoinPlan,
+        table_alias_map: &mut HashMap<String, TableName>,
+        table_infos: &mut Vec<TableSchema>,
+    ) -> errors::Result<Vec<(RowLocation, TableDataRow)>> {
+        let right_config = self.get_table_config_cached(join.right.clone()).await?;
+
+        if let Some(alias) = &join.right_alias {
+            table_alias_map.insert(alias.clone(), join.right.clone());
+        }
+
+        let context = JoinContext {
+            table_alias_map: table_alias_map.clone(),
+            left_columns: schema_columns(table_infos),
+            right_columns: schema_columns(std::slice::from_ref(&right_config)),
+            outer: None,
+        };
+
+        table_infos.push(right_config);
+
+        let right_rows = self
+            .full_scan(join.right.clone())
+            .await?
+            .into_iter()
+            .map(|(_, row)| row)
+            .collect::<Vec<_>>();
+
+        let (locations, left_rows): (Vec<_>, Vec<_>) = rows.into_iter().unzip();
+
+        let pairs = self
+            .match_join_pairs(&left_rows, &right_rows, join, &context)
+            .await?;
+
+        Ok(pairs
+            .into_iter()
+            .map(|(left_index, right_index)| {
+                (
+                    locations[left_index].clone(),
+                    concat_rows(&left_rows[left_index], &right_rows[right_index]),
+                )
+            })
+            .collect())
+    }
+
+    /// 모든 (좌, 우) 조합에 ON 절을 평가합니다. ON 절이 없으면 교차 곱입니다.
+    async fn nested_loop_join(
+        &self,
+        left_rows: &[TableDataRow],
+        right_rows: &[TableDataRow],
+        on: Option<&SQLExpression>,
+        context: &JoinContext,
+    ) -> errors::Result<Vec<(usize, usize)>> {
+        let conditions = on.cloned().into_iter().collect::<Vec<_>>();
+        let mut pairs = vec![];
+
+        for (left_index, left) in left_rows.iter().enumerate() {
+            let futures = right_rows.iter().enumerate().map(|(right_index, right)| {
+                let conditions = &conditions;
+                async move {
+                    let matched = self
+                        .join_condition_matched(left, right, conditions, context)
+                        .await?;
+                    Ok::<_, errors::Errors>((right_index, matched))
+                }
+            });
+
+            for result in join_all(futures).await {
+                let (right_index, matched) = result?;
+                if matched {
+                    pairs.push((left_index, right_index));
+                }
+            }
+        }
+
+        Ok(pairs)
+    }
+
+    /// 우측 행으로 키 해시 테이블을 만들고 좌측 행으로 탐색합니다.
+    async fn hash_join(
+        &self,
+        left_rows: &[TableDataRow],
+        right_rows: &[TableDataRow],
+        condition: &JoinCondition,
+        context: &JoinContext,
+    ) -> errors::Result<Vec<(usize, usize)>> {
+        let left_keys = self
+            .evaluate_join_keys(left_rows, &condition.keys, JoinSide::Left, context)
+            .await?;
+        let right_keys = self
+            .evaluate_join_keys(right_rows, &condition.keys, JoinSide::Right, context)
+            .await?;
+
+        let mut hash_table = HashMap::<Vec<TableDataFieldType>, Vec<usize>>::new();
+        for (right_index, key) in right_keys.into_iter().enumerate() {
+            if let Some(key) = key {
+                hash_table.entry(key).or_default().push(right_index);
+            }
+        }
+
+        let mut pairs = vec![];
+
+        for (left_index, key) in left_keys.iter().enumerate() {
+            let Some(candidates) = key.as_ref().and_then(|key| hash_table.get(key)) else {
+                continue;
+            };
+
+            for right_index in candidates {
+                if self
+                    .join_condition_matched(
+                        &left_rows[left_index],
+                        &right_rows[*right_index],
+                        &condition.residual,
+                        context,
+                    )
+                    .await?
+                {
+                    pairs.push((left_index, *right_index));
+                }
+            }
+        }
+
+        Ok(pairs)
+    }
+
+    /// 양쪽을 조인 키로 정렬한 뒤 같은 키 구간끼리 결합합니다.
+    async fn merge_join(
+        &self,
+        left_rows: &[TableDataRow],
+        right_rows: &[TableDataRow],
+        condition: &JoinCondition,
+        context: &JoinContext,
+    ) -> errors::Result<Vec<(usize, usize)>> {
+        let sorted_keys = |keys: Vec<Option<Vec<TableDataFieldType>>>| {
+            let mut keys = keys
+                .into_iter()
+                .enumerate()
+                .filter_map(|(index, key)| key.map(|key| (key, index)))
+                .collect::<Vec<_>>();
+            keys.sort_by(|(l, _), (r, _)| l.partial_cmp(r).unwrap_or(Ordering::Equal));
+            keys
+        };
+
+        let left_keys = sorted_keys(
+            self.evaluate_join_keys(left_rows, &condition.keys, JoinSide::Left, context)
+                .await?,
+        );
+        let right_keys = sorted_keys(
+            self.evaluate_join_keys(right_rows, &condition.keys, JoinSide::Right, context)
+                .await?,
+        );
+
+        let mut pairs = vec![];
+        let (mut i, mut j) = (0, 0);
+
+        while i < left_keys.len() && j < right_keys.len() {
+            match left_keys[i]
+                .0
+                .partial_cmp(&right_keys[j].0)
+                .unwrap_or(Ordering::Equal)
+            {
+                Ordering::Less => i += 1,
+                Ordering::Greater => j += 1,
+                Ordering::Equal => {
+                    let key = &left_keys[i].0;
+                    let left_end = (i..left_keys.len())
+                        .find(|&k| &left_keys[k].0 != key)
+                        .unwrap_or(left_keys.len());
+                    let right_end = (j..right_keys.len())
+                        .find(|&k| &right_keys[k].0 != key)
+                        .unwrap_or(right_keys.len());
+
+                    for (_, left_index) in &left_keys[i..left_end] {
+                        for (_, right_index) in &right_keys[j..right_end] {
+                            if self
+                                .join_condition_matched(
+                                    &left_rows[*left_index],
+                                    &right_rows[*right_index],
+                                    &condition.residual,
+                                    context,
+                                )
+                                .await?
+                            {
+                                pairs.push((*left_index, *right_index));
+                            }
+                        }
+                    }
+
+                    i = left_end;
+                    j = right_end;
+                }
+            }
+        }
+
+        Ok(pairs)
+    }
+
+    /// 한쪽 입력의 각 행에 대해 조인 키를 계산합니다. NULL이 섞인 키는 어떤 행과도
+    /// 같지 않으므로 None으로 돌려줍니다.
+    async fn evaluate_join_keys(
+        &self,
+        rows: &[TableDataRow],
+        keys: &[(SQLExpression, SQLExpression)],
+        side: JoinSide,
+        context: &JoinContext,
+    ) -> errors::Result<Vec<Option<Vec<TableDataFieldType>>>> {
+        let futures = rows.iter().map(|row| async move {
+            let reduce_context = ReduceContext {
+                row: Some(row.to_owned()),
+                table_alias_map: context.table_alias_map.clone(),
+                config_columns: vec![],
+                outer: context.outer.clone(),
+            };
+
+            let mut values = vec![];
+            for (left, right) in keys {
+                let expression = match side {
+                    JoinSide::Left => left,
+                    JoinSide::Right => right,
+                };
+
+                let value = self
+                    .reduce_expression(expression.clone(), reduce_context.clone())
+                    .await?;
+
+                if value.is_null() {
+                    return Ok(None);
+                }
+
+                values.push(value);
+            }
+
+            Ok::<_, errors::Errors>(Some(values))
+        });
+
+        join_all(futures).await.into_iter().collect()
+    }
+
+    /// 결합한 행에 대해 조건들이 모두 참인지 확인합니다. NULL은 거짓으로 봅니다.
+    async fn join_condition_matched(
+        &self,
+        left: &TableDataRow,
+        right: &TableDataRow,
+        conditions: &[SQLExpression],
+        context: &JoinContext,
+    ) -> errors::Result<bool> {
+        if conditions.is_empty() {
+            return Ok(true);
+        }
+
+        let reduce_context = ReduceContext {
+            row: Some(concat_rows(left, right)),
+            table_alias_map: context.table_alias_map.clone(),
+            config_columns: vec![],
+            outer: context.outer.clone(),
+        };
+
+        for condition in conditions {
+            match self
+                .reduce_expression(condition.clone(), reduce_context.clone())
+                .await?
+            {
+                TableDataFieldType::Boolean(true) => continue,
+                TableDataFieldType::Boolean(false) | TableDataFieldType::Null => return Ok(false),
+                _ => {
+                    return Err(TypeError::wrap(
+                        "join condition is valid only for boolean and null types",
+                    ));
+                }
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+pub(crate) fn concat_rows(left: &TableDataRow, right: &TableDataRow) -> TableDataRow {
+    TableDataRow {
+        fields: left
+            .fields
+            .iter()
+            .chain(right.fields.iter())
+            .cloned()
+            .collect(),
+    }
+}
+
+fn schema_columns(table_infos: &[TableSchema]) -> Vec<(TableName, Column)> {
+    table_infos
+        .iter()
+        .flat_map(|table_info| {
+            table_info
+                .columns
+                .iter()
+                .map(|column| (table_info.table.clone(), column.clone()))
+        })
+        .collect()
+}
+
+fn null_row(columns: &[(TableName, Column)]) -> TableDataRow {
+    TableDataRow {
+        fields: columns
+            .iter()
+            .map(|(table_name, column)| TableDataField {
+                table_name: table_name.clone(),
+                column_name: column.name.clone(),
+                data: TableDataFieldType::Null,
+            })
+            .collect(),
+    }
+}
+
+/// 매칭된 쌍을 행으로 만들고, 외부 조인이면 짝이 없는 행을 NULL로 채워 붙입니다.
+///
+/// pairs는 정렬되어 있어야 하며, 결과는 좌측 입력 순서를 따릅니다.
+fn assemble_joined_rows(
+    left_rows: Vec<TableDataRow>,
+    right_rows: Vec<TableDataRow>,
+    pairs: Vec<(usize, usize)>,
+    join_type: &JoinType,
+    context: &JoinContext,
+) -> Vec<TableDataRow> {
+    // 세미/안티 조인은 좌측 행만 남기며, 짝의 유무만 봅니다.
+    if matches!(join_type, JoinType::LeftSemiJoin | JoinType::LeftAntiJoin) {
+        let keep_matched = *join_type == JoinType::LeftSemiJoin;
+        let matched_left = pairs
+            .into_iter()
+            .map(|(left_index, _)| left_index)
+            .collect::<HashSet<_>>();
+
+        return left_rows
+            .into_iter()
+            .enumerate()
+            .filter(|(left_index, _)| matched_left.contains(left_index) == keep_matched)
+            .map(|(_, left)| left)
+            .collect();
+    }
+
+    let keep_left = matches!(join_type, JoinType::LeftOuterJoin | JoinType::FullOuterJoin);
+    let keep_right = matches!(
+        join_type,
+        JoinType::RightOuterJoin | JoinType::FullOuterJoin
+    );
+
+    let mut matched_right = HashSet::new();
+    let mut rows = vec![];
+    let mut pairs = pairs.into_iter().peekable();
+
+    for (left_index, left) in left_rows.iter().enumerate() {
+        let mut matched = false;
+
+        while let Some((_, right_index)) = pairs.next_if(|(l, _)| *l == left_index) {
+            matched = true;
+            matched_right.insert(right_index);
+            rows.push(concat_rows(left, &right_rows[right_index]));
+        }
+
+        if !matched && keep_left {
+            rows.push(concat_rows(left, &null_row(&context.right_columns)));
+        }
+    }
+
+    if keep_right {
+        let left_nulls = null_row(&context.left_columns);
+
+        for (right_index, right) in right_rows.iter().enumerate() {
+            if !matched_right.contains(&right_index) {
+                rows.push(concat_rows(&left_nulls, right));
+            }
+        }
+    }
+
+    rows
+}
+
+/// ON 절을 AND 단위로 나눠, 양쪽 컬럼을 잇는 등가 조건은 조인 키로,
+/// 나머지는 잔여 조건으로 분류합니다.
+fn split_join_condition(join: &JoinPlan, context: &JoinContext) -> JoinCondition {
+    let mut condition = JoinCondition::default();
+
+    let Some(on) = &join.filter else {
+        return condition;
+    };
+
+    let mut conjuncts = vec![];
+    collect_conjuncts(on, &mut conjuncts);
+
+    for conjunct in conjuncts {
+        if let SQLExpression::Binary(binary) = conjunct
+            && binary.operator == BinaryOperator::Eq
+            && let (SQLExpression::SelectColumn(lhs), SQLExpression::SelectColumn(rhs)) =
+                (&binary.lhs, &binary.rhs)
+        {
+            match (
+                column_side(lhs, join, context),
+                column_side(rhs, join, context),
+            ) {
+                (Some(JoinSide::Left), Some(JoinSide::Right)) => {
+                    condition
+                        .keys
+                        .push((binary.lhs.clone(), binary.rhs.clone()));
+                    continue;
+                }
+                (Some(JoinSide::Right), Some(JoinSide::Left)) => {
+                    condition
+                        .keys
+                        .push((binary.rhs.clone(), binary.lhs.clone()));
+                    continue;
+                }
+                _ => {}
+            }
+        }
+
+        condition.residual.push(conjunct.clone());
+    }
+
+    condition
+}
+
+/// 컬럼 참조가 조인의 좌측/우측 중 어디에 속하는지 판별합니다.
+/// 판별할 수 없거나 모호하면 None입니다.
+fn column_side(column: &SelectColumn, join: &JoinPlan, context: &JoinContext) -> Option<JoinSide> {
+    let in_columns = |columns: &[(TableName, Column)], table: Option<&str>| {
+        columns.iter().any(|(table_name, e)| {
+            e.name == column.column_name
+                && table
+                    .map(|table| table == table_name.table_name)
+                    .unwrap_or(true)
+        })
+    };
+
+    match &column.table_name {
+        Some(qualifier) => {
+            let is_right = match &join.right_alias {
+                Some(alias) => alias == qualifier,
+                None => qualifier == &join.right.table_name,
+            };
+
+            if is_right {
+                return in_columns(&context.right_columns, None).then_some(JoinSide::Right);
+            }
+
+            let table = context
+                .table_alias_map
+                .get(qualifier)
+                .map(|table_name| table_name.table_name.as_str())
+                .unwrap_or(qualifier.as_str());
+
+            in_columns(&context.left_columns, Some(table)).then_some(JoinSide::Left)
+        }
+        None => match (
+            in_columns(&context.left_columns, None),
+            in_columns(&context.right_columns, None),
+        ) {
+            (true, false) => Some(JoinSide::Left),
+            (false, true) => Some(JoinSide::Right),
+            _ => None,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::launch_config::LaunchConfig;
+    use crate::engine::ast::dml::expressions::binary::BinaryOperatorExpression;
+    use crate::engine::ast::types::DataType;
+
+    fn table(name: &str) -> TableName {
+        TableName::new(Some("rrdb".into()), name.into())
+    }
+
+    fn column(name: &str) -> Column {
+        Column::builder()
+            .set_name(name.into())
+            .set_data_type(DataType::Int)
+            .build()
+    }
+
+    fn row(table_name: &str, values: &[(&str, TableDataFieldType)]) -> TableDataRow {
+        TableDataRow {
+            fields: values
+                .iter()
+                .map(|(column_name, data)| TableDataField {
+                    table_name: table(table_name),
+                    column_name: column_name.to_string(),
+                    data: data.clone(),
+                })
+                .collect(),
+        }
+    }
+
+    fn join_plan(join_type: JoinType, join_scan_type: JoinScanType) -> JoinPlan {
+        JoinPlan {
+            left: table("users"),
+            right: table("orders"),
+            right_alias: Some("o".into()),
+            join_type,
+            join_scan_type,
+            select_columns: vec![],
+            index: None,
+            filter: Some(
+                BinaryOperatorExpression {
+                    operator: BinaryOperator::Eq,
+                    lhs: SelectColumn::new(Some("u".into()), "id".into()).into(),
+                    rhs: SelectColumn::new(Some("o".into()), "user_id".into()).into(),
+                }
+                .into(),
+            ),
+        }
+    }
+
+    fn context() -> JoinContext {
+        JoinContext {
+            table_alias_map: HashMap::from([
+                ("u".to_string(), table("users")),
+                ("o".to_string(), table("orders")),
+            ]),
+            left_columns: vec![(table("users"), column("id"))],
+            right_columns: vec![(table("orders"), column("user_id"))],
+            outer: None,
+        }
+    }
+
+    fn users() -> Vec<TableDataRow> {
+        [1, 2, 3]
+            .into_iter()
+            .map(|id| row("users", &[("id", TableDataFieldType::Integer(id))]))
+            .collect()
+    }
+
+    fn orders(user_ids: &[TableDataFieldType]) -> Vec<TableDataRow> {
+        user_ids
+            .iter()
+            .cloned()
+            .map(|user_id| row("orders", &[("user_id", user_id)]))
+            .collect()
+    }
+
+    fn values(rows: &[TableDataRow]) -> Vec<Vec<TableDataFieldType>> {
+        let mut values = rows
+            .iter()
+            .map(|row| row.fields.iter().map(|e| e.data.clone()).collect())
+            .collect::<Vec<Vec<_>>>();
+        values.sort_by(|l, r| l.partial_cmp(r).unwrap());
+        values
+    }
+
+    #[test]
+    fn split_join_condition_orients_equi_keys_left_to_right() {
+        let mut join = join_plan(JoinType::InnerJoin, JoinScanType::Hash);
+        join.filter = Some(
+            BinaryOperatorExpression {
+                operator: BinaryOperator::Eq,
+                lhs: SelectColumn::new(None, "user_id".into()).into(),
+                rhs: SelectColumn::new(Some("u".into()), "id".into()).into(),
+            }
+            .into(),
+        );
+
+        let condition = split_join_condition(&join, &context());
+
+        assert_eq!(
+            condition.keys,
+            vec![(
+                SelectColumn::new(Some("u".into()), "id".into()).into(),
+                SelectColumn::new(None, "user_id".into()).into(),
+            )]
+        );
+        assert!(condition.residual.is_empty());
+    }
+
+    /// 세 연산자는 방식만 다를 뿐 같은 결과를 내야 합니다.
+    #[tokio::test]
+    async fn join_operators_agree_for_every_join_type() {
+        let engine = DBEngine::new(LaunchConfig::default_for_base_path("target/test_join_rows"));
+
+        for join_type in [
+            JoinType::InnerJoin,
+            JoinType::LeftOuterJoin,
+            JoinType::RightOuterJoin,
+            JoinType::FullOuterJoin,
+            JoinType::LeftSemiJoin,
+            JoinType::LeftAntiJoin,
+        ] {
+            let mut results = vec![];
+
+            for scan_type in [
+                JoinScanType::NestedLoop,
+                JoinScanType::Hash,
+                JoinScanType::Merge,
+            ] {
+                let rows = engine
+                    .join_rows(
+                        users(),
+                        orders(&[
+                            TableDataFieldType::Integer(1),
+                            TableDataFieldType::Integer(1),
+                            TableDataFieldType::Integer(4),
+                        ]),
+                        &join_plan(join_type.clone(), scan_type),
+                        &context(),
+                    )
+                    .await
+                    .unwrap();
+                results.push(values(&rows));
+            }
+
+            assert_eq!(results[0], results[1], "{join_type:?}: hash differs");
+            assert_eq!(results[0], results[2], "{join_type:?}: merge differs");
+        }
+    }
+
+    /// NULL 키는 어떤 행과도 매칭되지 않고, 외부 조인에서는 짝 없는 행으로 남습니다.
+    #[tokio::test]
+    async fn full_outer_join_pads_both_sides_with_null() {
+        let engine = DBEngine::new(LaunchConfig::default_for_base_path("target/test_join_rows"));
+
+        let rows = engine
+            .join_rows(
+                users(),
+                orders(&[
+                    TableDataFieldType::Integer(1),
+                    TableDataFieldType::Integer(1),
+                    TableDataFieldType::Integer(4),
+                    TableDataFieldType::Null,
+                ]),
+                &join_plan(JoinType::FullOuterJoin, JoinScanType::Hash),
+                &context(),
+            )
+            .await
+            .unwrap();
+
+        use TableDataFieldType::{Integer, Null};
+        assert_eq!(
+            values(&rows),
+            vec![
+                vec![Integer(1), Integer(1)],
+                vec![Integer(1), Integer(1)],
+                vec![Integer(2), Null],
+                vec![Integer(3), Null],
+                vec![Null, Integer(4)],
+                vec![Null, Null],
+            ]
+        );
+    }
+
+    /// 세미/안티 조인은 좌측 행을 중복 없이 한 번만 남깁니다.
+    #[tokio::test]
+    async fn semi_and_anti_join_keep_only_left_rows() {
+        let engine = DBEngine::new(LaunchConfig::default_for_base_path("target/test_join_rows"));
+        let right = orders(&[
+            TableDataFieldType::Integer(1),
+            TableDataFieldType::Integer(1),
+            TableDataFieldType::Integer(3),
+            TableDataFieldType::Null,
+        ]);
+
+        use TableDataFieldType::Integer;
+        for (join_type, expected) in [
+            (
+                JoinType::LeftSemiJoin,
+                vec![vec![Integer(1)], vec![Integer(3)]],
+            ),
+            (JoinType::LeftAntiJoin, vec![vec![Integer(2)]]),
+        ] {
+            let rows = engine
+                .join_rows(
+                    users(),
+                    right.clone(),
+                    &join_plan(join_type.clone(), JoinScanType::Hash),
+                    &context(),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(values(&rows), expected, "{join_type:?}");
+        }
+    }
+}
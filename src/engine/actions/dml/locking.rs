@@ -0,0 +1,574 @@
+//! 행 락 연산자 (SELECT ... FOR UPDATE / FOR NO KEY UPDATE / FOR SHARE)
+//!
+//! FROM 스캔이 행마다 row index를 가상 필드로 덧붙여 두면, 필터링과 정렬을 거친 순서대로
+//! 행을 잠급니다. 락을 기다리는 동안 다른 트랜잭션이 행을 바꾸고 커밋했다면 READ COMMITTED는
+//! 새 버전으로 WHERE 조건을 다시 확인하고, REPEATABLE READ는 직렬화 오류로 실패합니다.
+//! (PostgreSQL과 동일)
+//!
+//! 행 락만 잡는 동안은 서로 막지 않지만, 잠근 행을 고치려면 그 테이블의 쓰기 락
+//! (`LockManager::lock_table_for_write`)을 쥐어야 합니다. 그래서 SKIP LOCKED로 같은 테이블의
+//! 서로 다른 행을 가져간 작업자들도 첫 UPDATE/DELETE부터 COMMIT까지는 한 번에 하나씩 진행됩니다.
+
+use std::collections::HashMap;
+
+use crate::engine::DBEngine;
+use crate::engine::actions::dml::scan::RowLocation;
+use crate::engine::ast::dml::parts::locking::{LockWaitPolicy, RowLockMode};
+use crate::engine::ast::dml::plan::select::lock_rows::LockRowsPlan;
+use crate::engine::ast::types::{SQLExpression, TableName};
+use crate::engine::expression::ReduceContext;
+use crate::engine::schema::row::{TableDataField, TableDataFieldType, TableDataRow};
+use crate::engine::transaction::StatementContext;
+use crate::errors;
+use crate::errors::execute_error::ExecuteError;
+use crate::errors::type_error::TypeError;
+
+/// 행 위치 필드가 속하는 가상 테이블 이름
+const ROW_LOCATION_TABLE_NAME: &str = "?row?";
+const ROW_LOCATION_COLUMN_NAME: &str = "row_index";
+
+/// 잠글 행을 찾을 수 있도록 스캔한 행에 row index를 덧붙입니다.
+pub(crate) fn with_row_location(mut row: TableDataRow, location: &RowLocation) -> TableDataRow {
+    row.fields.push(TableDataField {
+        table_name: TableName::new(None, ROW_LOCATION_TABLE_NAME.into()),
+        column_name: ROW_LOCATION_COLUMN_NAME.into(),
+        data: TableDataFieldType::Integer(location.row_index as i64),
+    });
+    row
+}
+
+fn find_row_location(row: &TableDataRow) -> errors::Result<RowLocation> {
+    row.fields
+        .iter()
+        .rev()
+        .find(|field| field.table_name.table_name == ROW_LOCATION_TABLE_NAME)
+        .and_then(|field| match field.data {
+            TableDataFieldType::Integer(row_index) => Some(RowLocation {
+                row_index: row_index as usize,
+            }),
+            _ => None,
+        })
+        .ok_or_else(|| ExecuteError::wrap("row location is missing for FOR UPDATE".to_string()))
+}
+
+impl DBEngine {
+    /// 행을 순서대로 잠그고, 잠근 행만 반환합니다. SKIP LOCKED로 건너뛴 행과 다시 확인해
+    /// 조건에 맞지 않게 된 행은 빠지며, `plan.limit`개를 잠그면 멈춥니다.
+    pub(crate) async fn lock_rows(
+        &self,
+        rows: Vec<TableDataRow>,
+        plan: &LockRowsPlan,
+        table_alias_map: &HashMap<String, TableName>,
+        outer: Option<Box<ReduceContext>>,
+    ) -> errors::Result<Vec<TableDataRow>> {
+        let transaction_id = StatementContext::current().and_then(|context| context.transaction_id);
+        let lock_manager = self.transaction_manager.lock_manager();
+
+        let mut recheck: Option<SQLExpression> = None;
+        let mut locked_rows = vec![];
+
+        for row in rows {
+            if plan.limit.is_some_and(|limit| locked_rows.len() >= limit) {
+                break;
+            }
+
+            let location = find_row_location(&row)?;
+
+            let locked = lock_manager
+                .lock_row(
+                    transaction_id,
+                    &plan.table_name,
+                    &location,
+                    plan.locking.mode,
+                    plan.locking.wait_policy,
+                )
+                .await?;
+            if !locked {
+                continue;
+            }
+
+            let latest = match self
+                .refetch_locked_row(&plan.table_name, location.row_index)
+                .await?
+            {
+                None => {
+                    locked_rows.push(row);
+                    continue;
+                }
+                // 기다리는 동안 지워진 행
+                Some(None) => continue,
+                Some(Some(latest)) => with_row_location(latest, &location),
+            };
+
+            let Some(expression) = &plan.recheck else {
+                locked_rows.push(latest);
+                continue;
+            };
+
+            if recheck.is_none() {
+                recheck = Some(self.materialize_subqueries(expression.clone()).await?);
+            }
+
+            let reduce_context = ReduceContext {
+                row: Some(latest.clone()),
+                table_alias_map: table_alias_map.clone(),
+                config_columns: vec![],
+                outer: outer.clone(),
+            };
+
+            match self
+                .reduce_expression(recheck.clone().unwrap(), reduce_context)
+                .await?
+            {
+                TableDataFieldType::Boolean(true) => locked_rows.push(latest),
+                TableDataFieldType::Boolean(false) | TableDataFieldType::Null => {}
+                _ => {
+                    return Err(TypeError::wrap(
+                        "condition expression is valid only for boolean and null types",
+                    ));
+                }
+            }
+        }
+
+        Ok(locked_rows)
+    }
+
+    /// UPDATE/DELETE 대상 행을 잠급니다. FOR UPDATE / FOR SHARE로 잠근 트랜잭션이 끝날
+    /// 때까지 기다립니다. 행을 바꾸는 트랜잭션은 쓰기 락을 쥔 하나뿐이므로, 기다린 뒤에
+    /// 행을 다시 읽을 필요는 없습니다.
+    pub(crate) async fn lock_target_rows(
+        &self,
+        table_name: &TableName,
+        row_indexes: impl IntoIterator<Item = usize>,
+        mode: RowLockMode,
+    ) -> errors::Result<()> {
+        // WAL replay처럼 명령 밖에서 쓰는 경우에는 다른 트랜잭션이 없습니다.
+        let Some(transaction_id) =
+            StatementContext::current().and_then(|context| context.transaction_id)
+        else {
+            return Ok(());
+        };
+
+        // 항상 같은 순서로 잠가야 두 명령이 서로의 행을 기다리는 일이 줄어듭니다.
+        let mut row_indexes = row_indexes.into_iter().collect::<Vec<_>>();
+        row_indexes.sort_unstable();
+        row_indexes.dedup();
+
+        let lock_manager = self.transaction_manager.lock_manager();
+        for row_index in row_indexes {
+            lock_manager
+                .lock_row(
+                    Some(transaction_id),
+                    table_name,
+                    &RowLocation { row_index },
+                    mode,
+                    LockWaitPolicy::Wait,
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::engine::actions::test_support::{execute_sql, names, setup_users_table, user_names};
+    use crate::engine::types::ExecuteField;
+    use crate::engine::{DBEngine, SharedWALManager};
+
+    async fn locked_ids(
+        engine: &DBEngine,
+        wal: SharedWALManager,
+        connection_id: &str,
+        sql: &str,
+    ) -> crate::errors::Result<Vec<ExecuteField>> {
+        Ok(execute_sql(engine, wal, connection_id, sql)
+            .await?
+            .rows
+            .into_iter()
+            .map(|row| row.fields[0].clone())
+            .collect())
+    }
+
+    #[tokio::test]
+    async fn skip_locked_hands_each_worker_a_different_row() {
+        let (engine, wal) = setup_users_table("target/test_row_locking/skip_locked").await;
+        execute_sql(
+            &engine,
+            wal.clone(),
+            "a",
+            "insert into users (id, name) values (3, 'c');",
+        )
+        .await
+        .unwrap();
+
+        let claim = "select id from users order by id limit 1 for update skip locked;";
+        for (connection_id, expected) in [("w1", 1), ("w2", 2)] {
+            execute_sql(&engine, wal.clone(), connection_id, "begin;")
+                .await
+                .unwrap();
+            assert_eq!(
+                locked_ids(&engine, wal.clone(), connection_id, claim)
+                    .await
+                    .unwrap(),
+                vec![ExecuteField::Integer(expected)]
+            );
+        }
+
+        let error = locked_ids(
+            &engine,
+            wal.clone(),
+            "w3",
+            "select id from users for share nowait;",
+        )
+        .await
+        .unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("could not obtain lock on row in relation \"users\""),
+            "{error}"
+        );
+        assert_eq!(
+            locked_ids(
+                &engine,
+                wal.clone(),
+                "w3",
+                "select id from users where id = 3 for update nowait;",
+            )
+            .await
+            .unwrap(),
+            vec![ExecuteField::Integer(3)]
+        );
+
+        // 커밋하면 락이 풀립니다.
+        execute_sql(&engine, wal.clone(), "w1", "commit;")
+            .await
+            .unwrap();
+        assert_eq!(
+            locked_ids(&engine, wal.clone(), "w3", claim).await.unwrap(),
+            vec![ExecuteField::Integer(1)]
+        );
+        assert_eq!(
+            locked_ids(
+                &engine,
+                wal.clone(),
+                "w3",
+                "select id from users order by id offset 1 for update skip locked;",
+            )
+            .await
+            .unwrap(),
+            vec![ExecuteField::Integer(3)]
+        );
+    }
+
+    #[tokio::test]
+    async fn update_waits_for_rows_locked_by_for_update() {
+        let (engine, wal) =
+            setup_users_table("target/test_row_locking/for_update_blocks_writer").await;
+
+        for sql in ["begin;", "select id from users where id = 1 for share;"] {
+            execute_sql(&engine, wal.clone(), "reader", sql)
+                .await
+                .unwrap();
+        }
+
+        let update = execute_sql(
+            &engine,
+            wal.clone(),
+            "writer",
+            "update users set name = 'z' where id = 1;",
+        );
+        tokio::pin!(update);
+        assert!(
+            tokio::time::timeout(Duration::from_millis(100), &mut update)
+                .await
+                .is_err()
+        );
+
+        execute_sql(&engine, wal.clone(), "reader", "commit;")
+            .await
+            .unwrap();
+        tokio::time::timeout(Duration::from_secs(5), update)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            user_names(&engine, wal.clone(), "reader").await,
+            names(&["z", "b"])
+        );
+    }
+
+    #[tokio::test]
+    async fn for_update_rechecks_rows_changed_while_waiting() {
+        let (engine, wal) = setup_users_table("target/test_row_locking/for_update_recheck").await;
+
+        for sql in ["begin;", "update users set name = 'z' where id = 1;"] {
+            execute_sql(&engine, wal.clone(), "writer", sql)
+                .await
+                .unwrap();
+        }
+        execute_sql(&engine, wal.clone(), "rc", "begin;")
+            .await
+            .unwrap();
+
+        let select = locked_ids(
+            &engine,
+            wal.clone(),
+            "rc",
+            "select id from users where name = 'a' for update;",
+        );
+        tokio::pin!(select);
+        assert!(
+            tokio::time::timeout(Duration::from_millis(100), &mut select)
+                .await
+                .is_err()
+        );
+
+        execute_sql(&engine, wal.clone(), "writer", "commit;")
+            .await
+            .unwrap();
+
+        // READ COMMITTED는 커밋된 새 버전으로 조건을 다시 확인합니다.
+        let rows = tokio::time::timeout(Duration::from_secs(5), select)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(rows.is_empty());
+        assert_eq!(
+            locked_ids(
+                &engine,
+                wal.clone(),
+                "rc",
+                "select name from users where name = 'z' for update;",
+            )
+            .await
+            .unwrap(),
+            names(&["z"])
+        );
+
+        // REPEATABLE READ는 스냅샷 뒤에 바뀐 행을 잠글 수 없습니다.
+        for sql in [
+            "begin isolation level repeatable read;",
+            "select id from users;",
+        ] {
+            execute_sql(&engine, wal.clone(), "rr", sql).await.unwrap();
+        }
+        execute_sql(
+            &engine,
+            wal.clone(),
+            "other",
+            "update users set name = 'y' where id = 2;",
+        )
+        .await
+        .unwrap();
+        let error = locked_ids(
+            &engine,
+            wal.clone(),
+            "rr",
+            "select id from users where id = 2 for update;",
+        )
+        .await
+        .unwrap_err();
+        assert!(error.to_string().contains("could not serialize"), "{error}");
+    }
+
+    #[tokio::test]
+    async fn row_lock_deadlock_is_detected_and_released_on_rollback() {
+        let (engine, wal) = setup_users_table("target/test_row_locking/row_lock_deadlock").await;
+
+        for (connection_id, sql) in [
+            ("a", "begin;"),
+            ("a", "select id from users where id = 1 for update;"),
+            ("b", "begin;"),
+            ("b", "update users set name = 'x' where id = 2;"),
+        ] {
+            execute_sql(&engine, wal.clone(), connection_id, sql)
+                .await
+                .unwrap();
+        }
+
+        // b는 a가 잠근 행을, a는 b가 쥔 쓰기 락을 기다리게 됩니다.
+        let update = execute_sql(
+            &engine,
+            wal.clone(),
+            "b",
+            "update users set name = 'x' where id = 1;",
+        );
+        tokio::pin!(update);
+        assert!(
+            tokio::time::timeout(Duration::from_millis(100), &mut update)
+                .await
+                .is_err()
+        );
+
+        let error = execute_sql(
+            &engine,
+            wal.clone(),
+            "a",
+            "update users set name = 'y' where id = 2;",
+        )
+        .await
+        .unwrap_err();
+        assert!(error.to_string().contains("deadlock detected"), "{error}");
+
+        // 롤백하면 a의 행 락이 풀려 b가 이어서 진행합니다.
+        execute_sql(&engine, wal.clone(), "a", "rollback;")
+            .await
+            .unwrap();
+        tokio::time::timeout(Duration::from_secs(5), update)
+            .await
+            .unwrap()
+            .unwrap();
+        execute_sql(&engine, wal.clone(), "b", "commit;")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            user_names(&engine, wal.clone(), "a").await,
+            names(&["x", "x"])
+        );
+    }
+
+    #[tokio::test]
+    async fn skip_locked_workers_on_the_same_table_write_one_at_a_time() {
+        let (engine, wal) =
+            setup_users_table("target/test_row_locking/skip_locked_table_writer").await;
+
+        let claim = "select id from users order by id limit 1 for update skip locked;";
+        for (connection_id, expected) in [("w1", 1), ("w2", 2)] {
+            execute_sql(&engine, wal.clone(), connection_id, "begin;")
+                .await
+                .unwrap();
+            assert_eq!(
+                locked_ids(&engine, wal.clone(), connection_id, claim)
+                    .await
+                    .unwrap(),
+                vec![ExecuteField::Integer(expected)]
+            );
+        }
+        execute_sql(
+            &engine,
+            wal.clone(),
+            "w1",
+            "update users set name = 'done' where id = 1;",
+        )
+        .await
+        .unwrap();
+
+        // 서로 다른 행을 가져가도 쓰기 락은 테이블 단위라서, 같은 테이블에 쓰는
+        // w2의 UPDATE는 w1이 끝날 때까지 기다립니다.
+        let update = execute_sql(
+            &engine,
+            wal.clone(),
+            "w2",
+            "update users set name = 'done' where id = 2;",
+        );
+        tokio::pin!(update);
+        assert!(
+            tokio::time::timeout(Duration::from_millis(100), &mut update)
+                .await
+                .is_err()
+        );
+
+        execute_sql(&engine, wal.clone(), "w1", "commit;")
+            .await
+            .unwrap();
+        tokio::time::timeout(Duration::from_secs(5), update)
+            .await
+            .unwrap()
+            .unwrap();
+        execute_sql(&engine, wal.clone(), "w2", "commit;")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            user_names(&engine, wal.clone(), "a").await,
+            names(&["done", "done"])
+        );
+    }
+
+    #[tokio::test]
+    async fn truncate_waits_for_row_locks_on_the_table() {
+        let (engine, wal) =
+            setup_users_table("target/test_row_locking/truncate_waits_for_locks").await;
+
+        for sql in ["begin;", "select id from users where id = 1 for update;"] {
+            execute_sql(&engine, wal.clone(), "holder", sql)
+                .await
+                .unwrap();
+        }
+
+        // 다른 트랜잭션이 잠근 행이 있으면 TRUNCATE는 그 트랜잭션이 끝날 때까지 기다립니다.
+        let truncate = execute_sql(&engine, wal.clone(), "other", "truncate users;");
+        tokio::pin!(truncate);
+        assert!(
+            tokio::time::timeout(Duration::from_millis(100), &mut truncate)
+                .await
+                .is_err()
+        );
+
+        execute_sql(&engine, wal.clone(), "holder", "commit;")
+            .await
+            .unwrap();
+        tokio::time::timeout(Duration::from_secs(5), truncate)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(user_names(&engine, wal.clone(), "holder").await.is_empty());
+
+        // 트랜잭션 안의 TRUNCATE는 COMMIT/ROLLBACK까지 테이블 락을 쥐므로, 그동안 다른
+        // 트랜잭션은 아직 보이는 이전 행도, 행 번호를 다시 쓰는 새 행도 잠그지 못합니다.
+        execute_sql(
+            &engine,
+            wal.clone(),
+            "holder",
+            "insert into users (id, name) values (1, 'a'), (2, 'b');",
+        )
+        .await
+        .unwrap();
+        for sql in [
+            "begin;",
+            "truncate users;",
+            "insert into users (id, name) values (5, 'e');",
+        ] {
+            execute_sql(&engine, wal.clone(), "other", sql)
+                .await
+                .unwrap();
+        }
+        let error = locked_ids(
+            &engine,
+            wal.clone(),
+            "holder",
+            "select id from users for update nowait;",
+        )
+        .await
+        .unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("could not obtain lock on row in relation \"users\""),
+            "{error}"
+        );
+
+        // 롤백하면 테이블 락이 풀리고 비우기 전의 행이 돌아옵니다.
+        execute_sql(&engine, wal.clone(), "other", "rollback;")
+            .await
+            .unwrap();
+        assert_eq!(
+            locked_ids(
+                &engine,
+                wal.clone(),
+                "holder",
+                "select id from users order by id for update nowait;",
+            )
+            .await
+            .unwrap(),
+            vec![ExecuteField::Integer(1), ExecuteField::Integer(2)]
+        );
+    }
+}
@@ -1,5 +1,15 @@
+pub mod aggregate;
+pub mod common_table;
 pub mod delete;
+pub mod function;
 pub mod insert;
+pub mod join;
+pub mod locking;
+pub mod on_conflict;
+pub mod returning;
 pub mod scan;
 pub mod select;
+pub mod set_operation;
+pub mod subquery;
 pub mod update;
+pub mod window;
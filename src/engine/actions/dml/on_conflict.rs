@@ -0,0 +1,573 @@
+//! INSERT ... ON CONFLICT (UPSERT)
+//!
+//! 충돌 여부는 고유 인덱스로 판단합니다. 기존 행 확인부터 인덱스/테이블 반영까지
+//! `row_storage_lock`을 쥔 채로 처리하므로, 확인과 반영 사이에 다른 쓰기가 끼어들 수 없습니다.
+//!
+//! WAL에는 쿼리 대신 결정된 결과(추가한 행, 수정한 행의 전체 값)를 기록합니다.
+//! `DO UPDATE SET n = n + 1`을 다시 실행하면 두 번 반영되기 때문입니다.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::engine::actions::dml::scan::DEFAULT_ROW_WRITE_BUFFER_LIMIT_BYTES;
+use crate::engine::actions::index::row_index_key;
+use crate::engine::ast::dml::parts::on_conflict::{
+    EXCLUDED_TABLE_NAME, OnConflictAction, OnConflictClause,
+};
+use crate::engine::ast::types::{Column, Function, SQLExpression, TableName};
+use crate::engine::expression::ReduceContext;
+use crate::engine::index::{IndexMeta, NULL_KEY};
+use crate::engine::schema::row::{TableDataFieldType, TableDataRow};
+use crate::engine::wal::types::{EntryType, UpsertWALPayload};
+use crate::engine::{DBEngine, SharedWALManager};
+use crate::errors;
+use crate::errors::execute_error::ExecuteError;
+use crate::errors::type_error::TypeError;
+
+// 고유 키가 가리키는 행
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ConflictRow {
+    Stored(usize),   // 이미 저장된 행 (row index)
+    Inserted(usize), // 이번 문장에서 추가할 행 (inserted_rows의 위치)
+}
+
+// 평가 준비를 마친 DO UPDATE 절
+struct ConflictUpdate {
+    update_items: Vec<(Column, SQLExpression)>,
+    where_clause: Option<SQLExpression>,
+}
+
+impl DBEngine {
+    // 값 검증을 마친 행들을 충돌 처리 규칙에 따라 추가하거나 기존 행을 수정합니다.
+    // 추가하거나 수정한 행을 처리한 순서대로 반환합니다. (수정한 행은 수정 후의 값)
+    pub(crate) async fn insert_on_conflict(
+        &self,
+        into_table: &TableName,
+        on_conflict: OnConflictClause,
+        rows: Vec<TableDataRow>,
+        index_metas: Vec<IndexMeta>,
+        wal_manager: Option<SharedWALManager>,
+    ) -> errors::Result<Vec<TableDataRow>> {
+        let table_config = self.get_table_config_cached(into_table.clone()).await?;
+        let columns_map = table_config.get_columns_map();
+
+        for column_name in &on_conflict.target_columns {
+            if !columns_map.contains_key(column_name) {
+                return Err(ExecuteError::wrap(format!(
+                    "column '{}' does not exist on table '{}'",
+                    column_name, into_table.table_name
+                )));
+            }
+        }
+
+        let unique_metas = index_metas
+            .iter()
+            .filter(|meta| meta.is_unique)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        // 충돌을 판단할 고유 인덱스. 대상 컬럼이 없으면 모든 고유 인덱스입니다.
+        let conflict_metas = if on_conflict.target_columns.is_empty() {
+            unique_metas.clone()
+        } else {
+            let matched = unique_metas
+                .iter()
+                .filter(|meta| on_conflict.target_columns == [meta.column_name.clone()])
+                .cloned()
+                .collect::<Vec<_>>();
+
+            if matched.is_empty() {
+                return Err(ExecuteError::wrap(
+                    "there is no unique index matching the ON CONFLICT specification",
+                ));
+            }
+
+            matched
+        };
+
+        let update = match on_conflict.action {
+            OnConflictAction::DoNothing => None,
+            OnConflictAction::DoUpdate {
+                update_items,
+                where_clause,
+            } => {
+                let mut prepared_items = vec![];
+
+                for update_item in update_items {
+                    let column = columns_map.get(&update_item.column).ok_or_else(|| {
+                        ExecuteError::wrap(format!(
+                            "column '{}' does not exist on table '{}'",
+                            update_item.column, into_table.table_name
+                        ))
+                    })?;
+
+                    let value = self.prepare_conflict_expression(update_item.value).await?;
+                    prepared_items.push((column.clone(), value));
+                }
+
+                let where_clause = match where_clause {
+                    Some(where_clause) => Some(
+                        self.prepare_conflict_expression(where_clause.expression)
+                            .await?,
+                    ),
+                    None => None,
+                };
+
+                Some(ConflictUpdate {
+                    update_items: prepared_items,
+                    where_clause,
+                })
+            }
+        };
+
+        let _guard = self.row_storage_lock.lock().await;
+
+        let stored_rows = self.read_table_rows_locked(into_table).await?;
+        let start_row_index = self.next_row_index_locked(into_table).await?;
+
+        // 이번 문장에서 바뀐 고유 키. 인덱스는 마지막에 한 번에 반영하므로 그 전까지는 여기를 먼저 봅니다.
+        let mut claimed_keys: HashMap<(String, String), Option<ConflictRow>> = HashMap::new();
+        let mut updated_row_indexes = HashSet::new();
+
+        let mut inserted_rows = vec![];
+        let mut updated_rows = vec![];
+        let mut affected_rows = vec![];
+
+        for row in rows {
+            let mut conflict = None;
+
+            for meta in &conflict_metas {
+                conflict = self.find_unique_row(&claimed_keys, meta, &row).await?;
+
+                if conflict.is_some() {
+                    break;
+                }
+            }
+
+            let Some(conflict) = conflict else {
+                // 충돌 대상이 아닌 고유 인덱스와의 중복은 그대로 오류입니다.
+                self.check_unique_keys(&claimed_keys, &unique_metas, &row, None)
+                    .await?;
+
+                let position = ConflictRow::Inserted(inserted_rows.len());
+                for meta in &unique_metas {
+                    if let Some(key) = row_index_key(&row, &meta.column_name) {
+                        claimed_keys.insert((meta.index_name.clone(), key), Some(position));
+                    }
+                }
+
+                affected_rows.push(row.clone());
+                inserted_rows.push(row);
+                continue;
+            };
+
+            // DO NOTHING
+            let Some(update) = &update else {
+                continue;
+            };
+
+            let row_index = match conflict {
+                ConflictRow::Stored(row_index) if !updated_row_indexes.contains(&row_index) => {
+                    row_index
+                }
+                _ => {
+                    return Err(ExecuteError::wrap(
+                        "ON CONFLICT DO UPDATE command cannot affect row a second time",
+                    ));
+                }
+            };
+
+            let existing_row = stored_rows
+                .get(row_index)
+                .cloned()
+                .flatten()
+                .ok_or_else(|| {
+                    ExecuteError::wrap(format!("row index '{}' not found", row_index))
+                })?;
+
+            let Some(updated_row) = self
+                .apply_conflict_update(update, &existing_row, &row, into_table)
+                .await?
+            else {
+                continue;
+            };
+
+            self.check_unique_keys(&claimed_keys, &unique_metas, &updated_row, Some(row_index))
+                .await?;
+
+            for meta in &unique_metas {
+                let old_key = row_index_key(&existing_row, &meta.column_name);
+                let new_key = row_index_key(&updated_row, &meta.column_name);
+
+                if old_key != new_key {
+                    if let Some(old_key) = old_key {
+                        claimed_keys.insert((meta.index_name.clone(), old_key), None);
+                    }
+                    if let Some(new_key) = new_key {
+                        claimed_keys.insert(
+                            (meta.index_name.clone(), new_key),
+                            Some(ConflictRow::Stored(row_index)),
+                        );
+                    }
+                }
+            }
+
+            updated_row_indexes.insert(row_index);
+            affected_rows.push(updated_row.clone());
+            updated_rows.push((row_index, updated_row));
+        }
+
+        if affected_rows.is_empty() {
+            return Ok(affected_rows);
+        }
+
+        let payload = UpsertWALPayload {
+            table_name: into_table.clone(),
+            start_row_index,
+            inserted_rows,
+            updated_rows,
+        };
+
+        // WAL-first: 인덱스/테이블을 변경하기 전에 먼저 durable하게 기록합니다.
+        if let Some(wal_manager) = &wal_manager {
+            let wal_payload = bincode::serialize(&payload)
+                .map_err(|error| ExecuteError::wrap(error.to_string()))?;
+
+            let transaction_id = self.transaction_manager.writer_transaction_id();
+            wal_manager
+                .lock()
+                .await
+                .append_record(EntryType::Upsert, Some(wal_payload), transaction_id)
+                .await?;
+        }
+
+        let UpsertWALPayload {
+            inserted_rows,
+            updated_rows,
+            ..
+        } = payload;
+
+        let inserted_count = inserted_rows.len();
+
+        self.apply_row_changes_locked(
+            into_table,
+            &stored_rows,
+            &index_metas,
+            updated_rows,
+            inserted_rows,
+            start_row_index,
+        )
+        .await?;
+
+        drop(_guard);
+
+        self.statistics_manager
+            .record_insert(into_table, inserted_count)
+            .await;
+
+        Ok(affected_rows)
+    }
+
+    /// WAL replay용 UPSERT.
+    ///
+    /// 수정한 행은 전체 값을 다시 쓰고 현재 행과 달라진 인덱스 키만 반영하므로,
+    /// 이미 반영된 항목을 다시 적용해도 결과가 같습니다. 추가한 행은 INSERT replay와
+    /// 같이 start_row_index로 이미 반영됐는지 판단합니다 (#236).
+    pub(crate) async fn upsert_replay(&self, payload: UpsertWALPayload) -> errors::Result<()> {
+        let UpsertWALPayload {
+            table_name,
+            start_row_index,
+            mut inserted_rows,
+            mut updated_rows,
+        } = payload;
+
+        self.ensure_indices_loaded().await?;
+        let index_metas = self.table_index_metas(&table_name).await;
+
+        let _guard = self.row_storage_lock.lock().await;
+
+        let stored_rows = self.read_table_rows_locked(&table_name).await?;
+        let next_row_index = self.next_row_index_locked(&table_name).await?;
+
+        if next_row_index >= start_row_index + inserted_rows.len() {
+            inserted_rows.clear();
+        }
+
+        // 이후에 삭제된 행은 다시 살리지 않습니다.
+        updated_rows.retain(|(row_index, _)| matches!(stored_rows.get(*row_index), Some(Some(_))));
+
+        let inserted_count = inserted_rows.len();
+
+        self.apply_row_changes_locked(
+            &table_name,
+            &stored_rows,
+            &index_metas,
+            updated_rows,
+            inserted_rows,
+            next_row_index,
+        )
+        .await?;
+
+        drop(_guard);
+
+        self.statistics_manager
+            .record_insert(&table_name, inserted_count)
+            .await;
+
+        Ok(())
+    }
+
+    // 결정된 변경을 인덱스와 테이블에 반영합니다. 호출자가 `row_storage_lock`을 쥐고 있어야 합니다.
+    // 인덱스 반영이나 저장에 실패하면 이미 반영한 인덱스 항목을 되돌립니다.
+    pub(crate) async fn apply_row_changes_locked(
+        &self,
+        table_name: &TableName,
+        stored_rows: &[Option<TableDataRow>],
+        index_metas: &[IndexMeta],
+        updated_rows: Vec<(usize, TableDataRow)>,
+        inserted_rows: Vec<TableDataRow>,
+        start_row_index: usize,
+    ) -> errors::Result<()> {
+        // 인덱스 반영 목록: (index_name, old_key, new_key, row_path)
+        let mut index_operations: Vec<(String, Option<String>, Option<String>, String)> = vec![];
+
+        for (row_index, row) in &updated_rows {
+            let old_row = stored_rows.get(*row_index).cloned().flatten();
+
+            for meta in index_metas {
+                let old_key = old_row
+                    .as_ref()
+                    .and_then(|old_row| row_index_key(old_row, &meta.column_name));
+                let new_key = row_index_key(row, &meta.column_name);
+
+                if old_key != new_key {
+                    index_operations.push((
+                        meta.index_name.clone(),
+                        old_key,
+                        new_key,
+                        row_index.to_string(),
+                    ));
+                }
+            }
+        }
+
+        for (offset, row) in inserted_rows.iter().enumerate() {
+            for meta in index_metas {
+                if let Some(key) = row_index_key(row, &meta.column_name) {
+                    index_operations.push((
+                        meta.index_name.clone(),
+                        None,
+                        Some(key),
+                        (start_row_index + offset).to_string(),
+                    ));
+                }
+            }
+        }
+
+        for (i, (index_name, old_key, new_key, row_path)) in index_operations.iter().enumerate() {
+            if let Err(error) = self
+                .apply_index_operation(index_name, old_key, new_key, row_path)
+                .await
+            {
+                for (index_name, old_key, new_key, row_path) in index_operations[..i].iter().rev() {
+                    let _ = self
+                        .apply_index_operation(index_name, new_key, old_key, row_path)
+                        .await;
+                }
+
+                return Err(error);
+            }
+        }
+
+        let replacements = updated_rows.into_iter().collect::<HashMap<_, _>>();
+
+        let result = async {
+            self.update_table_rows_locked(table_name, replacements)
+                .await?;
+            self.append_table_rows_locked(
+                table_name,
+                &inserted_rows,
+                DEFAULT_ROW_WRITE_BUFFER_LIMIT_BYTES,
+            )
+            .await
+        }
+        .await;
+
+        if let Err(error) = result {
+            for (index_name, old_key, new_key, row_path) in index_operations.iter().rev() {
+                let _ = self
+                    .apply_index_operation(index_name, new_key, old_key, row_path)
+                    .await;
+            }
+
+            return Err(error);
+        }
+
+        Ok(())
+    }
+
+    // 잠금을 쥔 동안에는 다른 테이블을 읽을 수 없으므로, 서브쿼리는 미리 값으로 바꿔둡니다.
+    async fn prepare_conflict_expression(
+        &self,
+        expression: SQLExpression,
+    ) -> errors::Result<SQLExpression> {
+        let mut expression = self.materialize_subqueries(expression).await?;
+
+        if expression.has_subquery() {
+            return Err(ExecuteError::wrap(
+                "correlated subqueries are not supported in ON CONFLICT DO UPDATE",
+            ));
+        }
+
+        let mut reads_tables = false;
+        expression.visit_mut(&mut |expression| {
+            if let SQLExpression::FunctionCall(call) = expression
+                && let Function::UserDefined(_) = call.function
+            {
+                reads_tables = true;
+            }
+
+            Ok(())
+        })?;
+
+        // 펼쳐 넣지 못한 사용자 정의 함수는 실행 중에 테이블을 읽습니다.
+        if reads_tables {
+            return Err(ExecuteError::wrap(
+                "user-defined functions that read tables are not supported in ON CONFLICT DO UPDATE",
+            ));
+        }
+
+        Ok(expression)
+    }
+
+    // 고유 키가 같은 행을 찾습니다. 이번 문장에서 바뀐 키를 먼저 확인합니다.
+    async fn find_unique_row(
+        &self,
+        claimed_keys: &HashMap<(String, String), Option<ConflictRow>>,
+        meta: &IndexMeta,
+        row: &TableDataRow,
+    ) -> errors::Result<Option<ConflictRow>> {
+        let Some(key) = row_index_key(row, &meta.column_name) else {
+            return Ok(None);
+        };
+
+        // NULL은 서로 충돌하지 않습니다.
+        if key == NULL_KEY {
+            return Ok(None);
+        }
+
+        if let Some(claimed) = claimed_keys.get(&(meta.index_name.clone(), key.clone())) {
+            return Ok(*claimed);
+        }
+
+        let row_paths = self.index_manager.get(&meta.index_name, &key).await?;
+
+        Ok(row_paths
+            .first()
+            .and_then(|row_path| row_path.parse().ok())
+            .map(ConflictRow::Stored))
+    }
+
+    // 행의 고유 키가 다른 행과 겹치지 않는지 확인합니다. own은 수정 중인 행 자신입니다.
+    async fn check_unique_keys(
+        &self,
+        claimed_keys: &HashMap<(String, String), Option<ConflictRow>>,
+        unique_metas: &[IndexMeta],
+        row: &TableDataRow,
+        own: Option<usize>,
+    ) -> errors::Result<()> {
+        for meta in unique_metas {
+            match self.find_unique_row(claimed_keys, meta, row).await? {
+                None => {}
+                Some(ConflictRow::Stored(row_index)) if Some(row_index) == own => {}
+                Some(_) => {
+                    return Err(ExecuteError::wrap(format!(
+                        "duplicate key value violates unique index on column '{}'",
+                        meta.column_name
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // 충돌한 기존 행에 DO UPDATE를 적용한 행을 반환합니다. WHERE 조건을 만족하지 않으면 None입니다.
+    // SET과 WHERE의 컬럼은 기존 행을, excluded.컬럼은 삽입하려던 행을 가리킵니다.
+    async fn apply_conflict_update(
+        &self,
+        update: &ConflictUpdate,
+        existing_row: &TableDataRow,
+        proposed_row: &TableDataRow,
+        into_table: &TableName,
+    ) -> errors::Result<Option<TableDataRow>> {
+        let excluded_table = TableName::new(
+            into_table.database_name.clone(),
+            EXCLUDED_TABLE_NAME.to_string(),
+        );
+
+        let mut excluded_row = proposed_row.clone();
+        for field in &mut excluded_row.fields {
+            field.table_name = excluded_table.clone();
+        }
+
+        let reduce_context = ReduceContext {
+            row: Some(existing_row.clone()),
+            outer: Some(Box::new(ReduceContext {
+                row: Some(excluded_row),
+                ..Default::default()
+            })),
+            ..Default::default()
+        };
+
+        if let Some(where_clause) = &update.where_clause {
+            let condition = self
+                .reduce_expression(where_clause.clone(), reduce_context.clone())
+                .await?;
+
+            match condition {
+                TableDataFieldType::Boolean(true) => {}
+                TableDataFieldType::Boolean(false) | TableDataFieldType::Null => return Ok(None),
+                _ => {
+                    return Err(TypeError::wrap(
+                        "condition expression is valid only for boolean and null types",
+                    ));
+                }
+            }
+        }
+
+        let mut updated_row = existing_row.clone();
+
+        for (column, expression) in &update.update_items {
+            let value = self
+                .reduce_expression(expression.clone(), reduce_context.clone())
+                .await?
+                .assign_to(&column.data_type)
+                .ok_or_else(|| {
+                    ExecuteError::wrap(format!(
+                        "column '{}' is of type {} but expression is of another type",
+                        column.name,
+                        String::from(column.data_type.clone())
+                    ))
+                })?;
+
+            if column.not_null && value == TableDataFieldType::Null {
+                return Err(ExecuteError::wrap(format!(
+                    "column '{}' is not null column",
+                    column.name
+                )));
+            }
+
+            let field = updated_row
+                .fields
+                .iter_mut()
+                .find(|field| field.column_name == column.name)
+                .ok_or_else(|| {
+                    ExecuteError::wrap(format!("column '{}' not found in data row", column.name))
+                })?;
+
+            field.data = value;
+        }
+
+        Ok(Some(updated_row))
+    }
+}
@@ -0,0 +1,561 @@
+//! INSERT/UPDATE/DELETE의 RETURNING 절
+//!
+//! 결과 컬럼과 타입은 데이터를 바꾸기 전에 미리 확정해, 잘못된 RETURNING 때문에
+//! 변경만 반영되고 오류가 나는 일이 없도록 합니다.
+//! 비상관 서브쿼리도 이때 값으로 바꿔두므로, 서브쿼리는 문장 실행 전의 데이터를 봅니다.
+
+use std::collections::HashMap;
+
+use futures::future::join_all;
+
+use crate::engine::DBEngine;
+use crate::engine::actions::dml::select::expand_select_items;
+use crate::engine::ast::dml::parts::returning::ReturningClause;
+use crate::engine::ast::dml::parts::target::UpdateTarget;
+use crate::engine::ast::types::{SQLExpression, TableName};
+use crate::engine::expression::ReduceContext;
+use crate::engine::schema::row::{TableDataFieldType, TableDataRow};
+use crate::engine::types::{
+    ExecuteColumn, ExecuteColumnType, ExecuteField, ExecuteResult, ExecuteRow,
+};
+use crate::errors;
+
+/// 평가 준비가 끝난 RETURNING 절
+pub(crate) struct ReturningProjection {
+    columns: Vec<ExecuteColumn>,
+    items: Vec<SQLExpression>,
+    table_alias_map: HashMap<String, TableName>,
+}
+
+impl DBEngine {
+    /// RETURNING 절의 결과 컬럼 정보. 쿼리를 실행하지 않습니다.
+    ///
+    /// targets[0]은 변경 대상 테이블이고, 나머지는 UPDATE ... FROM / DELETE ... USING의 테이블입니다.
+    pub async fn describe_returning_columns(
+        &self,
+        targets: &[UpdateTarget],
+        returning: &ReturningClause,
+    ) -> errors::Result<Vec<ExecuteColumn>> {
+        Ok(self.prepare_returning(targets, returning).await?.columns)
+    }
+
+    // * 를 펼치고 결과 컬럼 타입을 계산한 뒤, 비상관 서브쿼리를 값으로 바꿔둡니다.
+    pub(crate) async fn prepare_returning(
+        &self,
+        targets: &[UpdateTarget],
+        returning: &ReturningClause,
+    ) -> errors::Result<ReturningProjection> {
+        let mut config_columns = vec![];
+        let mut table_alias_map = HashMap::new();
+
+        for target in targets {
+            let table_config = self.get_table_config_cached(target.table.clone()).await?;
+
+            config_columns.extend(
+                table_config
+                    .columns
+                    .iter()
+                    .cloned()
+                    .map(|column| (target.table.clone(), column)),
+            );
+
+            if let Some(alias) = &target.alias {
+                table_alias_map.insert(alias.clone(), target.table.clone());
+            }
+        }
+
+        let select_items = expand_select_items(
+            returning.select_items.clone(),
+            &config_columns,
+            &table_alias_map,
+        );
+
+        let reduce_context = ReduceContext {
+            row: None,
+            table_alias_map: table_alias_map.clone(),
+            config_columns,
+            outer: None,
+        };
+
+        let mut columns = vec![];
+        let mut items = vec![];
+
+        for select_item in select_items {
+            let item = select_item.item.unwrap();
+
+            let name = match select_item.alias {
+                Some(alias) => alias,
+                None => match &item {
+                    SQLExpression::SelectColumn(column) => column.column_name.to_owned(),
+                    _ => "?column?".into(),
+                },
+            };
+
+            self.cache_subquery_schemas(&item).await?;
+            let data_type = self
+                .reduce_type(item.clone(), reduce_context.clone())
+                .await?;
+
+            columns.push(ExecuteColumn { name, data_type });
+            items.push(self.materialize_subqueries(item).await?);
+        }
+
+        Ok(ReturningProjection {
+            columns,
+            items,
+            table_alias_map,
+        })
+    }
+
+    // 영향받은 행마다 RETURNING 표현식을 계산해 결과를 만듭니다.
+    pub(crate) async fn project_returning(
+        &self,
+        projection: ReturningProjection,
+        rows: Vec<TableDataRow>,
+    ) -> errors::Result<ExecuteResult> {
+        let affected_rows = rows.len();
+
+        let ReturningProjection {
+            columns,
+            items,
+            table_alias_map,
+        } = projection;
+
+        let rows = rows.into_iter().map(|row| {
+            let table_alias_map = table_alias_map.clone();
+            let items = &items;
+            let columns = &columns;
+            async move {
+                let reduce_context = ReduceContext {
+                    row: Some(row),
+                    table_alias_map,
+                    config_columns: vec![],
+                    outer: None,
+                };
+
+                let mut fields = vec![];
+
+                for (item, column) in items.iter().zip(columns) {
+                    let value = self
+                        .reduce_expression(item.clone(), reduce_context.clone())
+                        .await?;
+
+                    // 정수와 실수가 섞인 CASE 등은 컬럼 타입인 실수로 넓혀서 내보냅니다.
+                    let field = match value {
+                        TableDataFieldType::Integer(value)
+                            if column.data_type == ExecuteColumnType::Float =>
+                        {
+                            ExecuteField::Float(value as f64)
+                        }
+                        value => ExecuteField::from(value),
+                    };
+
+                    fields.push(field);
+                }
+
+                Ok::<ExecuteRow, errors::Errors>(ExecuteRow { fields })
+            }
+        });
+
+        let rows = join_all(rows)
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(ExecuteResult::with_affected_rows(
+            columns,
+            rows,
+            affected_rows,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::sync::Arc;
+
+    use tokio::sync::Mutex;
+
+    use crate::config::launch_config::LaunchConfig;
+    use crate::engine::ast::dml::parts::returning::ReturningClause;
+    use crate::engine::ast::dml::parts::select_item::{SelectKind, SelectWildCard};
+    use crate::engine::ast::types::TableName;
+    use crate::engine::parser::predule::{Parser, ParserContext};
+    use crate::engine::types::{ExecuteColumn, ExecuteColumnType, ExecuteField, ExecuteResult};
+    use crate::engine::wal::endec::implements::bincode::{BincodeDecoder, BincodeEncoder};
+    use crate::engine::wal::manager::builder::WALBuilder;
+    use crate::engine::{DBEngine, SharedWALManager};
+
+    async fn build_test_engine(test_name: &str) -> (DBEngine, SharedWALManager) {
+        let base_path = PathBuf::from("target/test_returning_integration").join(test_name);
+        if base_path.exists() {
+            tokio::fs::remove_dir_all(&base_path).await.unwrap();
+        }
+
+        let config = LaunchConfig::default_for_base_path(&base_path);
+        tokio::fs::create_dir_all(&config.data_directory)
+            .await
+            .unwrap();
+        tokio::fs::create_dir_all(&config.wal_directory)
+            .await
+            .unwrap();
+
+        let wal = WALBuilder::new(&config)
+            .build(BincodeDecoder::new(), BincodeEncoder::new())
+            .await
+            .unwrap();
+
+        (DBEngine::new(config), Arc::new(Mutex::new(wal)))
+    }
+
+    async fn execute_sql(
+        engine: &DBEngine,
+        wal: SharedWALManager,
+        sql: &str,
+    ) -> crate::errors::Result<ExecuteResult> {
+        let mut parser = Parser::with_string(sql.to_string()).unwrap();
+        let mut statements = parser
+            .parse(ParserContext::default().set_default_database("rrdb".to_string()))
+            .unwrap();
+        let statement = statements.remove(0);
+
+        engine
+            .process_query(statement, wal, "test-connection".to_string())
+            .await
+    }
+
+    async fn setup_items_table(test_name: &str) -> (DBEngine, SharedWALManager) {
+        let (engine, wal) = build_test_engine(test_name).await;
+
+        for sql in [
+            "create database rrdb;",
+            "create table items (id integer primary key, name varchar(100), price float);",
+        ] {
+            execute_sql(&engine, wal.clone(), sql)
+                .await
+                .unwrap_or_else(|error| panic!("{sql} failed: {error}"));
+        }
+
+        (engine, wal)
+    }
+
+    fn all_rows(result: &ExecuteResult) -> Vec<Vec<ExecuteField>> {
+        result.rows.iter().map(|row| row.fields.clone()).collect()
+    }
+
+    fn item(id: i64, name: &str, price: f64) -> Vec<ExecuteField> {
+        vec![
+            ExecuteField::Integer(id),
+            ExecuteField::String(name.into()),
+            ExecuteField::Float(price),
+        ]
+    }
+
+    #[tokio::test]
+    async fn insert_returning_reports_inserted_rows() {
+        let (engine, wal) = setup_items_table("insert").await;
+
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "insert into items (name, id) values ('apple', 1), ('pear', 2) returning *;",
+        )
+        .await
+        .unwrap();
+
+        // 입력한 컬럼 순서와 상관없이 * 는 테이블 컬럼 순서를 따릅니다.
+        assert_eq!(
+            result.columns,
+            vec![
+                ExecuteColumn {
+                    name: "id".into(),
+                    data_type: ExecuteColumnType::Integer,
+                },
+                ExecuteColumn {
+                    name: "name".into(),
+                    data_type: ExecuteColumnType::String,
+                },
+                ExecuteColumn {
+                    name: "price".into(),
+                    data_type: ExecuteColumnType::Float,
+                },
+            ]
+        );
+        assert_eq!(
+            all_rows(&result),
+            vec![
+                vec![
+                    ExecuteField::Integer(1),
+                    ExecuteField::String("apple".into()),
+                    ExecuteField::Null,
+                ],
+                vec![
+                    ExecuteField::Integer(2),
+                    ExecuteField::String("pear".into()),
+                    ExecuteField::Null,
+                ],
+            ]
+        );
+        assert_eq!(result.affected_rows, Some(2));
+
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "insert into items (id, name, price) select 3, 'fig', 1.5 returning id * 10 as tenfold, upper(name);",
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            result.columns,
+            vec![
+                ExecuteColumn {
+                    name: "tenfold".into(),
+                    data_type: ExecuteColumnType::Integer,
+                },
+                ExecuteColumn {
+                    name: "?column?".into(),
+                    data_type: ExecuteColumnType::String,
+                },
+            ]
+        );
+        assert_eq!(
+            all_rows(&result),
+            vec![vec![
+                ExecuteField::Integer(30),
+                ExecuteField::String("FIG".into()),
+            ]]
+        );
+    }
+
+    #[tokio::test]
+    async fn update_and_delete_returning_report_new_and_old_rows() {
+        let (engine, wal) = setup_items_table("update_delete").await;
+
+        execute_sql(
+            &engine,
+            wal.clone(),
+            "insert into items (id, name, price) values (1, 'apple', 1.0), (2, 'pear', 2.0), (3, 'fig', 3.0);",
+        )
+        .await
+        .unwrap();
+
+        // UPDATE는 수정 후의 행을 돌려줍니다.
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "update items as i set price = 9.5 where id >= 2 returning i.id, price, (select count(*) from items) as total;",
+        )
+        .await
+        .unwrap();
+        let mut rows = all_rows(&result);
+        rows.sort_by_key(|row| match row[0] {
+            ExecuteField::Integer(id) => id,
+            _ => unreachable!(),
+        });
+        assert_eq!(
+            rows,
+            vec![
+                vec![
+                    ExecuteField::Integer(2),
+                    ExecuteField::Float(9.5),
+                    ExecuteField::Integer(3),
+                ],
+                vec![
+                    ExecuteField::Integer(3),
+                    ExecuteField::Float(9.5),
+                    ExecuteField::Integer(3),
+                ],
+            ]
+        );
+        assert_eq!(result.affected_rows, Some(2));
+
+        // DELETE는 삭제 전의 행을 돌려줍니다.
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "delete from items where id = 1 returning *;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(all_rows(&result), vec![item(1, "apple", 1.0)]);
+        assert_eq!(result.affected_rows, Some(1));
+
+        // 영향받은 행이 없어도 컬럼 정보는 그대로입니다.
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "delete from items where id = 1 returning name;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            result.columns,
+            vec![ExecuteColumn {
+                name: "name".into(),
+                data_type: ExecuteColumnType::String,
+            }]
+        );
+        assert!(result.rows.is_empty());
+        assert_eq!(result.affected_rows, Some(0));
+    }
+
+    /// UPDATE ... FROM / DELETE ... USING은 조인한 테이블의 컬럼을 SET과 RETURNING에서
+    /// 쓸 수 있고, WAL에는 결정된 행이 남으므로 replay해도 결과가 그대로입니다.
+    #[tokio::test]
+    async fn update_from_and_delete_using_modify_joined_rows() {
+        let (engine, wal) = setup_items_table("update_from_delete_using").await;
+
+        for sql in [
+            "create table discounts (item_id integer, rate float, label varchar(100));",
+            "insert into items (id, name, price) values (1, 'apple', 10.0), (2, 'pear', 20.0), (3, 'fig', 30.0);",
+            "insert into discounts (item_id, rate, label) values (1, 0.5, 'half'), (2, 0.9, 'sale'), (2, 0.1, 'dup');",
+        ] {
+            execute_sql(&engine, wal.clone(), sql)
+                .await
+                .unwrap_or_else(|error| panic!("{sql} failed: {error}"));
+        }
+
+        // 여러 FROM 행과 매칭되는 대상 행도 한 번만 수정합니다.
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "update items i set price = price * d.rate from discounts d where i.id = d.item_id and d.rate >= 0.5 returning i.id, price, d.label;",
+        )
+        .await
+        .unwrap();
+        let mut rows = all_rows(&result);
+        rows.sort_by_key(|row| match row[0] {
+            ExecuteField::Integer(id) => id,
+            _ => unreachable!(),
+        });
+        assert_eq!(
+            rows,
+            vec![
+                vec![
+                    ExecuteField::Integer(1),
+                    ExecuteField::Float(5.0),
+                    ExecuteField::String("half".into()),
+                ],
+                vec![
+                    ExecuteField::Integer(2),
+                    ExecuteField::Float(18.0),
+                    ExecuteField::String("sale".into()),
+                ],
+            ]
+        );
+        assert_eq!(result.affected_rows, Some(2));
+
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "delete from items using discounts d where items.id = d.item_id and d.label = 'dup' returning items.name;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            all_rows(&result),
+            vec![vec![ExecuteField::String("pear".into())]]
+        );
+
+        let select_items = "select id, name, price from items order by id;";
+        let expected = vec![item(1, "apple", 5.0), item(3, "fig", 30.0)];
+        let result = execute_sql(&engine, wal.clone(), select_items)
+            .await
+            .unwrap();
+        assert_eq!(all_rows(&result), expected);
+
+        // 이미 반영된 상태에서 replay해도 price * rate가 다시 적용되지 않습니다.
+        let entries = wal.lock().await.pending_entries().to_vec();
+        engine.replay_wal(&entries).await.unwrap();
+
+        let result = execute_sql(&engine, wal.clone(), select_items)
+            .await
+            .unwrap();
+        assert_eq!(all_rows(&result), expected);
+    }
+
+    #[tokio::test]
+    async fn upsert_returning_reports_inserted_and_updated_rows() {
+        let (engine, wal) = setup_items_table("upsert").await;
+
+        execute_sql(
+            &engine,
+            wal.clone(),
+            "insert into items (id, name, price) values (1, 'apple', 1.0);",
+        )
+        .await
+        .unwrap();
+
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "insert into items (id, name, price) values (2, 'pear', 2.0), (1, 'apple', 5.0) \
+             on conflict (id) do update set price = excluded.price returning *;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            all_rows(&result),
+            vec![item(2, "pear", 2.0), item(1, "apple", 5.0)]
+        );
+        assert_eq!(result.affected_rows, Some(2));
+
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "insert into items (id, name, price) values (1, 'apple', 7.0) on conflict do nothing returning id;",
+        )
+        .await
+        .unwrap();
+        assert!(result.rows.is_empty());
+        assert_eq!(result.affected_rows, Some(0));
+    }
+
+    #[tokio::test]
+    async fn invalid_returning_is_rejected_before_changing_data() {
+        let (engine, wal) = setup_items_table("invalid").await;
+
+        for sql in [
+            "insert into items (id, name) values (1, 'apple') returning missing;",
+            "insert into items (id, name) values (1, 'apple') returning other.id;",
+        ] {
+            assert!(
+                execute_sql(&engine, wal.clone(), sql).await.is_err(),
+                "{sql}"
+            );
+        }
+
+        let result = execute_sql(&engine, wal.clone(), "select count(*) from items;")
+            .await
+            .unwrap();
+        assert_eq!(all_rows(&result), vec![vec![ExecuteField::Integer(0)]]);
+    }
+
+    #[tokio::test]
+    async fn describe_returning_columns_does_not_execute() {
+        let (engine, wal) = setup_items_table("describe").await;
+
+        let columns = engine
+            .describe_returning_columns(
+                &[TableName::new(Some("rrdb".into()), "items".into()).into()],
+                &ReturningClause {
+                    select_items: vec![SelectKind::WildCard(SelectWildCard { alias: None })],
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            columns
+                .iter()
+                .map(|column| &column.name)
+                .collect::<Vec<_>>(),
+            vec!["id", "name", "price"]
+        );
+
+        let result = execute_sql(&engine, wal.clone(), "select count(*) from items;")
+            .await
+            .unwrap();
+        assert_eq!(all_rows(&result), vec![vec![ExecuteField::Integer(0)]]);
+    }
+}
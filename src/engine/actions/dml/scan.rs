@@ -2,21 +2,30 @@ use std::collections::{HashMap, HashSet};
 use std::io::ErrorKind as IOErrorKind;
 use std::path::{Path, PathBuf};
 
+use futures::{Stream, StreamExt, TryStreamExt, stream};
 use serde::{Deserialize, Serialize};
 use tokio::io::{AsyncSeekExt, AsyncWriteExt};
 
 use crate::engine::DBEngine;
+use crate::engine::aggregate::estimated_size;
 use crate::engine::ast::dml::plan::select::scan::IndexScanPlan;
+use crate::engine::ast::tcl::IsolationLevel;
 use crate::engine::ast::types::TableName;
 use crate::engine::encoder::schema_encoder::StorageEncoder;
-use crate::engine::row_buffer::{ROW_FRAME_LIVE, RowBufferWrite, encode_live_row_frames};
+use crate::engine::row_buffer::{
+    ROW_FRAME_LIVE, ROW_FRAME_TOMBSTONE, ROW_FRAME_VERSIONED, RowBufferWrite, RowSlot, RowVersion,
+    encode_row_versions,
+};
 use crate::engine::schema::row::TableDataRow;
+use crate::engine::transaction::{FROZEN_TRANSACTION_ID, Snapshot, StatementContext};
 use crate::errors;
 use crate::errors::execute_error::ExecuteError;
 
 const ROW_SEGMENT_FILENAME: &str = "00000001.rows";
 const ROW_META_FILENAME: &str = "meta.bin";
-const DEFAULT_ROW_WRITE_BUFFER_LIMIT_BYTES: usize = 16 * 1024 * 1024;
+pub(crate) const DEFAULT_ROW_WRITE_BUFFER_LIMIT_BYTES: usize = 16 * 1024 * 1024;
+/// 스캔 스트림이 `row_storage_lock`을 한 번 쥘 때 읽는 row index 수
+const SCAN_STREAM_BATCH_ROWS: usize = 1024;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub(crate) struct RowLocation {
@@ -41,23 +50,34 @@ impl DBEngine {
     /// The limit counts rows that survive the tombstone filter, not raw slots,
     /// so a segment whose head is mostly deleted still yields the requested
     /// number. `None` scans everything.
+    ///
+    /// Inside a statement only the row versions visible to its snapshot are
+    /// returned; outside one (WAL replay, internal callers) the latest versions are.
     pub(crate) async fn full_scan_limited(
         &self,
         table_name: TableName,
         limit: Option<usize>,
     ) -> errors::Result<Vec<(RowLocation, TableDataRow)>> {
+        // WITH 절 테이블은 디스크가 아니라 등록된 결과를 읽습니다.
+        if table_name.is_common_table() {
+            let rows = self.common_table(&table_name).await?.rows;
+
+            return Ok(rows
+                .into_iter()
+                .enumerate()
+                .map(|(row_index, row)| (RowLocation { row_index }, row))
+                .take(limit.unwrap_or(usize::MAX))
+                .collect());
+        }
+
         let _guard = self.row_storage_lock.lock().await;
-        let segment_path = self.row_segment_path(&table_name)?;
-        let cached_rows = { self.row_buffer_pool.lock().await.cached_rows(&segment_path) };
-        let rows = match cached_rows {
-            Some(rows) => rows,
-            None => {
-                let disk_rows = self.read_segment_rows(&segment_path).await?;
-                self.row_buffer_pool
-                    .lock()
-                    .await
-                    .read_rows(segment_path, || disk_rows)
+        let rows = match StatementContext::current() {
+            Some(context) => {
+                self.read_snapshot_rows_locked(&table_name, &context.snapshot)
+                    .await?
+                    .0
             }
+            None => self.read_table_rows_locked(&table_name).await?,
         };
 
         let live = rows
@@ -71,6 +91,84 @@ impl DBEngine {
         })
     }
 
+    /// `full_scan`과 같은 행을 `SCAN_STREAM_BATCH_ROWS`개의 row index씩 끊어 읽어 내보냅니다.
+    ///
+    /// 테이블 전체를 한 번에 복사하지 않으므로, 행을 하나씩 소비하는 쪽(해시 집계)이
+    /// 입력 전체를 들고 있지 않아도 됩니다. 배치마다 `row_storage_lock`을 다시 쥐지만
+    /// row index는 바뀌지 않고, 스트림을 만들 때의 문장 스냅샷으로 읽으므로 그 사이의
+    /// 다른 쓰기는 보이지 않습니다.
+    pub(crate) fn full_scan_stream(
+        &self,
+        table_name: TableName,
+    ) -> impl Stream<Item = errors::Result<(RowLocation, TableDataRow)>> + '_ {
+        // WITH 절 테이블은 이미 메모리에 있는 결과라 한 번에 읽습니다.
+        if table_name.is_common_table() {
+            return stream::once(self.full_scan(table_name))
+                .map_ok(|rows| stream::iter(rows.into_iter().map(Ok)))
+                .try_flatten()
+                .left_stream();
+        }
+
+        let snapshot = StatementContext::current().map(|context| context.snapshot);
+
+        stream::try_unfold(0, move |start| {
+            let table_name = table_name.clone();
+            let snapshot = snapshot.clone();
+
+            async move {
+                let rows = self
+                    .full_scan_batch(&table_name, snapshot.as_deref(), start)
+                    .await?;
+                if rows.is_empty() {
+                    return errors::Result::Ok(None);
+                }
+
+                let next_start = start + rows.len();
+                let live = rows
+                    .into_iter()
+                    .zip(start..)
+                    .filter_map(|(row, row_index)| {
+                        row.map(|row| Ok((RowLocation { row_index }, row)))
+                    })
+                    .collect::<Vec<_>>();
+
+                Ok(Some((stream::iter(live), next_start)))
+            }
+        })
+        .try_flatten()
+        .right_stream()
+    }
+
+    async fn full_scan_batch(
+        &self,
+        table_name: &TableName,
+        snapshot: Option<&Snapshot>,
+        start: usize,
+    ) -> errors::Result<Vec<Option<TableDataRow>>> {
+        let _guard = self.row_storage_lock.lock().await;
+        let segment_path = self.row_segment_path(table_name)?;
+
+        let cached_rows = {
+            self.row_buffer_pool.lock().await.rows_range(
+                &segment_path,
+                snapshot,
+                start,
+                SCAN_STREAM_BATCH_ROWS,
+            )
+        };
+        if let Some(rows) = cached_rows {
+            return Ok(rows);
+        }
+
+        let disk_rows = self.read_segment_rows(&segment_path).await?;
+        let mut row_buffer_pool = self.row_buffer_pool.lock().await;
+        row_buffer_pool.load_rows(segment_path.clone(), || disk_rows);
+
+        Ok(row_buffer_pool
+            .rows_range(&segment_path, snapshot, start, SCAN_STREAM_BATCH_ROWS)
+            .unwrap_or_default())
+    }
+
     /// 행을 세그먼트 파일 끝에 추가하고 시작 row index를 반환합니다.
     /// 반환된 시작 인덱스는 인덱스 유지보수(key -> row index)에 사용됩니다.
     /// 시작 인덱스는 meta.bin + 버퍼 상태를 사용해 계산하므로 세그먼트 전체 스캔이 필요 없습니다.
@@ -171,6 +269,25 @@ impl DBEngine {
         }
 
         let _guard = self.row_storage_lock.lock().await;
+        let start_index = self.next_row_index_locked(table_name).await?;
+
+        // 위치가 확정된 뒤, 아직 락을 쥔 상태에서 WAL을 기록합니다. 실패하면
+        // 행을 추가하지 않고 그대로 반환하므로 WAL과 데이터가 어긋나지 않습니다.
+        if let Some(on_reserved) = on_reserved {
+            on_reserved(start_index).await?;
+        }
+
+        self.append_table_rows_locked(table_name, rows, buffer_limit_bytes)
+            .await?;
+
+        Ok(start_index)
+    }
+
+    /// `next_row_index`와 같지만 호출자가 이미 `row_storage_lock`을 쥐고 있어야 합니다.
+    pub(crate) async fn next_row_index_locked(
+        &self,
+        table_name: &TableName,
+    ) -> errors::Result<usize> {
         let segment_path = self.row_segment_path(table_name)?;
         let meta_path = self.row_segment_meta_path(table_name)?;
 
@@ -180,26 +297,48 @@ impl DBEngine {
                 .await
                 .cached_row_count(&segment_path)
         };
-        let start_index = match cached_start_index {
-            Some(count) => count,
+
+        match cached_start_index {
+            Some(count) => Ok(count),
             None => {
                 let meta = self.read_segment_meta(&meta_path).await?;
                 let start_index = meta.next_row_index;
                 self.row_buffer_pool
                     .lock()
                     .await
-                    .seed_row_count(segment_path.clone(), start_index);
-                start_index
+                    .seed_row_count(segment_path, start_index);
+                Ok(start_index)
             }
-        };
+        }
+    }
 
-        // 위치가 확정된 뒤, 아직 락을 쥔 상태에서 WAL을 기록합니다. 실패하면
-        // 행을 추가하지 않고 그대로 반환하므로 WAL과 데이터가 어긋나지 않습니다.
-        if let Some(on_reserved) = on_reserved {
-            on_reserved(start_index).await?;
+    /// 행을 버퍼에 추가합니다. 호출자가 이미 `row_storage_lock`을 쥐고 있어야 하며,
+    /// 추가될 위치는 `next_row_index_locked`로 미리 알 수 있습니다.
+    pub(crate) async fn append_table_rows_locked(
+        &self,
+        table_name: &TableName,
+        rows: &[TableDataRow],
+        buffer_limit_bytes: usize,
+    ) -> errors::Result<()> {
+        if rows.is_empty() {
+            return Ok(());
         }
 
-        let frame = encode_live_row_frames(rows)?;
+        self.record_transaction_write_locked(table_name).await?;
+        self.reserve_transaction_memory(rows)?;
+
+        let segment_path = self.row_segment_path(table_name)?;
+        let xmin = Self::row_version_transaction_id();
+        let rows: Vec<RowVersion> = rows
+            .iter()
+            .cloned()
+            .map(|row| RowVersion {
+                xmin,
+                xmax: None,
+                row,
+            })
+            .collect();
+        let frame = encode_row_versions(&rows)?;
 
         let buffered_bytes = {
             self.row_buffer_pool
@@ -208,11 +347,13 @@ impl DBEngine {
                 .append_rows(segment_path, rows, frame)
         };
 
+        // 열린 트랜잭션이 쌓은 행은 flush에서 빠지고 버퍼에 남습니다.
         if buffered_bytes >= buffer_limit_bytes {
-            self.flush_row_buffers_locked(false).await?;
+            self.flush_row_buffers_locked(false, &self.transaction_manager.open_writers())
+                .await?;
         }
 
-        Ok(start_index)
+        Ok(())
     }
 
     pub(crate) async fn update_table_rows(
@@ -225,18 +366,27 @@ impl DBEngine {
         }
 
         let _guard = self.row_storage_lock.lock().await;
+        self.update_table_rows_locked(table_name, replacements)
+            .await
+    }
+
+    /// `update_table_rows`와 같지만 호출자가 이미 `row_storage_lock`을 쥐고 있어야 합니다.
+    pub(crate) async fn update_table_rows_locked(
+        &self,
+        table_name: &TableName,
+        replacements: HashMap<usize, TableDataRow>,
+    ) -> errors::Result<()> {
+        if replacements.is_empty() {
+            return Ok(());
+        }
+
+        self.record_transaction_write_locked(table_name).await?;
+        self.reserve_transaction_memory(replacements.values())?;
+
         let segment_path = self.row_segment_path(table_name)?;
-        let cached_rows = { self.row_buffer_pool.lock().await.cached_rows(&segment_path) };
-        let mut rows = match cached_rows {
-            Some(rows) => rows,
-            None => {
-                let disk_rows = self.read_segment_rows(&segment_path).await?;
-                self.row_buffer_pool
-                    .lock()
-                    .await
-                    .read_rows(segment_path.clone(), || disk_rows)
-            }
-        };
+        let mut rows = self.read_table_rows_locked(table_name).await?;
+        self.check_concurrent_update_locked(&segment_path, replacements.keys().copied())
+            .await?;
 
         for (row_index, row) in replacements {
             let target = rows.get_mut(row_index).ok_or_else(|| {
@@ -253,10 +403,7 @@ impl DBEngine {
             *target = Some(row);
         }
 
-        self.row_buffer_pool
-            .lock()
-            .await
-            .replace_rows(segment_path, rows);
+        self.replace_table_rows_locked(segment_path, rows).await;
 
         Ok(())
     }
@@ -273,18 +420,12 @@ impl DBEngine {
         }
 
         let _guard = self.row_storage_lock.lock().await;
+        self.record_transaction_write_locked(table_name).await?;
+
         let segment_path = self.row_segment_path(table_name)?;
-        let cached_rows = { self.row_buffer_pool.lock().await.cached_rows(&segment_path) };
-        let mut rows = match cached_rows {
-            Some(rows) => rows,
-            None => {
-                let disk_rows = self.read_segment_rows(&segment_path).await?;
-                self.row_buffer_pool
-                    .lock()
-                    .await
-                    .read_rows(segment_path.clone(), || disk_rows)
-            }
-        };
+        let mut rows = self.read_table_rows_locked(table_name).await?;
+        self.check_concurrent_update_locked(&segment_path, row_indexes.iter().copied())
+            .await?;
 
         for row_index in row_indexes {
             let target = rows.get_mut(row_index).ok_or_else(|| {
@@ -293,20 +434,231 @@ impl DBEngine {
             *target = None;
         }
 
+        self.replace_table_rows_locked(segment_path, rows).await;
+
+        Ok(())
+    }
+
+    /// 최신 버전과 달라진 행마다 실행 중인 명령의 행 버전을 쌓습니다.
+    async fn replace_table_rows_locked(
+        &self,
+        segment_path: PathBuf,
+        rows: Vec<Option<TableDataRow>>,
+    ) {
+        let horizon = self.transaction_manager.horizon();
+        self.row_buffer_pool.lock().await.replace_rows(
+            segment_path,
+            rows,
+            Self::row_version_transaction_id(),
+            horizon,
+        );
+    }
+
+    /// 트랜잭션 블록이 버퍼에 쌓을 행 버전만큼 `transaction_memory_limit`에서 덜어 냅니다.
+    /// 커밋되지 않은 버전은 디스크에 내려가지 않으므로, 한도를 넘는 명령은 실패시킵니다.
+    fn reserve_transaction_memory<'a>(
+        &self,
+        rows: impl IntoIterator<Item = &'a TableDataRow>,
+    ) -> errors::Result<()> {
+        let Some(transaction_id) = self.transaction_manager.writer_transaction_id() else {
+            return Ok(());
+        };
+
+        let bytes = rows
+            .into_iter()
+            .flat_map(|row| &row.fields)
+            .map(|field| estimated_size(&field.data))
+            .sum();
+        let limit = self.config.transaction_memory_limit;
+
+        if !self
+            .transaction_manager
+            .reserve_buffered_bytes(transaction_id, bytes, limit)
+        {
+            return Err(ExecuteError::wrap(format!(
+                "transaction changes exceed transaction_memory_limit ({limit} bytes)"
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// 실행 중인 명령의 transaction id. 명령 밖(WAL replay 등)에서 쓴 행은 모든 스냅샷에 보입니다.
+    fn row_version_transaction_id() -> u64 {
+        StatementContext::current()
+            .and_then(|context| context.transaction_id)
+            .unwrap_or(FROZEN_TRANSACTION_ID)
+    }
+
+    /// REPEATABLE READ 트랜잭션이 스냅샷을 찍은 뒤 다른 트랜잭션이 바꾸거나 지운 행을
+    /// 고치려 하면, PostgreSQL과 같이 직렬화 오류로 실패시킵니다.
+    async fn check_concurrent_update_locked(
+        &self,
+        segment_path: &PathBuf,
+        row_indexes: impl IntoIterator<Item = usize>,
+    ) -> errors::Result<()> {
+        let Some(context) = StatementContext::current() else {
+            return Ok(());
+        };
+
+        if context.isolation_level != IsolationLevel::RepeatableRead {
+            return Ok(());
+        }
+
+        let row_buffer_pool = self.row_buffer_pool.lock().await;
+        if row_indexes.into_iter().any(|row_index| {
+            row_buffer_pool.changed_since_snapshot(segment_path, row_index, &context.snapshot)
+        }) {
+            return Err(ExecuteError::wrap(
+                "could not serialize access due to concurrent update".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// FOR UPDATE / FOR SHARE로 잠근 행을 스냅샷을 찍은 뒤 다른 트랜잭션이 바꿨다면 최신
+    /// 버전(지워졌으면 `Some(None)`)을 반환합니다. 바뀌지 않았으면 None입니다.
+    ///
+    /// 락을 쥔 뒤에 호출하므로 최신 버전은 이미 커밋된 버전입니다. REPEATABLE READ는
+    /// 스냅샷 밖의 버전을 읽을 수 없으므로 직렬화 오류로 실패합니다.
+    pub(crate) async fn refetch_locked_row(
+        &self,
+        table_name: &TableName,
+        row_index: usize,
+    ) -> errors::Result<Option<Option<TableDataRow>>> {
+        let Some(context) = StatementContext::current() else {
+            return Ok(None);
+        };
+
+        let _guard = self.row_storage_lock.lock().await;
+        // 잠근 행은 방금 스냅샷으로 읽었으므로 세그먼트가 버퍼에 올라와 있습니다.
+        let segment_path = self.row_segment_path(table_name)?;
+        let row_buffer_pool = self.row_buffer_pool.lock().await;
+        if !row_buffer_pool.changed_since_snapshot(&segment_path, row_index, &context.snapshot) {
+            return Ok(None);
+        }
+
+        if context.isolation_level == IsolationLevel::RepeatableRead {
+            return Err(ExecuteError::wrap(
+                "could not serialize access due to concurrent update".to_string(),
+            ));
+        }
+
+        Ok(Some(row_buffer_pool.latest_row(&segment_path, row_index)))
+    }
+
+    /// TRUNCATE: 행 세그먼트와 meta.bin을 빈 파일로 교체하고 row index를 0부터 다시 씁니다.
+    ///
+    /// 두 파일 모두 임시 파일에 쓴 뒤 rename하므로 각각은 원자적으로 바뀝니다. 두 rename
+    /// 사이에 크래시하더라도 WAL에 먼저 기록된 TRUNCATE를 replay하면 다시 맞춰집니다.
+    /// 트랜잭션 블록 안에서는 파일을 건드리지 않고 버퍼만 비우며, 커밋 뒤 flush에서 반영됩니다.
+    /// 비우기 전의 행은 TRUNCATE가 아직 보이지 않는 스냅샷을 위해 버퍼에 남겨 둡니다.
+    /// 호출자가 이미 `row_storage_lock`을 쥐고 있어야 합니다.
+    pub(crate) async fn truncate_table_rows_locked(
+        &self,
+        table_name: &TableName,
+    ) -> errors::Result<()> {
+        let segment_path = self.row_segment_path(table_name)?;
+        let transaction_id = Self::row_version_transaction_id();
+
+        if transaction_id != FROZEN_TRANSACTION_ID {
+            self.record_transaction_write_locked(table_name).await?;
+            self.read_table_rows_locked(table_name).await?;
+        }
+
+        if self.transaction_manager.writer_transaction_id().is_some() {
+            self.row_buffer_pool
+                .lock()
+                .await
+                .truncate_rows(segment_path, transaction_id, false);
+            return Ok(());
+        }
+
+        if let Some(parent) = segment_path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|error| ExecuteError::wrap(error.to_string()))?;
+        }
+
+        let temp_path = segment_path.with_extension("rows.tmp");
+        tokio::fs::write(&temp_path, [])
+            .await
+            .map_err(|error| ExecuteError::wrap(error.to_string()))?;
+        tokio::fs::rename(&temp_path, &segment_path)
+            .await
+            .map_err(|error| ExecuteError::wrap(error.to_string()))?;
+
+        self.write_segment_meta(
+            &self.row_segment_meta_path_from_segment_path(&segment_path),
+            &RowSegmentMeta::default(),
+        )
+        .await?;
+
         self.row_buffer_pool
             .lock()
             .await
-            .replace_rows(segment_path, rows);
+            .truncate_rows(segment_path, transaction_id, true);
 
         Ok(())
     }
 
-    /// Reads every frame in the segment in order. `None` marks a tombstoned
-    /// row; the position in the returned Vec is its stable row index.
-    async fn read_segment_rows(
+    /// 버퍼(없으면 디스크)에서 테이블의 모든 행을 읽습니다. `None`은 삭제된 행이며,
+    /// 위치가 곧 row index입니다. 호출자가 이미 `row_storage_lock`을 쥐고 있어야 합니다.
+    pub(crate) async fn read_table_rows_locked(
         &self,
-        segment_path: &Path,
+        table_name: &TableName,
     ) -> errors::Result<Vec<Option<TableDataRow>>> {
+        let segment_path = self.row_segment_path(table_name)?;
+        let cached_rows = { self.row_buffer_pool.lock().await.cached_rows(&segment_path) };
+
+        match cached_rows {
+            Some(rows) => Ok(rows),
+            None => {
+                let disk_rows = self.read_segment_rows(&segment_path).await?;
+                Ok(self
+                    .row_buffer_pool
+                    .lock()
+                    .await
+                    .read_rows(segment_path, || disk_rows))
+            }
+        }
+    }
+
+    /// 버퍼(없으면 디스크)에서 스냅샷에 보이는 행과, 그중 최신 버전이 아닌 행의
+    /// row index를 읽습니다. 호출자가 이미 `row_storage_lock`을 쥐고 있어야 합니다.
+    async fn read_snapshot_rows_locked(
+        &self,
+        table_name: &TableName,
+        snapshot: &Snapshot,
+    ) -> errors::Result<(Vec<Option<TableDataRow>>, Vec<usize>)> {
+        let segment_path = self.row_segment_path(table_name)?;
+        let cached_rows = {
+            self.row_buffer_pool
+                .lock()
+                .await
+                .snapshot_rows(&segment_path, snapshot)
+        };
+
+        match cached_rows {
+            Some(rows) => Ok(rows),
+            None => {
+                self.read_table_rows_locked(table_name).await?;
+                Ok(self
+                    .row_buffer_pool
+                    .lock()
+                    .await
+                    .snapshot_rows(&segment_path, snapshot)
+                    .unwrap_or_default())
+            }
+        }
+    }
+
+    /// Reads every frame in the segment in order. An empty slot marks a
+    /// tombstoned row; the position in the returned Vec is its stable row
+    /// index. Frames written before row versioning carry no xmin and are
+    /// read as visible to every snapshot.
+    async fn read_segment_rows(&self, segment_path: &Path) -> errors::Result<Vec<RowSlot>> {
         let content = match tokio::fs::read(segment_path).await {
             Ok(content) => content,
             Err(error) if error.kind() == IOErrorKind::NotFound => return Ok(Vec::new()),
@@ -324,7 +676,7 @@ impl DBEngine {
                 ));
             }
 
-            let tombstoned = content[offset] != ROW_FRAME_LIVE;
+            let frame_type = content[offset];
             offset += size_of::<u8>();
 
             let frame_len = u32::from_le_bytes(
@@ -340,16 +692,39 @@ impl DBEngine {
                 ));
             }
 
-            if tombstoned {
-                rows.push(None);
-            } else {
-                let row = encoder
-                    .decode::<TableDataRow>(&content[offset..offset + frame_len])
-                    .map_err(|error| {
-                        ExecuteError::wrap(format!("invalid row segment frame: {}", error))
-                    })?;
-                rows.push(Some(row));
-            }
+            let frame = &content[offset..offset + frame_len];
+            let (xmin, xmax, body) = match frame_type {
+                ROW_FRAME_TOMBSTONE => {
+                    rows.push(vec![]);
+                    offset += frame_len;
+                    continue;
+                }
+                ROW_FRAME_LIVE => (FROZEN_TRANSACTION_ID, 0, frame),
+                ROW_FRAME_VERSIONED if frame_len >= 2 * size_of::<u64>() => {
+                    let (xmin, rest) = frame.split_at(size_of::<u64>());
+                    let (xmax, body) = rest.split_at(size_of::<u64>());
+                    (
+                        u64::from_le_bytes(xmin.try_into().unwrap_or_default()),
+                        u64::from_le_bytes(xmax.try_into().unwrap_or_default()),
+                        body,
+                    )
+                }
+                frame_type => {
+                    return Err(ExecuteError::wrap(format!(
+                        "invalid row segment frame type: {}",
+                        frame_type
+                    )));
+                }
+            };
+
+            let row = encoder.decode::<TableDataRow>(body).map_err(|error| {
+                ExecuteError::wrap(format!("invalid row segment frame: {}", error))
+            })?;
+            rows.push(vec![RowVersion {
+                xmin,
+                xmax: (xmax != 0).then_some(xmax),
+                row,
+            }]);
 
             offset += frame_len;
         }
@@ -360,16 +735,35 @@ impl DBEngine {
     #[cfg(test)]
     pub(crate) async fn flush_row_buffers(&self) -> errors::Result<()> {
         let _guard = self.row_storage_lock.lock().await;
-        self.flush_row_buffers_locked(false).await
+        self.flush_row_buffers_locked(false, &self.transaction_manager.open_writers())
+            .await
     }
 
     pub(crate) async fn flush_row_buffers_durable(&self) -> errors::Result<()> {
+        self.flush_committed_row_buffers_durable(&self.transaction_manager.open_writers())
+            .await
+    }
+
+    /// `open_transactions`의 변경을 뺀 커밋된 상태를 디스크에 내리고 fsync합니다.
+    /// WAL 체크포인트는 같은 트랜잭션 목록의 엔트리를 남겨 두어야 합니다.
+    pub(crate) async fn flush_committed_row_buffers_durable(
+        &self,
+        open_transactions: &HashSet<u64>,
+    ) -> errors::Result<()> {
         let _guard = self.row_storage_lock.lock().await;
-        self.flush_row_buffers_locked(true).await
+        self.flush_row_buffers_locked(true, open_transactions).await
     }
 
-    async fn flush_row_buffers_locked(&self, durable: bool) -> errors::Result<()> {
-        let pending = self.row_buffer_pool.lock().await.drain_writes()?;
+    async fn flush_row_buffers_locked(
+        &self,
+        durable: bool,
+        open_transactions: &HashSet<u64>,
+    ) -> errors::Result<()> {
+        let pending = self
+            .row_buffer_pool
+            .lock()
+            .await
+            .drain_writes(open_transactions)?;
 
         for write in pending {
             if let Err(error) = self.apply_row_buffer_write(&write, durable).await {
@@ -554,6 +948,13 @@ impl DBEngine {
 
         let row_paths: Vec<String> = match &plan.eq_key {
             Some(key) => self.index_manager.get(&plan.index_name, key).await?,
+            None if !plan.in_keys.is_empty() => {
+                let mut row_paths = vec![];
+                for key in &plan.in_keys {
+                    row_paths.extend(self.index_manager.get(&plan.index_name, key).await?);
+                }
+                row_paths
+            }
             None => self
                 .index_manager
                 .range(
@@ -567,36 +968,46 @@ impl DBEngine {
                 .collect(),
         };
 
-        if row_paths.is_empty() {
+        if row_paths.is_empty() && StatementContext::current().is_none() {
             return Ok(Vec::new());
         }
 
         let _guard = self.row_storage_lock.lock().await;
-        let segment_path = self.row_segment_path(&table_name)?;
-        let cached_rows = { self.row_buffer_pool.lock().await.cached_rows(&segment_path) };
-        let all_rows = match cached_rows {
-            Some(rows) => rows,
-            None => {
-                let disk_rows = self.read_segment_rows(&segment_path).await?;
-                self.row_buffer_pool
-                    .lock()
-                    .await
-                    .read_rows(segment_path, || disk_rows)
+        let context = StatementContext::current();
+
+        let mut row_indexes = row_paths
+            .into_iter()
+            .map(|row_path| {
+                row_path.parse::<usize>().map_err(|_| {
+                    ExecuteError::wrap(format!(
+                        "index '{}' has invalid row path '{}'",
+                        plan.index_name, row_path
+                    ))
+                })
+            })
+            .collect::<errors::Result<Vec<_>>>()?;
+
+        let all_rows = match &context {
+            // 인덱스는 최신 버전만 가리키므로, 스냅샷에 지난 버전이 보이는 행도 후보에
+            // 넣습니다. 조건에 맞지 않는 후보는 뒤의 WHERE 필터가 걸러냅니다.
+            Some(context) => {
+                let (rows, diverged) = self
+                    .read_snapshot_rows_locked(&table_name, &context.snapshot)
+                    .await?;
+                let mut seen: HashSet<usize> = row_indexes.iter().copied().collect();
+                row_indexes.extend(diverged.into_iter().filter(|index| seen.insert(*index)));
+                rows
             }
+            None => self.read_table_rows_locked(&table_name).await?,
         };
 
-        let mut result = Vec::with_capacity(row_paths.len());
-
-        for row_path in row_paths {
-            let row_index = row_path.parse::<usize>().map_err(|_| {
-                ExecuteError::wrap(format!(
-                    "index '{}' has invalid row path '{}'",
-                    plan.index_name, row_path
-                ))
-            })?;
+        let mut result = Vec::with_capacity(row_indexes.len());
 
+        for row_index in row_indexes {
             match all_rows.get(row_index) {
                 Some(Some(row)) => result.push((RowLocation { row_index }, row.clone())),
+                // 스냅샷 뒤에 추가된 행이나 이 스냅샷에서 이미 지워진 행입니다.
+                Some(None) if context.is_some() => {}
                 Some(None) | None => {
                     return Err(ExecuteError::wrap(format!(
                         "index '{}' is out of sync with table data; drop and recreate the index",
@@ -615,10 +1026,16 @@ mod tests {
     use std::collections::{HashMap, HashSet};
     use std::path::PathBuf;
 
+    use futures::TryStreamExt;
+
     use crate::config::launch_config::LaunchConfig;
     use crate::engine::DBEngine;
+    use crate::engine::ast::tcl::IsolationLevel;
     use crate::engine::ast::types::TableName;
+    use crate::engine::encoder::schema_encoder::StorageEncoder;
+    use crate::engine::row_buffer::{ROW_FRAME_LIVE, ROW_FRAME_TOMBSTONE};
     use crate::engine::schema::row::{TableDataField, TableDataFieldType, TableDataRow};
+    use crate::engine::transaction::StatementContext;
 
     #[tokio::test]
     async fn full_scan_reads_buffered_rows_without_flushing_segment_file() {
@@ -783,4 +1200,115 @@ mod tests {
         assert_eq!(scanned[2].0.row_index, 3);
         assert_eq!(scanned[2].1.fields[0].data, TableDataFieldType::Integer(4));
     }
+
+    #[tokio::test]
+    async fn full_scan_stream_matches_full_scan_across_batches() {
+        let base_path = PathBuf::from(format!(
+            "target/test_row_segments/scan_stream_batches_{}",
+            std::process::id()
+        ));
+        if base_path.exists() {
+            tokio::fs::remove_dir_all(&base_path).await.unwrap();
+        }
+
+        let config = LaunchConfig::default_for_base_path(&base_path);
+        let table_name = TableName::new(Some("rrdb".to_string()), "users".to_string());
+        let rows_path = PathBuf::from(&config.data_directory)
+            .join("rrdb")
+            .join("tables")
+            .join("users")
+            .join("rows");
+        tokio::fs::create_dir_all(&rows_path).await.unwrap();
+
+        let engine = DBEngine::new(config);
+        let row = |id| TableDataRow {
+            fields: vec![TableDataField {
+                table_name: table_name.clone(),
+                column_name: "id".to_string(),
+                data: TableDataFieldType::Integer(id),
+            }],
+        };
+
+        // 디스크에 내려간 행과 버퍼에만 있는 행이 배치 경계에 걸치도록 나눠 넣습니다.
+        let first = (0..1500).map(row).collect::<Vec<_>>();
+        engine.append_table_rows(&table_name, &first).await.unwrap();
+        engine.flush_row_buffers().await.unwrap();
+        let second = (1500..2500).map(row).collect::<Vec<_>>();
+        engine
+            .append_table_rows(&table_name, &second)
+            .await
+            .unwrap();
+        engine
+            .delete_table_rows(&table_name, HashSet::from([1usize, 1024, 2000]))
+            .await
+            .unwrap();
+
+        let streamed = engine
+            .full_scan_stream(table_name.clone())
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        let scanned = engine.full_scan(table_name).await.unwrap();
+
+        assert_eq!(streamed.len(), 2497);
+        assert_eq!(streamed, scanned);
+    }
+
+    #[tokio::test]
+    async fn legacy_live_frames_are_visible_to_every_snapshot() {
+        let base_path = PathBuf::from(format!(
+            "target/test_row_segments/legacy_live_frames_{}",
+            std::process::id()
+        ));
+        if base_path.exists() {
+            tokio::fs::remove_dir_all(&base_path).await.unwrap();
+        }
+
+        let config = LaunchConfig::default_for_base_path(&base_path);
+        let table_name = TableName::new(Some("rrdb".to_string()), "users".to_string());
+        let engine = DBEngine::new(config);
+        let row = |id| TableDataRow {
+            fields: vec![TableDataField {
+                table_name: table_name.clone(),
+                column_name: "id".to_string(),
+                data: TableDataFieldType::Integer(id),
+            }],
+        };
+
+        // 행 버전이 생기기 전 형식: [LIVE][len][row], [TOMBSTONE][0]
+        let encoder = StorageEncoder::new();
+        let mut content = Vec::new();
+        for frame in [Some(row(1)), None, Some(row(3))] {
+            match frame {
+                Some(row) => {
+                    let body = encoder.encode(row);
+                    content.push(ROW_FRAME_LIVE);
+                    content.extend_from_slice(&(body.len() as u32).to_le_bytes());
+                    content.extend_from_slice(&body);
+                }
+                None => {
+                    content.push(ROW_FRAME_TOMBSTONE);
+                    content.extend_from_slice(&0u32.to_le_bytes());
+                }
+            }
+        }
+        let segment_path = engine.row_segment_path(&table_name).unwrap();
+        tokio::fs::create_dir_all(segment_path.parent().unwrap())
+            .await
+            .unwrap();
+        tokio::fs::write(&segment_path, content).await.unwrap();
+
+        let context = StatementContext {
+            transaction_id: None,
+            snapshot: engine.transaction_manager.snapshot(None).await.unwrap(),
+            isolation_level: IsolationLevel::ReadCommitted,
+        };
+        let scanned = context.scope(engine.full_scan(table_name)).await.unwrap();
+
+        assert_eq!(scanned.len(), 2);
+        assert_eq!(scanned[0].0.row_index, 0);
+        assert_eq!(scanned[0].1.fields[0].data, TableDataFieldType::Integer(1));
+        assert_eq!(scanned[1].0.row_index, 2);
+        assert_eq!(scanned[1].1.fields[0].data, TableDataFieldType::Integer(3));
+    }
 }
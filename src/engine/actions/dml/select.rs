@@ -1,28 +1,59 @@
 use std::cmp::Ordering;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use futures::future::join_all;
+use futures::{StreamExt, TryStreamExt, stream};
 
 use crate::engine::DBEngine;
+use crate::engine::actions::dml::aggregate::AggregateContext;
+use crate::engine::actions::dml::common_table::rebind_common_tables;
+use crate::engine::actions::dml::join::JoinContext;
+use crate::engine::actions::dml::locking::with_row_location;
+use crate::engine::actions::dml::set_operation::set_operation_columns;
+use crate::engine::actions::dml::window::WindowContext;
 use crate::engine::ast::dml::parts::from::FromTarget;
-use crate::engine::ast::dml::parts::order_by::{OrderByNulls, OrderByType};
+use crate::engine::ast::dml::parts::join::JoinType;
+use crate::engine::ast::dml::parts::order_by::{OrderByItem, OrderByNulls, OrderByType};
 use crate::engine::ast::dml::parts::select_item::{SelectItem, SelectKind};
 use crate::engine::ast::dml::plan::select::scan::ScanType;
 use crate::engine::ast::dml::plan::select::select_plan::SelectPlanItem;
 use crate::engine::ast::dml::select::SelectQuery;
-use crate::engine::ast::types::{SQLExpression, SelectColumn, TableName};
+use crate::engine::ast::types::{Column, SQLExpression, SelectColumn, TableName};
 use crate::engine::expression::ReduceContext;
 use crate::engine::optimizer::predule::Optimizer;
 use crate::engine::schema::row::{TableDataField, TableDataFieldType, TableDataRow};
-use crate::engine::types::{ExecuteColumn, ExecuteField, ExecuteResult, ExecuteRow};
+use crate::engine::schema::table::TableSchema;
+use crate::engine::types::{
+    ExecuteColumn, ExecuteColumnType, ExecuteField, ExecuteResult, ExecuteRow,
+};
 use crate::errors;
+use crate::errors::execute_error::ExecuteError;
 use crate::errors::type_error::TypeError;
 
 impl DBEngine {
     pub async fn describe_select_columns(
         &self,
-        query: SelectQuery,
+        mut query: SelectQuery,
     ) -> errors::Result<Vec<ExecuteColumn>> {
+        if query.with_clause.is_some() {
+            rebind_common_tables(&mut query);
+            let with_clause = query.with_clause.take();
+
+            return self
+                .with_common_table_schemas(
+                    with_clause,
+                    Box::pin(self.describe_select_columns(query)),
+                )
+                .await;
+        }
+
+        if let Some(set_operation) = query.set_operation {
+            let left = Box::pin(self.describe_select_columns(*set_operation.left)).await?;
+            let right = Box::pin(self.describe_select_columns(*set_operation.right)).await?;
+
+            return set_operation_columns(&set_operation.operator, &left, &right);
+        }
+
         let mut table_alias_map = HashMap::new();
         let mut table_infos = vec![];
 
@@ -35,10 +66,14 @@ impl DBEngine {
                     }
                     table_infos.push(table_config);
                 }
-                FromTarget::Subquery(_) => {
-                    return Err(crate::errors::execute_error::ExecuteError::wrap(
-                        "subquery describe is not implemented",
-                    ));
+                FromTarget::Subquery(subquery) => {
+                    let Some(alias) = from.alias else {
+                        return Err(ExecuteError::wrap("subquery in FROM must have an alias"));
+                    };
+
+                    let columns =
+                        Box::pin(self.describe_select_columns(subquery.into_query())).await?;
+                    table_infos.push(TableSchema::derived(TableName::new(None, alias), &columns));
                 }
             }
         }
@@ -63,95 +98,75 @@ impl DBEngine {
             })
             .collect::<Vec<_>>();
 
-        let select_items = query
-            .select_items
-            .into_iter()
-            .flat_map(|e| match e {
-                SelectKind::SelectItem(item) => vec![item],
-                SelectKind::WildCard(wildcard) => match wildcard.alias {
-                    Some(alias) => match table_alias_map.get(&alias) {
-                        Some(found_table_name) => config_columns
-                            .iter()
-                            .filter(|(table_name, _)| {
-                                found_table_name.table_name == table_name.table_name
-                            })
-                            .map(|(table_name, column_name)| {
-                                SelectItem::builder()
-                                    .set_item(
-                                        SelectColumn::new(
-                                            Some(table_name.table_name.clone()),
-                                            column_name.name.clone(),
-                                        )
-                                        .into(),
-                                    )
-                                    .build()
-                            })
-                            .collect(),
-                        None => config_columns
-                            .iter()
-                            .filter(|(table_name, _)| alias == table_name.table_name)
-                            .map(|(table_name, column_name)| {
-                                SelectItem::builder()
-                                    .set_item(
-                                        SelectColumn::new(
-                                            Some(table_name.table_name.clone()),
-                                            column_name.name.clone(),
-                                        )
-                                        .into(),
-                                    )
-                                    .build()
-                            })
-                            .collect(),
-                    },
-                    None => config_columns
-                        .iter()
-                        .map(|(table_name, column_name)| {
-                            SelectItem::builder()
-                                .set_item(
-                                    SelectColumn::new(
-                                        Some(table_name.table_name.clone()),
-                                        column_name.name.clone(),
-                                    )
-                                    .into(),
-                                )
-                                .build()
-                        })
-                        .collect(),
-                },
-            })
-            .collect::<Vec<_>>();
+        let select_items =
+            expand_select_items(query.select_items, &config_columns, &table_alias_map);
+
+        for select_item in &select_items {
+            self.cache_subquery_schemas(select_item.item.as_ref().unwrap())
+                .await?;
+        }
 
         let reduce_context = ReduceContext {
             row: None,
             table_alias_map,
             config_columns,
-            total_count: 0,
+            outer: None,
         };
 
-        select_items
-            .into_iter()
-            .map(|e| {
-                let item = e.item.unwrap();
-                let name = match e.alias {
-                    Some(alias) => alias,
-                    None => match &item {
-                        SQLExpression::SelectColumn(column) => column.column_name.to_owned(),
-                        _ => "?column?".into(),
-                    },
-                };
-                let data_type = self.reduce_type(item, reduce_context.clone())?;
-
-                Ok::<ExecuteColumn, errors::Errors>(ExecuteColumn { name, data_type })
-            })
-            .collect::<Result<Vec<_>, _>>()
+        let mut columns = vec![];
+
+        for e in select_items {
+            let item = e.item.unwrap();
+            let name = match e.alias {
+                Some(alias) => alias,
+                None => match &item {
+                    SQLExpression::SelectColumn(column) => column.column_name.to_owned(),
+                    _ => "?column?".into(),
+                },
+            };
+            let data_type = self.reduce_type(item, reduce_context.clone()).await?;
+
+            columns.push(ExecuteColumn { name, data_type });
+        }
+
+        Ok(columns)
     }
 
     pub async fn select(&self, query: SelectQuery) -> errors::Result<ExecuteResult> {
+        self.select_with_outer(query, None).await
+    }
+
+    /// SELECT 실행. outer가 있다면 상관 서브쿼리로서 바깥 쿼리의 행을 참조할 수 있습니다.
+    pub(crate) async fn select_with_outer(
+        &self,
+        mut query: SelectQuery,
+        outer: Option<ReduceContext>,
+    ) -> errors::Result<ExecuteResult> {
+        // WITH 절 테이블을 먼저 실행해 등록합니다. 바깥 행은 WITH 쿼리에서도 참조할 수 있습니다.
+        if query.with_clause.is_some() {
+            rebind_common_tables(&mut query);
+            let with_clause = query.with_clause.take();
+
+            return self
+                .with_common_tables(
+                    with_clause,
+                    outer.clone(),
+                    Box::pin(self.select_with_outer(query, outer)),
+                )
+                .await;
+        }
+
+        let outer = outer.map(Box::new);
+
         // 최적화 작업 (FROM 대상 테이블의 인덱스/통계로 컨텍스트 구성)
         let optimizer = match &query.from_table {
             Some(from_clause) => match &from_clause.from {
                 FromTarget::Table(table_name) => {
-                    Optimizer::with_context(self.build_optimizer_context(table_name).await)
+                    let mut context = self.build_optimizer_context(table_name).await;
+                    if !query.join_clause.is_empty() {
+                        context.relation_statistics = self.join_relation_statistics(&query).await;
+                    }
+                    Optimizer::with_context(context)
                 }
                 FromTarget::Subquery(_) => Optimizer::new(),
             },
@@ -160,23 +175,59 @@ impl DBEngine {
 
         let select_items = query.select_items.clone();
 
+        // 조인 순서가 바뀌어도 * 는 작성된 테이블 순서대로 펼칩니다.
+        let written_table_order = query
+            .from_table
+            .iter()
+            .filter_map(|from| match &from.from {
+                FromTarget::Table(table_name) => Some(table_name.clone()),
+                FromTarget::Subquery(_) => from
+                    .alias
+                    .as_ref()
+                    .map(|alias| TableName::new(None, alias.clone())),
+            })
+            .chain(query.join_clause.iter().map(|join| join.right.clone()))
+            .collect::<Vec<_>>();
+
+        let no_from_clause = query.from_table.is_none() && query.set_operation.is_none();
+
         let plan = optimizer.optimize_select(query).await?;
 
+        // FOR UPDATE / FOR SHARE는 스캔한 행의 위치를 알아야 잠글 수 있습니다.
+        let locks_rows = plan
+            .list
+            .iter()
+            .any(|each_plan| matches!(each_plan, SelectPlanItem::LockRows(_)));
+
+        // FROM 풀 스캔 뒤에 WHERE와 집계만 오면, 스캔한 행을 모아두지 않고
+        // WHERE로 거르면서 바로 집계에 흘려보냅니다.
+        let streams_into_aggregate = !locks_rows && scan_feeds_aggregate(&plan.list);
+        let mut deferred_scan = None;
+        let mut deferred_filters = vec![];
+
         let mut table_alias_map = HashMap::new();
-        let mut table_alias_reverse_map = HashMap::new();
         let mut table_infos = vec![];
 
         let mut rows = vec![];
-        let mut aggregate_total_count = None;
 
-        let mut no_from_clause = true;
+        // 집합 연산의 결과 컬럼 (결과 행은 이미 이 순서대로 계산되어 있음)
+        let mut compound_columns = None;
+
+        // FROM이 없으면 한 행짜리 입력으로 계산합니다. (집계도 이 한 행을 대상으로 합니다)
+        if no_from_clause {
+            rows.push(TableDataRow {
+                fields: vec![TableDataField {
+                    table_name: TableName::new(None, "no_table".into()),
+                    column_name: "result".into(),
+                    data: TableDataFieldType::Null,
+                }],
+            });
+        }
 
         for each_plan in plan.list {
             match each_plan {
                 // Select From 처리
                 SelectPlanItem::From(from) => {
-                    no_from_clause = false;
-
                     let table_name = from.table_name.clone();
 
                     let table_config = self.get_table_config_cached(table_name.clone()).await?;
@@ -184,56 +235,118 @@ impl DBEngine {
                     table_infos.push(table_config);
 
                     if let Some(alias) = from.alias {
-                        table_alias_map.insert(alias.clone(), table_name.clone());
-                        table_alias_reverse_map.insert(table_name.clone().table_name, alias);
+                        table_alias_map.insert(alias, table_name.clone());
+                    }
+
+                    if streams_into_aggregate {
+                        deferred_scan = Some(table_name);
+                        continue;
                     }
 
-                    match from.scan {
+                    let scanned = match from.scan {
                         ScanType::FullScan => {
-                            let mut result = self
-                                .full_scan_limited(table_name, from.scan_limit)
-                                .await?
-                                .into_iter()
-                                .map(|(_, e)| e)
-                                .collect();
-
-                            rows.append(&mut result);
+                            self.full_scan_limited(table_name, from.scan_limit).await?
                         }
                         ScanType::IndexScan(index_scan_plan) => {
-                            let mut result = self
-                                .index_scan(table_name, &index_scan_plan)
-                                .await?
-                                .into_iter()
-                                .map(|(_, row)| row)
-                                .collect();
-
-                            rows.append(&mut result);
+                            self.index_scan(table_name, &index_scan_plan).await?
+                        }
+                    };
+
+                    rows.extend(scanned.into_iter().map(|(location, row)| {
+                        if locks_rows {
+                            with_row_location(row, &location)
+                        } else {
+                            row
                         }
+                    }));
+                }
+                // 파생 테이블 처리
+                SelectPlanItem::Subquery(derived) => {
+                    let (table_config, mut result) = self.derived_table(derived).await?;
+
+                    table_infos.push(table_config);
+                    rows.append(&mut result);
+                }
+                SelectPlanItem::SetOperation(set_operation) => {
+                    let (columns, mut result) =
+                        self.set_operation(set_operation, outer.clone()).await?;
+
+                    compound_columns = Some(columns);
+                    rows.append(&mut result);
+                }
+                SelectPlanItem::Join(join) => {
+                    let right_config = self.get_table_config_cached(join.right.clone()).await?;
+
+                    // 세미/안티 조인의 우측 테이블은 결과 행에 포함되지 않으므로
+                    // ON 절 평가에만 alias를 사용합니다.
+                    let filters_only = matches!(
+                        join.join_type,
+                        JoinType::LeftSemiJoin | JoinType::LeftAntiJoin
+                    );
+
+                    let mut join_alias_map = table_alias_map.clone();
+
+                    if let Some(alias) = &join.right_alias {
+                        join_alias_map.insert(alias.clone(), join.right.clone());
+
+                        if !filters_only {
+                            table_alias_map.insert(alias.clone(), join.right.clone());
+                        }
+                    }
+
+                    let join_context = JoinContext {
+                        table_alias_map: join_alias_map,
+                        left_columns: table_infos
+                            .iter()
+                            .flat_map(|table_info: &TableSchema| {
+                                table_info
+                                    .columns
+                                    .iter()
+                                    .map(|column| (table_info.table.clone(), column.clone()))
+                            })
+                            .collect(),
+                        right_columns: right_config
+                            .columns
+                            .iter()
+                            .map(|column| (right_config.table.clone(), column.clone()))
+                            .collect(),
+                        outer: outer.clone(),
+                    };
+
+                    if !filters_only {
+                        table_infos.push(right_config);
                     }
+
+                    let right_rows = self
+                        .full_scan(join.right.clone())
+                        .await?
+                        .into_iter()
+                        .map(|(_, row)| row)
+                        .collect();
+
+                    rows = self
+                        .join_rows(rows, right_rows, &join, &join_context)
+                        .await?;
                 }
-                SelectPlanItem::Filter(filter) => {
+                SelectPlanItem::Filter(mut filter) => {
+                    filter.expression = self.materialize_subqueries(filter.expression).await?;
+
+                    // 집계로 흘려보낼 스캔이면 행마다 스트림에서 거릅니다.
+                    if deferred_scan.is_some() {
+                        deferred_filters.push(filter.expression);
+                        continue;
+                    }
+
                     let futures = rows.iter().cloned().map(|e| {
-                        let table_alias_map = table_alias_map.clone();
-                        let filter = filter.clone();
+                        let filter = &filter;
+                        let table_alias_map = &table_alias_map;
+                        let outer = &outer;
                         async move {
-                            let reduce_context = ReduceContext {
-                                row: Some(e.to_owned()),
-                                table_alias_map,
-                                config_columns: vec![],
-                                total_count: 0,
-                            };
-
-                            let condition = self
-                                .reduce_expression(filter.expression.clone(), reduce_context)
+                            let matched = self
+                                .row_matches_filter(&e, &filter.expression, table_alias_map, outer)
                                 .await?;
 
-                            match condition {
-                                TableDataFieldType::Boolean(boolean) => Ok((e, boolean)),
-                                TableDataFieldType::Null => Ok((e, false)),
-                                _ => Err(TypeError::wrap(
-                                    "condition expression is valid only for boolean and null types",
-                                )),
-                            }
+                            errors::Result::Ok((e, matched))
                         }
                     });
 
@@ -248,90 +361,130 @@ impl DBEngine {
                         .map(|(e, _)| e)
                         .collect();
                 }
-                SelectPlanItem::Group(ref group_by_clause) => {
-                    let mut grouped_map =
-                        HashMap::<Vec<TableDataField>, Vec<TableDataField>>::new();
-
-                    for row in rows {
-                        let mut group_key = vec![];
-                        let mut group_value = vec![];
-
-                        for field in row.fields {
-                            // group by 절에 포함된 컬럼일 경우 키값으로 사용
-                            if let Some(_found) = group_by_clause.group_by_items.iter().find(|e| {
-                                let mut table_name_matched = false;
-
-                                if let Some(table_name) = &e.item.table_name {
-                                    if table_name == &field.table_name.table_name {
-                                        table_name_matched = true;
-                                    } else if let Some(table_name) = table_alias_map.get(table_name)
-                                    {
-                                        if table_name.table_name == field.table_name.table_name {
-                                            table_name_matched = true;
+                SelectPlanItem::Aggregate(ref aggregate) => {
+                    let aggregate_context = AggregateContext {
+                        table_alias_map: table_alias_map.clone(),
+                        outer: outer.clone(),
+                    };
+
+                    let input = match deferred_scan.take() {
+                        Some(table_name) => {
+                            let filters = &deferred_filters;
+                            let table_alias_map = &table_alias_map;
+                            let outer = &outer;
+
+                            self.full_scan_stream(table_name)
+                                .try_filter_map(move |(_, row)| async move {
+                                    for filter in filters {
+                                        if !self
+                                            .row_matches_filter(
+                                                &row,
+                                                filter,
+                                                table_alias_map,
+                                                outer,
+                                            )
+                                            .await?
+                                        {
+                                            return Ok(None);
                                         }
-                                    } else if let Some(table_name) =
-                                        table_alias_reverse_map.get(table_name)
-                                        && table_name == &field.table_name.table_name
-                                    {
-                                        table_name_matched = true;
                                     }
-                                } else {
-                                    table_name_matched = true;
-                                }
 
-                                e.item.column_name == field.column_name && table_name_matched
-                            }) {
-                                group_key.push(field);
-                            }
-                            // 미포함된 컬럼일 경우 배열 형태로 값 중첩
-                            else {
-                                group_value.push(field);
-                            }
+                                    Ok(Some(row))
+                                })
+                                .left_stream()
                         }
+                        None => stream::iter(std::mem::take(&mut rows).into_iter().map(Ok))
+                            .right_stream(),
+                    };
 
-                        match grouped_map.get_mut(&group_key) {
-                            Some(value) => {
-                                for i in 0..value.len() {
-                                    value[i].push(group_value[i].data.clone());
-                                }
-                            }
-                            None => {
-                                grouped_map.insert(
-                                    group_key,
-                                    group_value
-                                        .into_iter()
-                                        .map(|e| e.to_array())
-                                        .collect::<Vec<_>>(),
+                    rows = self
+                        .hash_aggregate(input, aggregate, &aggregate_context)
+                        .await?;
+                }
+                SelectPlanItem::Window(ref window) => {
+                    let window_context = WindowContext {
+                        table_alias_map: table_alias_map.clone(),
+                        outer: outer.clone(),
+                    };
+
+                    rows = self.window(rows, window, &window_context).await?;
+                }
+                SelectPlanItem::Distinct(distinct) => {
+                    // 중복 판단 키: DISTINCT ON 표현식, 없으면 펼친 SELECT 목록 전체
+                    let key_items = match distinct.on {
+                        Some(on) => on,
+                        None => {
+                            let config_columns = table_infos
+                                .iter()
+                                .flat_map(|table_info: &TableSchema| {
+                                    table_info
+                                        .columns
+                                        .iter()
+                                        .map(|column| (table_info.table.clone(), column.clone()))
+                                })
+                                .collect::<Vec<_>>();
+
+                            expand_select_items(
+                                select_items.clone(),
+                                &config_columns,
+                                &table_alias_map,
+                            )
+                            .into_iter()
+                            .filter_map(|select_item| select_item.item)
+                            .collect()
+                        }
+                    };
+
+                    let mut key_expressions = vec![];
+                    for item in key_items {
+                        key_expressions.push(self.materialize_subqueries(item).await?);
+                    }
+
+                    let futures = rows.into_iter().map(|e| {
+                        let table_alias_map = table_alias_map.clone();
+                        let outer = outer.clone();
+                        let key_expressions = &key_expressions;
+
+                        async move {
+                            let reduce_context = ReduceContext {
+                                row: Some(e.to_owned()),
+                                table_alias_map,
+                                config_columns: vec![],
+                                outer,
+                            };
+
+                            let mut key = vec![];
+                            for expression in key_expressions {
+                                key.push(
+                                    self.reduce_expression(
+                                        expression.clone(),
+                                        reduce_context.clone(),
+                                    )
+                                    .await?,
                                 );
                             }
+
+                            Ok::<_, errors::Errors>((e, key))
                         }
-                    }
+                    });
 
-                    rows = grouped_map
+                    let keyed_rows = join_all(futures)
+                        .await
                         .into_iter()
-                        .map(|(mut key, mut value)| {
-                            key.append(&mut value);
-                            TableDataRow { fields: key }
-                        })
+                        .collect::<Result<Vec<_>, _>>()?;
+
+                    // 먼저 나온 행을 남깁니다. (정렬 뒤라면 DISTINCT ON 그룹별 첫 행)
+                    let mut seen = HashSet::new();
+                    rows = keyed_rows
+                        .into_iter()
+                        .filter(|(_, key)| seen.insert(key.clone()))
+                        .map(|(e, _)| e)
                         .collect();
                 }
-                SelectPlanItem::GroupAll => {
-                    aggregate_total_count = Some(rows.len());
-                    let mut fields = vec![];
-
-                    for row in rows {
-                        if fields.is_empty() {
-                            for field in row.fields {
-                                fields.push(field.to_array());
-                            }
-                        } else {
-                            for (i, field) in row.fields.into_iter().enumerate() {
-                                fields[i].push(field.data)
-                            }
-                        }
-                    }
-
-                    rows = vec![TableDataRow { fields }];
+                SelectPlanItem::LockRows(ref lock_rows) => {
+                    rows = self
+                        .lock_rows(rows, lock_rows, &table_alias_map, outer.clone())
+                        .await?;
                 }
                 SelectPlanItem::LimitOffset(limit_offset) => {
                     let offset = limit_offset.offset.unwrap_or(0) as usize;
@@ -351,6 +504,7 @@ impl DBEngine {
                 SelectPlanItem::Order(ref order_by_clause) => {
                     let futures = rows.into_iter().map(|e| {
                         let table_alias_map = table_alias_map.clone();
+                        let outer = outer.clone();
 
                         async move {
                             let mut order_by_values = vec![];
@@ -359,7 +513,7 @@ impl DBEngine {
                                 row: Some(e.to_owned()),
                                 table_alias_map,
                                 config_columns: vec![],
-                                total_count: 0,
+                                outer,
                             };
 
                             for order_by_item in &order_by_clause.order_by_items {
@@ -387,78 +541,35 @@ impl DBEngine {
 
                     let order_by_items = &order_by_clause.order_by_items;
 
-                    order_by_rows.sort_by(|(_, l), (_, r)| {
-                        for (i, order_by_item) in order_by_items.iter().enumerate() {
-                            let lhs = &l[i];
-                            let rhs = &r[i];
-
-                            if lhs.is_null() && rhs.is_null() {
-                                continue;
-                            }
-
-                            if lhs.is_null() {
-                                match order_by_item.nulls {
-                                    OrderByNulls::First => {
-                                        return Ordering::Less;
-                                    }
-                                    OrderByNulls::Last => {
-                                        return Ordering::Greater;
-                                    }
-                                }
-                            }
-
-                            if rhs.is_null() {
-                                match order_by_item.nulls {
-                                    OrderByNulls::First => {
-                                        return Ordering::Greater;
-                                    }
-                                    OrderByNulls::Last => {
-                                        return Ordering::Less;
-                                    }
-                                }
-                            }
-
-                            match order_by_item.order_type {
-                                OrderByType::Asc => {
-                                    if lhs < rhs {
-                                        return Ordering::Less;
-                                    } else if lhs > rhs {
-                                        return Ordering::Greater;
-                                    } else {
-                                        continue;
-                                    }
-                                }
-                                OrderByType::Desc => {
-                                    if lhs < rhs {
-                                        return Ordering::Greater;
-                                    } else if lhs > rhs {
-                                        return Ordering::Less;
-                                    } else {
-                                        continue;
-                                    }
-                                }
-                            }
-                        }
-
-                        Ordering::Equal
-                    });
+                    order_by_rows
+                        .sort_by(|(_, l), (_, r)| compare_order_values(order_by_items, l, r));
 
                     rows = order_by_rows.into_iter().map(|(e, _)| e).collect();
                 }
-                _ => unimplemented!("미구현"),
             }
         }
 
-        if no_from_clause {
-            rows.push(TableDataRow {
-                fields: vec![TableDataField {
-                    table_name: TableName::new(None, "no_table".into()),
-                    column_name: "result".into(),
-                    data: TableDataFieldType::Null,
-                }],
-            });
+        if let Some(columns) = compound_columns {
+            let rows = rows
+                .into_iter()
+                .map(|row| ExecuteRow {
+                    fields: row
+                        .fields
+                        .into_iter()
+                        .map(|field| field.data.into())
+                        .collect(),
+                })
+                .collect();
+
+            return Ok(ExecuteResult::new(columns, rows));
         }
 
+        table_infos.sort_by_key(|table_info: &TableSchema| {
+            written_table_order
+                .iter()
+                .position(|table_name| table_name == &table_info.table)
+        });
+
         let config_columns = table_infos
             .into_iter()
             .flat_map(|table_info| {
@@ -472,89 +583,70 @@ impl DBEngine {
             .collect::<Vec<_>>();
 
         // *까지 전부 유효한 항목으로 펼침
-        let select_items = select_items
-            .into_iter()
-            .flat_map(|e| match e {
-                SelectKind::SelectItem(item) => vec![item],
-                SelectKind::WildCard(wildcard) => match wildcard.alias {
-                    // a.* 와 같은 형태일 경우
-                    Some(alias) => match table_alias_map.get(&alias) {
-                        Some(found_table_name) => config_columns
-                            .iter()
-                            .filter(|(table_name, _)| {
-                                found_table_name.table_name == table_name.table_name
-                            })
-                            .map(|(table_name, column_name)| {
-                                SelectItem::builder()
-                                    .set_item(
-                                        SelectColumn::new(
-                                            Some(table_name.table_name.clone()),
-                                            column_name.name.clone(),
-                                        )
-                                        .into(),
-                                    )
-                                    .build()
-                            })
-                            .collect(),
-                        None => config_columns
-                            .iter()
-                            .filter(|(table_name, _)| alias == table_name.table_name)
-                            .map(|(table_name, column_name)| {
-                                SelectItem::builder()
-                                    .set_item(
-                                        SelectColumn::new(
-                                            Some(table_name.table_name.clone()),
-                                            column_name.name.clone(),
-                                        )
-                                        .into(),
-                                    )
-                                    .build()
-                            })
-                            .collect(),
-                    },
-                    None => config_columns
-                        .iter()
-                        .map(|(table_name, column_name)| {
-                            SelectItem::builder()
-                                .set_item(
-                                    SelectColumn::new(
-                                        Some(table_name.table_name.clone()),
-                                        column_name.name.clone(),
-                                    )
-                                    .into(),
-                                )
-                                .build()
-                        })
-                        .collect(),
+        let select_items = expand_select_items(select_items, &config_columns, &table_alias_map);
+
+        // 비상관 서브쿼리는 행마다 다시 실행하지 않도록 미리 값으로 바꿔둡니다.
+        let mut projection_items = vec![];
+        for select_item in &select_items {
+            let item = select_item.item.clone().unwrap();
+            self.cache_subquery_schemas(&item).await?;
+            projection_items.push(self.materialize_subqueries(item).await?);
+        }
+
+        let reduce_context = ReduceContext {
+            row: None,
+            table_alias_map: table_alias_map.clone(),
+            config_columns,
+            outer: outer.clone(),
+        };
+
+        let mut columns = vec![];
+
+        for e in select_items {
+            let item = e.item.unwrap();
+
+            let name = match e.alias {
+                Some(alias) => alias,
+                None => match &item {
+                    SQLExpression::SelectColumn(column) => column.column_name.to_owned(),
+                    _ => "?column?".into(),
                 },
-            })
-            .collect::<Vec<_>>();
+            };
+            let data_type = self.reduce_type(item, reduce_context.clone()).await?;
+
+            columns.push(ExecuteColumn { name, data_type });
+        }
 
         // 필요한 SELECT Item만 최종 계산
-        let total_count = aggregate_total_count.unwrap_or(rows.len());
         let rows = rows.into_iter().map(|row| {
             let table_alias_map = table_alias_map.clone();
-            let select_items = select_items.clone();
+            let projection_items = projection_items.clone();
+            let outer = outer.clone();
+            let columns = &columns;
             async move {
-                let fields = select_items.iter().map(|select_item| {
+                let fields = projection_items.iter().zip(columns).map(|(item, column)| {
                     let table_alias_map = table_alias_map.clone();
                     let row = row.clone();
+                    let outer = outer.clone();
                     async move {
                         let reduce_context = ReduceContext {
                             row: Some(row.clone()),
                             table_alias_map: table_alias_map.clone(),
                             config_columns: vec![],
-                            total_count,
+                            outer: outer.clone(),
                         };
 
                         let value = self
-                            .reduce_expression(
-                                select_item.item.as_ref().unwrap().clone(),
-                                reduce_context.clone(),
-                            )
+                            .reduce_expression(item.clone(), reduce_context.clone())
                             .await;
 
                         match value {
+                            // 정수와 실수가 섞인 CASE 등은 컬럼 타입인 실수로 넓혀서 내보냅니다.
+                            Ok(TableDataFieldType::Integer(value))
+                                if column.data_type == ExecuteColumnType::Float =>
+                            {
+                                Ok(ExecuteField::Float(value as f64))
+                            }
                             Ok(value) => Ok(ExecuteField::from(value)),
                             Err(error) => Err(error),
                         }
@@ -578,42 +670,168 @@ impl DBEngine {
             .into_iter()
             .collect::<Result<Vec<_>, _>>();
 
-        let reduce_context = ReduceContext {
-            row: None,
-            table_alias_map,
-            config_columns,
-            total_count: 0,
-        };
-
-        let columns = select_items
-            .into_iter()
-            .map(|e| {
-                let item = e.item.unwrap();
-
-                let name = match e.alias {
-                    Some(alias) => alias,
-                    None => match &item {
-                        SQLExpression::SelectColumn(column) => column.column_name.to_owned(),
-                        _ => "?column?".into(),
-                    },
-                };
-                let data_type = self.reduce_type(item, reduce_context.clone())?;
-
-                Ok::<ExecuteColumn, errors::Errors>(ExecuteColumn { name, data_type })
-            })
-            .collect::<Result<Vec<_>, _>>()?;
-
         match rows {
             Ok(rows) => Ok(ExecuteResult::new(columns, rows)),
             Err(error) => Err(error),
         }
     }
 
+    /// WHERE 조건을 행 하나에 평가합니다. NULL은 거짓으로 봅니다.
+    async fn row_matches_filter(
+        &self,
+        row: &TableDataRow,
+        expression: &SQLExpression,
+        table_alias_map: &HashMap<String, TableName>,
+        outer: &Option<Box<ReduceContext>>,
+    ) -> errors::Result<bool> {
+        let reduce_context = ReduceContext {
+            row: Some(row.to_owned()),
+            table_alias_map: table_alias_map.clone(),
+            config_columns: vec![],
+            outer: outer.clone(),
+        };
+
+        match self
+            .reduce_expression(expression.clone(), reduce_context)
+            .await?
+        {
+            TableDataFieldType::Boolean(boolean) => Ok(boolean),
+            TableDataFieldType::Null => Ok(false),
+            _ => Err(TypeError::wrap(
+                "condition expression is valid only for boolean and null types",
+            )),
+        }
+    }
+
     pub async fn filter(&self) {}
 
     pub async fn order_by(&self) {}
+}
+
+/// 실행 계획이 FROM 풀 스캔 → WHERE → 집계 순서로 시작하는지 확인합니다.
+/// 이 경우 스캔한 행은 집계에만 쓰이므로 모아두지 않고 흘려보낼 수 있습니다.
+fn scan_feeds_aggregate(plan_list: &[SelectPlanItem]) -> bool {
+    let Some(SelectPlanItem::From(from)) = plan_list.first() else {
+        return false;
+    };
+
+    if !matches!(from.scan, ScanType::FullScan) || from.scan_limit.is_some() {
+        return false;
+    }
+
+    plan_list[1..]
+        .iter()
+        .find(|each_plan| !matches!(each_plan, SelectPlanItem::Filter(_)))
+        .is_some_and(|each_plan| matches!(each_plan, SelectPlanItem::Aggregate(_)))
+}
+
+/// SELECT 목록의 * 와 a.* 를 테이블 컬럼들로 펼칩니다.
+pub(crate) fn expand_select_items(
+    select_items: Vec<SelectKind>,
+    config_columns: &[(TableName, Column)],
+    table_alias_map: &HashMap<String, TableName>,
+) -> Vec<SelectItem> {
+    // alias가 붙은 테이블의 컬럼은 alias로 한정해야 찾을 수 있습니다.
+    let select_item = |table_name: &TableName, column: &Column| {
+        let qualifier = table_alias_map
+            .iter()
+            .find(|(_, aliased)| *aliased == table_name)
+            .map(|(alias, _)| alias.clone())
+            .unwrap_or(table_name.table_name.clone());
+
+        SelectItem::builder()
+            .set_item(SelectColumn::new(Some(qualifier), column.name.clone()).into())
+            .build()
+    };
+
+    select_items
+        .into_iter()
+        .flat_map(|e| match e {
+            SelectKind::SelectItem(item) => vec![item],
+            SelectKind::WildCard(wildcard) => match wildcard.alias {
+                // a.* 와 같은 형태일 경우
+                Some(alias) => match table_alias_map.get(&alias) {
+                    Some(found_table_name) => config_columns
+                        .iter()
+                        .filter(|(table_name, _)| {
+                            found_table_name.table_name == table_name.table_name
+                        })
+                        .map(|(table_name, column)| select_item(table_name, column))
+                        .collect(),
+                    None => config_columns
+                        .iter()
+                        .filter(|(table_name, _)| alias == table_name.table_name)
+                        .map(|(table_name, column)| select_item(table_name, column))
+                        .collect(),
+                },
+                None => config_columns
+                    .iter()
+                    .map(|(table_name, column)| select_item(table_name, column))
+                    .collect(),
+            },
+        })
+        .collect::<Vec<_>>()
+}
+
+/// ORDER BY 항목별로 평가해 둔 두 행의 값을 비교합니다. (NULLS FIRST/LAST, ASC/DESC 반영)
+pub(crate) fn compare_order_values(
+    order_by_items: &[OrderByItem],
+    l: &[TableDataFieldType],
+    r: &[TableDataFieldType],
+) -> Ordering {
+    for (i, order_by_item) in order_by_items.iter().enumerate() {
+        let lhs = &l[i];
+        let rhs = &r[i];
+
+        if lhs.is_null() && rhs.is_null() {
+            continue;
+        }
+
+        if lhs.is_null() {
+            match order_by_item.nulls {
+                OrderByNulls::First => {
+                    return Ordering::Less;
+                }
+                OrderByNulls::Last => {
+                    return Ordering::Greater;
+                }
+            }
+        }
+
+        if rhs.is_null() {
+            match order_by_item.nulls {
+                OrderByNulls::First => {
+                    return Ordering::Greater;
+                }
+                OrderByNulls::Last => {
+                    return Ordering::Less;
+                }
+            }
+        }
+
+        match order_by_item.order_type {
+            OrderByType::Asc => {
+                if lhs < rhs {
+                    return Ordering::Less;
+                } else if lhs > rhs {
+                    return Ordering::Greater;
+                } else {
+                    continue;
+                }
+            }
+            OrderByType::Desc => {
+                if lhs < rhs {
+                    return Ordering::Greater;
+                } else if lhs > rhs {
+                    return Ordering::Less;
+                } else {
+                    continue;
+                }
+            }
+        }
+    }
 
-    pub async fn inner_join(&self) {}
+    Ordering::Equal
 }
 
 #[cfg(test)]
@@ -628,7 +846,7 @@ mod tests {
     use crate::engine::ast::dml::parts::insert_values::InsertValue;
     use crate::engine::ast::types::{SQLExpression, TableName};
     use crate::engine::parser::predule::{Parser, ParserContext};
-    use crate::engine::types::ExecuteField;
+    use crate::engine::types::{ExecuteColumnType, ExecuteField};
     use crate::engine::wal::endec::implements::bincode::{BincodeDecoder, BincodeEncoder};
     use crate::engine::wal::manager::builder::WALBuilder;
     use crate::engine::{DBEngine, SharedWALManager};
@@ -821,7 +1039,11 @@ mod tests {
                 .await
                 .unwrap_or_else(|error| panic!("{sql} failed: {error}"));
 
-            assert_eq!(result.rows.len(), expected, "unexpected row count for {sql}");
+            assert_eq!(
+                result.rows.len(),
+                expected,
+                "unexpected row count for {sql}"
+            );
         }
     }
 
@@ -846,7 +1068,1806 @@ mod tests {
             ("select id from key_value offset 1;", 2),
         ] {
             let result = execute_sql(&engine, wal.clone(), sql).await.unwrap();
-            assert_eq!(result.rows.len(), expected, "unexpected row count for {sql}");
+            assert_eq!(
+                result.rows.len(),
+                expected,
+                "unexpected row count for {sql}"
+            );
+        }
+    }
+
+    async fn setup_join_tables(test_name: &str) -> (DBEngine, SharedWALManager) {
+        let (engine, wal) = build_test_engine(test_name).await;
+
+        for sql in [
+            "create database rrdb;",
+            "create table users (id integer, name varchar(100));",
+            "create table orders (id integer, user_id integer, total integer);",
+            "insert into users (id, name) values (1, 'alice');",
+            "insert into users (id, name) values (2, 'bob');",
+            "insert into users (id, name) values (3, 'carol');",
+            "insert into orders (id, user_id, total) values (10, 1, 100);",
+            "insert into orders (id, user_id, total) values (11, 1, 200);",
+            "insert into orders (id, user_id, total) values (12, 2, 300);",
+            "insert into orders (id, user_id, total) values (13, 9, 400);",
+        ] {
+            execute_sql(&engine, wal.clone(), sql)
+                .await
+                .unwrap_or_else(|error| panic!("{sql} failed: {error}"));
+        }
+
+        (engine, wal)
+    }
+
+    fn integer_pairs(
+        result: &crate::engine::types::ExecuteResult,
+    ) -> Vec<(ExecuteField, ExecuteField)> {
+        result
+            .rows
+            .iter()
+            .map(|row| (row.fields[0].clone(), row.fields[1].clone()))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn inner_join_returns_only_matching_rows() {
+        let (engine, wal) = setup_join_tables("test_inner_join_rows").await;
+
+        let result = execute_sql(
+            &engine,
+            wal,
+            "select u.id, o.id from users u inner join orders o on u.id = o.user_id order by o.id;",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            integer_pairs(&result),
+            vec![
+                (ExecuteField::Integer(1), ExecuteField::Integer(10)),
+                (ExecuteField::Integer(1), ExecuteField::Integer(11)),
+                (ExecuteField::Integer(2), ExecuteField::Integer(12)),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn outer_joins_pad_unmatched_rows_with_null() {
+        let (engine, wal) = setup_join_tables("test_outer_join_rows").await;
+
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select u.id, o.id from users u left join orders o on u.id = o.user_id order by u.id, o.id;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            integer_pairs(&result),
+            vec![
+                (ExecuteField::Integer(1), ExecuteField::Integer(10)),
+                (ExecuteField::Integer(1), ExecuteField::Integer(11)),
+                (ExecuteField::Integer(2), ExecuteField::Integer(12)),
+                (ExecuteField::Integer(3), ExecuteField::Null),
+            ]
+        );
+
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select u.id, o.id from users u right join orders o on u.id = o.user_id order by o.id;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            integer_pairs(&result),
+            vec![
+                (ExecuteField::Integer(1), ExecuteField::Integer(10)),
+                (ExecuteField::Integer(1), ExecuteField::Integer(11)),
+                (ExecuteField::Integer(2), ExecuteField::Integer(12)),
+                (ExecuteField::Null, ExecuteField::Integer(13)),
+            ]
+        );
+
+        let result = execute_sql(
+            &engine,
+            wal,
+            "select u.id, o.id from users u full outer join orders o on u.id = o.user_id;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(result.rows.len(), 5);
+        assert!(integer_pairs(&result).contains(&(ExecuteField::Integer(3), ExecuteField::Null)));
+        assert!(integer_pairs(&result).contains(&(ExecuteField::Null, ExecuteField::Integer(13))));
+    }
+
+    /// ON 절의 비등가 조건은 중첩 루프로, WHERE 절은 조인 이후에 평가됩니다.
+    #[tokio::test]
+    async fn join_with_non_equi_condition_and_where_filter() {
+        let (engine, wal) = setup_join_tables("test_non_equi_join_rows").await;
+
+        let result = execute_sql(
+            &engine,
+            wal,
+            "select u.id, o.id from users u inner join orders o on o.total >= u.id * 150 where o.id < 13 order by u.id, o.id;",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            integer_pairs(&result),
+            vec![
+                (ExecuteField::Integer(1), ExecuteField::Integer(11)),
+                (ExecuteField::Integer(1), ExecuteField::Integer(12)),
+                (ExecuteField::Integer(2), ExecuteField::Integer(12)),
+            ]
+        );
+    }
+
+    fn first_column(result: &crate::engine::types::ExecuteResult) -> Vec<ExecuteField> {
+        result
+            .rows
+            .iter()
+            .map(|row| row.fields[0].clone())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn in_list_and_in_subquery_filter_rows() {
+        let (engine, wal) = setup_join_tables("test_in_subquery_rows").await;
+
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select id from users where id in (1, 3) order by id;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            first_column(&result),
+            vec![ExecuteField::Integer(1), ExecuteField::Integer(3)]
+        );
+
+        // 원소가 하나뿐인 목록
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select id from users where id in (2);",
+        )
+        .await
+        .unwrap();
+        assert_eq!(first_column(&result), vec![ExecuteField::Integer(2)]);
+
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select id from users where id not in (2) and id > 0 order by id;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            first_column(&result),
+            vec![ExecuteField::Integer(1), ExecuteField::Integer(3)]
+        );
+
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select id from users where id in (select user_id from orders) order by id;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            first_column(&result),
+            vec![ExecuteField::Integer(1), ExecuteField::Integer(2)]
+        );
+
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select id from users where id not in (select user_id from orders) order by id;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(first_column(&result), vec![ExecuteField::Integer(3)]);
+
+        // 서브쿼리 결과가 비면 IN은 FALSE, NOT IN은 TRUE
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select id from users where id not in (select user_id from orders where total > 1000) order by id;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(result.rows.len(), 3);
+
+        // 컬럼이 두개 이상인 서브쿼리는 오류
+        assert!(
+            execute_sql(
+                &engine,
+                wal,
+                "select id from users where id in (select user_id, total from orders);",
+            )
+            .await
+            .is_err()
+        );
+    }
+
+    /// NOT IN 목록에 NULL이 섞이면 어떤 행도 TRUE가 될 수 없습니다.
+    #[tokio::test]
+    async fn not_in_with_null_in_subquery_matches_nothing() {
+        let (engine, wal) = setup_join_tables("test_not_in_null_subquery").await;
+
+        execute_sql(
+            &engine,
+            wal.clone(),
+            "insert into orders (id, user_id, total) values (14, null, 500);",
+        )
+        .await
+        .unwrap();
+
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select id from users where id not in (select user_id from orders);",
+        )
+        .await
+        .unwrap();
+        assert!(result.rows.is_empty());
+
+        // IN은 일치하는 값이 있으면 NULL과 무관하게 TRUE
+        let result = execute_sql(
+            &engine,
+            wal,
+            "select id from users where id in (select user_id from orders) order by id;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            first_column(&result),
+            vec![ExecuteField::Integer(1), ExecuteField::Integer(2)]
+        );
+    }
+
+    /// #195: 통계가 있으면 INNER JOIN 순서를 재배치하지만, 결과 행과 * 의 컬럼 순서는
+    /// 작성된 쿼리 그대로여야 합니다.
+    #[tokio::test]
+    async fn reordered_inner_joins_keep_results_and_wildcard_order() {
+        let (engine, wal) = setup_join_tables("test_reordered_inner_joins").await;
+
+        for sql in [
+            "create table countries (id integer, code varchar(10));",
+            "insert into countries (id, code) values (1, 'kr');",
+        ] {
+            execute_sql(&engine, wal.clone(), sql).await.unwrap();
+        }
+
+        let result = execute_sql(
+            &engine,
+            wal,
+            "select * from orders o \
+                inner join users u on o.user_id = u.id \
+                inner join countries c on c.id = u.id \
+                order by o.id;",
+        )
+        .await
+        .unwrap();
+
+        let column_names = result
+            .columns
+            .iter()
+            .map(|column| column.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            column_names,
+            vec!["id", "user_id", "total", "id", "name", "id", "code"]
+        );
+
+        assert_eq!(result.rows.len(), 2);
+        assert_eq!(result.rows[0].fields[0], ExecuteField::Integer(10));
+        assert_eq!(result.rows[1].fields[0], ExecuteField::Integer(11));
+        assert_eq!(
+            result.rows[0].fields[6],
+            ExecuteField::String("kr".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn scalar_subqueries_in_select_list_and_where() {
+        let (engine, wal) = setup_join_tables("test_scalar_subquery_rows").await;
+
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select id, (select total from orders where id = 12) from users where id = (select user_id from orders where id = 12);",
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            integer_pairs(&result),
+            vec![(ExecuteField::Integer(2), ExecuteField::Integer(300))]
+        );
+
+        // 결과 행이 없으면 NULL
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select id, (select total from orders where id = 99) from users where id = 1;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            integer_pairs(&result),
+            vec![(ExecuteField::Integer(1), ExecuteField::Null)]
+        );
+
+        // 두 행 이상이면 오류
+        assert!(
+            execute_sql(
+                &engine,
+                wal,
+                "select id from users where id = (select user_id from orders);",
+            )
+            .await
+            .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn correlated_scalar_subquery_sees_outer_row() {
+        let (engine, wal) = setup_join_tables("test_correlated_scalar_subquery").await;
+
+        let result = execute_sql(
+            &engine,
+            wal,
+            "select u.id, (select o.total from orders o where o.user_id = u.id and o.id > 10) from users u order by u.id;",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            integer_pairs(&result),
+            vec![
+                (ExecuteField::Integer(1), ExecuteField::Integer(200)),
+                (ExecuteField::Integer(2), ExecuteField::Integer(300)),
+                (ExecuteField::Integer(3), ExecuteField::Null),
+            ]
+        );
+    }
+
+    /// 서브쿼리가 바깥 테이블에 alias를 붙여 다시 읽으면, 원래 테이블명은 바깥 행을 가리킵니다.
+    #[tokio::test]
+    async fn self_correlated_subquery_resolves_table_name_to_outer_row() {
+        let (engine, wal) = setup_join_tables("test_self_correlated_subquery").await;
+
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select id, (select count(*) from users u2 where u2.id < users.id) from users order by id;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            integer_pairs(&result),
+            vec![
+                (ExecuteField::Integer(1), ExecuteField::Integer(0)),
+                (ExecuteField::Integer(2), ExecuteField::Integer(1)),
+                (ExecuteField::Integer(3), ExecuteField::Integer(2)),
+            ]
+        );
+
+        for (sql, expected) in [
+            (
+                "select id from users where exists (select 1 from users u2 where u2.id = users.id + 1) order by id;",
+                vec![ExecuteField::Integer(1), ExecuteField::Integer(2)],
+            ),
+            (
+                "select id from users where not exists (select 1 from users u2 where u2.id = users.id + 1) order by id;",
+                vec![ExecuteField::Integer(3)],
+            ),
+            // 세미 조인으로 풀 수 있는 형태여도 같은 테이블이면 행 단위로 평가합니다.
+            (
+                "select id from users where exists (select 1 from users u2 where u2.id = users.id and u2.name != 'bob') order by id;",
+                vec![ExecuteField::Integer(1), ExecuteField::Integer(3)],
+            ),
+            (
+                "select u.id from users u where not exists (select 1 from users u2 where u2.id = u.id and u2.id > 1) order by u.id;",
+                vec![ExecuteField::Integer(1)],
+            ),
+        ] {
+            let result = execute_sql(&engine, wal.clone(), sql).await.unwrap();
+            assert_eq!(first_column(&result), expected, "{sql}");
+        }
+    }
+
+    /// 세미/안티 조인으로 풀리는 형태와 행 단위로 평가되는 형태의 결과가 같아야 합니다.
+    #[tokio::test]
+    async fn exists_and_not_exists_filter_rows() {
+        let (engine, wal) = setup_join_tables("test_exists_subquery_rows").await;
+
+        for (sql, expected) in [
+            (
+                "select u.id from users u where exists (select 1 from orders o where o.user_id = u.id) order by u.id;",
+                vec![ExecuteField::Integer(1), ExecuteField::Integer(2)],
+            ),
+            (
+                "select u.id from users u where exists (select 1 from orders where user_id = u.id) order by u.id;",
+                vec![ExecuteField::Integer(1), ExecuteField::Integer(2)],
+            ),
+            (
+                "select u.id from users u where not exists (select 1 from orders o where o.user_id = u.id) order by u.id;",
+                vec![ExecuteField::Integer(3)],
+            ),
+            (
+                "select u.id from users u where not exists (select 1 from orders where user_id = u.id and total > 150) order by u.id;",
+                vec![ExecuteField::Integer(3)],
+            ),
+            (
+                "select u.id from users u where u.id > 1 and exists (select 1 from orders o where o.user_id = u.id) order by u.id;",
+                vec![ExecuteField::Integer(2)],
+            ),
+            (
+                "select id from users where exists (select 1 from orders where total > 1000);",
+                vec![],
+            ),
+        ] {
+            let result = execute_sql(&engine, wal.clone(), sql).await.unwrap();
+            assert_eq!(first_column(&result), expected, "{sql}");
+        }
+    }
+
+    #[tokio::test]
+    async fn correlated_exists_is_planned_as_semi_join() {
+        use crate::engine::ast::dml::parts::join::JoinType;
+        use crate::engine::ast::dml::plan::select::select_plan::SelectPlanItem;
+        use crate::engine::ast::{DMLStatement, SQLStatement};
+        use crate::engine::optimizer::predule::Optimizer;
+
+        let mut parser = Parser::with_string(
+            "select u.id from users u where not exists (select 1 from orders o where o.user_id = u.id);"
+                .to_string(),
+        )
+        .unwrap();
+        let statement = parser
+            .parse(ParserContext::default().set_default_database("rrdb".to_string()))
+            .unwrap()
+            .remove(0);
+        let SQLStatement::DML(DMLStatement::SelectQuery(query)) = statement else {
+            panic!("not a select query");
+        };
+
+        let plan = Optimizer::new().optimize_select(query).await.unwrap();
+
+        assert!(plan.list.iter().any(|item| matches!(
+            item,
+            SelectPlanItem::Join(join) if join.join_type == JoinType::LeftAntiJoin
+        )));
+        assert!(
+            !plan
+                .list
+                .iter()
+                .any(|item| matches!(item, SelectPlanItem::Filter(_)))
+        );
+    }
+
+    #[tokio::test]
+    async fn derived_table_columns_are_exposed_under_alias() {
+        let (engine, wal) = setup_join_tables("test_derived_table_rows").await;
+
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select d.uid, d.total from (select user_id as uid, total from orders where total >= 200) as d where d.total < 400 order by d.total;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            integer_pairs(&result),
+            vec![
+                (ExecuteField::Integer(1), ExecuteField::Integer(200)),
+                (ExecuteField::Integer(2), ExecuteField::Integer(300)),
+            ]
+        );
+
+        // * 는 파생 테이블의 결과 컬럼으로 펼쳐집니다.
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select * from (select id, name from users where id > 1) u2 order by id;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            result
+                .columns
+                .iter()
+                .map(|column| column.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["id", "name"]
+        );
+        assert_eq!(
+            first_column(&result),
+            vec![ExecuteField::Integer(2), ExecuteField::Integer(3)]
+        );
+
+        // 중첩된 파생 테이블
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select x.id from (select id from (select id from users) a where a.id < 3) x order by x.id desc;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            first_column(&result),
+            vec![ExecuteField::Integer(2), ExecuteField::Integer(1)]
+        );
+
+        // alias가 없는 파생 테이블은 오류
+        assert!(
+            execute_sql(&engine, wal, "select * from (select id from users);")
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn derived_table_joins_with_base_table() {
+        let (engine, wal) = setup_join_tables("test_derived_table_join").await;
+
+        let result = execute_sql(
+            &engine,
+            wal,
+            "select u.id, d.total from (select user_id, total from orders) d inner join users u on u.id = d.user_id order by d.total;",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            integer_pairs(&result),
+            vec![
+                (ExecuteField::Integer(1), ExecuteField::Integer(100)),
+                (ExecuteField::Integer(1), ExecuteField::Integer(200)),
+                (ExecuteField::Integer(2), ExecuteField::Integer(300)),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn describe_select_columns_over_derived_table() {
+        use crate::engine::ast::{DMLStatement, SQLStatement};
+        use crate::engine::types::{ExecuteColumn, ExecuteColumnType};
+
+        let (engine, _wal) = setup_join_tables("test_derived_table_describe").await;
+
+        let mut parser = Parser::with_string(
+            "select d.total, d.label from (select total, 'x' as label from orders) d;".to_string(),
+        )
+        .unwrap();
+        let statement = parser
+            .parse(ParserContext::default().set_default_database("rrdb".to_string()))
+            .unwrap()
+            .remove(0);
+        let SQLStatement::DML(DMLStatement::SelectQuery(query)) = statement else {
+            panic!("not a select query");
+        };
+
+        assert_eq!(
+            engine.describe_select_columns(query).await.unwrap(),
+            vec![
+                ExecuteColumn {
+                    name: "total".into(),
+                    data_type: ExecuteColumnType::Integer,
+                },
+                ExecuteColumn {
+                    name: "label".into(),
+                    data_type: ExecuteColumnType::String,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn aggregate_functions_over_whole_table() {
+        let (engine, wal) = setup_join_tables("test_aggregate_functions").await;
+
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select count(*), count(distinct user_id), sum(total), avg(total), min(total), max(total), bool_and(total > 50), bool_or(total > 350), every(total > 150), var_pop(total) from orders;",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            result
+                .columns
+                .iter()
+                .map(|column| column.data_type.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                ExecuteColumnType::Integer,
+                ExecuteColumnType::Integer,
+                ExecuteColumnType::Integer,
+                ExecuteColumnType::Float,
+                ExecuteColumnType::Integer,
+                ExecuteColumnType::Integer,
+                ExecuteColumnType::Bool,
+                ExecuteColumnType::Bool,
+                ExecuteColumnType::Bool,
+                ExecuteColumnType::Float,
+            ]
+        );
+        assert_eq!(
+            result.rows[0].fields,
+            vec![
+                ExecuteField::Integer(4),
+                ExecuteField::Integer(3),
+                ExecuteField::Integer(1000),
+                ExecuteField::Float(250.0),
+                ExecuteField::Integer(100),
+                ExecuteField::Integer(400),
+                ExecuteField::Bool(true),
+                ExecuteField::Bool(true),
+                ExecuteField::Bool(false),
+                ExecuteField::Float(12500.0),
+            ]
+        );
+
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select string_agg(name, ','), array_agg(id) from users;",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            result.rows[0].fields,
+            vec![
+                ExecuteField::String("alice,bob,carol".into()),
+                ExecuteField::Array(vec![
+                    ExecuteField::Integer(1),
+                    ExecuteField::Integer(2),
+                    ExecuteField::Integer(3),
+                ]),
+            ]
+        );
+        assert_eq!(
+            result.columns[1].data_type,
+            ExecuteColumnType::Array(Box::new(ExecuteColumnType::Integer))
+        );
+    }
+
+    #[tokio::test]
+    async fn aggregate_functions_over_empty_input() {
+        let (engine, wal) = setup_join_tables("test_aggregate_empty_input").await;
+
+        let result = execute_sql(
+            &engine,
+            wal,
+            "select count(*), count(total), sum(total), avg(total), max(total), stddev(total) from orders where total > 1000;",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            result.rows[0].fields,
+            vec![
+                ExecuteField::Integer(0),
+                ExecuteField::Integer(0),
+                ExecuteField::Null,
+                ExecuteField::Null,
+                ExecuteField::Null,
+                ExecuteField::Null,
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn group_by_with_having_and_order_by_aggregates() {
+        let (engine, wal) = setup_join_tables("test_group_by_having_order").await;
+
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select o.user_id, count(*), sum(o.total) from orders o group by o.user_id having count(*) >= 1 and sum(o.total) > 250 order by sum(o.total) desc, count(*) desc;",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            result
+                .rows
+                .iter()
+                .map(|row| row.fields.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                vec![
+                    ExecuteField::Integer(9),
+                    ExecuteField::Integer(1),
+                    ExecuteField::Integer(400),
+                ],
+                vec![
+                    ExecuteField::Integer(1),
+                    ExecuteField::Integer(2),
+                    ExecuteField::Integer(300),
+                ],
+                vec![
+                    ExecuteField::Integer(2),
+                    ExecuteField::Integer(1),
+                    ExecuteField::Integer(300),
+                ],
+            ]
+        );
+
+        // GROUP BY가 있으면 입력이 없을 때 결과 행도 없습니다.
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select user_id, count(*) from orders where total > 1000 group by user_id;",
+        )
+        .await
+        .unwrap();
+        assert!(result.rows.is_empty());
+
+        // FROM 없는 집계는 한 행을 대상으로 합니다.
+        let result = execute_sql(&engine, wal.clone(), "select count(*);")
+            .await
+            .unwrap();
+        assert_eq!(first_column(&result), vec![ExecuteField::Integer(1)]);
+    }
+
+    #[tokio::test]
+    async fn aggregates_with_subquery_arguments_are_kept_apart() {
+        let (engine, wal) = setup_join_tables("test_aggregate_subquery_arguments").await;
+
+        // 인자가 서브쿼리로만 다른 두 집계는 서로 다른 결과 필드여야 합니다.
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select sum((select u.id from users u where u.id = o.user_id)), sum((select u.id * 10 from users u where u.id = o.user_id)) from orders o;",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            result.rows[0].fields,
+            vec![ExecuteField::Integer(4), ExecuteField::Integer(40)]
+        );
+
+        // WHERE 조건은 스캔한 행을 집계에 흘려보내면서 적용됩니다.
+        let result = execute_sql(
+            &engine,
+            wal,
+            "select count(*), sum(o.total) from orders o where o.total >= 200 and o.user_id in (select id from users);",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            result.rows[0].fields,
+            vec![ExecuteField::Integer(2), ExecuteField::Integer(500)]
+        );
+    }
+
+    #[tokio::test]
+    async fn aggregate_in_where_is_rejected() {
+        let (engine, wal) = setup_join_tables("test_aggregate_in_where").await;
+
+        let error = execute_sql(&engine, wal, "select id from orders where count(*) > 1;")
+            .await
+            .expect_err("aggregates are evaluated after WHERE");
+
+        assert!(
+            error.to_string().contains("not allowed here"),
+            "unexpected error: {error}"
+        );
+    }
+
+    #[tokio::test]
+    async fn select_distinct_removes_duplicate_rows() {
+        let (engine, wal) = setup_join_tables("test_select_distinct").await;
+
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select distinct user_id from orders order by user_id;",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            first_column(&result),
+            vec![
+                ExecuteField::Integer(1),
+                ExecuteField::Integer(2),
+                ExecuteField::Integer(9),
+            ]
+        );
+
+        // LIMIT은 중복 제거 뒤에 적용됩니다.
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select distinct user_id from orders order by user_id limit 2;",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            first_column(&result),
+            vec![ExecuteField::Integer(1), ExecuteField::Integer(2)]
+        );
+
+        // * 는 모든 컬럼으로 비교하므로 중복이 없습니다.
+        let result = execute_sql(&engine, wal.clone(), "select distinct * from orders;")
+            .await
+            .unwrap();
+
+        assert_eq!(result.rows.len(), 4);
+    }
+
+    #[tokio::test]
+    async fn select_distinct_on_keeps_first_row_of_each_group() {
+        let (engine, wal) = setup_join_tables("test_select_distinct_on").await;
+
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select distinct on (user_id) user_id, total from orders order by user_id, total desc;",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            integer_pairs(&result),
+            vec![
+                (ExecuteField::Integer(1), ExecuteField::Integer(200)),
+                (ExecuteField::Integer(2), ExecuteField::Integer(300)),
+                (ExecuteField::Integer(9), ExecuteField::Integer(400)),
+            ]
+        );
+
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select distinct on (user_id) user_id, total from orders order by total;",
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn set_operations_combine_query_results() {
+        let (engine, wal) = setup_join_tables("test_set_operations").await;
+
+        let cases = [
+            (
+                "select user_id from orders union select id from users order by user_id;",
+                vec![1, 2, 3, 9],
+            ),
+            (
+                "select user_id from orders union all select id from users order by user_id;",
+                vec![1, 1, 1, 2, 2, 3, 9],
+            ),
+            (
+                "select id from users intersect select user_id from orders order by id;",
+                vec![1, 2],
+            ),
+            (
+                "select user_id from orders intersect all select user_id from orders where total > 100 order by user_id;",
+                vec![1, 2, 9],
+            ),
+            (
+                "select id from users except select user_id from orders;",
+                vec![3],
+            ),
+            (
+                "select user_id from orders except all select id from users order by user_id;",
+                vec![1, 9],
+            ),
+            // ORDER BY와 LIMIT은 결합된 결과 전체에 적용됩니다.
+            (
+                "select user_id from orders union select id from users order by user_id desc limit 2;",
+                vec![9, 3],
+            ),
+            // 서브쿼리 안의 집합 연산
+            (
+                "select id from users where id in (select user_id from orders intersect select 2);",
+                vec![2],
+            ),
+        ];
+
+        for (sql, expected) in cases {
+            let result = execute_sql(&engine, wal.clone(), sql).await.unwrap();
+
+            assert_eq!(
+                first_column(&result),
+                expected
+                    .into_iter()
+                    .map(ExecuteField::Integer)
+                    .collect::<Vec<_>>(),
+                "{sql}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn set_operations_check_column_count_and_types() {
+        use crate::engine::ast::{DMLStatement, SQLStatement};
+        use crate::engine::types::ExecuteColumn;
+
+        let (engine, wal) = setup_join_tables("test_set_operation_columns").await;
+
+        // 이름은 왼쪽 쿼리를, 타입은 NULL이 아닌 쪽을 따릅니다.
+        let sql = "select id, null as label from users union select user_id, 'x' from orders;";
+        let result = execute_sql(&engine, wal.clone(), sql).await.unwrap();
+        let expected = vec![
+            ExecuteColumn {
+                name: "id".into(),
+                data_type: ExecuteColumnType::Integer,
+            },
+            ExecuteColumn {
+                name: "label".into(),
+                data_type: ExecuteColumnType::String,
+            },
+        ];
+        assert_eq!(result.columns, expected);
+        assert_eq!(result.rows.len(), 6);
+
+        let mut parser = Parser::with_string(sql.to_string()).unwrap();
+        let statement = parser
+            .parse(ParserContext::default().set_default_database("rrdb".to_string()))
+            .unwrap()
+            .remove(0);
+        let SQLStatement::DML(DMLStatement::SelectQuery(query)) = statement else {
+            panic!("not a select query");
+        };
+        assert_eq!(
+            engine.describe_select_columns(query).await.unwrap(),
+            expected
+        );
+
+        for sql in [
+            "select id, name from users union select user_id from orders;",
+            "select id from users except select name from users;",
+        ] {
+            assert!(
+                execute_sql(&engine, wal.clone(), sql).await.is_err(),
+                "{sql}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn with_clause_reuses_common_table_rows() {
+        let (engine, wal) = setup_join_tables("test_with_clause").await;
+
+        let cases = [
+            // WITH 절 테이블을 두 번 참조 (자기 조인)
+            (
+                "with big as (select id, user_id from orders where total >= 200) \
+                 select a.id from big a inner join big b on a.user_id = b.user_id order by a.id;",
+                vec![11, 12, 13],
+            ),
+            // 컬럼 목록으로 이름 바꾸기 + 뒤의 WITH 쿼리가 앞의 것을 참조
+            (
+                "with spent(uid, amount) as (select user_id, total from orders), \
+                 rich as (select uid from spent where amount > 250) \
+                 select uid from rich order by uid;",
+                vec![2, 9],
+            ),
+            // WITH 절 테이블이 같은 이름의 실제 테이블을 가림
+            (
+                "with users as (select 42 as id) select id from users;",
+                vec![42],
+            ),
+            // 서브쿼리 안의 WITH 절과 바깥 WITH 절 테이블 참조
+            (
+                "with o as (select user_id from orders) \
+                 select id from users where id in (with p as (select user_id from o where user_id > 1) select user_id from p);",
+                vec![2],
+            ),
+        ];
+
+        for (sql, expected) in cases {
+            let result = execute_sql(&engine, wal.clone(), sql).await.unwrap();
+
+            assert_eq!(
+                first_column(&result),
+                expected
+                    .into_iter()
+                    .map(ExecuteField::Integer)
+                    .collect::<Vec<_>>(),
+                "{sql}"
+            );
+        }
+
+        // 실행이 끝나면 등록이 해제됩니다.
+        assert!(engine.common_tables.read().await.is_empty());
+
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "with t(a, b) as (select 1) select a from t;",
+        )
+        .await;
+        assert!(result.is_err());
+        assert!(engine.common_tables.read().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn with_recursive_evaluates_to_a_fixpoint() {
+        let (engine, wal) = build_test_engine("test_with_recursive").await;
+
+        for sql in [
+            "create database rrdb;",
+            "create table employees (id integer, manager_id integer, name varchar(100));",
+            "insert into employees (id, manager_id, name) values (1, null, 'ceo');",
+            "insert into employees (id, manager_id, name) values (2, 1, 'cto');",
+            "insert into employees (id, manager_id, name) values (3, 2, 'dev');",
+            "insert into employees (id, manager_id, name) values (4, 3, 'intern');",
+            "insert into employees (id, manager_id, name) values (5, 1, 'cfo');",
+            "create table edges (src integer, dst integer);",
+            "insert into edges (src, dst) values (1, 2);",
+            "insert into edges (src, dst) values (2, 3);",
+            "insert into edges (src, dst) values (3, 1);",
+        ] {
+            execute_sql(&engine, wal.clone(), sql)
+                .await
+                .unwrap_or_else(|error| panic!("{sql} failed: {error}"));
+        }
+
+        let cases = [
+            (
+                "with recursive t(n) as (select 1 union all select n + 1 from t where n < 5) \
+                 select n from t;",
+                vec![1, 2, 3, 4, 5],
+            ),
+            // 조직도: cto 아래의 모든 직원
+            (
+                "with recursive reports as ( \
+                   select id from employees where id = 2 \
+                   union all \
+                   select e.id from employees e inner join reports r on e.manager_id = r.id \
+                 ) select id from reports order by id;",
+                vec![2, 3, 4],
+            ),
+            // 순환이 있어도 UNION은 새 행이 없으면 끝납니다.
+            (
+                "with recursive reach(node) as ( \
+                   select 1 union select edges.dst from edges inner join reach on edges.src = reach.node \
+                 ) select node from reach order by node;",
+                vec![1, 2, 3],
+            ),
+        ];
+
+        for (sql, expected) in cases {
+            let result = execute_sql(&engine, wal.clone(), sql).await.unwrap();
+
+            assert_eq!(
+                first_column(&result),
+                expected
+                    .into_iter()
+                    .map(ExecuteField::Integer)
+                    .collect::<Vec<_>>(),
+                "{sql}"
+            );
+        }
+
+        // 결과 컬럼 조회는 WITH 쿼리를 실행하지 않고 스키마만 등록해서 구합니다.
+        let mut parser = Parser::with_string(
+            "with recursive t(n) as (select 1 union all select n + 1 from t) select n from t;"
+                .to_string(),
+        )
+        .unwrap();
+        let statement = parser
+            .parse(ParserContext::default().set_default_database("rrdb".to_string()))
+            .unwrap()
+            .remove(0);
+        let crate::engine::ast::SQLStatement::DML(crate::engine::ast::DMLStatement::SelectQuery(
+            query,
+        )) = statement
+        else {
+            panic!("not a select query");
+        };
+        let columns = engine.describe_select_columns(query).await.unwrap();
+        assert_eq!(columns.len(), 1);
+        assert_eq!(columns[0].name, "n");
+        assert_eq!(columns[0].data_type, ExecuteColumnType::Integer);
+
+        // UNION ALL로 순환하면 반복 횟수 상한에 걸립니다.
+        let error = execute_sql(
+            &engine,
+            wal.clone(),
+            "with recursive reach(node) as ( \
+               select 1 union all select edges.dst from edges inner join reach on edges.src = reach.node \
+             ) select node from reach;",
+        )
+        .await
+        .unwrap_err();
+        assert!(error.to_string().contains("iterations"), "{error}");
+
+        // 재귀 항만 있는 형태는 거부합니다.
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "with recursive t(n) as (select n from t) select n from t;",
+        )
+        .await;
+        assert!(result.is_err());
+
+        assert!(engine.common_tables.read().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn with_clause_feeds_insert_update_and_delete() {
+        let (engine, wal) = setup_join_tables("test_with_dml").await;
+
+        for sql in [
+            "with extra(id, name) as (select 4, 'dave') insert into users (id, name) select id, name from extra;",
+            "with o as (select user_id from orders where total >= 300) \
+             update users set name = 'vip' where id in (select user_id from o);",
+            "with o as (select user_id from orders) delete from users where id not in (select user_id from o);",
+        ] {
+            execute_sql(&engine, wal.clone(), sql)
+                .await
+                .unwrap_or_else(|error| panic!("{sql} failed: {error}"));
+        }
+
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select id, name from users order by id;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            result
+                .rows
+                .iter()
+                .map(|row| row.fields.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                vec![
+                    ExecuteField::Integer(1),
+                    ExecuteField::String("alice".into())
+                ],
+                vec![ExecuteField::Integer(2), ExecuteField::String("vip".into())],
+            ]
+        );
+    }
+
+    fn all_rows(result: &crate::engine::types::ExecuteResult) -> Vec<Vec<ExecuteField>> {
+        result.rows.iter().map(|row| row.fields.clone()).collect()
+    }
+
+    fn integer_rows(rows: &[&[i64]]) -> Vec<Vec<ExecuteField>> {
+        rows.iter()
+            .map(|row| {
+                row.iter()
+                    .map(|value| ExecuteField::Integer(*value))
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn ranking_window_functions() {
+        let (engine, wal) = setup_join_tables("test_ranking_window_functions").await;
+
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select id, row_number() over (order by total desc), rank() over (partition by user_id order by total), dense_rank() over (order by user_id), ntile(2) over (order by id) from orders order by id;",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            all_rows(&result),
+            integer_rows(&[
+                &[10, 4, 1, 1, 1],
+                &[11, 3, 2, 1, 1],
+                &[12, 2, 1, 2, 2],
+                &[13, 1, 1, 3, 2],
+            ])
+        );
+
+        // 윈도우 함수는 WHERE와 GROUP BY 뒤의 행을 대상으로, ORDER BY와 LIMIT 앞에서 계산됩니다.
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select user_id, sum(total), rank() over (order by sum(total) desc) from orders where id > 10 group by user_id order by rank() over (order by sum(total) desc), user_id;",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            all_rows(&result),
+            integer_rows(&[&[9, 400, 1], &[2, 300, 2], &[1, 200, 3]])
+        );
+
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select id, count(*) over () from orders limit 1;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(all_rows(&result), integer_rows(&[&[10, 4]]));
+
+        let result = execute_sql(&engine, wal.clone(), "select row_number() over ();")
+            .await
+            .unwrap();
+        assert_eq!(first_column(&result), vec![ExecuteField::Integer(1)]);
+    }
+
+    #[tokio::test]
+    async fn value_window_functions() {
+        let (engine, wal) = setup_join_tables("test_value_window_functions").await;
+
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select id, lag(total) over (order by id), lead(total, 2, 0) over (order by id), first_value(total) over (partition by user_id order by id), last_value(total) over (partition by user_id order by id rows between current row and unbounded following) from orders order by id;",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            all_rows(&result),
+            vec![
+                vec![
+                    ExecuteField::Integer(10),
+                    ExecuteField::Null,
+                    ExecuteField::Integer(300),
+                    ExecuteField::Integer(100),
+                    ExecuteField::Integer(200),
+                ],
+                vec![
+                    ExecuteField::Integer(11),
+                    ExecuteField::Integer(100),
+                    ExecuteField::Integer(400),
+                    ExecuteField::Integer(100),
+                    ExecuteField::Integer(200),
+                ],
+                vec![
+                    ExecuteField::Integer(12),
+                    ExecuteField::Integer(200),
+                    ExecuteField::Integer(0),
+                    ExecuteField::Integer(300),
+                    ExecuteField::Integer(300),
+                ],
+                vec![
+                    ExecuteField::Integer(13),
+                    ExecuteField::Integer(300),
+                    ExecuteField::Integer(0),
+                    ExecuteField::Integer(400),
+                    ExecuteField::Integer(400),
+                ],
+            ]
+        );
+
+        let error = execute_sql(
+            &engine,
+            wal.clone(),
+            "select ntile(0) over (order by id) from orders;",
+        )
+        .await
+        .expect_err("ntile needs a positive bucket count");
+        assert!(
+            error.to_string().contains("greater than zero"),
+            "unexpected error: {error}"
+        );
+    }
+
+    #[tokio::test]
+    async fn aggregate_window_functions_with_frames() {
+        use crate::engine::ast::{DMLStatement, SQLStatement};
+        use crate::engine::types::ExecuteColumnType;
+
+        let (engine, wal) = setup_join_tables("test_aggregate_window_frames").await;
+
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select id, sum(total) over (), sum(total) over (order by id), sum(total) over (order by id rows between 1 preceding and 1 following), count(*) over (partition by user_id), sum(total) over (order by user_id range between current row and 7 following) from orders order by id;",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            all_rows(&result),
+            integer_rows(&[
+                &[10, 1000, 100, 300, 2, 600],
+                &[11, 1000, 300, 600, 2, 600],
+                &[12, 1000, 600, 900, 1, 700],
+                &[13, 1000, 1000, 700, 1, 400],
+            ])
+        );
+
+        // 기본 프레임은 같은 ORDER BY 값의 행까지 포함합니다.
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select id, sum(total) over (order by user_id) from orders order by id;",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            all_rows(&result),
+            integer_rows(&[&[10, 300], &[11, 300], &[12, 600], &[13, 1000]])
+        );
+
+        let mut parser = Parser::with_string(
+            "select rank() over (order by id), avg(total) over (), lag(total) over (order by id) from orders;"
+                .to_string(),
+        )
+        .unwrap();
+        let statement = parser
+            .parse(ParserContext::default().set_default_database("rrdb".to_string()))
+            .unwrap()
+            .remove(0);
+        let SQLStatement::DML(DMLStatement::SelectQuery(query)) = statement else {
+            panic!("not a select query");
+        };
+
+        assert_eq!(
+            engine
+                .describe_select_columns(query)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|column| column.data_type)
+                .collect::<Vec<_>>(),
+            vec![
+                ExecuteColumnType::Integer,
+                ExecuteColumnType::Float,
+                ExecuteColumnType::Integer,
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn case_and_cast_expressions() {
+        let (engine, wal) = setup_join_tables("test_case_and_cast_expressions").await;
+
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select id, case when total >= 300 then 'big' when total >= 200 then 'mid' else 'small' end, case user_id when 1 then 'one' when 2 then 'two' end from orders order by id;",
+        )
+        .await
+        .unwrap();
+
+        let string = |value: &str| ExecuteField::String(value.into());
+        assert_eq!(
+            all_rows(&result),
+            vec![
+                vec![ExecuteField::Integer(10), string("small"), string("one")],
+                vec![ExecuteField::Integer(11), string("mid"), string("one")],
+                vec![ExecuteField::Integer(12), string("big"), string("two")],
+                vec![ExecuteField::Integer(13), string("big"), ExecuteField::Null],
+            ]
+        );
+
+        // 결과 컬럼 타입은 변환 대상 타입을 따르고, 정수와 실수가 섞인 CASE는 실수가 됩니다.
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select case when id = 10 then 1 else 2.5 end, cast('42' as integer), '1.5'::float, 3.7::int, true::int, 0::bool, total::varchar(2), id::text from orders where id = 10;",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            all_rows(&result),
+            vec![vec![
+                ExecuteField::Float(1.0),
+                ExecuteField::Integer(42),
+                ExecuteField::Float(1.5),
+                ExecuteField::Integer(4),
+                ExecuteField::Integer(1),
+                ExecuteField::Bool(false),
+                string("10"),
+                string("10"),
+            ]]
+        );
+        assert_eq!(
+            result
+                .columns
+                .iter()
+                .map(|column| column.data_type.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                ExecuteColumnType::Float,
+                ExecuteColumnType::Integer,
+                ExecuteColumnType::Float,
+                ExecuteColumnType::Integer,
+                ExecuteColumnType::Integer,
+                ExecuteColumnType::Bool,
+                ExecuteColumnType::String,
+                ExecuteColumnType::String,
+            ]
+        );
+
+        // WHERE와 ORDER BY에서도 쓸 수 있습니다.
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select id from orders where case when user_id = 9 then false else true end order by case when id = 12 then 0 else id end;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(all_rows(&result), integer_rows(&[&[12], &[10], &[11]]));
+
+        // NULL은 단순 CASE의 어떤 WHEN 값과도 일치하지 않습니다.
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select case null when null then 1 else 0 end, null::int;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            all_rows(&result),
+            vec![vec![ExecuteField::Integer(0), ExecuteField::Null]]
+        );
+
+        for (sql, message) in [
+            (
+                "select 'abc'::int;",
+                "invalid input syntax for type integer: \"abc\"",
+            ),
+            ("select 1.5::bool;", "cannot cast type float to boolean"),
+            (
+                "select case when 1 then 'a' end;",
+                "argument of CASE/WHEN must be type boolean",
+            ),
+            (
+                "select case when true then 1 else 'a' end;",
+                "CASE types integer and varchar cannot be matched",
+            ),
+        ] {
+            let error = execute_sql(&engine, wal.clone(), sql).await.expect_err(sql);
+
+            assert!(error.to_string().contains(message), "{sql}: {error}");
+        }
+    }
+
+    #[tokio::test]
+    async fn scalar_and_conditional_functions() {
+        let (engine, wal) = setup_join_tables("test_scalar_and_conditional_functions").await;
+
+        let string = |value: &str| ExecuteField::String(value.into());
+
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select upper(name), length(name), substring(name, 2, 2), position('o' in name), concat(name, '-', id, null), replace(name, 'b', 'B'), split_part('a,b,c', ',', id) from users order by id;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            all_rows(&result),
+            vec![
+                vec![
+                    string("ALICE"),
+                    ExecuteField::Integer(5),
+                    string("li"),
+                    ExecuteField::Integer(0),
+                    string("alice-1"),
+                    string("alice"),
+                    string("a"),
+                ],
+                vec![
+                    string("BOB"),
+                    ExecuteField::Integer(3),
+                    string("ob"),
+                    ExecuteField::Integer(2),
+                    string("bob-2"),
+                    string("BoB"),
+                    string("b"),
+                ],
+                vec![
+                    string("CAROL"),
+                    ExecuteField::Integer(5),
+                    string("ar"),
+                    ExecuteField::Integer(4),
+                    string("carol-3"),
+                    string("carol"),
+                    string("c"),
+                ],
+            ]
+        );
+
+        // 수학 함수와 조건 함수, 그리고 결과 컬럼 타입
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select abs(-total), round(total::float / 3.0, 1), mod(total, 7), sqrt(total), nullif(user_id, 1), coalesce(null, user_id, 2.5), greatest(id, 11, null), least(total, 250) from orders where id <= 11 order by id;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            all_rows(&result),
+            vec![
+                vec![
+                    ExecuteField::Integer(100),
+                    ExecuteField::Float(33.3),
+                    ExecuteField::Integer(2),
+                    ExecuteField::Float(10.0),
+                    ExecuteField::Null,
+                    ExecuteField::Float(1.0),
+                    ExecuteField::Integer(11),
+                    ExecuteField::Integer(100),
+                ],
+                vec![
+                    ExecuteField::Integer(200),
+                    ExecuteField::Float(66.7),
+                    ExecuteField::Integer(4),
+                    ExecuteField::Float(200_f64.sqrt()),
+                    ExecuteField::Null,
+                    ExecuteField::Float(1.0),
+                    ExecuteField::Integer(11),
+                    ExecuteField::Integer(200),
+                ],
+            ]
+        );
+        assert_eq!(
+            result
+                .columns
+                .iter()
+                .map(|column| column.data_type.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                ExecuteColumnType::Integer,
+                ExecuteColumnType::Float,
+                ExecuteColumnType::Integer,
+                ExecuteColumnType::Float,
+                ExecuteColumnType::Integer,
+                ExecuteColumnType::Float,
+                ExecuteColumnType::Integer,
+                ExecuteColumnType::Integer,
+            ]
+        );
+
+        // WHERE 절에서도 쓸 수 있습니다.
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select id from users where lower(upper(name)) = 'bob' or trim('  carol ') = name order by id;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(all_rows(&result), integer_rows(&[&[2], &[3]]));
+
+        let result = execute_sql(&engine, wal.clone(), "select random();")
+            .await
+            .unwrap();
+        assert!(matches!(
+            all_rows(&result)[0][0],
+            ExecuteField::Float(value) if (0.0..1.0).contains(&value)
+        ));
+
+        // COALESCE는 NULL이 아닌 값을 찾으면 나머지 인자를 계산하지 않습니다.
+        let result = execute_sql(&engine, wal.clone(), "select coalesce(1, 1 / 0);")
+            .await
+            .unwrap();
+        assert_eq!(all_rows(&result), integer_rows(&[&[1]]));
+
+        for (sql, message) in [
+            (
+                "select length(1);",
+                "function length(integer) does not exist",
+            ),
+            (
+                "select substring('abc');",
+                "function substring(varchar) does not exist",
+            ),
+            (
+                "select coalesce(1, 'a');",
+                "COALESCE types integer and varchar cannot be matched",
+            ),
+            ("select mod(1, 0);", "division by zero"),
+            (
+                "select sqrt(-1);",
+                "cannot take square root of a negative number",
+            ),
+            (
+                "select split_part('a', ',', 0);",
+                "field position must not be zero",
+            ),
+        ] {
+            let error = execute_sql(&engine, wal.clone(), sql).await.expect_err(sql);
+
+            assert!(error.to_string().contains(message), "{sql}: {error}");
+        }
+    }
+
+    #[tokio::test]
+    async fn user_defined_functions() {
+        let (engine, wal) = setup_join_tables("test_user_defined_functions").await;
+
+        for sql in [
+            "create function add_tax(amount integer) returns integer as $$ select amount + amount / 10 $$ language sql;",
+            "create function greet(varchar(100)) returns varchar(100) as $$ select concat('hi ', $1) $$;",
+            "create function double_tax(amount integer) returns integer as $$ select add_tax(add_tax(amount)) $$;",
+            "create function order_total(uid integer) returns integer as $$ select sum(total) from orders where user_id = uid $$;",
+            "create function zero(x float) returns float as $$ select x - x $$;",
+        ] {
+            execute_sql(&engine, wal.clone(), sql)
+                .await
+                .unwrap_or_else(|error| panic!("{sql} failed: {error}"));
+        }
+
+        // 본문이 표현식 하나라면 호출 위치에 펼쳐 넣습니다.
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select id, add_tax(total) from orders where id <= 11 order by id;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(integer_rows(&[&[10, 110], &[11, 220]]), all_rows(&result));
+
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select greet(name), double_tax(100) from users where id = 1;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            all_rows(&result),
+            vec![vec![
+                ExecuteField::String("hi alice".into()),
+                ExecuteField::Integer(121),
+            ]]
+        );
+
+        // FROM 절이 있는 본문은 호출마다 실행하며, 결과 행이 없으면 NULL입니다.
+        let result = execute_sql(
+            &engine,
+            wal.clone(),
+            "select id, order_total(id) from users order by id;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            all_rows(&result),
+            vec![
+                vec![ExecuteField::Integer(1), ExecuteField::Integer(300)],
+                vec![ExecuteField::Integer(2), ExecuteField::Integer(300)],
+                vec![ExecuteField::Integer(3), ExecuteField::Null],
+            ]
+        );
+
+        // 두 번 참조되는 난수 인자는 복사하지 않고 한 번만 평가합니다.
+        let result = execute_sql(&engine, wal.clone(), "select zero(random());")
+            .await
+            .unwrap();
+        assert_eq!(all_rows(&result), vec![vec![ExecuteField::Float(0.0)]]);
+
+        // 같은 이름은 OR REPLACE로만 다시 정의할 수 있습니다.
+        let error = execute_sql(
+            &engine,
+            wal.clone(),
+            "create function add_tax(amount integer) returns integer as $$ select amount $$;",
+        )
+        .await
+        .unwrap_err();
+        assert!(error.to_string().contains("already exists"), "{error}");
+
+        // 반환 타입으로 바꿀 수 없는 본문과 내장 함수와 같은 이름은 만들 때 거부합니다.
+        for (sql, message) in [
+            (
+                "create function bad_literal() returns integer as $$ select 'abc' $$;",
+                "invalid input syntax for type integer: \"abc\"",
+            ),
+            (
+                "create function bad_name(uid integer) returns integer as $$ select name from users where id = uid $$;",
+                "return type mismatch in function declared to return integer: body returns varchar",
+            ),
+            (
+                "create function bad_greeting(varchar(100)) returns boolean as $$ select greet($1) $$;",
+                "return type mismatch in function declared to return boolean: body returns varchar",
+            ),
+            (
+                "create function lower(x varchar(10)) returns varchar(10) as $$ select x $$;",
+                "function lower is a built-in function and cannot be redefined",
+            ),
+        ] {
+            let error = execute_sql(&engine, wal.clone(), sql).await.expect_err(sql);
+            assert!(error.to_string().contains(message), "{sql}: {error}");
+        }
+
+        for sql in [
+            "create function answer() returns integer as $$ select '42' $$;",
+            "create function user_name(uid integer) returns varchar(100) as $$ select name from users where id = uid $$;",
+        ] {
+            execute_sql(&engine, wal.clone(), sql)
+                .await
+                .unwrap_or_else(|error| panic!("{sql} failed: {error}"));
+        }
+
+        let result = execute_sql(&engine, wal.clone(), "select answer(), user_name(2);")
+            .await
+            .unwrap();
+        assert_eq!(
+            all_rows(&result),
+            vec![vec![
+                ExecuteField::Integer(42),
+                ExecuteField::String("bob".into()),
+            ]]
+        );
+
+        execute_sql(
+            &engine,
+            wal.clone(),
+            "create or replace function add_tax(amount integer) returns integer as $$ select amount * 2 $$;",
+        )
+        .await
+        .unwrap();
+
+        let result = execute_sql(&engine, wal.clone(), "select add_tax(1), double_tax(1);")
+            .await
+            .unwrap();
+        assert_eq!(integer_rows(&[&[2, 4]]), all_rows(&result));
+
+        let error = execute_sql(&engine, wal.clone(), "select add_tax(1, 2);")
+            .await
+            .unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("takes 1 arguments, but 2 were given"),
+            "{error}"
+        );
+
+        // 재귀 호출은 거부합니다.
+        for sql in [
+            "create function loop_a(x integer) returns integer as $$ select loop_b(x) $$;",
+            "create function loop_b(x integer) returns integer as $$ select loop_a(x) $$;",
+        ] {
+            execute_sql(&engine, wal.clone(), sql).await.unwrap();
+        }
+
+        let error = execute_sql(&engine, wal.clone(), "select loop_a(1);")
+            .await
+            .unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("recursive function calls are not supported: loop_a -> loop_b -> loop_a"),
+            "{error}"
+        );
+
+        // DROP FUNCTION
+        let error = execute_sql(&engine, wal.clone(), "drop function add_tax(varchar(10));")
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("does not exist"), "{error}");
+
+        execute_sql(&engine, wal.clone(), "drop function add_tax(integer);")
+            .await
+            .unwrap();
+        execute_sql(&engine, wal.clone(), "drop function if exists add_tax;")
+            .await
+            .unwrap();
+
+        for sql in ["select add_tax(1);", "drop function add_tax;"] {
+            let error = execute_sql(&engine, wal.clone(), sql).await.unwrap_err();
+            assert!(
+                error
+                    .to_string()
+                    .contains("function add_tax does not exist"),
+                "{sql}: {error}"
+            );
         }
     }
 }
@@ -0,0 +1,272 @@
+//! 집합 연산 (UNION / INTERSECT / EXCEPT) 실행
+//!
+//! 양쪽 쿼리를 각각 끝까지 실행한 뒤, 결과 행 전체를 값 목록으로 비교해서 결합합니다.
+//! NULL끼리는 같은 값으로 봅니다. ALL이 없으면 결과에서 중복 행을 제거합니다.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::engine::DBEngine;
+use crate::engine::ast::dml::parts::set_operation::SetOperator;
+use crate::engine::ast::dml::plan::select::set_operation::SetOperationPlan;
+use crate::engine::ast::types::TableName;
+use crate::engine::expression::ReduceContext;
+use crate::engine::schema::row::{TableDataField, TableDataFieldType, TableDataRow};
+use crate::engine::types::{ExecuteColumn, ExecuteColumnType, ExecuteResult};
+use crate::errors;
+use crate::errors::execute_error::ExecuteError;
+use crate::errors::type_error::TypeError;
+
+/// 집합 연산 결과 필드가 속하는 가상 테이블 이름
+const SET_OPERATION_TABLE_NAME: &str = "?set_operation?";
+
+impl DBEngine {
+    /// 집합 연산을 실행합니다. 결과 행의 필드는 반환하는 컬럼 순서와 같습니다.
+    pub(crate) async fn set_operation(
+        &self,
+        plan: SetOperationPlan,
+        outer: Option<Box<ReduceContext>>,
+    ) -> errors::Result<(Vec<ExecuteColumn>, Vec<TableDataRow>)> {
+        let outer = outer.map(|outer| *outer);
+
+        let left = Box::pin(self.select_with_outer(*plan.left, outer.clone())).await?;
+        let right = Box::pin(self.select_with_outer(*plan.right, outer)).await?;
+
+        let columns = set_operation_columns(&plan.operator, &left.columns, &right.columns)?;
+
+        let rows = combine_rows(
+            &plan.operator,
+            plan.all,
+            result_values(left, &columns),
+            result_values(right, &columns),
+        );
+
+        let table_name = TableName::new(None, SET_OPERATION_TABLE_NAME.into());
+
+        let rows = rows
+            .into_iter()
+            .map(|values| TableDataRow {
+                fields: values
+                    .into_iter()
+                    .zip(&columns)
+                    .map(|(data, column)| TableDataField {
+                        table_name: table_name.clone(),
+                        column_name: column.name.clone(),
+                        data,
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        Ok((columns, rows))
+    }
+}
+
+/// 양쪽 결과 컬럼의 개수와 타입이 맞는지 확인하고, 결합된 결과의 컬럼을 정합니다.
+/// 이름은 왼쪽을 따르고, 타입은 NULL이면 반대쪽을, 정수와 실수가 섞이면 실수를 씁니다.
+pub(crate) fn set_operation_columns(
+    operator: &SetOperator,
+    left: &[ExecuteColumn],
+    right: &[ExecuteColumn],
+) -> errors::Result<Vec<ExecuteColumn>> {
+    if left.len() != right.len() {
+        return Err(ExecuteError::wrap(format!(
+            "each {} query must have the same number of columns",
+            operator.name()
+        )));
+    }
+
+    left.iter()
+        .zip(right)
+        .map(|(left, right)| {
+            let data_type = match (&left.data_type, &right.data_type) {
+                (lhs, rhs) if lhs == rhs => lhs.clone(),
+                (ExecuteColumnType::Null, other) | (other, ExecuteColumnType::Null) => {
+                    other.clone()
+                }
+                (ExecuteColumnType::Integer, ExecuteColumnType::Float)
+                | (ExecuteColumnType::Float, ExecuteColumnType::Integer) => {
+                    ExecuteColumnType::Float
+                }
+                (lhs, rhs) => {
+                    return Err(TypeError::wrap(format!(
+                        "{} types {:?} and {:?} cannot be matched",
+                        operator.name(),
+                        lhs,
+                        rhs
+                    )));
+                }
+            };
+
+            Ok(ExecuteColumn {
+                name: left.name.clone(),
+                data_type,
+            })
+        })
+        .collect()
+}
+
+/// 결과 행을 비교 가능한 값 목록으로 바꿉니다. 실수 컬럼의 정수 값은 실수로 맞춥니다.
+pub(crate) fn result_values(
+    result: ExecuteResult,
+    columns: &[ExecuteColumn],
+) -> Vec<Vec<TableDataFieldType>> {
+    result
+        .rows
+        .into_iter()
+        .map(|row| {
+            row.fields
+                .into_iter()
+                .zip(columns)
+                .map(|(field, column)| match (field.into(), &column.data_type) {
+                    (TableDataFieldType::Integer(value), ExecuteColumnType::Float) => {
+                        TableDataFieldType::Float((value as f64).into())
+                    }
+                    (value, _) => value,
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// 집합 연산의 의미대로 행을 결합합니다.
+///
+/// INTERSECT ALL은 양쪽에 나온 횟수 중 작은 만큼, EXCEPT ALL은 왼쪽 횟수에서 오른쪽 횟수를 뺀 만큼 남깁니다.
+fn combine_rows(
+    operator: &SetOperator,
+    all: bool,
+    left: Vec<Vec<TableDataFieldType>>,
+    right: Vec<Vec<TableDataFieldType>>,
+) -> Vec<Vec<TableDataFieldType>> {
+    let rows = match operator {
+        SetOperator::Union => {
+            let mut rows = left;
+            rows.extend(right);
+            rows
+        }
+        SetOperator::Intersect | SetOperator::Except => {
+            let mut right_counts: HashMap<Vec<TableDataFieldType>, usize> = HashMap::new();
+            for row in right {
+                *right_counts.entry(row).or_default() += 1;
+            }
+
+            let intersect = *operator == SetOperator::Intersect;
+
+            left.into_iter()
+                .filter(|row| match right_counts.get_mut(row) {
+                    Some(count) if *count > 0 => {
+                        if all {
+                            *count -= 1;
+                        }
+                        intersect
+                    }
+                    _ => !intersect,
+                })
+                .collect()
+        }
+    };
+
+    if all {
+        return rows;
+    }
+
+    let mut seen = HashSet::new();
+    rows.into_iter()
+        .filter(|row| seen.insert(row.clone()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(values: &[i64]) -> Vec<Vec<TableDataFieldType>> {
+        values
+            .iter()
+            .map(|value| vec![TableDataFieldType::Integer(*value)])
+            .collect()
+    }
+
+    #[test]
+    fn combine_rows_follows_set_semantics() {
+        let left = || rows(&[1, 1, 1, 2, 3]);
+        let right = || rows(&[1, 1, 3, 4]);
+
+        let cases = [
+            (SetOperator::Union, true, rows(&[1, 1, 1, 2, 3, 1, 1, 3, 4])),
+            (SetOperator::Union, false, rows(&[1, 2, 3, 4])),
+            (SetOperator::Intersect, true, rows(&[1, 1, 3])),
+            (SetOperator::Intersect, false, rows(&[1, 3])),
+            (SetOperator::Except, true, rows(&[1, 2])),
+            (SetOperator::Except, false, rows(&[2])),
+        ];
+
+        for (operator, all, expected) in cases {
+            assert_eq!(
+                combine_rows(&operator, all, left(), right()),
+                expected,
+                "{} all={}",
+                operator.name(),
+                all
+            );
+        }
+    }
+
+    #[test]
+    fn combine_rows_treats_nulls_as_equal() {
+        let left = vec![
+            vec![TableDataFieldType::Null],
+            vec![TableDataFieldType::Null],
+        ];
+        let right = vec![vec![TableDataFieldType::Null]];
+
+        assert_eq!(
+            combine_rows(&SetOperator::Union, false, left.clone(), right.clone()),
+            vec![vec![TableDataFieldType::Null]]
+        );
+        assert!(combine_rows(&SetOperator::Except, false, left, right).is_empty());
+    }
+
+    #[test]
+    fn set_operation_columns_checks_count_and_types() {
+        let column = |name: &str, data_type| ExecuteColumn {
+            name: name.into(),
+            data_type,
+        };
+
+        assert_eq!(
+            set_operation_columns(
+                &SetOperator::Union,
+                &[
+                    column("a", ExecuteColumnType::Integer),
+                    column("b", ExecuteColumnType::Null),
+                ],
+                &[
+                    column("x", ExecuteColumnType::Float),
+                    column("y", ExecuteColumnType::String),
+                ],
+            )
+            .unwrap(),
+            vec![
+                column("a", ExecuteColumnType::Float),
+                column("b", ExecuteColumnType::String),
+            ]
+        );
+
+        assert!(
+            set_operation_columns(
+                &SetOperator::Intersect,
+                &[column("a", ExecuteColumnType::Integer)],
+                &[],
+            )
+            .is_err()
+        );
+        assert!(
+            set_operation_columns(
+                &SetOperator::Except,
+                &[column("a", ExecuteColumnType::Integer)],
+                &[column("a", ExecuteColumnType::String)],
+            )
+            .is_err()
+        );
+    }
+}
@@ -0,0 +1,262 @@
+use std::collections::HashMap;
+
+use crate::engine::DBEngine;
+use crate::engine::ast::dml::parts::from::FromTarget;
+use crate::engine::ast::dml::plan::select::subquery::SelectSubqueryPlan;
+use crate::engine::ast::dml::select::SelectQuery;
+use crate::engine::ast::types::{SQLExpression, SelectColumn, TableName};
+use crate::engine::expression::ReduceContext;
+use crate::engine::schema::row::{TableDataField, TableDataRow};
+use crate::engine::schema::table::TableSchema;
+use crate::errors;
+
+/// 쿼리 한 단계에서 참조할 수 있는 테이블과 컬럼 목록
+struct SubqueryScope {
+    tables: Vec<(TableName, Option<String>, Vec<String>)>,
+}
+
+impl SubqueryScope {
+    fn resolves(&self, column: &SelectColumn) -> bool {
+        self.tables.iter().any(|(table_name, _, columns)| {
+            let table_matched = match &column.table_name {
+                Some(qualifier) => self.qualifier_matches(qualifier, table_name),
+                None => true,
+            };
+
+            table_matched && columns.contains(&column.column_name)
+        })
+    }
+
+    /// alias를 먼저 보고, alias가 붙은 테이블은 원래 테이블명으로 찾지 않습니다.
+    /// (ReduceContext의 컬럼 해석과 같은 규칙)
+    fn qualifier_matches(&self, qualifier: &str, table_name: &TableName) -> bool {
+        let aliased = |table: &TableName| {
+            self.tables
+                .iter()
+                .any(|(each, alias, _)| each == table && alias.is_some())
+        };
+
+        match self
+            .tables
+            .iter()
+            .find(|(_, alias, _)| alias.as_deref() == Some(qualifier))
+        {
+            Some((aliased_table, _, _)) => aliased_table == table_name,
+            None => table_name.table_name == qualifier && !aliased(table_name),
+        }
+    }
+}
+
+/// FROM, JOIN 절의 (테이블, alias) 목록. FROM 절의 파생 테이블은 포함하지 않습니다.
+fn query_tables(query: &SelectQuery) -> Vec<(TableName, Option<String>)> {
+    let mut tables = vec![];
+
+    if let Some(from) = &query.from_table
+        && let FromTarget::Table(table_name) = &from.from
+    {
+        tables.push((table_name.clone(), from.alias.clone()));
+    }
+
+    for join in &query.join_clause {
+        tables.push((join.right.clone(), join.right_alias.clone()));
+    }
+
+    tables
+}
+
+impl DBEngine {
+    /// 서브쿼리가 바깥 쿼리의 컬럼을 참조하는지(상관 서브쿼리인지) 판별합니다.
+    ///
+    /// 서브쿼리 안의 모든 컬럼 참조가 서브쿼리(또는 그 안쪽 서브쿼리)의 테이블로
+    /// 해석되면 비상관입니다.
+    pub(crate) async fn subquery_is_correlated(&self, query: &SelectQuery) -> errors::Result<bool> {
+        let mut scopes = vec![];
+        self.has_outer_reference(query, &mut scopes).await
+    }
+
+    async fn has_outer_reference(
+        &self,
+        query: &SelectQuery,
+        scopes: &mut Vec<SubqueryScope>,
+    ) -> errors::Result<bool> {
+        // 집합 연산은 피연산자 중 하나라도 바깥을 참조하면 상관입니다.
+        // (결합된 결과의 ORDER BY는 결과 컬럼만 참조합니다)
+        if query.set_operation.is_some() {
+            for block in query.blocks() {
+                if Box::pin(self.has_outer_reference(block, scopes)).await? {
+                    return Ok(true);
+                }
+            }
+
+            return Ok(false);
+        }
+
+        // 자체 WITH 절이 있는 서브쿼리는 실행 전에 그 테이블의 컬럼을 알 수 없으므로
+        // 상관 서브쿼리로 보고 바깥 행마다 실행합니다.
+        if query.with_clause.is_some() {
+            return Ok(true);
+        }
+
+        let mut scope = SubqueryScope { tables: vec![] };
+
+        // 파생 테이블은 바깥 행을 참조할 수 없으므로 결과 컬럼만 범위에 넣습니다.
+        if let Some(from) = &query.from_table
+            && let FromTarget::Subquery(derived) = &from.from
+        {
+            let Some(alias) = &from.alias else {
+                return Ok(true);
+            };
+
+            let columns = Box::pin(self.describe_select_columns(derived.query().clone()))
+                .await?
+                .into_iter()
+                .map(|e| e.name)
+                .collect();
+            scope.tables.push((
+                TableName::new(None, alias.clone()),
+                Some(alias.clone()),
+                columns,
+            ));
+        }
+
+        for (table_name, alias) in query_tables(query) {
+            let table_config = self.get_table_config_cached(table_name.clone()).await?;
+            let columns = table_config.columns.into_iter().map(|e| e.name).collect();
+            scope.tables.push((table_name, alias, columns));
+        }
+        scopes.push(scope);
+
+        let expressions = query.expressions();
+
+        let columns = expressions
+            .iter()
+            .flat_map(|e| e.get_select_column_list())
+            .chain(
+                query
+                    .group_by_clause
+                    .iter()
+                    .flat_map(|e| e.group_by_items.iter().map(|item| item.item.clone())),
+            );
+
+        let mut correlated = false;
+        for column in columns {
+            if !scopes.iter().any(|scope| scope.resolves(&column)) {
+                correlated = true;
+                break;
+            }
+        }
+
+        if !correlated {
+            'outer: for expression in &expressions {
+                for subquery in expression.get_subquery_list() {
+                    if Box::pin(self.has_outer_reference(subquery.query(), scopes)).await? {
+                        correlated = true;
+                        break 'outer;
+                    }
+                }
+            }
+        }
+
+        scopes.pop();
+
+        Ok(correlated)
+    }
+
+    /// 표현식에 포함된 서브쿼리가 참조하는 테이블 스키마를 미리 캐시에 올려둡니다.
+    /// reduce_type은 동기 함수라 서브쿼리의 결과 타입을 캐시에서 찾습니다.
+    pub(crate) async fn cache_subquery_schemas(
+        &self,
+        expression: &SQLExpression,
+    ) -> errors::Result<()> {
+        for subquery in expression.get_subquery_list() {
+            for query in subquery.query().blocks() {
+                for (table_name, _) in query_tables(query) {
+                    // WITH 절 테이블은 캐시가 아니라 레지스트리에서 찾습니다.
+                    if table_name.is_common_table() {
+                        continue;
+                    }
+
+                    self.get_table_config_cached(table_name).await?;
+                }
+
+                for expression in query.expressions() {
+                    Box::pin(self.cache_subquery_schemas(expression)).await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 서브쿼리의 결과 타입 추론용 컨텍스트. 스키마는 캐시된 것만 사용합니다.
+    /// (cache_subquery_schemas로 미리 올려둡니다)
+    pub(crate) async fn subquery_type_context(
+        &self,
+        query: &SelectQuery,
+        outer: ReduceContext,
+    ) -> ReduceContext {
+        let cache = self.table_config_cache.read().await;
+        let common_tables = self.common_tables.read().await;
+
+        let mut table_alias_map = HashMap::new();
+        let mut config_columns = vec![];
+
+        for (table_name, alias) in query_tables(query) {
+            let table_config = if table_name.is_common_table() {
+                common_tables
+                    .get(&table_name)
+                    .map(|common_table| &common_table.schema)
+            } else {
+                cache.get(&table_name)
+            };
+
+            if let Some(table_config) = table_config {
+                config_columns.extend(
+                    table_config
+                        .columns
+                        .iter()
+                        .map(|column| (table_config.table.clone(), column.clone())),
+                );
+            }
+
+            if let Some(alias) = alias {
+                table_alias_map.insert(alias, table_name);
+            }
+        }
+
+        ReduceContext {
+            row: None,
+            table_alias_map,
+            config_columns,
+            outer: Some(Box::new(outer)),
+        }
+    }
+
+    /// 파생 테이블(FROM 절 서브쿼리)을 실행하고, 결과 컬럼을 alias 테이블의 컬럼으로 바꿉니다.
+    pub(crate) async fn derived_table(
+        &self,
+        plan: SelectSubqueryPlan,
+    ) -> errors::Result<(TableSchema, Vec<TableDataRow>)> {
+        let result = Box::pin(self.select(*plan.subquery)).await?;
+        let table_config = TableSchema::derived(plan.table_name.clone(), &result.columns);
+
+        let rows = result
+            .rows
+            .into_iter()
+            .map(|row| TableDataRow {
+                fields: row
+                    .fields
+                    .into_iter()
+                    .zip(&result.columns)
+                    .map(|(field, column)| TableDataField {
+                        table_name: plan.table_name.clone(),
+                        column_name: column.name.clone(),
+                        data: field.into(),
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        Ok((table_config, rows))
+    }
+}
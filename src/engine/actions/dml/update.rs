@@ -2,17 +2,19 @@ use std::collections::HashMap;
 
 use futures::future::join_all;
 
+use crate::engine::actions::dml::join::concat_rows;
 use crate::engine::actions::index::row_index_key;
+use crate::engine::ast::dml::parts::locking::RowLockMode;
 use crate::engine::ast::dml::plan::select::scan::ScanType;
 use crate::engine::ast::dml::plan::update::update_plan::UpdatePlanItem;
 use crate::engine::ast::dml::update::UpdateQuery;
 use crate::engine::expression::ReduceContext;
 use crate::engine::optimizer::predule::Optimizer;
-use crate::engine::schema::row::TableDataFieldType;
+use crate::engine::schema::row::{TableDataFieldType, TableDataRow};
 use crate::engine::types::{
     ExecuteColumn, ExecuteColumnType, ExecuteField, ExecuteResult, ExecuteRow,
 };
-use crate::engine::wal::types::EntryType;
+use crate::engine::wal::types::{EntryType, UpdateRowsWALPayload};
 use crate::engine::{DBEngine, SharedWALManager};
 use crate::errors;
 use crate::errors::execute_error::ExecuteError;
@@ -30,26 +32,83 @@ impl DBEngine {
     /// Re-applies a previously WAL-logged UPDATE during crash recovery
     /// replay. Identical to `update()` but skips the WAL append (the
     /// operation is already durably recorded in the WAL being replayed).
-    pub(crate) async fn update_replay(&self, query: UpdateQuery) -> errors::Result<ExecuteResult> {
+    pub(crate) async fn update_replay(
+        &self,
+        mut query: UpdateQuery,
+    ) -> errors::Result<ExecuteResult> {
+        // replay 결과는 버려지므로 RETURNING은 계산하지 않습니다.
+        query.returning = None;
+
         self.update_internal(query, None).await
     }
 
+    /// WAL replay용 UPDATE ... FROM.
+    ///
+    /// 기록된 행 위치에 새 행 값을 그대로 다시 쓰므로, 이미 반영된 항목을 다시
+    /// 적용해도 결과가 같습니다. 이후에 삭제된 행은 다시 살리지 않습니다.
+    pub(crate) async fn update_rows_replay(
+        &self,
+        payload: UpdateRowsWALPayload,
+    ) -> errors::Result<()> {
+        let UpdateRowsWALPayload {
+            table_name,
+            mut updated_rows,
+        } = payload;
+
+        self.ensure_indices_loaded().await?;
+        let index_metas = self.table_index_metas(&table_name).await;
+
+        let _guard = self.row_storage_lock.lock().await;
+
+        let stored_rows = self.read_table_rows_locked(&table_name).await?;
+
+        updated_rows.retain(|(row_index, _)| matches!(stored_rows.get(*row_index), Some(Some(_))));
+
+        self.apply_row_changes_locked(
+            &table_name,
+            &stored_rows,
+            &index_metas,
+            updated_rows,
+            vec![],
+            0,
+        )
+        .await
+    }
+
     async fn update_internal(
         &self,
         query: UpdateQuery,
         wal_manager: Option<SharedWALManager>,
     ) -> errors::Result<ExecuteResult> {
+        // FROM 절이 있으면 조인 결과에 따라 대상 행이 달라지므로, 쿼리 대신
+        // 결정된 행 위치와 새 값을 WAL에 기록합니다.
+        let has_sources = !query.from_tables.is_empty();
+
         // WAL-first: 쿼리를 실행/소비하기 전에 페이로드를 미리 직렬화합니다.
         let wal_payload = match &wal_manager {
-            Some(_) => Some(
-                bincode::serialize(&query).map_err(|error| ExecuteError::wrap(error.to_string()))?,
+            Some(_) if !has_sources => Some(
+                bincode::serialize(&query)
+                    .map_err(|error| ExecuteError::wrap(error.to_string()))?,
             ),
-            None => None,
+            _ => None,
         };
 
-        let table = query.target_table.clone().unwrap().table;
+        let target = query.target_table.clone().unwrap();
+        let table = target.table.clone();
         let update_items = query.update_items.clone();
 
+        // RETURNING 절은 데이터를 바꾸기 전에 검증해 둡니다.
+        let returning = match &query.returning {
+            Some(returning) => {
+                let targets = std::iter::once(target.clone())
+                    .chain(query.from_tables.iter().cloned())
+                    .collect::<Vec<_>>();
+
+                Some(self.prepare_returning(&targets, returning).await?)
+            }
+            None => None,
+        };
+
         // 최적화 작업 (대상 테이블의 인덱스/통계로 컨텍스트 구성)
         let optimizer = Optimizer::with_context(self.build_optimizer_context(&table).await);
 
@@ -89,7 +148,9 @@ impl DBEngine {
                     }
                 }
                 // 필터링 처리
-                UpdatePlanItem::Filter(filter) => {
+                UpdatePlanItem::Filter(mut filter) => {
+                    filter.expression = self.materialize_subqueries(filter.expression).await?;
+
                     let futures = rows.iter().cloned().map(|(path, row)| {
                         let table_alias_map = table_alias_map.clone();
                         let filter = filter.clone();
@@ -98,7 +159,7 @@ impl DBEngine {
                                 row: Some(row.to_owned()),
                                 table_alias_map,
                                 config_columns: vec![],
-                                total_count: 0,
+                                outer: None,
                             };
 
                             let condition = self
@@ -126,9 +187,18 @@ impl DBEngine {
                         .map(|(path, row, _)| (path, row))
                         .collect();
                 }
+                // FROM 테이블 조인 처리
+                UpdatePlanItem::Join(join) => {
+                    rows = self
+                        .join_target_rows(rows, &join, &mut table_alias_map, &mut table_infos)
+                        .await?;
+                }
             }
         }
 
+        // 결합한 행의 앞부분이 대상 테이블의 행입니다.
+        let target_width = table_infos[0].columns.len();
+
         let config_columns = table_infos
             .into_iter()
             .flat_map(|table_info| {
@@ -146,16 +216,28 @@ impl DBEngine {
         let index_metas = self.table_index_metas(&table).await;
 
         let mut replacements = HashMap::new();
+        let mut updated_rows = vec![];
+        let mut returned_rows = vec![];
         // 인덱스 반영 목록: (index_name, old_key, new_key, row_path)
         let mut index_operations: Vec<(String, Option<String>, Option<String>, String)> = vec![];
 
-        for (location, mut row) in rows.into_iter() {
-            let old_row = row.clone();
+        for (location, joined_row) in rows.into_iter() {
+            // FROM 테이블의 여러 행과 매칭되더라도 대상 행은 한 번만 수정합니다.
+            if replacements.contains_key(&location.row_index) {
+                continue;
+            }
+
+            let old_row = TableDataRow {
+                fields: joined_row.fields[..target_width].to_vec(),
+            };
+            let mut row = old_row.clone();
+
+            // SET 절은 수정 전의 값(과 FROM 테이블의 값)을 기준으로 계산합니다.
             let reduce_context = ReduceContext {
-                row: None,
+                row: Some(joined_row.clone()),
                 table_alias_map: table_alias_map.clone(),
                 config_columns: config_columns.clone(),
-                total_count: 0,
+                outer: None,
             };
 
             for update_item in &update_items {
@@ -194,24 +276,62 @@ impl DBEngine {
                 }
             }
 
+            if returning.is_some() {
+                returned_rows.push(concat_rows(
+                    &row,
+                    &TableDataRow {
+                        fields: joined_row.fields[target_width..].to_vec(),
+                    },
+                ));
+            }
+
+            if has_sources {
+                updated_rows.push((location.row_index, row.clone()));
+            }
+
             replacements.insert(location.row_index, row);
         }
 
+        // FOR UPDATE / FOR SHARE로 잠근 행은 그 트랜잭션이 끝날 때까지 기다립니다.
+        self.lock_target_rows(
+            &table,
+            replacements.keys().copied(),
+            RowLockMode::NoKeyUpdate,
+        )
+        .await?;
+
         let affected_rows = replacements.len();
 
         if !replacements.is_empty() {
             // WAL-first: 인덱스/테이블을 변경하기 전에 먼저 durable하게 기록합니다.
             if let Some(wal_manager) = &wal_manager {
+                let (entry_type, wal_payload) = if has_sources {
+                    let payload = UpdateRowsWALPayload {
+                        table_name: table.clone(),
+                        updated_rows,
+                    };
+
+                    (
+                        EntryType::UpdateRows,
+                        Some(
+                            bincode::serialize(&payload)
+                                .map_err(|error| ExecuteError::wrap(error.to_string()))?,
+                        ),
+                    )
+                } else {
+                    (EntryType::Set, wal_payload)
+                };
+
+                let transaction_id = self.transaction_manager.writer_transaction_id();
                 wal_manager
                     .lock()
                     .await
-                    .append_record(EntryType::Set, wal_payload, None)
+                    .append_record(entry_type, wal_payload, transaction_id)
                     .await?;
             }
 
             // 인덱스 반영: 고유 제약 위반은 여기서 검출되며, 실패 시 적용분을 되돌립니다
-            for (i, (index_name, old_key, new_key, row_path)) in
-                index_operations.iter().enumerate()
+            for (i, (index_name, old_key, new_key, row_path)) in index_operations.iter().enumerate()
             {
                 if let Err(error) = self
                     .apply_index_operation(index_name, old_key, new_key, row_path)
@@ -241,6 +361,10 @@ impl DBEngine {
             }
         }
 
+        if let Some(returning) = returning {
+            return self.project_returning(returning, returned_rows).await;
+        }
+
         Ok(ExecuteResult::with_affected_rows(
             vec![ExecuteColumn {
                 name: "desc".into(),
@@ -0,0 +1,717 @@
+//! 윈도우 연산자 (OVER 절이 있는 함수 처리)
+//!
+//! 윈도우마다 입력 행을 PARTITION BY 값으로 나누고, 파티션 안을 ORDER BY 값으로 정렬한 뒤
+//! 행마다 함수 결과를 계산합니다. 결과는 입력 행의 순서를 바꾸지 않고 필드로 덧붙이며,
+//! 이후 단계(프로젝션, ORDER BY)에서는 find_window_value로 이 값을 읽습니다.
+//!
+//! 프레임은 파티션 안에서 정렬된 위치의 구간 [start, end)로 계산합니다.
+//! 프레임을 지정하지 않으면 ORDER BY가 있을 때 RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW
+//! (같은 ORDER BY 값의 행까지 포함), 없을 때는 파티션 전체입니다. (PostgreSQL과 동일)
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::engine::DBEngine;
+use crate::engine::actions::dml::aggregate::check_arity;
+use crate::engine::actions::dml::select::compare_order_values;
+use crate::engine::aggregate::Accumulator;
+use crate::engine::ast::dml::expressions::window::{
+    WindowExpression, WindowFrame, WindowFrameBound, WindowFrameUnit,
+};
+use crate::engine::ast::dml::parts::order_by::{OrderByItem, OrderByNulls, OrderByType};
+use crate::engine::ast::dml::plan::select::window::WindowPlan;
+use crate::engine::ast::types::{
+    AggregateFunction, BuiltInFunction, Function, SQLExpression, TableName, WindowFunction,
+};
+use crate::engine::expression::ReduceContext;
+use crate::engine::schema::row::{TableDataField, TableDataFieldType, TableDataRow};
+use crate::engine::types::ExecuteColumnType;
+use crate::errors;
+use crate::errors::execute_error::ExecuteError;
+
+/// 윈도우 함수 결과 필드가 속하는 가상 테이블 이름
+const WINDOW_TABLE_NAME: &str = "?window?";
+
+/// 윈도우 함수의 인자를 평가할 때 필요한 컨텍스트
+#[derive(Debug, Default, Clone)]
+pub(crate) struct WindowContext {
+    pub table_alias_map: HashMap<String, TableName>,
+    /// 상관 서브쿼리 안의 윈도우 함수라면 바깥 쿼리의 컨텍스트
+    pub outer: Option<Box<ReduceContext>>,
+}
+
+/// 윈도우 연산자 이후의 행에서 윈도우 함수 결과가 담기는 필드 이름
+fn window_field_name(window: &WindowExpression) -> String {
+    format!("{window:?}")
+}
+
+/// 윈도우 연산자가 계산해 둔 결과를 행에서 찾습니다.
+pub(crate) fn find_window_value(
+    window: &WindowExpression,
+    row: &TableDataRow,
+) -> Option<TableDataFieldType> {
+    let field_name = window_field_name(window);
+
+    row.fields
+        .iter()
+        .find(|field| {
+            field.table_name.table_name == WINDOW_TABLE_NAME && field.column_name == field_name
+        })
+        .map(|field| field.data.clone())
+}
+
+impl WindowFunction {
+    pub fn name(&self) -> &'static str {
+        match self {
+            WindowFunction::RowNumber => "row_number",
+            WindowFunction::Rank => "rank",
+            WindowFunction::DenseRank => "dense_rank",
+            WindowFunction::Lag => "lag",
+            WindowFunction::Lead => "lead",
+            WindowFunction::FirstValue => "first_value",
+            WindowFunction::LastValue => "last_value",
+            WindowFunction::Ntile => "ntile",
+        }
+    }
+
+    /// 받을 수 있는 인자 개수 (최소, 최대). LAG, LEAD는 (값, 거리, 기본값)입니다.
+    pub fn arity(&self) -> (usize, usize) {
+        match self {
+            WindowFunction::RowNumber | WindowFunction::Rank | WindowFunction::DenseRank => (0, 0),
+            WindowFunction::Lag | WindowFunction::Lead => (1, 3),
+            WindowFunction::FirstValue | WindowFunction::LastValue | WindowFunction::Ntile => {
+                (1, 1)
+            }
+        }
+    }
+
+    /// 결과 타입. 첫 번째 인자의 타입을 받아 결정합니다.
+    pub fn result_type(&self, argument_type: Option<ExecuteColumnType>) -> ExecuteColumnType {
+        match self {
+            WindowFunction::RowNumber
+            | WindowFunction::Rank
+            | WindowFunction::DenseRank
+            | WindowFunction::Ntile => ExecuteColumnType::Integer,
+            WindowFunction::Lag
+            | WindowFunction::Lead
+            | WindowFunction::FirstValue
+            | WindowFunction::LastValue => argument_type.unwrap_or(ExecuteColumnType::Null),
+        }
+    }
+}
+
+/// 행 하나에 대해 평가해 둔 윈도우 입력 값
+struct WindowInput {
+    partition_key: Vec<TableDataFieldType>,
+    order_key: Vec<TableDataFieldType>,
+    arguments: Vec<TableDataFieldType>,
+}
+
+impl DBEngine {
+    /// 윈도우 함수들을 계산해서 각 행에 결과 필드를 덧붙입니다.
+    pub(crate) async fn window(
+        &self,
+        mut rows: Vec<TableDataRow>,
+        plan: &WindowPlan,
+        context: &WindowContext,
+    ) -> errors::Result<Vec<TableDataRow>> {
+        for window in &plan.windows {
+            // 결과 필드 이름이 SELECT 목록의 표현식과 맞도록, 서브쿼리는 프로젝션과 같은 방식으로 값으로 바꿉니다.
+            let SQLExpression::Window(materialized) = self
+                .materialize_subqueries(SQLExpression::Window(Box::new(window.clone())))
+                .await?
+            else {
+                unreachable!("윈도우 표현식은 윈도우 표현식으로 유지됩니다");
+            };
+
+            check_window_arity(&materialized)?;
+
+            let mut inputs = Vec::with_capacity(rows.len());
+            for row in &rows {
+                inputs.push(self.window_input(&materialized, row, context).await?);
+            }
+
+            let values = compute_window(&materialized, &inputs)?;
+
+            // 프로젝션은 서브쿼리를 값으로 바꾼 표현식으로, ORDER BY는 원래 표현식으로 찾습니다.
+            let mut field_names = vec![window_field_name(&materialized)];
+            if *materialized != *window {
+                field_names.push(window_field_name(window));
+            }
+
+            for (row, data) in rows.iter_mut().zip(values) {
+                for field_name in &field_names {
+                    row.fields.push(TableDataField {
+                        table_name: TableName::new(None, WINDOW_TABLE_NAME.into()),
+                        column_name: field_name.clone(),
+                        data: data.clone(),
+                    });
+                }
+            }
+        }
+
+        Ok(rows)
+    }
+
+    async fn window_input(
+        &self,
+        window: &WindowExpression,
+        row: &TableDataRow,
+        context: &WindowContext,
+    ) -> errors::Result<WindowInput> {
+        let reduce_context = ReduceContext {
+            row: Some(row.clone()),
+            table_alias_map: context.table_alias_map.clone(),
+            config_columns: vec![],
+            outer: context.outer.clone(),
+        };
+
+        let mut partition_key = Vec::with_capacity(window.partition_by.len());
+        for expression in &window.partition_by {
+            partition_key.push(
+                self.reduce_expression(expression.clone(), reduce_context.clone())
+                    .await?,
+            );
+        }
+
+        let mut order_key = Vec::with_capacity(window.order_by.len());
+        for order_by_item in &window.order_by {
+            order_key.push(
+                self.reduce_expression(order_by_item.item.clone(), reduce_context.clone())
+                    .await?,
+            );
+        }
+
+        let mut arguments = Vec::with_capacity(window.call.arguments.len());
+        for argument in &window.call.arguments {
+            arguments.push(
+                self.reduce_expression(argument.clone(), reduce_context.clone())
+                    .await?,
+            );
+        }
+
+        Ok(WindowInput {
+            partition_key,
+            order_key,
+            arguments,
+        })
+    }
+}
+
+fn check_window_arity(window: &WindowExpression) -> errors::Result<()> {
+    let Function::BuiltIn(BuiltInFunction::Window(function)) = &window.call.function else {
+        return check_arity(&window.call);
+    };
+
+    let (min_arguments, max_arguments) = function.arity();
+    let arguments = window.call.arguments.len();
+
+    if arguments < min_arguments || arguments > max_arguments {
+        return Err(ExecuteError::wrap(format!(
+            "{} function takes {} parameter(s).",
+            function.name(),
+            max_arguments
+        )));
+    }
+
+    Ok(())
+}
+
+/// 입력 순서대로 각 행의 윈도우 함수 결과를 계산합니다.
+fn compute_window(
+    window: &WindowExpression,
+    inputs: &[WindowInput],
+) -> errors::Result<Vec<TableDataFieldType>> {
+    // 파티션은 처음 나온 순서대로 둡니다.
+    let mut partition_indexes: HashMap<&[TableDataFieldType], usize> = HashMap::new();
+    let mut partitions: Vec<Vec<usize>> = vec![];
+
+    for (index, input) in inputs.iter().enumerate() {
+        let partition = *partition_indexes
+            .entry(input.partition_key.as_slice())
+            .or_insert_with(|| {
+                partitions.push(vec![]);
+                partitions.len() - 1
+            });
+
+        partitions[partition].push(index);
+    }
+
+    let mut values = vec![TableDataFieldType::Null; inputs.len()];
+
+    for mut partition in partitions {
+        // 같은 ORDER BY 값끼리는 입력 순서를 유지합니다.
+        partition.sort_by(|l, r| {
+            compare_order_values(
+                &window.order_by,
+                &inputs[*l].order_key,
+                &inputs[*r].order_key,
+            )
+        });
+
+        let sorted = partition
+            .iter()
+            .map(|index| &inputs[*index])
+            .collect::<Vec<_>>();
+
+        for (position, value) in compute_partition(window, &sorted)?.into_iter().enumerate() {
+            values[partition[position]] = value;
+        }
+    }
+
+    Ok(values)
+}
+
+/// 정렬된 파티션 하나의 결과를 위치 순서대로 계산합니다.
+fn compute_partition(
+    window: &WindowExpression,
+    rows: &[&WindowInput],
+) -> errors::Result<Vec<TableDataFieldType>> {
+    let peers = peer_groups(window, rows);
+
+    match &window.call.function {
+        Function::BuiltIn(BuiltInFunction::Window(function)) => match function {
+            WindowFunction::RowNumber => Ok((1..=rows.len() as i64)
+                .map(TableDataFieldType::Integer)
+                .collect()),
+            WindowFunction::Rank => Ok(peers
+                .iter()
+                .map(|(start, _, _)| TableDataFieldType::Integer(*start as i64 + 1))
+                .collect()),
+            WindowFunction::DenseRank => Ok(peers
+                .iter()
+                .map(|(_, _, group)| TableDataFieldType::Integer(*group as i64 + 1))
+                .collect()),
+            WindowFunction::Ntile => ntile(rows),
+            WindowFunction::Lag => shift(rows, -1),
+            WindowFunction::Lead => shift(rows, 1),
+            WindowFunction::FirstValue | WindowFunction::LastValue => {
+                let first = *function == WindowFunction::FirstValue;
+
+                (0..rows.len())
+                    .map(|position| {
+                        let (start, end) = frame_bounds(window, rows, &peers, position)?;
+
+                        Ok(if start >= end {
+                            TableDataFieldType::Null
+                        } else if first {
+                            rows[start].arguments[0].clone()
+                        } else {
+                            rows[end - 1].arguments[0].clone()
+                        })
+                    })
+                    .collect()
+            }
+        },
+        function => match function.as_aggregate() {
+            Some(aggregate) => aggregate_over_frames(window, rows, &peers, aggregate),
+            None => Err(ExecuteError::wrap(
+                "OVER is allowed only for window and aggregate functions",
+            )),
+        },
+    }
+}
+
+/// 위치마다 (같은 ORDER BY 값 묶음의 시작, 끝, 묶음 번호)
+/// ORDER BY가 없으면 파티션 전체가 한 묶음입니다.
+fn peer_groups(window: &WindowExpression, rows: &[&WindowInput]) -> Vec<(usize, usize, usize)> {
+    let mut peers = Vec::with_capacity(rows.len());
+    let mut start = 0;
+    let mut group = 0;
+
+    while start < rows.len() {
+        let mut end = start + 1;
+        while end < rows.len()
+            && compare_order_values(
+                &window.order_by,
+                &rows[start].order_key,
+                &rows[end].order_key,
+            ) == Ordering::Equal
+        {
+            end += 1;
+        }
+
+        peers.extend((start..end).map(|_| (start, end, group)));
+        start = end;
+        group += 1;
+    }
+
+    peers
+}
+
+/// NTILE(n): 파티션을 최대한 같은 크기의 n개 묶음으로 나눕니다. 앞쪽 묶음이 한 행씩 더 클 수 있습니다.
+fn ntile(rows: &[&WindowInput]) -> errors::Result<Vec<TableDataFieldType>> {
+    let size = rows.len() as i64;
+
+    rows.iter()
+        .enumerate()
+        .map(|(position, row)| {
+            let buckets = match &row.arguments[0] {
+                TableDataFieldType::Null => return Ok(TableDataFieldType::Null),
+                TableDataFieldType::Integer(buckets) if *buckets > 0 => *buckets,
+                TableDataFieldType::Integer(_) => {
+                    return Err(ExecuteError::wrap(
+                        "argument of ntile must be greater than zero",
+                    ));
+                }
+                _ => return Err(ExecuteError::wrap("argument of ntile must be an integer")),
+            };
+
+            let position = position as i64;
+            let quotient = size / buckets;
+            let remainder = size % buckets;
+            let large_rows = remainder * (quotient + 1);
+
+            let bucket = if position < large_rows {
+                position / (quotient + 1)
+            } else {
+                remainder + (position - large_rows) / quotient
+            };
+
+            Ok(TableDataFieldType::Integer(bucket + 1))
+        })
+        .collect()
+}
+
+/// LAG(direction = -1), LEAD(direction = 1)
+/// 거리만큼 떨어진 행의 값을 반환하고, 파티션을 벗어나면 기본값(없으면 NULL)을 반환합니다.
+fn shift(rows: &[&WindowInput], direction: i64) -> errors::Result<Vec<TableDataFieldType>> {
+    rows.iter()
+        .enumerate()
+        .map(|(position, row)| {
+            let offset = match row.arguments.get(1) {
+                None => 1,
+                Some(TableDataFieldType::Null) => return Ok(TableDataFieldType::Null),
+                Some(TableDataFieldType::Integer(offset)) => *offset,
+                Some(_) => {
+                    return Err(ExecuteError::wrap(
+                        "offset of lag and lead must be an integer",
+                    ));
+                }
+            };
+
+            let target = offset
+                .checked_mul(direction)
+                .and_then(|offset| (position as i64).checked_add(offset));
+
+            match target {
+                Some(target) if target >= 0 && (target as usize) < rows.len() => {
+                    Ok(rows[target as usize].arguments[0].clone())
+                }
+                _ => Ok(row
+                    .arguments
+                    .get(2)
+                    .cloned()
+                    .unwrap_or(TableDataFieldType::Null)),
+            }
+        })
+        .collect()
+}
+
+/// 집계 함수를 위치마다의 프레임에 대해 계산합니다.
+fn aggregate_over_frames(
+    window: &WindowExpression,
+    rows: &[&WindowInput],
+    peers: &[(usize, usize, usize)],
+    aggregate: AggregateFunction,
+) -> errors::Result<Vec<TableDataFieldType>> {
+    let frame = effective_frame(window);
+    let mut values = Vec::with_capacity(rows.len());
+
+    // 프레임 시작이 고정이면 끝이 늘어나는 만큼만 누산기에 더해 갑니다.
+    if frame.start == WindowFrameBound::UnboundedPreceding {
+        let mut accumulator = Accumulator::new(aggregate, false);
+        let mut consumed = 0;
+
+        for position in 0..rows.len() {
+            let (_, end) = frame_bounds(window, rows, peers, position)?;
+
+            while consumed < end {
+                accumulator.update(rows[consumed].arguments.clone())?;
+                consumed += 1;
+            }
+
+            values.push(accumulator.clone().finish());
+        }
+
+        return Ok(values);
+    }
+
+    for position in 0..rows.len() {
+        let (start, end) = frame_bounds(window, rows, peers, position)?;
+
+        let mut accumulator = Accumulator::new(aggregate.clone(), false);
+        for row in rows.iter().take(end).skip(start) {
+            accumulator.update(row.arguments.clone())?;
+        }
+
+        values.push(accumulator.finish());
+    }
+
+    Ok(values)
+}
+
+fn effective_frame(window: &WindowExpression) -> WindowFrame {
+    match &window.frame {
+        Some(frame) => frame.clone(),
+        None if window.order_by.is_empty() => WindowFrame {
+            unit: WindowFrameUnit::Rows,
+            start: WindowFrameBound::UnboundedPreceding,
+            end: WindowFrameBound::UnboundedFollowing,
+        },
+        None => WindowFrame {
+            unit: WindowFrameUnit::Range,
+            start: WindowFrameBound::UnboundedPreceding,
+            end: WindowFrameBound::CurrentRow,
+        },
+    }
+}
+
+/// 위치의 프레임을 정렬된 파티션 안의 구간 [start, end)로 계산합니다.
+fn frame_bounds(
+    window: &WindowExpression,
+    rows: &[&WindowInput],
+    peers: &[(usize, usize, usize)],
+    position: usize,
+) -> errors::Result<(usize, usize)> {
+    let frame = effective_frame(window);
+    let size = rows.len();
+
+    let (start, end) = match frame.unit {
+        WindowFrameUnit::Rows => {
+            let start = match frame.start {
+                WindowFrameBound::UnboundedPreceding => 0,
+                WindowFrameBound::Preceding(offset) => position.saturating_sub(offset as usize),
+                WindowFrameBound::CurrentRow => position,
+                WindowFrameBound::Following(offset) => position.saturating_add(offset as usize),
+                WindowFrameBound::UnboundedFollowing => size,
+            };
+
+            let end = match frame.end {
+                WindowFrameBound::UnboundedPreceding => 0,
+                WindowFrameBound::Preceding(offset) => {
+                    (position + 1).saturating_sub(offset as usize)
+                }
+                WindowFrameBound::CurrentRow => position + 1,
+                WindowFrameBound::Following(offset) => {
+                    position.saturating_add(offset as usize).saturating_add(1)
+                }
+                WindowFrameBound::UnboundedFollowing => size,
+            };
+
+            (start, end)
+        }
+        WindowFrameUnit::Range => {
+            let (peer_start, peer_end, _) = peers[position];
+
+            let start = match frame.start {
+                WindowFrameBound::UnboundedPreceding => 0,
+                WindowFrameBound::CurrentRow => peer_start,
+                WindowFrameBound::UnboundedFollowing => size,
+                WindowFrameBound::Preceding(offset) => {
+                    range_offset_bound(window, rows, position, -(offset as f64), false)?
+                        .unwrap_or(peer_start)
+                }
+                WindowFrameBound::Following(offset) => {
+                    range_offset_bound(window, rows, position, offset as f64, false)?
+                        .unwrap_or(peer_start)
+                }
+            };
+
+            let end = match frame.end {
+                WindowFrameBound::UnboundedPreceding => 0,
+                WindowFrameBound::CurrentRow => peer_end,
+                WindowFrameBound::UnboundedFollowing => size,
+                WindowFrameBound::Preceding(offset) => {
+                    range_offset_bound(window, rows, position, -(offset as f64), true)?
+                        .unwrap_or(peer_end)
+                }
+                WindowFrameBound::Following(offset) => {
+                    range_offset_bound(window, rows, position, offset as f64, true)?
+                        .unwrap_or(peer_end)
+                }
+            };
+
+            (start, end)
+        }
+    };
+
+    Ok((start.min(size), end.min(size)))
+}
+
+/// RANGE offset 경계의 위치
+/// 현재 행과 ORDER BY 값의 차이(정렬 방향 기준)가 distance보다 작은 행들의 끝(end가 아니면),
+/// distance 이하인 행들의 끝(end면)을 반환합니다. 현재 행의 값이 NULL이면 None을 반환합니다.
+fn range_offset_bound(
+    window: &WindowExpression,
+    rows: &[&WindowInput],
+    position: usize,
+    distance: f64,
+    end: bool,
+) -> errors::Result<Option<usize>> {
+    let order_by_item: &OrderByItem = &window.order_by[0];
+
+    let Some(current) = numeric_order_value(&rows[position].order_key[0])? else {
+        return Ok(None);
+    };
+
+    // 정렬 방향 기준의 거리. NULL은 정렬 위치에 따라 가장 앞이나 뒤에 있습니다.
+    let mut differences = Vec::with_capacity(rows.len());
+    for row in rows {
+        let difference = match numeric_order_value(&row.order_key[0])? {
+            None => match order_by_item.nulls {
+                OrderByNulls::First => f64::NEG_INFINITY,
+                OrderByNulls::Last => f64::INFINITY,
+            },
+            Some(value) => match order_by_item.order_type {
+                OrderByType::Asc => value - current,
+                OrderByType::Desc => current - value,
+            },
+        };
+
+        differences.push(difference);
+    }
+
+    Ok(Some(differences.partition_point(|difference| {
+        if end {
+            *difference <= distance
+        } else {
+            *difference < distance
+        }
+    })))
+}
+
+fn numeric_order_value(value: &TableDataFieldType) -> errors::Result<Option<f64>> {
+    match value {
+        TableDataFieldType::Null => Ok(None),
+        TableDataFieldType::Integer(value) => Ok(Some(*value as f64)),
+        TableDataFieldType::Float(value) => Ok(Some(f64::from(*value))),
+        _ => Err(ExecuteError::wrap(
+            "RANGE with offset PRECEDING/FOLLOWING is supported only for numeric ORDER BY column",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::ast::dml::expressions::call::CallExpression;
+    use crate::engine::ast::types::SelectColumn;
+
+    fn input(partition: i64, order: i64, argument: i64) -> WindowInput {
+        WindowInput {
+            partition_key: vec![TableDataFieldType::Integer(partition)],
+            order_key: vec![TableDataFieldType::Integer(order)],
+            arguments: vec![TableDataFieldType::Integer(argument)],
+        }
+    }
+
+    fn window(function: Function, frame: Option<WindowFrame>) -> WindowExpression {
+        WindowExpression {
+            call: CallExpression {
+                function,
+                arguments: vec![SelectColumn::new(None, "v".into()).into()],
+                distinct: false,
+            },
+            partition_by: vec![SelectColumn::new(None, "p".into()).into()],
+            order_by: vec![OrderByItem {
+                item: SelectColumn::new(None, "o".into()).into(),
+                order_type: OrderByType::Asc,
+                nulls: OrderByNulls::Last,
+            }],
+            frame,
+        }
+    }
+
+    fn integers(values: &[i64]) -> Vec<TableDataFieldType> {
+        values
+            .iter()
+            .map(|value| TableDataFieldType::Integer(*value))
+            .collect()
+    }
+
+    #[test]
+    fn ranking_functions_follow_partition_order_and_peers() {
+        // 입력 순서가 섞여 있어도 결과는 입력 행 순서대로 돌아옵니다.
+        let inputs = vec![
+            input(1, 20, 0),
+            input(2, 5, 0),
+            input(1, 10, 0),
+            input(1, 20, 0),
+            input(1, 30, 0),
+        ];
+
+        let mut rank = window(WindowFunction::Rank.into(), None);
+        rank.call.arguments.clear();
+        let mut dense_rank = window(WindowFunction::DenseRank.into(), None);
+        dense_rank.call.arguments.clear();
+        let mut row_number = window(WindowFunction::RowNumber.into(), None);
+        row_number.call.arguments.clear();
+
+        assert_eq!(
+            compute_window(&rank, &inputs).unwrap(),
+            integers(&[2, 1, 1, 2, 4])
+        );
+        assert_eq!(
+            compute_window(&dense_rank, &inputs).unwrap(),
+            integers(&[2, 1, 1, 2, 3])
+        );
+        assert_eq!(
+            compute_window(&row_number, &inputs).unwrap(),
+            integers(&[2, 1, 1, 3, 4])
+        );
+    }
+
+    #[test]
+    fn ntile_gives_earlier_buckets_the_extra_rows() {
+        let inputs = (0..5).map(|i| input(1, i, 2)).collect::<Vec<_>>();
+
+        assert_eq!(
+            compute_window(&window(WindowFunction::Ntile.into(), None), &inputs).unwrap(),
+            integers(&[1, 1, 1, 2, 2])
+        );
+    }
+
+    #[test]
+    fn aggregate_frames_by_rows_and_range() {
+        let inputs = vec![
+            input(1, 1, 1),
+            input(1, 2, 10),
+            input(1, 2, 100),
+            input(1, 5, 1000),
+        ];
+
+        // 기본 프레임은 같은 ORDER BY 값의 행까지 포함합니다.
+        let running = window(AggregateFunction::Sum.into(), None);
+        assert_eq!(
+            compute_window(&running, &inputs).unwrap(),
+            integers(&[1, 111, 111, 1111])
+        );
+
+        let rows = window(
+            AggregateFunction::Sum.into(),
+            Some(WindowFrame {
+                unit: WindowFrameUnit::Rows,
+                start: WindowFrameBound::Preceding(1),
+                end: WindowFrameBound::CurrentRow,
+            }),
+        );
+        assert_eq!(
+            compute_window(&rows, &inputs).unwrap(),
+            integers(&[1, 11, 110, 1100])
+        );
+
+        let range = window(
+            AggregateFunction::Sum.into(),
+            Some(WindowFrame {
+                unit: WindowFrameUnit::Range,
+                start: WindowFrameBound::Preceding(1),
+                end: WindowFrameBound::Following(3),
+            }),
+        );
+        assert_eq!(
+            compute_window(&range, &inputs).unwrap(),
+            integers(&[111, 1111, 1111, 1000])
+        );
+    }
+}
@@ -3,11 +3,13 @@
 use std::collections::HashMap;
 
 use crate::engine::DBEngine;
+use crate::engine::ast::dml::parts::from::FromTarget;
+use crate::engine::ast::dml::select::SelectQuery;
 use crate::engine::ast::types::TableName;
-use crate::engine::index::{IndexMeta, field_to_key};
+use crate::engine::index::{IndexEntry, IndexMeta, NULL_KEY, field_to_key};
 use crate::engine::optimizer::cost::BLOCK_SIZE;
 use crate::engine::optimizer::predule::{OptimizerContext, TableStatistics};
-use crate::engine::schema::row::{TableDataFieldType, TableDataRow};
+use crate::engine::schema::row::TableDataRow;
 use crate::errors;
 use crate::errors::execute_error::ExecuteError;
 
@@ -18,15 +20,13 @@ pub(crate) fn qualified_index_name(database_name: &str, index_name: &str) -> Str
 }
 
 /// 행의 특정 컬럼 값을 인덱스 키로 변환합니다.
-/// NULL 값은 색인하지 않습니다 (PostgreSQL과 동일하게 unique 제약에서도 제외).
+/// NULL 값은 IS NULL 인덱스 스캔을 위해 NULL_KEY로 색인하되,
+/// PostgreSQL과 동일하게 unique 제약에서는 제외합니다.
 pub(crate) fn row_index_key(row: &TableDataRow, column_name: &str) -> Option<String> {
     row.fields
         .iter()
         .find(|field| field.column_name == column_name)
-        .and_then(|field| match &field.data {
-            TableDataFieldType::Null => None,
-            data => Some(field_to_key(data)),
-        })
+        .map(|field| field_to_key(&field.data))
 }
 
 impl DBEngine {
@@ -99,6 +99,34 @@ impl DBEngine {
         }
     }
 
+    /// 행 목록(`None`은 삭제된 행)으로 테이블의 모든 인덱스를 다시 만듭니다.
+    /// ROLLBACK과 커밋되지 않은 트랜잭션이 남긴 인덱스 항목을 걷어낼 때 사용합니다.
+    pub(crate) async fn rebuild_table_indexes(
+        &self,
+        table_name: &TableName,
+        rows: &[Option<TableDataRow>],
+    ) -> errors::Result<()> {
+        for meta in self.table_index_metas(table_name).await {
+            let entries = rows
+                .iter()
+                .enumerate()
+                .filter_map(|(row_index, row)| {
+                    let key = row_index_key(row.as_ref()?, &meta.column_name)?;
+                    Some(IndexEntry {
+                        key,
+                        row_path: row_index.to_string(),
+                    })
+                })
+                .collect();
+
+            self.index_manager
+                .replace_entries(&meta.index_name, entries)
+                .await?;
+        }
+
+        Ok(())
+    }
+
     /// 테이블 통계를 반환합니다. 캐시가 없으면 실제 스캔으로 계산 후 캐싱합니다.
     pub(crate) async fn table_statistics(
         &self,
@@ -118,16 +146,22 @@ impl DBEngine {
         let block_count = file_size.div_ceil(BLOCK_SIZE).max(1) as usize;
 
         let mut distinct_values = HashMap::new();
+        let mut null_counts = HashMap::new();
         for meta in self.table_index_metas(table_name).await {
             if let Ok(distinct) = self.index_manager.distinct_keys(&meta.index_name).await {
                 distinct_values.insert(meta.column_name.clone(), distinct);
             }
+
+            if let Ok(nulls) = self.index_manager.get(&meta.index_name, NULL_KEY).await {
+                null_counts.insert(meta.column_name.clone(), nulls.len());
+            }
         }
 
         let statistics = TableStatistics {
             row_count,
             block_count,
             distinct_values,
+            null_counts,
         };
 
         self.statistics_manager
@@ -168,9 +202,69 @@ impl DBEngine {
             }
         };
 
+        // DISTINCT 제거 판단용 (유니크 인덱스 + NOT NULL이면 행마다 값이 다름)
+        let not_null_columns = match self.get_table_config_cached(table_name.clone()).await {
+            Ok(table_config) => table_config
+                .columns
+                .into_iter()
+                .filter(|column| column.not_null)
+                .map(|column| column.name)
+                .collect(),
+            Err(error) => {
+                log::warn!(
+                    "build_optimizer_context: get_table_config_cached failed for {:?}: {}",
+                    table_name,
+                    error
+                );
+                vec![]
+            }
+        };
+
         OptimizerContext {
             indexes,
             statistics,
+            not_null_columns,
+            ..Default::default()
+        }
+    }
+
+    /// 조인에 참여하는 모든 테이블의 통계를 모읍니다. (#195)
+    /// 통계를 구하지 못한 테이블은 빠지며, 옵티마이저는 그 경우 작성된 조인 순서를 유지합니다.
+    pub(crate) async fn join_relation_statistics(
+        &self,
+        query: &SelectQuery,
+    ) -> HashMap<TableName, TableStatistics> {
+        let mut relation_statistics = HashMap::new();
+
+        let from_table = query.from_table.as_ref().and_then(|from| match &from.from {
+            FromTarget::Table(table_name) => Some(table_name.clone()),
+            FromTarget::Subquery(_) => None,
+        });
+
+        let tables = from_table
+            .into_iter()
+            .chain(query.join_clause.iter().map(|join| join.right.clone()));
+
+        for table_name in tables {
+            // WITH 절 테이블은 실행마다 결과가 달라 통계를 남기지 않습니다.
+            if relation_statistics.contains_key(&table_name) || table_name.is_common_table() {
+                continue;
+            }
+
+            match self.table_statistics(&table_name).await {
+                Ok(statistics) => {
+                    relation_statistics.insert(table_name, statistics);
+                }
+                Err(error) => {
+                    log::warn!(
+                        "join_relation_statistics: table_statistics failed for {:?}: {}",
+                        table_name,
+                        error
+                    );
+                }
+            }
         }
+
+        relation_statistics
     }
 }
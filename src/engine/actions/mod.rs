@@ -2,3 +2,7 @@ pub mod ddl;
 pub mod dml;
 pub mod etc;
 pub mod index;
+pub mod tcl;
+
+#[cfg(test)]
+pub(crate) mod test_support;
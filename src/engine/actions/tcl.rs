@@ -0,0 +1,1252 @@
+use std::collections::HashMap;
+
+use tokio::sync::OwnedRwLockReadGuard;
+
+use crate::engine::ast::tcl::{
+    BeginTransactionQuery, IsolationLevel, ReleaseSavepointQuery, RollbackToSavepointQuery,
+    SavepointQuery,
+};
+use crate::engine::ast::types::TableName;
+use crate::engine::ast::{DDLStatement, DMLStatement, SQLStatement, TCLStatement};
+use crate::engine::row_buffer::RowSavepoint;
+use crate::engine::transaction::{
+    AutocommitTransaction, Savepoint, StatementContext, Transaction, TransactionWriter,
+};
+use crate::engine::types::{
+    ExecuteColumn, ExecuteColumnType, ExecuteField, ExecuteResult, ExecuteRow,
+};
+use crate::engine::wal::types::{EntryType, SavepointWALPayload};
+use crate::engine::{DBEngine, SharedWALManager};
+use crate::errors;
+use crate::errors::execute_error::ExecuteError;
+
+/// 명령 하나를 실행하는 동안 쥐고 있는 트랜잭션 상태
+pub(crate) struct StatementTransaction {
+    pub(crate) context: StatementContext,
+    /// 트랜잭션 블록 밖의 쓰기 명령. 명령이 끝나 drop되면 커밋됩니다.
+    _autocommit: Option<AutocommitTransaction>,
+    /// 쓰기 명령이 끝날 때까지 durability flush를 막습니다.
+    _write_statement: Option<OwnedRwLockReadGuard<()>>,
+}
+
+impl DBEngine {
+    pub(crate) async fn transaction_control(
+        &self,
+        statement: TCLStatement,
+        connection_id: &str,
+        wal_manager: SharedWALManager,
+    ) -> errors::Result<ExecuteResult> {
+        match statement {
+            TCLStatement::BeginTransaction(query) => {
+                self.begin_transaction(query, connection_id).await
+            }
+            TCLStatement::Commit(_) => self.commit_transaction(connection_id, wal_manager).await,
+            TCLStatement::Rollback(_) => {
+                self.rollback_transaction(connection_id, wal_manager).await
+            }
+            TCLStatement::Savepoint(query) => {
+                self.savepoint(query, connection_id, wal_manager).await
+            }
+            TCLStatement::RollbackToSavepoint(query) => {
+                self.rollback_to_savepoint(query, connection_id, wal_manager)
+                    .await
+            }
+            TCLStatement::ReleaseSavepoint(query) => self.release_savepoint(query, connection_id),
+        }
+    }
+
+    pub async fn begin_transaction(
+        &self,
+        query: BeginTransactionQuery,
+        connection_id: &str,
+    ) -> errors::Result<ExecuteResult> {
+        // READ UNCOMMITTED는 PostgreSQL과 같이 READ COMMITTED로 동작합니다.
+        let isolation_level = match query.isolation_level {
+            None | Some(IsolationLevel::ReadUncommitted | IsolationLevel::ReadCommitted) => {
+                IsolationLevel::ReadCommitted
+            }
+            Some(IsolationLevel::RepeatableRead) => IsolationLevel::RepeatableRead,
+            Some(IsolationLevel::Serializable) => {
+                return Err(ExecuteError::wrap(
+                    "SERIALIZABLE isolation level is not supported".to_string(),
+                ));
+            }
+        };
+
+        if !self
+            .transaction_manager
+            .begin(connection_id, isolation_level)
+            .await?
+        {
+            // PostgreSQL과 같이 경고만 남기고 기존 트랜잭션을 이어갑니다.
+            // 클라이언트에 보내는 경고는 pgwire 계층이 NoticeResponse로 전달합니다.
+            log::warn!("there is already a transaction in progress ({connection_id})");
+            return Ok(Self::transaction_result(
+                "there is already a transaction in progress",
+            ));
+        }
+
+        Ok(Self::transaction_result("transaction started"))
+    }
+
+    /// 쓰기 트랜잭션이면 WAL에 COMMIT 레코드를 남깁니다. 행 버전은 이미 버퍼에
+    /// 쌓여 있으므로, 트랜잭션이 끝나는 순간 새 스냅샷에 한꺼번에 보이게 됩니다.
+    ///
+    /// 오류로 중단된 트랜잭션의 COMMIT은 PostgreSQL과 같이 ROLLBACK으로 처리합니다.
+    pub async fn commit_transaction(
+        &self,
+        connection_id: &str,
+        wal_manager: SharedWALManager,
+    ) -> errors::Result<ExecuteResult> {
+        let Some((transaction, writer)) = self.transaction_manager.end(connection_id) else {
+            log::warn!("there is no transaction in progress ({connection_id})");
+            return Ok(Self::transaction_result("transaction committed"));
+        };
+
+        if transaction.failed {
+            self.rollback_writer(transaction, writer, wal_manager)
+                .await?;
+            return Ok(Self::transaction_result("transaction rolled back"));
+        }
+
+        if let Some(writer) = writer {
+            let appended = wal_manager
+                .lock()
+                .await
+                .append_record(
+                    EntryType::TransactionCommit,
+                    None,
+                    Some(writer.transaction_id),
+                )
+                .await;
+
+            // COMMIT 레코드가 없으면 replay가 이 트랜잭션을 버리므로, 메모리 상태도 되돌립니다.
+            if let Err(error) = appended {
+                self.rollback_writer(transaction, Some(writer), wal_manager)
+                    .await?;
+                return Err(error);
+            }
+        }
+
+        drop(transaction);
+
+        Ok(Self::transaction_result("transaction committed"))
+    }
+
+    pub async fn rollback_transaction(
+        &self,
+        connection_id: &str,
+        wal_manager: SharedWALManager,
+    ) -> errors::Result<ExecuteResult> {
+        match self.transaction_manager.end(connection_id) {
+            Some((transaction, writer)) => {
+                self.rollback_writer(transaction, writer, wal_manager)
+                    .await?
+            }
+            None => log::warn!("there is no transaction in progress ({connection_id})"),
+        }
+
+        Ok(Self::transaction_result("transaction rolled back"))
+    }
+
+    /// 되돌아갈 수 있는 지점을 남깁니다. 쓰기 트랜잭션이면 지금까지 바꾼 행을 복사해 두고,
+    /// crash recovery가 되돌린 변경을 버릴 수 있도록 WAL에 마커를 남깁니다.
+    pub async fn savepoint(
+        &self,
+        query: SavepointQuery,
+        connection_id: &str,
+        wal_manager: SharedWALManager,
+    ) -> errors::Result<ExecuteResult> {
+        let status = self
+            .transaction_manager
+            .status(connection_id)
+            .ok_or_else(|| {
+                ExecuteError::wrap("SAVEPOINT can only be used in transaction blocks".to_string())
+            })?;
+
+        if status.failed {
+            return Err(Self::transaction_aborted_error());
+        }
+
+        let tables = if status.is_writer {
+            self.savepoint_transaction_rows(status.transaction_id)
+                .await?
+        } else {
+            HashMap::new()
+        };
+
+        let savepoint_id = self
+            .transaction_manager
+            .add_savepoint(connection_id, query.name.clone(), tables)
+            .ok_or_else(|| ExecuteError::wrap("transaction not found".to_string()))?;
+
+        if status.is_writer {
+            let appended = self
+                .append_savepoint_record(
+                    EntryType::Savepoint,
+                    savepoint_id,
+                    &query.name,
+                    status.transaction_id,
+                    wal_manager,
+                )
+                .await;
+
+            // 마커가 없는 세이브포인트로 되돌리면 replay는 트랜잭션의 처음부터 버립니다.
+            if let Err(error) = appended {
+                self.transaction_manager
+                    .release_savepoint(connection_id, &query.name);
+                return Err(error);
+            }
+        }
+
+        Ok(Self::transaction_result("savepoint created"))
+    }
+
+    /// 세이브포인트 뒤의 변경을 되돌립니다. 세이브포인트는 남아 있어 다시 되돌아갈 수 있고,
+    /// 오류로 중단된 트랜잭션도 이어서 쓸 수 있게 됩니다.
+    pub async fn rollback_to_savepoint(
+        &self,
+        query: RollbackToSavepointQuery,
+        connection_id: &str,
+        wal_manager: SharedWALManager,
+    ) -> errors::Result<ExecuteResult> {
+        let status = self
+            .transaction_manager
+            .status(connection_id)
+            .ok_or_else(|| {
+                ExecuteError::wrap(
+                    "ROLLBACK TO SAVEPOINT can only be used in transaction blocks".to_string(),
+                )
+            })?;
+
+        let savepoint = self
+            .transaction_manager
+            .find_savepoint(connection_id, &query.name)
+            .ok_or_else(|| {
+                ExecuteError::wrap(format!("savepoint \"{}\" does not exist", query.name))
+            })?;
+
+        if status.is_writer {
+            self.append_savepoint_record(
+                EntryType::RollbackToSavepoint,
+                savepoint.savepoint_id,
+                &query.name,
+                status.transaction_id,
+                wal_manager,
+            )
+            .await?;
+
+            // 마커를 남긴 뒤 되돌리지 못하면 메모리의 행이 replay 결과와 달라지므로,
+            // 트랜잭션을 더 쓰지 못하게 합니다.
+            let tables = self
+                .transaction_manager
+                .writer_tables(status.transaction_id);
+            if let Err(error) = self
+                .undo_transaction_rows(status.transaction_id, &tables, Some(&savepoint))
+                .await
+            {
+                self.transaction_manager.mark_failed(connection_id);
+                return Err(error);
+            }
+        }
+
+        self.transaction_manager
+            .rollback_to_savepoint(connection_id, savepoint.savepoint_id);
+
+        Ok(Self::transaction_result("rolled back to savepoint"))
+    }
+
+    /// 세이브포인트와 그 뒤에 만든 세이브포인트를 버립니다. 변경은 그대로 남습니다.
+    pub fn release_savepoint(
+        &self,
+        query: ReleaseSavepointQuery,
+        connection_id: &str,
+    ) -> errors::Result<ExecuteResult> {
+        let status = self
+            .transaction_manager
+            .status(connection_id)
+            .ok_or_else(|| {
+                ExecuteError::wrap(
+                    "RELEASE SAVEPOINT can only be used in transaction blocks".to_string(),
+                )
+            })?;
+
+        if status.failed {
+            return Err(Self::transaction_aborted_error());
+        }
+
+        if !self
+            .transaction_manager
+            .release_savepoint(connection_id, &query.name)
+        {
+            return Err(ExecuteError::wrap(format!(
+                "savepoint \"{}\" does not exist",
+                query.name
+            )));
+        }
+
+        Ok(Self::transaction_result("savepoint released"))
+    }
+
+    /// 연결이 끊기면 열려 있던 트랜잭션을 되돌립니다.
+    pub async fn close_connection(
+        &self,
+        connection_id: &str,
+        wal_manager: SharedWALManager,
+    ) -> errors::Result<()> {
+        if let Some((transaction, writer)) = self.transaction_manager.end(connection_id) {
+            log::debug!("rolling back open transaction of connection {connection_id}");
+            self.rollback_writer(transaction, writer, wal_manager)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// 트랜잭션 제어가 아닌 명령을 실행하기 전에 호출합니다.
+    ///
+    /// 읽기 명령은 락 없이 스냅샷만 찍습니다. 쓰기 명령은 바꿀 테이블마다 쓰기 락을 잡습니다.
+    /// 트랜잭션 블록 밖에서는 명령 하나짜리 트랜잭션으로 잡고, 트랜잭션 블록의 첫 쓰기
+    /// 명령은 WAL에 BEGIN 레코드도 남깁니다. 스냅샷은 쓰기 락을 잡은 뒤에 찍으므로, 쓰기
+    /// 명령은 대상 테이블의 커밋된 최신 행을 봅니다 (REPEATABLE READ 제외).
+    pub(crate) async fn begin_statement(
+        &self,
+        statement: &SQLStatement,
+        connection_id: &str,
+        wal_manager: SharedWALManager,
+    ) -> errors::Result<StatementTransaction> {
+        let writes = !matches!(
+            statement,
+            SQLStatement::DML(DMLStatement::SelectQuery(_)) | SQLStatement::Other(_)
+        );
+
+        let tables = Self::written_tables(statement);
+
+        let Some(status) = self.transaction_manager.status(connection_id) else {
+            let autocommit = if writes {
+                Some(self.transaction_manager.begin_autocommit(&tables).await?)
+            } else {
+                None
+            };
+            let transaction_id = autocommit
+                .as_ref()
+                .map(|autocommit| autocommit.transaction_id);
+            let write_statement = if writes {
+                Some(self.transaction_manager.begin_write_statement().await)
+            } else {
+                None
+            };
+
+            return Ok(StatementTransaction {
+                context: StatementContext {
+                    transaction_id,
+                    snapshot: self.transaction_manager.snapshot(transaction_id).await?,
+                    isolation_level: IsolationLevel::ReadCommitted,
+                },
+                _autocommit: autocommit,
+                _write_statement: write_statement,
+            });
+        };
+
+        if status.failed {
+            return Err(Self::transaction_aborted_error());
+        }
+
+        // 스키마/인덱스 변경은 되돌릴 수 없으므로 트랜잭션 안에서 받지 않습니다.
+        if let SQLStatement::DDL(ddl) = statement
+            && !matches!(ddl, DDLStatement::TruncateTableQuery(_))
+        {
+            return Err(ExecuteError::wrap(
+                "DDL cannot run inside a transaction block".to_string(),
+            ));
+        }
+
+        let mut write_statement = None;
+        if writes {
+            self.transaction_manager
+                .acquire_writer(connection_id, &tables)
+                .await?
+                .ok_or_else(|| ExecuteError::wrap("transaction not found".to_string()))?;
+            write_statement = Some(self.transaction_manager.begin_write_statement().await);
+
+            if !status.is_writer {
+                wal_manager
+                    .lock()
+                    .await
+                    .append_record(
+                        EntryType::TransactionBegin,
+                        None,
+                        Some(status.transaction_id),
+                    )
+                    .await?;
+            }
+        }
+
+        let snapshot = match status.isolation_level {
+            IsolationLevel::RepeatableRead => self
+                .transaction_manager
+                .transaction_snapshot(connection_id)
+                .ok_or_else(|| ExecuteError::wrap("transaction not found".to_string()))?,
+            _ => {
+                self.transaction_manager
+                    .snapshot(Some(status.transaction_id))
+                    .await?
+            }
+        };
+
+        Ok(StatementTransaction {
+            context: StatementContext {
+                transaction_id: Some(status.transaction_id),
+                snapshot,
+                isolation_level: status.isolation_level,
+            },
+            _autocommit: None,
+            _write_statement: write_statement,
+        })
+    }
+
+    /// 명령이 행이나 스키마를 바꾸는 테이블. 이름을 알 수 없는 대상(데이터베이스, 함수,
+    /// ON 절 없는 DROP INDEX)은 잠그지 않습니다.
+    fn written_tables(statement: &SQLStatement) -> Vec<TableName> {
+        let tables: Vec<&TableName> = match statement {
+            SQLStatement::DML(DMLStatement::InsertQuery(query)) => {
+                query.into_table.iter().collect()
+            }
+            SQLStatement::DML(DMLStatement::UpdateQuery(query)) => query
+                .target_table
+                .iter()
+                .map(|target| &target.table)
+                .collect(),
+            SQLStatement::DML(DMLStatement::DeleteQuery(query)) => query
+                .from_table
+                .iter()
+                .map(|target| &target.table)
+                .collect(),
+            SQLStatement::DDL(DDLStatement::TruncateTableQuery(query)) => {
+                query.tables.iter().collect()
+            }
+            SQLStatement::DDL(DDLStatement::CreateTableQuery(query)) => {
+                query.table.iter().collect()
+            }
+            SQLStatement::DDL(DDLStatement::AlterTableQuery(query)) => query.table.iter().collect(),
+            SQLStatement::DDL(DDLStatement::DropTableQuery(query)) => query.table.iter().collect(),
+            SQLStatement::DDL(DDLStatement::CreateIndexQuery(query)) => vec![&query.table],
+            SQLStatement::DDL(DDLStatement::DropIndexQuery(query)) => query.table.iter().collect(),
+            _ => vec![],
+        };
+
+        let mut written = Vec::with_capacity(tables.len());
+        for table in tables {
+            if !written.contains(table) {
+                written.push(table.clone());
+            }
+        }
+        written
+    }
+
+    /// 트랜잭션 블록이 이 테이블을 처음 바꾸기 직전의 행 개수를 남깁니다.
+    /// 호출자가 이미 `row_storage_lock`을 쥐고 있어야 합니다.
+    pub(crate) async fn record_transaction_write_locked(
+        &self,
+        table_name: &TableName,
+    ) -> errors::Result<()> {
+        let Some(transaction_id) = self.transaction_manager.writer_transaction_id() else {
+            return Ok(());
+        };
+        if !self
+            .transaction_manager
+            .is_untouched_table(transaction_id, table_name)
+        {
+            return Ok(());
+        }
+
+        let row_count = self.next_row_index_locked(table_name).await?;
+        self.transaction_manager.record_touched_table(
+            transaction_id,
+            table_name.clone(),
+            row_count,
+        );
+
+        Ok(())
+    }
+
+    /// 쓰기 트랜잭션이 쌓은 행 버전을 걷어내고 WAL에 ROLLBACK 레코드를 남깁니다.
+    /// 트랜잭션은 이 작업이 끝난 뒤에 놓아야 다른 스냅샷이 되돌리기 전의 행을 보지 않습니다.
+    async fn rollback_writer(
+        &self,
+        transaction: Transaction,
+        writer: Option<TransactionWriter>,
+        wal_manager: SharedWALManager,
+    ) -> errors::Result<()> {
+        let Some(writer) = writer else {
+            return Ok(());
+        };
+
+        self.undo_transaction_rows(writer.transaction_id, &writer.tables, None)
+            .await?;
+
+        let result = wal_manager
+            .lock()
+            .await
+            .append_record(
+                EntryType::TransactionRollback,
+                None,
+                Some(writer.transaction_id),
+            )
+            .await;
+
+        drop(transaction);
+        result
+    }
+
+    // 커밋되지 않은 행 버전은 디스크에 내려가지 않으므로 버퍼만 되돌리면 됩니다.
+    // 인덱스는 바로 파일에 쓰이므로 되돌린 행으로 다시 만듭니다.
+    // `savepoint`가 있으면 그 시점까지만 되돌립니다.
+    async fn undo_transaction_rows(
+        &self,
+        transaction_id: u64,
+        tables: &HashMap<TableName, usize>,
+        savepoint: Option<&Savepoint>,
+    ) -> errors::Result<()> {
+        let _guard = self.row_storage_lock.lock().await;
+
+        for (table_name, base_row_count) in tables {
+            // 버퍼에 적재되지 않은 세그먼트는 먼저 읽어 들여야 버전을 걷어낼 수 있습니다.
+            self.read_table_rows_locked(table_name).await?;
+
+            let segment_path = self.row_segment_path(table_name)?;
+            {
+                let mut row_buffer_pool = self.row_buffer_pool.lock().await;
+                match savepoint.and_then(|savepoint| savepoint.tables.get(table_name)) {
+                    Some(row_savepoint) => row_buffer_pool.rollback_to_savepoint(
+                        segment_path,
+                        transaction_id,
+                        row_savepoint,
+                    ),
+                    None => row_buffer_pool.abort_transaction(
+                        segment_path,
+                        transaction_id,
+                        *base_row_count,
+                    ),
+                }
+            }
+
+            let rows = self.read_table_rows_locked(table_name).await?;
+            self.rebuild_table_indexes(table_name, &rows).await?;
+            self.statistics_manager.invalidate(table_name).await;
+        }
+
+        Ok(())
+    }
+
+    // 쓰기 트랜잭션이 건드린 테이블마다 지금까지 바꾼 행을 복사해 둡니다.
+    async fn savepoint_transaction_rows(
+        &self,
+        transaction_id: u64,
+    ) -> errors::Result<HashMap<TableName, RowSavepoint>> {
+        let _guard = self.row_storage_lock.lock().await;
+        let mut tables = HashMap::new();
+
+        for table_name in self
+            .transaction_manager
+            .writer_tables(transaction_id)
+            .into_keys()
+        {
+            // 추가만 한 테이블은 디스크의 행을 아직 읽지 않았을 수 있습니다.
+            self.read_table_rows_locked(&table_name).await?;
+
+            let segment_path = self.row_segment_path(&table_name)?;
+            let row_savepoint = self
+                .row_buffer_pool
+                .lock()
+                .await
+                .savepoint_rows(&segment_path, transaction_id);
+            tables.insert(table_name, row_savepoint);
+        }
+
+        Ok(tables)
+    }
+
+    async fn append_savepoint_record(
+        &self,
+        entry_type: EntryType,
+        savepoint_id: u64,
+        name: &str,
+        transaction_id: u64,
+        wal_manager: SharedWALManager,
+    ) -> errors::Result<()> {
+        let payload = SavepointWALPayload {
+            savepoint_id,
+            name: name.to_string(),
+        };
+        let wal_payload =
+            bincode::serialize(&payload).map_err(|error| ExecuteError::wrap(error.to_string()))?;
+
+        wal_manager
+            .lock()
+            .await
+            .append_record(entry_type, Some(wal_payload), Some(transaction_id))
+            .await
+    }
+
+    fn transaction_aborted_error() -> errors::Errors {
+        ExecuteError::wrap(
+            "current transaction is aborted, commands ignored until end of transaction block"
+                .to_string(),
+        )
+    }
+
+    fn transaction_result(message: &str) -> ExecuteResult {
+        ExecuteResult::new(
+            vec![ExecuteColumn {
+                name: "desc".into(),
+                data_type: ExecuteColumnType::String,
+            }],
+            vec![ExecuteRow {
+                fields: vec![ExecuteField::String(message.to_string())],
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use tokio::sync::Mutex;
+
+    use crate::engine::DBEngine;
+    use crate::engine::actions::test_support::{
+        execute_sql, names, setup_users_table, user_ids, user_names, users_table,
+    };
+    use crate::engine::schema::row::TableDataFieldType;
+    use crate::engine::wal::endec::implements::bincode::{BincodeDecoder, BincodeEncoder};
+    use crate::engine::wal::manager::builder::WALBuilder;
+
+    async fn primary_key_rows(engine: &DBEngine) -> Vec<String> {
+        let mut rows: Vec<String> = engine
+            .index_manager
+            .scan_all("rrdb.users_pkey")
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|entry| entry.row_path)
+            .collect();
+        rows.sort();
+        rows
+    }
+
+    #[tokio::test]
+    async fn readers_see_only_committed_rows_without_waiting_for_writer() {
+        let (engine, wal) = setup_users_table("target/test_transaction/commit").await;
+
+        for sql in [
+            "begin transaction;",
+            "insert into users (id, name) values (3, 'c');",
+            "update users set name = 'z' where id = 1;",
+        ] {
+            execute_sql(&engine, wal.clone(), "a", sql)
+                .await
+                .unwrap_or_else(|error| panic!("{sql} failed: {error}"));
+        }
+
+        // 다른 연결의 SELECT는 쓰기 트랜잭션을 기다리지 않고 커밋된 행만 봅니다.
+        let other = tokio::time::timeout(
+            Duration::from_secs(5),
+            user_names(&engine, wal.clone(), "b"),
+        )
+        .await
+        .unwrap();
+        assert_eq!(other, names(&["a", "b"]));
+
+        // 트랜잭션 안에서는 자신의 변경이 보입니다.
+        assert_eq!(
+            user_names(&engine, wal.clone(), "a").await,
+            names(&["z", "b", "c"])
+        );
+
+        execute_sql(&engine, wal.clone(), "a", "commit;")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            user_names(&engine, wal.clone(), "b").await,
+            names(&["z", "b", "c"])
+        );
+        assert_eq!(primary_key_rows(&engine).await, vec!["0", "1", "2"]);
+    }
+
+    #[tokio::test]
+    async fn repeatable_read_keeps_its_snapshot_until_the_end() {
+        let (engine, wal) = setup_users_table("target/test_transaction/repeatable_read").await;
+
+        for (connection_id, sql) in [
+            ("rr", "begin isolation level repeatable read;"),
+            ("rc", "begin transaction isolation level read committed;"),
+        ] {
+            execute_sql(&engine, wal.clone(), connection_id, sql)
+                .await
+                .unwrap();
+        }
+        assert_eq!(
+            user_names(&engine, wal.clone(), "rr").await,
+            names(&["a", "b"])
+        );
+        assert_eq!(
+            user_names(&engine, wal.clone(), "rc").await,
+            names(&["a", "b"])
+        );
+
+        for sql in [
+            "insert into users (id, name) values (3, 'c');",
+            "update users set name = 'y' where id = 2;",
+        ] {
+            execute_sql(&engine, wal.clone(), "other", sql)
+                .await
+                .unwrap();
+        }
+
+        // REPEATABLE READ는 첫 명령의 스냅샷을, READ COMMITTED는 명령마다 새 스냅샷을 봅니다.
+        assert_eq!(
+            user_names(&engine, wal.clone(), "rr").await,
+            names(&["a", "b"])
+        );
+        assert_eq!(
+            user_names(&engine, wal.clone(), "rc").await,
+            names(&["a", "y", "c"])
+        );
+        let indexed = execute_sql(
+            &engine,
+            wal.clone(),
+            "rr",
+            "select name from users where id = 2;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(indexed.rows[0].fields, names(&["b"]));
+
+        execute_sql(&engine, wal.clone(), "rc", "commit;")
+            .await
+            .unwrap();
+
+        // 스냅샷 뒤에 다른 트랜잭션이 바꾼 행은 고칠 수 없습니다.
+        let error = execute_sql(
+            &engine,
+            wal.clone(),
+            "rr",
+            "update users set name = 'x' where id = 2;",
+        )
+        .await
+        .unwrap_err();
+        assert!(error.to_string().contains("could not serialize"), "{error}");
+        execute_sql(&engine, wal.clone(), "rr", "rollback;")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            user_names(&engine, wal.clone(), "rr").await,
+            names(&["a", "y", "c"])
+        );
+
+        let error = execute_sql(
+            &engine,
+            wal.clone(),
+            "rr",
+            "begin isolation level serializable;",
+        )
+        .await
+        .unwrap_err();
+        assert!(error.to_string().contains("not supported"), "{error}");
+    }
+
+    #[tokio::test]
+    async fn rollback_restores_rows_and_indexes() {
+        let (engine, wal) = setup_users_table("target/test_transaction/rollback").await;
+
+        for sql in [
+            "begin transaction;",
+            "insert into users (id, name) values (3, 'c');",
+            "update users set id = 10 where id = 1;",
+            "delete from users where id = 2;",
+            "truncate users;",
+            "insert into users (id, name) values (4, 'd');",
+            "rollback;",
+        ] {
+            execute_sql(&engine, wal.clone(), "a", sql)
+                .await
+                .unwrap_or_else(|error| panic!("{sql} failed: {error}"));
+        }
+
+        assert_eq!(
+            user_ids(&engine).await,
+            vec![
+                (0, TableDataFieldType::Integer(1)),
+                (1, TableDataFieldType::Integer(2)),
+            ]
+        );
+        assert_eq!(engine.next_row_index(&users_table()).await.unwrap(), 2);
+        assert_eq!(primary_key_rows(&engine).await, vec!["0", "1"]);
+
+        // 되돌린 키는 다시 쓸 수 있고, 남아 있던 키는 여전히 unique 검사에 걸립니다.
+        execute_sql(
+            &engine,
+            wal.clone(),
+            "b",
+            "insert into users (id, name) values (3, 'c');",
+        )
+        .await
+        .unwrap();
+        assert!(
+            execute_sql(
+                &engine,
+                wal.clone(),
+                "b",
+                "insert into users (id, name) values (2, 'b');",
+            )
+            .await
+            .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn failed_statement_aborts_transaction_until_rollback() {
+        let (engine, wal) = setup_users_table("target/test_transaction/aborted").await;
+
+        execute_sql(&engine, wal.clone(), "a", "begin transaction;")
+            .await
+            .unwrap();
+        execute_sql(
+            &engine,
+            wal.clone(),
+            "a",
+            "insert into users (id, name) values (3, 'c');",
+        )
+        .await
+        .unwrap();
+        assert!(
+            execute_sql(
+                &engine,
+                wal.clone(),
+                "a",
+                "insert into users (id, name) values (1, 'a');",
+            )
+            .await
+            .is_err()
+        );
+
+        let error = execute_sql(&engine, wal.clone(), "a", "select id from users;")
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("aborted"), "{error}");
+
+        // 중단된 트랜잭션의 COMMIT은 ROLLBACK으로 처리됩니다.
+        execute_sql(&engine, wal.clone(), "a", "commit;")
+            .await
+            .unwrap();
+        assert_eq!(user_ids(&engine).await.len(), 2);
+        assert_eq!(primary_key_rows(&engine).await, vec!["0", "1"]);
+    }
+
+    #[tokio::test]
+    async fn rollback_to_savepoint_undoes_only_later_changes() {
+        let (engine, wal) = setup_users_table("target/test_transaction/savepoint").await;
+
+        for sql in [
+            "begin transaction;",
+            "insert into users (id, name) values (3, 'c');",
+            "update users set name = 'x' where id = 2;",
+            "savepoint sp1;",
+            "insert into users (id, name) values (4, 'd');",
+            "update users set name = 'z' where id = 1;",
+            "delete from users where id = 3;",
+            "truncate users;",
+            "insert into users (id, name) values (5, 'e');",
+            "rollback to savepoint sp1;",
+        ] {
+            execute_sql(&engine, wal.clone(), "a", sql)
+                .await
+                .unwrap_or_else(|error| panic!("{sql} failed: {error}"));
+        }
+
+        assert_eq!(
+            user_names(&engine, wal.clone(), "a").await,
+            names(&["a", "x", "c"])
+        );
+        assert_eq!(primary_key_rows(&engine).await, vec!["0", "1", "2"]);
+
+        // 세이브포인트는 남아 있어서 다시 되돌아갈 수 있고, 뒤에 만든 세이브포인트는 사라집니다.
+        for sql in [
+            "insert into users (id, name) values (4, 'd');",
+            "savepoint sp2;",
+            "update users set name = 'y' where id = 4;",
+            "rollback to sp1;",
+        ] {
+            execute_sql(&engine, wal.clone(), "a", sql)
+                .await
+                .unwrap_or_else(|error| panic!("{sql} failed: {error}"));
+        }
+        assert_eq!(
+            user_names(&engine, wal.clone(), "a").await,
+            names(&["a", "x", "c"])
+        );
+
+        let error = execute_sql(&engine, wal.clone(), "a", "rollback to sp2;")
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("does not exist"), "{error}");
+
+        execute_sql(&engine, wal.clone(), "a", "release savepoint sp1;")
+            .await
+            .unwrap();
+        let error = execute_sql(&engine, wal.clone(), "a", "rollback to sp1;")
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("does not exist"), "{error}");
+
+        execute_sql(&engine, wal.clone(), "a", "commit;")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            user_ids(&engine).await,
+            vec![
+                (0, TableDataFieldType::Integer(1)),
+                (1, TableDataFieldType::Integer(2)),
+                (2, TableDataFieldType::Integer(3)),
+            ]
+        );
+        assert_eq!(
+            user_names(&engine, wal.clone(), "b").await,
+            names(&["a", "x", "c"])
+        );
+        assert_eq!(primary_key_rows(&engine).await, vec!["0", "1", "2"]);
+    }
+
+    #[tokio::test]
+    async fn rollback_to_savepoint_resumes_aborted_transaction() {
+        let (engine, wal) = setup_users_table("target/test_transaction/savepoint_aborted").await;
+
+        let error = execute_sql(&engine, wal.clone(), "a", "savepoint sp1;")
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("transaction blocks"), "{error}");
+
+        for sql in [
+            "begin transaction;",
+            "savepoint sp1;",
+            "insert into users (id, name) values (3, 'c');",
+        ] {
+            execute_sql(&engine, wal.clone(), "a", sql)
+                .await
+                .unwrap_or_else(|error| panic!("{sql} failed: {error}"));
+        }
+        assert!(
+            execute_sql(
+                &engine,
+                wal.clone(),
+                "a",
+                "insert into users (id, name) values (1, 'a');",
+            )
+            .await
+            .is_err()
+        );
+
+        let error = execute_sql(&engine, wal.clone(), "a", "release sp1;")
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("aborted"), "{error}");
+
+        for sql in [
+            "rollback to savepoint sp1;",
+            "insert into users (id, name) values (4, 'd');",
+            "commit;",
+        ] {
+            execute_sql(&engine, wal.clone(), "a", sql)
+                .await
+                .unwrap_or_else(|error| panic!("{sql} failed: {error}"));
+        }
+
+        assert_eq!(
+            user_names(&engine, wal.clone(), "b").await,
+            names(&["a", "b", "d"])
+        );
+        assert_eq!(primary_key_rows(&engine).await, vec!["0", "1", "2"]);
+    }
+
+    #[tokio::test]
+    async fn ddl_is_rejected_inside_transaction() {
+        let (engine, wal) = setup_users_table("target/test_transaction/ddl").await;
+
+        execute_sql(&engine, wal.clone(), "a", "begin transaction;")
+            .await
+            .unwrap();
+        let error = execute_sql(
+            &engine,
+            wal.clone(),
+            "a",
+            "create table others (id integer primary key);",
+        )
+        .await
+        .unwrap_err();
+        assert!(error.to_string().contains("transaction block"), "{error}");
+
+        execute_sql(&engine, wal.clone(), "a", "rollback;")
+            .await
+            .unwrap();
+        execute_sql(
+            &engine,
+            wal.clone(),
+            "a",
+            "create table others (id integer primary key);",
+        )
+        .await
+        .unwrap();
+    }
+
+    /// 크래시 직전 열려 있던 트랜잭션은 replay에서 빠지고, 그 트랜잭션이 인덱스
+    /// 파일에 남긴 키도 지워집니다.
+    #[tokio::test]
+    async fn replay_applies_only_committed_transactions() {
+        let (engine, wal) = setup_users_table("target/test_transaction/replay").await;
+        let config = engine.config.as_ref().clone();
+
+        for (connection_id, sql) in [
+            ("a", "begin transaction;"),
+            ("a", "insert into users (id, name) values (3, 'c');"),
+            ("a", "commit;"),
+            ("b", "begin transaction;"),
+            ("b", "insert into users (id, name) values (4, 'd');"),
+            ("b", "delete from users where id = 1;"),
+        ] {
+            execute_sql(&engine, wal.clone(), connection_id, sql)
+                .await
+                .unwrap_or_else(|error| panic!("{sql} failed: {error}"));
+        }
+
+        // 행은 버퍼에만 있던 상태로 재시작합니다.
+        let entries = wal.lock().await.pending_entries().to_vec();
+        drop(engine);
+
+        let engine = DBEngine::new(config);
+        engine.replay_wal(&entries).await.unwrap();
+
+        assert_eq!(
+            user_ids(&engine).await,
+            vec![
+                (0, TableDataFieldType::Integer(1)),
+                (1, TableDataFieldType::Integer(2)),
+                (2, TableDataFieldType::Integer(3)),
+            ]
+        );
+        assert_eq!(primary_key_rows(&engine).await, vec!["0", "1", "2"]);
+    }
+
+    /// 커밋된 트랜잭션이라도 세이브포인트로 되돌린 변경은 replay하지 않습니다.
+    #[tokio::test]
+    async fn replay_skips_changes_rolled_back_to_savepoint() {
+        let (engine, wal) = setup_users_table("target/test_transaction/replay_savepoint").await;
+        let config = engine.config.as_ref().clone();
+
+        for sql in [
+            "begin transaction;",
+            // 첫 쓰기 전의 세이브포인트는 WAL 마커가 없습니다.
+            "savepoint before_write;",
+            "insert into users (id, name) values (3, 'c');",
+            "rollback to before_write;",
+            "insert into users (id, name) values (4, 'd');",
+            "savepoint sp1;",
+            "insert into users (id, name) values (5, 'e');",
+            "delete from users where id = 1;",
+            "rollback to sp1;",
+            "insert into users (id, name) values (6, 'f');",
+            "commit;",
+        ] {
+            execute_sql(&engine, wal.clone(), "a", sql)
+                .await
+                .unwrap_or_else(|error| panic!("{sql} failed: {error}"));
+        }
+
+        let expected = vec![
+            (0, TableDataFieldType::Integer(1)),
+            (1, TableDataFieldType::Integer(2)),
+            (2, TableDataFieldType::Integer(4)),
+            (3, TableDataFieldType::Integer(6)),
+        ];
+        assert_eq!(user_ids(&engine).await, expected);
+
+        let entries = wal.lock().await.pending_entries().to_vec();
+        drop(engine);
+
+        let engine = DBEngine::new(config);
+        engine.replay_wal(&entries).await.unwrap();
+
+        assert_eq!(user_ids(&engine).await, expected);
+        assert_eq!(primary_key_rows(&engine).await, vec!["0", "1", "2", "3"]);
+    }
+
+    #[tokio::test]
+    async fn open_write_transaction_blocks_writes_to_the_same_table_only() {
+        let (engine, wal) = setup_users_table("target/test_transaction/table_writer").await;
+        execute_sql(
+            &engine,
+            wal.clone(),
+            "a",
+            "create table orders (id integer primary key);",
+        )
+        .await
+        .unwrap();
+
+        for sql in ["begin;", "update users set name = 'z' where id = 1;"] {
+            execute_sql(&engine, wal.clone(), "a", sql).await.unwrap();
+        }
+
+        // 쓰기 락은 테이블 단위라서 다른 테이블에 대한 쓰기는 기다리지 않습니다.
+        tokio::time::timeout(
+            Duration::from_secs(5),
+            execute_sql(
+                &engine,
+                wal.clone(),
+                "b",
+                "insert into orders (id) values (1);",
+            ),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+
+        // 같은 테이블에 대한 쓰기는 트랜잭션이 끝날 때까지 기다립니다.
+        let insert = execute_sql(
+            &engine,
+            wal.clone(),
+            "b",
+            "insert into users (id, name) values (3, 'c');",
+        );
+        tokio::pin!(insert);
+        assert!(
+            tokio::time::timeout(Duration::from_millis(100), &mut insert)
+                .await
+                .is_err()
+        );
+        // 열린 트랜잭션은 내구성 플러시를 막지 않습니다.
+        assert!(engine.transaction_manager.try_lock_writers().is_some());
+
+        execute_sql(&engine, wal.clone(), "a", "commit;")
+            .await
+            .unwrap();
+        tokio::time::timeout(Duration::from_secs(5), insert)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            user_names(&engine, wal.clone(), "b").await,
+            names(&["z", "b", "c"])
+        );
+    }
+
+    /// 트랜잭션이 열려 있는 동안의 플러시는 커밋된 행만 디스크에 내리고, 열린
+    /// 트랜잭션의 WAL 레코드는 체크포인트 뒤로 옮겨 둡니다.
+    #[tokio::test]
+    async fn flush_during_open_transaction_keeps_uncommitted_rows_in_wal() {
+        let (engine, wal) = setup_users_table("target/test_transaction/open_flush").await;
+        let config = engine.config.as_ref().clone();
+        execute_sql(
+            &engine,
+            wal.clone(),
+            "b",
+            "create table orders (id integer primary key);",
+        )
+        .await
+        .unwrap();
+
+        for sql in [
+            "begin;",
+            "insert into users (id, name) values (3, 'c');",
+            "update users set name = 'z' where id = 1;",
+        ] {
+            execute_sql(&engine, wal.clone(), "a", sql).await.unwrap();
+        }
+        execute_sql(
+            &engine,
+            wal.clone(),
+            "b",
+            "insert into orders (id) values (1);",
+        )
+        .await
+        .unwrap();
+
+        let open = engine.transaction_manager.open_writers();
+        assert_eq!(open.len(), 1);
+        engine
+            .flush_committed_row_buffers_durable(&open)
+            .await
+            .unwrap();
+        wal.lock().await.flush_keeping(&open).await.unwrap();
+
+        // 디스크에는 커밋된 상태만 있습니다.
+        let on_disk = DBEngine::new(config.clone());
+        assert_eq!(
+            user_ids(&on_disk).await,
+            vec![
+                (0, TableDataFieldType::Integer(1)),
+                (1, TableDataFieldType::Integer(2)),
+            ]
+        );
+        drop(on_disk);
+
+        execute_sql(&engine, wal.clone(), "a", "commit;")
+            .await
+            .unwrap();
+        drop(engine);
+
+        // 체크포인트 뒤로 옮겨 둔 레코드와 커밋 레코드로 트랜잭션을 복구합니다.
+        let mut wal = WALBuilder::new(&config)
+            .build(BincodeDecoder::new(), BincodeEncoder::new())
+            .await
+            .unwrap();
+        let engine = DBEngine::new(config);
+        engine.recover_from_wal(&mut wal).await.unwrap();
+
+        let wal = Arc::new(Mutex::new(wal));
+        assert_eq!(
+            user_names(&engine, wal.clone(), "a").await,
+            names(&["z", "b", "c"])
+        );
+        assert_eq!(primary_key_rows(&engine).await, vec!["0", "1", "2"]);
+    }
+
+    #[tokio::test]
+    async fn transaction_changes_are_bounded_by_memory_limit() {
+        let (engine, wal) = setup_users_table("target/test_transaction/memory_limit").await;
+        engine.flush_row_buffers_durable().await.unwrap();
+        let mut config = engine.config.as_ref().clone();
+        config.transaction_memory_limit = 100;
+        drop(engine);
+        let engine = DBEngine::new(config);
+
+        for sql in ["begin;", "insert into users (id, name) values (3, 'c');"] {
+            execute_sql(&engine, wal.clone(), "a", sql).await.unwrap();
+        }
+        let error = execute_sql(
+            &engine,
+            wal.clone(),
+            "a",
+            "insert into users (id, name) values (4, 'dddddddddddddddddddddddddddddddd');",
+        )
+        .await
+        .unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("exceed transaction_memory_limit")
+        );
+
+        execute_sql(&engine, wal.clone(), "a", "rollback;")
+            .await
+            .unwrap();
+        assert_eq!(
+            user_names(&engine, wal.clone(), "a").await,
+            names(&["a", "b"])
+        );
+    }
+}
@@ -0,0 +1,117 @@
+//! 엔진 액션 테스트가 함께 쓰는 준비 코드
+//!
+//! 테스트마다 `target` 아래의 빈 디렉터리에 엔진과 WAL을 띄우고, SQL 문자열을 그대로 실행합니다.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::config::launch_config::LaunchConfig;
+use crate::engine::ast::types::TableName;
+use crate::engine::parser::predule::{Parser, ParserContext};
+use crate::engine::schema::row::TableDataFieldType;
+use crate::engine::types::{ExecuteField, ExecuteResult};
+use crate::engine::wal::endec::implements::bincode::{BincodeDecoder, BincodeEncoder};
+use crate::engine::wal::manager::builder::WALBuilder;
+use crate::engine::{DBEngine, SharedWALManager};
+
+/// `base_path`를 비우고 그 아래에 데이터/WAL 디렉터리를 만든 엔진을 띄웁니다.
+pub(crate) async fn build_test_engine(base_path: impl AsRef<Path>) -> (DBEngine, SharedWALManager) {
+    let base_path = base_path.as_ref();
+    if base_path.exists() {
+        tokio::fs::remove_dir_all(base_path).await.unwrap();
+    }
+
+    let config = LaunchConfig::default_for_base_path(base_path);
+    tokio::fs::create_dir_all(&config.data_directory)
+        .await
+        .unwrap();
+    tokio::fs::create_dir_all(&config.wal_directory)
+        .await
+        .unwrap();
+
+    let wal = WALBuilder::new(&config)
+        .build(BincodeDecoder::new(), BincodeEncoder::new())
+        .await
+        .unwrap();
+
+    (DBEngine::new(config), Arc::new(Mutex::new(wal)))
+}
+
+/// `users (id integer primary key, name varchar(100))`에 (1, 'a'), (2, 'b')를 넣어 둔 엔진
+pub(crate) async fn setup_users_table(base_path: impl AsRef<Path>) -> (DBEngine, SharedWALManager) {
+    let (engine, wal) = build_test_engine(base_path).await;
+
+    for sql in [
+        "create database rrdb;",
+        "create table users (id integer primary key, name varchar(100));",
+        "insert into users (id, name) values (1, 'a'), (2, 'b');",
+    ] {
+        execute_sql(&engine, wal.clone(), "a", sql)
+            .await
+            .unwrap_or_else(|error| panic!("{sql} failed: {error}"));
+    }
+
+    (engine, wal)
+}
+
+/// 기본 데이터베이스를 rrdb로 두고, `connection_id` 연결에서 문장 하나를 실행합니다.
+pub(crate) async fn execute_sql(
+    engine: &DBEngine,
+    wal: SharedWALManager,
+    connection_id: &str,
+    sql: &str,
+) -> crate::errors::Result<ExecuteResult> {
+    let mut parser = Parser::with_string(sql.to_string()).unwrap();
+    let mut statements = parser
+        .parse(ParserContext::default().set_default_database("rrdb".to_string()))
+        .unwrap();
+    let statement = statements.remove(0);
+
+    engine
+        .process_query(statement, wal, connection_id.to_string())
+        .await
+}
+
+pub(crate) fn users_table() -> TableName {
+    TableName::new(Some("rrdb".to_string()), "users".to_string())
+}
+
+/// 스냅샷 없이 읽은 users 행의 (row index, id)
+pub(crate) async fn user_ids(engine: &DBEngine) -> Vec<(usize, TableDataFieldType)> {
+    engine
+        .full_scan(users_table())
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|(location, row)| (location.row_index, row.fields[0].data.clone()))
+        .collect()
+}
+
+/// `connection_id` 연결에서 본 users의 이름 (id 순)
+pub(crate) async fn user_names(
+    engine: &DBEngine,
+    wal: SharedWALManager,
+    connection_id: &str,
+) -> Vec<ExecuteField> {
+    execute_sql(
+        engine,
+        wal,
+        connection_id,
+        "select name from users order by id;",
+    )
+    .await
+    .unwrap()
+    .rows
+    .into_iter()
+    .map(|row| row.fields[0].clone())
+    .collect()
+}
+
+pub(crate) fn names(names: &[&str]) -> Vec<ExecuteField> {
+    names
+        .iter()
+        .map(|name| ExecuteField::String(name.to_string()))
+        .collect()
+}
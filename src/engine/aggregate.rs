@@ -0,0 +1,499 @@
+//! 집계 함수 누산기
+//!
+//! 집계 함수 하나의 진행 상태를 보관합니다. 입력 행마다 update로 인자 값을 넣고,
+//! finish로 결과를 얻습니다. NULL 입력은 ARRAY_AGG를 제외하고 무시하며,
+//! 입력이 없으면 COUNT는 0, 나머지는 NULL을 반환합니다. (PostgreSQL과 동일)
+//!
+//! 그룹별 누산기를 관리하는 해시 집계 연산자는 actions::dml::aggregate에 있습니다.
+
+use std::collections::HashSet;
+
+use crate::engine::ast::types::AggregateFunction;
+use crate::engine::schema::row::TableDataFieldType;
+use crate::engine::types::ExecuteColumnType;
+use crate::errors;
+use crate::errors::execute_error::ExecuteError;
+use crate::errors::type_error::TypeError;
+
+#[derive(Clone, Debug)]
+pub struct Accumulator {
+    function: AggregateFunction,
+    // DISTINCT 집계라면 이미 본 값 목록
+    seen: Option<HashSet<TableDataFieldType>>,
+    state: AccumulatorState,
+}
+
+#[derive(Clone, Debug)]
+enum AccumulatorState {
+    Count(i64),
+    Sum(Option<TableDataFieldType>),
+    Extreme(Option<TableDataFieldType>), // MIN, MAX
+    Avg { sum: f64, count: i64 },
+    Bool(Option<bool>), // EVERY, BOOL_AND, BOOL_OR
+    Array(Vec<TableDataFieldType>),
+    String(Option<String>),
+    // 분산 계산용 (Welford 알고리즘)
+    Moments { count: i64, mean: f64, m2: f64 },
+}
+
+impl AggregateFunction {
+    pub fn name(&self) -> &'static str {
+        match self {
+            AggregateFunction::Sum => "sum",
+            AggregateFunction::Count => "count",
+            AggregateFunction::Max => "max",
+            AggregateFunction::Min => "min",
+            AggregateFunction::Avg => "avg",
+            AggregateFunction::Every => "every",
+            AggregateFunction::ArrayAgg => "array_agg",
+            AggregateFunction::StringAgg => "string_agg",
+            AggregateFunction::BoolAnd => "bool_and",
+            AggregateFunction::BoolOr => "bool_or",
+            AggregateFunction::Stddev => "stddev",
+            AggregateFunction::StddevPop => "stddev_pop",
+            AggregateFunction::Variance => "variance",
+            AggregateFunction::VarPop => "var_pop",
+        }
+    }
+
+    /// 받을 수 있는 인자 개수 (최소, 최대). COUNT(*)는 인자가 없는 COUNT입니다.
+    pub fn arity(&self) -> (usize, usize) {
+        match self {
+            AggregateFunction::Count => (0, 1),
+            AggregateFunction::StringAgg => (2, 2),
+            _ => (1, 1),
+        }
+    }
+
+    /// 집계 결과 타입. 첫 번째 인자의 타입을 받아 결정합니다.
+    pub fn result_type(&self, argument_type: Option<ExecuteColumnType>) -> ExecuteColumnType {
+        match self {
+            AggregateFunction::Count => ExecuteColumnType::Integer,
+            AggregateFunction::Sum | AggregateFunction::Max | AggregateFunction::Min => {
+                argument_type.unwrap_or(ExecuteColumnType::Null)
+            }
+            AggregateFunction::Avg
+            | AggregateFunction::Stddev
+            | AggregateFunction::StddevPop
+            | AggregateFunction::Variance
+            | AggregateFunction::VarPop => ExecuteColumnType::Float,
+            AggregateFunction::Every | AggregateFunction::BoolAnd | AggregateFunction::BoolOr => {
+                ExecuteColumnType::Bool
+            }
+            AggregateFunction::ArrayAgg => {
+                ExecuteColumnType::Array(Box::new(argument_type.unwrap_or(ExecuteColumnType::Null)))
+            }
+            AggregateFunction::StringAgg => ExecuteColumnType::String,
+        }
+    }
+}
+
+impl Accumulator {
+    pub fn new(function: AggregateFunction, distinct: bool) -> Self {
+        let state = match function {
+            AggregateFunction::Count => AccumulatorState::Count(0),
+            AggregateFunction::Sum => AccumulatorState::Sum(None),
+            AggregateFunction::Max | AggregateFunction::Min => AccumulatorState::Extreme(None),
+            AggregateFunction::Avg => AccumulatorState::Avg { sum: 0.0, count: 0 },
+            AggregateFunction::Every | AggregateFunction::BoolAnd | AggregateFunction::BoolOr => {
+                AccumulatorState::Bool(None)
+            }
+            AggregateFunction::ArrayAgg => AccumulatorState::Array(vec![]),
+            AggregateFunction::StringAgg => AccumulatorState::String(None),
+            AggregateFunction::Stddev
+            | AggregateFunction::StddevPop
+            | AggregateFunction::Variance
+            | AggregateFunction::VarPop => AccumulatorState::Moments {
+                count: 0,
+                mean: 0.0,
+                m2: 0.0,
+            },
+        };
+
+        Self {
+            function,
+            seen: distinct.then(HashSet::new),
+            state,
+        }
+    }
+
+    /// 입력 행 하나의 인자 값들을 반영합니다.
+    pub fn update(&mut self, arguments: Vec<TableDataFieldType>) -> errors::Result<()> {
+        let mut arguments = arguments.into_iter();
+
+        // COUNT(*)
+        let Some(value) = arguments.next() else {
+            if let AccumulatorState::Count(count) = &mut self.state {
+                *count += 1;
+            }
+            return Ok(());
+        };
+
+        if value.is_null() && !matches!(self.state, AccumulatorState::Array(_)) {
+            return Ok(());
+        }
+
+        if let Some(seen) = &mut self.seen
+            && !seen.insert(value.clone())
+        {
+            return Ok(());
+        }
+
+        let function = self.function.name();
+        let type_error = || {
+            TypeError::wrap(format!(
+                "function {}({}) does not exist",
+                function,
+                type_name(&value)
+            ))
+        };
+
+        match &mut self.state {
+            AccumulatorState::Count(count) => *count += 1,
+            AccumulatorState::Sum(sum) => {
+                let added = match (sum.take(), &value) {
+                    (None, TableDataFieldType::Integer(_) | TableDataFieldType::Float(_)) => {
+                        value.clone()
+                    }
+                    (Some(TableDataFieldType::Integer(l)), TableDataFieldType::Integer(r)) => {
+                        TableDataFieldType::Integer(
+                            l.checked_add(*r)
+                                .ok_or_else(|| ExecuteError::wrap("bigint out of range"))?,
+                        )
+                    }
+                    (Some(acc), TableDataFieldType::Integer(_) | TableDataFieldType::Float(_)) => {
+                        TableDataFieldType::Float((as_f64(&acc) + as_f64(&value)).into())
+                    }
+                    _ => return Err(type_error()),
+                };
+                *sum = Some(added);
+            }
+            AccumulatorState::Extreme(extreme) => {
+                if value.is_array() {
+                    return Err(type_error());
+                }
+
+                let replace = match extreme {
+                    Some(current) => {
+                        if current.type_code() != value.type_code() {
+                            return Err(type_error());
+                        }
+
+                        match self.function {
+                            AggregateFunction::Max => value > *current,
+                            _ => value < *current,
+                        }
+                    }
+                    None => true,
+                };
+
+                if replace {
+                    *extreme = Some(value);
+                }
+            }
+            AccumulatorState::Avg { sum, count } => match value {
+                TableDataFieldType::Integer(_) | TableDataFieldType::Float(_) => {
+                    *sum += as_f64(&value);
+                    *count += 1;
+                }
+                _ => return Err(type_error()),
+            },
+            AccumulatorState::Bool(result) => match value {
+                TableDataFieldType::Boolean(boolean) => {
+                    *result = Some(match (self.function.clone(), *result) {
+                        (_, None) => boolean,
+                        (AggregateFunction::BoolOr, Some(acc)) => acc || boolean,
+                        (_, Some(acc)) => acc && boolean,
+                    });
+                }
+                _ => return Err(type_error()),
+            },
+            AccumulatorState::Array(array) => array.push(value),
+            AccumulatorState::String(result) => {
+                let TableDataFieldType::String(string) = value else {
+                    return Err(type_error());
+                };
+
+                match result {
+                    None => *result = Some(string),
+                    Some(result) => {
+                        // 구분자가 NULL이면 구분자 없이 이어붙입니다.
+                        match arguments.next() {
+                            Some(TableDataFieldType::String(delimiter)) => {
+                                result.push_str(&delimiter)
+                            }
+                            Some(TableDataFieldType::Null) | None => {}
+                            Some(_) => {
+                                return Err(TypeError::wrap(
+                                    "string_agg delimiter must be a string",
+                                ));
+                            }
+                        }
+                        result.push_str(&string);
+                    }
+                }
+            }
+            AccumulatorState::Moments { count, mean, m2 } => match value {
+                TableDataFieldType::Integer(_) | TableDataFieldType::Float(_) => {
+                    let value = as_f64(&value);
+                    *count += 1;
+                    let delta = value - *mean;
+                    *mean += delta / *count as f64;
+                    *m2 += delta * (value - *mean);
+                }
+                _ => return Err(type_error()),
+            },
+        }
+
+        Ok(())
+    }
+
+    /// 입력 값을 상태에 쌓아두는지 여부 (DISTINCT, ARRAY_AGG, STRING_AGG)
+    /// 이런 누산기는 입력이 들어올수록 메모리 사용량이 늘어납니다.
+    pub fn retains_input(&self) -> bool {
+        self.seen.is_some()
+            || matches!(
+                self.state,
+                AccumulatorState::Array(_) | AccumulatorState::String(_)
+            )
+    }
+
+    /// 집계 결과를 반환합니다.
+    pub fn finish(self) -> TableDataFieldType {
+        match self.state {
+            AccumulatorState::Count(count) => TableDataFieldType::Integer(count),
+            AccumulatorState::Sum(value) | AccumulatorState::Extreme(value) => {
+                value.unwrap_or(TableDataFieldType::Null)
+            }
+            AccumulatorState::Avg { sum, count } => {
+                if count == 0 {
+                    TableDataFieldType::Null
+                } else {
+                    TableDataFieldType::Float((sum / count as f64).into())
+                }
+            }
+            AccumulatorState::Bool(value) => value
+                .map(TableDataFieldType::Boolean)
+                .unwrap_or(TableDataFieldType::Null),
+            AccumulatorState::Array(array) => {
+                if array.is_empty() {
+                    TableDataFieldType::Null
+                } else {
+                    TableDataFieldType::Array(array)
+                }
+            }
+            AccumulatorState::String(value) => value
+                .map(TableDataFieldType::String)
+                .unwrap_or(TableDataFieldType::Null),
+            AccumulatorState::Moments { count, m2, .. } => {
+                // 표본 분산은 n - 1로, 모 분산은 n으로 나눕니다.
+                let (sample, sqrt) = match self.function {
+                    AggregateFunction::Stddev => (true, true),
+                    AggregateFunction::StddevPop => (false, true),
+                    AggregateFunction::Variance => (true, false),
+                    _ => (false, false),
+                };
+
+                let divisor = if sample { count - 1 } else { count };
+                if divisor <= 0 {
+                    return TableDataFieldType::Null;
+                }
+
+                let variance = m2 / divisor as f64;
+                let value = if sqrt { variance.sqrt() } else { variance };
+
+                TableDataFieldType::Float(value.into())
+            }
+        }
+    }
+}
+
+/// 값이 메모리에서 차지하는 크기의 추정치 (바이트)
+pub fn estimated_size(value: &TableDataFieldType) -> usize {
+    let heap = match value {
+        TableDataFieldType::String(string) => string.len(),
+        TableDataFieldType::Array(array) => array.iter().map(estimated_size).sum(),
+        _ => 0,
+    };
+
+    std::mem::size_of::<TableDataFieldType>() + heap
+}
+
+fn as_f64(value: &TableDataFieldType) -> f64 {
+    match value {
+        TableDataFieldType::Integer(integer) => *integer as f64,
+        TableDataFieldType::Float(float) => (*float).into(),
+        _ => 0.0,
+    }
+}
+
+fn type_name(value: &TableDataFieldType) -> &'static str {
+    match value {
+        TableDataFieldType::Integer(_) => "integer",
+        TableDataFieldType::Float(_) => "float",
+        TableDataFieldType::Boolean(_) => "boolean",
+        TableDataFieldType::String(_) => "varchar",
+        TableDataFieldType::Array(_) => "array",
+        TableDataFieldType::Null => "unknown",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use TableDataFieldType::{Boolean, Float, Integer, Null, String};
+
+    fn aggregate(
+        function: AggregateFunction,
+        distinct: bool,
+        rows: Vec<Vec<TableDataFieldType>>,
+    ) -> errors::Result<TableDataFieldType> {
+        let mut accumulator = Accumulator::new(function, distinct);
+        for row in rows {
+            accumulator.update(row)?;
+        }
+        Ok(accumulator.finish())
+    }
+
+    fn single(values: &[TableDataFieldType]) -> Vec<Vec<TableDataFieldType>> {
+        values.iter().cloned().map(|value| vec![value]).collect()
+    }
+
+    #[test]
+    fn aggregates_ignore_nulls_and_return_null_without_input() {
+        let values = single(&[Integer(4), Null, Integer(1), Integer(7)]);
+
+        use AggregateFunction::*;
+        assert_eq!(aggregate(Count, false, values.clone()).unwrap(), Integer(3));
+        assert_eq!(aggregate(Sum, false, values.clone()).unwrap(), Integer(12));
+        assert_eq!(aggregate(Max, false, values.clone()).unwrap(), Integer(7));
+        assert_eq!(aggregate(Min, false, values.clone()).unwrap(), Integer(1));
+        assert_eq!(aggregate(Avg, false, values).unwrap(), Float(4.0.into()));
+
+        let nulls = single(&[Null, Null]);
+        assert_eq!(aggregate(Count, false, nulls.clone()).unwrap(), Integer(0));
+        for function in [Sum, Max, Min, Avg, Every, StringAgg, Variance] {
+            assert_eq!(aggregate(function, false, vec![]).unwrap(), Null);
+        }
+        assert_eq!(aggregate(Sum, false, nulls).unwrap(), Null);
+
+        // COUNT(*)는 NULL 여부와 관계없이 행을 셉니다.
+        assert_eq!(
+            aggregate(Count, false, vec![vec![], vec![]]).unwrap(),
+            Integer(2)
+        );
+    }
+
+    #[test]
+    fn count_distinct_counts_each_value_once() {
+        let values = single(&[Integer(1), Integer(1), Null, Integer(2), Integer(2)]);
+
+        assert_eq!(
+            aggregate(AggregateFunction::Count, true, values.clone()).unwrap(),
+            Integer(2)
+        );
+        assert_eq!(
+            aggregate(AggregateFunction::Sum, true, values).unwrap(),
+            Integer(3)
+        );
+    }
+
+    #[test]
+    fn sum_promotes_to_float_and_rejects_overflow_and_strings() {
+        assert_eq!(
+            aggregate(
+                AggregateFunction::Sum,
+                false,
+                single(&[Integer(1), Float(0.5.into())])
+            )
+            .unwrap(),
+            Float(1.5.into())
+        );
+        assert!(
+            aggregate(
+                AggregateFunction::Sum,
+                false,
+                single(&[Integer(i64::MAX), Integer(1)])
+            )
+            .is_err()
+        );
+        assert!(aggregate(AggregateFunction::Sum, false, single(&[String("a".into())])).is_err());
+    }
+
+    #[test]
+    fn boolean_aggregates() {
+        let values = single(&[Boolean(true), Null, Boolean(false)]);
+
+        assert_eq!(
+            aggregate(AggregateFunction::Every, false, values.clone()).unwrap(),
+            Boolean(false)
+        );
+        assert_eq!(
+            aggregate(AggregateFunction::BoolAnd, false, values.clone()).unwrap(),
+            Boolean(false)
+        );
+        assert_eq!(
+            aggregate(AggregateFunction::BoolOr, false, values).unwrap(),
+            Boolean(true)
+        );
+        assert!(aggregate(AggregateFunction::BoolOr, false, single(&[Integer(1)])).is_err());
+    }
+
+    #[test]
+    fn array_agg_keeps_nulls_and_string_agg_skips_them() {
+        assert_eq!(
+            aggregate(
+                AggregateFunction::ArrayAgg,
+                false,
+                single(&[Integer(1), Null, Integer(2)])
+            )
+            .unwrap(),
+            TableDataFieldType::Array(vec![Integer(1), Null, Integer(2)])
+        );
+
+        let delimiter = String(", ".into());
+        assert_eq!(
+            aggregate(
+                AggregateFunction::StringAgg,
+                false,
+                vec![
+                    vec![String("a".into()), delimiter.clone()],
+                    vec![Null, delimiter.clone()],
+                    vec![String("b".into()), delimiter.clone()],
+                    vec![String("c".into()), Null],
+                ]
+            )
+            .unwrap(),
+            String("a, bc".into())
+        );
+    }
+
+    #[test]
+    fn variance_and_stddev_use_sample_or_population_divisor() {
+        let values = single(&[Integer(2), Integer(4), Integer(4), Integer(4), Integer(6)]);
+
+        assert_eq!(
+            aggregate(AggregateFunction::VarPop, false, values.clone()).unwrap(),
+            Float(1.6.into())
+        );
+        assert_eq!(
+            aggregate(AggregateFunction::Variance, false, values.clone()).unwrap(),
+            Float(2.0.into())
+        );
+        assert_eq!(
+            aggregate(AggregateFunction::Stddev, false, values).unwrap(),
+            Float(2.0f64.sqrt().into())
+        );
+
+        // 표본 분산은 값이 2개 이상이어야 합니다.
+        assert_eq!(
+            aggregate(AggregateFunction::Variance, false, single(&[Integer(1)])).unwrap(),
+            Null
+        );
+        assert_eq!(
+            aggregate(AggregateFunction::VarPop, false, single(&[Integer(1)])).unwrap(),
+            Float(0.0.into())
+        );
+    }
+}
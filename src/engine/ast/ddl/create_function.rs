@@ -0,0 +1,110 @@
+use serde::{Deserialize, Serialize};
+
+use crate::engine::ast::{
+    DDLStatement, SQLStatement,
+    types::{DataType, UserDefinedFunction},
+};
+
+/*
+CREATE [ OR REPLACE ] FUNCTION [database_name.]function_name
+    ( [ [ parameter_name ] data_type [, ...] ] )
+    RETURNS data_type
+    AS { 'body' | $$body$$ } [ LANGUAGE SQL ]
+*/
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CreateFunctionQuery {
+    pub function: UserDefinedFunction,
+    pub parameters: Vec<FunctionParameter>,
+    pub return_type: DataType,
+    /// 함수 본문 (SELECT 문 하나). 호출할 때마다 다시 파싱합니다.
+    pub body: String,
+    pub or_replace: bool,
+}
+
+// 함수 매개변수. 이름이 없으면 본문에서 $1, $2... 로만 참조할 수 있습니다.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct FunctionParameter {
+    pub name: Option<String>,
+    pub data_type: DataType,
+}
+
+impl CreateFunctionQuery {
+    pub fn builder() -> Self {
+        Self {
+            function: UserDefinedFunction {
+                database_name: None,
+                function_name: "".into(),
+            },
+            parameters: vec![],
+            return_type: DataType::Int,
+            body: "".into(),
+            or_replace: false,
+        }
+    }
+
+    pub fn set_function(mut self, function: UserDefinedFunction) -> Self {
+        self.function = function;
+        self
+    }
+
+    pub fn add_parameter(mut self, parameter: FunctionParameter) -> Self {
+        self.parameters.push(parameter);
+        self
+    }
+
+    pub fn set_return_type(mut self, return_type: DataType) -> Self {
+        self.return_type = return_type;
+        self
+    }
+
+    pub fn set_body(mut self, body: String) -> Self {
+        self.body = body;
+        self
+    }
+
+    pub fn set_or_replace(mut self, or_replace: bool) -> Self {
+        self.or_replace = or_replace;
+        self
+    }
+
+    pub fn build(self) -> SQLStatement {
+        SQLStatement::DDL(DDLStatement::CreateFunctionQuery(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_function() {
+        let function = UserDefinedFunction {
+            database_name: Some("db".into()),
+            function_name: "add_one".into(),
+        };
+
+        let query = CreateFunctionQuery::builder()
+            .set_function(function.clone())
+            .add_parameter(FunctionParameter {
+                name: Some("a".into()),
+                data_type: DataType::Int,
+            })
+            .set_return_type(DataType::Int)
+            .set_body("SELECT a + 1".into())
+            .set_or_replace(true)
+            .build();
+
+        let expected = SQLStatement::DDL(DDLStatement::CreateFunctionQuery(CreateFunctionQuery {
+            function,
+            parameters: vec![FunctionParameter {
+                name: Some("a".into()),
+                data_type: DataType::Int,
+            }],
+            return_type: DataType::Int,
+            body: "SELECT a + 1".into(),
+            or_replace: true,
+        }));
+
+        assert_eq!(query, expected);
+    }
+}
@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+
+use crate::engine::ast::{
+    DDLStatement, SQLStatement,
+    types::{DataType, UserDefinedFunction},
+};
+
+/*
+DROP FUNCTION [IF EXISTS] [database_name.]function_name [ ( [ [parameter_name] data_type [, ...] ] ) ];
+*/
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DropFunctionQuery {
+    pub function: UserDefinedFunction,
+    /// 인자 타입 목록이 주어지면 정의된 매개변수 타입과 같을 때만 삭제합니다.
+    pub parameter_types: Option<Vec<DataType>>,
+    pub if_exists: bool,
+}
+
+impl DropFunctionQuery {
+    pub fn builder() -> Self {
+        Self {
+            function: UserDefinedFunction {
+                database_name: None,
+                function_name: "".into(),
+            },
+            parameter_types: None,
+            if_exists: false,
+        }
+    }
+
+    pub fn set_function(mut self, function: UserDefinedFunction) -> Self {
+        self.function = function;
+        self
+    }
+
+    pub fn set_parameter_types(mut self, parameter_types: Vec<DataType>) -> Self {
+        self.parameter_types = Some(parameter_types);
+        self
+    }
+
+    pub fn set_if_exists(mut self, if_exists: bool) -> Self {
+        self.if_exists = if_exists;
+        self
+    }
+
+    pub fn build(self) -> SQLStatement {
+        SQLStatement::DDL(DDLStatement::DropFunctionQuery(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drop_function() {
+        let function = UserDefinedFunction {
+            database_name: Some("db".into()),
+            function_name: "add_one".into(),
+        };
+
+        let query = DropFunctionQuery::builder()
+            .set_function(function.clone())
+            .set_parameter_types(vec![DataType::Int])
+            .set_if_exists(true)
+            .build();
+
+        let expected = SQLStatement::DDL(DDLStatement::DropFunctionQuery(DropFunctionQuery {
+            function,
+            parameter_types: Some(vec![DataType::Int]),
+            if_exists: true,
+        }));
+
+        assert_eq!(query, expected);
+    }
+}
@@ -1,8 +1,11 @@
 pub mod alter_database;
 pub mod alter_table;
 pub mod create_database;
+pub mod create_function;
 pub mod create_index;
 pub mod create_table;
 pub mod drop_database;
+pub mod drop_function;
 pub mod drop_index;
 pub mod drop_table;
+pub mod truncate_table;
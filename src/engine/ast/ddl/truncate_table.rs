@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+use crate::engine::ast::{DDLStatement, SQLStatement, types::TableName};
+
+/*
+TRUNCATE [TABLE] [database_name.]table_name [, ...] [RESTART IDENTITY | CONTINUE IDENTITY];
+*/
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TruncateTableQuery {
+    pub tables: Vec<TableName>,
+    pub restart_identity: bool,
+}
+
+impl TruncateTableQuery {
+    pub fn builder() -> Self {
+        TruncateTableQuery {
+            tables: vec![],
+            restart_identity: false,
+        }
+    }
+
+    pub fn add_table(mut self, table: TableName) -> Self {
+        self.tables.push(table);
+        self
+    }
+
+    pub fn set_restart_identity(mut self, restart_identity: bool) -> Self {
+        self.restart_identity = restart_identity;
+        self
+    }
+
+    pub fn build(self) -> SQLStatement {
+        SQLStatement::DDL(DDLStatement::TruncateTableQuery(self))
+    }
+}
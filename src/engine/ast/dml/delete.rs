@@ -1,19 +1,28 @@
 use crate::engine::ast::{DMLStatement, SQLStatement, types::TableName};
 use serde::{Deserialize, Serialize};
 
-use super::parts::{_where::WhereClause, target::UpdateTarget};
+use super::parts::{
+    _where::WhereClause, returning::ReturningClause, target::UpdateTarget, with::WithClause,
+};
 
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Default)]
 pub struct DeleteQuery {
     pub from_table: Option<UpdateTarget>,
+    /// DELETE ... USING 절로 조인하는 테이블 목록
+    pub using_tables: Vec<UpdateTarget>,
     pub where_clause: Option<WhereClause>,
+    pub with_clause: Option<WithClause>,
+    pub returning: Option<ReturningClause>,
 }
 
 impl DeleteQuery {
     pub fn builder() -> Self {
         Self {
             from_table: None,
+            using_tables: vec![],
             where_clause: None,
+            with_clause: None,
+            returning: None,
         }
     }
 
@@ -32,11 +41,26 @@ impl DeleteQuery {
         self
     }
 
+    pub fn add_using_table(mut self, using: UpdateTarget) -> Self {
+        self.using_tables.push(using);
+        self
+    }
+
     pub fn set_where(mut self, where_clause: WhereClause) -> Self {
         self.where_clause = Some(where_clause);
         self
     }
 
+    pub fn set_with(mut self, with_clause: WithClause) -> Self {
+        self.with_clause = Some(with_clause);
+        self
+    }
+
+    pub fn set_returning(mut self, returning: ReturningClause) -> Self {
+        self.returning = Some(returning);
+        self
+    }
+
     pub fn build(self) -> Self {
         self
     }
@@ -63,6 +87,7 @@ mod tests {
                 expression: SQLExpression::String("a".into()),
             })
             .set_from_alias("alias".into())
+            .add_using_table(TableName::new(None, "other".into()).into())
             .build();
 
         assert_eq!(
@@ -72,9 +97,15 @@ mod tests {
                     table: TableName::new(None, "table".into()),
                     alias: Some("alias".into()),
                 }),
+                using_tables: vec![UpdateTarget {
+                    table: TableName::new(None, "other".into()),
+                    alias: None,
+                }],
                 where_clause: Some(WhereClause {
                     expression: SQLExpression::String("a".into()),
                 }),
+                with_clause: None,
+                returning: None,
             }
         );
     }
@@ -1,4 +1,8 @@
-use crate::engine::ast::types::{Function, SQLExpression};
+use std::fmt;
+
+use crate::engine::ast::types::{
+    BuiltInFunction, Function, SQLExpression, quote_identifier, write_separated,
+};
 
 use serde::{Deserialize, Serialize};
 
@@ -6,6 +10,43 @@ use serde::{Deserialize, Serialize};
 pub struct CallExpression {
     pub function: Function,
     pub arguments: Vec<SQLExpression>,
+    pub distinct: bool, // 집계 함수의 DISTINCT 여부 (COUNT(DISTINCT x))
+}
+
+impl fmt::Display for CallExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.function {
+            Function::BuiltIn(BuiltInFunction::Aggregate(function)) => {
+                write!(f, "{}", function.name())?
+            }
+            Function::BuiltIn(BuiltInFunction::Conditional(function)) => {
+                write!(f, "{}", function.name())?
+            }
+            Function::BuiltIn(BuiltInFunction::Scalar(function)) => {
+                write!(f, "{}", function.name())?
+            }
+            Function::BuiltIn(BuiltInFunction::Window(function)) => {
+                write!(f, "{}", function.name())?
+            }
+            Function::UserDefined(function) => {
+                if let Some(database_name) = &function.database_name {
+                    write!(f, "{}.", quote_identifier(database_name))?;
+                }
+                write!(f, "{}", quote_identifier(&function.function_name))?
+            }
+        }
+
+        write!(f, "(")?;
+        if self.distinct {
+            write!(f, "DISTINCT ")?;
+        }
+        // 인자가 없는 집계는 COUNT(*)입니다.
+        if self.arguments.is_empty() && self.function.is_aggregate() {
+            write!(f, "*")?;
+        }
+        write_separated(f, &self.arguments, ", ")?;
+        write!(f, ")")
+    }
 }
 
 impl From<CallExpression> for SQLExpression {
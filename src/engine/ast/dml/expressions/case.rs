@@ -0,0 +1,100 @@
+use crate::engine::ast::types::SQLExpression;
+use serde::{Deserialize, Serialize};
+
+// CASE [operand] WHEN condition THEN result [WHEN ...] [ELSE result] END
+// operand가 있으면 단순 CASE(operand = WHEN 값 비교), 없으면 검색 CASE(WHEN 조건식 평가)입니다.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct CaseExpression {
+    pub operand: Option<SQLExpression>,
+    pub branches: Vec<CaseWhenBranch>,
+    pub else_result: Option<SQLExpression>, // 없으면 NULL
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct CaseWhenBranch {
+    pub condition: SQLExpression,
+    pub result: SQLExpression,
+}
+
+impl CaseExpression {
+    // operand, WHEN 조건, THEN 결과, ELSE 결과 순서의 표현식들
+    pub fn expressions(&self) -> impl Iterator<Item = &SQLExpression> {
+        self.operand
+            .iter()
+            .chain(
+                self.branches
+                    .iter()
+                    .flat_map(|branch| [&branch.condition, &branch.result]),
+            )
+            .chain(self.else_result.iter())
+    }
+
+    pub fn expressions_mut(&mut self) -> impl Iterator<Item = &mut SQLExpression> {
+        self.operand
+            .iter_mut()
+            .chain(
+                self.branches
+                    .iter_mut()
+                    .flat_map(|branch| [&mut branch.condition, &mut branch.result]),
+            )
+            .chain(self.else_result.iter_mut())
+    }
+}
+
+impl From<CaseExpression> for SQLExpression {
+    fn from(value: CaseExpression) -> SQLExpression {
+        SQLExpression::Case(Box::new(value))
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use crate::engine::ast::types::SQLExpression;
+
+    #[test]
+    fn test_From_CaseExpression_for_SQLExpression() {
+        use super::{CaseExpression, CaseWhenBranch};
+        let case = CaseExpression {
+            operand: None,
+            branches: vec![CaseWhenBranch {
+                condition: SQLExpression::Boolean(true),
+                result: SQLExpression::Integer(1),
+            }],
+            else_result: None,
+        };
+        let sql_expression: SQLExpression = case.clone().into();
+        assert_eq!(sql_expression, SQLExpression::Case(Box::new(case)));
+    }
+
+    #[test]
+    fn test_CaseExpression_expressions() {
+        use super::{CaseExpression, CaseWhenBranch};
+        let case = CaseExpression {
+            operand: Some(SQLExpression::Integer(0)),
+            branches: vec![
+                CaseWhenBranch {
+                    condition: SQLExpression::Integer(1),
+                    result: SQLExpression::String("a".into()),
+                },
+                CaseWhenBranch {
+                    condition: SQLExpression::Integer(2),
+                    result: SQLExpression::String("b".into()),
+                },
+            ],
+            else_result: Some(SQLExpression::Null),
+        };
+
+        assert_eq!(
+            case.expressions().cloned().collect::<Vec<_>>(),
+            vec![
+                SQLExpression::Integer(0),
+                SQLExpression::Integer(1),
+                SQLExpression::String("a".into()),
+                SQLExpression::Integer(2),
+                SQLExpression::String("b".into()),
+                SQLExpression::Null,
+            ]
+        );
+    }
+}
@@ -0,0 +1,32 @@
+use crate::engine::ast::types::{DataType, SQLExpression};
+use serde::{Deserialize, Serialize};
+
+// CAST(expression AS data_type), expression::data_type
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct CastExpression {
+    pub expression: SQLExpression,
+    pub data_type: DataType,
+}
+
+impl From<CastExpression> for SQLExpression {
+    fn from(value: CastExpression) -> SQLExpression {
+        SQLExpression::Cast(Box::new(value))
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use crate::engine::ast::types::{DataType, SQLExpression};
+
+    #[test]
+    fn test_From_CastExpression_for_SQLExpression() {
+        use super::CastExpression;
+        let cast = CastExpression {
+            expression: SQLExpression::String("1".into()),
+            data_type: DataType::Int,
+        };
+        let sql_expression: SQLExpression = cast.clone().into();
+        assert_eq!(sql_expression, SQLExpression::Cast(Box::new(cast)));
+    }
+}
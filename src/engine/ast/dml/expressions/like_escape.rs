@@ -0,0 +1,45 @@
+use crate::engine::ast::types::SQLExpression;
+use serde::{Deserialize, Serialize};
+
+// a LIKE pattern ESCAPE escape 의 우변
+// 평가 시 escape 문자를 기본 escape 문자(\)로 치환한 패턴 문자열이 됩니다.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct LikeEscapeExpression {
+    pub pattern: SQLExpression,
+    pub escape: SQLExpression,
+}
+
+impl From<LikeEscapeExpression> for SQLExpression {
+    fn from(value: LikeEscapeExpression) -> SQLExpression {
+        SQLExpression::LikeEscape(Box::new(value))
+    }
+}
+
+impl From<Box<LikeEscapeExpression>> for SQLExpression {
+    fn from(value: Box<LikeEscapeExpression>) -> SQLExpression {
+        SQLExpression::LikeEscape(value)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use crate::engine::ast::types::SQLExpression;
+
+    #[test]
+    fn test_From_LikeEscapeExpression_for_SQLExpression() {
+        use super::LikeEscapeExpression;
+        let like_escape = LikeEscapeExpression {
+            pattern: SQLExpression::String("a!%".into()),
+            escape: SQLExpression::String("!".into()),
+        };
+        let sql_expression: SQLExpression = like_escape.into();
+        assert_eq!(
+            sql_expression,
+            SQLExpression::LikeEscape(Box::new(LikeEscapeExpression {
+                pattern: SQLExpression::String("a!%".into()),
+                escape: SQLExpression::String("!".into()),
+            }))
+        );
+    }
+}
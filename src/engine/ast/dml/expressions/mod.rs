@@ -2,9 +2,12 @@ pub mod between;
 pub mod binary;
 pub mod boolean;
 pub mod call;
+pub mod case;
+pub mod cast;
 pub mod float;
 pub mod identifier;
 pub mod integer;
+pub mod like_escape;
 pub mod list;
 pub mod not_between;
 pub mod operators;
@@ -12,3 +15,4 @@ pub mod parentheses;
 pub mod string;
 pub mod subquery;
 pub mod unary;
+pub mod window;
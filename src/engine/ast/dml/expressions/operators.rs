@@ -3,24 +3,28 @@ use serde::{Deserialize, Serialize};
 // 2항연산자
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
 pub enum BinaryOperator {
-    Add,     // A + B
-    Sub,     // A - B
-    Mul,     // A * B
-    Div,     // A / B
-    And,     // A AND B
-    Or,      // A OR B
-    Lt,      // A < B
-    Gt,      // A > B
-    Lte,     // A <= B
-    Gte,     // A >= B
-    Eq,      // A = B
-    Neq,     // A != B, A <> B
-    Like,    // A LIKE B
-    NotLike, // A NOT LIKE B
-    In,      // A In B
-    NotIn,   // A Not In B
-    Is,      // A Is B
-    IsNot,   // A Is Not B
+    Add,               // A + B
+    Sub,               // A - B
+    Mul,               // A * B
+    Div,               // A / B
+    And,               // A AND B
+    Or,                // A OR B
+    Lt,                // A < B
+    Gt,                // A > B
+    Lte,               // A <= B
+    Gte,               // A >= B
+    Eq,                // A = B
+    Neq,               // A != B, A <> B
+    Like,              // A LIKE B
+    NotLike,           // A NOT LIKE B
+    ILike,             // A ILIKE B
+    NotILike,          // A NOT ILIKE B
+    In,                // A In B
+    NotIn,             // A Not In B
+    Is,                // A Is B
+    IsNot,             // A Is Not B
+    IsDistinctFrom,    // A IS DISTINCT FROM B
+    IsNotDistinctFrom, // A IS NOT DISTINCT FROM B
 }
 
 // 단항연산자
@@ -46,22 +50,75 @@ impl BinaryOperator {
             BinaryOperator::Neq => 5,
             BinaryOperator::Like => 5,
             BinaryOperator::NotLike => 5,
+            BinaryOperator::ILike => 5,
+            BinaryOperator::NotILike => 5,
             BinaryOperator::In => 5,
             BinaryOperator::NotIn => 5,
             BinaryOperator::Is => 5,
             BinaryOperator::IsNot => 5,
+            BinaryOperator::IsDistinctFrom => 5,
+            BinaryOperator::IsNotDistinctFrom => 5,
             BinaryOperator::Add => 10,
             BinaryOperator::Sub => 10,
             BinaryOperator::Mul => 40,
             BinaryOperator::Div => 40,
         }
     }
+
+    // SQL 문장에서 쓰는 연산자 표기
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            BinaryOperator::Add => "+",
+            BinaryOperator::Sub => "-",
+            BinaryOperator::Mul => "*",
+            BinaryOperator::Div => "/",
+            BinaryOperator::And => "AND",
+            BinaryOperator::Or => "OR",
+            BinaryOperator::Lt => "<",
+            BinaryOperator::Gt => ">",
+            BinaryOperator::Lte => "<=",
+            BinaryOperator::Gte => ">=",
+            BinaryOperator::Eq => "=",
+            BinaryOperator::Neq => "<>",
+            BinaryOperator::Like => "LIKE",
+            BinaryOperator::NotLike => "NOT LIKE",
+            BinaryOperator::ILike => "ILIKE",
+            BinaryOperator::NotILike => "NOT ILIKE",
+            BinaryOperator::In => "IN",
+            BinaryOperator::NotIn => "NOT IN",
+            BinaryOperator::Is => "IS",
+            BinaryOperator::IsNot => "IS NOT",
+            BinaryOperator::IsDistinctFrom => "IS DISTINCT FROM",
+            BinaryOperator::IsNotDistinctFrom => "IS NOT DISTINCT FROM",
+        }
+    }
+
+    // NULL을 하나의 값으로 다루는 IS 계열 연산자 여부
+    pub fn is_null_safe(&self) -> bool {
+        matches!(
+            self,
+            BinaryOperator::Is
+                | BinaryOperator::IsNot
+                | BinaryOperator::IsDistinctFrom
+                | BinaryOperator::IsNotDistinctFrom
+        )
+    }
+
+    // LIKE 계열 패턴 매칭 연산자 여부
+    pub fn is_pattern_match(&self) -> bool {
+        matches!(
+            self,
+            BinaryOperator::Like
+                | BinaryOperator::NotLike
+                | BinaryOperator::ILike
+                | BinaryOperator::NotILike
+        )
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::BinaryOperator;
-    use crate::engine::parser::predule::Parser;
 
     #[test]
     fn test_get_precedence() {
@@ -79,10 +136,14 @@ mod tests {
         assert_eq!(BinaryOperator::Neq.get_precedence(), 5);
         assert_eq!(BinaryOperator::Like.get_precedence(), 5);
         assert_eq!(BinaryOperator::NotLike.get_precedence(), 5);
+        assert_eq!(BinaryOperator::ILike.get_precedence(), 5);
+        assert_eq!(BinaryOperator::NotILike.get_precedence(), 5);
         assert_eq!(BinaryOperator::In.get_precedence(), 5);
         assert_eq!(BinaryOperator::NotIn.get_precedence(), 5);
         assert_eq!(BinaryOperator::Is.get_precedence(), 5);
         assert_eq!(BinaryOperator::IsNot.get_precedence(), 5);
+        assert_eq!(BinaryOperator::IsDistinctFrom.get_precedence(), 5);
+        assert_eq!(BinaryOperator::IsNotDistinctFrom.get_precedence(), 5);
     }
 
     /// 회귀 테스트: AND/OR/비교 연산자가 섞인 표현식이 올바른 결합 순서로 파싱되는지 검증합니다.
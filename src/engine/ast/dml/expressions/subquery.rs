@@ -4,7 +4,28 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub enum SubqueryExpression {
-    Select(Box<SelectQuery>),
+    Select(Box<SelectQuery>), // (SELECT ...) 스칼라 서브쿼리 또는 IN의 우변
+    Exists(Box<SelectQuery>), // EXISTS (SELECT ...)
+}
+
+impl SubqueryExpression {
+    pub fn query(&self) -> &SelectQuery {
+        match self {
+            SubqueryExpression::Select(query) | SubqueryExpression::Exists(query) => query,
+        }
+    }
+
+    pub fn query_mut(&mut self) -> &mut SelectQuery {
+        match self {
+            SubqueryExpression::Select(query) | SubqueryExpression::Exists(query) => query,
+        }
+    }
+
+    pub fn into_query(self) -> SelectQuery {
+        match self {
+            SubqueryExpression::Select(query) | SubqueryExpression::Exists(query) => *query,
+        }
+    }
 }
 
 impl Default for SubqueryExpression {
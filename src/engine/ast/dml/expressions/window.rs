@@ -0,0 +1,138 @@
+use std::fmt;
+
+use crate::engine::ast::dml::expressions::call::CallExpression;
+use crate::engine::ast::dml::parts::order_by::OrderByItem;
+use crate::engine::ast::types::SQLExpression;
+use serde::{Deserialize, Serialize};
+
+// function(...) OVER (PARTITION BY ... ORDER BY ... frame)
+// call은 윈도우 함수(ROW_NUMBER 등)나 집계 함수 호출입니다.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct WindowExpression {
+    pub call: CallExpression,
+    pub partition_by: Vec<SQLExpression>,
+    pub order_by: Vec<OrderByItem>,
+    pub frame: Option<WindowFrame>, // 없으면 ORDER BY가 있을 때 RANGE UNBOUNDED PRECEDING ~ CURRENT ROW, 없을 때 파티션 전체
+}
+
+// {ROWS | RANGE} BETWEEN start AND end
+// BETWEEN 없이 start만 쓰면 end는 CURRENT ROW입니다.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct WindowFrame {
+    pub unit: WindowFrameUnit,
+    pub start: WindowFrameBound,
+    pub end: WindowFrameBound,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub enum WindowFrameUnit {
+    Rows,  // 행 개수 기준
+    Range, // ORDER BY 값 기준 (같은 값의 행은 함께 포함)
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub enum WindowFrameBound {
+    UnboundedPreceding,
+    Preceding(u64),
+    CurrentRow,
+    Following(u64),
+    UnboundedFollowing,
+}
+
+impl WindowExpression {
+    // 함수 인자, PARTITION BY, ORDER BY의 표현식들
+    pub fn expressions(&self) -> impl Iterator<Item = &SQLExpression> {
+        self.call
+            .arguments
+            .iter()
+            .chain(self.partition_by.iter())
+            .chain(
+                self.order_by
+                    .iter()
+                    .map(|order_by_item| &order_by_item.item),
+            )
+    }
+
+    pub fn expressions_mut(&mut self) -> impl Iterator<Item = &mut SQLExpression> {
+        self.call
+            .arguments
+            .iter_mut()
+            .chain(self.partition_by.iter_mut())
+            .chain(
+                self.order_by
+                    .iter_mut()
+                    .map(|order_by_item| &mut order_by_item.item),
+            )
+    }
+}
+
+impl fmt::Display for WindowExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut clauses = vec![];
+
+        if !self.partition_by.is_empty() {
+            clauses.push(format!("PARTITION BY {}", join(&self.partition_by)));
+        }
+        if !self.order_by.is_empty() {
+            clauses.push(format!("ORDER BY {}", join(&self.order_by)));
+        }
+        if let Some(frame) = &self.frame {
+            let unit = match frame.unit {
+                WindowFrameUnit::Rows => "ROWS",
+                WindowFrameUnit::Range => "RANGE",
+            };
+            clauses.push(format!("{unit} BETWEEN {} AND {}", frame.start, frame.end));
+        }
+
+        write!(f, "{} OVER ({})", self.call, clauses.join(" "))
+    }
+}
+
+fn join<T: fmt::Display>(items: &[T]) -> String {
+    items
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl fmt::Display for WindowFrameBound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WindowFrameBound::UnboundedPreceding => write!(f, "UNBOUNDED PRECEDING"),
+            WindowFrameBound::Preceding(offset) => write!(f, "{offset} PRECEDING"),
+            WindowFrameBound::CurrentRow => write!(f, "CURRENT ROW"),
+            WindowFrameBound::Following(offset) => write!(f, "{offset} FOLLOWING"),
+            WindowFrameBound::UnboundedFollowing => write!(f, "UNBOUNDED FOLLOWING"),
+        }
+    }
+}
+
+impl From<WindowExpression> for SQLExpression {
+    fn from(value: WindowExpression) -> SQLExpression {
+        SQLExpression::Window(Box::new(value))
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use crate::engine::ast::types::{SQLExpression, WindowFunction};
+
+    #[test]
+    fn test_From_WindowExpression_for_SQLExpression() {
+        use super::{CallExpression, WindowExpression};
+        let window = WindowExpression {
+            call: CallExpression {
+                function: WindowFunction::RowNumber.into(),
+                arguments: vec![],
+                distinct: false,
+            },
+            partition_by: vec![],
+            order_by: vec![],
+            frame: None,
+        };
+        let sql_expression: SQLExpression = window.clone().into();
+        assert_eq!(sql_expression, SQLExpression::Window(Box::new(window)));
+    }
+}
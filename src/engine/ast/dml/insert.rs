@@ -1,34 +1,41 @@
 use crate::engine::ast::{DMLStatement, SQLStatement, types::TableName};
 use serde::{Deserialize, Serialize};
 
-use super::{parts::insert_values::InsertValue, select::SelectQuery};
+use super::{
+    parts::{
+        insert_values::InsertValue, on_conflict::OnConflictClause, returning::ReturningClause,
+        with::WithClause,
+    },
+    select::SelectQuery,
+};
 
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Default)]
 pub struct InsertQuery {
     pub into_table: Option<TableName>,
     pub columns: Vec<String>,
     pub data: InsertData,
+    pub with_clause: Option<WithClause>,
+    pub on_conflict: Option<OnConflictClause>,
+    pub returning: Option<ReturningClause>,
 }
 
-#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Default)]
 pub enum InsertData {
     Select(Box<SelectQuery>),
     Values(Vec<InsertValue>),
+    #[default]
     None,
 }
 
-impl Default for InsertData {
-    fn default() -> Self {
-        Self::None
-    }
-}
-
 impl InsertQuery {
     pub fn builder() -> Self {
         Self {
             columns: vec![],
             into_table: None,
             data: InsertData::None,
+            with_clause: None,
+            on_conflict: None,
+            returning: None,
         }
     }
 
@@ -52,6 +59,21 @@ impl InsertQuery {
         self
     }
 
+    pub fn set_with(mut self, with_clause: WithClause) -> Self {
+        self.with_clause = Some(with_clause);
+        self
+    }
+
+    pub fn set_on_conflict(mut self, on_conflict: OnConflictClause) -> Self {
+        self.on_conflict = Some(on_conflict);
+        self
+    }
+
+    pub fn set_returning(mut self, returning: ReturningClause) -> Self {
+        self.returning = Some(returning);
+        self
+    }
+
     pub fn build(self) -> Self {
         self
     }
@@ -88,6 +110,9 @@ mod tests {
                 data: InsertData::Values(vec![InsertValue {
                     list: vec![Some(SQLExpression::String("a".into()))],
                 }]),
+                with_clause: None,
+                on_conflict: None,
+                returning: None,
             }))
         );
     }
@@ -0,0 +1,9 @@
+use crate::engine::ast::types::SQLExpression;
+
+use serde::{Deserialize, Serialize};
+
+// SELECT DISTINCT / SELECT DISTINCT ON (...)
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Default)]
+pub struct DistinctClause {
+    pub on: Option<Vec<SQLExpression>>, // DISTINCT ON의 표현식 목록. None이면 SELECT 목록 전체로 중복 판단
+}
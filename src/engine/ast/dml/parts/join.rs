@@ -19,4 +19,7 @@ pub enum JoinType {
     LeftOuterJoin,
     RightOuterJoin,
     FullOuterJoin,
+    // 아래 두 가지는 SQL로 작성할 수 없고, 옵티마이저가 EXISTS/NOT EXISTS를 풀어서 만듭니다.
+    LeftSemiJoin, // 우측에 짝이 있는 좌측 행만 (EXISTS)
+    LeftAntiJoin, // 우측에 짝이 없는 좌측 행만 (NOT EXISTS)
 }
@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+// FOR { UPDATE | NO KEY UPDATE | SHARE } [NOWAIT | SKIP LOCKED]
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct LockingClause {
+    pub mode: RowLockMode,
+    pub wait_policy: LockWaitPolicy,
+}
+
+// 약한 것부터 선언합니다. 한 트랜잭션이 같은 행을 다시 잠그면 더 강한 쪽을 남깁니다.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RowLockMode {
+    Share,
+    NoKeyUpdate,
+    Update,
+}
+
+impl RowLockMode {
+    // 공유 락끼리만 함께 잡을 수 있습니다.
+    pub fn conflicts_with(self, other: RowLockMode) -> bool {
+        !(self == RowLockMode::Share && other == RowLockMode::Share)
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            RowLockMode::Share => "FOR SHARE",
+            RowLockMode::NoKeyUpdate => "FOR NO KEY UPDATE",
+            RowLockMode::Update => "FOR UPDATE",
+        }
+    }
+}
+
+// 다른 트랜잭션이 잠근 행을 만났을 때의 동작
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum LockWaitPolicy {
+    #[default]
+    Wait,
+    NoWait,
+    SkipLocked,
+}
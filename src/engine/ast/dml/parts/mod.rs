@@ -1,11 +1,17 @@
 #[path = "./where.rs"]
 pub mod _where;
+pub mod distinct;
 pub mod from;
 pub mod group_by;
 pub mod having;
 pub mod insert_values;
 pub mod join;
+pub mod locking;
+pub mod on_conflict;
 pub mod order_by;
+pub mod returning;
 pub mod select_item;
+pub mod set_operation;
 pub mod target;
 pub mod update_item;
+pub mod with;
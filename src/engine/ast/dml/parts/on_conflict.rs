@@ -0,0 +1,23 @@
+use crate::engine::ast::dml::parts::{_where::WhereClause, update_item::UpdateItem};
+
+use serde::{Deserialize, Serialize};
+
+// DO UPDATE 절에서 삽입하려던 행을 가리키는 가상 테이블 이름
+pub const EXCLUDED_TABLE_NAME: &str = "excluded";
+
+// ON CONFLICT [(column)] DO NOTHING
+// ON CONFLICT (column) DO UPDATE SET column = expression, ... [WHERE condition]
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct OnConflictClause {
+    pub target_columns: Vec<String>, // 비어 있으면 모든 고유 인덱스에서 충돌을 확인합니다.
+    pub action: OnConflictAction,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub enum OnConflictAction {
+    DoNothing,
+    DoUpdate {
+        update_items: Vec<UpdateItem>,
+        where_clause: Option<WhereClause>,
+    },
+}
@@ -1,3 +1,5 @@
+use std::fmt;
+
 use crate::engine::ast::types::SQLExpression;
 
 use serde::{Deserialize, Serialize};
@@ -27,3 +29,18 @@ pub enum OrderByNulls {
     #[default]
     Last,
 }
+
+impl fmt::Display for OrderByItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let order_type = match self.order_type {
+            OrderByType::Asc => "ASC",
+            OrderByType::Desc => "DESC",
+        };
+        let nulls = match self.nulls {
+            OrderByNulls::First => "FIRST",
+            OrderByNulls::Last => "LAST",
+        };
+
+        write!(f, "{} {order_type} NULLS {nulls}", self.item)
+    }
+}
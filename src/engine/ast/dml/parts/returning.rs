@@ -0,0 +1,10 @@
+use crate::engine::ast::dml::parts::select_item::SelectKind;
+
+use serde::{Deserialize, Serialize};
+
+// INSERT/UPDATE/DELETE ... RETURNING expression [AS alias], ... | *
+// 영향받은 행(DELETE는 삭제 전, 나머지는 반영 후의 행)을 기준으로 계산합니다.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Default)]
+pub struct ReturningClause {
+    pub select_items: Vec<SelectKind>,
+}
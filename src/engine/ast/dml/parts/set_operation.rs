@@ -0,0 +1,30 @@
+use crate::engine::ast::dml::select::SelectQuery;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub enum SetOperator {
+    Union,
+    Intersect,
+    Except,
+}
+
+impl SetOperator {
+    pub fn name(&self) -> &'static str {
+        match self {
+            SetOperator::Union => "UNION",
+            SetOperator::Intersect => "INTERSECT",
+            SetOperator::Except => "EXCEPT",
+        }
+    }
+}
+
+// left UNION [ALL] right, left INTERSECT [ALL] right, left EXCEPT [ALL] right
+// ORDER BY, LIMIT, OFFSET은 이 절을 가진 SelectQuery에 붙어서 결합된 결과 전체에 적용됩니다.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct SetOperation {
+    pub operator: SetOperator,
+    pub all: bool, // ALL이면 중복 행을 유지합니다.
+    pub left: Box<SelectQuery>,
+    pub right: Box<SelectQuery>,
+}
@@ -0,0 +1,37 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::engine::ast::{dml::select::SelectQuery, types::TableName};
+
+use serde::{Deserialize, Serialize};
+
+// WITH 절 테이블을 가리키는 TableName의 데이터베이스 이름. 실제 데이터베이스 이름과 겹치지 않습니다.
+pub const COMMON_TABLE_DATABASE: &str = "?with?";
+
+static NEXT_COMMON_TABLE_ID: AtomicU64 = AtomicU64::new(0);
+
+// WITH [RECURSIVE] name [(column, ...)] AS (query), ...
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct WithClause {
+    pub recursive: bool,
+    pub common_tables: Vec<CommonTableExpression>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct CommonTableExpression {
+    pub name: String,
+    pub binding: TableName, // 파서가 FROM 절의 테이블 참조를 바꿔 넣는 고유 이름
+    pub columns: Vec<String>, // 비어 있으면 쿼리 결과의 컬럼명을 그대로 씁니다.
+    pub query: Box<SelectQuery>,
+}
+
+impl CommonTableExpression {
+    // 같은 이름의 WITH 절 테이블이나 실제 테이블과 겹치지 않는 고유 이름을 만듭니다.
+    pub fn new_binding(name: &str) -> TableName {
+        let id = NEXT_COMMON_TABLE_ID.fetch_add(1, Ordering::Relaxed);
+
+        TableName::new(
+            Some(COMMON_TABLE_DATABASE.to_owned()),
+            format!("{name}#{id}"),
+        )
+    }
+}
@@ -1,4 +1,5 @@
 use crate::engine::ast::dml::plan::select::filter::FilterPlan;
+use crate::engine::ast::dml::plan::select::join::JoinPlan;
 
 use super::from::DeleteFromPlan;
 
@@ -11,6 +12,7 @@ pub struct DeletePlan {
 pub enum DeletePlanItem {
     DeleteFrom(DeleteFromPlan),
     Filter(FilterPlan),
+    Join(JoinPlan),
 }
 
 impl From<FilterPlan> for DeletePlanItem {
@@ -19,6 +21,12 @@ impl From<FilterPlan> for DeletePlanItem {
     }
 }
 
+impl From<JoinPlan> for DeletePlanItem {
+    fn from(value: JoinPlan) -> DeletePlanItem {
+        DeletePlanItem::Join(value)
+    }
+}
+
 impl From<DeleteFromPlan> for DeletePlanItem {
     fn from(value: DeleteFromPlan) -> DeletePlanItem {
         DeletePlanItem::DeleteFrom(value)
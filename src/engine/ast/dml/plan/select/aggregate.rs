@@ -0,0 +1,12 @@
+use crate::engine::ast::dml::expressions::call::CallExpression;
+use crate::engine::ast::dml::parts::group_by::GroupByItem;
+
+/// 해시 집계 계획
+///
+/// group_by_items가 비어 있으면 입력 전체를 하나의 그룹으로 집계합니다. (입력이 없어도 결과는 한 행)
+/// aggregates는 SELECT 목록, HAVING, ORDER BY에서 참조하는 집계 호출이며, 그룹마다 이것들의 상태만 보관합니다.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AggregatePlan {
+    pub group_by_items: Vec<GroupByItem>,
+    pub aggregates: Vec<CallExpression>,
+}
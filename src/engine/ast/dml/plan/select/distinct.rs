@@ -0,0 +1,10 @@
+use crate::engine::ast::types::SQLExpression;
+
+/// 중복 제거 계획
+///
+/// on이 None이면 SELECT 목록 전체가 같은 행을, Some이면 해당 표현식 값이 같은 행을 중복으로 보고
+/// 먼저 나온 행 하나만 남깁니다. 정렬 뒤에 실행되므로 DISTINCT ON은 각 그룹의 첫 행을 남깁니다.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DistinctPlan {
+    pub on: Option<Vec<SQLExpression>>,
+}
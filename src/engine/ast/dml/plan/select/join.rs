@@ -7,6 +7,7 @@ use crate::engine::ast::{
 pub struct JoinPlan {
     pub left: TableName,
     pub right: TableName,
+    pub right_alias: Option<String>,
     pub join_type: JoinType,
     pub join_scan_type: JoinScanType,
     pub select_columns: Vec<String>,
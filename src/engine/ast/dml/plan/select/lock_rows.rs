@@ -0,0 +1,16 @@
+use crate::engine::ast::dml::parts::locking::LockingClause;
+use crate::engine::ast::types::{SQLExpression, TableName};
+
+/// 행 락 계획 (SELECT ... FOR UPDATE / FOR SHARE)
+///
+/// 필터링과 정렬 뒤, LIMIT 앞에서 실행됩니다. 결과 행을 순서대로 잠그며, SKIP LOCKED로
+/// 건너뛴 행은 결과에서 빠지므로 LIMIT을 채울 때까지 다음 행을 잠급니다.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LockRowsPlan {
+    pub table_name: TableName,
+    pub locking: LockingClause,
+    /// 기다리는 동안 다른 트랜잭션이 바꾼 행은 새 버전으로 WHERE 조건을 다시 확인합니다.
+    pub recheck: Option<SQLExpression>,
+    /// OFFSET + LIMIT. 이만큼 잠그면 나머지 행은 잠그지 않습니다.
+    pub limit: Option<usize>,
+}
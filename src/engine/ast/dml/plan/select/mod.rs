@@ -1,7 +1,12 @@
+pub mod aggregate;
+pub mod distinct;
 pub mod filter;
 pub mod from;
 pub mod join;
 pub mod limit_offset;
+pub mod lock_rows;
 pub mod scan;
 pub mod select_plan;
+pub mod set_operation;
 pub mod subquery;
+pub mod window;
@@ -12,6 +12,8 @@ pub struct IndexScanPlan {
     pub column_name: String,
     /// 동등(=) 조건 조회 키. 있으면 point lookup으로 실행됩니다.
     pub eq_key: Option<String>,
+    /// IN 목록 조회 키 (정렬, 중복 제거됨). 비어있지 않으면 키마다 point lookup으로 실행됩니다.
+    pub in_keys: Vec<String>,
     /// 범위 시작 키(포함). 배타 경계는 플랜 생성 시점에 "\0" suffix로 보정됩니다.
     pub start_key: Option<String>,
     /// 범위 끝 키(제외)
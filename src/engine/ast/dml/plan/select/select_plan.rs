@@ -1,8 +1,9 @@
-use crate::engine::ast::dml::parts::{group_by::GroupByClause, order_by::OrderByClause};
+use crate::engine::ast::dml::parts::order_by::OrderByClause;
 
 use super::{
-    filter::FilterPlan, from::SelectFromPlan, join::JoinPlan, limit_offset::LimitOffsetPlan,
-    subquery::SelectSubqueryPlan,
+    aggregate::AggregatePlan, distinct::DistinctPlan, filter::FilterPlan, from::SelectFromPlan,
+    join::JoinPlan, limit_offset::LimitOffsetPlan, lock_rows::LockRowsPlan,
+    set_operation::SetOperationPlan, subquery::SelectSubqueryPlan, window::WindowPlan,
 };
 
 #[derive(Clone, Debug, PartialEq)]
@@ -14,10 +15,13 @@ pub struct SelectPlan {
 pub enum SelectPlanItem {
     From(SelectFromPlan),
     Subquery(SelectSubqueryPlan),
+    SetOperation(SetOperationPlan),
     Join(JoinPlan),
     Order(OrderByClause),
-    Group(GroupByClause),
-    GroupAll,
+    Aggregate(AggregatePlan),
+    Window(WindowPlan),
+    Distinct(DistinctPlan),
+    LockRows(LockRowsPlan),
     LimitOffset(LimitOffsetPlan),
     Filter(FilterPlan),
 }
@@ -34,6 +38,12 @@ impl From<SelectSubqueryPlan> for SelectPlanItem {
     }
 }
 
+impl From<SetOperationPlan> for SelectPlanItem {
+    fn from(value: SetOperationPlan) -> SelectPlanItem {
+        SelectPlanItem::SetOperation(value)
+    }
+}
+
 impl From<JoinPlan> for SelectPlanItem {
     fn from(value: JoinPlan) -> SelectPlanItem {
         SelectPlanItem::Join(value)
@@ -46,9 +56,27 @@ impl From<OrderByClause> for SelectPlanItem {
     }
 }
 
-impl From<GroupByClause> for SelectPlanItem {
-    fn from(value: GroupByClause) -> SelectPlanItem {
-        SelectPlanItem::Group(value)
+impl From<AggregatePlan> for SelectPlanItem {
+    fn from(value: AggregatePlan) -> SelectPlanItem {
+        SelectPlanItem::Aggregate(value)
+    }
+}
+
+impl From<WindowPlan> for SelectPlanItem {
+    fn from(value: WindowPlan) -> SelectPlanItem {
+        SelectPlanItem::Window(value)
+    }
+}
+
+impl From<DistinctPlan> for SelectPlanItem {
+    fn from(value: DistinctPlan) -> SelectPlanItem {
+        SelectPlanItem::Distinct(value)
+    }
+}
+
+impl From<LockRowsPlan> for SelectPlanItem {
+    fn from(value: LockRowsPlan) -> SelectPlanItem {
+        SelectPlanItem::LockRows(value)
     }
 }
 
@@ -69,8 +97,13 @@ impl From<FilterPlan> for SelectPlanItem {
 mod tests {
     use crate::engine::ast::{
         dml::{
-            parts::join::JoinType,
+            parts::{
+                join::JoinType,
+                locking::{LockWaitPolicy, LockingClause, RowLockMode},
+                set_operation::SetOperator,
+            },
             plan::select::{from::SelectFromPlan, join::JoinScanType, scan::ScanType},
+            select::SelectQuery,
         },
         types::{SQLExpression, TableName},
     };
@@ -93,20 +126,34 @@ mod tests {
     fn From_SelectSubqueryPlan_for_SelectPlanItem() {
         let select_subquery = SelectSubqueryPlan {
             table_name: TableName::new(None, "table".into()),
-            select_columns: vec![],
-            index: None,
-            filter: None,
+            subquery: Box::new(SelectQuery::builder().build()),
         };
         let select_plan_item: SelectPlanItem = select_subquery.clone().into();
         assert_eq!(select_plan_item, SelectPlanItem::Subquery(select_subquery));
     }
 
+    #[test]
+    fn From_SetOperationPlan_for_SelectPlanItem() {
+        let set_operation = SetOperationPlan {
+            operator: SetOperator::Union,
+            all: false,
+            left: Box::new(SelectQuery::builder().build()),
+            right: Box::new(SelectQuery::builder().build()),
+        };
+        let select_plan_item: SelectPlanItem = set_operation.clone().into();
+        assert_eq!(
+            select_plan_item,
+            SelectPlanItem::SetOperation(set_operation)
+        );
+    }
+
     #[test]
     fn From_JoinPlan_for_SelectPlanItem() {
         let join = JoinPlan {
             join_type: JoinType::InnerJoin,
             left: TableName::new(None, "l".into()),
             right: TableName::new(None, "r".into()),
+            right_alias: None,
             join_scan_type: JoinScanType::Hash,
             select_columns: vec![],
             index: None,
@@ -126,13 +173,42 @@ mod tests {
     }
 
     #[test]
-    fn From_GroupByClause_for_SelectPlanItem() {
-        let group_by = GroupByClause {
+    fn From_AggregatePlan_for_SelectPlanItem() {
+        let aggregate = AggregatePlan {
             group_by_items: vec![],
-            group_by_all: false,
+            aggregates: vec![],
+        };
+        let select_plan_item: SelectPlanItem = aggregate.clone().into();
+        assert_eq!(select_plan_item, SelectPlanItem::Aggregate(aggregate));
+    }
+
+    #[test]
+    fn From_WindowPlan_for_SelectPlanItem() {
+        let window = WindowPlan { windows: vec![] };
+        let select_plan_item: SelectPlanItem = window.clone().into();
+        assert_eq!(select_plan_item, SelectPlanItem::Window(window));
+    }
+
+    #[test]
+    fn From_DistinctPlan_for_SelectPlanItem() {
+        let distinct = DistinctPlan { on: None };
+        let select_plan_item: SelectPlanItem = distinct.clone().into();
+        assert_eq!(select_plan_item, SelectPlanItem::Distinct(distinct));
+    }
+
+    #[test]
+    fn From_LockRowsPlan_for_SelectPlanItem() {
+        let lock_rows = LockRowsPlan {
+            table_name: TableName::new(None, "table".into()),
+            locking: LockingClause {
+                mode: RowLockMode::Update,
+                wait_policy: LockWaitPolicy::SkipLocked,
+            },
+            recheck: None,
+            limit: Some(1),
         };
-        let select_plan_item: SelectPlanItem = group_by.clone().into();
-        assert_eq!(select_plan_item, SelectPlanItem::Group(group_by));
+        let select_plan_item: SelectPlanItem = lock_rows.clone().into();
+        assert_eq!(select_plan_item, SelectPlanItem::LockRows(lock_rows));
     }
 
     #[test]
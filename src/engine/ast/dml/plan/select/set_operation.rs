@@ -0,0 +1,14 @@
+use crate::engine::ast::dml::parts::set_operation::SetOperator;
+use crate::engine::ast::dml::select::SelectQuery;
+
+/// 집합 연산 계획
+///
+/// 양쪽 쿼리를 각각 실행한 뒤 결합하고, 결과를 다음 단계(ORDER BY, LIMIT)에 넘깁니다.
+/// 결과 컬럼의 이름은 왼쪽 쿼리를 따릅니다.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetOperationPlan {
+    pub operator: SetOperator,
+    pub all: bool,
+    pub left: Box<SelectQuery>,
+    pub right: Box<SelectQuery>,
+}
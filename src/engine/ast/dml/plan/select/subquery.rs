@@ -1,9 +1,9 @@
-use crate::engine::ast::types::{Index, SQLExpression, TableName};
+use crate::engine::ast::dml::select::SelectQuery;
+use crate::engine::ast::types::TableName;
 
+/// FROM 절의 파생 테이블 (SELECT ... FROM (subquery) AS alias)
 #[derive(Clone, Debug, PartialEq)]
 pub struct SelectSubqueryPlan {
-    pub table_name: TableName,
-    pub select_columns: Vec<String>,
-    pub index: Option<Index>,
-    pub filter: Option<SQLExpression>,
+    pub table_name: TableName, // 파생 테이블의 alias. 결과 행의 테이블명으로 쓰입니다.
+    pub subquery: Box<SelectQuery>,
 }
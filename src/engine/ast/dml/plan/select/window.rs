@@ -0,0 +1,10 @@
+use crate::engine::ast::dml::expressions::window::WindowExpression;
+
+/// 윈도우 함수 계획
+///
+/// 필터링과 집계 뒤, 정렬과 LIMIT 앞에서 실행됩니다. 행을 줄이거나 합치지 않고,
+/// windows에 있는 윈도우 함수 표현식(SELECT 목록, ORDER BY에서 참조)의 결과를 행마다 필드로 덧붙입니다.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WindowPlan {
+    pub windows: Vec<WindowExpression>,
+}
@@ -1,4 +1,5 @@
 use crate::engine::ast::dml::plan::select::filter::FilterPlan;
+use crate::engine::ast::dml::plan::select::join::JoinPlan;
 
 use super::from::UpdateFromPlan;
 
@@ -11,6 +12,7 @@ pub struct UpdatePlan {
 pub enum UpdatePlanItem {
     UpdateFrom(UpdateFromPlan),
     Filter(FilterPlan),
+    Join(JoinPlan),
 }
 
 impl From<FilterPlan> for UpdatePlanItem {
@@ -19,6 +21,12 @@ impl From<FilterPlan> for UpdatePlanItem {
     }
 }
 
+impl From<JoinPlan> for UpdatePlanItem {
+    fn from(value: JoinPlan) -> UpdatePlanItem {
+        UpdatePlanItem::Join(value)
+    }
+}
+
 impl From<UpdateFromPlan> for UpdatePlanItem {
     fn from(value: UpdateFromPlan) -> UpdatePlanItem {
         UpdatePlanItem::UpdateFrom(value)
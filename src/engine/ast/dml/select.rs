@@ -1,27 +1,35 @@
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 
 use crate::engine::ast::{
     DMLStatement,
     ddl::drop_database::SQLStatement,
-    types::{SQLExpression, SelectColumn, TableName},
+    types::{SQLExpression, SelectColumn, TableName, quote_identifier, write_separated},
 };
+use crate::errors;
 
 use super::{
     expressions::subquery::SubqueryExpression,
     parts::{
         _where::WhereClause,
+        distinct::DistinctClause,
         from::{FromClause, FromTarget},
         group_by::{GroupByClause, GroupByItem},
         having::HavingClause,
-        join::JoinClause,
+        join::{JoinClause, JoinType},
+        locking::{LockWaitPolicy, LockingClause},
         order_by::{OrderByClause, OrderByItem},
         select_item::{SelectItem, SelectKind, SelectWildCard},
+        set_operation::{SetOperation, SetOperator},
+        with::WithClause,
     },
 };
 
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Default)]
 pub struct SelectQuery {
     pub select_items: Vec<SelectKind>,
+    pub distinct: Option<DistinctClause>,
     pub has_aggregate: bool,
     pub from_table: Option<FromClause>,
     pub join_clause: Vec<JoinClause>,
@@ -31,12 +39,16 @@ pub struct SelectQuery {
     pub having_clause: Option<HavingClause>,
     pub limit: Option<u32>,
     pub offset: Option<u32>,
+    pub set_operation: Option<SetOperation>, // UNION/INTERSECT/EXCEPT로 결합된 쿼리. 이때 SELECT 목록과 FROM 등은 비어 있습니다.
+    pub with_clause: Option<WithClause>,
+    pub locking_clause: Option<LockingClause>, // FOR UPDATE / FOR SHARE
 }
 
 impl SelectQuery {
     pub fn builder() -> Self {
         SelectQuery {
             select_items: vec![],
+            distinct: None,
             from_table: None,
             join_clause: vec![],
             where_clause: None,
@@ -46,6 +58,39 @@ impl SelectQuery {
             limit: None,
             offset: None,
             has_aggregate: false,
+            set_operation: None,
+            with_clause: None,
+            locking_clause: None,
+        }
+    }
+
+    // 두 쿼리를 집합 연산으로 결합한 쿼리를 만듭니다.
+    pub fn set_operation(
+        operator: SetOperator,
+        all: bool,
+        left: SelectQuery,
+        right: SelectQuery,
+    ) -> Self {
+        SelectQuery {
+            set_operation: Some(SetOperation {
+                operator,
+                all,
+                left: Box::new(left),
+                right: Box::new(right),
+            }),
+            ..SelectQuery::builder()
+        }
+    }
+
+    // 집합 연산을 이루는 단일 SELECT 블록 목록 (왼쪽부터). 집합 연산이 없으면 자기 자신입니다.
+    pub fn blocks(&self) -> Vec<&SelectQuery> {
+        match &self.set_operation {
+            Some(set_operation) => {
+                let mut blocks = set_operation.left.blocks();
+                blocks.append(&mut set_operation.right.blocks());
+                blocks
+            }
+            None => vec![self],
         }
     }
 
@@ -59,6 +104,22 @@ impl SelectQuery {
         self
     }
 
+    // 이미 WITH 절이 있다면 (괄호로 감싼 쿼리) 바깥 WITH 절의 테이블을 앞에 덧붙입니다.
+    pub fn set_with(mut self, mut with_clause: WithClause) -> Self {
+        if let Some(inner) = self.with_clause.take() {
+            with_clause.recursive |= inner.recursive;
+            with_clause.common_tables.extend(inner.common_tables);
+        }
+
+        self.with_clause = Some(with_clause);
+        self
+    }
+
+    pub fn set_distinct(mut self, distinct: DistinctClause) -> Self {
+        self.distinct = Some(distinct);
+        self
+    }
+
     pub fn set_from_table(mut self, from: TableName) -> Self {
         self.from_table = Some(from.into());
         self
@@ -68,6 +129,163 @@ impl SelectQuery {
         self.from_table.is_some()
     }
 
+    // 쿼리에 포함된 모든 표현식 목록을 추출합니다. (서브쿼리 내부는 제외)
+    pub fn expressions(&self) -> Vec<&SQLExpression> {
+        let select_items = self.select_items.iter().filter_map(|e| match e {
+            SelectKind::SelectItem(item) => item.item.as_ref(),
+            SelectKind::WildCard(_) => None,
+        });
+        let join_conditions = self.join_clause.iter().filter_map(|e| e.on.as_ref());
+        let where_clause = self.where_clause.iter().map(|e| &e.expression);
+        let having_clause = self.having_clause.iter().map(|e| e.expression.as_ref());
+        let order_by_items = self
+            .order_by_clause
+            .iter()
+            .flat_map(|e| e.order_by_items.iter().map(|item| &item.item));
+        let distinct_on = self.distinct.iter().flat_map(|e| e.on.iter().flatten());
+
+        select_items
+            .chain(distinct_on)
+            .chain(join_conditions)
+            .chain(where_clause)
+            .chain(having_clause)
+            .chain(order_by_items)
+            .collect()
+    }
+
+    // expressions의 수정 가능한 버전
+    pub fn expressions_mut(&mut self) -> Vec<&mut SQLExpression> {
+        let select_items = self.select_items.iter_mut().filter_map(|e| match e {
+            SelectKind::SelectItem(item) => item.item.as_mut(),
+            SelectKind::WildCard(_) => None,
+        });
+        let join_conditions = self.join_clause.iter_mut().filter_map(|e| e.on.as_mut());
+        let where_clause = self.where_clause.iter_mut().map(|e| &mut e.expression);
+        let having_clause = self.having_clause.iter_mut().map(|e| e.expression.as_mut());
+        let order_by_items = self
+            .order_by_clause
+            .iter_mut()
+            .flat_map(|e| e.order_by_items.iter_mut().map(|item| &mut item.item));
+        let distinct_on = self
+            .distinct
+            .iter_mut()
+            .flat_map(|e| e.on.iter_mut().flatten());
+
+        select_items
+            .chain(distinct_on)
+            .chain(join_conditions)
+            .chain(where_clause)
+            .chain(having_clause)
+            .chain(order_by_items)
+            .collect()
+    }
+
+    // 쿼리 자신과 그 안의 모든 서브쿼리(WITH 절, 집합 연산, FROM 절, 표현식 안)를 차례로 방문합니다.
+    pub fn visit_queries_mut(
+        &mut self,
+        visit: &mut impl FnMut(&mut SelectQuery) -> errors::Result<()>,
+    ) -> errors::Result<()> {
+        visit(self)?;
+
+        if let Some(with_clause) = &mut self.with_clause {
+            for common_table in &mut with_clause.common_tables {
+                common_table.query.visit_queries_mut(visit)?;
+            }
+        }
+
+        if let Some(set_operation) = &mut self.set_operation {
+            set_operation.left.visit_queries_mut(visit)?;
+            set_operation.right.visit_queries_mut(visit)?;
+        }
+
+        if let Some(from_clause) = &mut self.from_table
+            && let FromTarget::Subquery(subquery) = &mut from_clause.from
+        {
+            subquery.query_mut().visit_queries_mut(visit)?;
+        }
+
+        for expression in self.expressions_mut() {
+            for subquery in expression.get_subquery_list_mut() {
+                subquery.query_mut().visit_queries_mut(visit)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // 쿼리와 그 안의 모든 서브쿼리에서 테이블 참조 from을 to로 바꿉니다.
+    pub fn rename_table(&mut self, from: &TableName, to: &TableName) {
+        if let Some(with_clause) = &mut self.with_clause {
+            for common_table in &mut with_clause.common_tables {
+                common_table.query.rename_table(from, to);
+            }
+        }
+
+        if let Some(set_operation) = &mut self.set_operation {
+            set_operation.left.rename_table(from, to);
+            set_operation.right.rename_table(from, to);
+        }
+
+        if let Some(from_clause) = &mut self.from_table {
+            match &mut from_clause.from {
+                FromTarget::Table(table_name) if table_name == from => *table_name = to.clone(),
+                FromTarget::Table(_) => {}
+                FromTarget::Subquery(subquery) => subquery.query_mut().rename_table(from, to),
+            }
+        }
+
+        for join in &mut self.join_clause {
+            if &join.right == from {
+                join.right = to.clone();
+            }
+        }
+
+        for expression in self.expressions_mut() {
+            for subquery in expression.get_subquery_list_mut() {
+                subquery.query_mut().rename_table(from, to);
+            }
+        }
+    }
+
+    // 쿼리나 그 안의 서브쿼리가 테이블을 참조하는지 여부
+    pub fn references_table(&self, table_name: &TableName) -> bool {
+        let in_common_tables = self.with_clause.iter().any(|with_clause| {
+            with_clause
+                .common_tables
+                .iter()
+                .any(|common_table| common_table.query.references_table(table_name))
+        });
+
+        if in_common_tables {
+            return true;
+        }
+
+        if let Some(set_operation) = &self.set_operation {
+            return set_operation.left.references_table(table_name)
+                || set_operation.right.references_table(table_name);
+        }
+
+        let in_from = self
+            .from_table
+            .as_ref()
+            .is_some_and(|from_clause| match &from_clause.from {
+                FromTarget::Table(from) => from == table_name,
+                FromTarget::Subquery(subquery) => subquery.query().references_table(table_name),
+            });
+
+        in_from
+            || self
+                .join_clause
+                .iter()
+                .any(|join| &join.right == table_name)
+            || self.expressions().iter().any(|expression| {
+                expression
+                    .get_subquery_list()
+                    .iter()
+                    .any(|subquery| subquery.query().references_table(table_name))
+            })
+    }
+
     pub fn set_from_subquery(mut self, from: SubqueryExpression) -> Self {
         self.from_table = Some(FromClause {
             from: FromTarget::Subquery(from),
@@ -158,6 +376,11 @@ impl SelectQuery {
         self
     }
 
+    pub fn set_locking(mut self, locking_clause: LockingClause) -> Self {
+        self.locking_clause = Some(locking_clause);
+        self
+    }
+
     pub fn set_has_aggregate(mut self, has_aggregate: bool) -> Self {
         self.has_aggregate = has_aggregate;
         self
@@ -219,6 +442,147 @@ impl SelectQuery {
     }
 }
 
+impl fmt::Display for SelectQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(with_clause) = &self.with_clause {
+            write!(f, "WITH ")?;
+            if with_clause.recursive {
+                write!(f, "RECURSIVE ")?;
+            }
+            for (index, common_table) in with_clause.common_tables.iter().enumerate() {
+                if index > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{}", common_table.binding)?;
+                if !common_table.columns.is_empty() {
+                    let columns = common_table
+                        .columns
+                        .iter()
+                        .map(|column| quote_identifier(column))
+                        .collect::<Vec<_>>();
+                    write!(f, "({})", columns.join(", "))?;
+                }
+                write!(f, " AS ({})", common_table.query)?;
+            }
+            write!(f, " ")?;
+        }
+
+        if let Some(set_operation) = &self.set_operation {
+            write!(
+                f,
+                "({}) {}{} ({})",
+                set_operation.left,
+                set_operation.operator.name(),
+                if set_operation.all { " ALL" } else { "" },
+                set_operation.right
+            )?;
+        } else {
+            write!(f, "SELECT ")?;
+
+            if let Some(distinct) = &self.distinct {
+                write!(f, "DISTINCT ")?;
+                if let Some(on) = &distinct.on {
+                    write!(f, "ON (")?;
+                    write_separated(f, on, ", ")?;
+                    write!(f, ") ")?;
+                }
+            }
+
+            for (index, select_item) in self.select_items.iter().enumerate() {
+                if index > 0 {
+                    write!(f, ", ")?;
+                }
+                match select_item {
+                    SelectKind::WildCard(wildcard) => match &wildcard.alias {
+                        Some(alias) => write!(f, "{}.*", quote_identifier(alias))?,
+                        None => write!(f, "*")?,
+                    },
+                    SelectKind::SelectItem(select_item) => {
+                        if let Some(item) = &select_item.item {
+                            write!(f, "{item}")?;
+                        }
+                        if let Some(alias) = &select_item.alias {
+                            write!(f, " AS {}", quote_identifier(alias))?;
+                        }
+                    }
+                }
+            }
+
+            if let Some(from) = &self.from_table {
+                match &from.from {
+                    FromTarget::Table(table_name) => write!(f, " FROM {table_name}")?,
+                    FromTarget::Subquery(subquery) => write!(f, " FROM ({})", subquery.query())?,
+                }
+                if let Some(alias) = &from.alias {
+                    write!(f, " AS {}", quote_identifier(alias))?;
+                }
+            }
+
+            for join in &self.join_clause {
+                let join_type = match join.join_type {
+                    JoinType::InnerJoin => "INNER JOIN",
+                    JoinType::LeftOuterJoin => "LEFT JOIN",
+                    JoinType::RightOuterJoin => "RIGHT JOIN",
+                    JoinType::FullOuterJoin => "FULL JOIN",
+                    JoinType::LeftSemiJoin => "SEMI JOIN",
+                    JoinType::LeftAntiJoin => "ANTI JOIN",
+                };
+                write!(f, " {join_type} {}", join.right)?;
+                if let Some(alias) = &join.right_alias {
+                    write!(f, " AS {}", quote_identifier(alias))?;
+                }
+                if let Some(on) = &join.on {
+                    write!(f, " ON {on}")?;
+                }
+            }
+
+            if let Some(where_clause) = &self.where_clause {
+                write!(f, " WHERE {}", where_clause.expression)?;
+            }
+
+            if let Some(group_by) = &self.group_by_clause {
+                write!(f, " GROUP BY ")?;
+                if group_by.group_by_all {
+                    write!(f, "ALL")?;
+                } else {
+                    let items = group_by
+                        .group_by_items
+                        .iter()
+                        .map(|group_by_item| group_by_item.item.clone())
+                        .collect::<Vec<_>>();
+                    write_separated(f, &items, ", ")?;
+                }
+            }
+
+            if let Some(having) = &self.having_clause {
+                write!(f, " HAVING {}", having.expression)?;
+            }
+        }
+
+        if let Some(order_by) = &self.order_by_clause {
+            write!(f, " ORDER BY ")?;
+            write_separated(f, &order_by.order_by_items, ", ")?;
+        }
+        if let Some(limit) = self.limit {
+            write!(f, " LIMIT {limit}")?;
+        }
+        if let Some(offset) = self.offset {
+            write!(f, " OFFSET {offset}")?;
+        }
+
+        if let Some(locking) = &self.locking_clause {
+            write!(f, " {}", locking.mode.name())?;
+            match locking.wait_policy {
+                LockWaitPolicy::Wait => {}
+                LockWaitPolicy::NoWait => write!(f, " NOWAIT")?,
+                LockWaitPolicy::SkipLocked => write!(f, " SKIP LOCKED")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl From<SelectQuery> for SQLStatement {
     fn from(value: SelectQuery) -> SQLStatement {
         SQLStatement::DML(DMLStatement::SelectQuery(value))
@@ -260,7 +624,7 @@ mod tests {
     fn test_has_group_by() {
         let select_query = SelectQuery::builder().build();
 
-        assert_eq!(select_query.has_group_by(), false);
+        assert!(!select_query.has_group_by());
 
         let select_query = SelectQuery::builder()
             .add_group_by(GroupByItem {
@@ -271,7 +635,7 @@ mod tests {
             })
             .build();
 
-        assert_eq!(select_query.has_group_by(), true);
+        assert!(select_query.has_group_by());
     }
 
     #[test]
@@ -287,6 +651,7 @@ mod tests {
                             table_name: None,
                             column_name: "bar".into(),
                         })],
+                        distinct: false,
                     }))
                     .build(),
             )
@@ -315,6 +680,7 @@ mod tests {
                     item: Some(SQLExpression::String("a".into())),
                     alias: None
                 })],
+                distinct: None,
                 from_table: None,
                 join_clause: vec![],
                 where_clause: None,
@@ -324,6 +690,9 @@ mod tests {
                 limit: None,
                 offset: None,
                 has_aggregate: false,
+                set_operation: None,
+                with_clause: None,
+                locking_clause: None,
             }))
         );
     }
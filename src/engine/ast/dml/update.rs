@@ -1,13 +1,20 @@
 use crate::engine::ast::{DMLStatement, SQLStatement, types::TableName};
 use serde::{Deserialize, Serialize};
 
-use super::parts::{_where::WhereClause, target::UpdateTarget, update_item::UpdateItem};
+use super::parts::{
+    _where::WhereClause, returning::ReturningClause, target::UpdateTarget, update_item::UpdateItem,
+    with::WithClause,
+};
 
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Default)]
 pub struct UpdateQuery {
     pub target_table: Option<UpdateTarget>,
+    /// UPDATE ... FROM 절로 조인하는 테이블 목록
+    pub from_tables: Vec<UpdateTarget>,
     pub where_clause: Option<WhereClause>,
     pub update_items: Vec<UpdateItem>,
+    pub with_clause: Option<WithClause>,
+    pub returning: Option<ReturningClause>,
 }
 
 impl UpdateQuery {
@@ -15,7 +22,10 @@ impl UpdateQuery {
         Self {
             update_items: vec![],
             target_table: None,
+            from_tables: vec![],
             where_clause: None,
+            with_clause: None,
+            returning: None,
         }
     }
 
@@ -39,11 +49,26 @@ impl UpdateQuery {
         self
     }
 
+    pub fn add_from_table(mut self, from: UpdateTarget) -> Self {
+        self.from_tables.push(from);
+        self
+    }
+
     pub fn set_where(mut self, where_clause: WhereClause) -> Self {
         self.where_clause = Some(where_clause);
         self
     }
 
+    pub fn set_with(mut self, with_clause: WithClause) -> Self {
+        self.with_clause = Some(with_clause);
+        self
+    }
+
+    pub fn set_returning(mut self, returning: ReturningClause) -> Self {
+        self.returning = Some(returning);
+        self
+    }
+
     pub fn build(self) -> Self {
         self
     }
@@ -74,6 +99,7 @@ mod tests {
                 expression: SQLExpression::String("a".into()),
             })
             .set_target_alias("alias".into())
+            .add_from_table(TableName::new(None, "other".into()).into())
             .build();
         assert_eq!(
             update_query,
@@ -82,6 +108,10 @@ mod tests {
                     table: TableName::new(None, "table".into()),
                     alias: Some("alias".into()),
                 }),
+                from_tables: vec![UpdateTarget {
+                    table: TableName::new(None, "other".into()),
+                    alias: None,
+                }],
                 where_clause: Some(WhereClause {
                     expression: SQLExpression::String("a".into()),
                 }),
@@ -89,6 +119,8 @@ mod tests {
                     column: "a".into(),
                     value: SQLExpression::String("b".into()),
                 }],
+                with_clause: None,
+                returning: None,
             }
         );
     }
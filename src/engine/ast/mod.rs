@@ -9,9 +9,10 @@ pub mod types;
 use crate::engine::ast::{
     ddl::{
         alter_database::AlterDatabaseQuery, alter_table::AlterTableQuery,
-        create_database::CreateDatabaseQuery, create_index::CreateIndexQuery,
-        create_table::CreateTableQuery, drop_database::DropDatabaseQuery,
-        drop_index::DropIndexQuery, drop_table::DropTableQuery,
+        create_database::CreateDatabaseQuery, create_function::CreateFunctionQuery,
+        create_index::CreateIndexQuery, create_table::CreateTableQuery,
+        drop_database::DropDatabaseQuery, drop_function::DropFunctionQuery,
+        drop_index::DropIndexQuery, drop_table::DropTableQuery, truncate_table::TruncateTableQuery,
     },
     dml::{delete::DeleteQuery, insert::InsertQuery, select::SelectQuery, update::UpdateQuery},
     other::{
@@ -20,7 +21,10 @@ use crate::engine::ast::{
     },
 };
 
-use self::tcl::{BeginTransactionQuery, CommitQuery, RollbackQuery};
+use self::tcl::{
+    BeginTransactionQuery, CommitQuery, ReleaseSavepointQuery, RollbackQuery,
+    RollbackToSavepointQuery, SavepointQuery,
+};
 
 #[derive(Clone, Debug, PartialEq, Default)]
 pub enum SQLStatement {
@@ -41,8 +45,11 @@ pub enum DDLStatement {
     CreateTableQuery(CreateTableQuery),
     AlterTableQuery(AlterTableQuery),
     DropTableQuery(DropTableQuery),
+    TruncateTableQuery(TruncateTableQuery),
     CreateIndexQuery(CreateIndexQuery),
     DropIndexQuery(DropIndexQuery),
+    CreateFunctionQuery(CreateFunctionQuery),
+    DropFunctionQuery(DropFunctionQuery),
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -69,4 +76,7 @@ pub enum TCLStatement {
     BeginTransaction(BeginTransactionQuery),
     Commit(CommitQuery),
     Rollback(RollbackQuery),
+    Savepoint(SavepointQuery),
+    RollbackToSavepoint(RollbackToSavepointQuery),
+    ReleaseSavepoint(ReleaseSavepointQuery),
 }
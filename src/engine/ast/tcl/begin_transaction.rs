@@ -1,7 +1,37 @@
 use crate::engine::ast::{SQLStatement, TCLStatement};
 
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub struct BeginTransactionQuery {}
+/*
+BEGIN [TRANSACTION] [ISOLATION LEVEL { READ UNCOMMITTED | READ COMMITTED | REPEATABLE READ | SERIALIZABLE }];
+*/
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BeginTransactionQuery {
+    pub isolation_level: Option<IsolationLevel>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IsolationLevel {
+    ReadUncommitted,
+    ReadCommitted,
+    RepeatableRead,
+    Serializable,
+}
+
+impl BeginTransactionQuery {
+    pub fn builder() -> Self {
+        BeginTransactionQuery {
+            isolation_level: None,
+        }
+    }
+
+    pub fn set_isolation_level(mut self, isolation_level: IsolationLevel) -> Self {
+        self.isolation_level = Some(isolation_level);
+        self
+    }
+
+    pub fn build(self) -> SQLStatement {
+        self.into()
+    }
+}
 
 impl From<BeginTransactionQuery> for SQLStatement {
     fn from(value: BeginTransactionQuery) -> SQLStatement {
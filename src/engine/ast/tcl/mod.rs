@@ -1,7 +1,13 @@
 mod begin_transaction;
 mod commit;
+mod release_savepoint;
 mod rollback;
+mod rollback_to_savepoint;
+mod savepoint;
 
 pub use begin_transaction::*;
 pub use commit::*;
+pub use release_savepoint::*;
 pub use rollback::*;
+pub use rollback_to_savepoint::*;
+pub use savepoint::*;
@@ -0,0 +1,15 @@
+use crate::engine::ast::{SQLStatement, TCLStatement};
+
+/*
+RELEASE [SAVEPOINT] name;
+*/
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReleaseSavepointQuery {
+    pub name: String,
+}
+
+impl From<ReleaseSavepointQuery> for SQLStatement {
+    fn from(value: ReleaseSavepointQuery) -> SQLStatement {
+        SQLStatement::TCL(TCLStatement::ReleaseSavepoint(value))
+    }
+}
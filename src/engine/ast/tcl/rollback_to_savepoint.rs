@@ -0,0 +1,15 @@
+use crate::engine::ast::{SQLStatement, TCLStatement};
+
+/*
+ROLLBACK TO [SAVEPOINT] name;
+*/
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RollbackToSavepointQuery {
+    pub name: String,
+}
+
+impl From<RollbackToSavepointQuery> for SQLStatement {
+    fn from(value: RollbackToSavepointQuery) -> SQLStatement {
+        SQLStatement::TCL(TCLStatement::RollbackToSavepoint(value))
+    }
+}
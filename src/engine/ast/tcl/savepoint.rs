@@ -0,0 +1,15 @@
+use crate::engine::ast::{SQLStatement, TCLStatement};
+
+/*
+SAVEPOINT name;
+*/
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SavepointQuery {
+    pub name: String,
+}
+
+impl From<SavepointQuery> for SQLStatement {
+    fn from(value: SavepointQuery) -> SQLStatement {
+        SQLStatement::TCL(TCLStatement::Savepoint(value))
+    }
+}
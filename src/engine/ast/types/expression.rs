@@ -1,30 +1,42 @@
 use crate::engine::ast::dml::expressions::binary::BinaryOperatorExpression;
 use crate::engine::ast::dml::expressions::call::CallExpression;
+use crate::engine::ast::dml::expressions::case::CaseExpression;
+use crate::engine::ast::dml::expressions::cast::CastExpression;
+use crate::engine::ast::dml::expressions::like_escape::LikeEscapeExpression;
 use crate::engine::ast::dml::expressions::list::ListExpression;
 use crate::engine::ast::dml::expressions::not_between::NotBetweenExpression;
+use crate::engine::ast::dml::expressions::operators::UnaryOperator;
 use crate::engine::ast::dml::expressions::parentheses::ParenthesesExpression;
 use crate::engine::ast::dml::expressions::subquery::SubqueryExpression;
 use crate::engine::ast::dml::expressions::unary::UnaryOperatorExpression;
+use crate::engine::ast::dml::expressions::window::WindowExpression;
 use crate::engine::ast::dml::parts::_where::WhereClause;
 use crate::engine::{
     ast::dml::expressions::between::BetweenExpression, schema::row::TableDataFieldType,
 };
+use crate::errors;
 use crate::utils::collection::join_vec;
 
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 
 use super::select_column::SelectColumn;
 
-#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Default)]
 pub enum SQLExpression {
     // 복합 표현식
     Unary(Box<UnaryOperatorExpression>),     // 단항 연산식
     Binary(Box<BinaryOperatorExpression>),   // 2항 연산식
     Between(Box<BetweenExpression>),         // BETWEEN 식
     NotBetween(Box<NotBetweenExpression>),   // NOT BETWEEN 식
+    LikeEscape(Box<LikeEscapeExpression>),   // LIKE 패턴의 ESCAPE 절
     Parentheses(Box<ParenthesesExpression>), // 소괄호 표현식
     FunctionCall(CallExpression),            // 함수호출 표현식
-    Subquery(SubqueryExpression),            // SQL 서브쿼리 (미구현)
+    Subquery(SubqueryExpression),            // SQL 서브쿼리
+    Window(Box<WindowExpression>),           // 윈도우 함수 표현식 (OVER 절)
+    Case(Box<CaseExpression>),               // CASE WHEN ... END 식
+    Cast(Box<CastExpression>),               // CAST(... AS type), ...::type 식
 
     // 끝단 Primitive 값
     Integer(i64),
@@ -33,15 +45,10 @@ pub enum SQLExpression {
     String(String),
     List(ListExpression),
     SelectColumn(SelectColumn),
+    #[default]
     Null,
 }
 
-impl Default for SQLExpression {
-    fn default() -> Self {
-        Self::Null
-    }
-}
-
 impl SQLExpression {
     pub fn is_unary(&self) -> bool {
         #[allow(clippy::match_like_matches_macro)]
@@ -88,13 +95,27 @@ impl SQLExpression {
                 Self::get_select_column_list(&between.x),
                 Self::get_select_column_list(&between.y)
             ),
+            SQLExpression::LikeEscape(like_escape) => join_vec!(
+                Self::get_select_column_list(&like_escape.pattern),
+                Self::get_select_column_list(&like_escape.escape)
+            ),
             SQLExpression::Parentheses(paren) => Self::get_select_column_list(&paren.expression),
             SQLExpression::FunctionCall(function_call) => function_call
                 .arguments
                 .iter()
                 .flat_map(Self::get_select_column_list_recursion)
                 .collect(),
-            SQLExpression::Subquery(_subquery) => unimplemented!(),
+            // 서브쿼리의 컬럼은 서브쿼리를 실행할 때 따로 가져옵니다.
+            SQLExpression::Subquery(_subquery) => vec![],
+            SQLExpression::Window(window) => window
+                .expressions()
+                .flat_map(Self::get_select_column_list_recursion)
+                .collect(),
+            SQLExpression::Case(case) => case
+                .expressions()
+                .flat_map(Self::get_select_column_list_recursion)
+                .collect(),
+            SQLExpression::Cast(cast) => Self::get_select_column_list(&cast.expression),
         }
     }
 
@@ -121,10 +142,313 @@ impl SQLExpression {
             }
             Self::Parentheses(paren) => Self::has_aggregate_recursion(&paren.expression),
             Self::FunctionCall(call) => call.function.is_aggregate(),
+            // 윈도우 집계는 그룹을 만들지 않으므로, 인자나 PARTITION BY, ORDER BY 안의 집계만 봅니다.
+            Self::Window(window) => window.expressions().any(Self::has_aggregate_recursion),
+            Self::Case(case) => case.expressions().any(Self::has_aggregate_recursion),
+            Self::Cast(cast) => Self::has_aggregate_recursion(&cast.expression),
+            _ => false,
+        }
+    }
+
+    pub fn has_window(&self) -> bool {
+        !self.get_window_list().is_empty()
+    }
+
+    // 표현식에 포함된 윈도우 함수 표현식 목록을 추출합니다. (서브쿼리 안의 윈도우는 서브쿼리 소속이므로 제외)
+    pub fn get_window_list(&self) -> Vec<&WindowExpression> {
+        let mut windows = vec![];
+        Self::get_window_list_recursion(self, &mut windows);
+        windows
+    }
+
+    fn get_window_list_recursion<'a>(this: &'a Self, out: &mut Vec<&'a WindowExpression>) {
+        match this {
+            Self::Unary(unary) => Self::get_window_list_recursion(&unary.operand, out),
+            Self::Binary(binary) => {
+                Self::get_window_list_recursion(&binary.lhs, out);
+                Self::get_window_list_recursion(&binary.rhs, out);
+            }
+            Self::Between(between) => {
+                Self::get_window_list_recursion(&between.a, out);
+                Self::get_window_list_recursion(&between.x, out);
+                Self::get_window_list_recursion(&between.y, out);
+            }
+            Self::NotBetween(not_between) => {
+                Self::get_window_list_recursion(&not_between.a, out);
+                Self::get_window_list_recursion(&not_between.x, out);
+                Self::get_window_list_recursion(&not_between.y, out);
+            }
+            Self::LikeEscape(like_escape) => {
+                Self::get_window_list_recursion(&like_escape.pattern, out);
+                Self::get_window_list_recursion(&like_escape.escape, out);
+            }
+            Self::Parentheses(paren) => Self::get_window_list_recursion(&paren.expression, out),
+            Self::FunctionCall(call) => call
+                .arguments
+                .iter()
+                .for_each(|e| Self::get_window_list_recursion(e, out)),
+            Self::List(list) => list
+                .value
+                .iter()
+                .for_each(|e| Self::get_window_list_recursion(e, out)),
+            Self::Case(case) => case
+                .expressions()
+                .for_each(|e| Self::get_window_list_recursion(e, out)),
+            Self::Cast(cast) => Self::get_window_list_recursion(&cast.expression, out),
+            Self::Window(window) => out.push(window),
+            _ => {}
+        }
+    }
+
+    pub fn has_subquery(&self) -> bool {
+        Self::has_subquery_recursion(self)
+    }
+
+    fn has_subquery_recursion(this: &Self) -> bool {
+        match this {
+            Self::Unary(unary) => Self::has_subquery_recursion(&unary.operand),
+            Self::Binary(binary) => {
+                Self::has_subquery_recursion(&binary.lhs)
+                    | Self::has_subquery_recursion(&binary.rhs)
+            }
+            Self::Between(between) => {
+                Self::has_subquery_recursion(&between.a)
+                    | Self::has_subquery_recursion(&between.x)
+                    | Self::has_subquery_recursion(&between.y)
+            }
+            Self::NotBetween(not_between) => {
+                Self::has_subquery_recursion(&not_between.a)
+                    | Self::has_subquery_recursion(&not_between.x)
+                    | Self::has_subquery_recursion(&not_between.y)
+            }
+            Self::LikeEscape(like_escape) => {
+                Self::has_subquery_recursion(&like_escape.pattern)
+                    | Self::has_subquery_recursion(&like_escape.escape)
+            }
+            Self::Parentheses(paren) => Self::has_subquery_recursion(&paren.expression),
+            Self::FunctionCall(call) => call.arguments.iter().any(Self::has_subquery_recursion),
+            Self::Window(window) => window.expressions().any(Self::has_subquery_recursion),
+            Self::Case(case) => case.expressions().any(Self::has_subquery_recursion),
+            Self::Cast(cast) => Self::has_subquery_recursion(&cast.expression),
+            Self::List(list) => list.value.iter().any(Self::has_subquery_recursion),
+            Self::Subquery(_) => true,
             _ => false,
         }
     }
 
+    // 표현식에 직접 포함된 서브쿼리 목록을 추출합니다. (서브쿼리 내부로는 들어가지 않음)
+    pub fn get_subquery_list(&self) -> Vec<&SubqueryExpression> {
+        let mut subqueries = vec![];
+        Self::get_subquery_list_recursion(self, &mut subqueries);
+        subqueries
+    }
+
+    fn get_subquery_list_recursion<'a>(this: &'a Self, out: &mut Vec<&'a SubqueryExpression>) {
+        match this {
+            Self::Unary(unary) => Self::get_subquery_list_recursion(&unary.operand, out),
+            Self::Binary(binary) => {
+                Self::get_subquery_list_recursion(&binary.lhs, out);
+                Self::get_subquery_list_recursion(&binary.rhs, out);
+            }
+            Self::Between(between) => {
+                Self::get_subquery_list_recursion(&between.a, out);
+                Self::get_subquery_list_recursion(&between.x, out);
+                Self::get_subquery_list_recursion(&between.y, out);
+            }
+            Self::NotBetween(not_between) => {
+                Self::get_subquery_list_recursion(&not_between.a, out);
+                Self::get_subquery_list_recursion(&not_between.x, out);
+                Self::get_subquery_list_recursion(&not_between.y, out);
+            }
+            Self::LikeEscape(like_escape) => {
+                Self::get_subquery_list_recursion(&like_escape.pattern, out);
+                Self::get_subquery_list_recursion(&like_escape.escape, out);
+            }
+            Self::Parentheses(paren) => Self::get_subquery_list_recursion(&paren.expression, out),
+            Self::FunctionCall(call) => call
+                .arguments
+                .iter()
+                .for_each(|e| Self::get_subquery_list_recursion(e, out)),
+            Self::Window(window) => window
+                .expressions()
+                .for_each(|e| Self::get_subquery_list_recursion(e, out)),
+            Self::Case(case) => case
+                .expressions()
+                .for_each(|e| Self::get_subquery_list_recursion(e, out)),
+            Self::Cast(cast) => Self::get_subquery_list_recursion(&cast.expression, out),
+            Self::List(list) => list
+                .value
+                .iter()
+                .for_each(|e| Self::get_subquery_list_recursion(e, out)),
+            Self::Subquery(subquery) => out.push(subquery),
+            _ => {}
+        }
+    }
+
+    // get_subquery_list의 수정 가능한 버전
+    pub fn get_subquery_list_mut(&mut self) -> Vec<&mut SubqueryExpression> {
+        let mut subqueries = vec![];
+        Self::get_subquery_list_mut_recursion(self, &mut subqueries);
+        subqueries
+    }
+
+    fn get_subquery_list_mut_recursion<'a>(
+        this: &'a mut Self,
+        out: &mut Vec<&'a mut SubqueryExpression>,
+    ) {
+        match this {
+            Self::Unary(unary) => Self::get_subquery_list_mut_recursion(&mut unary.operand, out),
+            Self::Binary(binary) => {
+                Self::get_subquery_list_mut_recursion(&mut binary.lhs, out);
+                Self::get_subquery_list_mut_recursion(&mut binary.rhs, out);
+            }
+            Self::Between(between) => {
+                Self::get_subquery_list_mut_recursion(&mut between.a, out);
+                Self::get_subquery_list_mut_recursion(&mut between.x, out);
+                Self::get_subquery_list_mut_recursion(&mut between.y, out);
+            }
+            Self::NotBetween(not_between) => {
+                Self::get_subquery_list_mut_recursion(&mut not_between.a, out);
+                Self::get_subquery_list_mut_recursion(&mut not_between.x, out);
+                Self::get_subquery_list_mut_recursion(&mut not_between.y, out);
+            }
+            Self::LikeEscape(like_escape) => {
+                Self::get_subquery_list_mut_recursion(&mut like_escape.pattern, out);
+                Self::get_subquery_list_mut_recursion(&mut like_escape.escape, out);
+            }
+            Self::Parentheses(paren) => {
+                Self::get_subquery_list_mut_recursion(&mut paren.expression, out)
+            }
+            Self::FunctionCall(call) => call
+                .arguments
+                .iter_mut()
+                .for_each(|e| Self::get_subquery_list_mut_recursion(e, out)),
+            Self::Window(window) => window
+                .expressions_mut()
+                .for_each(|e| Self::get_subquery_list_mut_recursion(e, out)),
+            Self::Case(case) => case
+                .expressions_mut()
+                .for_each(|e| Self::get_subquery_list_mut_recursion(e, out)),
+            Self::Cast(cast) => Self::get_subquery_list_mut_recursion(&mut cast.expression, out),
+            Self::List(list) => list
+                .value
+                .iter_mut()
+                .for_each(|e| Self::get_subquery_list_mut_recursion(e, out)),
+            Self::Subquery(subquery) => out.push(subquery),
+            _ => {}
+        }
+    }
+
+    // 표현식과 모든 하위 표현식을 하위부터 방문합니다. (서브쿼리 내부는 제외)
+    // 방문 중에 표현식을 바꾸면 바뀐 표현식의 하위는 다시 방문하지 않습니다.
+    pub fn visit_mut(
+        &mut self,
+        visit: &mut impl FnMut(&mut SQLExpression) -> errors::Result<()>,
+    ) -> errors::Result<()> {
+        match self {
+            Self::Unary(unary) => unary.operand.visit_mut(visit)?,
+            Self::Binary(binary) => {
+                binary.lhs.visit_mut(visit)?;
+                binary.rhs.visit_mut(visit)?;
+            }
+            Self::Between(between) => {
+                between.a.visit_mut(visit)?;
+                between.x.visit_mut(visit)?;
+                between.y.visit_mut(visit)?;
+            }
+            Self::NotBetween(not_between) => {
+                not_between.a.visit_mut(visit)?;
+                not_between.x.visit_mut(visit)?;
+                not_between.y.visit_mut(visit)?;
+            }
+            Self::LikeEscape(like_escape) => {
+                like_escape.pattern.visit_mut(visit)?;
+                like_escape.escape.visit_mut(visit)?;
+            }
+            Self::Parentheses(paren) => paren.expression.visit_mut(visit)?,
+            Self::FunctionCall(call) => {
+                for argument in &mut call.arguments {
+                    argument.visit_mut(visit)?;
+                }
+            }
+            Self::Window(window) => {
+                for expression in window.expressions_mut() {
+                    expression.visit_mut(visit)?;
+                }
+            }
+            Self::Case(case) => {
+                for expression in case.expressions_mut() {
+                    expression.visit_mut(visit)?;
+                }
+            }
+            Self::Cast(cast) => cast.expression.visit_mut(visit)?,
+            Self::List(list) => {
+                for value in &mut list.value {
+                    value.visit_mut(visit)?;
+                }
+            }
+            Self::Subquery(_)
+            | Self::Integer(_)
+            | Self::Float(_)
+            | Self::Boolean(_)
+            | Self::String(_)
+            | Self::SelectColumn(_)
+            | Self::Null => {}
+        }
+
+        visit(self)
+    }
+
+    // 표현식이 참조하는 집계 호출 목록을 추출합니다. (서브쿼리 안의 집계는 서브쿼리 소속이므로 제외)
+    pub fn get_aggregate_list(&self) -> Vec<&CallExpression> {
+        let mut aggregates = vec![];
+        Self::get_aggregate_list_recursion(self, &mut aggregates);
+        aggregates
+    }
+
+    fn get_aggregate_list_recursion<'a>(this: &'a Self, out: &mut Vec<&'a CallExpression>) {
+        match this {
+            Self::Unary(unary) => Self::get_aggregate_list_recursion(&unary.operand, out),
+            Self::Binary(binary) => {
+                Self::get_aggregate_list_recursion(&binary.lhs, out);
+                Self::get_aggregate_list_recursion(&binary.rhs, out);
+            }
+            Self::Between(between) => {
+                Self::get_aggregate_list_recursion(&between.a, out);
+                Self::get_aggregate_list_recursion(&between.x, out);
+                Self::get_aggregate_list_recursion(&between.y, out);
+            }
+            Self::NotBetween(not_between) => {
+                Self::get_aggregate_list_recursion(&not_between.a, out);
+                Self::get_aggregate_list_recursion(&not_between.x, out);
+                Self::get_aggregate_list_recursion(&not_between.y, out);
+            }
+            Self::LikeEscape(like_escape) => {
+                Self::get_aggregate_list_recursion(&like_escape.pattern, out);
+                Self::get_aggregate_list_recursion(&like_escape.escape, out);
+            }
+            Self::Parentheses(paren) => Self::get_aggregate_list_recursion(&paren.expression, out),
+            Self::FunctionCall(call) if call.function.is_aggregate() => out.push(call),
+            Self::FunctionCall(call) => call
+                .arguments
+                .iter()
+                .for_each(|e| Self::get_aggregate_list_recursion(e, out)),
+            // 윈도우 집계 자체는 집계 연산자가 아니라 윈도우 연산자가 계산합니다.
+            Self::Window(window) => window
+                .expressions()
+                .for_each(|e| Self::get_aggregate_list_recursion(e, out)),
+            Self::Case(case) => case
+                .expressions()
+                .for_each(|e| Self::get_aggregate_list_recursion(e, out)),
+            Self::Cast(cast) => Self::get_aggregate_list_recursion(&cast.expression, out),
+            Self::List(list) => list
+                .value
+                .iter()
+                .for_each(|e| Self::get_aggregate_list_recursion(e, out)),
+            _ => {}
+        }
+    }
+
     pub fn find_non_aggregate_columns(&self) -> Vec<SelectColumn> {
         Self::find_non_aggregate_columns_recursion(self)
     }
@@ -146,6 +470,10 @@ impl SQLExpression {
                 Self::find_non_aggregate_columns_recursion(&not_between.x),
                 Self::find_non_aggregate_columns_recursion(&not_between.y)
             ),
+            Self::LikeEscape(like_escape) => join_vec!(
+                Self::find_non_aggregate_columns_recursion(&like_escape.pattern),
+                Self::find_non_aggregate_columns_recursion(&like_escape.escape)
+            ),
             Self::Parentheses(paren) => {
                 Self::find_non_aggregate_columns_recursion(&paren.expression)
             }
@@ -160,6 +488,15 @@ impl SQLExpression {
                         .collect()
                 }
             }
+            Self::Window(window) => window
+                .expressions()
+                .flat_map(Self::find_non_aggregate_columns_recursion)
+                .collect(),
+            Self::Case(case) => case
+                .expressions()
+                .flat_map(Self::find_non_aggregate_columns_recursion)
+                .collect(),
+            Self::Cast(cast) => Self::find_non_aggregate_columns_recursion(&cast.expression),
             Self::SelectColumn(column) => vec![column.to_owned()],
             _ => vec![],
         }
@@ -204,6 +541,15 @@ impl SQLExpression {
                     vec![]
                 }
             }
+            Self::Window(window) => window
+                .expressions()
+                .flat_map(|e| Self::find_aggregate_columns_recursion(e, context))
+                .collect(),
+            Self::Case(case) => case
+                .expressions()
+                .flat_map(|e| Self::find_aggregate_columns_recursion(e, context))
+                .collect(),
+            Self::Cast(cast) => Self::find_aggregate_columns_recursion(&cast.expression, context),
             Self::SelectColumn(column) => {
                 if context.in_aggregate {
                     vec![column.to_owned()]
@@ -247,6 +593,132 @@ impl From<TableDataFieldType> for SQLExpression {
     }
 }
 
+/// SQL 문장 형태로 씁니다. 같은 식이면 같은 문자열이 되고, 다른 식은 다른 문자열이 됩니다.
+/// (집계 결과 필드 이름처럼 식을 구분하는 키로 씁니다)
+impl fmt::Display for SQLExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SQLExpression::Unary(unary) => {
+                match unary.operator {
+                    UnaryOperator::Pos => write!(f, "+")?,
+                    UnaryOperator::Neg => write!(f, "-")?,
+                    UnaryOperator::Not => write!(f, "NOT ")?,
+                }
+
+                match &unary.operand {
+                    SQLExpression::Binary(_) => write!(f, "({})", unary.operand),
+                    operand => write!(f, "{operand}"),
+                }
+            }
+            SQLExpression::Binary(binary) => {
+                let precedence = binary.operator.get_precedence();
+
+                // 우선순위로 결합 순서가 드러나지 않는 피연산자는 괄호로 감쌉니다.
+                let operand =
+                    |f: &mut fmt::Formatter<'_>, operand: &SQLExpression, right| match operand {
+                        SQLExpression::Binary(inner)
+                            if inner.operator.get_precedence() < precedence
+                                || (right && inner.operator.get_precedence() == precedence) =>
+                        {
+                            write!(f, "({operand})")
+                        }
+                        _ => write!(f, "{operand}"),
+                    };
+
+                operand(f, &binary.lhs, false)?;
+                write!(f, " {} ", binary.operator.symbol())?;
+                operand(f, &binary.rhs, true)
+            }
+            SQLExpression::Between(between) => {
+                write!(f, "{} BETWEEN {} AND {}", between.a, between.x, between.y)
+            }
+            SQLExpression::NotBetween(between) => {
+                write!(
+                    f,
+                    "{} NOT BETWEEN {} AND {}",
+                    between.a, between.x, between.y
+                )
+            }
+            SQLExpression::LikeEscape(like) => write!(f, "{} ESCAPE {}", like.pattern, like.escape),
+            SQLExpression::Parentheses(paren) => write!(f, "({})", paren.expression),
+            SQLExpression::FunctionCall(call) => write!(f, "{call}"),
+            SQLExpression::Subquery(SubqueryExpression::Select(query)) => write!(f, "({query})"),
+            SQLExpression::Subquery(SubqueryExpression::Exists(query)) => {
+                write!(f, "EXISTS ({query})")
+            }
+            SQLExpression::Window(window) => write!(f, "{window}"),
+            SQLExpression::Case(case) => {
+                write!(f, "CASE")?;
+                if let Some(operand) = &case.operand {
+                    write!(f, " {operand}")?;
+                }
+                for branch in &case.branches {
+                    write!(f, " WHEN {} THEN {}", branch.condition, branch.result)?;
+                }
+                if let Some(else_result) = &case.else_result {
+                    write!(f, " ELSE {else_result}")?;
+                }
+                write!(f, " END")
+            }
+            SQLExpression::Cast(cast) => write!(
+                f,
+                "CAST({} AS {})",
+                cast.expression,
+                String::from(cast.data_type.clone())
+            ),
+            SQLExpression::Integer(value) => write!(f, "{value}"),
+            // 정수와 구분되도록 소수점을 남깁니다.
+            SQLExpression::Float(value) if value.is_finite() && value.fract() == 0.0 => {
+                write!(f, "{value:.1}")
+            }
+            SQLExpression::Float(value) => write!(f, "{value}"),
+            SQLExpression::Boolean(true) => write!(f, "TRUE"),
+            SQLExpression::Boolean(false) => write!(f, "FALSE"),
+            SQLExpression::String(value) => write!(f, "'{}'", value.replace('\'', "''")),
+            SQLExpression::List(list) => {
+                write!(f, "(")?;
+                write_separated(f, &list.value, ", ")?;
+                write!(f, ")")
+            }
+            SQLExpression::SelectColumn(column) => write!(f, "{column}"),
+            SQLExpression::Null => write!(f, "NULL"),
+        }
+    }
+}
+
+/// 목록을 구분자로 이어서 씁니다.
+pub(crate) fn write_separated<T: fmt::Display>(
+    f: &mut fmt::Formatter<'_>,
+    items: &[T],
+    separator: &str,
+) -> fmt::Result {
+    for (index, item) in items.iter().enumerate() {
+        if index > 0 {
+            write!(f, "{separator}")?;
+        }
+        write!(f, "{item}")?;
+    }
+
+    Ok(())
+}
+
+/// 소문자 식별자가 아니면 큰따옴표로 감쌉니다.
+pub(crate) fn quote_identifier(identifier: &str) -> String {
+    let plain = identifier
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_lowercase() || c == '_')
+        && identifier
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+
+    if plain {
+        identifier.to_string()
+    } else {
+        format!("\"{}\"", identifier.replace('"', "\"\""))
+    }
+}
+
 #[derive(Debug, Clone, Default, Copy)]
 struct RecursionContext {
     pub in_aggregate: bool,
@@ -264,8 +736,10 @@ mod tests {
                 not_between::NotBetweenExpression,
                 operators::{BinaryOperator, UnaryOperator},
                 parentheses::ParenthesesExpression,
+                subquery::SubqueryExpression,
                 unary::UnaryOperatorExpression,
             },
+            dml::{parts::select_item::SelectItem, select::SelectQuery},
             types::{
                 AggregateFunction, ConditionalFunction, Function, SQLExpression, SelectColumn,
             },
@@ -366,6 +840,7 @@ mod tests {
                         None,
                         "id".into(),
                     ))],
+                    distinct: false,
                 }),
                 expected: vec![SelectColumn::new(None, "id".into())],
             },
@@ -410,6 +885,7 @@ mod tests {
                     operand: SQLExpression::FunctionCall(CallExpression {
                         function: Function::BuiltIn(AggregateFunction::Count.into()),
                         arguments: vec![SQLExpression::Integer(1)],
+                        distinct: false,
                     }),
                 })),
                 expected: true,
@@ -429,6 +905,7 @@ mod tests {
                     lhs: SQLExpression::FunctionCall(CallExpression {
                         function: Function::BuiltIn(AggregateFunction::Count.into()),
                         arguments: vec![SQLExpression::Integer(1)],
+                        distinct: false,
                     }),
                     rhs: SQLExpression::Integer(2),
                     operator: BinaryOperator::Add,
@@ -450,6 +927,7 @@ mod tests {
                     a: SQLExpression::FunctionCall(CallExpression {
                         function: Function::BuiltIn(AggregateFunction::Count.into()),
                         arguments: vec![SQLExpression::Integer(1)],
+                        distinct: false,
                     }),
                     x: SQLExpression::Integer(2),
                     y: SQLExpression::Integer(3),
@@ -471,6 +949,7 @@ mod tests {
                     a: SQLExpression::FunctionCall(CallExpression {
                         function: Function::BuiltIn(AggregateFunction::Count.into()),
                         arguments: vec![SQLExpression::Integer(1)],
+                        distinct: false,
                     }),
                     x: SQLExpression::Integer(2),
                     y: SQLExpression::Integer(3),
@@ -490,6 +969,7 @@ mod tests {
                     expression: SQLExpression::FunctionCall(CallExpression {
                         function: Function::BuiltIn(AggregateFunction::Count.into()),
                         arguments: vec![SQLExpression::Integer(1)],
+                        distinct: false,
                     }),
                 })),
                 expected: true,
@@ -538,6 +1018,7 @@ mod tests {
                             None,
                             "id".into(),
                         ))],
+                        distinct: false,
                     }),
                 })),
                 expected: vec![SelectColumn::new(None, "id".into())],
@@ -560,6 +1041,7 @@ mod tests {
                             None,
                             "id".into(),
                         ))],
+                        distinct: false,
                     }),
                     rhs: SQLExpression::SelectColumn(SelectColumn::new(None, "name".into())),
                     operator: BinaryOperator::Add,
@@ -584,6 +1066,7 @@ mod tests {
                             None,
                             "id".into(),
                         ))],
+                        distinct: false,
                     }),
                     x: SQLExpression::Integer(1),
                     y: SQLExpression::Integer(10),
@@ -608,6 +1091,7 @@ mod tests {
                             None,
                             "id".into(),
                         ))],
+                        distinct: false,
                     }),
                     x: SQLExpression::Integer(1),
                     y: SQLExpression::Integer(10),
@@ -630,6 +1114,7 @@ mod tests {
                             None,
                             "id".into(),
                         ))],
+                        distinct: false,
                     }),
                 })),
                 expected: vec![SelectColumn::new(None, "id".into())],
@@ -642,6 +1127,7 @@ mod tests {
                         None,
                         "id".into(),
                     ))],
+                    distinct: false,
                 }),
                 expected: vec![],
             },
@@ -764,6 +1250,7 @@ mod tests {
                             None,
                             "id".into(),
                         ))],
+                        distinct: false,
                     }),
                 })),
                 expected: vec![],
@@ -789,6 +1276,7 @@ mod tests {
                             None,
                             "id".into(),
                         ))],
+                        distinct: false,
                     }),
                     rhs: SQLExpression::SelectColumn(SelectColumn::new(None, "name".into())),
                     operator: BinaryOperator::Add,
@@ -813,6 +1301,7 @@ mod tests {
                             None,
                             "id".into(),
                         ))],
+                        distinct: false,
                     }),
                     x: SQLExpression::Integer(1),
                     y: SQLExpression::Integer(10),
@@ -837,6 +1326,7 @@ mod tests {
                             None,
                             "id".into(),
                         ))],
+                        distinct: false,
                     }),
                     x: SQLExpression::Integer(1),
                     y: SQLExpression::Integer(10),
@@ -859,6 +1349,7 @@ mod tests {
                             None,
                             "id".into(),
                         ))],
+                        distinct: false,
                     }),
                 })),
                 expected: vec![],
@@ -871,6 +1362,7 @@ mod tests {
                         None,
                         "id".into(),
                     ))],
+                    distinct: false,
                 }),
                 expected: vec![SelectColumn::new(None, "id".into())],
             },
@@ -885,4 +1377,97 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_SQLExpression_get_aggregate_list() {
+        let sum = CallExpression {
+            function: Function::BuiltIn(AggregateFunction::Sum.into()),
+            arguments: vec![SQLExpression::SelectColumn(SelectColumn::new(
+                None,
+                "total".into(),
+            ))],
+            distinct: false,
+        };
+        let count = CallExpression {
+            function: Function::BuiltIn(AggregateFunction::Count.into()),
+            arguments: vec![],
+            distinct: false,
+        };
+
+        // coalesce(sum(total), 0) + count(*) + (select count(*))
+        let expression = SQLExpression::Binary(Box::new(BinaryOperatorExpression {
+            operator: BinaryOperator::Add,
+            lhs: SQLExpression::Binary(Box::new(BinaryOperatorExpression {
+                operator: BinaryOperator::Add,
+                lhs: SQLExpression::FunctionCall(CallExpression {
+                    function: Function::BuiltIn(ConditionalFunction::Coalesce.into()),
+                    arguments: vec![
+                        SQLExpression::FunctionCall(sum.clone()),
+                        SQLExpression::Integer(0),
+                    ],
+                    distinct: false,
+                }),
+                rhs: SQLExpression::FunctionCall(count.clone()),
+            })),
+            rhs: SQLExpression::Subquery(SubqueryExpression::Select(Box::new(
+                SelectQuery::builder()
+                    .add_select_item(
+                        SelectItem::builder()
+                            .set_item(SQLExpression::FunctionCall(count.clone()))
+                            .build(),
+                    )
+                    .build(),
+            ))),
+        }));
+
+        assert_eq!(expression.get_aggregate_list(), vec![&sum, &count]);
+    }
+
+    #[test]
+    fn test_display_as_sql() {
+        use crate::engine::parser::predule::{Parser, ParserContext};
+
+        let parse = |sql: &str| {
+            let mut parser = Parser::with_string(format!("select {sql} from t;")).unwrap();
+            let statement = parser.parse(ParserContext::default()).unwrap().remove(0);
+            let crate::engine::ast::SQLStatement::DML(
+                crate::engine::ast::DMLStatement::SelectQuery(query),
+            ) = statement
+            else {
+                panic!("expected select");
+            };
+            let crate::engine::ast::dml::parts::select_item::SelectKind::SelectItem(item) =
+                query.select_items[0].clone()
+            else {
+                panic!("expected select item");
+            };
+            item.item.unwrap()
+        };
+
+        for (sql, expected) in [
+            ("count(*)", "count(*)"),
+            ("sum(DISTINCT o.total + 1)", "sum(DISTINCT o.total + 1)"),
+            ("avg(1.0)", "avg(1.0)"),
+            ("-x * 2.5", "-x * 2.5"),
+            ("(a - b) - (c - d)", "(a - b) - (c - d)"),
+            ("'it''s' = \"Name\"", "'it''s' = \"Name\""),
+            (
+                "case when a is null then 0 else cast(a as integer) end",
+                "CASE WHEN a IS NULL THEN 0 ELSE CAST(a AS integer) END",
+            ),
+            (
+                "max((select u.id from users u where u.id = t.id))",
+                "max((SELECT u.id FROM users AS u WHERE u.id = t.id))",
+            ),
+        ] {
+            assert_eq!(parse(sql).to_string(), expected, "{sql}");
+        }
+
+        // 정수와 실수, 서로 다른 서브쿼리는 다른 문자열이 됩니다.
+        assert_ne!(parse("sum(1)").to_string(), parse("sum(1.0)").to_string());
+        assert_ne!(
+            parse("sum((select 1 from u))").to_string(),
+            parse("sum((select 2 from u))").to_string()
+        );
+    }
 }
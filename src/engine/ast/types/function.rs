@@ -1,3 +1,6 @@
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
@@ -11,17 +14,33 @@ impl Function {
         match self {
             Self::BuiltIn(built_in) => match built_in {
                 BuiltInFunction::Aggregate(_) => true,
-                BuiltInFunction::Conditional(_) => false,
+                BuiltInFunction::Conditional(_)
+                | BuiltInFunction::Scalar(_)
+                | BuiltInFunction::Window(_) => false,
             },
             Self::UserDefined(_) => false,
         }
     }
+
+    // OVER 절과 함께만 쓸 수 있는 윈도우 전용 함수인지 여부
+    pub fn is_window(&self) -> bool {
+        matches!(self, Self::BuiltIn(BuiltInFunction::Window(_)))
+    }
+
+    pub fn as_aggregate(&self) -> Option<AggregateFunction> {
+        match self {
+            Self::BuiltIn(BuiltInFunction::Aggregate(aggregate)) => Some(aggregate.clone()),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
 pub enum BuiltInFunction {
     Aggregate(AggregateFunction),
     Conditional(ConditionalFunction),
+    Scalar(ScalarFunction),
+    Window(WindowFunction),
 }
 
 impl From<BuiltInFunction> for Function {
@@ -42,6 +61,12 @@ pub enum AggregateFunction {
     Every,
     ArrayAgg,
     StringAgg,
+    BoolAnd,
+    BoolOr,
+    Stddev,    // 표본 표준편차 (STDDEV, STDDEV_SAMP)
+    StddevPop, // 모 표준편차
+    Variance,  // 표본 분산 (VARIANCE, VAR_SAMP)
+    VarPop,    // 모 분산
 }
 
 impl From<AggregateFunction> for BuiltInFunction {
@@ -56,30 +81,168 @@ impl From<AggregateFunction> for Function {
     }
 }
 
+// 내장 함수 이름표 (대문자 이름, 함수)
+// 별칭은 같은 함수를 다른 이름으로 한 번 더 등록합니다.
+const BUILT_IN_FUNCTIONS: &[(&str, BuiltInFunction)] = &[
+    ("SUM", BuiltInFunction::Aggregate(AggregateFunction::Sum)),
+    (
+        "COUNT",
+        BuiltInFunction::Aggregate(AggregateFunction::Count),
+    ),
+    ("MAX", BuiltInFunction::Aggregate(AggregateFunction::Max)),
+    ("MIN", BuiltInFunction::Aggregate(AggregateFunction::Min)),
+    ("AVG", BuiltInFunction::Aggregate(AggregateFunction::Avg)),
+    (
+        "EVERY",
+        BuiltInFunction::Aggregate(AggregateFunction::Every),
+    ),
+    (
+        "ARRAYAGG",
+        BuiltInFunction::Aggregate(AggregateFunction::ArrayAgg),
+    ),
+    (
+        "ARRAY_AGG",
+        BuiltInFunction::Aggregate(AggregateFunction::ArrayAgg),
+    ),
+    (
+        "STRINGAGG",
+        BuiltInFunction::Aggregate(AggregateFunction::StringAgg),
+    ),
+    (
+        "STRING_AGG",
+        BuiltInFunction::Aggregate(AggregateFunction::StringAgg),
+    ),
+    (
+        "BOOL_AND",
+        BuiltInFunction::Aggregate(AggregateFunction::BoolAnd),
+    ),
+    (
+        "BOOL_OR",
+        BuiltInFunction::Aggregate(AggregateFunction::BoolOr),
+    ),
+    (
+        "STDDEV",
+        BuiltInFunction::Aggregate(AggregateFunction::Stddev),
+    ),
+    (
+        "STDDEV_SAMP",
+        BuiltInFunction::Aggregate(AggregateFunction::Stddev),
+    ),
+    (
+        "STDDEV_POP",
+        BuiltInFunction::Aggregate(AggregateFunction::StddevPop),
+    ),
+    (
+        "VARIANCE",
+        BuiltInFunction::Aggregate(AggregateFunction::Variance),
+    ),
+    (
+        "VAR_SAMP",
+        BuiltInFunction::Aggregate(AggregateFunction::Variance),
+    ),
+    (
+        "VAR_POP",
+        BuiltInFunction::Aggregate(AggregateFunction::VarPop),
+    ),
+    (
+        "NULLIF",
+        BuiltInFunction::Conditional(ConditionalFunction::NullIf),
+    ),
+    (
+        "COALESCE",
+        BuiltInFunction::Conditional(ConditionalFunction::Coalesce),
+    ),
+    (
+        "GREATEST",
+        BuiltInFunction::Conditional(ConditionalFunction::Greatest),
+    ),
+    (
+        "LEAST",
+        BuiltInFunction::Conditional(ConditionalFunction::Least),
+    ),
+    ("LOWER", BuiltInFunction::Scalar(ScalarFunction::Lower)),
+    ("UPPER", BuiltInFunction::Scalar(ScalarFunction::Upper)),
+    ("LENGTH", BuiltInFunction::Scalar(ScalarFunction::Length)),
+    (
+        "CHAR_LENGTH",
+        BuiltInFunction::Scalar(ScalarFunction::Length),
+    ),
+    (
+        "SUBSTRING",
+        BuiltInFunction::Scalar(ScalarFunction::Substring),
+    ),
+    ("SUBSTR", BuiltInFunction::Scalar(ScalarFunction::Substring)),
+    ("TRIM", BuiltInFunction::Scalar(ScalarFunction::Trim)),
+    ("BTRIM", BuiltInFunction::Scalar(ScalarFunction::Trim)),
+    ("REPLACE", BuiltInFunction::Scalar(ScalarFunction::Replace)),
+    ("CONCAT", BuiltInFunction::Scalar(ScalarFunction::Concat)),
+    (
+        "POSITION",
+        BuiltInFunction::Scalar(ScalarFunction::Position),
+    ),
+    (
+        "SPLIT_PART",
+        BuiltInFunction::Scalar(ScalarFunction::SplitPart),
+    ),
+    ("ABS", BuiltInFunction::Scalar(ScalarFunction::Abs)),
+    ("ROUND", BuiltInFunction::Scalar(ScalarFunction::Round)),
+    ("FLOOR", BuiltInFunction::Scalar(ScalarFunction::Floor)),
+    ("CEIL", BuiltInFunction::Scalar(ScalarFunction::Ceil)),
+    ("CEILING", BuiltInFunction::Scalar(ScalarFunction::Ceil)),
+    ("MOD", BuiltInFunction::Scalar(ScalarFunction::Mod)),
+    ("POWER", BuiltInFunction::Scalar(ScalarFunction::Power)),
+    ("POW", BuiltInFunction::Scalar(ScalarFunction::Power)),
+    ("SQRT", BuiltInFunction::Scalar(ScalarFunction::Sqrt)),
+    ("RANDOM", BuiltInFunction::Scalar(ScalarFunction::Random)),
+    (
+        "ROW_NUMBER",
+        BuiltInFunction::Window(WindowFunction::RowNumber),
+    ),
+    ("RANK", BuiltInFunction::Window(WindowFunction::Rank)),
+    (
+        "DENSE_RANK",
+        BuiltInFunction::Window(WindowFunction::DenseRank),
+    ),
+    ("LAG", BuiltInFunction::Window(WindowFunction::Lag)),
+    ("LEAD", BuiltInFunction::Window(WindowFunction::Lead)),
+    (
+        "FIRST_VALUE",
+        BuiltInFunction::Window(WindowFunction::FirstValue),
+    ),
+    (
+        "LAST_VALUE",
+        BuiltInFunction::Window(WindowFunction::LastValue),
+    ),
+    ("NTILE", BuiltInFunction::Window(WindowFunction::Ntile)),
+];
+
+static BUILT_IN_FUNCTION_REGISTRY: LazyLock<HashMap<&'static str, &'static BuiltInFunction>> =
+    LazyLock::new(|| {
+        BUILT_IN_FUNCTIONS
+            .iter()
+            .map(|(name, function)| (*name, function))
+            .collect()
+    });
+
+impl BuiltInFunction {
+    // 이름으로 내장 함수를 찾습니다. (대소문자 무시)
+    pub fn lookup(function_name: &str) -> Option<BuiltInFunction> {
+        BUILT_IN_FUNCTION_REGISTRY
+            .get(function_name.to_uppercase().as_str())
+            .map(|function| (*function).clone())
+    }
+}
+
 impl TryFrom<String> for BuiltInFunction {
     type Error = ();
 
     fn try_from(function_name: String) -> Result<BuiltInFunction, Self::Error> {
-        match function_name.to_uppercase().as_str() {
-            "SUM" => Ok(AggregateFunction::Sum.into()),
-            "COUNT" => Ok(AggregateFunction::Count.into()),
-            "MAX" => Ok(AggregateFunction::Max.into()),
-            "MIN" => Ok(AggregateFunction::Min.into()),
-            "AVG" => Ok(AggregateFunction::Avg.into()),
-            "EVERY" => Ok(AggregateFunction::Every.into()),
-            "ARRAYAGG" => Ok(AggregateFunction::ArrayAgg.into()),
-            "STRINGAGG" => Ok(AggregateFunction::StringAgg.into()),
-            "NULLIF" => Ok(ConditionalFunction::NullIf.into()),
-            "COALESCE" => Ok(ConditionalFunction::Coalesce.into()),
-            "GREATEST" => Ok(ConditionalFunction::Greatest.into()),
-            "LEAST" => Ok(ConditionalFunction::Least.into()),
-            _ => Err(()),
-        }
+        BuiltInFunction::lookup(&function_name).ok_or(())
     }
 }
 
-// 집합 함수
-// 참고 https://www.postgresql.org/docs/9.5/functions-aggregate.html
+// 조건 함수
+// 참고 https://www.postgresql.org/docs/current/functions-conditional.html
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
 pub enum ConditionalFunction {
     NullIf,
@@ -100,8 +263,71 @@ impl From<ConditionalFunction> for Function {
     }
 }
 
-// 함수명을 가리키는 값입니다.
+// 스칼라 함수 (문자열, 수학)
+// 참고 https://www.postgresql.org/docs/current/functions-string.html
+// 참고 https://www.postgresql.org/docs/current/functions-math.html
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub enum ScalarFunction {
+    Lower,
+    Upper,
+    Length,
+    Substring,
+    Trim,
+    Replace,
+    Concat,
+    Position,
+    SplitPart,
+    Abs,
+    Round,
+    Floor,
+    Ceil,
+    Mod,
+    Power,
+    Sqrt,
+    Random,
+}
+
+impl From<ScalarFunction> for BuiltInFunction {
+    fn from(value: ScalarFunction) -> BuiltInFunction {
+        BuiltInFunction::Scalar(value)
+    }
+}
+
+impl From<ScalarFunction> for Function {
+    fn from(value: ScalarFunction) -> Function {
+        BuiltInFunction::Scalar(value).into()
+    }
+}
+
+// 윈도우 함수
+// 집계 함수도 OVER 절과 함께 쓰면 윈도우 함수로 동작합니다.
+// 참고 https://www.postgresql.org/docs/current/functions-window.html
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub enum WindowFunction {
+    RowNumber,
+    Rank,
+    DenseRank,
+    Lag,
+    Lead,
+    FirstValue,
+    LastValue,
+    Ntile,
+}
+
+impl From<WindowFunction> for BuiltInFunction {
+    fn from(value: WindowFunction) -> BuiltInFunction {
+        BuiltInFunction::Window(value)
+    }
+}
+
+impl From<WindowFunction> for Function {
+    fn from(value: WindowFunction) -> Function {
+        BuiltInFunction::Window(value).into()
+    }
+}
+
+// 함수명을 가리키는 값입니다.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct UserDefinedFunction {
     pub database_name: Option<String>,
     pub function_name: String,
@@ -121,67 +347,58 @@ mod tests {
 
     #[test]
     fn test_function_is_aggregate() {
-        assert_eq!(
-            Function::BuiltIn(BuiltInFunction::Aggregate(AggregateFunction::Sum)).is_aggregate(),
-            true
+        assert!(
+            Function::BuiltIn(BuiltInFunction::Aggregate(AggregateFunction::Sum)).is_aggregate()
         );
-        assert_eq!(
-            Function::BuiltIn(BuiltInFunction::Aggregate(AggregateFunction::Count)).is_aggregate(),
-            true
+        assert!(
+            Function::BuiltIn(BuiltInFunction::Aggregate(AggregateFunction::Count)).is_aggregate()
         );
-        assert_eq!(
-            Function::BuiltIn(BuiltInFunction::Aggregate(AggregateFunction::Max)).is_aggregate(),
-            true
+        assert!(
+            Function::BuiltIn(BuiltInFunction::Aggregate(AggregateFunction::Max)).is_aggregate()
         );
-        assert_eq!(
-            Function::BuiltIn(BuiltInFunction::Aggregate(AggregateFunction::Min)).is_aggregate(),
-            true
+        assert!(
+            Function::BuiltIn(BuiltInFunction::Aggregate(AggregateFunction::Min)).is_aggregate()
         );
-        assert_eq!(
-            Function::BuiltIn(BuiltInFunction::Aggregate(AggregateFunction::Avg)).is_aggregate(),
-            true
+        assert!(
+            Function::BuiltIn(BuiltInFunction::Aggregate(AggregateFunction::Avg)).is_aggregate()
         );
-        assert_eq!(
-            Function::BuiltIn(BuiltInFunction::Aggregate(AggregateFunction::Every)).is_aggregate(),
-            true
+        assert!(
+            Function::BuiltIn(BuiltInFunction::Aggregate(AggregateFunction::Every)).is_aggregate()
         );
-        assert_eq!(
+        assert!(
             Function::BuiltIn(BuiltInFunction::Aggregate(AggregateFunction::ArrayAgg))
-                .is_aggregate(),
-            true
+                .is_aggregate()
         );
-        assert_eq!(
+        assert!(
             Function::BuiltIn(BuiltInFunction::Aggregate(AggregateFunction::StringAgg))
-                .is_aggregate(),
-            true
+                .is_aggregate()
         );
-        assert_eq!(
-            Function::BuiltIn(BuiltInFunction::Conditional(ConditionalFunction::NullIf))
-                .is_aggregate(),
-            false
+        assert!(
+            !Function::BuiltIn(BuiltInFunction::Conditional(ConditionalFunction::NullIf))
+                .is_aggregate()
         );
-        assert_eq!(
-            Function::BuiltIn(BuiltInFunction::Conditional(ConditionalFunction::Coalesce))
-                .is_aggregate(),
-            false
+        assert!(
+            !Function::BuiltIn(BuiltInFunction::Conditional(ConditionalFunction::Coalesce))
+                .is_aggregate()
         );
-        assert_eq!(
-            Function::BuiltIn(BuiltInFunction::Conditional(ConditionalFunction::Greatest))
-                .is_aggregate(),
-            false
+        assert!(
+            !Function::BuiltIn(BuiltInFunction::Conditional(ConditionalFunction::Greatest))
+                .is_aggregate()
         );
-        assert_eq!(
-            Function::BuiltIn(BuiltInFunction::Conditional(ConditionalFunction::Least))
-                .is_aggregate(),
-            false
+        assert!(
+            !Function::BuiltIn(BuiltInFunction::Conditional(ConditionalFunction::Least))
+                .is_aggregate()
         );
-        assert_eq!(
-            Function::UserDefined(UserDefinedFunction {
+        assert!(!Function::BuiltIn(BuiltInFunction::Scalar(ScalarFunction::Lower)).is_aggregate());
+        assert!(
+            !Function::BuiltIn(BuiltInFunction::Window(WindowFunction::RowNumber)).is_aggregate()
+        );
+        assert!(
+            !Function::UserDefined(UserDefinedFunction {
                 database_name: None,
                 function_name: "my_function".into()
             })
-            .is_aggregate(),
-            false
+            .is_aggregate()
         );
     }
 
@@ -221,4 +438,29 @@ mod tests {
             Function::BuiltIn(BuiltInFunction::Aggregate(AggregateFunction::StringAgg))
         );
     }
+
+    #[test]
+    fn test_built_in_function_lookup() {
+        assert_eq!(
+            BuiltInFunction::lookup("lower"),
+            Some(ScalarFunction::Lower.into())
+        );
+        assert_eq!(
+            BuiltInFunction::lookup("Ceiling"),
+            Some(ScalarFunction::Ceil.into())
+        );
+        assert_eq!(
+            BuiltInFunction::lookup("stddev_samp"),
+            Some(AggregateFunction::Stddev.into())
+        );
+        assert_eq!(
+            BuiltInFunction::lookup("coalesce"),
+            Some(ConditionalFunction::Coalesce.into())
+        );
+        assert_eq!(BuiltInFunction::lookup("my_function"), None);
+        assert_eq!(
+            BuiltInFunction::try_from("NTILE".to_owned()),
+            Ok(WindowFunction::Ntile.into())
+        );
+    }
 }
@@ -1,4 +1,6 @@
-use crate::engine::ast::types::SQLExpression;
+use std::fmt;
+
+use crate::engine::ast::types::{SQLExpression, quote_identifier};
 use serde::{Deserialize, Serialize};
 
 // [table_alias.]column_name
@@ -18,6 +20,15 @@ impl SelectColumn {
     }
 }
 
+impl fmt::Display for SelectColumn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(table_name) = &self.table_name {
+            write!(f, "{}.", quote_identifier(table_name))?;
+        }
+        write!(f, "{}", quote_identifier(&self.column_name))
+    }
+}
+
 impl From<SelectColumn> for SQLExpression {
     fn from(value: SelectColumn) -> SQLExpression {
         SQLExpression::SelectColumn(value)
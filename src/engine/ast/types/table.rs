@@ -1,6 +1,10 @@
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 
 use crate::engine::ast::dml::parts::from::{FromClause, FromTarget};
+use crate::engine::ast::dml::parts::with::COMMON_TABLE_DATABASE;
+use crate::engine::ast::types::quote_identifier;
 
 // [database_name.]table_name
 // 테이블명을 가리키는 값입니다.
@@ -17,6 +21,20 @@ impl TableName {
             table_name,
         }
     }
+
+    // WITH 절 테이블을 가리키는 이름인지 여부
+    pub fn is_common_table(&self) -> bool {
+        self.database_name.as_deref() == Some(COMMON_TABLE_DATABASE)
+    }
+}
+
+impl fmt::Display for TableName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(database_name) = &self.database_name {
+            write!(f, "{}.", quote_identifier(database_name))?;
+        }
+        write!(f, "{}", quote_identifier(&self.table_name))
+    }
 }
 
 impl From<TableName> for FromClause {
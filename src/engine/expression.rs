@@ -4,10 +4,18 @@ use futures::future::join_all;
 use itertools::Itertools;
 
 use crate::engine::DBEngine;
+use crate::engine::actions::dml::aggregate::find_aggregate_value;
+use crate::engine::actions::dml::function::check_argument_count;
+use crate::engine::actions::dml::window::find_window_value;
 use crate::engine::ast::dml::expressions::binary::BinaryOperatorExpression;
+use crate::engine::ast::dml::expressions::case::CaseExpression;
+use crate::engine::ast::dml::expressions::list::ListExpression;
 use crate::engine::ast::dml::expressions::operators::{BinaryOperator, UnaryOperator};
-use crate::engine::ast::types::function::{AggregateFunction, BuiltInFunction, Function};
-use crate::engine::ast::types::{Column, SQLExpression, TableName};
+use crate::engine::ast::dml::expressions::subquery::SubqueryExpression;
+use crate::engine::ast::dml::parts::select_item::SelectKind;
+use crate::engine::ast::types::function::{BuiltInFunction, ConditionalFunction, Function};
+use crate::engine::ast::types::{Column, SQLExpression, SelectColumn, TableName};
+use crate::engine::pattern;
 use crate::engine::schema::row::TableDataFieldType;
 use crate::engine::schema::row::TableDataRow;
 use crate::engine::types::ExecuteColumnType;
@@ -20,7 +28,8 @@ pub struct ReduceContext {
     pub table_alias_map: HashMap<String, TableName>,
     pub row: Option<TableDataRow>,
     pub config_columns: Vec<(TableName, Column)>,
-    pub total_count: usize,
+    /// 상관 서브쿼리를 평가할 때 바깥 쿼리의 컨텍스트
+    pub outer: Option<Box<ReduceContext>>,
 }
 
 impl DBEngine {
@@ -110,22 +119,29 @@ impl DBEngine {
                         TableDataFieldType::Boolean(value) => {
                             Ok(TableDataFieldType::Boolean(!value))
                         }
+                        TableDataFieldType::Null => Ok(TableDataFieldType::Null),
                         _ => Err(TypeError::wrap(
-                            "unary '!' operator is valid only for integer and float types.",
+                            "unary '!' operator is valid only for boolean type.",
                         )),
                     }
                 }
             },
             SQLExpression::Binary(binary) => {
+                // IN의 우변은 값 하나가 아니라 목록 또는 서브쿼리입니다.
+                if matches!(binary.operator, BinaryOperator::In | BinaryOperator::NotIn) {
+                    return Box::pin(self.reduce_in_expression(*binary, context)).await;
+                }
+
                 let lhs =
                     Box::pin(self.reduce_expression(binary.lhs.clone(), context.clone())).await?;
                 let rhs =
                     Box::pin(self.reduce_expression(binary.rhs.clone(), context.clone())).await?;
 
-                if lhs.type_code() != rhs.type_code() {
-                    return Err(TypeError::wrap(
-                        "The types of lhs and rhs do not match.".to_string(),
-                    ));
+                // LIKE 계열은 NULL 전파 후 문자열끼리만 비교합니다.
+                if binary.operator.is_pattern_match()
+                    && !matches!(lhs, TableDataFieldType::Array(_))
+                {
+                    return Self::reduce_pattern_match(&binary.operator, lhs, rhs);
                 }
 
                 if let TableDataFieldType::Array(ref left_array) = lhs {
@@ -204,6 +220,26 @@ impl DBEngine {
                     return Ok(TableDataFieldType::Array(result));
                 }
 
+                // IS 계열은 NULL도 일반 값처럼 비교합니다.
+                if binary.operator.is_null_safe() {
+                    return Self::reduce_is_predicate(&binary.operator, lhs, rhs);
+                }
+
+                if matches!(binary.operator, BinaryOperator::And | BinaryOperator::Or) {
+                    return Self::reduce_logical(&binary.operator, lhs, rhs);
+                }
+
+                // 그 외 연산자는 한쪽이라도 NULL이면 결과도 NULL입니다.
+                if lhs == TableDataFieldType::Null || rhs == TableDataFieldType::Null {
+                    return Ok(TableDataFieldType::Null);
+                }
+
+                if lhs.type_code() != rhs.type_code() {
+                    return Err(TypeError::wrap(
+                        "The types of lhs and rhs do not match.".to_string(),
+                    ));
+                }
+
                 match binary.operator {
                     BinaryOperator::Add => match lhs {
                         TableDataFieldType::Integer(lhs_value) => {
@@ -249,7 +285,7 @@ impl DBEngine {
                         }
                         TableDataFieldType::Float(lhs_value) => {
                             if let TableDataFieldType::Float(rhs_value) = rhs {
-                                return Ok(TableDataFieldType::Float(lhs_value + rhs_value));
+                                return Ok(TableDataFieldType::Float(lhs_value - rhs_value));
                             }
                             unreachable!()
                         }
@@ -307,28 +343,6 @@ impl DBEngine {
                             "binary '/' operator is valid only for integer and float types.",
                         )),
                     },
-                    BinaryOperator::And => match lhs {
-                        TableDataFieldType::Boolean(lhs_value) => {
-                            if let TableDataFieldType::Boolean(rhs_value) = rhs {
-                                return Ok(TableDataFieldType::Boolean(lhs_value && rhs_value));
-                            }
-                            unreachable!()
-                        }
-                        _ => Err(TypeError::wrap(
-                            "binary 'And' operator is valid only for boolean type.",
-                        )),
-                    },
-                    BinaryOperator::Or => match lhs {
-                        TableDataFieldType::Boolean(lhs_value) => {
-                            if let TableDataFieldType::Boolean(rhs_value) = rhs {
-                                return Ok(TableDataFieldType::Boolean(lhs_value || rhs_value));
-                            }
-                            unreachable!()
-                        }
-                        _ => Err(TypeError::wrap(
-                            "binary 'Or' operator is valid only for boolean type.",
-                        )),
-                    },
                     BinaryOperator::Lt => match lhs {
                         TableDataFieldType::Integer(lhs_value) => {
                             if let TableDataFieldType::Integer(rhs_value) = rhs {
@@ -423,12 +437,19 @@ impl DBEngine {
                     },
                     BinaryOperator::Eq => Ok(TableDataFieldType::Boolean(lhs == rhs)),
                     BinaryOperator::Neq => Ok(TableDataFieldType::Boolean(lhs != rhs)),
-                    BinaryOperator::Like => unimplemented!("미구현"),
-                    BinaryOperator::NotLike => unimplemented!("미구현"),
-                    BinaryOperator::In => unimplemented!("미구현"),
-                    BinaryOperator::NotIn => unimplemented!("미구현"),
-                    BinaryOperator::Is => unimplemented!("미구현"),
-                    BinaryOperator::IsNot => unimplemented!("미구현"),
+                    BinaryOperator::Like
+                    | BinaryOperator::NotLike
+                    | BinaryOperator::ILike
+                    | BinaryOperator::NotILike => {
+                        Self::reduce_pattern_match(&binary.operator, lhs, rhs)
+                    }
+                    BinaryOperator::In | BinaryOperator::NotIn => unreachable!(),
+                    BinaryOperator::And
+                    | BinaryOperator::Or
+                    | BinaryOperator::Is
+                    | BinaryOperator::IsNot
+                    | BinaryOperator::IsDistinctFrom
+                    | BinaryOperator::IsNotDistinctFrom => unreachable!(),
                 }
             }
             SQLExpression::Between(between) => {
@@ -441,177 +462,115 @@ impl DBEngine {
                 unimplemented!("미구현")
             }
             SQLExpression::NotBetween(_between) => unimplemented!("미구현"),
+            SQLExpression::LikeEscape(like_escape) => {
+                let pattern =
+                    Box::pin(self.reduce_expression(like_escape.pattern, context.clone())).await?;
+                let escape = Box::pin(self.reduce_expression(like_escape.escape, context)).await?;
+
+                match (pattern, escape) {
+                    (TableDataFieldType::Null, _) | (_, TableDataFieldType::Null) => {
+                        Ok(TableDataFieldType::Null)
+                    }
+                    (TableDataFieldType::String(pattern), TableDataFieldType::String(escape)) => {
+                        Ok(TableDataFieldType::String(pattern::like_escape(
+                            &pattern, &escape,
+                        )?))
+                    }
+                    _ => Err(TypeError::wrap(
+                        "LIKE pattern and ESCAPE are valid only for string types.",
+                    )),
+                }
+            }
             SQLExpression::Parentheses(paren) => {
                 Box::pin(self.reduce_expression(paren.expression, context)).await
             }
-            SQLExpression::FunctionCall(call) => match call.function {
+            SQLExpression::FunctionCall(call) => match &call.function {
                 Function::BuiltIn(builtin) => match builtin {
-                    BuiltInFunction::Aggregate(aggregate) => match aggregate {
-                        AggregateFunction::Count => {
-                            if call.arguments.len() != 1 {
-                                return Err(ExecuteError::wrap(
-                                    "Count function takes only one parameter.",
-                                ));
-                            }
-
-                            let argument = call.arguments[0].clone();
-                            let value =
-                                Box::pin(self.reduce_expression(argument, context.clone())).await?;
-
-                            match value {
-                                TableDataFieldType::Array(array) => {
-                                    let value = array
-                                        .into_iter()
-                                        .filter(|e| {
-                                            #[allow(clippy::match_like_matches_macro)]
-                                            match e {
-                                                TableDataFieldType::Null => false,
-                                                _ => true,
-                                            }
-                                        })
-                                        .count();
-
-                                    Ok(TableDataFieldType::Integer(value as i64))
-                                }
-                                TableDataFieldType::Null => Ok(TableDataFieldType::Integer(0)),
-                                _ => Ok(TableDataFieldType::Integer(context.total_count as i64)),
-                            }
-                        }
-                        AggregateFunction::Sum => {
-                            if call.arguments.len() != 1 {
-                                return Err(ExecuteError::wrap(
-                                    "Sum function takes only one parameter.",
-                                ));
-                            }
-
-                            let argument = call.arguments[0].clone();
-                            let value =
-                                Box::pin(self.reduce_expression(argument, context.clone())).await?;
-
-                            match value {
-                                TableDataFieldType::Array(array) => {
-                                    let value = array.into_iter().fold(
-                                        TableDataFieldType::Null,
-                                        |acc, e| match e {
-                                            TableDataFieldType::Integer(integer) => {
-                                                if let TableDataFieldType::Integer(acc_value) = acc
-                                                {
-                                                    TableDataFieldType::Integer(acc_value + integer)
-                                                } else {
-                                                    TableDataFieldType::Integer(integer)
-                                                }
-                                            }
-                                            TableDataFieldType::Float(integer) => {
-                                                if let TableDataFieldType::Float(acc_value) = acc {
-                                                    TableDataFieldType::Float(acc_value + integer)
-                                                } else {
-                                                    TableDataFieldType::Float(integer)
-                                                }
-                                            }
-                                            _ => acc,
-                                        },
-                                    );
-
-                                    Ok(value)
-                                }
-                                _ => {
-                                    unimplemented!("미구현");
-                                }
-                            }
-                        }
-                        AggregateFunction::Max => {
-                            if call.arguments.len() != 1 {
-                                return Err(ExecuteError::wrap(
-                                    "Max function takes only one parameter.",
-                                ));
-                            }
-
-                            unimplemented!("미구현");
-                        }
-                        AggregateFunction::Min => {
-                            if call.arguments.len() != 1 {
-                                return Err(ExecuteError::wrap(
-                                    "Min function takes only one parameter.",
-                                ));
-                            }
+                    // 집계 결과는 해시 집계 연산자가 미리 계산해서 행에 넣어둡니다.
+                    BuiltInFunction::Aggregate(aggregate) => context
+                        .row
+                        .as_ref()
+                        .and_then(|row| find_aggregate_value(&call, row))
+                        .ok_or_else(|| {
+                            ExecuteError::wrap(format!(
+                                "aggregate function {}() is not allowed here",
+                                aggregate.name()
+                            ))
+                        }),
+                    BuiltInFunction::Conditional(conditional) => {
+                        let conditional = conditional.clone();
+                        Box::pin(self.reduce_conditional_function(
+                            conditional,
+                            call.arguments,
+                            context,
+                        ))
+                        .await
+                    }
+                    BuiltInFunction::Scalar(scalar) => {
+                        let scalar = scalar.clone();
+                        let arguments =
+                            Box::pin(self.reduce_arguments(call.arguments, context)).await?;
 
-                            unimplemented!("미구현");
-                        }
-                        _ => unimplemented!("미구현"),
-                    },
-                    BuiltInFunction::Conditional(_) => {
-                        unimplemented!("미구현")
+                        scalar.call(arguments)
                     }
+                    BuiltInFunction::Window(window) => Err(ExecuteError::wrap(format!(
+                        "window function {}() requires an OVER clause",
+                        window.name()
+                    ))),
                 },
-                Function::UserDefined(_) => unimplemented!("미구현"),
+                // 펼쳐 넣지 못한 사용자 정의 함수는 본문을 직접 실행합니다.
+                Function::UserDefined(function) => {
+                    let function = function.clone();
+                    let arguments =
+                        Box::pin(self.reduce_arguments(call.arguments, context)).await?;
+
+                    Box::pin(self.call_user_defined_function(&function, arguments)).await
+                }
             },
-            SQLExpression::Subquery(_) => unimplemented!("미구현"),
-            SQLExpression::SelectColumn(select_column) => {
-                let column_name = select_column.column_name.clone();
-
-                match context.row {
-                    Some(ref row) => {
-                        let same_name_datas = row
-                            .fields
-                            .iter()
-                            .filter(|e| e.column_name == column_name)
-                            .cloned()
-                            .collect::<Vec<_>>();
-
-                        // 없으면 오류
-                        if same_name_datas.is_empty() {
-                            return Err(ExecuteError::wrap(format!(
-                                "1 column select '{:?}' not exists",
-                                select_column
-                            )));
-                        }
-
-                        // 테이블명 선택한게 있으면
-                        match select_column.table_name {
-                            Some(ref table_name) => {
-                                if let Some(found) = same_name_datas.iter().find(|e| {
-                                    // alias가 있으면
-                                    if let Some(table_name) =
-                                        context.table_alias_map.get(table_name)
-                                    {
-                                        *table_name == e.table_name
-                                    }
-                                    // 없으면 자체 테이블명 비교
-                                    else {
-                                        table_name == &e.table_name.table_name
-                                    }
-                                }) {
-                                    Ok(found.data.to_owned())
-                                } else {
-                                    Err(ExecuteError::wrap(format!(
-                                        "column select '{:?}' is ambiguous",
-                                        select_column
-                                    )))
-                                }
-                            }
-                            None => {
-                                if same_name_datas.len() >= 2 {
-                                    Err(ExecuteError::wrap(format!(
-                                        "column select '{:?}' is ambiguous",
-                                        select_column
-                                    )))
-                                } else {
-                                    Ok(same_name_datas[0].data.to_owned())
-                                }
-                            }
-                        }
-                    }
-                    None => Err(ExecuteError::wrap(format!(
-                        "column select '{:?}' not exists",
-                        select_column
-                    ))),
+            // 윈도우 함수 결과는 윈도우 연산자가 미리 계산해서 행에 넣어둡니다.
+            SQLExpression::Window(window) => context
+                .row
+                .as_ref()
+                .and_then(|row| find_window_value(&window, row))
+                .ok_or_else(|| ExecuteError::wrap("window functions are not allowed here")),
+            SQLExpression::Case(case) => {
+                Box::pin(self.reduce_case_expression(*case, context)).await
+            }
+            SQLExpression::Cast(cast) => {
+                let value = Box::pin(self.reduce_expression(cast.expression, context)).await?;
+
+                value.cast_to(&cast.data_type)
+            }
+            SQLExpression::Subquery(SubqueryExpression::Exists(mut query)) => {
+                // 행이 하나라도 있는지만 보면 되므로 하나만 가져옵니다.
+                if query.limit.is_none() && query.offset.is_none() {
+                    query.limit = Some(1);
+                }
+
+                let result = Box::pin(self.select_with_outer(*query, Some(context))).await?;
+
+                Ok(TableDataFieldType::Boolean(!result.rows.is_empty()))
+            }
+            SQLExpression::Subquery(subquery) => {
+                let mut values =
+                    Box::pin(self.reduce_subquery_values(subquery, Some(context))).await?;
+
+                match values.len() {
+                    0 => Ok(TableDataFieldType::Null),
+                    1 => Ok(values.remove(0)),
+                    _ => Err(ExecuteError::wrap(
+                        "more than one row returned by a subquery used as an expression",
+                    )),
                 }
             }
+            SQLExpression::SelectColumn(select_column) => {
+                Self::reduce_select_column(&select_column, &context)
+            }
         }
     }
 
     #[allow(clippy::only_used_in_recursion)]
-    pub fn reduce_type(
+    pub async fn reduce_type(
         &self,
         expression: SQLExpression,
         context: ReduceContext,
@@ -627,12 +586,20 @@ impl DBEngine {
             }
             SQLExpression::Unary(unary) => match unary.operator {
                 UnaryOperator::Neg | UnaryOperator::Pos | UnaryOperator::Not => {
-                    self.reduce_type(unary.operand, context)
+                    Box::pin(self.reduce_type(unary.operand, context)).await
                 }
             },
             SQLExpression::Binary(binary) => {
-                let lhs = self.reduce_type(binary.lhs, context.clone())?;
-                let rhs = self.reduce_type(binary.rhs, context)?;
+                // IN의 우변(목록, 서브쿼리)은 결과 타입에 영향을 주지 않음
+                if matches!(binary.operator, BinaryOperator::In | BinaryOperator::NotIn) {
+                    return match Box::pin(self.reduce_type(binary.lhs, context)).await? {
+                        ExecuteColumnType::Null => Ok(ExecuteColumnType::Null),
+                        _ => Ok(ExecuteColumnType::Bool),
+                    };
+                }
+
+                let lhs = Box::pin(self.reduce_type(binary.lhs, context.clone())).await?;
+                let rhs = Box::pin(self.reduce_type(binary.rhs, context)).await?;
 
                 match binary.operator {
                     BinaryOperator::Add
@@ -659,6 +626,8 @@ impl DBEngine {
                     | BinaryOperator::Neq
                     | BinaryOperator::Like
                     | BinaryOperator::NotLike
+                    | BinaryOperator::ILike
+                    | BinaryOperator::NotILike
                     | BinaryOperator::In
                     | BinaryOperator::NotIn => {
                         if let ExecuteColumnType::Null = lhs {
@@ -671,80 +640,701 @@ impl DBEngine {
 
                         Ok(ExecuteColumnType::Bool)
                     }
-                    BinaryOperator::Is | BinaryOperator::IsNot => Ok(ExecuteColumnType::Bool),
+                    BinaryOperator::Is
+                    | BinaryOperator::IsNot
+                    | BinaryOperator::IsDistinctFrom
+                    | BinaryOperator::IsNotDistinctFrom => Ok(ExecuteColumnType::Bool),
                 }
             }
             SQLExpression::Between(_) => Ok(ExecuteColumnType::Bool),
             SQLExpression::NotBetween(_between) => Ok(ExecuteColumnType::Bool),
-            SQLExpression::Parentheses(paren) => self.reduce_type(paren.expression, context),
+            SQLExpression::LikeEscape(_) => Ok(ExecuteColumnType::String),
+            SQLExpression::Parentheses(paren) => {
+                Box::pin(self.reduce_type(paren.expression, context)).await
+            }
             SQLExpression::FunctionCall(call) => match call.function {
                 Function::BuiltIn(builtin) => match builtin {
-                    BuiltInFunction::Aggregate(aggregate) => match aggregate {
-                        AggregateFunction::Sum => Ok(ExecuteColumnType::Integer),
-                        AggregateFunction::Count => Ok(ExecuteColumnType::Integer),
-                        AggregateFunction::Max => Ok(ExecuteColumnType::Integer),
-                        AggregateFunction::Min => Ok(ExecuteColumnType::Integer),
-                        _ => unimplemented!("미구현"),
-                    },
-                    BuiltInFunction::Conditional(_) => unimplemented!("미구현"),
+                    BuiltInFunction::Aggregate(aggregate) => {
+                        let argument_type = match call.arguments.into_iter().next() {
+                            Some(argument) => {
+                                Some(Box::pin(self.reduce_type(argument, context)).await?)
+                            }
+                            None => None,
+                        };
+
+                        Ok(aggregate.result_type(argument_type))
+                    }
+                    BuiltInFunction::Conditional(conditional) => {
+                        let argument_types =
+                            Box::pin(self.reduce_argument_types(call.arguments, context)).await?;
+
+                        conditional
+                            .signature()
+                            .check(conditional.name(), &argument_types)
+                    }
+                    BuiltInFunction::Scalar(scalar) => {
+                        let argument_types =
+                            Box::pin(self.reduce_argument_types(call.arguments, context)).await?;
+
+                        scalar.signature().check(scalar.name(), &argument_types)
+                    }
+                    BuiltInFunction::Window(window) => {
+                        let argument_type = match call.arguments.into_iter().next() {
+                            Some(argument) => {
+                                Some(Box::pin(self.reduce_type(argument, context)).await?)
+                            }
+                            None => None,
+                        };
+
+                        Ok(window.result_type(argument_type))
+                    }
                 },
-                Function::UserDefined(_) => {
-                    unimplemented!("미구현")
+                Function::UserDefined(function) => {
+                    Box::pin(self.reduce_argument_types(call.arguments.clone(), context)).await?;
+
+                    // 함수 정의는 실행 전에 resolve_functions에서 캐시에 올려둡니다.
+                    let cache = self.function_config_cache.read().await;
+
+                    let schema = cache.get(&function).ok_or_else(|| {
+                        ExecuteError::wrap(format!(
+                            "function {} does not exist",
+                            function.function_name
+                        ))
+                    })?;
+
+                    check_argument_count(schema, call.arguments.len())?;
+
+                    Ok(schema.return_type.clone().into())
                 }
             },
-            SQLExpression::Subquery(_) => unimplemented!("미구현"),
+            SQLExpression::Window(window) => {
+                Box::pin(self.reduce_type(SQLExpression::FunctionCall(window.call), context)).await
+            }
+            SQLExpression::Case(case) => Box::pin(self.reduce_case_type(*case, context)).await,
+            SQLExpression::Cast(cast) => {
+                // 변환 대상 표현식의 오류(없는 컬럼 등)는 그대로 드러냅니다.
+                Box::pin(self.reduce_type(cast.expression, context)).await?;
+
+                Ok(cast.data_type.into())
+            }
+            SQLExpression::Subquery(SubqueryExpression::Exists(_)) => Ok(ExecuteColumnType::Bool),
+            // 집합 연산의 결과 타입은 왼쪽 쿼리를 따릅니다.
+            SQLExpression::Subquery(SubqueryExpression::Select(query))
+                if query.set_operation.is_some() =>
+            {
+                let left = query.set_operation.unwrap().left;
+                Box::pin(self.reduce_type(
+                    SQLExpression::Subquery(SubqueryExpression::Select(left)),
+                    context,
+                ))
+                .await
+            }
+            SQLExpression::Subquery(SubqueryExpression::Select(query)) => {
+                let context = self.subquery_type_context(&query, context).await;
+
+                match query.select_items.first() {
+                    Some(SelectKind::SelectItem(item)) if query.select_items.len() == 1 => {
+                        match item.item.clone() {
+                            Some(expression) => {
+                                Box::pin(self.reduce_type(expression, context)).await
+                            }
+                            None => Ok(ExecuteColumnType::Null),
+                        }
+                    }
+                    Some(SelectKind::WildCard(_)) if context.config_columns.len() == 1 => {
+                        Ok(context.config_columns[0].1.data_type.to_owned().into())
+                    }
+                    _ => Err(ExecuteError::wrap("subquery must return only one column")),
+                }
+            }
             SQLExpression::SelectColumn(select_column) => {
-                let column_name = select_column.column_name.clone();
+                Self::reduce_select_column_type(&select_column, &context)
+            }
+        }
+    }
 
-                if context.config_columns.is_empty() {
+    // CASE 결과 타입
+    // THEN, ELSE 결과들의 공통 타입입니다. NULL은 무시하고, 정수와 실수가 섞이면 실수가 됩니다.
+    async fn reduce_case_type(
+        &self,
+        case: CaseExpression,
+        context: ReduceContext,
+    ) -> errors::Result<ExecuteColumnType> {
+        if case.operand.is_none() {
+            for branch in &case.branches {
+                match Box::pin(self.reduce_type(branch.condition.clone(), context.clone())).await? {
+                    ExecuteColumnType::Bool | ExecuteColumnType::Null => {}
+                    _ => {
+                        return Err(TypeError::wrap(
+                            "argument of CASE/WHEN must be type boolean",
+                        ));
+                    }
+                }
+            }
+        }
+
+        let results = case
+            .branches
+            .into_iter()
+            .map(|branch| branch.result)
+            .chain(case.else_result);
+
+        let mut result_type = ExecuteColumnType::Null;
+
+        for result in results {
+            let next_type = Box::pin(self.reduce_type(result, context.clone())).await?;
+
+            result_type = match result_type.clone().common_type(next_type.clone()) {
+                Some(common_type) => common_type,
+                None => {
+                    return Err(TypeError::wrap(format!(
+                        "CASE types {} and {} cannot be matched",
+                        result_type.name(),
+                        next_type.name()
+                    )));
+                }
+            };
+        }
+
+        Ok(result_type)
+    }
+
+    // 컬럼 값을 현재 행에서 찾고, 없다면 바깥 쿼리의 행에서 찾습니다. (상관 서브쿼리)
+    fn reduce_select_column(
+        select_column: &SelectColumn,
+        context: &ReduceContext,
+    ) -> errors::Result<TableDataFieldType> {
+        let found = match context.row {
+            Some(ref row) => Self::find_column_value(select_column, row, &context.table_alias_map)?,
+            None => None,
+        };
+
+        match (found, &context.outer) {
+            (Some(value), _) => Ok(value),
+            (None, Some(outer)) => Self::reduce_select_column(select_column, outer),
+            (None, None) => Err(ExecuteError::wrap(format!(
+                "column select '{:?}' not exists",
+                select_column
+            ))),
+        }
+    }
+
+    // 행에서 컬럼 값을 찾습니다. 없으면 None, 특정할 수 없으면 오류입니다.
+    fn find_column_value(
+        select_column: &SelectColumn,
+        row: &TableDataRow,
+        table_alias_map: &HashMap<String, TableName>,
+    ) -> errors::Result<Option<TableDataFieldType>> {
+        let same_name_datas = row
+            .fields
+            .iter()
+            .filter(|e| e.column_name == select_column.column_name)
+            .collect::<Vec<_>>();
+
+        match select_column.table_name {
+            // 테이블명 선택한게 있으면
+            Some(ref table_name) => Ok(same_name_datas
+                .into_iter()
+                .find(|e| Self::qualifier_matches(table_name, &e.table_name, table_alias_map))
+                .map(|e| e.data.to_owned())),
+            None => {
+                if same_name_datas.len() >= 2 {
                     return Err(ExecuteError::wrap(format!(
-                        "column select '{:?}' not exists",
+                        "column select '{:?}' is ambiguous",
                         select_column
                     )));
                 }
 
-                let same_name_columns = context
-                    .config_columns
-                    .iter()
-                    .filter(|(_, e)| e.name == column_name)
-                    .cloned()
-                    .collect::<Vec<_>>();
-
-                // 테이블명 선택한게 있으면
-                match select_column.table_name {
-                    Some(ref table_name) => {
-                        if let Some(found) =
-                            context.config_columns.iter().find(|(each_table_name, _)| {
-                                // alias가 있으면
-                                if let Some(table_name) = context.table_alias_map.get(table_name) {
-                                    table_name == each_table_name
-                                }
-                                // 없으면 자체 테이블명 비교
-                                else {
-                                    table_name == &each_table_name.table_name
-                                }
-                            })
-                        {
-                            Ok(found.1.data_type.to_owned().into())
-                        } else {
-                            Err(ExecuteError::wrap(format!(
-                                "column select '{:?}' is ambiguous",
-                                select_column
-                            )))
+                Ok(same_name_datas.first().map(|e| e.data.to_owned()))
+            }
+        }
+    }
+
+    // 컬럼 한정자가 해당 테이블을 가리키는지 판별합니다.
+    // alias를 먼저 보고, alias가 붙은 테이블은 원래 테이블명으로 찾지 않습니다.
+    // (FROM t t2 안에서 t.id는 바깥 쿼리의 t를 가리킵니다)
+    fn qualifier_matches(
+        qualifier: &str,
+        table_name: &TableName,
+        table_alias_map: &HashMap<String, TableName>,
+    ) -> bool {
+        match table_alias_map.get(qualifier) {
+            Some(aliased) => aliased == table_name,
+            None => {
+                qualifier == table_name.table_name
+                    && !table_alias_map
+                        .values()
+                        .any(|aliased| aliased == table_name)
+            }
+        }
+    }
+
+    // 컬럼 타입을 현재 쿼리의 테이블에서 찾고, 없다면 바깥 쿼리의 테이블에서 찾습니다.
+    fn reduce_select_column_type(
+        select_column: &SelectColumn,
+        context: &ReduceContext,
+    ) -> errors::Result<ExecuteColumnType> {
+        let same_name_columns = context
+            .config_columns
+            .iter()
+            .filter(|(_, e)| e.name == select_column.column_name)
+            .collect::<Vec<_>>();
+
+        let found = match select_column.table_name {
+            // 테이블명 선택한게 있으면
+            Some(ref table_name) => same_name_columns.into_iter().find(|(each_table_name, _)| {
+                Self::qualifier_matches(table_name, each_table_name, &context.table_alias_map)
+            }),
+            None => {
+                if same_name_columns.len() >= 2 {
+                    return Err(ExecuteError::wrap(format!(
+                        "column select '{:?}' is ambiguous",
+                        select_column
+                    )));
+                }
+
+                same_name_columns.into_iter().next()
+            }
+        };
+
+        match (found, &context.outer) {
+            (Some((_, column)), _) => Ok(column.data_type.to_owned().into()),
+            (None, Some(outer)) => Self::reduce_select_column_type(select_column, outer),
+            (None, None) => Err(ExecuteError::wrap(format!(
+                "column select '{:?}' not exists",
+                select_column
+            ))),
+        }
+    }
+
+    // CASE 평가
+    // WHEN 조건을 순서대로 보고, 처음으로 참인 분기의 결과만 계산합니다. 없다면 ELSE 결과(없으면 NULL)입니다.
+    // 단순 CASE는 operand = WHEN 값 비교로 평가하므로, NULL인 operand는 어떤 분기와도 일치하지 않습니다.
+    // 함수 인자들을 차례대로 계산합니다.
+    async fn reduce_arguments(
+        &self,
+        arguments: Vec<SQLExpression>,
+        context: ReduceContext,
+    ) -> errors::Result<Vec<TableDataFieldType>> {
+        let mut values = Vec::with_capacity(arguments.len());
+
+        for argument in arguments {
+            values.push(Box::pin(self.reduce_expression(argument, context.clone())).await?);
+        }
+
+        Ok(values)
+    }
+
+    async fn reduce_argument_types(
+        &self,
+        arguments: Vec<SQLExpression>,
+        context: ReduceContext,
+    ) -> errors::Result<Vec<ExecuteColumnType>> {
+        let mut argument_types = Vec::with_capacity(arguments.len());
+
+        for argument in arguments {
+            argument_types.push(Box::pin(self.reduce_type(argument, context.clone())).await?);
+        }
+
+        Ok(argument_types)
+    }
+
+    // COALESCE는 NULL이 아닌 값을 찾으면 나머지 인자는 계산하지 않습니다.
+    // 타입 검사는 계산하지 않은 인자까지 포함해서 reduce_type과 같은 결과를 내도록 합니다.
+    async fn reduce_conditional_function(
+        &self,
+        conditional: ConditionalFunction,
+        arguments: Vec<SQLExpression>,
+        context: ReduceContext,
+    ) -> errors::Result<TableDataFieldType> {
+        if conditional != ConditionalFunction::Coalesce {
+            let arguments = Box::pin(self.reduce_arguments(arguments, context)).await?;
+
+            return conditional.call(arguments);
+        }
+
+        let mut values = Vec::with_capacity(arguments.len());
+        let mut arguments = arguments.into_iter();
+
+        for argument in arguments.by_ref() {
+            let value = Box::pin(self.reduce_expression(argument, context.clone())).await?;
+            let is_null = value.is_null();
+            values.push(value);
+
+            if !is_null {
+                break;
+            }
+        }
+
+        let mut argument_types = values
+            .iter()
+            .map(ExecuteColumnType::from)
+            .collect::<Vec<_>>();
+        argument_types
+            .extend(Box::pin(self.reduce_argument_types(arguments.collect(), context)).await?);
+
+        conditional
+            .signature()
+            .check(conditional.name(), &argument_types)?;
+
+        conditional.call(values)
+    }
+
+    async fn reduce_case_expression(
+        &self,
+        case: CaseExpression,
+        context: ReduceContext,
+    ) -> errors::Result<TableDataFieldType> {
+        let operand = match case.operand {
+            Some(operand) => {
+                Some(Box::pin(self.reduce_expression(operand, context.clone())).await?)
+            }
+            None => None,
+        };
+
+        for branch in case.branches {
+            let condition = match &operand {
+                Some(operand) => BinaryOperatorExpression {
+                    operator: BinaryOperator::Eq,
+                    lhs: operand.clone().into(),
+                    rhs: branch.condition,
+                }
+                .into(),
+                None => branch.condition,
+            };
+
+            match Box::pin(self.reduce_expression(condition, context.clone())).await? {
+                TableDataFieldType::Boolean(true) => {
+                    return Box::pin(self.reduce_expression(branch.result, context)).await;
+                }
+                TableDataFieldType::Boolean(false) | TableDataFieldType::Null => {}
+                _ => {
+                    return Err(TypeError::wrap(
+                        "argument of CASE/WHEN must be type boolean",
+                    ));
+                }
+            }
+        }
+
+        match case.else_result {
+            Some(else_result) => Box::pin(self.reduce_expression(else_result, context)).await,
+            None => Ok(TableDataFieldType::Null),
+        }
+    }
+
+    // a IN (x, y, z), a IN (SELECT ...) 평가
+    async fn reduce_in_expression(
+        &self,
+        binary: BinaryOperatorExpression,
+        context: ReduceContext,
+    ) -> errors::Result<TableDataFieldType> {
+        let lhs = Box::pin(self.reduce_expression(binary.lhs, context.clone())).await?;
+
+        let candidates = match binary.rhs {
+            SQLExpression::List(list) => {
+                let futures = list
+                    .value
+                    .into_iter()
+                    .map(|e| self.reduce_expression(e, context.clone()));
+                Box::pin(join_all(futures))
+                    .await
+                    .into_iter()
+                    .collect::<Result<Vec<_>, _>>()?
+            }
+            SQLExpression::Subquery(subquery) => {
+                Box::pin(self.reduce_subquery_values(subquery, Some(context))).await?
+            }
+            _ => {
+                return Err(ExecuteError::wrap(
+                    "binary 'IN' operator requires a list or a subquery.",
+                ));
+            }
+        };
+
+        let negated = binary.operator == BinaryOperator::NotIn;
+
+        match lhs {
+            TableDataFieldType::Array(values) => Ok(TableDataFieldType::Array(
+                values
+                    .into_iter()
+                    .map(|value| Self::in_list_contains(value, &candidates, negated))
+                    .collect::<Result<Vec<_>, _>>()?,
+            )),
+            value => Self::in_list_contains(value, &candidates, negated),
+        }
+    }
+
+    // IN 판정 (3값 논리)
+    // 일치하는 값이 있으면 TRUE, 없는데 목록에 NULL이 있다면 NULL, 그 외엔 FALSE
+    // NOT IN은 그 부정입니다. (NULL은 그대로 NULL)
+    fn in_list_contains(
+        value: TableDataFieldType,
+        candidates: &[TableDataFieldType],
+        negated: bool,
+    ) -> errors::Result<TableDataFieldType> {
+        if candidates.is_empty() {
+            return Ok(TableDataFieldType::Boolean(negated));
+        }
+
+        if value == TableDataFieldType::Null {
+            return Ok(TableDataFieldType::Null);
+        }
+
+        let mut has_null = false;
+
+        for candidate in candidates {
+            if *candidate == TableDataFieldType::Null {
+                has_null = true;
+                continue;
+            }
+
+            if value.type_code() != candidate.type_code() {
+                return Err(TypeError::wrap(
+                    "The types of lhs and rhs do not match.".to_string(),
+                ));
+            }
+
+            if value == *candidate {
+                return Ok(TableDataFieldType::Boolean(!negated));
+            }
+        }
+
+        if has_null {
+            Ok(TableDataFieldType::Null)
+        } else {
+            Ok(TableDataFieldType::Boolean(negated))
+        }
+    }
+
+    // 서브쿼리를 실행해 단일 컬럼 결과 값 목록을 반환합니다.
+    // outer가 있다면 서브쿼리에서 바깥 쿼리의 행을 참조할 수 있습니다.
+    pub(crate) async fn reduce_subquery_values(
+        &self,
+        subquery: SubqueryExpression,
+        outer: Option<ReduceContext>,
+    ) -> errors::Result<Vec<TableDataFieldType>> {
+        let result = Box::pin(self.select_with_outer(subquery.into_query(), outer)).await?;
+
+        if result.columns.len() != 1 {
+            return Err(ExecuteError::wrap("subquery must return only one column"));
+        }
+
+        Ok(result
+            .rows
+            .into_iter()
+            .map(|mut row| row.fields.swap_remove(0).into())
+            .collect())
+    }
+
+    // 바깥 행과 무관한(비상관) 서브쿼리를 미리 한 번만 실행해 값으로 바꿔둡니다.
+    // IN 서브쿼리는 값 목록, 스칼라 서브쿼리는 값, EXISTS는 TRUE/FALSE가 됩니다.
+    // 상관 서브쿼리는 행마다 다시 평가해야 하므로 그대로 둡니다.
+    pub(crate) async fn materialize_subqueries(
+        &self,
+        expression: SQLExpression,
+    ) -> errors::Result<SQLExpression> {
+        match expression {
+            SQLExpression::Binary(mut binary) => {
+                if matches!(binary.operator, BinaryOperator::In | BinaryOperator::NotIn) {
+                    let subquery = match binary.rhs {
+                        SQLExpression::Subquery(ref subquery) => Some(subquery.clone()),
+                        SQLExpression::Parentheses(ref paren) => match &paren.expression {
+                            SQLExpression::Subquery(subquery) => Some(subquery.clone()),
+                            _ => None,
+                        },
+                        _ => None,
+                    };
+
+                    if let Some(subquery) = subquery
+                        && !self.subquery_is_correlated(subquery.query()).await?
+                    {
+                        let values = Box::pin(self.reduce_subquery_values(subquery, None)).await?;
+
+                        // 결과가 비었다면 빈 목록이 되며, IN은 FALSE, NOT IN은 TRUE가 됩니다.
+                        binary.rhs = ListExpression {
+                            value: values.into_iter().map(Into::into).collect(),
                         }
+                        .into();
+                        binary.lhs = Box::pin(self.materialize_subqueries(binary.lhs)).await?;
+
+                        return Ok(SQLExpression::Binary(binary));
                     }
-                    None => {
-                        if same_name_columns.len() >= 2 {
-                            Err(ExecuteError::wrap(format!(
-                                "column select '{:?}' is ambiguous",
-                                select_column
-                            )))
-                        } else {
-                            Ok(same_name_columns[0].1.data_type.to_owned().into())
+                }
+
+                binary.lhs = Box::pin(self.materialize_subqueries(binary.lhs)).await?;
+                binary.rhs = Box::pin(self.materialize_subqueries(binary.rhs)).await?;
+
+                Ok(SQLExpression::Binary(binary))
+            }
+            SQLExpression::Subquery(subquery) => {
+                if self.subquery_is_correlated(subquery.query()).await? {
+                    return Ok(SQLExpression::Subquery(subquery));
+                }
+
+                let value = Box::pin(self.reduce_expression(
+                    SQLExpression::Subquery(subquery),
+                    ReduceContext::default(),
+                ))
+                .await?;
+
+                Ok(value.into())
+            }
+            SQLExpression::Unary(mut unary) => {
+                unary.operand = Box::pin(self.materialize_subqueries(unary.operand)).await?;
+                Ok(SQLExpression::Unary(unary))
+            }
+            SQLExpression::Parentheses(mut paren) => {
+                paren.expression = Box::pin(self.materialize_subqueries(paren.expression)).await?;
+                Ok(SQLExpression::Parentheses(paren))
+            }
+            SQLExpression::Between(mut between) => {
+                between.a = Box::pin(self.materialize_subqueries(between.a)).await?;
+                between.x = Box::pin(self.materialize_subqueries(between.x)).await?;
+                between.y = Box::pin(self.materialize_subqueries(between.y)).await?;
+                Ok(SQLExpression::Between(between))
+            }
+            SQLExpression::NotBetween(mut between) => {
+                between.a = Box::pin(self.materialize_subqueries(between.a)).await?;
+                between.x = Box::pin(self.materialize_subqueries(between.x)).await?;
+                between.y = Box::pin(self.materialize_subqueries(between.y)).await?;
+                Ok(SQLExpression::NotBetween(between))
+            }
+            SQLExpression::FunctionCall(mut call) => {
+                let mut arguments = vec![];
+                for argument in call.arguments {
+                    arguments.push(Box::pin(self.materialize_subqueries(argument)).await?);
+                }
+                call.arguments = arguments;
+                Ok(SQLExpression::FunctionCall(call))
+            }
+            SQLExpression::Window(mut window) => {
+                for expression in window.expressions_mut() {
+                    let materialized =
+                        Box::pin(self.materialize_subqueries(std::mem::take(expression))).await?;
+                    *expression = materialized;
+                }
+                Ok(SQLExpression::Window(window))
+            }
+            SQLExpression::Case(mut case) => {
+                for expression in case.expressions_mut() {
+                    let materialized =
+                        Box::pin(self.materialize_subqueries(std::mem::take(expression))).await?;
+                    *expression = materialized;
+                }
+                Ok(SQLExpression::Case(case))
+            }
+            SQLExpression::Cast(mut cast) => {
+                cast.expression = Box::pin(self.materialize_subqueries(cast.expression)).await?;
+                Ok(SQLExpression::Cast(cast))
+            }
+            expression => Ok(expression),
+        }
+    }
+
+    // AND, OR 평가 (3값 논리)
+    // AND는 FALSE가, OR는 TRUE가 한쪽에라도 있으면 NULL과 무관하게 결과가 정해집니다.
+    fn reduce_logical(
+        operator: &BinaryOperator,
+        lhs: TableDataFieldType,
+        rhs: TableDataFieldType,
+    ) -> errors::Result<TableDataFieldType> {
+        let to_bool = |value: TableDataFieldType| match value {
+            TableDataFieldType::Boolean(value) => Ok(Some(value)),
+            TableDataFieldType::Null => Ok(None),
+            _ => Err(TypeError::wrap(format!(
+                "binary '{:?}' operator is valid only for boolean type.",
+                operator
+            ))),
+        };
+
+        let (lhs, rhs) = (to_bool(lhs)?, to_bool(rhs)?);
+
+        let dominant = matches!(operator, BinaryOperator::Or);
+
+        if lhs == Some(dominant) || rhs == Some(dominant) {
+            return Ok(TableDataFieldType::Boolean(dominant));
+        }
+
+        match (lhs, rhs) {
+            (Some(_), Some(_)) => Ok(TableDataFieldType::Boolean(!dominant)),
+            _ => Ok(TableDataFieldType::Null),
+        }
+    }
+
+    // IS [NOT] NULL, IS [NOT] TRUE/FALSE, IS [NOT] DISTINCT FROM 평가
+    // 결과는 항상 TRUE 또는 FALSE입니다.
+    fn reduce_is_predicate(
+        operator: &BinaryOperator,
+        lhs: TableDataFieldType,
+        rhs: TableDataFieldType,
+    ) -> errors::Result<TableDataFieldType> {
+        let matched = match operator {
+            BinaryOperator::Is | BinaryOperator::IsNot => match (lhs, rhs) {
+                (lhs, TableDataFieldType::Null) => lhs == TableDataFieldType::Null,
+                (TableDataFieldType::Null, TableDataFieldType::Boolean(_)) => false,
+                (TableDataFieldType::Boolean(lhs), TableDataFieldType::Boolean(rhs)) => lhs == rhs,
+                (_, TableDataFieldType::Boolean(_)) => {
+                    return Err(TypeError::wrap(
+                        "IS TRUE, IS FALSE is valid only for boolean type.",
+                    ));
+                }
+                _ => {
+                    return Err(TypeError::wrap(
+                        "IS operator is valid only for NULL, TRUE and FALSE.",
+                    ));
+                }
+            },
+            BinaryOperator::IsDistinctFrom | BinaryOperator::IsNotDistinctFrom => {
+                match (lhs, rhs) {
+                    (TableDataFieldType::Null, TableDataFieldType::Null) => false,
+                    (TableDataFieldType::Null, _) | (_, TableDataFieldType::Null) => true,
+                    (lhs, rhs) => {
+                        if lhs.type_code() != rhs.type_code() {
+                            return Err(TypeError::wrap(
+                                "The types of lhs and rhs do not match.".to_string(),
+                            ));
                         }
+
+                        lhs != rhs
                     }
                 }
             }
+            _ => unreachable!(),
+        };
+
+        match operator {
+            BinaryOperator::IsNot | BinaryOperator::IsNotDistinctFrom => {
+                Ok(TableDataFieldType::Boolean(!matched))
+            }
+            _ => Ok(TableDataFieldType::Boolean(matched)),
+        }
+    }
+
+    // LIKE, NOT LIKE, ILIKE, NOT ILIKE 평가
+    fn reduce_pattern_match(
+        operator: &BinaryOperator,
+        lhs: TableDataFieldType,
+        rhs: TableDataFieldType,
+    ) -> errors::Result<TableDataFieldType> {
+        match (lhs, rhs) {
+            (TableDataFieldType::Null, _) | (_, TableDataFieldType::Null) => {
+                Ok(TableDataFieldType::Null)
+            }
+            (TableDataFieldType::String(value), TableDataFieldType::String(pattern)) => {
+                let case_insensitive =
+                    matches!(operator, BinaryOperator::ILike | BinaryOperator::NotILike);
+                let matched = pattern::like_match(&value, &pattern, case_insensitive)?;
+
+                match operator {
+                    BinaryOperator::NotLike | BinaryOperator::NotILike => {
+                        Ok(TableDataFieldType::Boolean(!matched))
+                    }
+                    _ => Ok(TableDataFieldType::Boolean(matched)),
+                }
+            }
+            _ => Err(TypeError::wrap(
+                "binary 'LIKE' operator is valid only for string types.",
+            )),
         }
     }
 }
@@ -759,27 +1349,30 @@ mod tests {
     use crate::engine::{DBEngine, expression::ReduceContext};
 
     #[tokio::test]
-    async fn count_constant_uses_total_count() {
+    async fn aggregate_outside_aggregation_is_error() {
         let engine = DBEngine::new(LaunchConfig::default());
 
-        let result = engine
+        let error = engine
             .reduce_expression(
                 SQLExpression::FunctionCall(CallExpression {
                     function: Function::BuiltIn(BuiltInFunction::Aggregate(
                         AggregateFunction::Count,
                     )),
                     arguments: vec![SQLExpression::Integer(1)],
+                    distinct: false,
                 }),
                 ReduceContext {
                     row: Some(TableDataRow { fields: vec![] }),
-                    total_count: 3,
                     ..Default::default()
                 },
             )
             .await
-            .unwrap();
+            .expect_err("aggregate must be computed by the aggregate operator");
 
-        assert_eq!(result, TableDataFieldType::Integer(3));
+        assert!(
+            error.to_string().contains("not allowed here"),
+            "unexpected error: {error}"
+        );
     }
 
     use crate::engine::ast::dml::expressions::binary::BinaryOperatorExpression;
@@ -857,7 +1450,6 @@ mod tests {
         }
     }
 
-
     async fn negate(value: i64) -> crate::errors::Result<TableDataFieldType> {
         let engine = DBEngine::new(LaunchConfig::default());
         let expression = SQLExpression::Unary(Box::new(UnaryOperatorExpression {
@@ -893,4 +1485,328 @@ mod tests {
             assert_eq!(result, TableDataFieldType::Integer(expected));
         }
     }
+
+    async fn reduce_operands(
+        operator: BinaryOperator,
+        lhs: SQLExpression,
+        rhs: SQLExpression,
+    ) -> crate::errors::Result<TableDataFieldType> {
+        let engine = DBEngine::new(LaunchConfig::default());
+        let expression =
+            SQLExpression::Binary(Box::new(BinaryOperatorExpression { operator, lhs, rhs }));
+
+        engine
+            .reduce_expression(expression, ReduceContext::default())
+            .await
+    }
+
+    #[tokio::test]
+    async fn like_operators_match_patterns() {
+        use crate::engine::ast::dml::expressions::like_escape::LikeEscapeExpression;
+
+        let string = |value: &str| SQLExpression::String(value.to_owned());
+
+        let cases = [
+            (BinaryOperator::Like, string("apple"), string("app%"), true),
+            (BinaryOperator::Like, string("apple"), string("_pple"), true),
+            (BinaryOperator::Like, string("Apple"), string("app%"), false),
+            (BinaryOperator::NotLike, string("apple"), string("b%"), true),
+            (BinaryOperator::ILike, string("Apple"), string("app%"), true),
+            (
+                BinaryOperator::NotILike,
+                string("Apple"),
+                string("APP%"),
+                false,
+            ),
+            (
+                BinaryOperator::Like,
+                string("10%"),
+                LikeEscapeExpression {
+                    pattern: string("10!%"),
+                    escape: string("!"),
+                }
+                .into(),
+                true,
+            ),
+            (
+                BinaryOperator::Like,
+                string("100"),
+                LikeEscapeExpression {
+                    pattern: string("10!%"),
+                    escape: string("!"),
+                }
+                .into(),
+                false,
+            ),
+        ];
+
+        for (operator, lhs, rhs, expected) in cases {
+            let label = format!("{operator:?} {lhs:?} {rhs:?}");
+            let result = reduce_operands(operator, lhs, rhs).await.unwrap();
+            assert_eq!(result, TableDataFieldType::Boolean(expected), "{label}");
+        }
+    }
+
+    #[tokio::test]
+    async fn like_with_null_operand_is_null() {
+        let result = reduce_operands(
+            BinaryOperator::Like,
+            SQLExpression::Null,
+            SQLExpression::String("a%".into()),
+        )
+        .await
+        .unwrap();
+        assert_eq!(result, TableDataFieldType::Null);
+
+        let result = reduce_operands(
+            BinaryOperator::NotLike,
+            SQLExpression::String("abc".into()),
+            SQLExpression::Null,
+        )
+        .await
+        .unwrap();
+        assert_eq!(result, TableDataFieldType::Null);
+    }
+
+    #[tokio::test]
+    async fn in_list_follows_three_valued_logic() {
+        use crate::engine::ast::dml::expressions::list::ListExpression;
+
+        let list = |values: Vec<SQLExpression>| -> SQLExpression {
+            ListExpression { value: values }.into()
+        };
+        let int = SQLExpression::Integer;
+
+        let cases = [
+            (
+                BinaryOperator::In,
+                int(1),
+                list(vec![int(1), int(2)]),
+                TableDataFieldType::Boolean(true),
+            ),
+            (
+                BinaryOperator::In,
+                int(3),
+                list(vec![int(1), int(2)]),
+                TableDataFieldType::Boolean(false),
+            ),
+            (
+                BinaryOperator::In,
+                int(1),
+                list(vec![SQLExpression::Null, int(1)]),
+                TableDataFieldType::Boolean(true),
+            ),
+            (
+                BinaryOperator::In,
+                int(3),
+                list(vec![int(1), SQLExpression::Null]),
+                TableDataFieldType::Null,
+            ),
+            (
+                BinaryOperator::In,
+                SQLExpression::Null,
+                list(vec![int(1), int(2)]),
+                TableDataFieldType::Null,
+            ),
+            (
+                BinaryOperator::In,
+                SQLExpression::Null,
+                list(vec![]),
+                TableDataFieldType::Boolean(false),
+            ),
+            (
+                BinaryOperator::NotIn,
+                int(3),
+                list(vec![int(1), int(2)]),
+                TableDataFieldType::Boolean(true),
+            ),
+            (
+                BinaryOperator::NotIn,
+                int(1),
+                list(vec![int(1), int(2)]),
+                TableDataFieldType::Boolean(false),
+            ),
+            (
+                BinaryOperator::NotIn,
+                int(3),
+                list(vec![int(1), SQLExpression::Null]),
+                TableDataFieldType::Null,
+            ),
+            (
+                BinaryOperator::NotIn,
+                SQLExpression::Null,
+                list(vec![]),
+                TableDataFieldType::Boolean(true),
+            ),
+            (
+                BinaryOperator::In,
+                int(7),
+                list(vec![int(7)]),
+                TableDataFieldType::Boolean(true),
+            ),
+        ];
+
+        for (operator, lhs, rhs, expected) in cases {
+            let label = format!("{operator:?} {lhs:?} {rhs:?}");
+            let result = reduce_operands(operator, lhs, rhs).await.unwrap();
+            assert_eq!(result, expected, "{label}");
+        }
+    }
+
+    #[tokio::test]
+    async fn in_list_with_mismatched_types_returns_error() {
+        use crate::engine::ast::dml::expressions::list::ListExpression;
+
+        let result = reduce_operands(
+            BinaryOperator::In,
+            SQLExpression::Integer(1),
+            ListExpression {
+                value: vec![SQLExpression::String("1".into())],
+            }
+            .into(),
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn like_on_non_string_returns_error() {
+        let result = reduce_operands(
+            BinaryOperator::Like,
+            SQLExpression::Integer(1),
+            SQLExpression::String("1%".into()),
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn logical_operators_follow_three_valued_logic() {
+        use SQLExpression::{Boolean, Null};
+
+        let cases = [
+            (
+                BinaryOperator::And,
+                Null,
+                Boolean(false),
+                TableDataFieldType::Boolean(false),
+            ),
+            (
+                BinaryOperator::And,
+                Boolean(true),
+                Null,
+                TableDataFieldType::Null,
+            ),
+            (BinaryOperator::And, Null, Null, TableDataFieldType::Null),
+            (
+                BinaryOperator::And,
+                Boolean(true),
+                Boolean(true),
+                TableDataFieldType::Boolean(true),
+            ),
+            (
+                BinaryOperator::Or,
+                Null,
+                Boolean(true),
+                TableDataFieldType::Boolean(true),
+            ),
+            (
+                BinaryOperator::Or,
+                Boolean(false),
+                Null,
+                TableDataFieldType::Null,
+            ),
+            (
+                BinaryOperator::Or,
+                Boolean(false),
+                Boolean(false),
+                TableDataFieldType::Boolean(false),
+            ),
+            (BinaryOperator::Eq, Null, Null, TableDataFieldType::Null),
+            (
+                BinaryOperator::Eq,
+                SQLExpression::Integer(1),
+                Null,
+                TableDataFieldType::Null,
+            ),
+            (
+                BinaryOperator::Lt,
+                Null,
+                SQLExpression::Integer(1),
+                TableDataFieldType::Null,
+            ),
+            (
+                BinaryOperator::Add,
+                SQLExpression::Integer(1),
+                Null,
+                TableDataFieldType::Null,
+            ),
+        ];
+
+        for (operator, lhs, rhs, expected) in cases {
+            let result = reduce_operands(operator.clone(), lhs.clone(), rhs.clone())
+                .await
+                .unwrap();
+
+            assert_eq!(result, expected, "{lhs:?} {operator:?} {rhs:?}");
+        }
+
+        let engine = DBEngine::new(LaunchConfig::default());
+        let result = engine
+            .reduce_expression(
+                SQLExpression::Unary(Box::new(UnaryOperatorExpression {
+                    operator: UnaryOperator::Not,
+                    operand: Null,
+                })),
+                ReduceContext::default(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result, TableDataFieldType::Null);
+    }
+
+    #[tokio::test]
+    async fn is_predicates_never_return_null() {
+        use SQLExpression::{Boolean, Integer, Null};
+
+        let cases = [
+            (BinaryOperator::Is, Null, Null, true),
+            (BinaryOperator::Is, Integer(1), Null, false),
+            (BinaryOperator::IsNot, Integer(1), Null, true),
+            (BinaryOperator::IsNot, Null, Null, false),
+            (BinaryOperator::Is, Boolean(true), Boolean(true), true),
+            (BinaryOperator::Is, Null, Boolean(true), false),
+            (BinaryOperator::IsNot, Null, Boolean(false), true),
+            (BinaryOperator::IsNot, Boolean(false), Boolean(false), false),
+            (BinaryOperator::IsDistinctFrom, Null, Null, false),
+            (BinaryOperator::IsDistinctFrom, Integer(1), Null, true),
+            (BinaryOperator::IsDistinctFrom, Integer(1), Integer(2), true),
+            (BinaryOperator::IsNotDistinctFrom, Null, Null, true),
+            (
+                BinaryOperator::IsNotDistinctFrom,
+                Integer(1),
+                Integer(1),
+                true,
+            ),
+            (BinaryOperator::IsNotDistinctFrom, Null, Integer(1), false),
+        ];
+
+        for (operator, lhs, rhs, expected) in cases {
+            let result = reduce_operands(operator.clone(), lhs.clone(), rhs.clone())
+                .await
+                .unwrap();
+
+            assert_eq!(
+                result,
+                TableDataFieldType::Boolean(expected),
+                "{lhs:?} {operator:?} {rhs:?}"
+            );
+        }
+
+        let result = reduce_operands(BinaryOperator::Is, Integer(1), Boolean(true)).await;
+        assert!(result.is_err());
+    }
 }
@@ -44,6 +44,10 @@ impl IndexMeta {
     }
 }
 
+/// Index key of a NULL value. NULLs are indexed so that `IS NULL` can use an
+/// index scan, but they never conflict with each other in a unique index.
+pub const NULL_KEY: &str = "N:";
+
 /// Convert a TableDataFieldType to a lexicographically sortable string key.
 ///
 /// Integer encoding: flips the sign bit so that negative values sort before
@@ -61,7 +65,7 @@ pub fn field_to_key(field: &TableDataFieldType) -> String {
     match field {
         TableDataFieldType::Integer(v) => {
             // Flip the sign bit so negative < positive in unsigned comparison
-            let bits = (*v as i64 as u64) ^ (1u64 << 63);
+            let bits = (*v as u64) ^ (1u64 << 63);
             format!("I:{:016X}", bits)
         }
         TableDataFieldType::Float(v) => {
@@ -79,6 +83,6 @@ pub fn field_to_key(field: &TableDataFieldType) -> String {
         TableDataFieldType::Boolean(v) => format!("B:{}", if *v { 1 } else { 0 }),
         TableDataFieldType::String(v) => format!("S:{}", v),
         TableDataFieldType::Array(_) => format!("A:{}", field.to_string()),
-        TableDataFieldType::Null => "N:".to_string(),
+        TableDataFieldType::Null => NULL_KEY.to_string(),
     }
 }
@@ -44,9 +44,9 @@ use std::path::Path;
 use crate::errors;
 use crate::errors::execute_error::ExecuteError;
 
-use super::IndexEntry;
 use super::page::{INDEX_PAGE_SIZE, InternalPage, LeafEntry, LeafPage, Page, PageId};
 use super::page_store::PageStore;
+use super::{IndexEntry, NULL_KEY};
 
 /// A boxed, pinned, `Send` future -- needed because `insert_into_subtree`
 /// and `push_tail_into_overflow` recurse through `async fn`, which `rustc`
@@ -224,7 +224,7 @@ impl PageBackedBTreeIndex {
         Box::pin(async move {
             match self.store.read_page(page_id).await? {
                 Page::Leaf(mut leaf) => {
-                    if self.is_unique {
+                    if self.is_unique && key != NULL_KEY {
                         let exists_elsewhere = leaf.entries.iter().any(|e| e.key == key);
                         let exists_in_overflow = if let Some(overflow_id) = leaf.overflow {
                             self.overflow_contains_key(overflow_id, &key).await?
@@ -889,7 +889,11 @@ impl PageBackedBTreeIndex {
             )));
         }
 
-        if self.is_unique && old_key != new_key && self.get_one(&new_key).await?.is_some() {
+        if self.is_unique
+            && new_key != NULL_KEY
+            && old_key != new_key
+            && self.get_one(&new_key).await?.is_some()
+        {
             return Err(ExecuteError::wrap(format!(
                 "unique index violation on column '{}': key '{}' already exists",
                 self.column_name, new_key
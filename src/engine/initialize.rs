@@ -218,6 +218,7 @@ mod tests {
     use crate::engine::index::manager::IndexManager;
     use crate::engine::optimizer::statistics::StatisticsManager;
     use crate::engine::row_buffer::RowBufferPool;
+    use crate::engine::transaction::TransactionManager;
 
     fn build_test_engine(
         config: Arc<LaunchConfig>,
@@ -232,10 +233,13 @@ mod tests {
             command_runner,
             table_config_cache: Arc::new(RwLock::new(std::collections::HashMap::new())),
             row_storage_lock: Arc::new(Mutex::new(())),
-            index_manager: Arc::new(IndexManager::new(data_directory)),
+            index_manager: Arc::new(IndexManager::new(data_directory.clone())),
             statistics_manager: Arc::new(StatisticsManager::new()),
             indices_loaded: Arc::new(OnceCell::new()),
             row_buffer_pool: Arc::new(Mutex::new(RowBufferPool::default())),
+            common_tables: Arc::new(RwLock::new(std::collections::HashMap::new())),
+            function_config_cache: Arc::new(RwLock::new(std::collections::HashMap::new())),
+            transaction_manager: Arc::new(TransactionManager::new(data_directory)),
         }
     }
 
@@ -262,6 +266,8 @@ wal_enabled = true
 wal_directory = "/var/lib/rrdb/wal"
 wal_segment_size = 16777216
 wal_extension = "log"
+aggregate_memory_limit = 67108864
+transaction_memory_limit = 268435456
 "##;
 
         struct TestCase {
@@ -272,7 +278,7 @@ wal_extension = "log"
             mock_config: Box<dyn Fn() -> Arc<LaunchConfig>>,
         }
 
-        let test_cases = vec![
+        let test_cases = [
             TestCase {
                 name: "init 정상 동작 (linux)",
                 want_error: false,
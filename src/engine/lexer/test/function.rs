@@ -0,0 +1,85 @@
+//! CREATE FUNCTION 본문에 쓰이는 달러 인용 문자열과 위치 매개변수 토큰 테스트.
+#[cfg(test)]
+use crate::engine::lexer::predule::{OperatorToken, Token, Tokenizer};
+
+#[test]
+pub fn create_function_with_dollar_quoted_body() {
+    let text =
+        "CREATE OR REPLACE FUNCTION add_one(a int) RETURNS int AS $$ SELECT a + 1 $$;".to_owned();
+
+    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Create,
+            Token::Or,
+            Token::Identifier("REPLACE".to_owned()),
+            Token::Function,
+            Token::Identifier("add_one".to_owned()),
+            Token::LeftParentheses,
+            Token::Identifier("a".to_owned()),
+            Token::Identifier("int".to_owned()),
+            Token::RightParentheses,
+            Token::Returns,
+            Token::Identifier("int".to_owned()),
+            Token::As,
+            Token::String(" SELECT a + 1 ".to_owned()),
+            Token::SemiColon,
+        ]
+    );
+}
+
+#[test]
+pub fn dollar_quoted_string_and_positional_parameter() {
+    struct TestCase {
+        name: String,
+        input: String,
+        expected: Vec<Token>,
+    }
+
+    let test_cases = vec![
+        TestCase {
+            name: "안쪽의 따옴표는 그대로 둡니다".to_owned(),
+            input: "$$it's $1$$".to_owned(),
+            expected: vec![Token::String("it's $1".to_owned())],
+        },
+        TestCase {
+            name: "태그가 있는 달러 인용".to_owned(),
+            input: "$body$ SELECT $$x$$ $body$".to_owned(),
+            expected: vec![Token::String(" SELECT $$x$$ ".to_owned())],
+        },
+        TestCase {
+            name: "빈 문자열".to_owned(),
+            input: "$$$$".to_owned(),
+            expected: vec![Token::String("".to_owned())],
+        },
+        TestCase {
+            name: "위치 매개변수".to_owned(),
+            input: "$1 * $12".to_owned(),
+            expected: vec![
+                Token::Identifier("$1".to_owned()),
+                Token::Operator(OperatorToken::Asterisk),
+                Token::Identifier("$12".to_owned()),
+            ],
+        },
+        TestCase {
+            name: "입력 끝의 위치 매개변수".to_owned(),
+            input: "$2".to_owned(),
+            expected: vec![Token::Identifier("$2".to_owned())],
+        },
+    ];
+
+    for t in test_cases {
+        let got = Tokenizer::string_to_tokens(t.input).unwrap();
+
+        assert_eq!(got, t.expected, "TC: {}", t.name);
+    }
+
+    for input in ["$$ unterminated", "$tag$ x $other$", "$", "$ 1"] {
+        assert!(
+            Tokenizer::string_to_tokens(input.to_owned()).is_err(),
+            "{input}"
+        );
+    }
+}
@@ -1,3 +1,4 @@
 pub(crate) mod comment;
 pub(crate) mod eof;
+pub(crate) mod function;
 pub(crate) mod select;
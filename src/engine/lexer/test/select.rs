@@ -412,6 +412,29 @@ pub fn select_from_where_1() {
     );
 }
 
+#[test]
+pub fn select_from_where_like_escape() {
+    let text = r#"SELECT name from person where name ilike 'a!%%' escape '!'"#.to_owned();
+
+    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Select,
+            Token::Identifier("name".to_owned()),
+            Token::From,
+            Token::Identifier("person".to_owned()),
+            Token::Where,
+            Token::Identifier("name".to_owned()),
+            Token::ILike,
+            Token::String("a!%%".to_owned()),
+            Token::Escape,
+            Token::String("!".to_owned()),
+        ]
+    );
+}
+
 // #[test]
 // pub fn inner_join() {
 //     let text = r#"
@@ -452,3 +475,154 @@ fn test_command_query() {
         vec![Token::Backslash, Token::Identifier("l".to_owned()),]
     );
 }
+
+#[test]
+pub fn select_set_operations() {
+    let text =
+        r#"SELECT a from t UNION ALL SELECT a from u intersect SELECT a from v except select 1"#
+            .to_owned();
+
+    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Select,
+            Token::Identifier("a".to_owned()),
+            Token::From,
+            Token::Identifier("t".to_owned()),
+            Token::Union,
+            Token::All,
+            Token::Select,
+            Token::Identifier("a".to_owned()),
+            Token::From,
+            Token::Identifier("u".to_owned()),
+            Token::Intersect,
+            Token::Select,
+            Token::Identifier("a".to_owned()),
+            Token::From,
+            Token::Identifier("v".to_owned()),
+            Token::Except,
+            Token::Select,
+            Token::Integer(1),
+        ]
+    );
+}
+
+#[test]
+pub fn select_with_recursive() {
+    let text = r#"WITH RECURSIVE t(n) AS (SELECT 1) SELECT n FROM t"#.to_owned();
+
+    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+
+    assert_eq!(
+        tokens,
+        vec![
+            Token::With,
+            Token::Recursive,
+            Token::Identifier("t".to_owned()),
+            Token::LeftParentheses,
+            Token::Identifier("n".to_owned()),
+            Token::RightParentheses,
+            Token::As,
+            Token::LeftParentheses,
+            Token::Select,
+            Token::Integer(1),
+            Token::RightParentheses,
+            Token::Select,
+            Token::Identifier("n".to_owned()),
+            Token::From,
+            Token::Identifier("t".to_owned()),
+        ]
+    );
+}
+
+#[test]
+pub fn select_window_function() {
+    let text = r#"SELECT rank() OVER (PARTITION BY a ORDER BY b ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW), sum(b) over (range between 1 preceding and unbounded following) FROM t"#.to_owned();
+
+    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Select,
+            Token::Identifier("rank".to_owned()),
+            Token::LeftParentheses,
+            Token::RightParentheses,
+            Token::Over,
+            Token::LeftParentheses,
+            Token::Partition,
+            Token::By,
+            Token::Identifier("a".to_owned()),
+            Token::Order,
+            Token::By,
+            Token::Identifier("b".to_owned()),
+            Token::Rows,
+            Token::Between,
+            Token::Unbounded,
+            Token::Preceding,
+            Token::And,
+            Token::Current,
+            Token::Row,
+            Token::RightParentheses,
+            Token::Comma,
+            Token::Identifier("sum".to_owned()),
+            Token::LeftParentheses,
+            Token::Identifier("b".to_owned()),
+            Token::RightParentheses,
+            Token::Over,
+            Token::LeftParentheses,
+            Token::Range,
+            Token::Between,
+            Token::Integer(1),
+            Token::Preceding,
+            Token::And,
+            Token::Unbounded,
+            Token::Following,
+            Token::RightParentheses,
+            Token::From,
+            Token::Identifier("t".to_owned()),
+        ]
+    );
+}
+
+#[test]
+pub fn select_case_and_cast() {
+    let text =
+        r#"SELECT CASE a WHEN 1 THEN 'x' ELSE 'y' END, CAST(b AS INT), c::text FROM t"#.to_owned();
+
+    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Select,
+            Token::Case,
+            Token::Identifier("a".to_owned()),
+            Token::When,
+            Token::Integer(1),
+            Token::Then,
+            Token::String("x".to_owned()),
+            Token::Else,
+            Token::String("y".to_owned()),
+            Token::End,
+            Token::Comma,
+            Token::Cast,
+            Token::LeftParentheses,
+            Token::Identifier("b".to_owned()),
+            Token::As,
+            Token::Identifier("INT".to_owned()),
+            Token::RightParentheses,
+            Token::Comma,
+            Token::Identifier("c".to_owned()),
+            Token::DoubleColon,
+            Token::Identifier("text".to_owned()),
+            Token::From,
+            Token::Identifier("t".to_owned()),
+        ]
+    );
+
+    // 콜론 하나는 토큰이 아닙니다.
+    assert!(Tokenizer::string_to_tokens("SELECT a:b".to_owned()).is_err());
+}
@@ -59,6 +59,14 @@ impl Tokenizer {
         self.last_char == '.'
     }
 
+    pub fn is_colon(&self) -> bool {
+        self.last_char == ':'
+    }
+
+    pub fn is_dollar(&self) -> bool {
+        self.last_char == '$'
+    }
+
     pub fn is_backtick(&self) -> bool {
         self.last_char == '`'
     }
@@ -159,6 +167,7 @@ impl Tokenizer {
                 "CREATE" => Token::Create,
                 "ALTER" => Token::Alter,
                 "DROP" => Token::Drop,
+                "TRUNCATE" => Token::Truncate,
                 "DATABASE" => Token::Database,
                 "TABLE" => Token::Table,
                 "COLUMN" => Token::Column,
@@ -179,8 +188,11 @@ impl Tokenizer {
                 "NOT" => Token::Not,
                 "BETWEEN" => Token::Between,
                 "LIKE" => Token::Like,
+                "ILIKE" => Token::ILike,
+                "ESCAPE" => Token::Escape,
                 "IN" => Token::In,
                 "IS" => Token::Is,
+                "DISTINCT" => Token::Distinct,
                 "TRUE" => Token::Boolean(true),
                 "FALSE" => Token::Boolean(false),
                 "NULL" => Token::Null,
@@ -194,11 +206,38 @@ impl Tokenizer {
                 "NULLS" => Token::Nulls,
                 "FIRST" => Token::First,
                 "LAST" => Token::Last,
+                "UNION" => Token::Union,
+                "INTERSECT" => Token::Intersect,
+                "EXCEPT" => Token::Except,
+                "WITH" => Token::With,
+                "RECURSIVE" => Token::Recursive,
+                "OVER" => Token::Over,
+                "PARTITION" => Token::Partition,
+                "ROWS" => Token::Rows,
+                "RANGE" => Token::Range,
+                "UNBOUNDED" => Token::Unbounded,
+                "PRECEDING" => Token::Preceding,
+                "FOLLOWING" => Token::Following,
+                "CURRENT" => Token::Current,
+                "ROW" => Token::Row,
+                "CASE" => Token::Case,
+                "WHEN" => Token::When,
+                "THEN" => Token::Then,
+                "ELSE" => Token::Else,
+                "END" => Token::End,
+                "CAST" => Token::Cast,
+                "RETURNING" => Token::Returning,
+                "USING" => Token::Using,
+                "FOR" => Token::For,
                 "BEGIN" => Token::Begin,
                 "TRANSACTION" => Token::Transaction,
                 "COMMIT" => Token::Commit,
                 "ALL" => Token::All,
                 "ROLLBACK" => Token::Rollback,
+                "SAVEPOINT" => Token::Savepoint,
+                "RELEASE" => Token::Release,
+                "FUNCTION" => Token::Function,
+                "RETURNS" => Token::Returns,
                 _ => Token::Identifier(identifier),
             };
 
@@ -441,6 +480,59 @@ impl Tokenizer {
 
             Token::Identifier(string)
         }
+        // $1 형태의 위치 매개변수, 혹은 $$...$$, $tag$...$tag$ 형태의 달러 인용 문자열
+        else if self.is_dollar() {
+            self.read_char();
+
+            if self.is_digit() {
+                let mut parameter = vec!['$'];
+
+                while self.is_digit() {
+                    parameter.push(self.last_char);
+                    self.read_char();
+                }
+
+                self.unread_char();
+
+                Token::Identifier(parameter.into_iter().collect::<String>())
+            } else {
+                let mut tag = vec!['$'];
+
+                while self.is_alphabet_or_number() || self.is_underscore() {
+                    tag.push(self.last_char);
+                    self.read_char();
+                }
+
+                if !self.is_dollar() {
+                    return Err(LexingError::wrap(format!(
+                        "unexpected character: {:?}",
+                        '$'
+                    )));
+                }
+
+                tag.push('$');
+
+                // 같은 태그가 다시 나올 때까지가 문자열입니다. 안쪽의 따옴표는 이스케이프하지 않습니다.
+                let start = self.buffer_index;
+                let Some(length) = self.buffer[start..]
+                    .windows(tag.len())
+                    .position(|window| window == tag.as_slice())
+                else {
+                    return Err(LexingError::wrap(format!(
+                        "unterminated dollar-quoted string: missing closing '{}'",
+                        tag.iter().collect::<String>()
+                    )));
+                };
+
+                self.buffer_index = start + length + tag.len();
+
+                Token::String(
+                    self.buffer[start..start + length]
+                        .iter()
+                        .collect::<String>(),
+                )
+            }
+        }
         // 세미콜론
         else if self.is_semicolon() {
             Token::SemiColon
@@ -449,6 +541,21 @@ impl Tokenizer {
         else if self.is_dot() {
             Token::Period
         }
+        // 타입 변환 연산자 (::)
+        else if self.is_colon() {
+            self.read_char();
+
+            if self.last_char == ':' {
+                Token::DoubleColon
+            } else {
+                self.unread_char();
+
+                return Err(LexingError::wrap(format!(
+                    "unexpected character: {:?}",
+                    ':'
+                )));
+            }
+        }
         // 괄호
         else if self.is_parentheses() {
             if self.last_char == '(' {
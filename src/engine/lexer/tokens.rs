@@ -38,11 +38,35 @@ pub enum Token {
     Nulls,
     First,
     Last,
+    Union,
+    Intersect,
+    Except,
+    With,
+    Recursive,
+    Over,
+    Partition,
+    Rows,
+    Range,
+    Unbounded,
+    Preceding,
+    Following,
+    Current,
+    Row,
+    Case,
+    When,
+    Then,
+    Else,
+    End,
+    Cast,
+    Returning,
+    Using,
+    For,
 
     // DDL
     Create,
     Alter,
     Drop,
+    Truncate,
     Database,
     Table,
     Column,
@@ -63,12 +87,16 @@ pub enum Token {
     Type,
     Default,
     Data,
+    Function,
+    Returns,
 
     // TCL
     Begin,
     Transaction,
     Commit,
     Rollback,
+    Savepoint,
+    Release,
 
     // ETC
     // Analyze,
@@ -80,8 +108,11 @@ pub enum Token {
     Not,
     Between,
     Like,
+    ILike,
+    Escape,
     In,
     Is,
+    Distinct,
 
     // primary expression
     Identifier(String),
@@ -103,6 +134,7 @@ pub enum Token {
     LeftParentheses,
     RightParentheses,
     Backslash,
+    DoubleColon, // ::
 
     // exception handling
     EOF,
@@ -143,6 +175,7 @@ impl Token {
         match self {
             Token::Not => match second_token {
                 Token::Like => Ok(BinaryOperator::NotLike),
+                Token::ILike => Ok(BinaryOperator::NotILike),
                 Token::In => Ok(BinaryOperator::NotIn),
                 _ => Err(Errors::new(ErrorKind::IntoError(
                     "BinaryOperator Cast Error".to_string(),
@@ -158,6 +191,43 @@ impl Token {
         }
     }
 
+    // PostgreSQL의 non-reserved keyword처럼, 식별자 자리에서는 식별자로 읽는 키워드
+    // (create table t(range int), select rows from t 등)
+    fn unreserved_keyword_name(&self) -> Option<&'static str> {
+        match self {
+            Token::Partition => Some("partition"),
+            Token::Rows => Some("rows"),
+            Token::Range => Some("range"),
+            Token::Unbounded => Some("unbounded"),
+            Token::Preceding => Some("preceding"),
+            Token::Following => Some("following"),
+            Token::Current => Some("current"),
+            Token::Row => Some("row"),
+            // PostgreSQL에서는 예약어지만, CASE 밖의 END는 식별자로 읽습니다.
+            Token::End => Some("end"),
+            Token::Function => Some("function"),
+            Token::Returns => Some("returns"),
+            Token::Savepoint => Some("savepoint"),
+            Token::Release => Some("release"),
+            Token::Escape => Some("escape"),
+            Token::Recursive => Some("recursive"),
+            Token::Truncate => Some("truncate"),
+            _ => None,
+        }
+    }
+
+    pub fn is_unreserved_keyword(&self) -> bool {
+        self.unreserved_keyword_name().is_some()
+    }
+
+    // 식별자 자리의 토큰을 읽습니다. non-reserved 키워드는 식별자로 바꾸고, 나머지는 그대로 둡니다.
+    pub fn into_identifier(self) -> Token {
+        match self.unreserved_keyword_name() {
+            Some(name) => Token::Identifier(name.to_string()),
+            None => self,
+        }
+    }
+
     pub fn is_expression(&self) -> bool {
         match self {
             Token::Identifier(_)
@@ -167,9 +237,11 @@ impl Token {
             | Token::String(_)
             | Token::Null
             | Token::LeftParentheses
+            | Token::Case
+            | Token::Cast
             | Token::Not => true,
             Token::Operator(operator) => operator.is_unary_operator(),
-            _ => false,
+            _ => self.is_unreserved_keyword(),
         }
     }
 }
@@ -183,6 +255,7 @@ impl TryInto<BinaryOperator> for Token {
             Token::And => Ok(BinaryOperator::And),
             Token::Or => Ok(BinaryOperator::Or),
             Token::Like => Ok(BinaryOperator::Like),
+            Token::ILike => Ok(BinaryOperator::ILike),
             Token::In => Ok(BinaryOperator::In),
             Token::Is => Ok(BinaryOperator::Is),
             _ => Err(Errors::new(ErrorKind::IntoError(
@@ -242,6 +315,13 @@ mod tests {
                 expected: BinaryOperator::NotLike,
                 want_error: false,
             },
+            TestCase {
+                name: "NOT ILIKE".into(),
+                first: Token::Not,
+                second: Token::ILike,
+                expected: BinaryOperator::NotILike,
+                want_error: false,
+            },
             TestCase {
                 name: "NOT IN".into(),
                 first: Token::Not,
@@ -353,11 +433,26 @@ mod tests {
                 input: Token::Not,
                 expected: true,
             },
+            TestCase {
+                name: "Case".into(),
+                input: Token::Case,
+                expected: true,
+            },
+            TestCase {
+                name: "Cast".into(),
+                input: Token::Cast,
+                expected: true,
+            },
             TestCase {
                 name: "Operator".into(),
                 input: Token::Operator(OperatorToken::Plus),
                 expected: true,
             },
+            TestCase {
+                name: "Range".into(),
+                input: Token::Range,
+                expected: true,
+            },
             TestCase {
                 name: "And".into(),
                 input: Token::And,
@@ -444,6 +539,12 @@ mod tests {
                 expected: BinaryOperator::Like,
                 want_error: false,
             },
+            TestCase {
+                name: "ILIKE".into(),
+                input: Token::ILike,
+                expected: BinaryOperator::ILike,
+                want_error: false,
+            },
             TestCase {
                 name: "IN".into(),
                 input: Token::In,
@@ -0,0 +1,604 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::{Mutex as StdMutex, MutexGuard, PoisonError};
+
+use tokio::sync::Notify;
+
+use crate::engine::actions::dml::scan::RowLocation;
+use crate::engine::ast::dml::parts::locking::{LockWaitPolicy, RowLockMode};
+use crate::engine::ast::types::TableName;
+use crate::errors;
+use crate::errors::execute_error::ExecuteError;
+
+type RowLockKey = (TableName, RowLocation);
+
+/// 행/테이블 단위 락 관리자.
+///
+/// - 행 락: SELECT ... FOR UPDATE / FOR SHARE, UPDATE, DELETE가 대상 행에 잡습니다.
+/// - 테이블 락: TRUNCATE가 잡습니다. 다른 트랜잭션이 그 테이블의 행 락을 쥐고 있으면
+///   기다리고, 테이블 락을 쥔 동안에는 다른 트랜잭션이 행 락을 잡지 못합니다.
+/// - 쓰기 락: 행을 바꾸는 명령이 대상 테이블마다 잡습니다. 한 테이블에는 한 트랜잭션만
+///   쓸 수 있고, 다른 테이블에 쓰는 트랜잭션은 서로 기다리지 않습니다.
+///
+/// 락은 트랜잭션이 끝날 때(`release_all`)까지 유지됩니다. 트랜잭션 블록 밖의 SELECT는
+/// transaction id가 없으므로, 다른 트랜잭션의 락만 확인하고 자신은 락을 남기지 않습니다.
+/// 세 가지 락을 기다리는 트랜잭션은 모두 같은 대기 그래프에 들어가므로, 서로 얽힌
+/// 교착 상태도 찾아냅니다.
+#[derive(Default)]
+pub(crate) struct LockManager {
+    state: StdMutex<LockState>,
+    /// 락이 풀리면 기다리던 명령을 모두 깨웁니다.
+    released: Notify,
+}
+
+#[derive(Default)]
+struct LockState {
+    /// 행마다 락을 쥔 트랜잭션과 그 모드
+    rows: HashMap<RowLockKey, HashMap<u64, RowLockMode>>,
+    /// 테이블 락(TRUNCATE)을 쥔 트랜잭션
+    tables: HashMap<TableName, u64>,
+    /// 트랜잭션마다 쥔 행 락
+    held: HashMap<u64, HashSet<RowLockKey>>,
+    /// 테이블마다 쓰기 락을 쥔 트랜잭션
+    writers: HashMap<TableName, u64>,
+    /// 락을 기다리는 트랜잭션 -> 그 락과 충돌하는 락을 쥔 트랜잭션
+    waits_for: HashMap<u64, HashSet<u64>>,
+}
+
+impl LockState {
+    fn conflicting_holders(
+        &self,
+        key: &RowLockKey,
+        transaction_id: Option<u64>,
+        mode: RowLockMode,
+    ) -> HashSet<u64> {
+        self.rows
+            .get(key)
+            .into_iter()
+            .flatten()
+            .filter(|(holder, held_mode)| {
+                Some(**holder) != transaction_id && mode.conflicts_with(**held_mode)
+            })
+            .map(|(holder, _)| *holder)
+            .chain(self.table_holder(&key.0, transaction_id))
+            .collect()
+    }
+
+    /// 테이블 락은 그 테이블의 모든 행 락과 충돌합니다.
+    fn conflicting_table_holders(
+        &self,
+        table_name: &TableName,
+        transaction_id: u64,
+    ) -> HashSet<u64> {
+        self.rows
+            .iter()
+            .filter(|((table, _), _)| table == table_name)
+            .flat_map(|(_, holders)| holders.keys())
+            .copied()
+            .filter(|holder| *holder != transaction_id)
+            .chain(self.table_holder(table_name, Some(transaction_id)))
+            .chain(self.writer_holder(table_name, transaction_id))
+            .collect()
+    }
+
+    /// 쓰기 락은 다른 트랜잭션의 쓰기 락, 테이블 락과 충돌합니다. 행 락은 쓰기 명령이
+    /// 행마다 따로 확인합니다.
+    fn conflicting_writer_holders(
+        &self,
+        table_name: &TableName,
+        transaction_id: u64,
+    ) -> HashSet<u64> {
+        self.writer_holder(table_name, transaction_id)
+            .into_iter()
+            .chain(self.table_holder(table_name, Some(transaction_id)))
+            .collect()
+    }
+
+    fn writer_holder(&self, table_name: &TableName, transaction_id: u64) -> Option<u64> {
+        self.writers
+            .get(table_name)
+            .copied()
+            .filter(|holder| *holder != transaction_id)
+    }
+
+    fn table_holder(&self, table_name: &TableName, transaction_id: Option<u64>) -> Option<u64> {
+        self.tables
+            .get(table_name)
+            .copied()
+            .filter(|holder| Some(*holder) != transaction_id)
+    }
+
+    /// `transaction_id`가 기다리는 트랜잭션을 따라가다 자신으로 돌아오면 교착 상태입니다.
+    fn has_deadlock(&self, transaction_id: u64) -> bool {
+        let mut visited = HashSet::new();
+        let mut stack = self.waiting_on(transaction_id);
+
+        while let Some(current) = stack.pop() {
+            if current == transaction_id {
+                return true;
+            }
+            if visited.insert(current) {
+                stack.extend(self.waiting_on(current));
+            }
+        }
+
+        false
+    }
+
+    fn waiting_on(&self, transaction_id: u64) -> Vec<u64> {
+        self.waits_for
+            .get(&transaction_id)
+            .into_iter()
+            .flatten()
+            .copied()
+            .collect()
+    }
+}
+
+impl LockManager {
+    /// 행을 잠급니다. SKIP LOCKED로 건너뛴 행이면 false를 반환합니다.
+    pub(crate) async fn lock_row(
+        &self,
+        transaction_id: Option<u64>,
+        table_name: &TableName,
+        location: &RowLocation,
+        mode: RowLockMode,
+        wait_policy: LockWaitPolicy,
+    ) -> errors::Result<bool> {
+        let key = (table_name.clone(), location.clone());
+        let _wait = LockWait {
+            manager: self,
+            transaction_id,
+        };
+
+        loop {
+            // 락을 확인하기 전에 등록해 두어야 그 사이에 풀린 락의 알림을 놓치지 않습니다.
+            let released = self.released.notified();
+            tokio::pin!(released);
+            released.as_mut().enable();
+
+            {
+                let mut state = self.lock_state();
+                let holders = state.conflicting_holders(&key, transaction_id, mode);
+
+                if holders.is_empty() {
+                    if let Some(transaction_id) = transaction_id {
+                        let held_mode = state
+                            .rows
+                            .entry(key.clone())
+                            .or_default()
+                            .entry(transaction_id)
+                            .or_insert(mode);
+                        *held_mode = (*held_mode).max(mode);
+                        state.held.entry(transaction_id).or_default().insert(key);
+                    }
+                    return Ok(true);
+                }
+
+                match wait_policy {
+                    LockWaitPolicy::SkipLocked => return Ok(false),
+                    LockWaitPolicy::NoWait => {
+                        return Err(ExecuteError::wrap(format!(
+                            "could not obtain lock on row in relation \"{}\"",
+                            table_name.table_name
+                        )));
+                    }
+                    LockWaitPolicy::Wait => {}
+                }
+
+                if let Some(transaction_id) = transaction_id {
+                    state.waits_for.insert(transaction_id, holders);
+                    if state.has_deadlock(transaction_id) {
+                        return Err(deadlock_error());
+                    }
+                }
+            }
+
+            released.await;
+        }
+    }
+
+    /// 테이블을 잠급니다 (TRUNCATE). 다른 트랜잭션이 그 테이블의 행 락이나 테이블 락을
+    /// 쥐고 있으면 그 트랜잭션이 끝날 때까지 기다립니다.
+    pub(crate) async fn lock_table(
+        &self,
+        transaction_id: u64,
+        table_name: &TableName,
+    ) -> errors::Result<()> {
+        let _wait = LockWait {
+            manager: self,
+            transaction_id: Some(transaction_id),
+        };
+
+        loop {
+            let released = self.released.notified();
+            tokio::pin!(released);
+            released.as_mut().enable();
+
+            {
+                let mut state = self.lock_state();
+                let holders = state.conflicting_table_holders(table_name, transaction_id);
+
+                if holders.is_empty() {
+                    state.tables.insert(table_name.clone(), transaction_id);
+                    return Ok(());
+                }
+
+                state.waits_for.insert(transaction_id, holders);
+                if state.has_deadlock(transaction_id) {
+                    return Err(deadlock_error());
+                }
+            }
+
+            released.await;
+        }
+    }
+
+    /// 테이블에 쓰기 락을 잡습니다. 다른 트랜잭션이 그 테이블에 쓰고 있거나 TRUNCATE로
+    /// 잠갔으면 그 트랜잭션이 끝날 때까지 기다립니다.
+    pub(crate) async fn lock_table_for_write(
+        &self,
+        transaction_id: u64,
+        table_name: &TableName,
+    ) -> errors::Result<()> {
+        let _wait = LockWait {
+            manager: self,
+            transaction_id: Some(transaction_id),
+        };
+
+        loop {
+            let released = self.released.notified();
+            tokio::pin!(released);
+            released.as_mut().enable();
+
+            {
+                let mut state = self.lock_state();
+                let holders = state.conflicting_writer_holders(table_name, transaction_id);
+
+                if holders.is_empty() {
+                    state.writers.insert(table_name.clone(), transaction_id);
+                    return Ok(());
+                }
+
+                state.waits_for.insert(transaction_id, holders);
+                if state.has_deadlock(transaction_id) {
+                    return Err(deadlock_error());
+                }
+            }
+
+            released.await;
+        }
+    }
+
+    /// 트랜잭션이 쥔 행 락, 테이블 락, 쓰기 락을 모두 풀고 기다리던 명령을 깨웁니다.
+    pub(crate) fn release_all(&self, transaction_id: u64) {
+        {
+            let mut state = self.lock_state();
+
+            for key in state.held.remove(&transaction_id).unwrap_or_default() {
+                if let Some(holders) = state.rows.get_mut(&key) {
+                    holders.remove(&transaction_id);
+                    if holders.is_empty() {
+                        state.rows.remove(&key);
+                    }
+                }
+            }
+
+            state.tables.retain(|_, holder| *holder != transaction_id);
+            state.writers.retain(|_, holder| *holder != transaction_id);
+        }
+
+        self.released.notify_waiters();
+    }
+
+    #[cfg(test)]
+    fn held_count(&self, transaction_id: u64) -> usize {
+        self.lock_state()
+            .held
+            .get(&transaction_id)
+            .map_or(0, HashSet::len)
+    }
+
+    fn lock_state(&self) -> MutexGuard<'_, LockState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// 락을 기다리는 동안 대기 그래프에 남긴 간선. 락을 얻거나 포기하면 지웁니다.
+struct LockWait<'a> {
+    manager: &'a LockManager,
+    transaction_id: Option<u64>,
+}
+
+impl Drop for LockWait<'_> {
+    fn drop(&mut self) {
+        if let Some(transaction_id) = self.transaction_id {
+            self.manager.lock_state().waits_for.remove(&transaction_id);
+        }
+    }
+}
+
+fn deadlock_error() -> errors::Errors {
+    ExecuteError::wrap("deadlock detected".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use super::*;
+
+    fn jobs() -> TableName {
+        TableName::new(Some("rrdb".to_string()), "jobs".to_string())
+    }
+
+    fn row(row_index: usize) -> RowLocation {
+        RowLocation { row_index }
+    }
+
+    #[tokio::test]
+    async fn conflicting_lock_waits_until_release() {
+        let manager = Arc::new(LockManager::default());
+        let table_name = jobs();
+
+        assert!(
+            manager
+                .lock_row(
+                    Some(1),
+                    &table_name,
+                    &row(0),
+                    RowLockMode::Share,
+                    LockWaitPolicy::Wait
+                )
+                .await
+                .unwrap()
+        );
+        // 공유 락끼리는 함께 잡을 수 있고, 자기 락을 더 강하게 다시 잡는 것도 막지 않습니다.
+        assert!(
+            manager
+                .lock_row(
+                    Some(2),
+                    &table_name,
+                    &row(0),
+                    RowLockMode::Share,
+                    LockWaitPolicy::NoWait
+                )
+                .await
+                .unwrap()
+        );
+        assert!(
+            manager
+                .lock_row(
+                    Some(1),
+                    &table_name,
+                    &row(1),
+                    RowLockMode::Update,
+                    LockWaitPolicy::NoWait
+                )
+                .await
+                .unwrap()
+        );
+
+        assert!(
+            !manager
+                .lock_row(
+                    Some(3),
+                    &table_name,
+                    &row(0),
+                    RowLockMode::NoKeyUpdate,
+                    LockWaitPolicy::SkipLocked
+                )
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            manager
+                .lock_row(
+                    None,
+                    &table_name,
+                    &row(1),
+                    RowLockMode::Share,
+                    LockWaitPolicy::NoWait
+                )
+                .await
+                .unwrap_err()
+                .to_string(),
+            "could not obtain lock on row in relation \"jobs\""
+        );
+
+        let waiter = tokio::spawn({
+            let manager = manager.clone();
+            async move {
+                manager
+                    .lock_row(
+                        Some(3),
+                        &jobs(),
+                        &row(0),
+                        RowLockMode::Update,
+                        LockWaitPolicy::Wait,
+                    )
+                    .await
+            }
+        });
+
+        manager.release_all(1);
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!waiter.is_finished());
+        assert_eq!(manager.held_count(1), 0);
+
+        manager.release_all(2);
+        assert!(waiter.await.unwrap().unwrap());
+        assert_eq!(manager.held_count(3), 1);
+    }
+
+    #[tokio::test]
+    async fn waiting_in_a_cycle_is_a_deadlock() {
+        let manager = Arc::new(LockManager::default());
+        let table_name = jobs();
+
+        for (transaction_id, row_index) in [(1, 0), (2, 1)] {
+            manager
+                .lock_row(
+                    Some(transaction_id),
+                    &table_name,
+                    &row(row_index),
+                    RowLockMode::Update,
+                    LockWaitPolicy::Wait,
+                )
+                .await
+                .unwrap();
+        }
+
+        let waiter = tokio::spawn({
+            let manager = manager.clone();
+            async move {
+                manager
+                    .lock_row(
+                        Some(1),
+                        &jobs(),
+                        &row(1),
+                        RowLockMode::Update,
+                        LockWaitPolicy::Wait,
+                    )
+                    .await
+            }
+        });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        assert_eq!(
+            manager
+                .lock_row(
+                    Some(2),
+                    &table_name,
+                    &row(0),
+                    RowLockMode::Update,
+                    LockWaitPolicy::Wait
+                )
+                .await
+                .unwrap_err()
+                .to_string(),
+            "deadlock detected"
+        );
+
+        manager.release_all(2);
+        assert!(waiter.await.unwrap().unwrap());
+
+        // 쓰기 락을 쥔 트랜잭션 2가 트랜잭션 1의 행 락을 기다리는 동안, 트랜잭션 1이
+        // 같은 테이블의 쓰기 락을 기다리면 서로를 기다리게 됩니다.
+        manager.lock_table_for_write(2, &table_name).await.unwrap();
+        let writer = tokio::spawn({
+            let manager = manager.clone();
+            async move {
+                manager
+                    .lock_row(
+                        Some(2),
+                        &jobs(),
+                        &row(0),
+                        RowLockMode::NoKeyUpdate,
+                        LockWaitPolicy::Wait,
+                    )
+                    .await
+            }
+        });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        assert_eq!(
+            manager
+                .lock_table_for_write(1, &table_name)
+                .await
+                .unwrap_err()
+                .to_string(),
+            "deadlock detected"
+        );
+
+        manager.release_all(1);
+        assert!(writer.await.unwrap().unwrap());
+    }
+
+    #[tokio::test]
+    async fn write_lock_is_per_table() {
+        let manager = Arc::new(LockManager::default());
+        let users = TableName::new(Some("rrdb".to_string()), "users".to_string());
+
+        manager.lock_table_for_write(1, &jobs()).await.unwrap();
+        // 자기 쓰기 락은 다시 잡을 수 있고, 다른 테이블에는 바로 쓸 수 있습니다.
+        manager.lock_table_for_write(1, &jobs()).await.unwrap();
+        manager.lock_table_for_write(2, &users).await.unwrap();
+
+        let waiter = tokio::spawn({
+            let manager = manager.clone();
+            async move { manager.lock_table_for_write(2, &jobs()).await }
+        });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!waiter.is_finished());
+
+        // TRUNCATE의 테이블 락도 다른 트랜잭션의 쓰기 락을 기다립니다.
+        let truncate = tokio::spawn({
+            let manager = manager.clone();
+            async move { manager.lock_table(3, &users).await }
+        });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!truncate.is_finished());
+
+        manager.release_all(1);
+        waiter.await.unwrap().unwrap();
+
+        manager.release_all(2);
+        truncate.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn table_lock_conflicts_with_row_locks_of_other_transactions() {
+        let manager = Arc::new(LockManager::default());
+        let table_name = jobs();
+
+        for transaction_id in [1, 2] {
+            manager
+                .lock_row(
+                    Some(transaction_id),
+                    &table_name,
+                    &row(0),
+                    RowLockMode::Share,
+                    LockWaitPolicy::Wait,
+                )
+                .await
+                .unwrap();
+        }
+
+        let truncate = tokio::spawn({
+            let manager = manager.clone();
+            async move { manager.lock_table(2, &jobs()).await }
+        });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!truncate.is_finished());
+
+        // 트랜잭션 2 자신의 행 락은 막지 않습니다.
+        manager.release_all(1);
+        truncate.await.unwrap().unwrap();
+
+        // 테이블 락을 쥔 동안에는 다른 트랜잭션이 어느 행도 잠그지 못합니다.
+        assert!(
+            !manager
+                .lock_row(
+                    Some(3),
+                    &table_name,
+                    &row(5),
+                    RowLockMode::Share,
+                    LockWaitPolicy::SkipLocked
+                )
+                .await
+                .unwrap()
+        );
+
+        let other = tokio::spawn({
+            let manager = manager.clone();
+            async move { manager.lock_table(3, &jobs()).await }
+        });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!other.is_finished());
+
+        manager.release_all(2);
+        other.await.unwrap().unwrap();
+        assert_eq!(manager.held_count(2), 0);
+    }
+}
@@ -2,43 +2,55 @@ pub mod ast;
 pub mod encoder;
 pub mod index;
 pub mod lexer;
+pub mod lock;
 pub mod optimizer;
 pub mod parser;
 pub mod path_identifier;
+pub mod pattern;
 pub mod row_buffer;
 pub mod schema;
 pub mod server;
+pub mod transaction;
 pub mod wal;
 
 // DB Engine implementations
 pub mod actions;
+pub mod aggregate;
 pub mod expression;
 pub mod initialize;
+pub mod scalar;
 pub mod types;
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 use std::sync::Arc;
 
 use crate::common::command::{CommandRunner, RealCommandRunner};
 use crate::common::fs::{FileSystem, RealFileSystem};
 use crate::config::launch_config::LaunchConfig;
+use crate::engine::actions::dml::common_table::CommonTable;
 use crate::engine::ast::ddl::create_index::CreateIndexQuery;
 use crate::engine::ast::ddl::drop_index::DropIndexQuery;
+use crate::engine::ast::ddl::truncate_table::TruncateTableQuery;
 use crate::engine::ast::dml::delete::DeleteQuery;
 use crate::engine::ast::dml::insert::InsertQuery;
 use crate::engine::ast::dml::update::UpdateQuery;
-use crate::engine::ast::types::TableName;
+use crate::engine::ast::types::{TableName, UserDefinedFunction};
 use crate::engine::ast::{DDLStatement, DMLStatement, OtherStatement, SQLStatement};
 use crate::engine::encoder::schema_encoder::StorageEncoder;
 use crate::engine::index::manager::IndexManager;
 use crate::engine::optimizer::statistics::StatisticsManager;
 use crate::engine::row_buffer::RowBufferPool;
+use crate::engine::schema::function::FunctionSchema;
 use crate::engine::schema::table::TableSchema;
+use crate::engine::transaction::TransactionManager;
 use crate::engine::types::ExecuteResult;
 use crate::engine::wal::endec::implements::bincode::BincodeEncoder;
 use crate::engine::wal::manager::WALManager;
-use crate::engine::wal::types::{EntryType, InsertWALPayload, WALEntry};
+use crate::engine::wal::types::{
+    DeleteRowsWALPayload, EntryType, InsertWALPayload, SavepointWALPayload, UpdateRowsWALPayload,
+    UpsertWALPayload, WALEntry,
+};
 use crate::errors;
 use crate::errors::execute_error::ExecuteError;
 use tokio::sync::{Mutex, RwLock};
@@ -56,6 +68,10 @@ pub struct DBEngine {
     /// 디스크의 인덱스 파일을 메모리로 적재했는지 여부 (최초 사용 시 1회 적재)
     pub(crate) indices_loaded: Arc<tokio::sync::OnceCell<()>>,
     pub(crate) row_buffer_pool: Arc<Mutex<RowBufferPool>>,
+    /// 실행 중인 쿼리의 WITH 절 테이블 (바인딩 이름 -> 결과)
+    pub(crate) common_tables: Arc<RwLock<HashMap<TableName, CommonTable>>>,
+    pub(crate) function_config_cache: Arc<RwLock<HashMap<UserDefinedFunction, FunctionSchema>>>,
+    pub(crate) transaction_manager: Arc<TransactionManager>,
 }
 
 impl DBEngine {
@@ -68,10 +84,13 @@ impl DBEngine {
             command_runner: Arc::new(RealCommandRunner {}),
             table_config_cache: Arc::new(RwLock::new(HashMap::new())),
             row_storage_lock: Arc::new(Mutex::new(())),
-            index_manager: Arc::new(IndexManager::new(data_directory)),
+            index_manager: Arc::new(IndexManager::new(data_directory.clone())),
             statistics_manager: Arc::new(StatisticsManager::new()),
             indices_loaded: Arc::new(tokio::sync::OnceCell::new()),
             row_buffer_pool: Arc::new(Mutex::new(RowBufferPool::default())),
+            common_tables: Arc::new(RwLock::new(HashMap::new())),
+            function_config_cache: Arc::new(RwLock::new(HashMap::new())),
+            transaction_manager: Arc::new(TransactionManager::new(data_directory)),
         }
     }
 
@@ -80,12 +99,61 @@ impl DBEngine {
         &self,
         statement: SQLStatement,
         wal_manager: SharedWALManager,
-        _connection_id: String,
+        connection_id: String,
     ) -> errors::Result<ExecuteResult> {
         log::debug!("AST echo: {:?}", statement);
 
+        if let SQLStatement::TCL(statement) = statement {
+            return self
+                .transaction_control(statement, &connection_id, wal_manager)
+                .await
+                .map_err(|error| ExecuteError::wrap(error.to_string()));
+        }
+
+        // 스냅샷을 찍고, 쓰기 명령이면 쓰기 락을 잡습니다. 명령이 끝날 때까지 쥐고 있습니다.
+        let statement_transaction = match self
+            .begin_statement(&statement, &connection_id, wal_manager.clone())
+            .await
+        {
+            Ok(statement_transaction) => statement_transaction,
+            Err(error) => {
+                self.transaction_manager.mark_failed(&connection_id);
+                return Err(ExecuteError::wrap(error.to_string()));
+            }
+        };
+
+        // 사용자 정의 함수 호출을 해석하고, 가능한 호출은 본문을 펼쳐 넣습니다.
+        let mut statement = statement;
+        if let Err(error) = self.resolve_functions(&mut statement).await {
+            self.transaction_manager.mark_failed(&connection_id);
+            return Err(ExecuteError::wrap(error.to_string()));
+        }
+
+        // 실행 future가 커서 스택을 넘치지 않도록 힙에 둡니다.
+        let result = statement_transaction
+            .context
+            .clone()
+            .scope(Box::pin(self.execute_statement(statement, wal_manager)))
+            .await;
+        drop(statement_transaction);
+
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                // 트랜잭션 안에서 실패하면 COMMIT/ROLLBACK 전까지 다른 명령을 받지 않습니다.
+                self.transaction_manager.mark_failed(&connection_id);
+                Err(ExecuteError::wrap(error.to_string()))
+            }
+        }
+    }
+
+    async fn execute_statement(
+        &self,
+        statement: SQLStatement,
+        wal_manager: SharedWALManager,
+    ) -> errors::Result<ExecuteResult> {
         // 쿼리 실행
-        let result = match statement {
+        match statement {
             SQLStatement::DDL(DDLStatement::CreateDatabaseQuery(query)) => {
                 self.create_database(query).await
             }
@@ -102,21 +170,36 @@ impl DBEngine {
                 self.alter_table(query).await
             }
             SQLStatement::DDL(DDLStatement::DropTableQuery(query)) => self.drop_table(query).await,
+            SQLStatement::DDL(DDLStatement::TruncateTableQuery(query)) => {
+                self.truncate_table(query, wal_manager.clone()).await
+            }
             SQLStatement::DDL(DDLStatement::CreateIndexQuery(query)) => {
                 self.create_index(query, wal_manager.clone()).await
             }
             SQLStatement::DDL(DDLStatement::DropIndexQuery(query)) => {
                 self.drop_index(query, wal_manager.clone()).await
             }
-            SQLStatement::DML(DMLStatement::InsertQuery(query)) => {
-                self.insert(query, wal_manager.clone()).await
+            SQLStatement::DDL(DDLStatement::CreateFunctionQuery(query)) => {
+                self.create_function(query).await
+            }
+            SQLStatement::DDL(DDLStatement::DropFunctionQuery(query)) => {
+                self.drop_function(query).await
+            }
+            SQLStatement::DML(DMLStatement::InsertQuery(mut query)) => {
+                let with_clause = query.with_clause.take();
+                self.with_common_tables(with_clause, None, self.insert(query, wal_manager.clone()))
+                    .await
             }
             SQLStatement::DML(DMLStatement::SelectQuery(query)) => self.select(query).await,
-            SQLStatement::DML(DMLStatement::UpdateQuery(query)) => {
-                self.update(query, wal_manager.clone()).await
+            SQLStatement::DML(DMLStatement::UpdateQuery(mut query)) => {
+                let with_clause = query.with_clause.take();
+                self.with_common_tables(with_clause, None, self.update(query, wal_manager.clone()))
+                    .await
             }
-            SQLStatement::DML(DMLStatement::DeleteQuery(query)) => {
-                self.delete(query, wal_manager.clone()).await
+            SQLStatement::DML(DMLStatement::DeleteQuery(mut query)) => {
+                let with_clause = query.with_clause.take();
+                self.with_common_tables(with_clause, None, self.delete(query, wal_manager.clone()))
+                    .await
             }
             SQLStatement::Other(OtherStatement::ShowDatabases(query)) => {
                 self.show_databases(query).await
@@ -126,12 +209,7 @@ impl DBEngine {
             }
             SQLStatement::Other(OtherStatement::ShowTables(query)) => self.show_tables(query).await,
             SQLStatement::Other(OtherStatement::DescTable(query)) => self.desc_table(query).await,
-            _ => unimplemented!("no execute implementation"),
-        };
-
-        match result {
-            Ok(result) => Ok(result),
-            Err(error) => Err(ExecuteError::wrap(error.to_string())),
+            _ => Err(ExecuteError::wrap("no execute implementation".to_string())),
         }
     }
 
@@ -143,9 +221,50 @@ impl DBEngine {
     /// mutation -- would also have failed the first time around and so never
     /// actually took effect) aborts recovery so that the WAL is preserved for
     /// diagnosis and a later retry.
+    ///
+    /// Entries written inside a transaction are applied only if the log also
+    /// holds that transaction's COMMIT record. Its rows never reached the
+    /// segment files before then, but index pages are written in place, so the
+    /// indexes of tables any logged transaction touched are rebuilt from the
+    /// rows on disk first. That covers a transaction which was still open at
+    /// the last checkpoint and committed afterwards: its keys are in the index
+    /// files while its rows are not. Entries a committed transaction undid with
+    /// `ROLLBACK TO SAVEPOINT` are skipped the same way.
     pub async fn replay_wal(&self, entries: &[WALEntry]) -> errors::Result<()> {
+        let committed = Self::wal_committed_transactions(entries);
+        let rolled_back = Self::wal_rolled_back_entries(entries);
+        let applies = |index: usize, entry: &WALEntry| {
+            entry
+                .transaction_id
+                .is_none_or(|transaction_id| committed.contains(&transaction_id))
+                && !rolled_back.contains(&index)
+        };
+
+        self.rebuild_indexes_of_transactions(
+            entries
+                .iter()
+                .enumerate()
+                .filter(|(index, entry)| entry.transaction_id.is_some() || !applies(*index, entry))
+                .map(|(_, entry)| entry),
+        )
+        .await?;
+
+        // 테이블별로 마지막 TRUNCATE의 위치. 그보다 앞선 행 변경은 TRUNCATE가 어차피
+        // 지우므로 건너뜁니다. 디스크에 이미 TRUNCATE 이후의 행이 있을 수 있어서,
+        // 다시 적용하면 row index가 어긋나거나 unique 검사에 걸릴 수 있습니다.
+        let truncated_at = Self::wal_truncate_positions(entries, applies);
+
         for (index, entry) in entries.iter().enumerate() {
+            if !applies(index, entry) {
+                continue;
+            }
+
             let data = entry.data.as_deref();
+            let superseded = |table: Option<&TableName>| {
+                table
+                    .and_then(|table| truncated_at.get(table))
+                    .is_some_and(|position| *position > index)
+            };
 
             let result: errors::Result<()> = async {
                 match entry.entry_type {
@@ -154,21 +273,31 @@ impl DBEngine {
                         // 단독)으로 기록된 WAL도 그대로 읽을 수 있어야 하므로,
                         // 실패 시 이전 형식으로 폴백합니다 (#236).
                         match Self::decode_wal_payload::<InsertWALPayload>(data) {
+                            Ok(payload) if superseded(payload.query.into_table.as_ref()) => Ok(()),
                             Ok(payload) => {
                                 self.insert_replay_with_payload(payload).await.map(|_| ())
                             }
                             Err(_) => {
                                 let query = Self::decode_wal_payload::<InsertQuery>(data)?;
+                                if superseded(query.into_table.as_ref()) {
+                                    return Ok(());
+                                }
                                 self.insert_replay(query).await.map(|_| ())
                             }
                         }
                     }
                     EntryType::Set => {
                         let query = Self::decode_wal_payload::<UpdateQuery>(data)?;
+                        if superseded(query.target_table.as_ref().map(|target| &target.table)) {
+                            return Ok(());
+                        }
                         self.update_replay(query).await.map(|_| ())
                     }
                     EntryType::Delete => {
                         let query = Self::decode_wal_payload::<DeleteQuery>(data)?;
+                        if superseded(query.from_table.as_ref().map(|target| &target.table)) {
+                            return Ok(());
+                        }
                         self.delete_replay(query).await.map(|_| ())
                     }
                     EntryType::CreateIndex => {
@@ -179,6 +308,34 @@ impl DBEngine {
                         let query = Self::decode_wal_payload::<DropIndexQuery>(data)?;
                         self.drop_index_replay(query).await.map(|_| ())
                     }
+                    EntryType::Upsert => {
+                        let payload = Self::decode_wal_payload::<UpsertWALPayload>(data)?;
+                        if superseded(Some(&payload.table_name)) {
+                            return Ok(());
+                        }
+                        self.upsert_replay(payload).await
+                    }
+                    EntryType::UpdateRows => {
+                        let payload = Self::decode_wal_payload::<UpdateRowsWALPayload>(data)?;
+                        if superseded(Some(&payload.table_name)) {
+                            return Ok(());
+                        }
+                        self.update_rows_replay(payload).await
+                    }
+                    EntryType::DeleteRows => {
+                        let payload = Self::decode_wal_payload::<DeleteRowsWALPayload>(data)?;
+                        if superseded(Some(&payload.table_name)) {
+                            return Ok(());
+                        }
+                        self.delete_rows_replay(payload).await
+                    }
+                    EntryType::TruncateTable => {
+                        let query = Self::decode_wal_payload::<TruncateTableQuery>(data)?;
+                        self.truncate_table_replay(query).await
+                    }
+                    EntryType::Savepoint | EntryType::RollbackToSavepoint => {
+                        Self::decode_wal_payload::<SavepointWALPayload>(data).map(|_| ())
+                    }
                     EntryType::Checkpoint
                     | EntryType::TransactionBegin
                     | EntryType::TransactionCommit
@@ -198,6 +355,154 @@ impl DBEngine {
         Ok(())
     }
 
+    // 테이블별 마지막 TRUNCATE 엔트리의 위치. 잘못된 페이로드는 replay 루프에서 보고합니다.
+    fn wal_truncate_positions(
+        entries: &[WALEntry],
+        applies: impl Fn(usize, &WALEntry) -> bool,
+    ) -> HashMap<TableName, usize> {
+        let mut positions = HashMap::new();
+
+        for (index, entry) in entries.iter().enumerate() {
+            if !matches!(entry.entry_type, EntryType::TruncateTable) || !applies(index, entry) {
+                continue;
+            }
+
+            if let Ok(query) = Self::decode_wal_payload::<TruncateTableQuery>(entry.data.as_deref())
+            {
+                for table in query.tables {
+                    positions.insert(table, index);
+                }
+            }
+        }
+
+        positions
+    }
+
+    // COMMIT 레코드가 있는 트랜잭션 id
+    fn wal_committed_transactions(entries: &[WALEntry]) -> HashSet<u64> {
+        entries
+            .iter()
+            .filter(|entry| matches!(entry.entry_type, EntryType::TransactionCommit))
+            .filter_map(|entry| entry.transaction_id)
+            .collect()
+    }
+
+    // ROLLBACK TO SAVEPOINT로 되돌린 엔트리의 위치. 세이브포인트 마커부터 롤백 마커 사이의
+    // 같은 트랜잭션 엔트리이며, 마커가 없는 세이브포인트(첫 쓰기 전)는 트랜잭션의 처음부터입니다.
+    fn wal_rolled_back_entries(entries: &[WALEntry]) -> HashSet<usize> {
+        let mut savepoints = HashMap::new();
+        let mut rolled_back = HashSet::new();
+
+        for (index, entry) in entries.iter().enumerate() {
+            let Some(transaction_id) = entry.transaction_id else {
+                continue;
+            };
+            if !matches!(
+                entry.entry_type,
+                EntryType::Savepoint | EntryType::RollbackToSavepoint
+            ) {
+                continue;
+            }
+            let Ok(payload) =
+                Self::decode_wal_payload::<SavepointWALPayload>(entry.data.as_deref())
+            else {
+                continue;
+            };
+
+            let key = (transaction_id, payload.savepoint_id);
+            if matches!(entry.entry_type, EntryType::Savepoint) {
+                savepoints.insert(key, index);
+                continue;
+            }
+
+            let start = savepoints.get(&key).copied().unwrap_or_default();
+            rolled_back.extend(
+                (start..index)
+                    .filter(|position| entries[*position].transaction_id == Some(transaction_id)),
+            );
+        }
+
+        rolled_back
+    }
+
+    // 엔트리가 바꾸는 테이블. 페이로드를 읽지 못하면 비어 있습니다.
+    fn wal_entry_tables(entry: &WALEntry) -> Vec<TableName> {
+        let data = entry.data.as_deref();
+
+        let table = match entry.entry_type {
+            EntryType::Insert => match Self::decode_wal_payload::<InsertWALPayload>(data) {
+                Ok(payload) => payload.query.into_table,
+                Err(_) => Self::decode_wal_payload::<InsertQuery>(data)
+                    .ok()
+                    .and_then(|query| query.into_table),
+            },
+            EntryType::Set => Self::decode_wal_payload::<UpdateQuery>(data)
+                .ok()
+                .and_then(|query| query.target_table)
+                .map(|target| target.table),
+            EntryType::Delete => Self::decode_wal_payload::<DeleteQuery>(data)
+                .ok()
+                .and_then(|query| query.from_table)
+                .map(|target| target.table),
+            EntryType::Upsert => Self::decode_wal_payload::<UpsertWALPayload>(data)
+                .ok()
+                .map(|payload| payload.table_name),
+            EntryType::UpdateRows => Self::decode_wal_payload::<UpdateRowsWALPayload>(data)
+                .ok()
+                .map(|payload| payload.table_name),
+            EntryType::DeleteRows => Self::decode_wal_payload::<DeleteRowsWALPayload>(data)
+                .ok()
+                .map(|payload| payload.table_name),
+            EntryType::TruncateTable => {
+                return Self::decode_wal_payload::<TruncateTableQuery>(data)
+                    .map(|query| query.tables)
+                    .unwrap_or_default();
+            }
+            EntryType::Checkpoint
+            | EntryType::CreateIndex
+            | EntryType::DropIndex
+            | EntryType::TransactionBegin
+            | EntryType::TransactionCommit
+            | EntryType::TransactionRollback
+            | EntryType::Savepoint
+            | EntryType::RollbackToSavepoint => None,
+        };
+
+        table.into_iter().collect()
+    }
+
+    // 트랜잭션이 남긴 인덱스 항목을 디스크의 행 기준으로 다시 맞춥니다. 커밋된
+    // 트랜잭션의 항목은 이어지는 replay가 다시 넣습니다.
+    async fn rebuild_indexes_of_transactions<'a>(
+        &self,
+        entries: impl Iterator<Item = &'a WALEntry>,
+    ) -> errors::Result<()> {
+        let mut tables: Vec<TableName> = vec![];
+        for table in entries.flat_map(Self::wal_entry_tables) {
+            if !tables.contains(&table) {
+                tables.push(table);
+            }
+        }
+
+        if tables.is_empty() {
+            return Ok(());
+        }
+
+        self.ensure_indices_loaded().await?;
+
+        for table in tables {
+            if self.get_table_config_cached(table.clone()).await.is_err() {
+                continue;
+            }
+
+            let _guard = self.row_storage_lock.lock().await;
+            let rows = self.read_table_rows_locked(&table).await?;
+            self.rebuild_table_indexes(&table, &rows).await?;
+        }
+
+        Ok(())
+    }
+
     fn decode_wal_payload<T>(data: Option<&[u8]>) -> errors::Result<T>
     where
         T: serde::de::DeserializeOwned,
@@ -213,6 +518,10 @@ impl DBEngine {
         &self,
         table_name: TableName,
     ) -> errors::Result<TableSchema> {
+        if table_name.is_common_table() {
+            return Ok(self.common_table(&table_name).await?.schema);
+        }
+
         if let Some(table_config) = self.table_config_cache.read().await.get(&table_name) {
             return Ok(table_config.clone());
         }
@@ -273,6 +582,80 @@ impl DBEngine {
         }
     }
 
+    pub(crate) async fn get_function_config_cached(
+        &self,
+        function: &UserDefinedFunction,
+    ) -> errors::Result<FunctionSchema> {
+        if let Some(function_config) = self.function_config_cache.read().await.get(function) {
+            return Ok(function_config.clone());
+        }
+
+        let function_config = self.get_function_config(function).await?;
+
+        self.function_config_cache
+            .write()
+            .await
+            .insert(function.clone(), function_config.clone());
+
+        Ok(function_config)
+    }
+
+    pub(crate) async fn invalidate_function_config_cache(&self, function: &UserDefinedFunction) {
+        self.function_config_cache.write().await.remove(function);
+    }
+
+    // 데이터베이스가 삭제되거나 이름이 바뀌면 그 데이터베이스의 함수 캐시를 비웁니다.
+    pub(crate) async fn invalidate_database_function_configs(&self, database_name: &str) {
+        self.function_config_cache
+            .write()
+            .await
+            .retain(|function, _| function.database_name.as_deref() != Some(database_name));
+    }
+
+    pub async fn get_function_config(
+        &self,
+        function: &UserDefinedFunction,
+    ) -> errors::Result<FunctionSchema> {
+        let encoder = StorageEncoder::new();
+
+        let Some(database_name) = &function.database_name else {
+            return Err(ExecuteError::wrap(format!(
+                "function {} does not exist",
+                function.function_name
+            )));
+        };
+
+        crate::engine::path_identifier::validate_path_identifier(database_name, "database name")?;
+        crate::engine::path_identifier::validate_path_identifier(
+            &function.function_name,
+            "function name",
+        )?;
+
+        let config_path = self
+            .get_data_directory()
+            .join(database_name)
+            .join("functions")
+            .join(&function.function_name)
+            .join("function.config");
+
+        match tokio::fs::read(&config_path).await {
+            Ok(data) => match encoder.decode::<FunctionSchema>(data.as_slice()) {
+                Ok(function_config) => Ok(function_config),
+                Err(error) => Err(ExecuteError::wrap(format!(
+                    "invalid config data: {}",
+                    error
+                ))),
+            },
+            Err(error) => match error.kind() {
+                std::io::ErrorKind::NotFound => Err(ExecuteError::wrap(format!(
+                    "function {} does not exist",
+                    function.function_name
+                ))),
+                _ => Err(ExecuteError::wrap(format!("{:?}", error))),
+            },
+        }
+    }
+
     // 데이터 저장 경로를 반환합니다..
     pub fn get_data_directory(&self) -> PathBuf {
         PathBuf::from(self.config.data_directory.clone())
@@ -438,6 +821,9 @@ mod tests {
             data: InsertData::Values(vec![InsertValue {
                 list: vec![Some(SQLExpression::Integer(value))],
             }]),
+            with_clause: None,
+            on_conflict: None,
+            returning: None,
         }
     }
 
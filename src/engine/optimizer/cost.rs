@@ -14,6 +14,10 @@ pub const CPU_OPERATOR_COST: f64 = 0.0025;
 pub const DEFAULT_EQ_SELECTIVITY: f64 = 0.005;
 /// 범위 조건 선택도 기본값
 pub const DEFAULT_RANGE_SELECTIVITY: f64 = 1.0 / 3.0;
+/// IS NULL 조건 선택도 기본값
+pub const DEFAULT_NULL_SELECTIVITY: f64 = 0.005;
+/// LIKE 고정 접두사 한 글자당 선택도
+pub const PREFIX_CHAR_SELECTIVITY: f64 = 0.1;
 /// 블록 개수 추정에 사용하는 블록 크기 (bytes)
 pub const BLOCK_SIZE: u64 = 8192;
 
@@ -39,6 +43,62 @@ pub fn eq_selectivity(distinct_values: Option<usize>) -> f64 {
     }
 }
 
+/// IS NULL 조건 선택도: NULL 개수를 알면 그 비율, 모르면 기본값
+pub fn null_selectivity(null_count: Option<usize>, row_count: usize) -> f64 {
+    match null_count {
+        Some(count) => (count as f64 / row_count.max(1) as f64).min(1.0),
+        None => DEFAULT_NULL_SELECTIVITY,
+    }
+}
+
+/// LIKE 'abc%' 접두사 조건 선택도: 글자마다 PREFIX_CHAR_SELECTIVITY배로 줄어들되,
+/// 동등 조건보다 선택적일 수는 없습니다.
+pub fn prefix_selectivity(prefix_length: usize, distinct_values: Option<usize>) -> f64 {
+    let exponent = prefix_length.min(i32::MAX as usize) as i32;
+
+    PREFIX_CHAR_SELECTIVITY
+        .powi(exponent)
+        .max(eq_selectivity(distinct_values))
+}
+
+/// 조인 조건 하나의 선택도.
+///
+/// 등가 조인(a.x = b.y)은 양쪽 고유값 개수 중 큰 쪽의 역수로 추정합니다.
+/// 고유값 통계가 없는 컬럼은 호출하는 쪽에서 테이블 행 개수로 대신 넘깁니다
+/// (조인 키는 대부분 PK/FK라 고유하다고 보는 편이 실제에 가깝습니다).
+pub fn join_selectivity(left_distinct: usize, right_distinct: usize) -> f64 {
+    1.0 / left_distinct.max(right_distinct).max(1) as f64
+}
+
+/// 조인 결과 행 개수 추정치
+pub fn join_cardinality(left_rows: f64, right_rows: f64, selectivity: f64) -> f64 {
+    (left_rows * right_rows * selectivity).max(1.0)
+}
+
+/// 중첩 루프 조인 비용: 모든 (좌, 우) 조합에 조건 평가
+pub fn nested_loop_join_cost(left_rows: f64, right_rows: f64) -> f64 {
+    left_rows * right_rows * CPU_OPERATOR_COST
+}
+
+/// 해시 조인 비용: 우측으로 해시 테이블 구성 + 좌측 행마다 탐색
+pub fn hash_join_cost(left_rows: f64, right_rows: f64) -> f64 {
+    right_rows * (CPU_TUPLE_COST + CPU_OPERATOR_COST) + left_rows * CPU_OPERATOR_COST
+}
+
+/// 머지 조인 비용: 양쪽 정렬 + 한 번의 병합 순회
+pub fn merge_join_cost(left_rows: f64, right_rows: f64) -> f64 {
+    sort_cost(left_rows) + sort_cost(right_rows) + (left_rows + right_rows) * CPU_OPERATOR_COST
+}
+
+/// 정렬 비용: n log n 번의 비교
+pub fn sort_cost(rows: f64) -> f64 {
+    if rows > 1.0 {
+        rows * rows.log2() * CPU_OPERATOR_COST
+    } else {
+        0.0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -77,6 +137,51 @@ mod tests {
         assert!(full < index);
     }
 
+    #[test]
+    fn hash_join_beats_nested_loop_for_large_inputs() {
+        let (left, right) = (10_000.0, 5_000.0);
+
+        assert!(hash_join_cost(left, right) < nested_loop_join_cost(left, right));
+        assert!(hash_join_cost(left, right) < merge_join_cost(left, right));
+    }
+
+    #[test]
+    fn nested_loop_is_cheapest_for_single_row_inputs() {
+        assert!(nested_loop_join_cost(1.0, 1.0) < hash_join_cost(1.0, 1.0));
+    }
+
+    #[test]
+    fn join_selectivity_uses_the_larger_distinct_count() {
+        assert_eq!(join_selectivity(10, 1_000), 0.001);
+        assert_eq!(
+            join_cardinality(100.0, 1_000.0, join_selectivity(100, 1_000)),
+            100.0
+        );
+        assert_eq!(join_selectivity(0, 0), 1.0);
+    }
+
+    #[test]
+    fn prefix_selectivity_narrows_with_length_but_not_below_eq() {
+        assert!(prefix_selectivity(1, None) > prefix_selectivity(3, None));
+        assert_eq!(prefix_selectivity(2, Some(10)), 0.1);
+        assert_eq!(prefix_selectivity(10, Some(100)), 0.01);
+
+        // 긴 접두사로 정확히 한 값만 찾는 편이 인덱스를 쓸 가치가 있음
+        let (row_count, block_count) = (10_000, 100);
+        assert!(
+            index_scan_cost(row_count, prefix_selectivity(3, Some(row_count)))
+                < full_scan_cost(row_count, block_count)
+        );
+    }
+
+    #[test]
+    fn null_selectivity_uses_null_count() {
+        assert_eq!(null_selectivity(Some(5), 1000), 0.005);
+        assert_eq!(null_selectivity(Some(0), 1000), 0.0);
+        assert_eq!(null_selectivity(Some(10), 0), 1.0);
+        assert_eq!(null_selectivity(None, 1000), DEFAULT_NULL_SELECTIVITY);
+    }
+
     #[test]
     fn eq_selectivity_uses_distinct_values() {
         assert_eq!(eq_selectivity(Some(100)), 0.01);
@@ -0,0 +1,345 @@
+//! 상관 서브쿼리 풀기 (decorrelation)
+//!
+//! WHERE 절 최상위 AND 항에 있는 상관 EXISTS / NOT EXISTS 서브쿼리를
+//! LeftSemiJoin / LeftAntiJoin으로 바꿔서, 바깥 행마다 서브쿼리를 다시 실행하지 않고
+//! 한 번의 조인으로 처리되게 합니다.
+//!
+//! 서브쿼리가 단일 테이블을 읽고, 집계/LIMIT/중첩 서브쿼리 없이
+//! 바깥 컬럼과의 등가 조건을 하나 이상 가진 단순한 형태일 때만 변환합니다.
+//! 그 외에는 실행 시점에 행 단위로 평가합니다.
+//! NOT IN은 NULL 처리 규칙이 안티 조인과 달라서 변환하지 않습니다.
+
+use crate::engine::ast::dml::expressions::binary::BinaryOperatorExpression;
+use crate::engine::ast::dml::expressions::operators::{BinaryOperator, UnaryOperator};
+use crate::engine::ast::dml::expressions::subquery::SubqueryExpression;
+use crate::engine::ast::dml::parts::_where::WhereClause;
+use crate::engine::ast::dml::parts::from::FromTarget;
+use crate::engine::ast::dml::parts::join::{JoinClause, JoinType};
+use crate::engine::ast::dml::select::SelectQuery;
+use crate::engine::ast::types::{SQLExpression, TableName};
+use crate::engine::optimizer::optimizer::collect_conjuncts;
+
+/// WHERE 절의 EXISTS / NOT EXISTS 서브쿼리 중 변환 가능한 것을 세미/안티 조인으로 바꿉니다.
+pub fn decorrelate_exists(mut query: SelectQuery) -> SelectQuery {
+    let Some(where_clause) = query.where_clause.take() else {
+        return query;
+    };
+
+    let outer_tables = match outer_tables(&query) {
+        Some(tables) => tables,
+        None => {
+            query.where_clause = Some(where_clause);
+            return query;
+        }
+    };
+
+    let mut conjuncts = vec![];
+    collect_conjuncts(&where_clause.expression, &mut conjuncts);
+
+    let mut remaining = vec![];
+    let mut joins = vec![];
+
+    for conjunct in conjuncts {
+        let rewritten = exists_operand(conjunct)
+            .and_then(|(subquery, negated)| semi_join(subquery, negated, &outer_tables));
+
+        match rewritten {
+            Some(join) => joins.push(join),
+            None => remaining.push(conjunct.clone()),
+        }
+    }
+
+    if joins.is_empty() {
+        query.where_clause = Some(where_clause);
+        return query;
+    }
+
+    query.join_clause.extend(joins);
+    query.where_clause = remaining
+        .into_iter()
+        .reduce(|lhs, rhs| {
+            BinaryOperatorExpression {
+                operator: BinaryOperator::And,
+                lhs,
+                rhs,
+            }
+            .into()
+        })
+        .map(|expression| WhereClause { expression });
+
+    query
+}
+
+/// 바깥 쿼리의 테이블과, 컬럼 한정자로 쓸 수 있는 이름 (별칭이 있으면 별칭, 없으면 테이블명)
+fn outer_tables(query: &SelectQuery) -> Option<Vec<(TableName, String)>> {
+    let from_clause = query.from_table.as_ref()?;
+
+    let FromTarget::Table(table_name) = &from_clause.from else {
+        return None;
+    };
+
+    let mut tables = vec![(
+        table_name.clone(),
+        from_clause
+            .alias
+            .clone()
+            .unwrap_or(table_name.table_name.clone()),
+    )];
+
+    tables.extend(query.join_clause.iter().map(|join| {
+        (
+            join.right.clone(),
+            join.right_alias
+                .clone()
+                .unwrap_or(join.right.table_name.clone()),
+        )
+    }));
+
+    Some(tables)
+}
+
+/// EXISTS (...) 또는 NOT EXISTS (...) 형태라면 (서브쿼리, 부정 여부)를 반환합니다.
+fn exists_operand(expression: &SQLExpression) -> Option<(&SelectQuery, bool)> {
+    match expression {
+        SQLExpression::Subquery(SubqueryExpression::Exists(query)) => Some((query, false)),
+        SQLExpression::Unary(unary) if unary.operator == UnaryOperator::Not => {
+            exists_operand(&unary.operand).map(|(query, negated)| (query, !negated))
+        }
+        SQLExpression::Parentheses(parentheses) => exists_operand(&parentheses.expression),
+        _ => None,
+    }
+}
+
+/// 서브쿼리를 바깥 쿼리에 붙일 세미/안티 조인 절로 바꿉니다. 변환할 수 없으면 None.
+fn semi_join(
+    subquery: &SelectQuery,
+    negated: bool,
+    outer_tables: &[(TableName, String)],
+) -> Option<JoinClause> {
+    if !subquery.join_clause.is_empty()
+        || subquery.group_by_clause.is_some()
+        || subquery.having_clause.is_some()
+        || subquery.has_aggregate
+        || subquery.limit.is_some()
+        || subquery.offset.is_some()
+    {
+        return None;
+    }
+
+    let from_clause = subquery.from_table.as_ref()?;
+    let FromTarget::Table(table_name) = &from_clause.from else {
+        return None;
+    };
+
+    let inner_name = from_clause
+        .alias
+        .clone()
+        .unwrap_or(table_name.table_name.clone());
+
+    // 한정자나 테이블이 겹치면 조인된 행에서 어느 쪽의 컬럼인지 구분할 수 없습니다.
+    // (FROM t WHERE EXISTS (SELECT ... FROM t t2 ...)는 바깥 행마다 평가합니다)
+    if outer_tables
+        .iter()
+        .any(|(outer_table, outer_name)| outer_table == table_name || outer_name == &inner_name)
+    {
+        return None;
+    }
+
+    let where_clause = subquery.where_clause.as_ref()?;
+    let condition = &where_clause.expression;
+
+    if !condition.get_subquery_list().is_empty() {
+        return None;
+    }
+
+    // 모든 컬럼은 서브쿼리 테이블 또는 바깥 테이블로 명시적으로 한정되어야 합니다.
+    let is_inner = |qualifier: &Option<String>| qualifier.as_ref() == Some(&inner_name);
+    let is_outer = |qualifier: &Option<String>| {
+        qualifier.as_ref().is_some_and(|qualifier| {
+            outer_tables
+                .iter()
+                .any(|(_, outer_name)| outer_name == qualifier)
+        })
+    };
+
+    let columns_resolved = condition
+        .get_select_column_list()
+        .iter()
+        .all(|column| is_inner(&column.table_name) || is_outer(&column.table_name));
+
+    if !columns_resolved {
+        return None;
+    }
+
+    let mut conjuncts = vec![];
+    collect_conjuncts(condition, &mut conjuncts);
+
+    let has_correlated_equality = conjuncts.iter().any(|conjunct| match conjunct {
+        SQLExpression::Binary(binary) if binary.operator == BinaryOperator::Eq => {
+            match (&binary.lhs, &binary.rhs) {
+                (SQLExpression::SelectColumn(lhs), SQLExpression::SelectColumn(rhs)) => {
+                    (is_inner(&lhs.table_name) && is_outer(&rhs.table_name))
+                        || (is_outer(&lhs.table_name) && is_inner(&rhs.table_name))
+                }
+                _ => false,
+            }
+        }
+        _ => false,
+    });
+
+    if !has_correlated_equality {
+        return None;
+    }
+
+    Some(JoinClause {
+        join_type: if negated {
+            JoinType::LeftAntiJoin
+        } else {
+            JoinType::LeftSemiJoin
+        },
+        right: table_name.clone(),
+        right_alias: from_clause.alias.clone(),
+        on: Some(condition.clone()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::ast::dml::expressions::unary::UnaryOperatorExpression;
+    use crate::engine::ast::types::SelectColumn;
+
+    fn column(table: &str, column: &str) -> SQLExpression {
+        SelectColumn::new(Some(table.into()), column.into()).into()
+    }
+
+    fn binary(lhs: SQLExpression, rhs: SQLExpression, operator: BinaryOperator) -> SQLExpression {
+        BinaryOperatorExpression { operator, lhs, rhs }.into()
+    }
+
+    fn eq(lhs: SQLExpression, rhs: SQLExpression) -> SQLExpression {
+        binary(lhs, rhs, BinaryOperator::Eq)
+    }
+
+    fn query(table: &str, alias: Option<&str>, expression: SQLExpression) -> SelectQuery {
+        let mut query = SelectQuery::builder()
+            .set_from_table(TableName::new(None, table.into()))
+            .set_where(WhereClause { expression });
+
+        if let Some(alias) = alias {
+            query = query.set_from_alias(alias.into());
+        }
+
+        query.build()
+    }
+
+    fn exists(subquery: SelectQuery) -> SQLExpression {
+        SQLExpression::Subquery(SubqueryExpression::Exists(Box::new(subquery)))
+    }
+
+    #[test]
+    fn correlated_exists_becomes_semi_join() {
+        let subquery = query(
+            "orders",
+            Some("o"),
+            eq(column("o", "user_id"), column("u", "id")),
+        );
+        let outer = query(
+            "users",
+            Some("u"),
+            binary(
+                exists(subquery),
+                eq(column("u", "active"), SQLExpression::Boolean(true)),
+                BinaryOperator::And,
+            ),
+        );
+
+        let rewritten = decorrelate_exists(outer);
+
+        assert_eq!(rewritten.join_clause.len(), 1);
+        assert_eq!(rewritten.join_clause[0].join_type, JoinType::LeftSemiJoin);
+        assert_eq!(rewritten.join_clause[0].right_alias, Some("o".into()));
+        assert_eq!(
+            rewritten.join_clause[0].on,
+            Some(eq(column("o", "user_id"), column("u", "id")))
+        );
+        assert_eq!(
+            rewritten.where_clause.map(|w| w.expression),
+            Some(eq(column("u", "active"), SQLExpression::Boolean(true)))
+        );
+    }
+
+    #[test]
+    fn correlated_not_exists_becomes_anti_join() {
+        let subquery = query(
+            "orders",
+            None,
+            eq(column("orders", "user_id"), column("users", "id")),
+        );
+        let outer = query(
+            "users",
+            None,
+            UnaryOperatorExpression {
+                operator: UnaryOperator::Not,
+                operand: exists(subquery),
+            }
+            .into(),
+        );
+
+        let rewritten = decorrelate_exists(outer);
+
+        assert_eq!(rewritten.join_clause.len(), 1);
+        assert_eq!(rewritten.join_clause[0].join_type, JoinType::LeftAntiJoin);
+        assert_eq!(rewritten.where_clause, None);
+    }
+
+    #[test]
+    fn exists_without_correlated_equality_is_kept() {
+        let cases = vec![
+            // 비상관 서브쿼리
+            query(
+                "orders",
+                Some("o"),
+                eq(column("o", "user_id"), SQLExpression::Integer(1)),
+            ),
+            // 한정자 없는 컬럼
+            query(
+                "orders",
+                Some("o"),
+                eq(
+                    SelectColumn::new(None, "user_id".into()).into(),
+                    column("u", "id"),
+                ),
+            ),
+            // 바깥 테이블과 한정자가 겹침
+            query(
+                "orders",
+                Some("u"),
+                eq(column("u", "user_id"), column("u", "id")),
+            ),
+            // 바깥과 같은 테이블 (조인된 행에서 구분할 수 없음)
+            query(
+                "users",
+                Some("u2"),
+                eq(column("u2", "id"), column("u", "id")),
+            ),
+            // LIMIT이 있으면 의미가 달라질 수 있음
+            {
+                let mut subquery = query(
+                    "orders",
+                    Some("o"),
+                    eq(column("o", "user_id"), column("u", "id")),
+                );
+                subquery.limit = Some(1);
+                subquery
+            },
+        ];
+
+        for subquery in cases {
+            let outer = query("users", Some("u"), exists(subquery));
+            let rewritten = decorrelate_exists(outer.clone());
+
+            assert_eq!(rewritten, outer);
+        }
+    }
+}
@@ -0,0 +1,679 @@
+//! 조인 순서 및 조인 알고리즘 선택 (#195)
+//!
+//! 모든 테이블의 통계가 있는 INNER JOIN은 비용이 가장 낮은 left-deep 순서로
+//! 재배치합니다. 테이블이 DP_JOIN_LIMIT개 이하면 동적 계획법으로 모든 순서를,
+//! 그보다 많으면 탐욕법으로 탐색합니다. 외부 조인이 섞였거나 통계가 없으면
+//! 작성된 순서를 유지하고 조인마다 알고리즘만 고릅니다.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::engine::ast::dml::expressions::binary::BinaryOperatorExpression;
+use crate::engine::ast::dml::expressions::operators::BinaryOperator;
+use crate::engine::ast::dml::parts::join::{JoinClause, JoinType};
+use crate::engine::ast::dml::plan::select::join::JoinScanType;
+use crate::engine::ast::types::{SQLExpression, SelectColumn, TableName};
+use crate::engine::optimizer::cost;
+use crate::engine::optimizer::optimizer::collect_conjuncts;
+use crate::engine::optimizer::statistics::TableStatistics;
+
+/// 동적 계획법으로 조인 순서를 탐색하는 최대 테이블 개수.
+/// 부분집합이 2^N개라 이보다 크면 탐욕법으로 전환합니다.
+pub const DP_JOIN_LIMIT: usize = 8;
+
+/// 조인에 참여하는 테이블 (FROM 테이블 + JOIN 대상 테이블)
+#[derive(Debug, Clone)]
+pub struct JoinRelation {
+    pub table_name: TableName,
+    pub alias: Option<String>,
+    pub statistics: Option<TableStatistics>,
+}
+
+/// 계획된 조인 한 단계. relation은 JoinRelation 목록의 인덱스입니다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JoinStep {
+    pub relation: usize,
+    pub join_type: JoinType,
+    pub on: Option<SQLExpression>,
+    pub join_scan_type: JoinScanType,
+}
+
+/// 조인 순서 계획: first 테이블을 스캔한 뒤 steps 순서대로 결합합니다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JoinOrder {
+    pub first: usize,
+    pub steps: Vec<JoinStep>,
+}
+
+/// ON 절을 AND 단위로 나눈 조건 하나
+#[derive(Debug, Clone)]
+struct JoinPredicate {
+    expression: SQLExpression,
+    /// 조건이 참조하는 테이블 집합 (비트마스크). 판별할 수 없으면 None
+    relations: Option<u64>,
+    /// 서로 다른 두 테이블의 컬럼을 잇는 등가 조건이면 양쪽 (테이블, 컬럼)
+    equi: Option<((usize, String), (usize, String))>,
+}
+
+/// 조인 순서와 조인별 알고리즘을 결정합니다.
+///
+/// relations[0]은 FROM 테이블, relations[i + 1]은 joins[i]의 대상 테이블입니다.
+pub fn plan_join_order(relations: &[JoinRelation], joins: &[JoinClause]) -> JoinOrder {
+    let predicates_per_join = joins
+        .iter()
+        .map(|join| match &join.on {
+            Some(on) => split_predicates(on, relations),
+            None => vec![],
+        })
+        .collect::<Vec<_>>();
+
+    let reorderable = relations.len() <= u64::BITS as usize
+        && joins
+            .iter()
+            .all(|join| join.join_type == JoinType::InnerJoin)
+        && relations
+            .iter()
+            .all(|relation| relation.statistics.is_some())
+        && has_unique_names(relations)
+        && predicates_per_join
+            .iter()
+            .flatten()
+            .all(|predicate| predicate.relations.is_some());
+
+    let estimator = Estimator {
+        relations,
+        predicates: predicates_per_join.iter().flatten().cloned().collect(),
+    };
+
+    if reorderable {
+        let order = if relations.len() <= DP_JOIN_LIMIT {
+            estimator.dynamic_programming_order()
+        } else {
+            estimator.greedy_order()
+        };
+
+        return estimator.build_order(&order);
+    }
+
+    // 작성된 순서 유지: 외부 조인은 순서를 바꾸면 결과가 달라집니다.
+    let mut left = 1u64;
+    let steps = joins
+        .iter()
+        .enumerate()
+        .map(|(i, join)| {
+            let relation = i + 1;
+            let has_equi_key = has_column_equality(join.on.as_ref());
+
+            let join_scan_type = if relation < u64::BITS as usize {
+                estimator
+                    .step_cost(left, relation, has_equi_key)
+                    .map(|(_, join_scan_type)| join_scan_type)
+                    .unwrap_or_else(|| heuristic_join_scan_type(has_equi_key))
+            } else {
+                heuristic_join_scan_type(has_equi_key)
+            };
+
+            if relation < u64::BITS as usize {
+                left |= 1 << relation;
+            }
+
+            JoinStep {
+                relation,
+                join_type: join.join_type.clone(),
+                on: join.on.clone(),
+                join_scan_type,
+            }
+        })
+        .collect();
+
+    JoinOrder { first: 0, steps }
+}
+
+/// UPDATE ... FROM / DELETE ... USING의 WHERE 절을 조인 단계별로 나눈 계획
+#[derive(Debug, Clone, PartialEq)]
+pub struct DmlJoinOrder {
+    /// 대상 테이블 컬럼만 참조하는 조건. 조인 전에 적용하고 스캔 선택에도 씁니다.
+    pub target_filter: Option<SQLExpression>,
+    /// relations[i + 1]을 결합하는 단계. 작성된 순서를 유지합니다.
+    pub steps: Vec<JoinStep>,
+    /// 한정자 없는 컬럼이나 서브쿼리처럼 단계를 판별할 수 없는 조건. 모든 조인 뒤에 적용합니다.
+    pub residual: Option<SQLExpression>,
+}
+
+/// 수정 대상 테이블(relations[0])과 FROM/USING 테이블의 조인 계획을 세웁니다.
+///
+/// 각 조건은 참조하는 테이블이 모두 결합된 가장 이른 단계의 ON 절로 내려보냅니다.
+pub fn plan_dml_join_order(
+    relations: &[JoinRelation],
+    where_expression: Option<&SQLExpression>,
+) -> DmlJoinOrder {
+    let predicates = match where_expression {
+        Some(expression) => split_predicates(expression, relations),
+        None => vec![],
+    };
+
+    // 조건별로 적용할 단계: 0은 조인 전, relations.len()은 모든 조인 뒤
+    let stage_of = |predicate: &JoinPredicate| match predicate.relations {
+        Some(mask) => (u64::BITS - mask.leading_zeros()).saturating_sub(1) as usize,
+        None => relations.len(),
+    };
+
+    let conjunction = |stage: usize| {
+        predicates
+            .iter()
+            .filter(|predicate| stage_of(predicate) == stage)
+            .map(|predicate| predicate.expression.clone())
+            .reduce(|lhs, rhs| {
+                BinaryOperatorExpression {
+                    operator: BinaryOperator::And,
+                    lhs,
+                    rhs,
+                }
+                .into()
+            })
+    };
+
+    let steps = (1..relations.len())
+        .map(|relation| {
+            let on = conjunction(relation);
+
+            JoinStep {
+                relation,
+                join_type: JoinType::InnerJoin,
+                join_scan_type: heuristic_join_scan_type(has_column_equality(on.as_ref())),
+                on,
+            }
+        })
+        .collect();
+
+    DmlJoinOrder {
+        target_filter: conjunction(0),
+        steps,
+        residual: conjunction(relations.len()),
+    }
+}
+
+/// 통계가 없을 때의 기본 선택: 등가 조건이 있으면 해시 조인, 없으면 중첩 루프
+fn heuristic_join_scan_type(has_equi_key: bool) -> JoinScanType {
+    if has_equi_key {
+        JoinScanType::Hash
+    } else {
+        JoinScanType::NestedLoop
+    }
+}
+
+/// 두 컬럼을 잇는 등가 조건이 하나라도 있는지 확인합니다.
+fn has_column_equality(on: Option<&SQLExpression>) -> bool {
+    let mut conjuncts = vec![];
+    if let Some(on) = on {
+        collect_conjuncts(on, &mut conjuncts);
+    }
+
+    conjuncts.into_iter().any(|conjunct| match conjunct {
+        SQLExpression::Binary(binary) => {
+            binary.operator == BinaryOperator::Eq
+                && matches!(binary.lhs, SQLExpression::SelectColumn(_))
+                && matches!(binary.rhs, SQLExpression::SelectColumn(_))
+        }
+        _ => false,
+    })
+}
+
+fn relation_name(relation: &JoinRelation) -> &str {
+    relation
+        .alias
+        .as_deref()
+        .unwrap_or(&relation.table_name.table_name)
+}
+
+fn has_unique_names(relations: &[JoinRelation]) -> bool {
+    let mut names = HashSet::new();
+    relations
+        .iter()
+        .all(|relation| names.insert(relation_name(relation)))
+}
+
+/// 테이블명(또는 별칭)으로 한정된 컬럼이 어느 테이블을 가리키는지 찾습니다.
+/// 한정자가 없는 컬럼은 스키마 없이 판별할 수 없으므로 None입니다.
+fn resolve_column(column: &SelectColumn, relations: &[JoinRelation]) -> Option<usize> {
+    let qualifier = column.table_name.as_ref()?;
+
+    relations
+        .iter()
+        .position(|relation| relation_name(relation) == qualifier)
+}
+
+fn split_predicates(on: &SQLExpression, relations: &[JoinRelation]) -> Vec<JoinPredicate> {
+    let mut conjuncts = vec![];
+    collect_conjuncts(on, &mut conjuncts);
+
+    conjuncts
+        .into_iter()
+        .map(|conjunct| {
+            let relations_mask = if conjunct.has_subquery() {
+                None
+            } else {
+                conjunct
+                    .get_select_column_list()
+                    .iter()
+                    .try_fold(0u64, |mask, column| {
+                        let relation = resolve_column(column, relations)?;
+                        (relation < u64::BITS as usize).then(|| mask | (1 << relation))
+                    })
+            };
+
+            let equi = match conjunct {
+                SQLExpression::Binary(binary) if binary.operator == BinaryOperator::Eq => {
+                    match (&binary.lhs, &binary.rhs) {
+                        (SQLExpression::SelectColumn(lhs), SQLExpression::SelectColumn(rhs)) => {
+                            match (
+                                resolve_column(lhs, relations),
+                                resolve_column(rhs, relations),
+                            ) {
+                                (Some(l), Some(r)) if l != r => Some((
+                                    (l, lhs.column_name.clone()),
+                                    (r, rhs.column_name.clone()),
+                                )),
+                                _ => None,
+                            }
+                        }
+                        _ => None,
+                    }
+                }
+                _ => None,
+            };
+
+            JoinPredicate {
+                expression: conjunct.clone(),
+                relations: relations_mask,
+                equi,
+            }
+        })
+        .collect()
+}
+
+/// 통계 기반 카디널리티/비용 추정기
+struct Estimator<'a> {
+    relations: &'a [JoinRelation],
+    predicates: Vec<JoinPredicate>,
+}
+
+impl Estimator<'_> {
+    fn statistics(&self, relation: usize) -> Option<&TableStatistics> {
+        self.relations.get(relation)?.statistics.as_ref()
+    }
+
+    fn rows(&self, relation: usize) -> Option<f64> {
+        self.statistics(relation)
+            .map(|statistics| statistics.row_count.max(1) as f64)
+    }
+
+    /// 컬럼의 고유값 개수. 통계가 없으면 행 개수로 대신합니다.
+    fn distinct_values(&self, relation: usize, column_name: &str) -> usize {
+        match self.statistics(relation) {
+            Some(statistics) => statistics
+                .distinct_values
+                .get(column_name)
+                .copied()
+                .unwrap_or(statistics.row_count),
+            None => 1,
+        }
+    }
+
+    fn selectivity(&self, predicate: &JoinPredicate) -> f64 {
+        match &predicate.equi {
+            Some(((l, l_column), (r, r_column))) => cost::join_selectivity(
+                self.distinct_values(*l, l_column),
+                self.distinct_values(*r, r_column),
+            ),
+            None => cost::DEFAULT_RANGE_SELECTIVITY,
+        }
+    }
+
+    fn members(mask: u64) -> impl Iterator<Item = usize> {
+        (0..u64::BITS as usize).filter(move |i| mask & (1 << i) != 0)
+    }
+
+    /// 테이블 집합을 모두 조인했을 때의 결과 행 개수
+    fn cardinality(&self, mask: u64) -> Option<f64> {
+        let mut rows = 1.0;
+        for relation in Self::members(mask) {
+            rows *= self.rows(relation)?;
+        }
+
+        for predicate in &self.predicates {
+            if let Some(relations) = predicate.relations
+                && relations & !mask == 0
+                && relations.count_ones() >= 2
+            {
+                rows *= self.selectivity(predicate);
+            }
+        }
+
+        Some(rows.max(1.0))
+    }
+
+    fn scan_cost(&self, relation: usize) -> Option<f64> {
+        self.statistics(relation)
+            .map(|statistics| cost::full_scan_cost(statistics.row_count, statistics.block_count))
+    }
+
+    /// 좌측 집합과 relation 사이에 등가 조건이 있는지 확인합니다.
+    fn connects_with_equi(&self, left: u64, relation: usize) -> bool {
+        self.predicates
+            .iter()
+            .any(|predicate| match &predicate.equi {
+                Some(((l, _), (r, _))) => {
+                    (left & (1 << l) != 0 && *r == relation)
+                        || (left & (1 << r) != 0 && *l == relation)
+                }
+                None => false,
+            })
+    }
+
+    /// 좌측 집합에 relation을 결합하는 비용과 그때 쓸 조인 알고리즘
+    fn step_cost(
+        &self,
+        left: u64,
+        relation: usize,
+        has_equi_key: bool,
+    ) -> Option<(f64, JoinScanType)> {
+        let left_rows = self.cardinality(left)?;
+        let right_rows = self.rows(relation)?;
+        let output_rows = self.cardinality(left | (1 << relation))?;
+
+        let mut candidates = vec![(
+            cost::nested_loop_join_cost(left_rows, right_rows),
+            JoinScanType::NestedLoop,
+        )];
+        if has_equi_key {
+            candidates.push((
+                cost::hash_join_cost(left_rows, right_rows),
+                JoinScanType::Hash,
+            ));
+            candidates.push((
+                cost::merge_join_cost(left_rows, right_rows),
+                JoinScanType::Merge,
+            ));
+        }
+
+        let (join_cost, join_scan_type) = candidates
+            .into_iter()
+            .min_by(|(l, _), (r, _)| l.total_cmp(r))?;
+
+        Some((
+            self.scan_cost(relation)? + join_cost + output_rows * cost::CPU_TUPLE_COST,
+            join_scan_type,
+        ))
+    }
+
+    /// 부분집합마다 가장 싼 left-deep 순서를 기억하며 전체 집합까지 확장합니다.
+    fn dynamic_programming_order(&self) -> Vec<usize> {
+        let n = self.relations.len();
+        let full = (1u64 << n) - 1;
+
+        let mut best = HashMap::<u64, (f64, Vec<usize>)>::new();
+        for relation in 0..n {
+            best.insert(
+                1 << relation,
+                (self.scan_cost(relation).unwrap_or(0.0), vec![relation]),
+            );
+        }
+
+        // 원소를 하나 뺀 부분집합은 항상 더 작은 수이므로 숫자 순서대로 채우면 됩니다.
+        for mask in 1..=full {
+            if mask.count_ones() < 2 {
+                continue;
+            }
+
+            for relation in Self::members(mask) {
+                let left = mask & !(1 << relation);
+                let Some((left_cost, left_order)) = best.get(&left) else {
+                    continue;
+                };
+                let Some((step_cost, _)) =
+                    self.step_cost(left, relation, self.connects_with_equi(left, relation))
+                else {
+                    continue;
+                };
+
+                let total = left_cost + step_cost;
+                if best.get(&mask).is_none_or(|(cost, _)| total < *cost) {
+                    let mut order = left_order.clone();
+                    order.push(relation);
+                    best.insert(mask, (total, order));
+                }
+            }
+        }
+
+        best.remove(&full)
+            .map(|(_, order)| order)
+            .unwrap_or_else(|| (0..n).collect())
+    }
+
+    /// 가장 작은 테이블에서 시작해, 매번 결합 비용이 가장 싼 테이블을 붙입니다.
+    fn greedy_order(&self) -> Vec<usize> {
+        let n = self.relations.len();
+
+        let first = (0..n)
+            .min_by(|l, r| {
+                let l = self.rows(*l).unwrap_or(f64::MAX);
+                let r = self.rows(*r).unwrap_or(f64::MAX);
+                l.total_cmp(&r)
+            })
+            .unwrap_or(0);
+
+        let mut order = vec![first];
+        let mut mask = 1u64 << first;
+
+        while order.len() < n {
+            let next = (0..n)
+                .filter(|relation| mask & (1 << relation) == 0)
+                .min_by(|l, r| {
+                    let cost = |relation: usize| {
+                        self.step_cost(mask, relation, self.connects_with_equi(mask, relation))
+                            .map(|(cost, _)| cost)
+                            .unwrap_or(f64::MAX)
+                    };
+                    cost(*l).total_cmp(&cost(*r))
+                })
+                .expect("at least one relation is left");
+
+            order.push(next);
+            mask |= 1 << next;
+        }
+
+        order
+    }
+
+    /// 정해진 순서대로 각 단계에 적용 가능한 조건을 배치하고 알고리즘을 고릅니다.
+    fn build_order(&self, order: &[usize]) -> JoinOrder {
+        let first = order[0];
+        let mut mask = 1u64 << first;
+        let mut assigned = vec![false; self.predicates.len()];
+        let mut steps = vec![];
+
+        for &relation in &order[1..] {
+            let has_equi_key = self.connects_with_equi(mask, relation);
+            let join_scan_type = self
+                .step_cost(mask, relation, has_equi_key)
+                .map(|(_, join_scan_type)| join_scan_type)
+                .unwrap_or_else(|| heuristic_join_scan_type(has_equi_key));
+
+            mask |= 1 << relation;
+
+            let mut on: Option<SQLExpression> = None;
+            for (i, predicate) in self.predicates.iter().enumerate() {
+                if assigned[i] || predicate.relations.unwrap_or(u64::MAX) & !mask != 0 {
+                    continue;
+                }
+                assigned[i] = true;
+
+                on = Some(match on {
+                    Some(lhs) => BinaryOperatorExpression {
+                        operator: BinaryOperator::And,
+                        lhs,
+                        rhs: predicate.expression.clone(),
+                    }
+                    .into(),
+                    None => predicate.expression.clone(),
+                });
+            }
+
+            steps.push(JoinStep {
+                relation,
+                join_type: JoinType::InnerJoin,
+                on,
+                join_scan_type,
+            });
+        }
+
+        JoinOrder { first, steps }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::parser::predule::{Parser, ParserContext};
+
+    fn relation(name: &str, alias: &str, row_count: Option<usize>) -> JoinRelation {
+        JoinRelation {
+            table_name: TableName::new(Some("rrdb".into()), name.into()),
+            alias: Some(alias.into()),
+            statistics: row_count.map(|row_count| TableStatistics {
+                row_count,
+                block_count: row_count / 100 + 1,
+                distinct_values: HashMap::new(),
+                null_counts: HashMap::new(),
+            }),
+        }
+    }
+
+    fn joins(sql: &str) -> Vec<JoinClause> {
+        let mut parser = Parser::with_string(sql.into()).unwrap();
+        match parser
+            .parse(ParserContext::default().set_default_database("rrdb".to_string()))
+            .unwrap()
+            .remove(0)
+        {
+            crate::engine::ast::SQLStatement::DML(
+                crate::engine::ast::DMLStatement::SelectQuery(query),
+            ) => query.join_clause,
+            other => panic!("expected select query, got {:?}", other),
+        }
+    }
+
+    fn order_of(order: &JoinOrder) -> Vec<usize> {
+        std::iter::once(order.first)
+            .chain(order.steps.iter().map(|step| step.relation))
+            .collect()
+    }
+
+    const CHAIN: &str = "select * from orders o \
+        inner join users u on o.user_id = u.id \
+        inner join items i on i.order_id = o.id \
+        inner join countries c on u.country_id = c.id;";
+
+    /// 모든 단계는 등가 조건을 따라 결합되어야 하고(교차 곱 없음), 큰 테이블을
+    /// 해시 테이블로 만드는 순서는 피해야 합니다.
+    #[test]
+    fn dynamic_programming_avoids_cross_products_and_large_intermediates() {
+        let relations = vec![
+            relation("orders", "o", Some(100_000)),
+            relation("users", "u", Some(10_000)),
+            relation("items", "i", Some(1_000_000)),
+            relation("countries", "c", Some(10)),
+        ];
+
+        let order = plan_join_order(&relations, &joins(CHAIN));
+        let sequence = order_of(&order);
+
+        // 모든 단계가 앞선 테이블과 등가 조건으로 연결되어야 합니다 (교차 곱 없음).
+        for step in &order.steps {
+            assert!(step.on.is_some(), "cross product in {:?}", sequence);
+            assert_ne!(step.join_scan_type, JoinScanType::NestedLoop);
+        }
+        // 해시 테이블은 작은 쪽으로 만들어야 하므로, 백만 건짜리 items는
+        // 빌드 쪽(우측)이 아니라 처음부터 탐색 쪽(좌측)에 놓입니다.
+        assert_eq!(sequence[0], 2);
+        // 세 개의 ON 조건은 하나도 빠짐없이 어딘가에 배치됩니다.
+        let placed = order
+            .steps
+            .iter()
+            .map(|step| {
+                let mut conjuncts = vec![];
+                collect_conjuncts(step.on.as_ref().unwrap(), &mut conjuncts);
+                conjuncts.len()
+            })
+            .sum::<usize>();
+        assert_eq!(placed, 3);
+    }
+
+    #[test]
+    fn greedy_order_covers_every_relation_once() {
+        let relations = vec![
+            relation("orders", "o", Some(100_000)),
+            relation("users", "u", Some(10_000)),
+            relation("items", "i", Some(1_000_000)),
+            relation("countries", "c", Some(10)),
+        ];
+        let estimator = Estimator {
+            relations: &relations,
+            predicates: joins(CHAIN)
+                .iter()
+                .flat_map(|join| split_predicates(join.on.as_ref().unwrap(), &relations))
+                .collect(),
+        };
+
+        let mut order = estimator.greedy_order();
+        assert_eq!(order[0], 3, "starts from the smallest table");
+        order.sort();
+        assert_eq!(order, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn outer_joins_keep_the_written_order() {
+        let relations = vec![
+            relation("orders", "o", Some(100_000)),
+            relation("users", "u", Some(10)),
+        ];
+
+        let order = plan_join_order(
+            &relations,
+            &joins("select * from orders o left join users u on o.user_id = u.id;"),
+        );
+
+        assert_eq!(order_of(&order), vec![0, 1]);
+        assert_eq!(order.steps[0].join_type, JoinType::LeftOuterJoin);
+        assert_eq!(order.steps[0].join_scan_type, JoinScanType::Hash);
+    }
+
+    #[test]
+    fn missing_statistics_fall_back_to_heuristics() {
+        let relations = vec![relation("orders", "o", None), relation("users", "u", None)];
+
+        let order = plan_join_order(
+            &relations,
+            &joins("select * from orders o inner join users u on o.total > u.id;"),
+        );
+
+        assert_eq!(order_of(&order), vec![0, 1]);
+        assert_eq!(order.steps[0].join_scan_type, JoinScanType::NestedLoop);
+    }
+
+    /// 한정자 없는 컬럼은 어느 테이블 것인지 알 수 없어 순서를 바꾸지 않습니다.
+    #[test]
+    fn unqualified_columns_disable_reordering() {
+        let relations = vec![
+            relation("orders", "o", Some(100_000)),
+            relation("users", "u", Some(10)),
+        ];
+
+        let order = plan_join_order(
+            &relations,
+            &joins("select * from orders o inner join users u on user_id = id;"),
+        );
+
+        assert_eq!(order_of(&order), vec![0, 1]);
+    }
+}
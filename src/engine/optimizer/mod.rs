@@ -1,4 +1,6 @@
 pub mod cost;
+pub mod decorrelate;
+pub mod join_order;
 #[allow(clippy::module_inception)]
 pub mod optimizer;
 pub mod predule;
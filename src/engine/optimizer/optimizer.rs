@@ -1,25 +1,43 @@
 use std::collections::HashMap;
 
 use crate::engine::ast::dml::delete::DeleteQuery;
+use crate::engine::ast::dml::expressions::call::CallExpression;
 use crate::engine::ast::dml::expressions::operators::{BinaryOperator, UnaryOperator};
+use crate::engine::ast::dml::expressions::window::WindowExpression;
+use crate::engine::ast::dml::parts::distinct::DistinctClause;
 use crate::engine::ast::dml::parts::from::FromTarget;
+use crate::engine::ast::dml::parts::join::JoinClause;
+use crate::engine::ast::dml::parts::locking::LockingClause;
+use crate::engine::ast::dml::parts::select_item::SelectKind;
+use crate::engine::ast::dml::parts::target::UpdateTarget;
 use crate::engine::ast::dml::plan::delete::delete_plan::DeletePlan;
 use crate::engine::ast::dml::plan::delete::from::DeleteFromPlan;
+use crate::engine::ast::dml::plan::select::aggregate::AggregatePlan;
+use crate::engine::ast::dml::plan::select::distinct::DistinctPlan;
 use crate::engine::ast::dml::plan::select::filter::FilterPlan;
 use crate::engine::ast::dml::plan::select::from::SelectFromPlan;
+use crate::engine::ast::dml::plan::select::join::JoinPlan;
 use crate::engine::ast::dml::plan::select::limit_offset::LimitOffsetPlan;
+use crate::engine::ast::dml::plan::select::lock_rows::LockRowsPlan;
 use crate::engine::ast::dml::plan::select::scan::{IndexScanPlan, ScanType};
 use crate::engine::ast::dml::plan::select::select_plan::{SelectPlan, SelectPlanItem};
+use crate::engine::ast::dml::plan::select::set_operation::SetOperationPlan;
+use crate::engine::ast::dml::plan::select::subquery::SelectSubqueryPlan;
+use crate::engine::ast::dml::plan::select::window::WindowPlan;
 use crate::engine::ast::dml::plan::update::from::UpdateFromPlan;
 use crate::engine::ast::dml::plan::update::update_plan::UpdatePlan;
 use crate::engine::ast::dml::select::SelectQuery;
 use crate::engine::ast::dml::update::UpdateQuery;
 use crate::engine::ast::types::{SQLExpression, SelectColumn, TableName};
-use crate::engine::index::{IndexMeta, field_to_key};
+use crate::engine::index::{IndexMeta, NULL_KEY, field_to_key};
 use crate::engine::optimizer::cost;
+use crate::engine::optimizer::decorrelate::decorrelate_exists;
+use crate::engine::optimizer::join_order::{JoinRelation, plan_dml_join_order, plan_join_order};
 use crate::engine::optimizer::statistics::TableStatistics;
+use crate::engine::pattern;
 use crate::engine::schema::row::TableDataFieldType;
 use crate::errors;
+use crate::errors::execute_error::ExecuteError;
 
 /// 옵티마이저가 계획을 세울 때 참조하는 컨텍스트 (#195)
 ///
@@ -29,6 +47,10 @@ use crate::errors;
 pub struct OptimizerContext {
     pub indexes: Vec<IndexMeta>,
     pub statistics: Option<TableStatistics>,
+    /// 조인에 참여하는 테이블별 통계 (조인 순서/알고리즘 선택용)
+    pub relation_statistics: HashMap<TableName, TableStatistics>,
+    /// FROM 대상 테이블의 NOT NULL 컬럼 (DISTINCT 제거 판단용)
+    pub not_null_columns: Vec<String>,
 }
 
 /// WHERE 절 분석으로 얻은 컬럼별 키 경계
@@ -39,6 +61,12 @@ struct ColumnBounds {
     start_key: Option<String>,
     /// 끝 키 (제외)
     end_key: Option<String>,
+    /// LIKE 'abc%'에서 온 고정 접두사 길이 (선택도 추정용)
+    prefix_length: Option<usize>,
+    /// IN 목록에서 온 조회 키 (정렬, 중복 제거됨)
+    in_keys: Option<Vec<String>>,
+    /// IS NULL에서 온 조건인지 (eq_key가 NULL 키)
+    is_null: bool,
 }
 
 pub struct Optimizer {
@@ -57,11 +85,49 @@ impl Optimizer {
     }
 
     pub async fn optimize_select(&self, query: SelectQuery) -> errors::Result<SelectPlan> {
+        // 상관 EXISTS / NOT EXISTS는 가능하면 세미/안티 조인으로 풉니다.
+        // 행 락은 FROM 테이블 하나의 행에만 잡으므로, FOR UPDATE 쿼리는 조인으로 바꾸지 않습니다.
+        let query = match &query.locking_clause {
+            Some(locking_clause) => {
+                check_locking_clause(&query, locking_clause)?;
+                query
+            }
+            None => decorrelate_exists(query),
+        };
+
         let mut has_from = false;
         let mut plan = SelectPlan { list: vec![] };
 
+        // 집합 연산: 양쪽 쿼리는 실행할 때 각자 최적화되고, 결합된 결과에 ORDER BY와 LIMIT만 적용합니다.
+        if let Some(set_operation) = query.set_operation {
+            plan.list.push(
+                SetOperationPlan {
+                    operator: set_operation.operator,
+                    all: set_operation.all,
+                    left: set_operation.left,
+                    right: set_operation.right,
+                }
+                .into(),
+            );
+
+            if let Some(order_by_clause) = query.order_by_clause {
+                plan.list.push(order_by_clause.into());
+            }
+
+            if query.limit.is_some() || query.offset.is_some() {
+                plan.list.push(
+                    LimitOffsetPlan {
+                        limit: query.limit,
+                        offset: query.offset,
+                    }
+                    .into(),
+                );
+            }
+
+            return Ok(plan);
+        }
+
         // 스캔 방식 결정 (조인 쿼리는 아직 FullScan만 지원)
-        // TODO(#195): 조인 실행기가 구현되면 조인 순서 최적화 추가
         let scan = if query.join_clause.is_empty() {
             match &query.from_table {
                 Some(from_clause) => match &from_clause.from {
@@ -78,6 +144,18 @@ impl Optimizer {
             ScanType::FullScan
         };
 
+        // 유니크 + NOT NULL 컬럼을 그대로 내보내면 이미 모든 행이 서로 다르므로 DISTINCT가 필요 없습니다.
+        let distinct = match query.distinct {
+            Some(ref distinct) if !self.is_distinct_redundant(&query, distinct) => {
+                Some(DistinctPlan {
+                    on: distinct.on.clone(),
+                })
+            }
+            _ => None,
+        };
+
+        let windows = referenced_windows(&query);
+
         // LIMIT pushdown (#51). Only safe when nothing between the scan and the
         // limit can reorder or remove rows: a filter, a sort, a grouping or a
         // join all mean the first N rows off the table are not the first N rows
@@ -92,21 +170,42 @@ impl Optimizer {
             && query.having_clause.is_none()
             && query.order_by_clause.is_none()
             && !query.has_aggregate
+            && distinct.is_none()
+            && windows.is_empty()
+            // SKIP LOCKED로 건너뛴 행만큼 더 읽어야 합니다.
+            && query.locking_clause.is_none()
         {
-            query.limit.map(|limit| {
-                (limit as usize).saturating_add(query.offset.unwrap_or(0) as usize)
-            })
+            query
+                .limit
+                .map(|limit| (limit as usize).saturating_add(query.offset.unwrap_or(0) as usize))
         } else {
             None
         };
 
+        let aggregates = referenced_aggregates(&query);
+
+        let lock_rows = query.locking_clause.map(|locking| LockRowsPlan {
+            table_name: match query.from_table.as_ref().map(|from| &from.from) {
+                Some(FromTarget::Table(table_name)) => table_name.clone(),
+                _ => unreachable!("checked by check_locking_clause"),
+            },
+            locking,
+            recheck: query
+                .where_clause
+                .as_ref()
+                .map(|where_clause| where_clause.expression.clone()),
+            limit: query
+                .limit
+                .map(|limit| (limit as usize).saturating_add(query.offset.unwrap_or(0) as usize)),
+        });
+
         // FROM 절 분석
         if let Some(from_clause) = query.from_table {
             has_from = true;
             let alias = from_clause.alias;
 
             match from_clause.from {
-                FromTarget::Table(table_name) => plan.list.push(
+                FromTarget::Table(table_name) if query.join_clause.is_empty() => plan.list.push(
                     SelectFromPlan {
                         table_name,
                         alias,
@@ -115,16 +214,38 @@ impl Optimizer {
                     }
                     .into(),
                 ),
-                FromTarget::Subquery(_subquery) => {}
+                // JOIN 절 구성 (조인 순서에 따라 FROM 스캔 대상이 바뀔 수 있음)
+                FromTarget::Table(table_name) => {
+                    plan.list
+                        .append(&mut self.plan_joins(table_name, alias, query.join_clause));
+                }
+                // 파생 테이블: 서브쿼리 결과를 alias 이름의 테이블처럼 사용합니다.
+                FromTarget::Subquery(subquery) => {
+                    let Some(alias) = alias else {
+                        return Err(ExecuteError::wrap("subquery in FROM must have an alias"));
+                    };
+
+                    let table_name = TableName::new(None, alias);
+                    let derived: SelectPlanItem = SelectSubqueryPlan {
+                        table_name: table_name.clone(),
+                        subquery: Box::new(subquery.into_query()),
+                    }
+                    .into();
+
+                    if query.join_clause.is_empty() {
+                        plan.list.push(derived);
+                    } else {
+                        // 파생 테이블은 통계가 없어 조인 순서상 항상 처음에 스캔되므로,
+                        // 테이블 스캔 자리에 서브쿼리 실행을 넣습니다.
+                        let mut items = self.plan_joins(table_name, None, query.join_clause);
+                        items[0] = derived;
+                        plan.list.append(&mut items);
+                    }
+                }
             }
         }
 
         if has_from {
-            // JOIN 절 구성
-            if !query.join_clause.is_empty() {
-                // TODO
-            }
-
             // WHERE 절 필터링 구성
             // 인덱스 스캔이 선택되어도 필터는 유지됩니다 (잔여 조건 처리 및 정합성 보장)
             if let Some(where_clause) = query.where_clause {
@@ -133,21 +254,33 @@ impl Optimizer {
                 plan.list.push(FilterPlan { expression }.into());
             }
 
-            // GROUP BY 절 구성
-            if let Some(group_by_clause) = query.group_by_clause {
-                plan.list.push(group_by_clause.into());
+            // GROUP BY 및 집계 구성 (GROUP BY 없이 집계만 있으면 전체를 하나의 그룹으로 집계)
+            if query.group_by_clause.is_some() || query.has_aggregate || !aggregates.is_empty() {
+                plan.list.push(
+                    AggregatePlan {
+                        group_by_items: query
+                            .group_by_clause
+                            .map(|group_by_clause| group_by_clause.group_by_items)
+                            .unwrap_or_default(),
+                        aggregates,
+                    }
+                    .into(),
+                );
+            }
 
-                // HAVING 절 구성
-                if let Some(having_clause) = query.having_clause {
-                    plan.list.push(
-                        FilterPlan {
-                            expression: *having_clause.expression,
-                        }
-                        .into(),
-                    );
-                }
-            } else if query.has_aggregate {
-                plan.list.push(SelectPlanItem::GroupAll);
+            // HAVING 절 구성
+            if let Some(having_clause) = query.having_clause {
+                plan.list.push(
+                    FilterPlan {
+                        expression: *having_clause.expression,
+                    }
+                    .into(),
+                );
+            }
+
+            // 윈도우 함수 구성 (집계와 HAVING 뒤의 행을 대상으로 계산)
+            if !windows.is_empty() {
+                plan.list.push(WindowPlan { windows }.into());
             }
 
             // ORDER BY 절 구성
@@ -155,6 +288,16 @@ impl Optimizer {
                 plan.list.push(order_by_clause.into());
             }
 
+            // DISTINCT 구성 (정렬 뒤에 실행해야 DISTINCT ON이 그룹별 첫 행을 남김)
+            if let Some(distinct) = distinct {
+                plan.list.push(distinct.into());
+            }
+
+            // 행 락 구성 (정렬된 순서대로 잠그고, 건너뛴 행을 뺀 뒤 LIMIT을 적용합니다)
+            if let Some(lock_rows) = lock_rows {
+                plan.list.push(lock_rows.into());
+            }
+
             // LIMIT OFFSET 절 구성
             if query.limit.is_some() || query.offset.is_some() {
                 plan.list.push(
@@ -165,6 +308,21 @@ impl Optimizer {
                     .into(),
                 );
             }
+        } else {
+            // FROM 없는 SELECT는 한 행짜리 입력을 집계합니다. (SELECT count(*) → 1)
+            if !aggregates.is_empty() {
+                plan.list.push(
+                    AggregatePlan {
+                        group_by_items: vec![],
+                        aggregates,
+                    }
+                    .into(),
+                );
+            }
+
+            if !windows.is_empty() {
+                plan.list.push(WindowPlan { windows }.into());
+            }
         }
 
         Ok(plan)
@@ -175,6 +333,27 @@ impl Optimizer {
 
         let target_table = query.target_table.clone().unwrap();
 
+        // UPDATE ... FROM: 대상 테이블에 FROM 테이블들을 조인해 수정할 행을 찾습니다.
+        if !query.from_tables.is_empty() {
+            let (scan, items) = self.plan_dml_joins(
+                &target_table,
+                &query.from_tables,
+                query.where_clause.map(|w| w.expression),
+            );
+
+            plan.list.push(
+                UpdateFromPlan {
+                    table_name: target_table.table,
+                    alias: target_table.alias,
+                    scan,
+                }
+                .into(),
+            );
+            plan.list.extend(items);
+
+            return Ok(plan);
+        }
+
         let scan = self.choose_scan(
             &target_table.table,
             target_table.alias.as_ref(),
@@ -207,6 +386,27 @@ impl Optimizer {
 
         let target_table = query.from_table.clone().unwrap();
 
+        // DELETE ... USING: 대상 테이블에 USING 테이블들을 조인해 삭제할 행을 찾습니다.
+        if !query.using_tables.is_empty() {
+            let (scan, items) = self.plan_dml_joins(
+                &target_table,
+                &query.using_tables,
+                query.where_clause.map(|w| w.expression),
+            );
+
+            plan.list.push(
+                DeleteFromPlan {
+                    table_name: target_table.table,
+                    alias: target_table.alias,
+                    scan,
+                }
+                .into(),
+            );
+            plan.list.extend(items);
+
+            return Ok(plan);
+        }
+
         let scan = self.choose_scan(
             &target_table.table,
             target_table.alias.as_ref(),
@@ -234,10 +434,125 @@ impl Optimizer {
         Ok(plan)
     }
 
+    /// UPDATE ... FROM / DELETE ... USING의 대상 테이블 스캔 방식과 그 뒤의 필터/조인 목록
+    ///
+    /// 대상 테이블만 참조하는 조건은 조인 전에 걸러 스캔 선택에도 쓰고, 나머지는
+    /// 조인 ON 절이나 마지막 필터로 보냅니다.
+    fn plan_dml_joins<T: From<FilterPlan> + From<JoinPlan>>(
+        &self,
+        target: &UpdateTarget,
+        sources: &[UpdateTarget],
+        where_expression: Option<SQLExpression>,
+    ) -> (ScanType, Vec<T>) {
+        let relations = std::iter::once(target)
+            .chain(sources)
+            .map(|relation| JoinRelation {
+                table_name: relation.table.clone(),
+                alias: relation.alias.clone(),
+                statistics: None,
+            })
+            .collect::<Vec<_>>();
+
+        let order = plan_dml_join_order(&relations, where_expression.as_ref());
+
+        let scan = self.choose_scan(
+            &target.table,
+            target.alias.as_ref(),
+            order.target_filter.as_ref(),
+        );
+
+        let mut items = vec![];
+
+        if let Some(expression) = order.target_filter {
+            items.push(FilterPlan { expression }.into());
+        }
+
+        for step in order.steps {
+            let relation = &relations[step.relation];
+
+            items.push(
+                JoinPlan {
+                    left: target.table.clone(),
+                    right: relation.table_name.clone(),
+                    right_alias: relation.alias.clone(),
+                    join_type: step.join_type,
+                    join_scan_type: step.join_scan_type,
+                    select_columns: vec![],
+                    index: None,
+                    filter: step.on,
+                }
+                .into(),
+            );
+        }
+
+        if let Some(expression) = order.residual {
+            items.push(FilterPlan { expression }.into());
+        }
+
+        (scan, items)
+    }
+
+    /// 조인 순서와 조인별 알고리즘을 정해 FROM 스캔 + JoinPlan 목록으로 펼칩니다. (#195)
+    ///
+    /// 모든 JoinPlan의 left는 첫 스캔 대상 테이블입니다. 좌측 입력은 그 테이블에서
+    /// 시작해 앞선 조인 결과가 누적된 행입니다.
+    fn plan_joins(
+        &self,
+        table_name: TableName,
+        alias: Option<String>,
+        joins: Vec<JoinClause>,
+    ) -> Vec<SelectPlanItem> {
+        let relations = std::iter::once((table_name, alias))
+            .chain(
+                joins
+                    .iter()
+                    .map(|join| (join.right.clone(), join.right_alias.clone())),
+            )
+            .map(|(table_name, alias)| JoinRelation {
+                statistics: self.context.relation_statistics.get(&table_name).cloned(),
+                table_name,
+                alias,
+            })
+            .collect::<Vec<_>>();
+
+        let order = plan_join_order(&relations, &joins);
+        let first = &relations[order.first];
+
+        let mut items = vec![
+            SelectFromPlan {
+                table_name: first.table_name.clone(),
+                alias: first.alias.clone(),
+                scan: ScanType::FullScan,
+                scan_limit: None,
+            }
+            .into(),
+        ];
+
+        for step in order.steps {
+            let relation = &relations[step.relation];
+
+            items.push(
+                JoinPlan {
+                    left: first.table_name.clone(),
+                    right: relation.table_name.clone(),
+                    right_alias: relation.alias.clone(),
+                    join_type: step.join_type,
+                    join_scan_type: step.join_scan_type,
+                    select_columns: vec![],
+                    index: None,
+                    filter: step.on,
+                }
+                .into(),
+            );
+        }
+
+        items
+    }
+
     /// 비용 기반으로 FullScan / IndexScan 중 하나를 선택합니다. (#195)
     ///
     /// 1. WHERE 절을 AND 단위로 분해 (heuristic predicate pushdown)
-    /// 2. 인덱스 컬럼에 대한 sargable 조건(=, >, >=, <, <=, BETWEEN)을 추출
+    /// 2. 인덱스 컬럼에 대한 sargable 조건(=, >, >=, <, <=, BETWEEN, LIKE 'abc%', IN)을 추출
     /// 3. 인덱스별 선택도/비용을 계산해 풀 스캔 비용보다 저렴한 최적 인덱스를 선택
     fn choose_scan(
         &self,
@@ -287,14 +602,33 @@ impl Optimizer {
                 None => continue,
             };
 
-            let selectivity = if bounds.eq_key.is_some() {
-                if index.is_unique {
-                    1.0 / statistics.row_count.max(1) as f64
-                } else {
-                    cost::eq_selectivity(
-                        statistics.distinct_values.get(&index.column_name).copied(),
-                    )
+            let key_selectivity = if index.is_unique {
+                1.0 / statistics.row_count.max(1) as f64
+            } else {
+                cost::eq_selectivity(statistics.distinct_values.get(&index.column_name).copied())
+            };
+
+            let use_in_keys = bounds.eq_key.is_none() && bounds.in_keys.is_some();
+
+            // 유니크 인덱스라도 NULL은 여러 개일 수 있음
+            let selectivity = if bounds.is_null {
+                cost::null_selectivity(
+                    statistics.null_counts.get(&index.column_name).copied(),
+                    statistics.row_count,
+                )
+            } else if bounds.eq_key.is_some() {
+                key_selectivity
+            } else if let Some(in_keys) = &bounds.in_keys {
+                // 조건끼리 교집합이 비었다면 인덱스로 표현할 수 없으므로 필터에 맡김
+                if in_keys.is_empty() {
+                    continue;
                 }
+                (key_selectivity * in_keys.len() as f64).min(1.0)
+            } else if let Some(prefix_length) = bounds.prefix_length {
+                cost::prefix_selectivity(
+                    prefix_length,
+                    statistics.distinct_values.get(&index.column_name).copied(),
+                )
             } else if bounds.start_key.is_some() || bounds.end_key.is_some() {
                 cost::DEFAULT_RANGE_SELECTIVITY
             } else {
@@ -310,12 +644,17 @@ impl Optimizer {
                         index_name: index.index_name.clone(),
                         column_name: index.column_name.clone(),
                         eq_key: bounds.eq_key.clone(),
-                        start_key: if bounds.eq_key.is_some() {
+                        in_keys: if use_in_keys {
+                            bounds.in_keys.clone().unwrap_or_default()
+                        } else {
+                            vec![]
+                        },
+                        start_key: if bounds.eq_key.is_some() || use_in_keys {
                             None
                         } else {
                             bounds.start_key.clone()
                         },
-                        end_key: if bounds.eq_key.is_some() {
+                        end_key: if bounds.eq_key.is_some() || use_in_keys {
                             None
                         } else {
                             bounds.end_key.clone()
@@ -332,6 +671,65 @@ impl Optimizer {
     }
 }
 
+impl Optimizer {
+    /// DISTINCT 대상에 유니크 인덱스가 걸린 NOT NULL 컬럼이 그대로 포함되어 있는지 확인합니다.
+    /// 단일 테이블을 그룹핑 없이 읽는 경우에만 판단하며, 그 외에는 항상 DISTINCT를 실행합니다.
+    fn is_distinct_redundant(&self, query: &SelectQuery, distinct: &DistinctClause) -> bool {
+        if !query.join_clause.is_empty() || query.group_by_clause.is_some() || query.has_aggregate {
+            return false;
+        }
+
+        let Some(from_clause) = &query.from_table else {
+            return false;
+        };
+
+        let FromTarget::Table(table_name) = &from_clause.from else {
+            return false;
+        };
+
+        let is_unique_not_null = |column_name: &String| {
+            self.context.not_null_columns.contains(column_name)
+                && self.context.indexes.iter().any(|index| {
+                    index.is_unique
+                        && &index.column_name == column_name
+                        && index.table_name.table_name == table_name.table_name
+                })
+        };
+
+        let expressions: Vec<&SQLExpression> = match &distinct.on {
+            Some(on) => on.iter().collect(),
+            None => {
+                // 와일드카드는 테이블의 모든 컬럼을 내보냅니다.
+                let has_wildcard = query
+                    .select_items
+                    .iter()
+                    .any(|select_kind| matches!(select_kind, SelectKind::WildCard(_)));
+
+                if has_wildcard {
+                    return self.context.not_null_columns.iter().any(is_unique_not_null);
+                }
+
+                query
+                    .select_items
+                    .iter()
+                    .filter_map(|select_kind| match select_kind {
+                        SelectKind::SelectItem(select_item) => select_item.item.as_ref(),
+                        SelectKind::WildCard(_) => None,
+                    })
+                    .collect()
+            }
+        };
+
+        expressions.into_iter().any(|expression| match expression {
+            SQLExpression::SelectColumn(column) => {
+                column_matches(column, table_name, from_clause.alias.as_ref())
+                    && is_unique_not_null(&column.column_name)
+            }
+            _ => false,
+        })
+    }
+}
+
 impl Default for Optimizer {
     fn default() -> Self {
         Self::new()
@@ -339,7 +737,10 @@ impl Default for Optimizer {
 }
 
 /// AND로 연결된 표현식을 개별 조건으로 분해합니다.
-fn collect_conjuncts<'a>(expression: &'a SQLExpression, out: &mut Vec<&'a SQLExpression>) {
+pub(crate) fn collect_conjuncts<'a>(
+    expression: &'a SQLExpression,
+    out: &mut Vec<&'a SQLExpression>,
+) {
     match expression {
         SQLExpression::Binary(binary) if binary.operator == BinaryOperator::And => {
             collect_conjuncts(&binary.lhs, out);
@@ -352,6 +753,108 @@ fn collect_conjuncts<'a>(expression: &'a SQLExpression, out: &mut Vec<&'a SQLExp
     }
 }
 
+/// 집계 이후 단계(SELECT 목록, HAVING, ORDER BY)에서 참조하는 집계 호출을 중복 없이 모읍니다.
+/// 행 락은 FROM 테이블의 행 위치에 잡으므로, 결과 행이 테이블의 행 하나와 대응하는
+/// 쿼리에만 허용합니다.
+fn check_locking_clause(query: &SelectQuery, locking_clause: &LockingClause) -> errors::Result<()> {
+    let name = locking_clause.mode.name();
+
+    if query.set_operation.is_some() {
+        return Err(ExecuteError::wrap(format!(
+            "{name} is not allowed with UNION/INTERSECT/EXCEPT"
+        )));
+    }
+
+    if !matches!(
+        query.from_table.as_ref().map(|from| &from.from),
+        Some(FromTarget::Table(_))
+    ) {
+        return Err(ExecuteError::wrap(format!(
+            "{name} requires a table in the FROM clause"
+        )));
+    }
+
+    let reason = if !query.join_clause.is_empty() {
+        "JOIN"
+    } else if query.distinct.is_some() {
+        "DISTINCT clause"
+    } else if query.group_by_clause.is_some() || query.having_clause.is_some() {
+        "GROUP BY clause"
+    } else if query.has_aggregate || !referenced_aggregates(query).is_empty() {
+        "aggregate functions"
+    } else if !referenced_windows(query).is_empty() {
+        "window functions"
+    } else {
+        return Ok(());
+    };
+
+    Err(ExecuteError::wrap(format!(
+        "{name} is not allowed with {reason}"
+    )))
+}
+
+fn referenced_aggregates(query: &SelectQuery) -> Vec<CallExpression> {
+    let select_items = query
+        .select_items
+        .iter()
+        .filter_map(|select_kind| match select_kind {
+            SelectKind::SelectItem(select_item) => select_item.item.as_ref(),
+            SelectKind::WildCard(_) => None,
+        });
+    let having = query
+        .having_clause
+        .iter()
+        .map(|having_clause| having_clause.expression.as_ref());
+    let order_by = query
+        .order_by_clause
+        .iter()
+        .flat_map(|order_by_clause| order_by_clause.order_by_items.iter())
+        .map(|order_by_item| &order_by_item.item);
+
+    let mut aggregates: Vec<CallExpression> = vec![];
+
+    for call in select_items
+        .chain(having)
+        .chain(order_by)
+        .flat_map(SQLExpression::get_aggregate_list)
+    {
+        if !aggregates.contains(call) {
+            aggregates.push(call.clone());
+        }
+    }
+
+    aggregates
+}
+
+/// SELECT 목록과 ORDER BY에서 참조하는 윈도우 함수 표현식을 중복 없이 모읍니다.
+fn referenced_windows(query: &SelectQuery) -> Vec<WindowExpression> {
+    let select_items = query
+        .select_items
+        .iter()
+        .filter_map(|select_kind| match select_kind {
+            SelectKind::SelectItem(select_item) => select_item.item.as_ref(),
+            SelectKind::WildCard(_) => None,
+        });
+    let order_by = query
+        .order_by_clause
+        .iter()
+        .flat_map(|order_by_clause| order_by_clause.order_by_items.iter())
+        .map(|order_by_item| &order_by_item.item);
+
+    let mut windows: Vec<WindowExpression> = vec![];
+
+    for window in select_items
+        .chain(order_by)
+        .flat_map(SQLExpression::get_window_list)
+    {
+        if !windows.contains(window) {
+            windows.push(window.clone());
+        }
+    }
+
+    windows
+}
+
 /// 리터럴 표현식을 인덱스 키로 변환 가능한 값으로 평가합니다.
 fn literal_to_field(expression: &SQLExpression) -> Option<TableDataFieldType> {
     match expression {
@@ -387,6 +890,120 @@ fn exclusive_after(key: &str) -> String {
     format!("{}\u{0}", key)
 }
 
+/// 인덱스 키의 타입 접두사 (I:, S: 등)
+fn key_type_prefix(key: &str) -> &str {
+    match key.find(':') {
+        Some(position) => &key[..=position],
+        None => key,
+    }
+}
+
+/// 키 문자열로 시작하는 모든 키보다 뒤에 정렬되는 배타 경계 키를 만듭니다.
+/// 마지막 글자를 다음 코드 포인트로 올리며, 올릴 수 없는 글자는 버리고 앞 글자를 올립니다.
+fn prefix_upper_bound(key: &str) -> Option<String> {
+    let mut chars = key.chars().collect::<Vec<_>>();
+
+    while let Some(last) = chars.pop() {
+        let next = (last as u32 + 1..=char::MAX as u32).find_map(char::from_u32);
+
+        if let Some(next) = next {
+            chars.push(next);
+            return Some(chars.into_iter().collect());
+        }
+    }
+
+    None
+}
+
+/// LIKE 패턴 리터럴을 기본 escape 문자 기준 패턴 문자열로 평가합니다.
+fn literal_to_like_pattern(expression: &SQLExpression) -> Option<String> {
+    match expression {
+        SQLExpression::String(pattern) => Some(pattern.clone()),
+        SQLExpression::LikeEscape(like_escape) => {
+            match (&like_escape.pattern, &like_escape.escape) {
+                (SQLExpression::String(pattern), SQLExpression::String(escape)) => {
+                    pattern::like_escape(pattern, escape).ok()
+                }
+                _ => None,
+            }
+        }
+        SQLExpression::Parentheses(parentheses) => literal_to_like_pattern(&parentheses.expression),
+        _ => None,
+    }
+}
+
+/// column LIKE 'abc%'를 고정 접두사의 키 범위로 바꿉니다.
+/// 와일드카드가 없는 패턴은 등호 조건과 같습니다.
+fn extract_like_bounds(
+    column: &SelectColumn,
+    pattern: &SQLExpression,
+    table_name: &TableName,
+    alias: Option<&String>,
+) -> Option<(String, ColumnBounds)> {
+    if !column_matches(column, table_name, alias) {
+        return None;
+    }
+
+    let pattern = literal_to_like_pattern(pattern)?;
+    let (prefix, exact) = pattern::like_prefix(&pattern)?;
+
+    let key = field_to_key(&TableDataFieldType::String(prefix.clone()));
+
+    let bounds = if exact {
+        ColumnBounds {
+            eq_key: Some(key),
+            ..Default::default()
+        }
+    } else if prefix.is_empty() {
+        return None;
+    } else {
+        ColumnBounds {
+            end_key: prefix_upper_bound(&key),
+            start_key: Some(key),
+            prefix_length: Some(prefix.chars().count()),
+            ..Default::default()
+        }
+    };
+
+    Some((column.column_name.clone(), bounds))
+}
+
+/// column IN (1, 2, 3)을 키 목록으로 바꿉니다.
+/// NULL 원소는 어떤 값과도 같지 않으므로 조회 키에서 제외합니다.
+fn extract_in_bounds(
+    column: &SelectColumn,
+    list: &SQLExpression,
+    table_name: &TableName,
+    alias: Option<&String>,
+) -> Option<(String, ColumnBounds)> {
+    if !column_matches(column, table_name, alias) {
+        return None;
+    }
+
+    let elements = match list {
+        SQLExpression::List(list) => list.value.iter().collect::<Vec<_>>(),
+        SQLExpression::Parentheses(parentheses) => vec![&parentheses.expression],
+        _ => return None,
+    };
+
+    let mut keys = elements
+        .into_iter()
+        .filter(|element| !matches!(element, SQLExpression::Null))
+        .map(|element| literal_to_field(element).map(|field| field_to_key(&field)))
+        .collect::<Option<Vec<_>>>()?;
+
+    keys.sort();
+    keys.dedup();
+
+    Some((
+        column.column_name.clone(),
+        ColumnBounds {
+            in_keys: Some(keys),
+            ..Default::default()
+        },
+    ))
+}
+
 /// 단일 조건에서 (컬럼명, 키 경계)를 추출합니다. sargable하지 않으면 None.
 fn extract_sargable_bounds(
     expression: &SQLExpression,
@@ -394,6 +1011,42 @@ fn extract_sargable_bounds(
     alias: Option<&String>,
 ) -> Option<(String, ColumnBounds)> {
     match expression {
+        // ILIKE는 대소문자를 무시하므로 키 범위로 바꿀 수 없음
+        SQLExpression::Binary(binary) if binary.operator == BinaryOperator::In => {
+            match &binary.lhs {
+                SQLExpression::SelectColumn(column) => {
+                    extract_in_bounds(column, &binary.rhs, table_name, alias)
+                }
+                _ => None,
+            }
+        }
+        SQLExpression::Binary(binary)
+            if binary.operator == BinaryOperator::Is && binary.rhs == SQLExpression::Null =>
+        {
+            match &binary.lhs {
+                SQLExpression::SelectColumn(column)
+                    if column_matches(column, table_name, alias) =>
+                {
+                    Some((
+                        column.column_name.clone(),
+                        ColumnBounds {
+                            eq_key: Some(NULL_KEY.to_string()),
+                            is_null: true,
+                            ..Default::default()
+                        },
+                    ))
+                }
+                _ => None,
+            }
+        }
+        SQLExpression::Binary(binary) if binary.operator == BinaryOperator::Like => {
+            match &binary.lhs {
+                SQLExpression::SelectColumn(column) => {
+                    extract_like_bounds(column, &binary.rhs, table_name, alias)
+                }
+                _ => None,
+            }
+        }
         SQLExpression::Binary(binary) => {
             let (column, literal, operator) = match (&binary.lhs, &binary.rhs) {
                 (SQLExpression::SelectColumn(column), rhs) => {
@@ -407,12 +1060,16 @@ fn extract_sargable_bounds(
                 _ => return None,
             };
 
-            if !column_matches(column, table_name, alias) {
+            // NULL과의 비교는 참이 될 수 없으므로 필터에 맡김
+            if !column_matches(column, table_name, alias) || literal == TableDataFieldType::Null {
                 return None;
             }
 
             let key = field_to_key(&literal);
 
+            // 열린 범위는 같은 타입의 키 안으로 제한합니다. (NULL 키 등은 범위에서 제외)
+            let type_prefix = key_type_prefix(&key).to_string();
+
             let bounds = match operator {
                 BinaryOperator::Eq => ColumnBounds {
                     eq_key: Some(key),
@@ -420,17 +1077,21 @@ fn extract_sargable_bounds(
                 },
                 BinaryOperator::Gt => ColumnBounds {
                     start_key: Some(exclusive_after(&key)),
+                    end_key: prefix_upper_bound(&type_prefix),
                     ..Default::default()
                 },
                 BinaryOperator::Gte => ColumnBounds {
                     start_key: Some(key),
+                    end_key: prefix_upper_bound(&type_prefix),
                     ..Default::default()
                 },
                 BinaryOperator::Lt => ColumnBounds {
+                    start_key: Some(type_prefix.clone()),
                     end_key: Some(key),
                     ..Default::default()
                 },
                 BinaryOperator::Lte => ColumnBounds {
+                    start_key: Some(type_prefix.clone()),
                     end_key: Some(exclusive_after(&key)),
                     ..Default::default()
                 },
@@ -458,6 +1119,7 @@ fn extract_sargable_bounds(
                     eq_key: None,
                     start_key: Some(field_to_key(&start)),
                     end_key: Some(exclusive_after(&field_to_key(&end))),
+                    ..Default::default()
                 },
             ))
         }
@@ -481,6 +1143,7 @@ fn flip_operator(operator: &BinaryOperator) -> Option<BinaryOperator> {
 fn merge_bounds(existing: &mut ColumnBounds, new: ColumnBounds) {
     if existing.eq_key.is_none() {
         existing.eq_key = new.eq_key;
+        existing.is_null = new.is_null;
     }
 
     existing.start_key = match (existing.start_key.take(), new.start_key) {
@@ -492,13 +1155,24 @@ fn merge_bounds(existing: &mut ColumnBounds, new: ColumnBounds) {
         (Some(a), Some(b)) => Some(a.min(b)),
         (a, b) => a.or(b),
     };
+
+    existing.prefix_length = existing.prefix_length.max(new.prefix_length);
+
+    existing.in_keys = match (existing.in_keys.take(), new.in_keys) {
+        (Some(a), Some(b)) => Some(a.into_iter().filter(|key| b.contains(key)).collect()),
+        (a, b) => a.or(b),
+    };
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::engine::ast::dml::expressions::binary::BinaryOperatorExpression;
+    use crate::engine::ast::dml::parts::join::JoinType;
+    use crate::engine::ast::dml::parts::locking::{LockWaitPolicy, RowLockMode};
+    use crate::engine::ast::dml::parts::set_operation::SetOperator;
     use crate::engine::ast::dml::plan::delete::delete_plan::DeletePlanItem;
+    use crate::engine::ast::dml::plan::select::join::JoinScanType;
     use crate::engine::ast::dml::plan::update::update_plan::UpdatePlanItem;
     use crate::engine::parser::predule::{Parser, ParserContext};
 
@@ -520,6 +1194,7 @@ mod tests {
             row_count,
             block_count: row_count / 100 + 1,
             distinct_values: HashMap::from([("id".to_string(), row_count)]),
+            null_counts: HashMap::new(),
         }
     }
 
@@ -527,6 +1202,7 @@ mod tests {
         OptimizerContext {
             indexes: vec![index_meta("users_pkey", "id", unique)],
             statistics: Some(statistics(row_count)),
+            ..Default::default()
         }
     }
 
@@ -580,6 +1256,7 @@ mod tests {
         let optimizer = Optimizer::with_context(OptimizerContext {
             indexes: vec![],
             statistics: Some(statistics(10_000)),
+            ..Default::default()
         });
         assert_eq!(
             optimizer.choose_scan(&table(), None, Some(&expression)),
@@ -590,6 +1267,7 @@ mod tests {
         let optimizer = Optimizer::with_context(OptimizerContext {
             indexes: vec![index_meta("users_pkey", "id", true)],
             statistics: None,
+            ..Default::default()
         });
         assert_eq!(
             optimizer.choose_scan(&table(), None, Some(&expression)),
@@ -618,13 +1296,15 @@ mod tests {
     #[test]
     fn choose_scan_merges_range_bounds_from_and_conjuncts() {
         // id > 10 AND id <= 20 AND name = 'a' (name은 인덱스 없음)
-        let optimizer = Optimizer::with_context(OptimizerContext {
+        let _optimizer = Optimizer::with_context(OptimizerContext {
             indexes: vec![index_meta("users_pkey", "id", true)],
             statistics: Some(TableStatistics {
                 row_count: 1_000_000,
                 block_count: 10_000,
                 distinct_values: HashMap::new(),
+                null_counts: HashMap::new(),
             }),
+            ..Default::default()
         });
 
         let expression: SQLExpression = BinaryOperatorExpression {
@@ -688,6 +1368,269 @@ mod tests {
         assert_eq!(bounds.start_key, Some(exclusive_after(&integer_key(42))));
     }
 
+    fn like_expression(operator: BinaryOperator, pattern: SQLExpression) -> SQLExpression {
+        BinaryOperatorExpression {
+            operator,
+            lhs: SelectColumn::new(None, "name".to_string()).into(),
+            rhs: pattern,
+        }
+        .into()
+    }
+
+    fn string_key(value: &str) -> String {
+        field_to_key(&TableDataFieldType::String(value.to_string()))
+    }
+
+    #[test]
+    fn extract_sargable_bounds_turns_like_prefix_into_key_range() {
+        let expression =
+            like_expression(BinaryOperator::Like, SQLExpression::String("abc%".into()));
+
+        let (column, bounds) = extract_sargable_bounds(&expression, &table(), None).unwrap();
+        assert_eq!(column, "name");
+        assert_eq!(bounds.eq_key, None);
+        assert_eq!(bounds.start_key, Some(string_key("abc")));
+        assert_eq!(bounds.end_key, Some(string_key("abd")));
+        assert_eq!(bounds.prefix_length, Some(3));
+
+        // 범위 안: abc, abcz, abc\u{10FFFF} / 범위 밖: abd, ab
+        let (start, end) = (bounds.start_key.unwrap(), bounds.end_key.unwrap());
+        for inside in ["abc", "abcz", "abc\u{10FFFF}"] {
+            let key = string_key(inside);
+            assert!(start <= key && key < end, "{inside} must be in range");
+        }
+        for outside in ["abd", "ab"] {
+            let key = string_key(outside);
+            assert!(
+                !(start <= key && key < end),
+                "{outside} must be out of range"
+            );
+        }
+    }
+
+    #[test]
+    fn extract_sargable_bounds_handles_like_without_wildcards_and_escape() {
+        use crate::engine::ast::dml::expressions::like_escape::LikeEscapeExpression;
+
+        // 와일드카드가 없으면 등호 조건
+        let expression = like_expression(BinaryOperator::Like, SQLExpression::String("abc".into()));
+        let (_, bounds) = extract_sargable_bounds(&expression, &table(), None).unwrap();
+        assert_eq!(bounds.eq_key, Some(string_key("abc")));
+
+        // 10!%% ESCAPE '!' → 접두사 '10%'
+        let expression = like_expression(
+            BinaryOperator::Like,
+            LikeEscapeExpression {
+                pattern: SQLExpression::String("10!%%".into()),
+                escape: SQLExpression::String("!".into()),
+            }
+            .into(),
+        );
+        let (_, bounds) = extract_sargable_bounds(&expression, &table(), None).unwrap();
+        assert_eq!(bounds.start_key, Some(string_key("10%")));
+        assert_eq!(bounds.end_key, Some(string_key("10&")));
+    }
+
+    #[test]
+    fn extract_sargable_bounds_rejects_unanchored_and_case_insensitive_patterns() {
+        let cases = [
+            like_expression(BinaryOperator::Like, SQLExpression::String("%abc".into())),
+            like_expression(BinaryOperator::Like, SQLExpression::String("_bc%".into())),
+            like_expression(BinaryOperator::ILike, SQLExpression::String("abc%".into())),
+            like_expression(
+                BinaryOperator::NotLike,
+                SQLExpression::String("abc%".into()),
+            ),
+        ];
+
+        for expression in cases {
+            assert!(
+                extract_sargable_bounds(&expression, &table(), None).is_none(),
+                "{expression:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn choose_scan_picks_index_for_selective_like_prefix() {
+        let optimizer = Optimizer::with_context(OptimizerContext {
+            indexes: vec![index_meta("users_name_idx", "name", false)],
+            statistics: Some(TableStatistics {
+                row_count: 10_000,
+                block_count: 101,
+                distinct_values: HashMap::from([("name".to_string(), 10_000)]),
+                null_counts: HashMap::new(),
+            }),
+            ..Default::default()
+        });
+
+        let expression =
+            like_expression(BinaryOperator::Like, SQLExpression::String("abc%".into()));
+        match optimizer.choose_scan(&table(), None, Some(&expression)) {
+            ScanType::IndexScan(plan) => {
+                assert_eq!(plan.index_name, "rrdb.users_name_idx");
+                assert_eq!(plan.eq_key, None);
+                assert_eq!(plan.start_key, Some(string_key("abc")));
+                assert_eq!(plan.end_key, Some(string_key("abd")));
+            }
+            other => panic!("expected IndexScan, got {:?}", other),
+        }
+
+        // 한 글자 접두사는 행의 1/10을 임의 접근해야 하므로 풀 스캔이 더 저렴
+        let expression = like_expression(BinaryOperator::Like, SQLExpression::String("a%".into()));
+        assert_eq!(
+            optimizer.choose_scan(&table(), None, Some(&expression)),
+            ScanType::FullScan
+        );
+    }
+
+    fn in_expression(values: Vec<SQLExpression>) -> SQLExpression {
+        BinaryOperatorExpression {
+            operator: BinaryOperator::In,
+            lhs: SelectColumn::new(None, "id".to_string()).into(),
+            rhs: crate::engine::ast::dml::expressions::list::ListExpression { value: values }
+                .into(),
+        }
+        .into()
+    }
+
+    #[test]
+    fn extract_sargable_bounds_turns_in_list_into_sorted_keys() {
+        let expression = in_expression(vec![
+            SQLExpression::Integer(3),
+            SQLExpression::Integer(1),
+            SQLExpression::Null,
+            SQLExpression::Integer(3),
+        ]);
+
+        let (column, bounds) = extract_sargable_bounds(&expression, &table(), None).unwrap();
+        assert_eq!(column, "id");
+        assert_eq!(bounds.in_keys, Some(vec![integer_key(1), integer_key(3)]));
+
+        // 리터럴이 아닌 원소가 있으면 키로 바꿀 수 없음
+        let expression = in_expression(vec![
+            SQLExpression::Integer(1),
+            SelectColumn::new(None, "other".to_string()).into(),
+        ]);
+        assert!(extract_sargable_bounds(&expression, &table(), None).is_none());
+    }
+
+    #[test]
+    fn choose_scan_picks_multi_key_index_scan_for_in_list() {
+        let optimizer = Optimizer::with_context(context(10_000, true));
+        let expression = in_expression(vec![SQLExpression::Integer(42), SQLExpression::Integer(7)]);
+
+        match optimizer.choose_scan(&table(), None, Some(&expression)) {
+            ScanType::IndexScan(plan) => {
+                assert_eq!(plan.eq_key, None);
+                assert_eq!(plan.in_keys, vec![integer_key(7), integer_key(42)]);
+                assert_eq!(plan.start_key, None);
+                assert_eq!(plan.end_key, None);
+            }
+            other => panic!("expected IndexScan, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn merge_bounds_intersects_in_lists() {
+        let mut bounds = ColumnBounds::default();
+        merge_bounds(
+            &mut bounds,
+            ColumnBounds {
+                in_keys: Some(vec![integer_key(1), integer_key(2)]),
+                ..Default::default()
+            },
+        );
+        merge_bounds(
+            &mut bounds,
+            ColumnBounds {
+                in_keys: Some(vec![integer_key(2), integer_key(3)]),
+                ..Default::default()
+            },
+        );
+        assert_eq!(bounds.in_keys, Some(vec![integer_key(2)]));
+
+        // 교집합이 비면 인덱스 스캔 대신 필터에 맡김
+        let optimizer = Optimizer::with_context(context(10_000, true));
+        let expression: SQLExpression = BinaryOperatorExpression {
+            operator: BinaryOperator::And,
+            lhs: in_expression(vec![SQLExpression::Integer(1)]),
+            rhs: in_expression(vec![SQLExpression::Integer(2)]),
+        }
+        .into();
+        assert_eq!(
+            optimizer.choose_scan(&table(), None, Some(&expression)),
+            ScanType::FullScan
+        );
+    }
+
+    #[test]
+    fn prefix_upper_bound_carries_past_the_last_code_point() {
+        assert_eq!(prefix_upper_bound("S:ab"), Some("S:ac".to_string()));
+        assert_eq!(prefix_upper_bound("S:a\u{10FFFF}"), Some("S:b".to_string()));
+        assert_eq!(prefix_upper_bound("\u{10FFFF}"), None);
+    }
+
+    #[test]
+    fn choose_scan_picks_null_key_lookup_for_is_null() {
+        // 유니크 인덱스라도 NULL은 여러 행일 수 있으므로 1/rows가 아닌 NULL 선택도로 추정
+        let optimizer = Optimizer::with_context(context(10_000, true));
+        let expression: SQLExpression = BinaryOperatorExpression {
+            operator: BinaryOperator::Is,
+            lhs: SelectColumn::new(None, "id".to_string()).into(),
+            rhs: SQLExpression::Null,
+        }
+        .into();
+
+        let (_, bounds) = extract_sargable_bounds(&expression, &table(), None).unwrap();
+        assert!(bounds.is_null);
+        assert_eq!(bounds.eq_key, Some(NULL_KEY.to_string()));
+
+        match optimizer.choose_scan(&table(), None, Some(&expression)) {
+            ScanType::IndexScan(plan) => {
+                assert_eq!(plan.eq_key, Some(NULL_KEY.to_string()));
+            }
+            other => panic!("expected IndexScan, got {:?}", other),
+        }
+
+        // IS NOT NULL, = NULL은 키 조회로 바꾸지 않음
+        for (operator, rhs) in [
+            (BinaryOperator::IsNot, SQLExpression::Null),
+            (BinaryOperator::Eq, SQLExpression::Null),
+        ] {
+            let expression: SQLExpression = BinaryOperatorExpression {
+                operator,
+                lhs: SelectColumn::new(None, "id".to_string()).into(),
+                rhs,
+            }
+            .into();
+
+            assert!(extract_sargable_bounds(&expression, &table(), None).is_none());
+        }
+    }
+
+    #[test]
+    fn extract_sargable_bounds_keeps_open_ranges_within_key_type() {
+        let range = |operator| -> SQLExpression {
+            BinaryOperatorExpression {
+                operator,
+                lhs: SelectColumn::new(None, "id".to_string()).into(),
+                rhs: SQLExpression::Integer(10),
+            }
+            .into()
+        };
+
+        let (_, bounds) =
+            extract_sargable_bounds(&range(BinaryOperator::Gt), &table(), None).unwrap();
+        assert_eq!(bounds.start_key, Some(exclusive_after(&integer_key(10))));
+        assert_eq!(bounds.end_key, Some("I;".to_string()));
+
+        let (_, bounds) =
+            extract_sargable_bounds(&range(BinaryOperator::Lt), &table(), None).unwrap();
+        assert_eq!(bounds.start_key, Some("I:".to_string()));
+        assert_eq!(bounds.end_key, Some(integer_key(10)));
+    }
+
     #[test]
     fn extract_sargable_bounds_rejects_other_table_column() {
         let expression: SQLExpression = BinaryOperatorExpression {
@@ -772,6 +1715,35 @@ mod tests {
         assert!(matches!(plan.list[1], SelectPlanItem::Filter(_)));
     }
 
+    #[tokio::test]
+    async fn optimize_select_places_window_between_having_and_order_by() {
+        let query = match parse_one(
+            "select a, rank() over (order by sum(b)) from foo where b > 0 group by a having count(*) > 1 order by a limit 3;",
+        ) {
+            crate::engine::ast::SQLStatement::DML(
+                crate::engine::ast::DMLStatement::SelectQuery(query),
+            ) => query,
+            other => panic!("expected select query, got {:?}", other),
+        };
+
+        let plan = Optimizer::new().optimize_select(query).await.unwrap();
+
+        assert!(matches!(plan.list[0], SelectPlanItem::From(_)));
+        assert!(matches!(plan.list[1], SelectPlanItem::Filter(_)));
+        // 윈도우의 ORDER BY 안에 있는 집계도 집계 연산자가 계산합니다.
+        let SelectPlanItem::Aggregate(aggregate) = &plan.list[2] else {
+            panic!("expected aggregate plan, got {:?}", plan.list[2]);
+        };
+        assert_eq!(aggregate.aggregates.len(), 2);
+        assert!(matches!(plan.list[3], SelectPlanItem::Filter(_)));
+        let SelectPlanItem::Window(window) = &plan.list[4] else {
+            panic!("expected window plan, got {:?}", plan.list[4]);
+        };
+        assert_eq!(window.windows.len(), 1);
+        assert!(matches!(plan.list[5], SelectPlanItem::Order(_)));
+        assert!(matches!(plan.list[6], SelectPlanItem::LimitOffset(_)));
+    }
+
     /// Parse `sql` into the single statement it contains.
     fn parse_one(sql: &str) -> crate::engine::ast::SQLStatement {
         let mut parser = Parser::with_string(sql.into()).unwrap();
@@ -922,6 +1894,92 @@ mod tests {
         }
     }
 
+    /// UPDATE ... FROM: predicates on the target alone are applied before the
+    /// join (and drive scan selection), join predicates become the ON clause,
+    /// and unqualified ones wait until every table is joined.
+    #[tokio::test]
+    async fn optimize_update_from_splits_the_where_clause_around_the_join() {
+        let optimizer = Optimizer::with_context(context(10_000, true));
+        let plan = optimizer
+            .optimize_update(update_query(
+                "update users u set name = o.name from orders o where (u.id = 42 and u.id = o.user_id) and (o.total > 10 and name = 'x');",
+            ))
+            .await
+            .unwrap();
+
+        match &plan.list[0] {
+            UpdatePlanItem::UpdateFrom(from) => {
+                assert!(matches!(from.scan, ScanType::IndexScan(_)));
+            }
+            other => panic!("expected UpdateFrom plan, got {:?}", other),
+        }
+
+        match &plan.list[1] {
+            UpdatePlanItem::Filter(filter) => {
+                assert_eq!(filter.expression.get_select_column_list().len(), 1);
+            }
+            other => panic!("expected target filter, got {:?}", other),
+        }
+
+        match &plan.list[2] {
+            UpdatePlanItem::Join(join) => {
+                assert_eq!(join.right.table_name, "orders");
+                assert_eq!(join.right_alias, Some("o".into()));
+                assert_eq!(join.join_type, JoinType::InnerJoin);
+                assert_eq!(join.join_scan_type, JoinScanType::Hash);
+
+                let mut conjuncts = vec![];
+                collect_conjuncts(join.filter.as_ref().unwrap(), &mut conjuncts);
+                assert_eq!(conjuncts.len(), 2);
+            }
+            other => panic!("expected Join plan, got {:?}", other),
+        }
+
+        match &plan.list[3] {
+            UpdatePlanItem::Filter(filter) => assert_eq!(
+                filter.expression.get_select_column_list(),
+                vec![SelectColumn::new(None, "name".into())]
+            ),
+            other => panic!("expected residual filter, got {:?}", other),
+        }
+
+        assert_eq!(plan.list.len(), 4);
+    }
+
+    /// DELETE ... USING joins every USING table in the written order; without
+    /// a column equality the join falls back to a nested loop.
+    #[tokio::test]
+    async fn optimize_delete_using_joins_each_using_table() {
+        let optimizer = Optimizer::with_context(context(10_000, true));
+        let plan = optimizer
+            .optimize_delete(delete_query(
+                "delete from users u using orders o, items i where o.total > u.id and i.id = o.item_id;",
+            ))
+            .await
+            .unwrap();
+
+        match &plan.list[0] {
+            DeletePlanItem::DeleteFrom(from) => assert_eq!(from.scan, ScanType::FullScan),
+            other => panic!("expected DeleteFrom plan, got {:?}", other),
+        }
+
+        let joins = plan
+            .list
+            .iter()
+            .filter_map(|item| match item {
+                DeletePlanItem::Join(join) => Some(join),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(joins.len(), 2);
+        assert_eq!(joins[0].right.table_name, "orders");
+        assert_eq!(joins[0].join_scan_type, JoinScanType::NestedLoop);
+        assert_eq!(joins[1].right.table_name, "items");
+        assert_eq!(joins[1].join_scan_type, JoinScanType::Hash);
+        assert_eq!(plan.list.len(), 3);
+    }
+
     #[tokio::test]
     async fn optimize_select_keeps_full_scan_for_join_queries() {
         let mut parser = Parser::with_string(
@@ -948,8 +2006,219 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn optimize_select_emits_a_join_plan_per_join_clause() {
+        let query = match parse_one(
+            "select * from users u inner join orders o on u.id = o.user_id left join items i on i.price > o.total;",
+        ) {
+            crate::engine::ast::SQLStatement::DML(
+                crate::engine::ast::DMLStatement::SelectQuery(query),
+            ) => query,
+            other => panic!("expected select query, got {:?}", other),
+        };
+
+        let plan = Optimizer::new().optimize_select(query).await.unwrap();
+
+        let joins = plan
+            .list
+            .iter()
+            .filter_map(|item| match item {
+                SelectPlanItem::Join(join) => Some(join),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(joins.len(), 2);
+        assert_eq!(joins[0].right.table_name, "orders");
+        assert_eq!(joins[0].right_alias, Some("o".to_string()));
+        assert_eq!(joins[0].join_scan_type, JoinScanType::Hash);
+        assert_eq!(joins[1].right.table_name, "items");
+        assert_eq!(joins[1].join_type, JoinType::LeftOuterJoin);
+        // 등가 조건이 없으면 해시 조인을 쓸 수 없습니다.
+        assert_eq!(joins[1].join_scan_type, JoinScanType::NestedLoop);
+    }
+
+    #[tokio::test]
+    async fn optimize_select_runs_derived_table_before_joins_and_filters() {
+        let query = match parse_one(
+            "select * from (select user_id from orders) d inner join users u on u.id = d.user_id where u.id > 1;",
+        ) {
+            crate::engine::ast::SQLStatement::DML(
+                crate::engine::ast::DMLStatement::SelectQuery(query),
+            ) => query,
+            other => panic!("expected select query, got {:?}", other),
+        };
+
+        let plan = Optimizer::new().optimize_select(query).await.unwrap();
+
+        match &plan.list[0] {
+            SelectPlanItem::Subquery(derived) => {
+                assert_eq!(derived.table_name, TableName::new(None, "d".into()));
+            }
+            other => panic!("expected Subquery plan, got {:?}", other),
+        }
+        assert!(
+            matches!(&plan.list[1], SelectPlanItem::Join(join) if join.right.table_name == "users")
+        );
+        assert!(matches!(&plan.list[2], SelectPlanItem::Filter(_)));
+
+        // 파생 테이블에는 alias가 필요합니다.
+        let query = match parse_one("select * from (select user_id from orders);") {
+            crate::engine::ast::SQLStatement::DML(
+                crate::engine::ast::DMLStatement::SelectQuery(query),
+            ) => query,
+            other => panic!("expected select query, got {:?}", other),
+        };
+        assert!(Optimizer::new().optimize_select(query).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn optimize_select_collects_aggregates_for_hash_aggregate() {
+        let query = match parse_one(
+            "select user_id, count(*), sum(total) from orders group by user_id having sum(total) > 100 order by count(*);",
+        ) {
+            crate::engine::ast::SQLStatement::DML(
+                crate::engine::ast::DMLStatement::SelectQuery(query),
+            ) => query,
+            other => panic!("expected select query, got {:?}", other),
+        };
+
+        let plan = Optimizer::new().optimize_select(query).await.unwrap();
+
+        match &plan.list[1] {
+            SelectPlanItem::Aggregate(aggregate) => {
+                assert_eq!(aggregate.group_by_items.len(), 1);
+                // HAVING, ORDER BY에서 다시 나온 집계는 한 번만 계산합니다.
+                assert_eq!(
+                    aggregate
+                        .aggregates
+                        .iter()
+                        .map(|call| call.function.as_aggregate().unwrap().name())
+                        .collect::<Vec<_>>(),
+                    vec!["count", "sum"]
+                );
+            }
+            other => panic!("expected Aggregate plan, got {:?}", other),
+        }
+        assert!(matches!(&plan.list[2], SelectPlanItem::Filter(_)));
+        assert!(matches!(&plan.list[3], SelectPlanItem::Order(_)));
+    }
+
+    #[tokio::test]
+    async fn optimize_select_plans_set_operation_before_order_and_limit() {
+        let query = match parse_one(
+            "select id from users union all select user_id from orders order by id limit 1;",
+        ) {
+            crate::engine::ast::SQLStatement::DML(
+                crate::engine::ast::DMLStatement::SelectQuery(query),
+            ) => query,
+            other => panic!("expected select query, got {:?}", other),
+        };
+
+        let plan = Optimizer::new().optimize_select(query).await.unwrap();
+
+        assert_eq!(plan.list.len(), 3);
+        match &plan.list[0] {
+            SelectPlanItem::SetOperation(set_operation) => {
+                assert_eq!(set_operation.operator, SetOperator::Union);
+                assert!(set_operation.all);
+                assert!(set_operation.left.from_table.is_some());
+                assert!(set_operation.right.from_table.is_some());
+            }
+            other => panic!("expected SetOperation plan, got {:?}", other),
+        }
+        assert!(matches!(&plan.list[1], SelectPlanItem::Order(_)));
+        assert!(matches!(&plan.list[2], SelectPlanItem::LimitOffset(_)));
+    }
+
+    async fn distinct_plan_for(sql: &str, context: OptimizerContext) -> Option<DistinctPlan> {
+        let query = match parse_one(sql) {
+            crate::engine::ast::SQLStatement::DML(
+                crate::engine::ast::DMLStatement::SelectQuery(query),
+            ) => query,
+            other => panic!("expected select query, got {:?}", other),
+        };
+
+        let plan = Optimizer::with_context(context)
+            .optimize_select(query)
+            .await
+            .unwrap();
+
+        plan.list.into_iter().find_map(|item| match item {
+            SelectPlanItem::Distinct(distinct) => Some(distinct),
+            _ => None,
+        })
+    }
+
+    #[tokio::test]
+    async fn optimize_select_runs_distinct_after_order_and_before_limit() {
+        let query = match parse_one(
+            "select distinct on (name) name, id from users order by name, id desc limit 2;",
+        ) {
+            crate::engine::ast::SQLStatement::DML(
+                crate::engine::ast::DMLStatement::SelectQuery(query),
+            ) => query,
+            other => panic!("expected select query, got {:?}", other),
+        };
+
+        let plan = Optimizer::new().optimize_select(query).await.unwrap();
+
+        assert!(matches!(&plan.list[1], SelectPlanItem::Order(_)));
+        assert_eq!(
+            plan.list[2],
+            SelectPlanItem::Distinct(DistinctPlan {
+                on: Some(vec![SelectColumn::new(None, "name".into()).into()]),
+            })
+        );
+        assert!(matches!(&plan.list[3], SelectPlanItem::LimitOffset(_)));
+    }
+
+    #[tokio::test]
+    async fn distinct_over_a_unique_not_null_column_is_removed() {
+        let unique_not_null = || OptimizerContext {
+            not_null_columns: vec!["id".into()],
+            ..context(10_000, true)
+        };
+
+        for sql in [
+            "select distinct id, name from users;",
+            "select distinct u.id from users u;",
+            "select distinct * from users;",
+            "select distinct on (id) id, name from users;",
+        ] {
+            assert_eq!(
+                distinct_plan_for(sql, unique_not_null()).await,
+                None,
+                "{:?} cannot produce duplicates",
+                sql
+            );
+        }
+
+        // 유니크 컬럼이 결과에 없거나, NULL이 여러 번 나올 수 있거나, 유니크하지 않으면 유지합니다.
+        let cases = [
+            ("select distinct name from users;", unique_not_null()),
+            ("select distinct id + 1 from users;", unique_not_null()),
+            ("select distinct id from users;", context(10_000, true)),
+            (
+                "select distinct id from users;",
+                OptimizerContext {
+                    not_null_columns: vec!["id".into()],
+                    ..context(10_000, false)
+                },
+            ),
+        ];
+
+        for (sql, context) in cases {
+            assert!(
+                distinct_plan_for(sql, context).await.is_some(),
+                "{:?} must keep its DISTINCT",
+                sql
+            );
+        }
+    }
+
     /// Parse `sql` and return the scan limit the optimizer put on the FROM plan.
-    async fn scan_limit_for(sql: &str) -> Option<usize> {
+    async fn optimize_select_sql(sql: &str) -> errors::Result<SelectPlan> {
         let mut parser = Parser::with_string(sql.into()).unwrap();
         let statements = parser
             .parse(ParserContext::default().set_default_database("rrdb".to_string()))
@@ -962,7 +2231,11 @@ mod tests {
         };
 
         let optimizer = Optimizer::with_context(context(10_000, true));
-        let plan = optimizer.optimize_select(query).await.unwrap();
+        optimizer.optimize_select(query).await
+    }
+
+    async fn scan_limit_for(sql: &str) -> Option<usize> {
+        let plan = optimize_select_sql(sql).await.unwrap();
 
         match &plan.list[0] {
             SelectPlanItem::From(from) => from.scan_limit,
@@ -1002,6 +2275,12 @@ mod tests {
             "select a from foo group by a limit 1;",
             // Aggregate over the whole table needs every row.
             "select count(1) from foo limit 1;",
+            // DISTINCT drops duplicates after the scan.
+            "select distinct a from foo limit 1;",
+            // A window function can look at every row of its partition.
+            "select count(*) over () from foo limit 1;",
+            // SKIP LOCKED drops rows locked by other transactions.
+            "select * from foo limit 1 for update skip locked;",
         ] {
             assert_eq!(
                 scan_limit_for(sql).await,
@@ -1019,4 +2298,75 @@ mod tests {
         assert_eq!(scan_limit_for("select * from foo offset 5;").await, None);
         assert_eq!(scan_limit_for("select * from foo;").await, None);
     }
+
+    #[tokio::test]
+    async fn rows_are_locked_after_sorting_and_before_the_limit() {
+        let plan = optimize_select_sql(
+            "select * from foo where a = 1 order by a limit 2 offset 1 for update skip locked;",
+        )
+        .await
+        .unwrap();
+
+        let kinds = plan
+            .list
+            .iter()
+            .map(|item| match item {
+                SelectPlanItem::From(_) => "from",
+                SelectPlanItem::Filter(_) => "filter",
+                SelectPlanItem::Order(_) => "order",
+                SelectPlanItem::LockRows(_) => "lock_rows",
+                SelectPlanItem::LimitOffset(_) => "limit_offset",
+                other => panic!("unexpected plan item {:?}", other),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            vec!["from", "filter", "order", "lock_rows", "limit_offset"]
+        );
+
+        let SelectPlanItem::LockRows(lock_rows) = &plan.list[3] else {
+            unreachable!();
+        };
+        assert_eq!(
+            lock_rows.table_name,
+            TableName::new(Some("rrdb".into()), "foo".into())
+        );
+        assert_eq!(lock_rows.locking.mode, RowLockMode::Update);
+        assert_eq!(lock_rows.locking.wait_policy, LockWaitPolicy::SkipLocked);
+        assert!(lock_rows.recheck.is_some());
+        assert_eq!(lock_rows.limit, Some(3));
+    }
+
+    #[tokio::test]
+    async fn locking_clause_requires_rows_of_a_single_table() {
+        for (sql, message) in [
+            (
+                "select * from foo f inner join bar b on f.id = b.id for update;",
+                "FOR UPDATE is not allowed with JOIN",
+            ),
+            (
+                "select a from foo group by a for share;",
+                "FOR SHARE is not allowed with GROUP BY clause",
+            ),
+            (
+                "select count(1) from foo for no key update;",
+                "FOR NO KEY UPDATE is not allowed with aggregate functions",
+            ),
+            (
+                "select distinct a from foo for update;",
+                "FOR UPDATE is not allowed with DISTINCT clause",
+            ),
+            (
+                "select count(*) over () from foo for update;",
+                "FOR UPDATE is not allowed with window functions",
+            ),
+            (
+                "select * from (select a from foo) f for update;",
+                "FOR UPDATE requires a table in the FROM clause",
+            ),
+        ] {
+            let error = optimize_select_sql(sql).await.unwrap_err();
+            assert_eq!(error.to_string(), message, "{sql}");
+        }
+    }
 }
@@ -15,6 +15,8 @@ pub struct TableStatistics {
     pub block_count: usize,
     /// 컬럼별 고유값 개수 추정치 (인덱스가 있는 컬럼만 채워짐)
     pub distinct_values: HashMap<String, usize>,
+    /// 컬럼별 NULL 개수 (인덱스가 있는 컬럼만 채워짐)
+    pub null_counts: HashMap<String, usize>,
 }
 
 /// 테이블 통계 캐시 관리자.
@@ -73,6 +75,22 @@ impl StatisticsManager {
         }
     }
 
+    /// TRUNCATE 반영: 캐시된 통계가 있으면 빈 테이블 상태로 초기화합니다.
+    pub async fn record_truncate(&self, table: &TableName) {
+        if let Some(statistics) = self.statistics.write().await.get_mut(table) {
+            statistics.row_count = 0;
+            statistics.block_count = 0;
+            statistics
+                .distinct_values
+                .values_mut()
+                .for_each(|count| *count = 0);
+            statistics
+                .null_counts
+                .values_mut()
+                .for_each(|count| *count = 0);
+        }
+    }
+
     pub async fn invalidate(&self, table: &TableName) {
         self.statistics.write().await.remove(table);
     }
@@ -115,6 +133,7 @@ mod tests {
                     row_count: 100,
                     block_count: 1,
                     distinct_values: HashMap::new(),
+                    null_counts: HashMap::new(),
                 },
             )
             .await;
@@ -130,6 +149,33 @@ mod tests {
         assert_eq!(manager.get(&table()).await.unwrap().row_count, 0);
     }
 
+    #[tokio::test]
+    async fn record_truncate_resets_counts_but_keeps_tracked_columns() {
+        let manager = StatisticsManager::new();
+        manager
+            .set(
+                table(),
+                TableStatistics {
+                    row_count: 100,
+                    block_count: 10,
+                    distinct_values: HashMap::from([("id".to_string(), 100)]),
+                    null_counts: HashMap::from([("id".to_string(), 0)]),
+                },
+            )
+            .await;
+
+        manager.record_truncate(&table()).await;
+        assert_eq!(
+            manager.get(&table()).await.unwrap(),
+            TableStatistics {
+                row_count: 0,
+                block_count: 0,
+                distinct_values: HashMap::from([("id".to_string(), 0)]),
+                null_counts: HashMap::from([("id".to_string(), 0)]),
+            }
+        );
+    }
+
     #[tokio::test]
     async fn invalidate_removes_statistics() {
         let manager = StatisticsManager::new();
@@ -164,6 +210,7 @@ mod tests {
                     row_count: 100,
                     block_count: 10,
                     distinct_values: HashMap::new(),
+                    null_counts: HashMap::new(),
                 },
             )
             .await;
@@ -1,8 +1,11 @@
+use crate::engine::ast::types::TableName;
+
 #[derive(Debug, Default, Clone)]
 pub struct ParserContext {
     pub in_between_clause: bool,
     pub in_parentheses: bool,
     pub default_database: Option<String>,
+    pub common_tables: Vec<(String, TableName)>, // 현재 범위에서 보이는 WITH 절 테이블 (이름, 바인딩)
 }
 
 impl ParserContext {
@@ -20,4 +23,18 @@ impl ParserContext {
         self.default_database = Some(default_database);
         self
     }
+
+    pub fn add_common_table(mut self, name: String, binding: TableName) -> Self {
+        self.common_tables.push((name, binding));
+        self
+    }
+
+    // 이름으로 WITH 절 테이블을 찾습니다. 안쪽에서 선언된 테이블이 바깥 테이블을 가립니다.
+    pub fn find_common_table(&self, name: &str) -> Option<&TableName> {
+        self.common_tables
+            .iter()
+            .rev()
+            .find(|(common_table_name, _)| common_table_name == name)
+            .map(|(_, binding)| binding)
+    }
 }
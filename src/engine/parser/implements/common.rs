@@ -15,7 +15,7 @@ impl Parser {
             return Err(ParsingError::wrap("need more tokens"));
         }
 
-        let current_token = self.get_next_token();
+        let current_token = self.get_next_token().into_identifier();
 
         match current_token {
             Token::Identifier(name) => {
@@ -198,7 +198,7 @@ impl Parser {
         }
 
         // 첫번째로 오는 이름은 테이블명으로 추정
-        let current_token = self.get_next_token();
+        let current_token = self.get_next_token().into_identifier();
         let mut database_name = None;
 
         let mut table_name = if let Token::Identifier(name) = current_token {
@@ -225,7 +225,7 @@ impl Parser {
                 return Err(ParsingError::wrap("need more tokens"));
             }
 
-            let current_token = self.get_next_token();
+            let current_token = self.get_next_token().into_identifier();
 
             if let Token::Identifier(name) = current_token {
                 database_name = Some(table_name);
@@ -327,7 +327,7 @@ impl Parser {
             return Err(ParsingError::wrap("need more tokens"));
         }
 
-        let current_token = self.get_next_token();
+        let current_token = self.get_next_token().into_identifier();
 
         if let Token::Identifier(name) = current_token {
             select_column.column_name = name;
@@ -344,7 +344,7 @@ impl Parser {
             let current_token = self.get_next_token();
 
             if current_token == Token::Period {
-                let current_token = self.get_next_token();
+                let current_token = self.get_next_token().into_identifier();
 
                 if let Token::Identifier(name) = current_token {
                     select_column.table_name = Some(select_column.column_name);
@@ -383,7 +383,7 @@ impl Parser {
                         true
                     }
                 }
-                Token::Or | Token::Like | Token::In => {
+                Token::Or | Token::Like | Token::ILike | Token::In => {
                     self.unget_next_token(current_token);
                     true
                 }
@@ -418,7 +418,7 @@ impl Parser {
                         let second_token = self.get_next_token();
 
                         match second_token {
-                            Token::In | Token::Like => {
+                            Token::In | Token::Like | Token::ILike => {
                                 self.unget_next_token(second_token);
                                 self.unget_next_token(current_token);
                                 true
@@ -478,6 +478,19 @@ impl Parser {
         }
     }
 
+    // 다음 토큰이 ESCAPE인지
+    pub(crate) fn next_token_is_escape(&mut self) -> bool {
+        if !self.has_next_token() {
+            false
+        } else {
+            let current_token = self.get_next_token();
+
+            self.unget_next_token(current_token.clone());
+
+            current_token == Token::Escape
+        }
+    }
+
     // 다음 토큰이 여는 괄호인지
     pub(crate) fn next_token_is_between(&mut self) -> bool {
         if !self.has_next_token() {
@@ -938,7 +951,7 @@ impl Parser {
                     return Err(ParsingError::wrap("need more tokens"));
                 }
 
-                let current_token = self.get_next_token();
+                let current_token = self.get_next_token().into_identifier();
 
                 match current_token {
                     Token::Identifier(id) => Ok(id),
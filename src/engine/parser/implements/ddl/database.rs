@@ -25,7 +25,7 @@ impl Parser {
             return Err(ParsingError::wrap("need more tokens".to_string()));
         }
 
-        let current_token = self.get_next_token();
+        let current_token = self.get_next_token().into_identifier();
 
         match current_token {
             Token::Identifier(identifier) => {
@@ -67,7 +67,7 @@ impl Parser {
             return Err(ParsingError::wrap("need more tokens".to_string()));
         }
 
-        let current_token = self.get_next_token();
+        let current_token = self.get_next_token().into_identifier();
 
         match current_token {
             Token::Identifier(identifier) => {
@@ -105,7 +105,7 @@ impl Parser {
 
         let mut query_builder = AlterDatabaseQuery::builder();
 
-        let current_token = self.get_next_token();
+        let current_token = self.get_next_token().into_identifier();
 
         match current_token {
             Token::Identifier(identifier) => {
@@ -145,7 +145,7 @@ impl Parser {
                     return Err(ParsingError::wrap("expected identifier. but no more token"));
                 }
 
-                let current_token = self.get_next_token();
+                let current_token = self.get_next_token().into_identifier();
 
                 match current_token {
                     Token::Identifier(identifier) => {
@@ -0,0 +1,280 @@
+use std::collections::HashSet;
+
+use crate::engine::ast::ddl::create_function::{CreateFunctionQuery, FunctionParameter};
+use crate::engine::ast::ddl::drop_function::DropFunctionQuery;
+use crate::engine::ast::dml::parts::select_item::SelectKind;
+use crate::engine::ast::dml::select::SelectQuery;
+use crate::engine::ast::types::UserDefinedFunction;
+use crate::engine::ast::{DMLStatement, SQLStatement};
+use crate::engine::lexer::predule::Token;
+use crate::engine::parser::context::ParserContext;
+use crate::engine::parser::predule::Parser;
+use crate::errors;
+use crate::errors::parsing_error::ParsingError;
+
+impl Parser {
+    // CREATE [OR REPLACE] FUNCTION 쿼리 분석
+    // 진입 시점에는 FUNCTION 토큰까지 소비된 상태입니다.
+    pub(crate) fn handle_create_function_query(
+        &mut self,
+        context: ParserContext,
+        or_replace: bool,
+    ) -> errors::Result<SQLStatement> {
+        let function = self.parse_function_name(context)?;
+
+        let mut query_builder = CreateFunctionQuery::builder()
+            .set_function(function.clone())
+            .set_or_replace(or_replace);
+
+        // 매개변수 목록 파싱
+        let mut parameter_names = HashSet::new();
+        for parameter in self.parse_function_parameters()? {
+            if let Some(name) = &parameter.name
+                && !parameter_names.insert(name.clone())
+            {
+                return Err(ParsingError::wrap(format!(
+                    "parameter name \"{}\" used more than once",
+                    name
+                )));
+            }
+
+            query_builder = query_builder.add_parameter(parameter);
+        }
+
+        // RETURNS 타입 파싱
+        if !self.has_next_token() {
+            return Err(ParsingError::wrap("need more tokens"));
+        }
+
+        let current_token = self.get_next_token();
+
+        if current_token != Token::Returns {
+            return Err(ParsingError::wrap(format!(
+                "expected 'RETURNS'. but your input word is '{:?}'",
+                current_token
+            )));
+        }
+
+        query_builder = query_builder.set_return_type(self.parse_cast_data_type()?);
+
+        // AS '본문'과 LANGUAGE SQL은 순서에 상관없이 올 수 있습니다.
+        let mut body = None;
+
+        while self.has_next_token() {
+            let current_token = self.get_next_token();
+
+            match current_token {
+                Token::SemiColon => break,
+                Token::As if body.is_none() => match self.get_next_token_or_eof() {
+                    Token::String(text) => body = Some(text),
+                    token => {
+                        return Err(ParsingError::wrap(format!(
+                            "expected function body string. but your input word is '{:?}'",
+                            token
+                        )));
+                    }
+                },
+                Token::Identifier(keyword) if keyword.eq_ignore_ascii_case("LANGUAGE") => {
+                    match self.get_next_token_or_eof() {
+                        Token::Identifier(language) if language.eq_ignore_ascii_case("SQL") => {}
+                        token => {
+                            return Err(ParsingError::wrap(format!(
+                                "only LANGUAGE SQL is supported. but your input word is '{:?}'",
+                                token
+                            )));
+                        }
+                    }
+                }
+                _ => {
+                    return Err(ParsingError::wrap(format!(
+                        "expected 'AS' or 'LANGUAGE'. but your input word is '{:?}'",
+                        current_token
+                    )));
+                }
+            }
+        }
+
+        let Some(body) = body else {
+            return Err(ParsingError::wrap("expected 'AS' with function body"));
+        };
+
+        // 본문의 문법 오류는 함수를 만들 때 드러냅니다.
+        Self::parse_function_body(&body, function.database_name)?;
+
+        Ok(query_builder.set_body(body).build())
+    }
+
+    // DROP FUNCTION 쿼리 분석
+    // 진입 시점에는 FUNCTION 토큰까지 소비된 상태입니다.
+    pub(crate) fn handle_drop_function_query(
+        &mut self,
+        context: ParserContext,
+    ) -> errors::Result<SQLStatement> {
+        if !self.has_next_token() {
+            return Err(ParsingError::wrap("need more tokens"));
+        }
+
+        let mut query_builder = DropFunctionQuery::builder();
+
+        // IF EXISTS 파싱
+        let if_exists = self.has_if_exists()?;
+        query_builder = query_builder.set_if_exists(if_exists);
+
+        query_builder = query_builder.set_function(self.parse_function_name(context)?);
+
+        // 선택적인 매개변수 타입 목록
+        if self.has_next_token() && self.pick_next_token() == Token::LeftParentheses {
+            let parameter_types = self
+                .parse_function_parameters()?
+                .into_iter()
+                .map(|parameter| parameter.data_type)
+                .collect();
+
+            query_builder = query_builder.set_parameter_types(parameter_types);
+        }
+
+        if !self.has_next_token() {
+            return Ok(query_builder.build());
+        }
+
+        let current_token = self.get_next_token();
+
+        if Token::SemiColon != current_token {
+            return Err(ParsingError::wrap(format!(
+                "expected ';'. but your input word is '{:?}'",
+                current_token
+            )));
+        }
+
+        Ok(query_builder.build())
+    }
+
+    // 함수 본문을 SELECT 쿼리로 파싱합니다.
+    // 본문의 테이블명은 함수가 속한 데이터베이스를 기준으로 찾습니다.
+    pub(crate) fn parse_function_body(
+        body: &str,
+        database_name: Option<String>,
+    ) -> errors::Result<SelectQuery> {
+        let context = ParserContext {
+            default_database: database_name,
+            ..Default::default()
+        };
+
+        let mut parser = Parser::with_string(body.to_owned())?;
+        let mut statements = parser.parse(context)?;
+
+        let query = match statements.pop() {
+            Some(SQLStatement::DML(DMLStatement::SelectQuery(query)))
+                if statements.is_empty() && !parser.has_next_token() =>
+            {
+                query
+            }
+            _ => {
+                return Err(ParsingError::wrap(
+                    "function body must be a single SELECT statement",
+                ));
+            }
+        };
+
+        let first_block = query.blocks()[0];
+        if first_block.select_items.len() != 1
+            || matches!(first_block.select_items[0], SelectKind::WildCard(_))
+        {
+            return Err(ParsingError::wrap(
+                "function body must return exactly one column",
+            ));
+        }
+
+        Ok(query)
+    }
+
+    // [database_name.]function_name
+    fn parse_function_name(
+        &mut self,
+        context: ParserContext,
+    ) -> errors::Result<UserDefinedFunction> {
+        let Token::Identifier(name) = self.get_next_token_or_eof().into_identifier() else {
+            return Err(ParsingError::wrap("expected function name"));
+        };
+
+        if self.has_next_token() && self.pick_next_token() == Token::Period {
+            self.get_next_token();
+
+            let Token::Identifier(function_name) = self.get_next_token_or_eof().into_identifier()
+            else {
+                return Err(ParsingError::wrap("expected function name after '.'"));
+            };
+
+            return Ok(UserDefinedFunction {
+                database_name: Some(name),
+                function_name,
+            });
+        }
+
+        Ok(UserDefinedFunction {
+            database_name: context.default_database,
+            function_name: name,
+        })
+    }
+
+    // ( [ [parameter_name] data_type [, ...] ] )
+    fn parse_function_parameters(&mut self) -> errors::Result<Vec<FunctionParameter>> {
+        let current_token = self.get_next_token_or_eof();
+
+        if current_token != Token::LeftParentheses {
+            return Err(ParsingError::wrap(format!(
+                "expected '('. but your input word is '{:?}'",
+                current_token
+            )));
+        }
+
+        let mut parameters = vec![];
+
+        loop {
+            if self.next_token_is_right_parentheses() {
+                self.get_next_token();
+                break;
+            }
+
+            if !parameters.is_empty() {
+                let current_token = self.get_next_token_or_eof();
+
+                if current_token != Token::Comma {
+                    return Err(ParsingError::wrap(format!(
+                        "expected ',' or ')'. but your input word is '{:?}'",
+                        current_token
+                    )));
+                }
+            }
+
+            // 식별자 두개가 연달아 오면 앞쪽이 매개변수 이름입니다. (DOUBLE PRECISION 제외)
+            let name = match self.get_next_token_or_eof().into_identifier() {
+                Token::Identifier(name)
+                    if !name.eq_ignore_ascii_case("DOUBLE")
+                        && self.has_next_token()
+                        && matches!(self.pick_next_token(), Token::Identifier(_)) =>
+                {
+                    Some(name)
+                }
+                token => {
+                    self.unget_next_token(token);
+                    None
+                }
+            };
+
+            let data_type = self.parse_cast_data_type()?;
+
+            parameters.push(FunctionParameter { name, data_type });
+        }
+
+        Ok(parameters)
+    }
+
+    fn get_next_token_or_eof(&mut self) -> Token {
+        if self.has_next_token() {
+            self.get_next_token()
+        } else {
+            Token::EOF
+        }
+    }
+}
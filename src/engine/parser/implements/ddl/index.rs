@@ -30,7 +30,7 @@ impl Parser {
             return Err(ParsingError::wrap("need more tokens".to_string()));
         }
 
-        let current_token = self.get_next_token();
+        let current_token = self.get_next_token().into_identifier();
 
         let index_name = if let Token::Identifier(name) = current_token {
             name
@@ -81,7 +81,7 @@ impl Parser {
                 return Err(ParsingError::wrap("need more tokens".to_string()));
             }
 
-            let current_token = self.get_next_token();
+            let current_token = self.get_next_token().into_identifier();
 
             match current_token {
                 Token::RightParentheses => break,
@@ -143,7 +143,7 @@ impl Parser {
             return Err(ParsingError::wrap("need more tokens".to_string()));
         }
 
-        let current_token = self.get_next_token();
+        let current_token = self.get_next_token().into_identifier();
 
         let index_name = if let Token::Identifier(name) = current_token {
             name
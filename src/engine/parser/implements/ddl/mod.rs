@@ -1,4 +1,5 @@
 pub mod database;
+pub mod function;
 pub mod index;
 pub mod table;
 pub mod top_level;
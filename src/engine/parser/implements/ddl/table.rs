@@ -6,6 +6,7 @@ use crate::engine::ast::ddl::alter_table::{
 use crate::engine::ast::ddl::create_table::CreateTableQuery;
 use crate::engine::ast::ddl::drop_database::SQLStatement;
 use crate::engine::ast::ddl::drop_table::DropTableQuery;
+use crate::engine::ast::ddl::truncate_table::TruncateTableQuery;
 use crate::engine::lexer::predule::Token;
 use crate::engine::parser::context::ParserContext;
 use crate::engine::parser::predule::Parser;
@@ -110,7 +111,7 @@ impl Parser {
                     return Err(ParsingError::wrap("need more tokens".to_string()));
                 }
 
-                let current_token = self.get_next_token();
+                let current_token = self.get_next_token().into_identifier();
 
                 match current_token {
                     // table name rename
@@ -119,7 +120,7 @@ impl Parser {
                             return Err(ParsingError::wrap("need more tokens".to_string()));
                         }
 
-                        let current_token = self.get_next_token();
+                        let current_token = self.get_next_token().into_identifier();
 
                         match current_token {
                             Token::Identifier(identifier) => {
@@ -140,7 +141,7 @@ impl Parser {
                             return Err(ParsingError::wrap("need more tokens".to_string()));
                         }
 
-                        let current_token = self.get_next_token();
+                        let current_token = self.get_next_token().into_identifier();
 
                         if let Token::Identifier(from_name) = current_token {
                             if !self.has_next_token() {
@@ -160,7 +161,7 @@ impl Parser {
                                 return Err(ParsingError::wrap("need more tokens".to_string()));
                             }
 
-                            let current_token = self.get_next_token();
+                            let current_token = self.get_next_token().into_identifier();
 
                             if let Token::Identifier(to_name) = current_token {
                                 query_builder = query_builder.set_action(
@@ -198,7 +199,7 @@ impl Parser {
                             return Err(ParsingError::wrap("need more tokens".to_string()));
                         }
 
-                        let current_token = self.get_next_token();
+                        let current_token = self.get_next_token().into_identifier();
 
                         if let Token::Identifier(to_name) = current_token {
                             query_builder = query_builder
@@ -223,7 +224,7 @@ impl Parser {
                     return Err(ParsingError::wrap("need more tokens".to_string()));
                 }
 
-                let current_token = self.get_next_token();
+                let current_token = self.get_next_token().into_identifier();
 
                 match current_token {
                     Token::Column => {
@@ -257,7 +258,7 @@ impl Parser {
                     return Err(ParsingError::wrap("need more tokens".to_string()));
                 }
 
-                let current_token = self.get_next_token();
+                let current_token = self.get_next_token().into_identifier();
 
                 if let Token::Identifier(column_name) = current_token {
                     query_builder =
@@ -278,7 +279,7 @@ impl Parser {
                     return Err(ParsingError::wrap("need more tokens".to_string()));
                 }
 
-                let current_token = self.get_next_token();
+                let current_token = self.get_next_token().into_identifier();
 
                 if let Token::Identifier(column_name) = current_token {
                     if !self.has_next_token() {
@@ -439,4 +440,88 @@ impl Parser {
 
         Ok(query_builder.build())
     }
+
+    // TRUNCATE [TABLE] 쿼리 분석
+    // TRUNCATE TABLE foo, bar RESTART IDENTITY;
+    pub(crate) fn handle_truncate_table_query(
+        &mut self,
+        context: ParserContext,
+    ) -> errors::Result<SQLStatement> {
+        let mut query_builder = TruncateTableQuery::builder();
+
+        if !self.has_next_token() {
+            return Err(ParsingError::wrap("need more tokens".to_string()));
+        }
+
+        // TABLE 키워드는 생략할 수 있습니다.
+        if self.pick_next_token() == Token::Table {
+            self.get_next_token();
+        }
+
+        // 테이블 목록 파싱
+        loop {
+            if !self.has_next_token() {
+                return Err(ParsingError::wrap("need more tokens".to_string()));
+            }
+
+            let table = self.parse_table_name(context.clone())?;
+            query_builder = query_builder.add_table(table);
+
+            if self.has_next_token() && self.pick_next_token() == Token::Comma {
+                self.get_next_token();
+                continue;
+            }
+
+            break;
+        }
+
+        if !self.has_next_token() {
+            return Ok(query_builder.build());
+        }
+
+        let current_token = self.get_next_token();
+
+        // RESTART IDENTITY | CONTINUE IDENTITY
+        // 컬럼명 등으로도 쓰이는 단어라 키워드 토큰이 아닙니다.
+        let current_token = match current_token {
+            Token::Identifier(ref keyword)
+                if keyword.eq_ignore_ascii_case("RESTART")
+                    || keyword.eq_ignore_ascii_case("CONTINUE") =>
+            {
+                if !self.has_next_token() {
+                    return Err(ParsingError::wrap("need more tokens".to_string()));
+                }
+
+                match self.get_next_token() {
+                    Token::Identifier(ref identity)
+                        if identity.eq_ignore_ascii_case("IDENTITY") => {}
+                    token => {
+                        return Err(ParsingError::wrap(format!(
+                            "expected 'IDENTITY'. but your input word is '{:?}'",
+                            token
+                        )));
+                    }
+                }
+
+                query_builder =
+                    query_builder.set_restart_identity(keyword.eq_ignore_ascii_case("RESTART"));
+
+                if !self.has_next_token() {
+                    return Ok(query_builder.build());
+                }
+
+                self.get_next_token()
+            }
+            token => token,
+        };
+
+        if Token::SemiColon != current_token {
+            return Err(ParsingError::wrap(format!(
+                "expected ';'. but your input word is '{:?}'",
+                current_token
+            )));
+        }
+
+        Ok(query_builder.build())
+    }
 }
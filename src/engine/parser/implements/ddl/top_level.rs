@@ -21,6 +21,40 @@ impl Parser {
             Token::Table => self.handle_create_table_query(context),
             Token::Database => self.handle_create_database_query(),
             Token::Index => self.handle_create_index_query(context, false),
+            Token::Function => self.handle_create_function_query(context, false),
+            // CREATE OR REPLACE FUNCTION
+            // REPLACE는 함수명(replace())으로도 쓰이므로 키워드 토큰이 아닙니다.
+            Token::Or => {
+                if !self.has_next_token() {
+                    return Err(ParsingError::wrap("need more tokens".to_string()));
+                }
+
+                let current_token = self.get_next_token();
+
+                match current_token {
+                    Token::Identifier(ref keyword) if keyword.eq_ignore_ascii_case("REPLACE") => {}
+                    _ => {
+                        return Err(ParsingError::wrap(format!(
+                            "expected 'REPLACE'. but your input word is '{:?}'",
+                            current_token
+                        )));
+                    }
+                }
+
+                if !self.has_next_token() {
+                    return Err(ParsingError::wrap("need more tokens".to_string()));
+                }
+
+                let current_token = self.get_next_token();
+
+                match current_token {
+                    Token::Function => self.handle_create_function_query(context, true),
+                    _ => Err(ParsingError::wrap(format!(
+                        "expected 'FUNCTION'. but your input word is '{:?}'",
+                        current_token
+                    ))),
+                }
+            }
             Token::Unique => {
                 if !self.has_next_token() {
                     return Err(ParsingError::wrap("need more tokens".to_string()));
@@ -37,7 +71,7 @@ impl Parser {
                 }
             }
             _ => Err(ParsingError::wrap(format!(
-                "not supported command. possible commands: (create table, create database, create index). but your input is {:?}",
+                "not supported command. possible commands: (create table, create database, create index, create function). but your input is {:?}",
                 current_token
             ))),
         }
@@ -77,8 +111,9 @@ impl Parser {
             Token::Table => self.handle_drop_table_query(context),
             Token::Database => self.handle_drop_database_query(),
             Token::Index => self.handle_drop_index_query(context),
+            Token::Function => self.handle_drop_function_query(context),
             _ => Err(ParsingError::wrap(
-                "not supported command. possible commands: (drop table, drop database, drop index)",
+                "not supported command. possible commands: (drop table, drop database, drop index, drop function)",
             )),
         }
     }
@@ -0,0 +1,95 @@
+use crate::engine::ast::dml::expressions::case::{CaseExpression, CaseWhenBranch};
+use crate::engine::ast::types::SQLExpression;
+use crate::engine::lexer::predule::Token;
+use crate::engine::parser::predule::{Parser, ParserContext};
+use crate::errors;
+use crate::errors::parsing_error::ParsingError;
+
+impl Parser {
+    // CASE [operand] WHEN ... THEN ... [WHEN ... THEN ...] [ELSE ...] END 파싱 (CASE는 이미 삼킨 상태)
+    pub(crate) fn parse_case_expression(
+        &mut self,
+        context: ParserContext,
+    ) -> errors::Result<SQLExpression> {
+        // CASE 안쪽은 감싸는 BETWEEN 절과 무관하게 파싱합니다.
+        let context = context
+            .set_in_between_clause(false)
+            .set_in_parentheses(false);
+
+        if !self.has_next_token() {
+            return Err(ParsingError::wrap("need more tokens"));
+        }
+
+        // WHEN이 바로 오지 않으면 단순 CASE의 비교 대상입니다.
+        let operand = if self.pick_next_token() == Token::When {
+            None
+        } else {
+            Some(self.parse_expression(context.clone())?)
+        };
+
+        let mut case = CaseExpression {
+            operand,
+            branches: vec![],
+            else_result: None,
+        };
+
+        loop {
+            if !self.has_next_token() {
+                return Err(ParsingError::wrap("need more tokens"));
+            }
+
+            let current_token = self.get_next_token();
+
+            match current_token {
+                Token::When => {
+                    let condition = self.parse_expression(context.clone())?;
+
+                    self.parse_case_keyword(Token::Then)?;
+
+                    let result = self.parse_expression(context.clone())?;
+
+                    case.branches.push(CaseWhenBranch { condition, result });
+                }
+                Token::Else if !case.branches.is_empty() => {
+                    case.else_result = Some(self.parse_expression(context.clone())?);
+
+                    self.parse_case_keyword(Token::End)?;
+
+                    break;
+                }
+                Token::End if !case.branches.is_empty() => break,
+                _ => {
+                    return Err(ParsingError::wrap(format!(
+                        "expected 'WHEN'{}. but your input word is '{:?}'",
+                        if case.branches.is_empty() {
+                            ""
+                        } else {
+                            ", 'ELSE' or 'END'"
+                        },
+                        current_token
+                    )));
+                }
+            }
+        }
+
+        Ok(case.into())
+    }
+
+    // THEN, END 등 CASE 식의 키워드 삼킴
+    fn parse_case_keyword(&mut self, keyword: Token) -> errors::Result<()> {
+        if !self.has_next_token() {
+            return Err(ParsingError::wrap("need more tokens"));
+        }
+
+        let current_token = self.get_next_token();
+
+        if current_token != keyword {
+            return Err(ParsingError::wrap(format!(
+                "expected '{:?}'. but your input word is '{:?}'",
+                keyword, current_token
+            )));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,136 @@
+use crate::engine::ast::dml::expressions::cast::CastExpression;
+use crate::engine::ast::types::{DataType, SQLExpression};
+use crate::engine::lexer::predule::Token;
+use crate::engine::parser::predule::{Parser, ParserContext};
+use crate::errors;
+use crate::errors::parsing_error::ParsingError;
+
+impl Parser {
+    // CAST(expression AS data_type) 파싱 (CAST는 이미 삼킨 상태)
+    pub(crate) fn parse_cast_expression(
+        &mut self,
+        context: ParserContext,
+    ) -> errors::Result<SQLExpression> {
+        if !self.has_next_token() {
+            return Err(ParsingError::wrap("need more tokens"));
+        }
+
+        // ( 삼킴
+        let current_token = self.get_next_token();
+
+        if current_token != Token::LeftParentheses {
+            return Err(ParsingError::wrap(format!(
+                "expected left parentheses after 'CAST'. but your input is {:?}",
+                current_token
+            )));
+        }
+
+        let context = context
+            .set_in_between_clause(false)
+            .set_in_parentheses(true);
+
+        let expression = self.parse_expression(context)?;
+
+        if !self.has_next_token() {
+            return Err(ParsingError::wrap("need more tokens"));
+        }
+
+        // AS 삼킴
+        let current_token = self.get_next_token();
+
+        if current_token != Token::As {
+            return Err(ParsingError::wrap(format!(
+                "expected 'AS'. but your input word is '{:?}'",
+                current_token
+            )));
+        }
+
+        let data_type = self.parse_cast_data_type()?;
+
+        if !self.has_next_token() {
+            return Err(ParsingError::wrap("need more tokens"));
+        }
+
+        // ) 삼킴
+        let current_token = self.get_next_token();
+
+        if current_token != Token::RightParentheses {
+            return Err(ParsingError::wrap(format!(
+                "expected right parentheses of 'CAST'. but your input is {:?}",
+                current_token
+            )));
+        }
+
+        Ok(CastExpression {
+            expression,
+            data_type,
+        }
+        .into())
+    }
+
+    // 뒤따르는 expression::data_type 들을 파싱해 감쌉니다. :: 는 어떤 연산자보다 먼저 묶입니다.
+    pub(crate) fn parse_postfix_cast(
+        &mut self,
+        mut expression: SQLExpression,
+    ) -> errors::Result<SQLExpression> {
+        while self.has_next_token() && self.pick_next_token() == Token::DoubleColon {
+            // :: 삼킴
+            self.get_next_token();
+
+            let data_type = self.parse_cast_data_type()?;
+
+            expression = CastExpression {
+                expression,
+                data_type,
+            }
+            .into();
+        }
+
+        Ok(expression)
+    }
+
+    // 변환 대상 타입 파싱
+    // 컬럼 타입 외에 PostgreSQL의 별칭(TEXT, BIGINT, 길이 없는 VARCHAR 등)도 허용합니다.
+    pub(crate) fn parse_cast_data_type(&mut self) -> errors::Result<DataType> {
+        if !self.has_next_token() {
+            return Err(ParsingError::wrap("need more tokens"));
+        }
+
+        let current_token = self.get_next_token();
+
+        let Token::Identifier(ref type_name) = current_token else {
+            return Err(ParsingError::wrap(format!(
+                "expected data type. but your input word is '{:?}'",
+                current_token
+            )));
+        };
+
+        let next_is_left_parentheses =
+            self.has_next_token() && self.pick_next_token() == Token::LeftParentheses;
+
+        match type_name.to_uppercase().as_str() {
+            "TEXT" => Ok(DataType::Varchar(i64::MAX)),
+            "VARCHAR" if !next_is_left_parentheses => Ok(DataType::Varchar(i64::MAX)),
+            "BIGINT" | "INT8" | "INT4" | "SMALLINT" | "INT2" => Ok(DataType::Int),
+            "REAL" | "FLOAT4" | "FLOAT8" => Ok(DataType::Float),
+            "DOUBLE" => {
+                // DOUBLE PRECISION
+                match self.has_next_token().then(|| self.get_next_token()) {
+                    Some(Token::Identifier(precision))
+                        if precision.eq_ignore_ascii_case("PRECISION") =>
+                    {
+                        Ok(DataType::Float)
+                    }
+                    token => Err(ParsingError::wrap(format!(
+                        "expected 'PRECISION' after 'DOUBLE'. but your input word is '{:?}'",
+                        token
+                    ))),
+                }
+            }
+            _ => {
+                self.unget_next_token(current_token);
+                self.parse_data_type()
+            }
+        }
+    }
+}
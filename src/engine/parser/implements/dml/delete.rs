@@ -53,12 +53,28 @@ impl Parser {
             query_builder = query_builder.set_from_alias(alias);
         }
 
+        // USING 절 파싱
+        if self.has_next_token() && self.pick_next_token() == Token::Using {
+            self.get_next_token(); // using 토큰 삼키기
+
+            let target = query_builder.from_table.clone().unwrap();
+            for using_table in self.parse_join_targets(context.clone(), &target)? {
+                query_builder = query_builder.add_using_table(using_table);
+            }
+        }
+
         // WHERE 절 파싱
         if self.next_token_is_where() {
-            let where_clause = self.parse_where(context)?;
+            let where_clause = self.parse_where(context.clone())?;
             query_builder = query_builder.set_where(where_clause);
         }
 
+        // RETURNING 절 파싱
+        if self.next_token_is_returning() {
+            let returning = self.parse_returning(context)?;
+            query_builder = query_builder.set_returning(returning);
+        }
+
         Ok(query_builder.build())
     }
 }
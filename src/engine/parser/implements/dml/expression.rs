@@ -1,15 +1,18 @@
 use crate::engine::ast::dml::expressions::between::BetweenExpression;
 use crate::engine::ast::dml::expressions::binary::BinaryOperatorExpression;
 use crate::engine::ast::dml::expressions::call::CallExpression;
+use crate::engine::ast::dml::expressions::like_escape::LikeEscapeExpression;
 use crate::engine::ast::dml::expressions::list::ListExpression;
 use crate::engine::ast::dml::expressions::not_between::NotBetweenExpression;
 use crate::engine::ast::dml::expressions::operators::{BinaryOperator, UnaryOperator};
 use crate::engine::ast::dml::expressions::parentheses::ParenthesesExpression;
+use crate::engine::ast::dml::expressions::subquery::SubqueryExpression;
 use crate::engine::ast::dml::expressions::unary::UnaryOperatorExpression;
 use crate::engine::ast::types::{
-    BuiltInFunction, SQLExpression, SelectColumn, UserDefinedFunction,
+    AggregateFunction, BuiltInFunction, SQLExpression, ScalarFunction, SelectColumn,
+    UserDefinedFunction,
 };
-use crate::engine::lexer::predule::Token;
+use crate::engine::lexer::predule::{OperatorToken, Token};
 use crate::engine::parser::predule::Parser;
 use crate::engine::parser::predule::ParserContext;
 use crate::errors::parsing_error::ParsingError;
@@ -24,7 +27,7 @@ impl Parser {
             return Err(ParsingError::wrap("need more tokens"));
         }
 
-        let current_token = self.get_next_token();
+        let current_token = self.get_next_token().into_identifier();
 
         match current_token {
             Token::Operator(operator) => {
@@ -48,7 +51,7 @@ impl Parser {
                 Ok(expression)
             }
             Token::Integer(integer) => {
-                let lhs = SQLExpression::Integer(integer);
+                let lhs = self.parse_postfix_cast(SQLExpression::Integer(integer))?;
 
                 if self.next_token_is_binary_operator(context.clone()) {
                     let expression = self.parse_binary_expression(lhs, context)?;
@@ -61,7 +64,7 @@ impl Parser {
                 }
             }
             Token::Float(float) => {
-                let lhs = SQLExpression::Float(float);
+                let lhs = self.parse_postfix_cast(SQLExpression::Float(float))?;
 
                 if self.next_token_is_binary_operator(context.clone()) {
                     let expression = self.parse_binary_expression(lhs, context)?;
@@ -74,7 +77,7 @@ impl Parser {
                 }
             }
             Token::String(string) => {
-                let lhs = SQLExpression::String(string);
+                let lhs = self.parse_postfix_cast(SQLExpression::String(string))?;
 
                 if self.next_token_is_binary_operator(context.clone()) {
                     let expression = self.parse_binary_expression(lhs, context)?;
@@ -87,7 +90,7 @@ impl Parser {
                 }
             }
             Token::Boolean(boolean) => {
-                let lhs = SQLExpression::Boolean(boolean);
+                let lhs = self.parse_postfix_cast(SQLExpression::Boolean(boolean))?;
 
                 if self.next_token_is_binary_operator(context.clone()) {
                     let expression = self.parse_binary_expression(lhs, context)?;
@@ -100,7 +103,7 @@ impl Parser {
                 }
             }
             Token::Null => {
-                let lhs = SQLExpression::Null;
+                let lhs = self.parse_postfix_cast(SQLExpression::Null)?;
 
                 if self.next_token_is_binary_operator(context.clone()) {
                     let expression = self.parse_binary_expression(lhs, context)?;
@@ -120,10 +123,11 @@ impl Parser {
                 let second_token = self.get_next_token();
 
                 match second_token {
-                    Token::Select => {
+                    Token::Select | Token::With => {
                         self.unget_next_token(second_token);
                         self.unget_next_token(current_token);
                         let lhs = self.parse_subquery(context.clone())?.into();
+                        let lhs = self.parse_postfix_cast(lhs)?;
 
                         if self.next_token_is_binary_operator(context.clone()) {
                             let expression = self.parse_binary_expression(lhs, context)?;
@@ -139,6 +143,7 @@ impl Parser {
                         self.unget_next_token(second_token);
                         self.unget_next_token(current_token);
                         let lhs = self.parse_parentheses_expression(context.clone())?;
+                        let lhs = self.parse_postfix_cast(lhs)?;
 
                         if self.next_token_is_binary_operator(context.clone()) {
                             let expression = self.parse_binary_expression(lhs, context)?;
@@ -152,6 +157,35 @@ impl Parser {
                     }
                 }
             }
+            Token::Exists => {
+                let query = self.parse_subquery(context.clone())?.into_query();
+                let lhs = SubqueryExpression::Exists(Box::new(query)).into();
+
+                if self.next_token_is_binary_operator(context.clone()) {
+                    let expression = self.parse_binary_expression(lhs, context)?;
+                    Ok(expression)
+                } else {
+                    Ok(lhs)
+                }
+            }
+            Token::Case | Token::Cast => {
+                let lhs = if current_token == Token::Case {
+                    self.parse_case_expression(context.clone())?
+                } else {
+                    self.parse_cast_expression(context.clone())?
+                };
+                let lhs = self.parse_postfix_cast(lhs)?;
+
+                if self.next_token_is_binary_operator(context.clone()) {
+                    let expression = self.parse_binary_expression(lhs, context)?;
+                    Ok(expression)
+                } else if self.next_token_is_between() {
+                    let expression = self.parse_between_expression(lhs, context)?;
+                    Ok(expression)
+                } else {
+                    Ok(lhs)
+                }
+            }
             Token::RightParentheses => Err(ParsingError::wrap(format!(
                 "unexpected token: {:?}",
                 current_token
@@ -160,7 +194,17 @@ impl Parser {
                 self.unget_next_token(Token::Identifier(identifier));
                 let select_column = self.parse_select_column()?;
 
-                let lhs = SQLExpression::SelectColumn(select_column.clone());
+                let lhs = if self.next_token_is_left_parentheses() {
+                    let SelectColumn {
+                        table_name,
+                        column_name,
+                    } = select_column;
+
+                    self.parse_function_call_expression(table_name, column_name, context.clone())?
+                } else {
+                    SQLExpression::SelectColumn(select_column)
+                };
+                let lhs = self.parse_postfix_cast(lhs)?;
 
                 if self.next_token_is_binary_operator(context.clone()) {
                     let expression = self.parse_binary_expression(lhs, context)?;
@@ -168,27 +212,6 @@ impl Parser {
                 } else if self.next_token_is_between() {
                     let expression = self.parse_between_expression(lhs, context)?;
                     Ok(expression)
-                } else if self.next_token_is_left_parentheses() {
-                    let SelectColumn {
-                        table_name,
-                        column_name,
-                    } = select_column;
-
-                    let lhs = self.parse_function_call_expression(
-                        table_name,
-                        column_name,
-                        context.clone(),
-                    )?;
-
-                    if self.next_token_is_binary_operator(context.clone()) {
-                        let expression = self.parse_binary_expression(lhs, context)?;
-                        Ok(expression)
-                    } else if self.next_token_is_between() {
-                        let expression = self.parse_between_expression(lhs, context)?;
-                        Ok(expression)
-                    } else {
-                        Ok(lhs)
-                    }
                 } else {
                     Ok(lhs)
                 }
@@ -334,17 +357,44 @@ impl Parser {
         // 연산자 획득
         let current_token = self.get_next_token();
 
-        let operator: Result<BinaryOperator, _> =
-            if current_token.can_be_multi_token_operator() && self.has_next_token() {
-                let second_token = self.get_next_token();
-                current_token.try_into_multi_token_operator(second_token)
-            } else {
-                current_token.try_into()
-            };
+        let operator: Result<BinaryOperator, _> = if current_token == Token::Is {
+            self.parse_is_operator()
+        } else if current_token.can_be_multi_token_operator() && self.has_next_token() {
+            let second_token = self.get_next_token();
+            current_token.try_into_multi_token_operator(second_token)
+        } else {
+            current_token.try_into()
+        };
 
         match operator {
             Ok(operator) => {
-                let rhs = self.parse_expression(context)?;
+                // IS [NOT] NULL, IS [NOT] TRUE, IS [NOT] FALSE
+                if matches!(operator, BinaryOperator::Is | BinaryOperator::IsNot) {
+                    let rhs = self.parse_is_operand()?;
+                    let expression: SQLExpression =
+                        BinaryOperatorExpression { lhs, rhs, operator }.into();
+
+                    return if self.next_token_is_binary_operator(context.clone()) {
+                        self.parse_binary_expression(expression, context)
+                    } else {
+                        Ok(expression)
+                    };
+                }
+
+                let rhs = Self::in_list_operand(&operator, self.parse_expression(context.clone())?);
+
+                // LIKE 'pattern' ESCAPE 'c'
+                if operator.is_pattern_match() && self.next_token_is_escape() {
+                    let rhs = self.parse_like_escape(rhs)?;
+                    let expression: SQLExpression =
+                        BinaryOperatorExpression { lhs, rhs, operator }.into();
+
+                    return if self.next_token_is_binary_operator(context.clone()) {
+                        self.parse_binary_expression(expression, context)
+                    } else {
+                        Ok(expression)
+                    };
+                }
 
                 let current_precedence = operator.get_precedence();
 
@@ -380,7 +430,7 @@ impl Parser {
                         else {
                             let new_lhs = BinaryOperatorExpression {
                                 lhs,
-                                rhs: rhs_binary.lhs,
+                                rhs: Self::in_list_operand(&operator, rhs_binary.lhs),
                                 operator,
                             };
                             Ok(BinaryOperatorExpression {
@@ -399,6 +449,137 @@ impl Parser {
         }
     }
 
+    /**
+     * IN (1)처럼 원소가 하나뿐인 목록은 소괄호 표현식으로 파싱되므로, IN의 오른쪽이면 목록으로 되돌림
+     */
+    fn in_list_operand(operator: &BinaryOperator, operand: SQLExpression) -> SQLExpression {
+        match operand {
+            SQLExpression::Parentheses(paren)
+                if matches!(operator, BinaryOperator::In | BinaryOperator::NotIn) =>
+            {
+                ListExpression {
+                    value: vec![paren.expression],
+                }
+                .into()
+            }
+            operand => operand,
+        }
+    }
+
+    /**
+     * IS 뒤의 연산자 파싱 (IS는 이미 삼킨 상태)
+     * IS [NOT] ..., IS [NOT] DISTINCT FROM
+     */
+    pub(crate) fn parse_is_operator(&mut self) -> errors::Result<BinaryOperator> {
+        if !self.has_next_token() {
+            return Err(ParsingError::wrap("need more tokens"));
+        }
+
+        let mut current_token = self.get_next_token();
+
+        let negated = current_token == Token::Not;
+
+        if negated {
+            if !self.has_next_token() {
+                return Err(ParsingError::wrap("need more tokens"));
+            }
+
+            current_token = self.get_next_token();
+        }
+
+        if current_token != Token::Distinct {
+            self.unget_next_token(current_token);
+
+            return Ok(if negated {
+                BinaryOperator::IsNot
+            } else {
+                BinaryOperator::Is
+            });
+        }
+
+        if !self.has_next_token() {
+            return Err(ParsingError::wrap("need more tokens"));
+        }
+
+        // FROM 삼킴
+        let current_token = self.get_next_token();
+
+        if current_token != Token::From {
+            return Err(ParsingError::wrap(format!(
+                "expected FROM after IS DISTINCT. but your input is {:?}",
+                current_token
+            )));
+        }
+
+        Ok(if negated {
+            BinaryOperator::IsNotDistinctFrom
+        } else {
+            BinaryOperator::IsDistinctFrom
+        })
+    }
+
+    /**
+     * IS [NOT] 뒤의 피연산자 파싱
+     * NULL, TRUE, FALSE
+     */
+    pub(crate) fn parse_is_operand(&mut self) -> errors::Result<SQLExpression> {
+        if !self.has_next_token() {
+            return Err(ParsingError::wrap("need more tokens"));
+        }
+
+        let current_token = self.get_next_token();
+
+        match current_token {
+            Token::Null => Ok(SQLExpression::Null),
+            Token::Boolean(boolean) => Ok(SQLExpression::Boolean(boolean)),
+            _ => Err(ParsingError::wrap(format!(
+                "expected NULL, TRUE or FALSE after IS. but your input is {:?}",
+                current_token
+            ))),
+        }
+    }
+
+    /**
+     * LIKE 패턴의 ESCAPE 절 파싱
+     * ESCAPE 'c'
+     */
+    pub(crate) fn parse_like_escape(
+        &mut self,
+        pattern: SQLExpression,
+    ) -> errors::Result<SQLExpression> {
+        if !self.has_next_token() {
+            return Err(ParsingError::wrap("need more tokens"));
+        }
+
+        // ESCAPE 삼킴
+        let current_token = self.get_next_token();
+
+        if current_token != Token::Escape {
+            return Err(ParsingError::wrap(format!(
+                "expected ESCAPE. but your input is {:?}",
+                current_token
+            )));
+        }
+
+        if !self.has_next_token() {
+            return Err(ParsingError::wrap("need more tokens"));
+        }
+
+        let current_token = self.get_next_token();
+
+        match current_token {
+            Token::String(escape) => Ok(LikeEscapeExpression {
+                pattern,
+                escape: SQLExpression::String(escape),
+            }
+            .into()),
+            _ => Err(ParsingError::wrap(format!(
+                "expected escape string literal. but your input is {:?}",
+                current_token
+            ))),
+        }
+    }
+
     /**
      * 함수호출 파싱
      */
@@ -408,6 +589,8 @@ impl Parser {
         function_name: String,
         context: ParserContext,
     ) -> errors::Result<SQLExpression> {
+        let display_name = function_name.to_lowercase();
+
         let function = if database_name.is_some() {
             UserDefinedFunction {
                 database_name,
@@ -417,8 +600,9 @@ impl Parser {
         } else {
             match BuiltInFunction::try_from(function_name.clone()) {
                 Ok(builtin) => builtin.into(),
+                // 사용자 정의 함수는 현재 데이터베이스에서 찾습니다.
                 Err(_) => UserDefinedFunction {
-                    database_name,
+                    database_name: context.default_database.clone(),
                     function_name,
                 }
                 .into(),
@@ -428,6 +612,7 @@ impl Parser {
         let mut call_expression = CallExpression {
             function,
             arguments: vec![],
+            distinct: false,
         };
 
         if !self.has_next_token() {
@@ -448,17 +633,56 @@ impl Parser {
             return Err(ParsingError::wrap("need more tokens"));
         }
 
+        match self.get_next_token() {
+            // 집계 함수의 DISTINCT 한정자 (COUNT(DISTINCT x))
+            Token::Distinct => {
+                if !call_expression.function.is_aggregate() {
+                    return Err(ParsingError::wrap(
+                        "DISTINCT specified, but it is not an aggregate function",
+                    ));
+                }
+
+                call_expression.distinct = true;
+            }
+            // COUNT(*)는 인자 없는 COUNT로 표현합니다.
+            Token::Operator(OperatorToken::Asterisk)
+                if call_expression.function == AggregateFunction::Count.into() =>
+            {
+                if !self.next_token_is_right_parentheses() {
+                    return Err(ParsingError::wrap("expected right parentheses after '*'"));
+                }
+            }
+            token => self.unget_next_token(token),
+        }
+
         // 닫는 괄호가 나올때까지 인자 파싱
         loop {
             // 닫는 괄호가 나왔다면 종료
             if self.next_token_is_right_parentheses() {
                 let _ = self.get_next_token();
-                return Ok(call_expression.into());
+                return self.parse_over_clause(call_expression, &display_name, context);
             }
 
             // 표현식 파싱
             let expression = self.parse_expression(context.clone())?;
 
+            // POSITION(찾을 문자열 IN 문자열)은 인자 두개로 풀어서 받습니다.
+            if call_expression.function == ScalarFunction::Position.into()
+                && let SQLExpression::Binary(binary) = &expression
+                && binary.operator == BinaryOperator::In
+                && !matches!(
+                    binary.rhs,
+                    SQLExpression::List(_) | SQLExpression::Subquery(_)
+                )
+            {
+                let SQLExpression::Binary(binary) = expression else {
+                    unreachable!()
+                };
+                call_expression.arguments.push(binary.lhs);
+                call_expression.arguments.push(binary.rhs);
+                continue;
+            }
+
             call_expression.arguments.push(expression);
 
             // 쉼표 삼키기.
@@ -1,5 +1,7 @@
 use crate::engine::ast::dml::insert::InsertQuery;
+use crate::engine::ast::dml::parts::_where::WhereClause;
 use crate::engine::ast::dml::parts::insert_values::InsertValue;
+use crate::engine::ast::dml::parts::on_conflict::{OnConflictAction, OnConflictClause};
 use crate::engine::lexer::predule::Token;
 use crate::engine::parser::predule::{Parser, ParserContext};
 use crate::errors;
@@ -67,7 +69,7 @@ impl Parser {
         match current_token {
             Token::Values => {
                 self.unget_next_token(current_token);
-                let values = self.parse_insert_values(context)?;
+                let values = self.parse_insert_values(context.clone())?;
 
                 if values.iter().any(|e| e.list.len() != columns.len()) {
                     return Err(ParsingError::wrap(
@@ -77,11 +79,12 @@ impl Parser {
 
                 query_builder = query_builder.set_values(values);
             }
-            Token::Select => {
+            Token::Select | Token::With => {
                 self.unget_next_token(current_token);
-                let select = self.handle_select_query(context)?;
+                let select = self.handle_select_query(context.clone())?;
 
-                if select.select_items.len() != columns.len() {
+                // 집합 연산이라면 컬럼 수는 가장 왼쪽 쿼리를 따릅니다.
+                if select.blocks()[0].select_items.len() != columns.len() {
                     return Err(ParsingError::wrap(
                         "The number of values in insert and the number of columns do not match.",
                     ));
@@ -97,13 +100,130 @@ impl Parser {
             }
         }
 
-        // TODO: On Conflict 절 파싱
+        // ON CONFLICT 절 파싱
+        if self.has_next_token() && self.pick_next_token() == Token::On {
+            self.get_next_token();
 
-        // TODO: Returning 절 파싱
+            let on_conflict = self.parse_on_conflict(context.clone())?;
+            query_builder = query_builder.set_on_conflict(on_conflict);
+        }
+
+        // RETURNING 절 파싱
+        if self.next_token_is_returning() {
+            let returning = self.parse_returning(context)?;
+            query_builder = query_builder.set_returning(returning);
+        }
 
         Ok(query_builder.build())
     }
 
+    // ON CONFLICT 절 파싱. ON 토큰까지 소비된 상태에서 시작합니다.
+    // CONFLICT, DO, NOTHING은 예약어가 아니므로 식별자로 비교합니다.
+    // INSERT INTO foo (a, b) VALUES (1, 2) ON CONFLICT (a) DO UPDATE SET b = excluded.b;
+    //                                         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    pub(crate) fn parse_on_conflict(
+        &mut self,
+        context: ParserContext,
+    ) -> errors::Result<OnConflictClause> {
+        self.expect_keyword_identifier("CONFLICT")?;
+
+        // 충돌 대상 컬럼 (선택)
+        let mut target_columns = vec![];
+
+        if self.has_next_token() && self.pick_next_token() == Token::LeftParentheses {
+            self.get_next_token();
+            target_columns = self.parse_insert_columns(context.clone())?;
+
+            if target_columns.is_empty() {
+                return Err(ParsingError::wrap(
+                    "ON CONFLICT target must have at least one column",
+                ));
+            }
+        }
+
+        self.expect_keyword_identifier("DO")?;
+
+        if !self.has_next_token() {
+            return Err(ParsingError::wrap("need more tokens"));
+        }
+
+        let current_token = self.get_next_token();
+
+        let action = match current_token {
+            Token::Identifier(keyword) if keyword.eq_ignore_ascii_case("NOTHING") => {
+                OnConflictAction::DoNothing
+            }
+            Token::Update => {
+                // 어떤 행을 수정할지 정해야 하므로 충돌 대상이 필요합니다.
+                if target_columns.is_empty() {
+                    return Err(ParsingError::wrap(
+                        "ON CONFLICT DO UPDATE requires a conflict target column",
+                    ));
+                }
+
+                if !self.has_next_token() {
+                    return Err(ParsingError::wrap("need more tokens"));
+                }
+
+                let current_token = self.get_next_token();
+
+                if current_token != Token::Set {
+                    return Err(ParsingError::wrap(format!(
+                        "expected 'SET'. but your input word is '{:?}'",
+                        current_token
+                    )));
+                }
+
+                let update_items = self.parse_update_items(context.clone())?;
+
+                if update_items.is_empty() {
+                    return Err(ParsingError::wrap(
+                        "ON CONFLICT DO UPDATE requires at least one SET item",
+                    ));
+                }
+
+                let mut where_clause = None;
+
+                if self.next_token_is_where() {
+                    self.get_next_token(); // where 토큰 삼키기
+
+                    let expression = self.parse_expression(context)?;
+                    where_clause = Some(WhereClause { expression });
+                }
+
+                OnConflictAction::DoUpdate {
+                    update_items,
+                    where_clause,
+                }
+            }
+            _ => {
+                return Err(ParsingError::wrap(format!(
+                    "expected 'NOTHING' or 'UPDATE'. but your input word is '{:?}'",
+                    current_token
+                )));
+            }
+        };
+
+        Ok(OnConflictClause {
+            target_columns,
+            action,
+        })
+    }
+
+    fn expect_keyword_identifier(&mut self, keyword: &str) -> errors::Result<()> {
+        if !self.has_next_token() {
+            return Err(ParsingError::wrap("need more tokens"));
+        }
+
+        match self.get_next_token() {
+            Token::Identifier(identifier) if identifier.eq_ignore_ascii_case(keyword) => Ok(()),
+            current_token => Err(ParsingError::wrap(format!(
+                "expected '{}'. but your input word is '{:?}'",
+                keyword, current_token
+            ))),
+        }
+    }
+
     // INSERT의 컬럼명 지정 부분 파싱
     // INSERT INTO (A, B, C) Values (1, 2, 3);
     //              ^^^^^^^
@@ -117,7 +237,7 @@ impl Parser {
                 return Err(ParsingError::wrap("need more tokens"));
             }
 
-            let current_token = self.get_next_token();
+            let current_token = self.get_next_token().into_identifier();
 
             match current_token {
                 Token::Identifier(identifier) => {
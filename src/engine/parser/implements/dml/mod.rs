@@ -1,5 +1,10 @@
+pub mod case;
+pub mod cast;
 pub mod delete;
 pub mod expression;
 pub mod insert;
+pub mod returning;
 pub mod select;
 pub mod update;
+pub mod window;
+pub mod with;
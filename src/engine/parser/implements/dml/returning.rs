@@ -0,0 +1,83 @@
+use crate::engine::ast::dml::parts::returning::ReturningClause;
+use crate::engine::ast::dml::parts::select_item::{SelectKind, SelectWildCard};
+use crate::engine::lexer::predule::{OperatorToken, Token};
+use crate::engine::parser::predule::{Parser, ParserContext};
+use crate::errors;
+use crate::errors::parsing_error::ParsingError;
+
+impl Parser {
+    // RETURNING 절 파싱
+    // DELETE FROM foo WHERE a = 1 RETURNING a, b + 1 AS c;
+    //                             ^^^^^^^^^^^^^^^^^^^^^^^
+    // ';'는 소비하지 않습니다.
+    pub(crate) fn parse_returning(
+        &mut self,
+        context: ParserContext,
+    ) -> errors::Result<ReturningClause> {
+        if !self.has_next_token() {
+            return Err(ParsingError::wrap("need more tokens"));
+        }
+
+        let current_token = self.get_next_token();
+
+        if current_token != Token::Returning {
+            return Err(ParsingError::wrap(format!(
+                "expected 'RETURNING'. but your input word is '{:?}'",
+                current_token
+            )));
+        }
+
+        let mut select_items = vec![];
+
+        loop {
+            if !self.has_next_token() {
+                break;
+            }
+
+            let current_token = self.get_next_token();
+
+            match current_token {
+                Token::SemiColon => {
+                    self.unget_next_token(current_token);
+                    break;
+                }
+                Token::Comma => continue,
+                Token::Operator(OperatorToken::Asterisk) => {
+                    select_items.push(SelectKind::WildCard(SelectWildCard { alias: None }));
+                }
+                _ => {
+                    self.unget_next_token(current_token);
+                    let select_item = self.parse_select_item(context.clone())?;
+
+                    if let Some(item) = &select_item.item {
+                        if item.has_aggregate() {
+                            return Err(ParsingError::wrap(
+                                "aggregate functions are not allowed in RETURNING",
+                            ));
+                        }
+
+                        if item.has_window() {
+                            return Err(ParsingError::wrap(
+                                "window functions are not allowed in RETURNING",
+                            ));
+                        }
+                    }
+
+                    select_items.push(SelectKind::SelectItem(select_item));
+                }
+            }
+        }
+
+        if select_items.is_empty() {
+            return Err(ParsingError::wrap(
+                "RETURNING requires at least one expression",
+            ));
+        }
+
+        Ok(ReturningClause { select_items })
+    }
+
+    pub(crate) fn next_token_is_returning(&mut self) -> bool {
+        self.has_next_token() && self.pick_next_token() == Token::Returning
+    }
+}
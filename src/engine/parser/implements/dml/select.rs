@@ -1,11 +1,14 @@
 use std::collections::HashSet;
 
 use crate::engine::ast::dml::parts::_where::WhereClause;
+use crate::engine::ast::dml::parts::distinct::DistinctClause;
 use crate::engine::ast::dml::parts::group_by::GroupByItem;
 use crate::engine::ast::dml::parts::having::HavingClause;
 use crate::engine::ast::dml::parts::join::{JoinClause, JoinType};
+use crate::engine::ast::dml::parts::locking::{LockWaitPolicy, LockingClause, RowLockMode};
 use crate::engine::ast::dml::parts::order_by::{OrderByItem, OrderByNulls, OrderByType};
 use crate::engine::ast::dml::parts::select_item::{SelectItem, SelectKind, SelectWildCard};
+use crate::engine::ast::dml::parts::set_operation::SetOperator;
 use crate::engine::ast::dml::select::SelectQuery;
 use crate::engine::lexer::predule::{OperatorToken, Token};
 use crate::engine::parser::predule::{Parser, ParserContext};
@@ -13,9 +16,152 @@ use crate::errors;
 use crate::errors::parsing_error::ParsingError;
 
 impl Parser {
+    // SELECT 문 파싱. 앞에 오는 WITH 절과 UNION/INTERSECT/EXCEPT로 결합된 쿼리도 여기서 처리합니다.
     pub(crate) fn handle_select_query(
         &mut self,
         context: ParserContext,
+    ) -> errors::Result<SelectQuery> {
+        let (with_clause, context) = self.parse_with_clause(context)?;
+
+        let query = self.parse_compound_select(context)?;
+
+        Ok(match with_clause {
+            Some(with_clause) => query.set_with(with_clause),
+            None => query,
+        })
+    }
+
+    // INTERSECT가 UNION/EXCEPT보다 먼저 결합되며, 같은 우선순위끼리는 왼쪽부터 결합됩니다.
+    fn parse_compound_select(&mut self, context: ParserContext) -> errors::Result<SelectQuery> {
+        let (first, mut parenthesized) = self.parse_set_operand(context.clone())?;
+
+        let mut operands = vec![first];
+        let mut operators = vec![];
+
+        while let Some(operator) = self.parse_set_operator()? {
+            // 괄호 없는 중간 블록의 ORDER BY/LIMIT은 어디에 적용되는지 모호하므로 허용하지 않습니다.
+            let previous = operands.last().unwrap();
+            if previous.locking_clause.is_some() {
+                return Err(ParsingError::wrap(
+                    "FOR UPDATE is not allowed with UNION/INTERSECT/EXCEPT",
+                ));
+            }
+            if !parenthesized
+                && (previous.order_by_clause.is_some()
+                    || previous.limit.is_some()
+                    || previous.offset.is_some())
+            {
+                return Err(ParsingError::wrap(format!(
+                    "ORDER BY, LIMIT and OFFSET must follow the last query of {}. wrap the query in parentheses",
+                    operator.0.name()
+                )));
+            }
+
+            let (operand, operand_parenthesized) = self.parse_set_operand(context.clone())?;
+            parenthesized = operand_parenthesized;
+
+            operators.push(operator);
+            operands.push(operand);
+        }
+
+        if operators.is_empty() {
+            return Ok(operands.pop().unwrap());
+        }
+
+        // 마지막 블록 뒤의 ORDER BY/LIMIT/OFFSET은 결합된 결과 전체에 적용됩니다.
+        let last = operands.last_mut().unwrap();
+        if last.locking_clause.is_some() {
+            return Err(ParsingError::wrap(
+                "FOR UPDATE is not allowed with UNION/INTERSECT/EXCEPT",
+            ));
+        }
+        let order_by_clause = last.order_by_clause.take();
+        let limit = last.limit.take();
+        let offset = last.offset.take();
+
+        // INTERSECT 먼저 결합
+        let mut operands = operands.into_iter();
+        let mut terms = vec![operands.next().unwrap()];
+        let mut term_operators = vec![];
+
+        for ((operator, all), operand) in operators.into_iter().zip(operands) {
+            if operator == SetOperator::Intersect {
+                let left = terms.pop().unwrap();
+                terms.push(SelectQuery::set_operation(operator, all, left, operand));
+            } else {
+                term_operators.push((operator, all));
+                terms.push(operand);
+            }
+        }
+
+        // UNION, EXCEPT는 왼쪽부터 결합
+        let mut terms = terms.into_iter();
+        let mut query = terms.next().unwrap();
+
+        for ((operator, all), term) in term_operators.into_iter().zip(terms) {
+            query = SelectQuery::set_operation(operator, all, query, term);
+        }
+
+        query.order_by_clause = order_by_clause;
+        query.limit = limit;
+        query.offset = offset;
+
+        if parenthesized {
+            query = self.parse_select_tail(query, context)?;
+        }
+
+        Ok(query)
+    }
+
+    // 집합 연산의 피연산자 하나를 파싱합니다. 괄호로 감싼 쿼리라면 두번째 값이 true입니다.
+    fn parse_set_operand(&mut self, context: ParserContext) -> errors::Result<(SelectQuery, bool)> {
+        if self.next_token_is_left_parentheses() {
+            let subquery = self.parse_subquery(context)?;
+            return Ok((subquery.into_query(), true));
+        }
+
+        Ok((self.parse_select_block(context)?, false))
+    }
+
+    // UNION [ALL | DISTINCT], INTERSECT [ALL | DISTINCT], EXCEPT [ALL | DISTINCT]
+    // 집합 연산자가 아니라면 아무것도 삼키지 않고 None을 반환합니다.
+    fn parse_set_operator(&mut self) -> errors::Result<Option<(SetOperator, bool)>> {
+        if !self.has_next_token() {
+            return Ok(None);
+        }
+
+        let current_token = self.get_next_token();
+
+        let operator = match current_token {
+            Token::Union => SetOperator::Union,
+            Token::Intersect => SetOperator::Intersect,
+            Token::Except => SetOperator::Except,
+            _ => {
+                self.unget_next_token(current_token);
+                return Ok(None);
+            }
+        };
+
+        if !self.has_next_token() {
+            return Err(ParsingError::wrap("need more tokens"));
+        }
+
+        let all = match self.get_next_token() {
+            Token::All => true,
+            Token::Distinct => false,
+            token => {
+                self.unget_next_token(token);
+                false
+            }
+        };
+
+        Ok(Some((operator, all)))
+    }
+
+    // 집합 연산이 없는 단일 SELECT 블록 파싱
+    pub(crate) fn parse_select_block(
+        &mut self,
+        context: ParserContext,
     ) -> errors::Result<SelectQuery> {
         if !self.has_next_token() {
             return Err(ParsingError::wrap("need more tokens"));
@@ -37,6 +183,11 @@ impl Parser {
 
         let mut query_builder = SelectQuery::builder();
 
+        // DISTINCT [ON (...)] 파싱
+        if let Some(distinct) = self.parse_distinct(context.clone())? {
+            query_builder = query_builder.set_distinct(distinct);
+        }
+
         // FROM 절이나 세미콜론이 나오기 전까지 select 절 파싱
         loop {
             if !self.has_next_token() {
@@ -55,7 +206,11 @@ impl Parser {
                     // from 없는 select절로 간주. 종료.
                     return Ok(query_builder.build());
                 }
-                Token::RightParentheses => {
+                Token::RightParentheses
+                | Token::Union
+                | Token::Intersect
+                | Token::Except
+                | Token::Returning => {
                     // from 없는 select절로 간주. 종료.
                     self.unget_next_token(current_token);
                     return Ok(query_builder.build());
@@ -88,8 +243,13 @@ impl Parser {
             let subquery = self.parse_subquery(context.clone())?;
             query_builder = query_builder.set_from_subquery(subquery);
         } else {
-            let table_name = self.parse_table_name(context.clone())?;
+            let (table_name, common_table_name) = self.parse_from_table_name(context.clone())?;
             query_builder = query_builder.set_from_table(table_name);
+
+            // WITH 절 테이블은 별칭이 없으면 원래 이름으로 참조합니다.
+            if let Some(common_table_name) = common_table_name {
+                query_builder = query_builder.set_from_alias(common_table_name);
+            }
         }
 
         if self.next_token_is_table_alias() {
@@ -141,7 +301,15 @@ impl Parser {
                             return Ok(query_builder.build());
                         }
                         Token::Comma => continue,
-                        Token::Having | Token::Limit | Token::Offset | Token::Order => {
+                        Token::Having
+                        | Token::Limit
+                        | Token::Offset
+                        | Token::Order
+                        | Token::For
+                        | Token::Union
+                        | Token::Intersect
+                        | Token::Except
+                        | Token::Returning => {
                             self.unget_next_token(current_token);
                             break;
                         }
@@ -237,6 +405,15 @@ impl Parser {
             }
         }
 
+        self.parse_select_tail(query_builder, context)
+    }
+
+    // ORDER BY, LIMIT, OFFSET 절 파싱
+    fn parse_select_tail(
+        &mut self,
+        mut query_builder: SelectQuery,
+        context: ParserContext,
+    ) -> errors::Result<SelectQuery> {
         // Order By 절 파싱
         if self.next_token_is_order_by() {
             // ORDER BY 삼킴
@@ -252,14 +429,23 @@ impl Parser {
 
                 match current_token {
                     Token::SemiColon => {
+                        Self::check_distinct_on_order_by(&query_builder)?;
                         return Ok(query_builder.build());
                     }
                     Token::RightParentheses => {
+                        Self::check_distinct_on_order_by(&query_builder)?;
                         self.unget_next_token(current_token);
                         return Ok(query_builder.build());
                     }
                     Token::Comma => continue,
-                    Token::Group | Token::Limit | Token::Offset => {
+                    Token::Group
+                    | Token::Limit
+                    | Token::Offset
+                    | Token::For
+                    | Token::Union
+                    | Token::Intersect
+                    | Token::Except
+                    | Token::Returning => {
                         self.unget_next_token(current_token);
                         break;
                     }
@@ -299,9 +485,165 @@ impl Parser {
             }
         }
 
+        Self::check_distinct_on_order_by(&query_builder)?;
+
+        // FOR UPDATE / FOR SHARE 절 파싱
+        if self.has_next_token() && self.pick_next_token() == Token::For {
+            self.get_next_token();
+
+            if query_builder.set_operation.is_some() {
+                return Err(ParsingError::wrap(
+                    "FOR UPDATE is not allowed with UNION/INTERSECT/EXCEPT",
+                ));
+            }
+
+            let locking_clause = self.parse_locking_clause()?;
+            query_builder = query_builder.set_locking(locking_clause);
+        }
+
         Ok(query_builder.build())
     }
 
+    // FOR 다음의 UPDATE | NO KEY UPDATE | SHARE [NOWAIT | SKIP LOCKED] 파싱
+    // SHARE, NOWAIT 등은 컬럼명으로도 쓰이는 단어라 키워드 토큰이 아닙니다.
+    fn parse_locking_clause(&mut self) -> errors::Result<LockingClause> {
+        if !self.has_next_token() {
+            return Err(ParsingError::wrap("need more tokens"));
+        }
+
+        let mode = match self.get_next_token() {
+            Token::Update => RowLockMode::Update,
+            Token::Identifier(ref keyword) if keyword.eq_ignore_ascii_case("SHARE") => {
+                RowLockMode::Share
+            }
+            Token::Identifier(ref keyword) if keyword.eq_ignore_ascii_case("NO") => {
+                if !self.has_next_token() || self.get_next_token() != Token::Key {
+                    return Err(ParsingError::wrap("expected 'KEY' after 'FOR NO'"));
+                }
+                if !self.has_next_token() || self.get_next_token() != Token::Update {
+                    return Err(ParsingError::wrap("expected 'UPDATE' after 'FOR NO KEY'"));
+                }
+                RowLockMode::NoKeyUpdate
+            }
+            token => {
+                return Err(ParsingError::wrap(format!(
+                    "expected 'UPDATE', 'NO KEY UPDATE' or 'SHARE' after 'FOR'. but your input word is '{:?}'",
+                    token
+                )));
+            }
+        };
+
+        let mut wait_policy = LockWaitPolicy::Wait;
+
+        if self.has_next_token() {
+            match self.get_next_token() {
+                Token::Identifier(ref keyword) if keyword.eq_ignore_ascii_case("NOWAIT") => {
+                    wait_policy = LockWaitPolicy::NoWait;
+                }
+                Token::Identifier(ref keyword) if keyword.eq_ignore_ascii_case("SKIP") => {
+                    self.parse_keyword_identifier("LOCKED")?;
+                    wait_policy = LockWaitPolicy::SkipLocked;
+                }
+                token => self.unget_next_token(token),
+            }
+        }
+
+        Ok(LockingClause { mode, wait_policy })
+    }
+
+    // SELECT 바로 뒤의 DISTINCT [ON (표현식, ...)] 파싱
+    // DISTINCT가 없다면 아무것도 삼키지 않고 None을 반환합니다.
+    pub(crate) fn parse_distinct(
+        &mut self,
+        context: ParserContext,
+    ) -> errors::Result<Option<DistinctClause>> {
+        if !self.has_next_token() {
+            return Ok(None);
+        }
+
+        let current_token = self.get_next_token();
+
+        if current_token != Token::Distinct {
+            self.unget_next_token(current_token);
+            return Ok(None);
+        }
+
+        if !self.has_next_token() {
+            return Err(ParsingError::wrap("need more tokens"));
+        }
+
+        let current_token = self.get_next_token();
+
+        if current_token != Token::On {
+            self.unget_next_token(current_token);
+            return Ok(Some(DistinctClause { on: None }));
+        }
+
+        if !self.next_token_is_left_parentheses() {
+            return Err(ParsingError::wrap("expected '(' after DISTINCT ON"));
+        }
+
+        // 여는 괄호 삼키기
+        self.get_next_token();
+
+        let mut expressions = vec![];
+
+        // 닫는 괄호가 나올때까지 표현식 파싱
+        loop {
+            if !self.has_next_token() {
+                return Err(ParsingError::wrap("need more tokens"));
+            }
+
+            if self.next_token_is_right_parentheses() {
+                self.get_next_token();
+                break;
+            }
+
+            expressions.push(self.parse_expression(context.clone())?);
+
+            // 쉼표 삼키기.
+            if self.next_token_is_comma() {
+                self.get_next_token();
+            }
+        }
+
+        if expressions.is_empty() {
+            return Err(ParsingError::wrap(
+                "DISTINCT ON requires at least one expression",
+            ));
+        }
+
+        Ok(Some(DistinctClause {
+            on: Some(expressions),
+        }))
+    }
+
+    // DISTINCT ON 표현식은 ORDER BY의 앞쪽 표현식들과 일치해야 합니다.
+    // 그렇지 않으면 그룹마다 어떤 행이 남을지 정해지지 않습니다.
+    fn check_distinct_on_order_by(query: &SelectQuery) -> errors::Result<()> {
+        let Some(DistinctClause { on: Some(on) }) = &query.distinct else {
+            return Ok(());
+        };
+
+        let Some(order_by) = &query.order_by_clause else {
+            return Ok(());
+        };
+
+        let matched = order_by
+            .order_by_items
+            .iter()
+            .take(on.len())
+            .all(|order_by_item| on.contains(&order_by_item.item));
+
+        if !matched {
+            return Err(ParsingError::wrap(
+                "SELECT DISTINCT ON expressions must match initial ORDER BY expressions",
+            ));
+        }
+
+        Ok(())
+    }
+
     pub(crate) fn parse_select_item(
         &mut self,
         context: ParserContext,
@@ -329,7 +671,7 @@ impl Parser {
                     return Err(ParsingError::wrap("expected alias. need more".to_string()));
                 }
 
-                let current_token = self.get_next_token();
+                let current_token = self.get_next_token().into_identifier();
 
                 match current_token {
                     Token::Identifier(identifier) => {
@@ -352,7 +694,12 @@ impl Parser {
                 // 현재 select_item은 종료된 것으로 판단.
                 Ok(select_item.build())
             }
-            Token::SemiColon | Token::RightParentheses => {
+            Token::SemiColon
+            | Token::RightParentheses
+            | Token::Union
+            | Token::Intersect
+            | Token::Except
+            | Token::Returning => {
                 self.unget_next_token(current_token);
                 // 현재 select_item은 종료된 것으로 판단.
                 Ok(select_item.build())
@@ -460,12 +807,12 @@ impl Parser {
             return Err(ParsingError::wrap("need more tokens"));
         }
 
-        let right = self.parse_table_name(context.clone())?;
+        let (right, common_table_name) = self.parse_from_table_name(context.clone())?;
 
         let right_alias = if self.next_token_is_table_alias() {
             self.parse_table_alias().ok()
         } else {
-            None
+            common_table_name
         };
 
         let on = if !self.has_next_token() {
@@ -508,6 +855,12 @@ impl Parser {
 
         let expression = self.parse_expression(context)?;
 
+        if expression.has_window() {
+            return Err(ParsingError::wrap(
+                "window functions are not allowed in WHERE",
+            ));
+        }
+
         Ok(expression.into())
     }
 
@@ -527,6 +880,12 @@ impl Parser {
 
         let expression = self.parse_expression(context)?;
 
+        if expression.has_window() {
+            return Err(ParsingError::wrap(
+                "window functions are not allowed in HAVING",
+            ));
+        }
+
         Ok(HavingClause {
             expression: expression.into(),
         })
@@ -1,4 +1,5 @@
 use crate::engine::ast::dml::parts::_where::WhereClause;
+use crate::engine::ast::dml::parts::target::UpdateTarget;
 use crate::engine::ast::dml::parts::update_item::UpdateItem;
 use crate::engine::ast::dml::update::UpdateQuery;
 use crate::engine::lexer::predule::{OperatorToken, Token};
@@ -52,22 +53,60 @@ impl Parser {
             )));
         }
 
+        for update_item in self.parse_update_items(context.clone())? {
+            query_builder = query_builder.add_update_item(update_item);
+        }
+
+        // FROM 절 파싱
+        if self.has_next_token() && self.pick_next_token() == Token::From {
+            self.get_next_token(); // from 토큰 삼키기
+
+            let target = query_builder.target_table.clone().unwrap();
+            for from_table in self.parse_join_targets(context.clone(), &target)? {
+                query_builder = query_builder.add_from_table(from_table);
+            }
+        }
+
+        // Where 절 파싱
+        if self.next_token_is_where() {
+            self.get_next_token(); // where 토큰 삼키기
+
+            let expression = self.parse_expression(context.clone())?;
+            query_builder = query_builder.set_where(WhereClause { expression });
+        }
+
+        // RETURNING 절 파싱
+        if self.next_token_is_returning() {
+            let returning = self.parse_returning(context)?;
+            query_builder = query_builder.set_returning(returning);
+        }
+
+        Ok(query_builder.build())
+    }
+
+    // SET 절의 수정 항목 목록 파싱
+    // UPDATE foo SET a = 1, b = b + 1 WHERE ...
+    //                ^^^^^^^^^^^^^^^^
+    // FROM, WHERE, RETURNING이나 ';' 앞에서 멈추며, 해당 토큰은 소비하지 않습니다.
+    pub(crate) fn parse_update_items(
+        &mut self,
+        context: ParserContext,
+    ) -> errors::Result<Vec<UpdateItem>> {
+        let mut update_items = vec![];
+
         loop {
             if !self.has_next_token() {
                 break;
             }
 
-            let current_token = self.get_next_token();
+            let current_token = self.get_next_token().into_identifier();
 
             match current_token {
                 Token::Comma => continue,
-                Token::Where => {
+                Token::From | Token::Where | Token::Returning | Token::SemiColon => {
                     self.unget_next_token(current_token);
                     break;
                 }
-                Token::SemiColon => {
-                    return Ok(query_builder.build());
-                }
                 Token::Identifier(identifier) => {
                     if !self.has_next_token() {
                         return Err(ParsingError::wrap("need more tokens"));
@@ -88,12 +127,10 @@ impl Parser {
 
                     let expression = self.parse_expression(context.clone())?;
 
-                    let update_item = UpdateItem {
+                    update_items.push(UpdateItem {
                         column: identifier,
                         value: expression,
-                    };
-
-                    query_builder = query_builder.add_update_item(update_item)
+                    });
                 }
                 _ => {
                     return Err(ParsingError::wrap(format!(
@@ -104,14 +141,58 @@ impl Parser {
             }
         }
 
-        // Where 절 파싱
-        if self.next_token_is_where() {
-            self.get_next_token(); // where 토큰 삼키기
+        Ok(update_items)
+    }
 
-            let expression = self.parse_expression(context)?;
-            query_builder = query_builder.set_where(WhereClause { expression });
+    // UPDATE ... FROM / DELETE ... USING 뒤의 조인 테이블 목록 파싱
+    // UPDATE foo SET a = 1 FROM bar b, baz WHERE ...
+    //                           ^^^^^^^^^^
+    // 대상 테이블을 포함해 같은 이름(별칭)이 두 번 나오면 오류입니다.
+    pub(crate) fn parse_join_targets(
+        &mut self,
+        context: ParserContext,
+        target: &UpdateTarget,
+    ) -> errors::Result<Vec<UpdateTarget>> {
+        let name_of = |target: &UpdateTarget| {
+            target
+                .alias
+                .clone()
+                .unwrap_or_else(|| target.table.table_name.clone())
+        };
+
+        let mut names = vec![name_of(target)];
+        let mut join_targets = vec![];
+
+        loop {
+            if !self.has_next_token() {
+                return Err(ParsingError::wrap("need more tokens"));
+            }
+
+            let table = self.parse_table_name(context.clone())?;
+            let mut join_target = UpdateTarget::from(table);
+
+            if self.next_token_is_table_alias() {
+                join_target.alias = Some(self.parse_table_alias()?);
+            }
+
+            let name = name_of(&join_target);
+            if names.contains(&name) {
+                return Err(ParsingError::wrap(format!(
+                    "table name {:?} specified more than once",
+                    name
+                )));
+            }
+            names.push(name);
+            join_targets.push(join_target);
+
+            if self.has_next_token() && self.pick_next_token() == Token::Comma {
+                self.get_next_token();
+                continue;
+            }
+
+            break;
         }
 
-        Ok(query_builder.build())
+        Ok(join_targets)
     }
 }
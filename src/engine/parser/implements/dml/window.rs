@@ -0,0 +1,296 @@
+use crate::engine::ast::dml::expressions::call::CallExpression;
+use crate::engine::ast::dml::expressions::window::{
+    WindowExpression, WindowFrame, WindowFrameBound, WindowFrameUnit,
+};
+use crate::engine::ast::types::SQLExpression;
+use crate::engine::lexer::predule::Token;
+use crate::engine::parser::predule::{Parser, ParserContext};
+use crate::errors;
+use crate::errors::parsing_error::ParsingError;
+
+impl Parser {
+    // 함수 호출 뒤의 OVER 절 파싱
+    // OVER 절이 없다면 함수 호출을 그대로 반환합니다. 윈도우 전용 함수는 OVER 절이 반드시 필요합니다.
+    pub(crate) fn parse_over_clause(
+        &mut self,
+        call: CallExpression,
+        function_name: &str,
+        context: ParserContext,
+    ) -> errors::Result<SQLExpression> {
+        let has_over = self.has_next_token() && self.pick_next_token() == Token::Over;
+
+        if call.function.is_aggregate() && call.arguments.iter().any(SQLExpression::has_window) {
+            return Err(ParsingError::wrap(if has_over {
+                "window function calls cannot be nested"
+            } else {
+                "aggregate function calls cannot contain window function calls"
+            }));
+        }
+
+        if !has_over {
+            if call.function.is_window() {
+                return Err(ParsingError::wrap(format!(
+                    "window function {}() requires an OVER clause",
+                    function_name
+                )));
+            }
+
+            return Ok(call.into());
+        }
+
+        // OVER 삼킴
+        self.get_next_token();
+
+        if !call.function.is_window() && !call.function.is_aggregate() {
+            return Err(ParsingError::wrap(format!(
+                "OVER specified, but {}() is not a window function nor an aggregate function",
+                function_name
+            )));
+        }
+
+        if call.distinct {
+            return Err(ParsingError::wrap(
+                "DISTINCT is not implemented for window functions",
+            ));
+        }
+
+        if call.arguments.iter().any(SQLExpression::has_window) {
+            return Err(ParsingError::wrap("window function calls cannot be nested"));
+        }
+
+        if !self.has_next_token() {
+            return Err(ParsingError::wrap("need more tokens"));
+        }
+
+        // ( 삼킴
+        let current_token = self.get_next_token();
+
+        if current_token != Token::LeftParentheses {
+            return Err(ParsingError::wrap(format!(
+                "expected left parentheses after 'OVER'. but your input is {:?}",
+                current_token
+            )));
+        }
+
+        let context = context.set_in_parentheses(false);
+
+        let mut window = WindowExpression {
+            call,
+            partition_by: vec![],
+            order_by: vec![],
+            frame: None,
+        };
+
+        // PARTITION BY 절 파싱
+        if self.has_next_token() && self.pick_next_token() == Token::Partition {
+            self.get_next_token();
+            self.parse_window_by_keyword()?;
+
+            loop {
+                window
+                    .partition_by
+                    .push(self.parse_expression(context.clone())?);
+
+                if !self.next_token_is_comma() {
+                    break;
+                }
+
+                self.get_next_token();
+            }
+        }
+
+        // ORDER BY 절 파싱
+        if self.next_token_is_order_by() {
+            self.get_next_token();
+            self.get_next_token();
+
+            loop {
+                window
+                    .order_by
+                    .push(self.parse_order_by_item(context.clone())?);
+
+                if !self.next_token_is_comma() {
+                    break;
+                }
+
+                self.get_next_token();
+            }
+        }
+
+        // 프레임 절 파싱
+        if self.has_next_token() && matches!(self.pick_next_token(), Token::Rows | Token::Range) {
+            let frame = self.parse_window_frame()?;
+
+            let has_offset = matches!(
+                frame.start,
+                WindowFrameBound::Preceding(_) | WindowFrameBound::Following(_)
+            ) || matches!(
+                frame.end,
+                WindowFrameBound::Preceding(_) | WindowFrameBound::Following(_)
+            );
+
+            if frame.unit == WindowFrameUnit::Range && has_offset && window.order_by.len() != 1 {
+                return Err(ParsingError::wrap(
+                    "RANGE with offset PRECEDING/FOLLOWING requires exactly one ORDER BY column",
+                ));
+            }
+
+            window.frame = Some(frame);
+        }
+
+        if !self.has_next_token() {
+            return Err(ParsingError::wrap("need more tokens"));
+        }
+
+        // ) 삼킴
+        let current_token = self.get_next_token();
+
+        if current_token != Token::RightParentheses {
+            return Err(ParsingError::wrap(format!(
+                "expected right parentheses of window definition. but your input is {:?}",
+                current_token
+            )));
+        }
+
+        Ok(window.into())
+    }
+
+    // PARTITION 뒤의 BY 삼킴
+    fn parse_window_by_keyword(&mut self) -> errors::Result<()> {
+        if !self.has_next_token() {
+            return Err(ParsingError::wrap("need more tokens"));
+        }
+
+        let current_token = self.get_next_token();
+
+        if current_token != Token::By {
+            return Err(ParsingError::wrap(format!(
+                "expected 'BY'. but your input word is '{:?}'",
+                current_token
+            )));
+        }
+
+        Ok(())
+    }
+
+    // {ROWS | RANGE} {start | BETWEEN start AND end}
+    fn parse_window_frame(&mut self) -> errors::Result<WindowFrame> {
+        let unit = match self.get_next_token() {
+            Token::Rows => WindowFrameUnit::Rows,
+            _ => WindowFrameUnit::Range,
+        };
+
+        if !self.has_next_token() {
+            return Err(ParsingError::wrap("need more tokens"));
+        }
+
+        let (start, end) = if self.pick_next_token() == Token::Between {
+            self.get_next_token();
+
+            let start = self.parse_window_frame_bound()?;
+
+            if !self.has_next_token() {
+                return Err(ParsingError::wrap("need more tokens"));
+            }
+
+            let current_token = self.get_next_token();
+
+            if current_token != Token::And {
+                return Err(ParsingError::wrap(format!(
+                    "expected 'AND'. but your input word is '{:?}'",
+                    current_token
+                )));
+            }
+
+            (start, self.parse_window_frame_bound()?)
+        } else {
+            (
+                self.parse_window_frame_bound()?,
+                WindowFrameBound::CurrentRow,
+            )
+        };
+
+        if start == WindowFrameBound::UnboundedFollowing {
+            return Err(ParsingError::wrap(
+                "frame start cannot be UNBOUNDED FOLLOWING",
+            ));
+        }
+
+        if end == WindowFrameBound::UnboundedPreceding {
+            return Err(ParsingError::wrap(
+                "frame end cannot be UNBOUNDED PRECEDING",
+            ));
+        }
+
+        // 시작 경계가 끝 경계보다 뒤에 있는 종류라면 프레임이 항상 비므로 오류로 봅니다.
+        let position = |bound: &WindowFrameBound| match bound {
+            WindowFrameBound::UnboundedPreceding => 0,
+            WindowFrameBound::Preceding(_) => 1,
+            WindowFrameBound::CurrentRow => 2,
+            WindowFrameBound::Following(_) => 3,
+            WindowFrameBound::UnboundedFollowing => 4,
+        };
+
+        if position(&start) > position(&end) {
+            return Err(ParsingError::wrap(format!(
+                "frame starting from {:?} cannot end with {:?}",
+                start, end
+            )));
+        }
+
+        Ok(WindowFrame { unit, start, end })
+    }
+
+    // UNBOUNDED PRECEDING | n PRECEDING | CURRENT ROW | n FOLLOWING | UNBOUNDED FOLLOWING
+    fn parse_window_frame_bound(&mut self) -> errors::Result<WindowFrameBound> {
+        if !self.has_next_token() {
+            return Err(ParsingError::wrap("need more tokens"));
+        }
+
+        let current_token = self.get_next_token();
+
+        let offset = match current_token {
+            Token::Current => {
+                if !self.has_next_token() {
+                    return Err(ParsingError::wrap("need more tokens"));
+                }
+
+                let current_token = self.get_next_token();
+
+                if current_token != Token::Row {
+                    return Err(ParsingError::wrap(format!(
+                        "expected 'ROW' after 'CURRENT'. but your input word is '{:?}'",
+                        current_token
+                    )));
+                }
+
+                return Ok(WindowFrameBound::CurrentRow);
+            }
+            Token::Unbounded => None,
+            Token::Integer(offset) if offset >= 0 => Some(offset as u64),
+            _ => {
+                return Err(ParsingError::wrap(format!(
+                    "expected frame bound. but your input word is '{:?}'",
+                    current_token
+                )));
+            }
+        };
+
+        if !self.has_next_token() {
+            return Err(ParsingError::wrap("need more tokens"));
+        }
+
+        let current_token = self.get_next_token();
+
+        match (current_token, offset) {
+            (Token::Preceding, None) => Ok(WindowFrameBound::UnboundedPreceding),
+            (Token::Preceding, Some(offset)) => Ok(WindowFrameBound::Preceding(offset)),
+            (Token::Following, None) => Ok(WindowFrameBound::UnboundedFollowing),
+            (Token::Following, Some(offset)) => Ok(WindowFrameBound::Following(offset)),
+            (current_token, _) => Err(ParsingError::wrap(format!(
+                "expected 'PRECEDING' or 'FOLLOWING'. but your input word is '{:?}'",
+                current_token
+            ))),
+        }
+    }
+}
@@ -0,0 +1,223 @@
+use crate::engine::ast::SQLStatement;
+use crate::engine::ast::dml::parts::with::{CommonTableExpression, WithClause};
+use crate::engine::ast::types::TableName;
+use crate::engine::lexer::predule::Token;
+use crate::engine::parser::predule::{Parser, ParserContext};
+use crate::errors;
+use crate::errors::parsing_error::ParsingError;
+
+impl Parser {
+    // WITH 절로 시작하는 문장 파싱. WITH 절 뒤에는 SELECT, INSERT, UPDATE, DELETE가 올 수 있습니다.
+    pub(crate) fn handle_with_query(
+        &mut self,
+        context: ParserContext,
+    ) -> errors::Result<SQLStatement> {
+        let (with_clause, context) = self.parse_with_clause(context)?;
+        let with_clause = with_clause.ok_or_else(|| ParsingError::wrap("expected 'WITH'"))?;
+
+        if !self.has_next_token() {
+            return Err(ParsingError::wrap("need more tokens"));
+        }
+
+        let current_token = self.get_next_token();
+        self.unget_next_token(current_token.clone());
+
+        match current_token {
+            Token::Select | Token::LeftParentheses => Ok(self
+                .handle_select_query(context)?
+                .set_with(with_clause)
+                .into()),
+            Token::Insert => Ok(self
+                .handle_insert_query(context)?
+                .set_with(with_clause)
+                .into()),
+            Token::Update => Ok(self
+                .handle_update_query(context)?
+                .set_with(with_clause)
+                .into()),
+            Token::Delete => Ok(self
+                .handle_delete_query(context)?
+                .set_with(with_clause)
+                .into()),
+            _ => Err(ParsingError::wrap(format!(
+                "expected 'SELECT', 'INSERT', 'UPDATE' or 'DELETE' after WITH clause. but your input word is '{:?}'",
+                current_token
+            ))),
+        }
+    }
+
+    // WITH [RECURSIVE] name [(column, ...)] AS (query), ...
+    // WITH 절이 없다면 아무것도 삼키지 않습니다.
+    // 반환하는 context에는 선언된 테이블들이 바인딩되어 있어, 이후 FROM 절에서 실제 테이블보다 먼저 찾아집니다.
+    pub(crate) fn parse_with_clause(
+        &mut self,
+        mut context: ParserContext,
+    ) -> errors::Result<(Option<WithClause>, ParserContext)> {
+        if !self.has_next_token() {
+            return Ok((None, context));
+        }
+
+        let current_token = self.get_next_token();
+
+        if current_token != Token::With {
+            self.unget_next_token(current_token);
+            return Ok((None, context));
+        }
+
+        if !self.has_next_token() {
+            return Err(ParsingError::wrap("need more tokens"));
+        }
+
+        let current_token = self.get_next_token();
+        let recursive = current_token == Token::Recursive;
+
+        if !recursive {
+            self.unget_next_token(current_token);
+        }
+
+        let mut common_tables: Vec<CommonTableExpression> = vec![];
+
+        loop {
+            if !self.has_next_token() {
+                return Err(ParsingError::wrap("need more tokens"));
+            }
+
+            let name = match self.get_next_token().into_identifier() {
+                Token::Identifier(name) => name,
+                token => {
+                    return Err(ParsingError::wrap(format!(
+                        "expected WITH query name. but your input word is '{:?}'",
+                        token
+                    )));
+                }
+            };
+
+            if common_tables
+                .iter()
+                .any(|common_table| common_table.name == name)
+            {
+                return Err(ParsingError::wrap(format!(
+                    "WITH query name '{}' specified more than once",
+                    name
+                )));
+            }
+
+            let columns = if self.next_token_is_left_parentheses() {
+                self.parse_with_columns()?
+            } else {
+                vec![]
+            };
+
+            if !self.has_next_token() {
+                return Err(ParsingError::wrap("need more tokens"));
+            }
+
+            let current_token = self.get_next_token();
+
+            if current_token != Token::As {
+                return Err(ParsingError::wrap(format!(
+                    "expected 'AS'. but your input word is '{:?}'",
+                    current_token
+                )));
+            }
+
+            let binding = CommonTableExpression::new_binding(&name);
+
+            // RECURSIVE라면 쿼리 본문에서 자기 자신을 참조할 수 있습니다.
+            let query_context = if recursive {
+                context
+                    .clone()
+                    .add_common_table(name.clone(), binding.clone())
+            } else {
+                context.clone()
+            };
+
+            if !self.next_token_is_left_parentheses() {
+                return Err(ParsingError::wrap(format!(
+                    "expected left parentheses after 'AS' of WITH query '{}'",
+                    name
+                )));
+            }
+
+            let query = self.parse_subquery(query_context)?.into_query();
+
+            context = context.add_common_table(name.clone(), binding.clone());
+
+            common_tables.push(CommonTableExpression {
+                name,
+                binding,
+                columns,
+                query: Box::new(query),
+            });
+
+            if self.next_token_is_comma() {
+                self.get_next_token();
+                continue;
+            }
+
+            break;
+        }
+
+        Ok((
+            Some(WithClause {
+                recursive,
+                common_tables,
+            }),
+            context,
+        ))
+    }
+
+    // name (column, ...) 의 컬럼 목록 파싱
+    fn parse_with_columns(&mut self) -> errors::Result<Vec<String>> {
+        // ( 삼킴
+        self.get_next_token();
+
+        let mut columns = vec![];
+
+        loop {
+            if !self.has_next_token() {
+                return Err(ParsingError::wrap("need more tokens"));
+            }
+
+            match self.get_next_token().into_identifier() {
+                Token::Identifier(column) => columns.push(column),
+                Token::Comma => continue,
+                Token::RightParentheses if !columns.is_empty() => break,
+                token => {
+                    return Err(ParsingError::wrap(format!(
+                        "expected column name of WITH query. but your input word is '{:?}'",
+                        token
+                    )));
+                }
+            }
+        }
+
+        Ok(columns)
+    }
+
+    // FROM, JOIN 절의 테이블명 파싱
+    // 데이터베이스로 한정하지 않은 이름이 WITH 절 테이블과 같다면 그 테이블의 바인딩을 반환하고,
+    // 별칭이 없을 때 쓸 원래 이름도 함께 반환합니다.
+    pub(crate) fn parse_from_table_name(
+        &mut self,
+        context: ParserContext,
+    ) -> errors::Result<(TableName, Option<String>)> {
+        let mut unqualified_context = context.clone();
+        unqualified_context.default_database = None;
+
+        let table_name = self.parse_table_name(unqualified_context)?;
+
+        if table_name.database_name.is_none() {
+            if let Some(binding) = context.find_common_table(&table_name.table_name) {
+                return Ok((binding.clone(), Some(table_name.table_name)));
+            }
+
+            return Ok((
+                TableName::new(context.default_database, table_name.table_name),
+                None,
+            ));
+        }
+
+        Ok((table_name, None))
+    }
+}
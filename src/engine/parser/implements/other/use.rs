@@ -14,7 +14,7 @@ impl Parser {
             return Err(ParsingError::wrap("need more tokens"));
         }
 
-        let current_token = self.get_next_token();
+        let current_token = self.get_next_token().into_identifier();
 
         match current_token {
             Token::Identifier(identifier) => Ok(UseDatabaseQuery {
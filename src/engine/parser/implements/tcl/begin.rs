@@ -1,25 +1,107 @@
 use crate::engine::ast::SQLStatement;
-use crate::engine::ast::tcl::BeginTransactionQuery;
+use crate::engine::ast::tcl::{BeginTransactionQuery, IsolationLevel};
 use crate::engine::lexer::tokens::Token;
 use crate::engine::parser::predule::{Parser, ParserContext};
 use crate::errors;
 use crate::errors::parsing_error::ParsingError;
 
 impl Parser {
+    // BEGIN [TRANSACTION] [ISOLATION LEVEL ...] 쿼리 분석
     pub(crate) fn parse_begin_query(
         &mut self,
         _context: ParserContext,
     ) -> errors::Result<SQLStatement> {
+        let mut query_builder = BeginTransactionQuery::builder();
+
+        // TRANSACTION 키워드는 생략할 수 있습니다.
+        if self.has_next_token() && self.pick_next_token() == Token::Transaction {
+            self.get_next_token();
+        }
+
+        if !self.has_next_token() {
+            return Ok(query_builder.build());
+        }
+
+        match self.get_next_token() {
+            Token::SemiColon => {
+                self.unget_next_token(Token::SemiColon);
+                return Ok(query_builder.build());
+            }
+            // ISOLATION LEVEL은 컬럼명 등으로도 쓰이는 단어라 키워드 토큰이 아닙니다.
+            Token::Identifier(ref keyword) if keyword.eq_ignore_ascii_case("ISOLATION") => {
+                self.parse_keyword_identifier("LEVEL")?;
+                query_builder = query_builder.set_isolation_level(self.parse_isolation_level()?);
+            }
+            token => {
+                return Err(ParsingError::wrap(format!(
+                    "expected 'TRANSACTION' or 'ISOLATION LEVEL'. but your input word is '{:?}'",
+                    token
+                )));
+            }
+        }
+
+        if self.has_next_token() && self.pick_next_token() != Token::SemiColon {
+            return Err(ParsingError::wrap(format!(
+                "unexpected token '{:?}' after BEGIN",
+                self.pick_next_token()
+            )));
+        }
+
+        Ok(query_builder.build())
+    }
+
+    // READ UNCOMMITTED | READ COMMITTED | REPEATABLE READ | SERIALIZABLE
+    fn parse_isolation_level(&mut self) -> errors::Result<IsolationLevel> {
         if !self.has_next_token() {
             return Err(ParsingError::wrap("need more tokens"));
         }
 
-        let current_token = self.get_next_token();
+        match self.get_next_token() {
+            Token::Identifier(ref keyword) if keyword.eq_ignore_ascii_case("READ") => {
+                if !self.has_next_token() {
+                    return Err(ParsingError::wrap("need more tokens"));
+                }
 
-        if current_token != Token::Transaction {
-            return Err(ParsingError::wrap("Expected BEGIN".to_string()));
+                match self.get_next_token() {
+                    Token::Identifier(ref keyword) if keyword.eq_ignore_ascii_case("COMMITTED") => {
+                        Ok(IsolationLevel::ReadCommitted)
+                    }
+                    Token::Identifier(ref keyword)
+                        if keyword.eq_ignore_ascii_case("UNCOMMITTED") =>
+                    {
+                        Ok(IsolationLevel::ReadUncommitted)
+                    }
+                    token => Err(ParsingError::wrap(format!(
+                        "expected 'COMMITTED' or 'UNCOMMITTED'. but your input word is '{:?}'",
+                        token
+                    ))),
+                }
+            }
+            Token::Identifier(ref keyword) if keyword.eq_ignore_ascii_case("REPEATABLE") => {
+                self.parse_keyword_identifier("READ")?;
+                Ok(IsolationLevel::RepeatableRead)
+            }
+            Token::Identifier(ref keyword) if keyword.eq_ignore_ascii_case("SERIALIZABLE") => {
+                Ok(IsolationLevel::Serializable)
+            }
+            token => Err(ParsingError::wrap(format!(
+                "expected isolation level. but your input word is '{:?}'",
+                token
+            ))),
         }
+    }
 
-        Ok(BeginTransactionQuery {}.into())
+    pub(crate) fn parse_keyword_identifier(&mut self, expected: &str) -> errors::Result<()> {
+        if !self.has_next_token() {
+            return Err(ParsingError::wrap("need more tokens"));
+        }
+
+        match self.get_next_token() {
+            Token::Identifier(ref keyword) if keyword.eq_ignore_ascii_case(expected) => Ok(()),
+            token => Err(ParsingError::wrap(format!(
+                "expected '{}'. but your input word is '{:?}'",
+                expected, token
+            ))),
+        }
     }
 }
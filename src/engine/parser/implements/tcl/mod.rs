@@ -1,3 +1,4 @@
 pub mod begin;
 pub mod commit;
 pub mod rollback;
+pub mod savepoint;
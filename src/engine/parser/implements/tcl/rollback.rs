@@ -1,13 +1,27 @@
 use crate::engine::ast::SQLStatement;
-use crate::engine::ast::tcl::RollbackQuery;
+use crate::engine::ast::tcl::{RollbackQuery, RollbackToSavepointQuery};
+use crate::engine::lexer::tokens::Token;
 use crate::engine::parser::predule::{Parser, ParserContext};
 use crate::errors;
 
 impl Parser {
+    // ROLLBACK 또는 ROLLBACK TO [SAVEPOINT] name 쿼리 분석
     pub(crate) fn parse_rollback_query(
         &mut self,
         _context: ParserContext,
     ) -> errors::Result<SQLStatement> {
-        Ok(RollbackQuery {}.into())
+        if !self.has_next_token() || self.pick_next_token() != Token::To {
+            return Ok(RollbackQuery {}.into());
+        }
+        self.get_next_token();
+
+        // SAVEPOINT 키워드는 생략할 수 있습니다.
+        if self.has_next_token() && self.pick_next_token() == Token::Savepoint {
+            self.get_next_token();
+        }
+
+        let name = self.parse_savepoint_name()?;
+
+        Ok(RollbackToSavepointQuery { name }.into())
     }
 }
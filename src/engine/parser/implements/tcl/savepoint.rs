@@ -0,0 +1,58 @@
+use crate::engine::ast::SQLStatement;
+use crate::engine::ast::tcl::{ReleaseSavepointQuery, SavepointQuery};
+use crate::engine::lexer::tokens::Token;
+use crate::engine::parser::predule::{Parser, ParserContext};
+use crate::errors;
+use crate::errors::parsing_error::ParsingError;
+
+impl Parser {
+    // SAVEPOINT name 쿼리 분석
+    pub(crate) fn parse_savepoint_query(
+        &mut self,
+        _context: ParserContext,
+    ) -> errors::Result<SQLStatement> {
+        let name = self.parse_savepoint_name()?;
+
+        Ok(SavepointQuery { name }.into())
+    }
+
+    // RELEASE [SAVEPOINT] name 쿼리 분석
+    pub(crate) fn parse_release_query(
+        &mut self,
+        _context: ParserContext,
+    ) -> errors::Result<SQLStatement> {
+        // SAVEPOINT 키워드는 생략할 수 있습니다.
+        if self.has_next_token() && self.pick_next_token() == Token::Savepoint {
+            self.get_next_token();
+        }
+
+        let name = self.parse_savepoint_name()?;
+
+        Ok(ReleaseSavepointQuery { name }.into())
+    }
+
+    pub(crate) fn parse_savepoint_name(&mut self) -> errors::Result<String> {
+        if !self.has_next_token() {
+            return Err(ParsingError::wrap("need more tokens"));
+        }
+
+        let name = match self.get_next_token().into_identifier() {
+            Token::Identifier(name) => name,
+            token => {
+                return Err(ParsingError::wrap(format!(
+                    "expected savepoint name. but your input word is '{:?}'",
+                    token
+                )));
+            }
+        };
+
+        if self.has_next_token() && self.pick_next_token() != Token::SemiColon {
+            return Err(ParsingError::wrap(format!(
+                "unexpected token '{:?}' after savepoint name",
+                self.pick_next_token()
+            )));
+        }
+
+        Ok(name)
+    }
+}
@@ -55,11 +55,19 @@ impl Parser {
                 Token::Create => statements.push(self.handle_create_query(context.clone())?),
                 Token::Alter => statements.push(self.handle_alter_query(context.clone())?),
                 Token::Drop => statements.push(self.handle_drop_query(context.clone())?),
+                Token::Truncate => {
+                    statements.push(self.handle_truncate_table_query(context.clone())?)
+                }
                 Token::Select => {
                     self.unget_next_token(current_token);
                     let query = self.handle_select_query(context.clone())?;
                     statements.push(query.into());
                 }
+                Token::With => {
+                    self.unget_next_token(current_token);
+                    let query = self.handle_with_query(context.clone())?;
+                    statements.push(query);
+                }
                 Token::Update => {
                     self.unget_next_token(current_token);
                     let query = self.handle_update_query(context.clone())?;
@@ -103,6 +111,14 @@ impl Parser {
                     let query = self.parse_rollback_query(context.clone())?;
                     statements.push(query);
                 }
+                Token::Savepoint => {
+                    let query = self.parse_savepoint_query(context.clone())?;
+                    statements.push(query);
+                }
+                Token::Release => {
+                    let query = self.parse_release_query(context.clone())?;
+                    statements.push(query);
+                }
                 _ => {
                     break;
                 }
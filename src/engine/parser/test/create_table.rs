@@ -50,3 +50,38 @@ pub fn create_table() {
         vec![expected],
     );
 }
+
+#[test]
+pub fn create_table_with_unreserved_keyword_columns() {
+    let text =
+        "CREATE TABLE t (range INTEGER, rows INTEGER, current INTEGER, end INTEGER, function INTEGER, returns INTEGER, savepoint INTEGER, release INTEGER, escape INTEGER, recursive INTEGER, truncate INTEGER);".to_owned();
+
+    let mut parser = Parser::with_string(text).unwrap();
+
+    let mut expected = CreateTableQuery::builder().set_table(TableName::new(None, "t".to_owned()));
+    for name in [
+        "range",
+        "rows",
+        "current",
+        "end",
+        "function",
+        "returns",
+        "savepoint",
+        "release",
+        "escape",
+        "recursive",
+        "truncate",
+    ] {
+        expected = expected.add_column(
+            Column::builder()
+                .set_name(name.to_owned())
+                .set_data_type(DataType::Int)
+                .build(),
+        );
+    }
+
+    assert_eq!(
+        parser.parse(ParserContext::default()).unwrap(),
+        vec![expected.build()],
+    );
+}
@@ -4,6 +4,7 @@ use crate::engine::ast::dml::delete::DeleteQuery;
 use crate::engine::ast::dml::expressions::binary::BinaryOperatorExpression;
 use crate::engine::ast::dml::expressions::operators::BinaryOperator;
 use crate::engine::ast::dml::parts::_where::WhereClause;
+use crate::engine::ast::dml::parts::target::UpdateTarget;
 use crate::engine::ast::types::{SQLExpression, SelectColumn, TableName};
 use crate::engine::lexer::predule::OperatorToken;
 use crate::engine::lexer::tokens::Token;
@@ -85,6 +86,84 @@ pub fn test_delete_query() {
                 .build(),
             want_error: false,
         },
+        TestCase {
+            name: "성공: DELETE FROM foo.bar b USING foo.baz c WHERE b.id = c.id".into(),
+            input: vec![
+                Token::Delete,
+                Token::From,
+                Token::Identifier("foo".into()),
+                Token::Period,
+                Token::Identifier("bar".into()),
+                Token::Identifier("b".into()),
+                Token::Using,
+                Token::Identifier("foo".into()),
+                Token::Period,
+                Token::Identifier("baz".into()),
+                Token::Identifier("c".into()),
+                Token::Where,
+                Token::Identifier("b".into()),
+                Token::Period,
+                Token::Identifier("id".into()),
+                Token::Operator(OperatorToken::Eq),
+                Token::Identifier("c".into()),
+                Token::Period,
+                Token::Identifier("id".into()),
+            ],
+            expected: DeleteQuery::builder()
+                .set_from_table(TableName {
+                    database_name: Some("foo".into()),
+                    table_name: "bar".into(),
+                })
+                .set_from_alias("b".into())
+                .add_using_table(UpdateTarget {
+                    table: TableName {
+                        database_name: Some("foo".into()),
+                        table_name: "baz".into(),
+                    },
+                    alias: Some("c".into()),
+                })
+                .set_where(WhereClause {
+                    expression: BinaryOperatorExpression {
+                        operator: BinaryOperator::Eq,
+                        lhs: SelectColumn::new(Some("b".into()), "id".into()).into(),
+                        rhs: SelectColumn::new(Some("c".into()), "id".into()).into(),
+                    }
+                    .into(),
+                })
+                .build(),
+            want_error: false,
+        },
+        TestCase {
+            name: "실패: DELETE FROM foo.bar b USING foo.baz b".into(),
+            input: vec![
+                Token::Delete,
+                Token::From,
+                Token::Identifier("foo".into()),
+                Token::Period,
+                Token::Identifier("bar".into()),
+                Token::Identifier("b".into()),
+                Token::Using,
+                Token::Identifier("foo".into()),
+                Token::Period,
+                Token::Identifier("baz".into()),
+                Token::Identifier("b".into()),
+            ],
+            expected: Default::default(),
+            want_error: true,
+        },
+        TestCase {
+            name: "실패: DELETE FROM foo.bar USING".into(),
+            input: vec![
+                Token::Delete,
+                Token::From,
+                Token::Identifier("foo".into()),
+                Token::Period,
+                Token::Identifier("bar".into()),
+                Token::Using,
+            ],
+            expected: Default::default(),
+            want_error: true,
+        },
         TestCase {
             name: "실패: 토큰이 하나도 없음".into(),
             input: vec![],
@@ -132,7 +211,7 @@ pub fn test_delete_query() {
         );
 
         if let Ok(statements) = got {
-            assert_eq!(statements, t.expected.into(), "TC: {}", t.name);
+            assert_eq!(statements, t.expected, "TC: {}", t.name);
         }
     }
 }
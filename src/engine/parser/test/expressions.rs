@@ -3,15 +3,20 @@
 use crate::engine::ast::dml::expressions::between::BetweenExpression;
 use crate::engine::ast::dml::expressions::binary::BinaryOperatorExpression;
 use crate::engine::ast::dml::expressions::call::CallExpression;
+use crate::engine::ast::dml::expressions::case::{CaseExpression, CaseWhenBranch};
+use crate::engine::ast::dml::expressions::cast::CastExpression;
+use crate::engine::ast::dml::expressions::like_escape::LikeEscapeExpression;
 use crate::engine::ast::dml::expressions::list::ListExpression;
 use crate::engine::ast::dml::expressions::not_between::NotBetweenExpression;
 use crate::engine::ast::dml::expressions::operators::{BinaryOperator, UnaryOperator};
 use crate::engine::ast::dml::expressions::parentheses::ParenthesesExpression;
+use crate::engine::ast::dml::expressions::subquery::SubqueryExpression;
 use crate::engine::ast::dml::expressions::unary::UnaryOperatorExpression;
 use crate::engine::ast::dml::parts::select_item::SelectItem;
 use crate::engine::ast::dml::select::SelectQuery;
 use crate::engine::ast::types::{
-    ConditionalFunction, SQLExpression, SelectColumn, UserDefinedFunction,
+    AggregateFunction, ConditionalFunction, DataType, SQLExpression, ScalarFunction, SelectColumn,
+    UserDefinedFunction,
 };
 use crate::engine::lexer::predule::OperatorToken;
 use crate::engine::lexer::tokens::Token;
@@ -229,6 +234,7 @@ fn test_parse_expression() {
                 }
                 .into(),
                 arguments: vec![SQLExpression::Null, SQLExpression::Integer(1)],
+                distinct: false,
             }
             .into(),
             want_error: false,
@@ -246,6 +252,7 @@ fn test_parse_expression() {
             expected: CallExpression {
                 function: ConditionalFunction::Coalesce.into(),
                 arguments: vec![SQLExpression::Null, SQLExpression::Integer(1)],
+                distinct: false,
             }
             .into(),
             want_error: false,
@@ -530,6 +537,356 @@ fn test_parse_expression() {
             .into(),
             want_error: false,
         },
+        TestCase {
+            name: "1 in (1)".into(),
+            input: vec![
+                Token::Integer(1),
+                Token::In,
+                Token::LeftParentheses,
+                Token::Integer(1),
+                Token::RightParentheses,
+            ],
+            expected: BinaryOperatorExpression {
+                operator: BinaryOperator::In,
+                lhs: SQLExpression::Integer(1),
+                rhs: ListExpression {
+                    value: vec![SQLExpression::Integer(1)],
+                }
+                .into(),
+            }
+            .into(),
+            want_error: false,
+        },
+        TestCase {
+            name: "1 not in (1) and 2 = 2".into(),
+            input: vec![
+                Token::Integer(1),
+                Token::Not,
+                Token::In,
+                Token::LeftParentheses,
+                Token::Integer(1),
+                Token::RightParentheses,
+                Token::And,
+                Token::Integer(2),
+                Token::Operator(OperatorToken::Eq),
+                Token::Integer(2),
+            ],
+            expected: BinaryOperatorExpression {
+                operator: BinaryOperator::And,
+                lhs: BinaryOperatorExpression {
+                    operator: BinaryOperator::NotIn,
+                    lhs: SQLExpression::Integer(1),
+                    rhs: ListExpression {
+                        value: vec![SQLExpression::Integer(1)],
+                    }
+                    .into(),
+                }
+                .into(),
+                rhs: BinaryOperatorExpression {
+                    operator: BinaryOperator::Eq,
+                    lhs: SQLExpression::Integer(2),
+                    rhs: SQLExpression::Integer(2),
+                }
+                .into(),
+            }
+            .into(),
+            want_error: false,
+        },
+        TestCase {
+            name: "name ilike 'a%'".into(),
+            input: vec![
+                Token::Identifier("name".to_owned()),
+                Token::ILike,
+                Token::String("a%".to_owned()),
+            ],
+            expected: BinaryOperatorExpression {
+                operator: BinaryOperator::ILike,
+                lhs: SelectColumn::new(None, "name".to_owned()).into(),
+                rhs: SQLExpression::String("a%".to_owned()),
+            }
+            .into(),
+            want_error: false,
+        },
+        TestCase {
+            name: "name not ilike 'a%'".into(),
+            input: vec![
+                Token::Identifier("name".to_owned()),
+                Token::Not,
+                Token::ILike,
+                Token::String("a%".to_owned()),
+            ],
+            expected: BinaryOperatorExpression {
+                operator: BinaryOperator::NotILike,
+                lhs: SelectColumn::new(None, "name".to_owned()).into(),
+                rhs: SQLExpression::String("a%".to_owned()),
+            }
+            .into(),
+            want_error: false,
+        },
+        TestCase {
+            name: "name like 'a!%%' escape '!' and id = 1".into(),
+            input: vec![
+                Token::Identifier("name".to_owned()),
+                Token::Like,
+                Token::String("a!%%".to_owned()),
+                Token::Escape,
+                Token::String("!".to_owned()),
+                Token::And,
+                Token::Identifier("id".to_owned()),
+                Token::Operator(OperatorToken::Eq),
+                Token::Integer(1),
+            ],
+            expected: BinaryOperatorExpression {
+                operator: BinaryOperator::And,
+                lhs: BinaryOperatorExpression {
+                    operator: BinaryOperator::Like,
+                    lhs: SelectColumn::new(None, "name".to_owned()).into(),
+                    rhs: LikeEscapeExpression {
+                        pattern: SQLExpression::String("a!%%".to_owned()),
+                        escape: SQLExpression::String("!".to_owned()),
+                    }
+                    .into(),
+                }
+                .into(),
+                rhs: BinaryOperatorExpression {
+                    operator: BinaryOperator::Eq,
+                    lhs: SelectColumn::new(None, "id".to_owned()).into(),
+                    rhs: SQLExpression::Integer(1),
+                }
+                .into(),
+            }
+            .into(),
+            want_error: false,
+        },
+        TestCase {
+            name: "id = 1 and name not like 'a!%' escape '!'".into(),
+            input: vec![
+                Token::Identifier("id".to_owned()),
+                Token::Operator(OperatorToken::Eq),
+                Token::Integer(1),
+                Token::And,
+                Token::Identifier("name".to_owned()),
+                Token::Not,
+                Token::Like,
+                Token::String("a!%".to_owned()),
+                Token::Escape,
+                Token::String("!".to_owned()),
+            ],
+            expected: BinaryOperatorExpression {
+                operator: BinaryOperator::And,
+                lhs: BinaryOperatorExpression {
+                    operator: BinaryOperator::Eq,
+                    lhs: SelectColumn::new(None, "id".to_owned()).into(),
+                    rhs: SQLExpression::Integer(1),
+                }
+                .into(),
+                rhs: BinaryOperatorExpression {
+                    operator: BinaryOperator::NotLike,
+                    lhs: SelectColumn::new(None, "name".to_owned()).into(),
+                    rhs: LikeEscapeExpression {
+                        pattern: SQLExpression::String("a!%".to_owned()),
+                        escape: SQLExpression::String("!".to_owned()),
+                    }
+                    .into(),
+                }
+                .into(),
+            }
+            .into(),
+            want_error: false,
+        },
+        TestCase {
+            name: "escape like 'a!%' escape '!'".into(),
+            input: vec![
+                Token::Escape,
+                Token::Like,
+                Token::String("a!%".to_owned()),
+                Token::Escape,
+                Token::String("!".to_owned()),
+            ],
+            expected: BinaryOperatorExpression {
+                operator: BinaryOperator::Like,
+                lhs: SelectColumn::new(None, "escape".to_owned()).into(),
+                rhs: LikeEscapeExpression {
+                    pattern: SQLExpression::String("a!%".to_owned()),
+                    escape: SQLExpression::String("!".to_owned()),
+                }
+                .into(),
+            }
+            .into(),
+            want_error: false,
+        },
+        TestCase {
+            name: "name like 'a%' escape 1".into(),
+            input: vec![
+                Token::Identifier("name".to_owned()),
+                Token::Like,
+                Token::String("a%".to_owned()),
+                Token::Escape,
+                Token::Integer(1),
+            ],
+            expected: SQLExpression::Null,
+            want_error: true,
+        },
+        TestCase {
+            name: "x is null and y = 1".into(),
+            input: vec![
+                Token::Identifier("x".to_owned()),
+                Token::Is,
+                Token::Null,
+                Token::And,
+                Token::Identifier("y".to_owned()),
+                Token::Operator(OperatorToken::Eq),
+                Token::Integer(1),
+            ],
+            expected: BinaryOperatorExpression {
+                operator: BinaryOperator::And,
+                lhs: BinaryOperatorExpression {
+                    operator: BinaryOperator::Is,
+                    lhs: SelectColumn::new(None, "x".to_owned()).into(),
+                    rhs: SQLExpression::Null,
+                }
+                .into(),
+                rhs: BinaryOperatorExpression {
+                    operator: BinaryOperator::Eq,
+                    lhs: SelectColumn::new(None, "y".to_owned()).into(),
+                    rhs: SQLExpression::Integer(1),
+                }
+                .into(),
+            }
+            .into(),
+            want_error: false,
+        },
+        TestCase {
+            name: "id = 1 and name is not null".into(),
+            input: vec![
+                Token::Identifier("id".to_owned()),
+                Token::Operator(OperatorToken::Eq),
+                Token::Integer(1),
+                Token::And,
+                Token::Identifier("name".to_owned()),
+                Token::Is,
+                Token::Not,
+                Token::Null,
+            ],
+            expected: BinaryOperatorExpression {
+                operator: BinaryOperator::And,
+                lhs: BinaryOperatorExpression {
+                    operator: BinaryOperator::Eq,
+                    lhs: SelectColumn::new(None, "id".to_owned()).into(),
+                    rhs: SQLExpression::Integer(1),
+                }
+                .into(),
+                rhs: BinaryOperatorExpression {
+                    operator: BinaryOperator::IsNot,
+                    lhs: SelectColumn::new(None, "name".to_owned()).into(),
+                    rhs: SQLExpression::Null,
+                }
+                .into(),
+            }
+            .into(),
+            want_error: false,
+        },
+        TestCase {
+            name: "a + 1 is null".into(),
+            input: vec![
+                Token::Identifier("a".to_owned()),
+                Token::Operator(OperatorToken::Plus),
+                Token::Integer(1),
+                Token::Is,
+                Token::Null,
+            ],
+            expected: BinaryOperatorExpression {
+                operator: BinaryOperator::Is,
+                lhs: BinaryOperatorExpression {
+                    operator: BinaryOperator::Add,
+                    lhs: SelectColumn::new(None, "a".to_owned()).into(),
+                    rhs: SQLExpression::Integer(1),
+                }
+                .into(),
+                rhs: SQLExpression::Null,
+            }
+            .into(),
+            want_error: false,
+        },
+        TestCase {
+            name: "flag is not true".into(),
+            input: vec![
+                Token::Identifier("flag".to_owned()),
+                Token::Is,
+                Token::Not,
+                Token::Boolean(true),
+            ],
+            expected: BinaryOperatorExpression {
+                operator: BinaryOperator::IsNot,
+                lhs: SelectColumn::new(None, "flag".to_owned()).into(),
+                rhs: SQLExpression::Boolean(true),
+            }
+            .into(),
+            want_error: false,
+        },
+        TestCase {
+            name: "a is distinct from b + 1".into(),
+            input: vec![
+                Token::Identifier("a".to_owned()),
+                Token::Is,
+                Token::Distinct,
+                Token::From,
+                Token::Identifier("b".to_owned()),
+                Token::Operator(OperatorToken::Plus),
+                Token::Integer(1),
+            ],
+            expected: BinaryOperatorExpression {
+                operator: BinaryOperator::IsDistinctFrom,
+                lhs: SelectColumn::new(None, "a".to_owned()).into(),
+                rhs: BinaryOperatorExpression {
+                    operator: BinaryOperator::Add,
+                    lhs: SelectColumn::new(None, "b".to_owned()).into(),
+                    rhs: SQLExpression::Integer(1),
+                }
+                .into(),
+            }
+            .into(),
+            want_error: false,
+        },
+        TestCase {
+            name: "a is not distinct from null".into(),
+            input: vec![
+                Token::Identifier("a".to_owned()),
+                Token::Is,
+                Token::Not,
+                Token::Distinct,
+                Token::From,
+                Token::Null,
+            ],
+            expected: BinaryOperatorExpression {
+                operator: BinaryOperator::IsNotDistinctFrom,
+                lhs: SelectColumn::new(None, "a".to_owned()).into(),
+                rhs: SQLExpression::Null,
+            }
+            .into(),
+            want_error: false,
+        },
+        TestCase {
+            name: "a is 1".into(),
+            input: vec![
+                Token::Identifier("a".to_owned()),
+                Token::Is,
+                Token::Integer(1),
+            ],
+            expected: SQLExpression::Null,
+            want_error: true,
+        },
+        TestCase {
+            name: "a is distinct b".into(),
+            input: vec![
+                Token::Identifier("a".to_owned()),
+                Token::Is,
+                Token::Distinct,
+                Token::Identifier("b".to_owned()),
+            ],
+            expected: SQLExpression::Null,
+            want_error: true,
+        },
         TestCase {
             name: "3+(10*2+44)-11".into(),
             input: vec![
@@ -689,6 +1046,64 @@ fn test_parse_expression() {
             .into(),
             want_error: false,
         },
+        TestCase {
+            name: r#"EXISTS (SELECT 1) AND foo"#.into(),
+            input: vec![
+                Token::Exists,
+                Token::LeftParentheses,
+                Token::Select,
+                Token::Integer(1),
+                Token::RightParentheses,
+                Token::And,
+                Token::Identifier("foo".to_owned()),
+            ],
+            expected: BinaryOperatorExpression {
+                operator: BinaryOperator::And,
+                lhs: SQLExpression::Subquery(SubqueryExpression::Exists(Box::new(
+                    SelectQuery::builder()
+                        .add_select_item(
+                            SelectItem::builder()
+                                .set_item(SQLExpression::Integer(1))
+                                .build(),
+                        )
+                        .build(),
+                ))),
+                rhs: SelectColumn::new(None, "foo".to_owned()).into(),
+            }
+            .into(),
+            want_error: false,
+        },
+        TestCase {
+            name: r#"NOT EXISTS (SELECT 1)"#.into(),
+            input: vec![
+                Token::Not,
+                Token::Exists,
+                Token::LeftParentheses,
+                Token::Select,
+                Token::Integer(1),
+                Token::RightParentheses,
+            ],
+            expected: UnaryOperatorExpression {
+                operator: UnaryOperator::Not,
+                operand: SQLExpression::Subquery(SubqueryExpression::Exists(Box::new(
+                    SelectQuery::builder()
+                        .add_select_item(
+                            SelectItem::builder()
+                                .set_item(SQLExpression::Integer(1))
+                                .build(),
+                        )
+                        .build(),
+                ))),
+            }
+            .into(),
+            want_error: false,
+        },
+        TestCase {
+            name: r#"EXISTS 1"#.into(),
+            input: vec![Token::Exists, Token::Integer(1)],
+            expected: SQLExpression::Null,
+            want_error: true,
+        },
         TestCase {
             name: r#"foo between 1 and 5"#.into(),
             input: vec![
@@ -725,6 +1140,7 @@ fn test_parse_expression() {
                     }
                     .into(),
                     arguments: vec![SQLExpression::Integer(1)],
+                    distinct: false,
                 }
                 .into(),
                 rhs: SQLExpression::Integer(10),
@@ -752,6 +1168,7 @@ fn test_parse_expression() {
                     }
                     .into(),
                     arguments: vec![SQLExpression::Integer(1)],
+                    distinct: false,
                 }
                 .into(),
                 x: SQLExpression::Integer(1),
@@ -1093,10 +1510,93 @@ fn test_parse_function_call_expression() {
                 }
                 .into(),
                 arguments: vec![],
+                distinct: false,
+            }
+            .into(),
+            want_error: false,
+        },
+        TestCase {
+            name: "count(*)".into(),
+            database_name: None,
+            function_name: "count".into(),
+            input: vec![
+                Token::LeftParentheses,
+                Token::Operator(OperatorToken::Asterisk),
+                Token::RightParentheses,
+            ],
+            expected: CallExpression {
+                function: AggregateFunction::Count.into(),
+                arguments: vec![],
+                distinct: false,
+            }
+            .into(),
+            want_error: false,
+        },
+        TestCase {
+            name: "count(distinct user_id)".into(),
+            database_name: None,
+            function_name: "count".into(),
+            input: vec![
+                Token::LeftParentheses,
+                Token::Distinct,
+                Token::Identifier("user_id".to_owned()),
+                Token::RightParentheses,
+            ],
+            expected: CallExpression {
+                function: AggregateFunction::Count.into(),
+                arguments: vec![SelectColumn::new(None, "user_id".into()).into()],
+                distinct: true,
+            }
+            .into(),
+            want_error: false,
+        },
+        TestCase {
+            name: "string_agg(name, ',')".into(),
+            database_name: None,
+            function_name: "string_agg".into(),
+            input: vec![
+                Token::LeftParentheses,
+                Token::Identifier("name".to_owned()),
+                Token::Comma,
+                Token::String(",".to_owned()),
+                Token::RightParentheses,
+            ],
+            expected: CallExpression {
+                function: AggregateFunction::StringAgg.into(),
+                arguments: vec![
+                    SelectColumn::new(None, "name".into()).into(),
+                    SQLExpression::String(",".into()),
+                ],
+                distinct: false,
             }
             .into(),
             want_error: false,
         },
+        TestCase {
+            name: "오류: 집계 함수가 아닌 함수의 DISTINCT".into(),
+            database_name: None,
+            function_name: "foo".into(),
+            input: vec![
+                Token::LeftParentheses,
+                Token::Distinct,
+                Token::Identifier("x".to_owned()),
+                Token::RightParentheses,
+            ],
+            expected: Default::default(),
+            want_error: true,
+        },
+        TestCase {
+            name: "오류: sum(*)".into(),
+            database_name: None,
+            function_name: "sum".into(),
+            input: vec![
+                Token::LeftParentheses,
+                Token::Operator(OperatorToken::Asterisk),
+                Token::RightParentheses,
+            ],
+            expected: Default::default(),
+            want_error: true,
+        },
         TestCase {
             name: "오류: 빈 토큰".into(),
             database_name: None,
@@ -1229,3 +1729,281 @@ fn test_parse_between_expression() {
         }
     }
 }
+
+fn parse_expression_text(text: &str) -> crate::errors::Result<SQLExpression> {
+    let mut parser = Parser::with_string(text.to_owned())?;
+
+    parser.parse_expression(Default::default())
+}
+
+fn column(name: &str) -> SQLExpression {
+    SQLExpression::SelectColumn(SelectColumn::new(None, name.into()))
+}
+
+#[test]
+fn test_parse_case_expression() {
+    struct TestCase {
+        name: String,
+        input: &'static str,
+        expected: SQLExpression,
+    }
+
+    let test_cases = vec![
+        TestCase {
+            name: "검색 CASE".into(),
+            input: "CASE WHEN a > 1 THEN 'big' WHEN a IS NULL THEN NULL ELSE 'small' END",
+            expected: CaseExpression {
+                operand: None,
+                branches: vec![
+                    CaseWhenBranch {
+                        condition: BinaryOperatorExpression {
+                            operator: BinaryOperator::Gt,
+                            lhs: column("a"),
+                            rhs: SQLExpression::Integer(1),
+                        }
+                        .into(),
+                        result: SQLExpression::String("big".into()),
+                    },
+                    CaseWhenBranch {
+                        condition: BinaryOperatorExpression {
+                            operator: BinaryOperator::Is,
+                            lhs: column("a"),
+                            rhs: SQLExpression::Null,
+                        }
+                        .into(),
+                        result: SQLExpression::Null,
+                    },
+                ],
+                else_result: Some(SQLExpression::String("small".into())),
+            }
+            .into(),
+        },
+        TestCase {
+            name: "단순 CASE, ELSE 없음".into(),
+            input: "CASE a + 1 WHEN 1 THEN true END",
+            expected: CaseExpression {
+                operand: Some(
+                    BinaryOperatorExpression {
+                        operator: BinaryOperator::Add,
+                        lhs: column("a"),
+                        rhs: SQLExpression::Integer(1),
+                    }
+                    .into(),
+                ),
+                branches: vec![CaseWhenBranch {
+                    condition: SQLExpression::Integer(1),
+                    result: SQLExpression::Boolean(true),
+                }],
+                else_result: None,
+            }
+            .into(),
+        },
+        TestCase {
+            name: "END 컬럼".into(),
+            input: "CASE WHEN end THEN end ELSE 0 END",
+            expected: CaseExpression {
+                operand: None,
+                branches: vec![CaseWhenBranch {
+                    condition: column("end"),
+                    result: column("end"),
+                }],
+                else_result: Some(SQLExpression::Integer(0)),
+            }
+            .into(),
+        },
+        TestCase {
+            name: "CASE 뒤의 연산".into(),
+            input: "CASE WHEN b THEN 1 ELSE 2 END * 10",
+            expected: BinaryOperatorExpression {
+                operator: BinaryOperator::Mul,
+                lhs: CaseExpression {
+                    operand: None,
+                    branches: vec![CaseWhenBranch {
+                        condition: column("b"),
+                        result: SQLExpression::Integer(1),
+                    }],
+                    else_result: Some(SQLExpression::Integer(2)),
+                }
+                .into(),
+                rhs: SQLExpression::Integer(10),
+            }
+            .into(),
+        },
+    ];
+
+    for t in test_cases {
+        let got = parse_expression_text(t.input);
+
+        assert_eq!(got.ok(), Some(t.expected), "TC: {}", t.name);
+    }
+
+    for input in [
+        "CASE END",
+        "CASE ELSE 1 END",
+        "CASE WHEN a 1 END",
+        "CASE WHEN a THEN 1",
+        "CASE WHEN a THEN 1 ELSE 2",
+    ] {
+        assert!(parse_expression_text(input).is_err(), "{input}");
+    }
+}
+
+#[test]
+fn test_parse_cast_expression() {
+    struct TestCase {
+        name: String,
+        input: &'static str,
+        expected: SQLExpression,
+    }
+
+    let cast = |expression: SQLExpression, data_type: DataType| -> SQLExpression {
+        CastExpression {
+            expression,
+            data_type,
+        }
+        .into()
+    };
+
+    let test_cases = vec![
+        TestCase {
+            name: "CAST AS INTEGER".into(),
+            input: "CAST('1' AS INTEGER)",
+            expected: cast(SQLExpression::String("1".into()), DataType::Int),
+        },
+        TestCase {
+            name: "CAST AS VARCHAR(n)".into(),
+            input: "CAST(a + 1 AS varchar(3))",
+            expected: cast(
+                BinaryOperatorExpression {
+                    operator: BinaryOperator::Add,
+                    lhs: column("a"),
+                    rhs: SQLExpression::Integer(1),
+                }
+                .into(),
+                DataType::Varchar(3),
+            ),
+        },
+        TestCase {
+            name: "::text".into(),
+            input: "a::text",
+            expected: cast(column("a"), DataType::Varchar(i64::MAX)),
+        },
+        TestCase {
+            name: ":: 연속".into(),
+            input: "'1.5'::double precision::bigint",
+            expected: cast(
+                cast(SQLExpression::String("1.5".into()), DataType::Float),
+                DataType::Int,
+            ),
+        },
+        TestCase {
+            name: ":: 가 다른 연산자보다 먼저 묶임".into(),
+            input: "1 + '2'::int",
+            expected: BinaryOperatorExpression {
+                operator: BinaryOperator::Add,
+                lhs: SQLExpression::Integer(1),
+                rhs: cast(SQLExpression::String("2".into()), DataType::Int),
+            }
+            .into(),
+        },
+        TestCase {
+            name: "단항 연산자보다도 먼저 묶임".into(),
+            input: "-'2'::int",
+            expected: UnaryOperatorExpression {
+                operator: UnaryOperator::Neg,
+                operand: cast(SQLExpression::String("2".into()), DataType::Int),
+            }
+            .into(),
+        },
+        TestCase {
+            name: "함수 호출 결과의 ::".into(),
+            input: "count(a)::float",
+            expected: cast(
+                CallExpression {
+                    function: AggregateFunction::Count.into(),
+                    arguments: vec![column("a")],
+                    distinct: false,
+                }
+                .into(),
+                DataType::Float,
+            ),
+        },
+        TestCase {
+            name: "소괄호의 ::".into(),
+            input: "(a)::bool",
+            expected: cast(
+                ParenthesesExpression {
+                    expression: column("a"),
+                }
+                .into(),
+                DataType::Boolean,
+            ),
+        },
+    ];
+
+    for t in test_cases {
+        let got = parse_expression_text(t.input);
+
+        assert_eq!(got.ok(), Some(t.expected), "TC: {}", t.name);
+    }
+
+    for input in [
+        "CAST(1 INTEGER)",
+        "CAST(1 AS)",
+        "CAST(1 AS INTEGER",
+        "CAST 1 AS INTEGER",
+        "1::",
+        "1::unknown_type",
+        "1::double",
+    ] {
+        assert!(parse_expression_text(input).is_err(), "{input}");
+    }
+}
+
+#[test]
+fn test_parse_scalar_function_call() {
+    let call = |function: ScalarFunction, arguments: Vec<SQLExpression>| -> SQLExpression {
+        CallExpression {
+            function: function.into(),
+            arguments,
+            distinct: false,
+        }
+        .into()
+    };
+
+    let test_cases = vec![
+        ("lower(a)", call(ScalarFunction::Lower, vec![column("a")])),
+        (
+            "SUBSTR(a, 2, 3)",
+            call(
+                ScalarFunction::Substring,
+                vec![
+                    column("a"),
+                    SQLExpression::Integer(2),
+                    SQLExpression::Integer(3),
+                ],
+            ),
+        ),
+        (
+            "position('b', a)",
+            call(
+                ScalarFunction::Position,
+                vec![SQLExpression::String("b".into()), column("a")],
+            ),
+        ),
+        (
+            "position('b' IN a)",
+            call(
+                ScalarFunction::Position,
+                vec![SQLExpression::String("b".into()), column("a")],
+            ),
+        ),
+        ("random()", call(ScalarFunction::Random, vec![])),
+    ];
+
+    for (input, expected) in test_cases {
+        let got = parse_expression_text(input);
+
+        assert_eq!(got.ok(), Some(expected), "TC: {}", input);
+    }
+}
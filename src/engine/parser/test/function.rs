@@ -0,0 +1,174 @@
+#![cfg(test)]
+
+use crate::engine::ast::ddl::create_function::{CreateFunctionQuery, FunctionParameter};
+use crate::engine::ast::ddl::drop_function::DropFunctionQuery;
+use crate::engine::ast::types::{DataType, UserDefinedFunction};
+use crate::engine::parser::context::ParserContext;
+use crate::engine::parser::predule::Parser;
+
+fn function(database_name: Option<&str>, function_name: &str) -> UserDefinedFunction {
+    UserDefinedFunction {
+        database_name: database_name.map(ToOwned::to_owned),
+        function_name: function_name.to_owned(),
+    }
+}
+
+#[test]
+pub fn create_function() {
+    struct TestCase {
+        name: String,
+        input: String,
+        expected: CreateFunctionQuery,
+    }
+
+    let test_cases = vec![
+        TestCase {
+            name: "이름 있는 매개변수와 달러 인용 본문".into(),
+            input: "CREATE FUNCTION add_tax(amount integer, rate float) RETURNS float AS $$ SELECT amount * rate $$ LANGUAGE SQL;".into(),
+            expected: CreateFunctionQuery {
+                function: function(Some("rrdb"), "add_tax"),
+                parameters: vec![
+                    FunctionParameter {
+                        name: Some("amount".into()),
+                        data_type: DataType::Int,
+                    },
+                    FunctionParameter {
+                        name: Some("rate".into()),
+                        data_type: DataType::Float,
+                    },
+                ],
+                return_type: DataType::Float,
+                body: " SELECT amount * rate ".into(),
+                or_replace: false,
+            },
+        },
+        TestCase {
+            name: "OR REPLACE, 이름 없는 매개변수, LANGUAGE 먼저".into(),
+            input: "create or replace function other.greet(varchar(10), double precision) returns text language sql as 'select $1';".into(),
+            expected: CreateFunctionQuery {
+                function: function(Some("other"), "greet"),
+                parameters: vec![
+                    FunctionParameter {
+                        name: None,
+                        data_type: DataType::Varchar(10),
+                    },
+                    FunctionParameter {
+                        name: None,
+                        data_type: DataType::Float,
+                    },
+                ],
+                return_type: DataType::Varchar(i64::MAX),
+                body: "select $1".into(),
+                or_replace: true,
+            },
+        },
+        TestCase {
+            name: "매개변수 없음".into(),
+            input: "create function one() returns int as $body$ select 1 $body$".into(),
+            expected: CreateFunctionQuery {
+                function: function(Some("rrdb"), "one"),
+                parameters: vec![],
+                return_type: DataType::Int,
+                body: " select 1 ".into(),
+                or_replace: false,
+            },
+        },
+        TestCase {
+            name: "키워드 이름의 함수와 매개변수".into(),
+            input: "create function function(returns int) returns int as $$ select returns $$".into(),
+            expected: CreateFunctionQuery {
+                function: function(Some("rrdb"), "function"),
+                parameters: vec![FunctionParameter {
+                    name: Some("returns".into()),
+                    data_type: DataType::Int,
+                }],
+                return_type: DataType::Int,
+                body: " select returns ".into(),
+                or_replace: false,
+            },
+        },
+    ];
+
+    for t in test_cases {
+        let mut parser = Parser::with_string(t.input).unwrap();
+        let got = parser
+            .parse(ParserContext::default().set_default_database("rrdb".into()))
+            .unwrap();
+
+        assert_eq!(got, vec![t.expected.build()], "TC: {}", t.name);
+    }
+}
+
+#[test]
+pub fn create_function_errors() {
+    let test_cases = vec![
+        (
+            "create function f(a int, a int) returns int as $$ select a $$;",
+            "parameter name \"a\" used more than once",
+        ),
+        ("create function f(a int) as $$ select a $$;", "RETURNS"),
+        ("create function f(a int) returns int;", "expected 'AS'"),
+        (
+            "create function f(a int) returns int as $$ select a $$ language plpgsql;",
+            "only LANGUAGE SQL is supported",
+        ),
+        (
+            "create function f(a int) returns int as $$ delete from t where id = a $$;",
+            "function body must be a single SELECT statement",
+        ),
+        (
+            "create function f(a int) returns int as $$ select a; select a $$;",
+            "function body must be a single SELECT statement",
+        ),
+        (
+            "create function f(a int) returns int as $$ select a, a $$;",
+            "function body must return exactly one column",
+        ),
+        (
+            "create or function f() returns int as $$ select 1 $$;",
+            "REPLACE",
+        ),
+    ];
+
+    for (input, expected) in test_cases {
+        let mut parser = Parser::with_string(input.to_owned()).unwrap();
+        let error = parser
+            .parse(ParserContext::default())
+            .expect_err(input)
+            .to_string();
+
+        assert!(error.contains(expected), "{input}: {error}");
+    }
+}
+
+#[test]
+pub fn drop_function() {
+    let test_cases = vec![
+        (
+            "drop function add_tax;",
+            DropFunctionQuery::builder().set_function(function(Some("rrdb"), "add_tax")),
+        ),
+        (
+            "drop function if exists other.add_tax(integer, varchar(10));",
+            DropFunctionQuery::builder()
+                .set_function(function(Some("other"), "add_tax"))
+                .set_parameter_types(vec![DataType::Int, DataType::Varchar(10)])
+                .set_if_exists(true),
+        ),
+        (
+            "drop function one()",
+            DropFunctionQuery::builder()
+                .set_function(function(Some("rrdb"), "one"))
+                .set_parameter_types(vec![]),
+        ),
+    ];
+
+    for (input, expected) in test_cases {
+        let mut parser = Parser::with_string(input.to_owned()).unwrap();
+        let got = parser
+            .parse(ParserContext::default().set_default_database("rrdb".into()))
+            .unwrap();
+
+        assert_eq!(got, vec![expected.build()], "{input}");
+    }
+}
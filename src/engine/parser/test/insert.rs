@@ -433,7 +433,7 @@ fn test_insert_query() {
         );
 
         if let Ok(statements) = got {
-            assert_eq!(statements, t.expected.into(), "TC: {}", t.name);
+            assert_eq!(statements, t.expected, "TC: {}", t.name);
         }
     }
 }
@@ -661,3 +661,100 @@ fn test_parse_insert_values() {
         }
     }
 }
+
+#[test]
+fn test_insert_on_conflict() {
+    use crate::engine::ast::dml::expressions::binary::BinaryOperatorExpression;
+    use crate::engine::ast::dml::expressions::operators::BinaryOperator;
+    use crate::engine::ast::dml::parts::_where::WhereClause;
+    use crate::engine::ast::dml::parts::on_conflict::{OnConflictAction, OnConflictClause};
+    use crate::engine::ast::dml::parts::update_item::UpdateItem;
+
+    let base = || {
+        InsertQuery::builder()
+            .set_into_table(TableName::new(Some("rrdb".into()), "counters".into()))
+            .set_columns(vec!["id".into(), "hits".into()])
+            .set_values(vec![InsertValue {
+                list: vec![
+                    Some(SQLExpression::Integer(1)),
+                    Some(SQLExpression::Integer(1)),
+                ],
+            }])
+    };
+
+    let test_cases = vec![
+        (
+            "insert into counters (id, hits) values (1, 1) on conflict do nothing;",
+            base()
+                .set_on_conflict(OnConflictClause {
+                    target_columns: vec![],
+                    action: OnConflictAction::DoNothing,
+                })
+                .build(),
+        ),
+        (
+            "INSERT INTO counters (id, hits) VALUES (1, 1) ON CONFLICT (id) DO UPDATE SET hits = hits + excluded.hits WHERE excluded.hits > 0",
+            base()
+                .set_on_conflict(OnConflictClause {
+                    target_columns: vec!["id".into()],
+                    action: OnConflictAction::DoUpdate {
+                        update_items: vec![UpdateItem {
+                            column: "hits".into(),
+                            value: BinaryOperatorExpression {
+                                operator: BinaryOperator::Add,
+                                lhs: SelectColumn::new(None, "hits".into()).into(),
+                                rhs: SelectColumn::new(Some("excluded".into()), "hits".into())
+                                    .into(),
+                            }
+                            .into(),
+                        }],
+                        where_clause: Some(WhereClause {
+                            expression: BinaryOperatorExpression {
+                                operator: BinaryOperator::Gt,
+                                lhs: SelectColumn::new(Some("excluded".into()), "hits".into())
+                                    .into(),
+                                rhs: SQLExpression::Integer(0),
+                            }
+                            .into(),
+                        }),
+                    },
+                })
+                .build(),
+        ),
+    ];
+
+    for (input, expected) in test_cases {
+        let mut parser = Parser::with_string(input.to_string()).unwrap();
+
+        let got = parser
+            .parse(ParserContext::default().set_default_database("rrdb".into()))
+            .unwrap();
+
+        assert_eq!(got, vec![expected.into()], "{input}");
+    }
+
+    for (input, expected) in [
+        (
+            "insert into counters (id, hits) values (1, 1) on conflict do update set hits = 1;",
+            "requires a conflict target column",
+        ),
+        (
+            "insert into counters (id, hits) values (1, 1) on conflict (id) do update set;",
+            "requires at least one SET item",
+        ),
+        (
+            "insert into counters (id, hits) values (1, 1) on conflict (id) do something;",
+            "expected 'NOTHING' or 'UPDATE'",
+        ),
+        (
+            "insert into counters (id, hits) values (1, 1) on duplicate do nothing;",
+            "expected 'CONFLICT'",
+        ),
+    ] {
+        let mut parser = Parser::with_string(input.to_string()).unwrap();
+
+        let error = parser.parse(ParserContext::default()).unwrap_err();
+
+        assert!(error.to_string().contains(expected), "{input}: {error}");
+    }
+}
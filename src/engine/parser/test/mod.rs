@@ -6,10 +6,13 @@ pub(crate) mod alter_table;
 pub(crate) mod create_table;
 pub(crate) mod drop_table;
 pub(crate) mod expressions;
+pub(crate) mod function;
 pub(crate) mod index;
+pub(crate) mod truncate_table;
 
 pub(crate) mod delete;
 pub(crate) mod insert;
+pub(crate) mod returning;
 pub(crate) mod select;
 pub(crate) mod update;
 
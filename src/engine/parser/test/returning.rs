@@ -0,0 +1,178 @@
+#![cfg(test)]
+
+use crate::engine::ast::dml::delete::DeleteQuery;
+use crate::engine::ast::dml::expressions::binary::BinaryOperatorExpression;
+use crate::engine::ast::dml::expressions::operators::BinaryOperator;
+use crate::engine::ast::dml::insert::InsertQuery;
+use crate::engine::ast::dml::parts::_where::WhereClause;
+use crate::engine::ast::dml::parts::insert_values::InsertValue;
+use crate::engine::ast::dml::parts::on_conflict::{OnConflictAction, OnConflictClause};
+use crate::engine::ast::dml::parts::returning::ReturningClause;
+use crate::engine::ast::dml::parts::select_item::{SelectItem, SelectKind, SelectWildCard};
+use crate::engine::ast::dml::parts::update_item::UpdateItem;
+use crate::engine::ast::dml::select::SelectQuery;
+use crate::engine::ast::dml::update::UpdateQuery;
+use crate::engine::ast::types::{SQLExpression, SelectColumn, TableName};
+use crate::engine::parser::predule::{Parser, ParserContext};
+
+fn returning_item(item: SQLExpression, alias: Option<&str>) -> SelectKind {
+    let mut select_item = SelectItem::builder().set_item(item);
+
+    if let Some(alias) = alias {
+        select_item = select_item.set_alias(alias.into());
+    }
+
+    SelectKind::SelectItem(select_item.build())
+}
+
+#[test]
+fn test_returning_clause() {
+    let table_name = || TableName::new(Some("rrdb".into()), "foo".into());
+    let id_filter = || WhereClause {
+        expression: BinaryOperatorExpression {
+            operator: BinaryOperator::Eq,
+            lhs: SelectColumn::new(None, "id".into()).into(),
+            rhs: SQLExpression::Integer(1),
+        }
+        .into(),
+    };
+
+    let test_cases = vec![
+        (
+            "insert into foo (id, name) values (1, 'a') on conflict do nothing returning *;",
+            InsertQuery::builder()
+                .set_into_table(table_name())
+                .set_columns(vec!["id".into(), "name".into()])
+                .set_values(vec![InsertValue {
+                    list: vec![
+                        Some(SQLExpression::Integer(1)),
+                        Some(SQLExpression::String("a".into())),
+                    ],
+                }])
+                .set_on_conflict(OnConflictClause {
+                    target_columns: vec![],
+                    action: OnConflictAction::DoNothing,
+                })
+                .set_returning(ReturningClause {
+                    select_items: vec![SelectKind::WildCard(SelectWildCard { alias: None })],
+                })
+                .build()
+                .into(),
+        ),
+        (
+            "INSERT INTO foo (id) SELECT 1 RETURNING id, id + 1 AS next",
+            InsertQuery::builder()
+                .set_into_table(table_name())
+                .set_columns(vec!["id".into()])
+                .set_select(
+                    SelectQuery::builder()
+                        .add_select_item(
+                            SelectItem::builder()
+                                .set_item(SQLExpression::Integer(1))
+                                .build(),
+                        )
+                        .build(),
+                )
+                .set_returning(ReturningClause {
+                    select_items: vec![
+                        returning_item(SelectColumn::new(None, "id".into()).into(), None),
+                        returning_item(
+                            BinaryOperatorExpression {
+                                operator: BinaryOperator::Add,
+                                lhs: SelectColumn::new(None, "id".into()).into(),
+                                rhs: SQLExpression::Integer(1),
+                            }
+                            .into(),
+                            Some("next"),
+                        ),
+                    ],
+                })
+                .build()
+                .into(),
+        ),
+        (
+            "update foo set name = 'b' returning name",
+            UpdateQuery::builder()
+                .set_target_table(table_name())
+                .add_update_item(UpdateItem {
+                    column: "name".into(),
+                    value: SQLExpression::String("b".into()),
+                })
+                .set_returning(ReturningClause {
+                    select_items: vec![returning_item(
+                        SelectColumn::new(None, "name".into()).into(),
+                        None,
+                    )],
+                })
+                .build()
+                .into(),
+        ),
+        (
+            "update foo as f set name = 'b' where id = 1 returning f.id, *;",
+            UpdateQuery::builder()
+                .set_target_table(table_name())
+                .set_target_alias("f".into())
+                .add_update_item(UpdateItem {
+                    column: "name".into(),
+                    value: SQLExpression::String("b".into()),
+                })
+                .set_where(id_filter())
+                .set_returning(ReturningClause {
+                    select_items: vec![
+                        returning_item(
+                            SelectColumn::new(Some("f".into()), "id".into()).into(),
+                            None,
+                        ),
+                        SelectKind::WildCard(SelectWildCard { alias: None }),
+                    ],
+                })
+                .build()
+                .into(),
+        ),
+        (
+            "delete from foo where id = 1 returning name as deleted_name;",
+            DeleteQuery::builder()
+                .set_from_table(table_name())
+                .set_where(id_filter())
+                .set_returning(ReturningClause {
+                    select_items: vec![returning_item(
+                        SelectColumn::new(None, "name".into()).into(),
+                        Some("deleted_name"),
+                    )],
+                })
+                .build()
+                .into(),
+        ),
+    ];
+
+    for (input, expected) in test_cases {
+        let mut parser = Parser::with_string(input.to_string()).unwrap();
+
+        let got = parser
+            .parse(ParserContext::default().set_default_database("rrdb".into()))
+            .unwrap();
+
+        assert_eq!(got, vec![expected], "{input}");
+    }
+
+    for (input, expected) in [
+        (
+            "delete from foo returning;",
+            "RETURNING requires at least one expression",
+        ),
+        (
+            "update foo set name = 'b' returning count(*);",
+            "aggregate functions are not allowed in RETURNING",
+        ),
+        (
+            "delete from foo returning row_number() over ();",
+            "window functions are not allowed in RETURNING",
+        ),
+    ] {
+        let mut parser = Parser::with_string(input.to_string()).unwrap();
+
+        let error = parser.parse(ParserContext::default()).unwrap_err();
+
+        assert!(error.to_string().contains(expected), "{input}: {error}");
+    }
+}
@@ -3,19 +3,31 @@
 use crate::engine::ast::dml::expressions::binary::BinaryOperatorExpression;
 use crate::engine::ast::dml::expressions::call::CallExpression;
 use crate::engine::ast::dml::expressions::operators::BinaryOperator;
+use crate::engine::ast::dml::expressions::window::{
+    WindowExpression, WindowFrame, WindowFrameBound, WindowFrameUnit,
+};
 use crate::engine::ast::dml::parts::_where::WhereClause;
+use crate::engine::ast::dml::parts::distinct::DistinctClause;
+use crate::engine::ast::dml::parts::from::FromTarget;
 use crate::engine::ast::dml::parts::group_by::GroupByItem;
 use crate::engine::ast::dml::parts::having::HavingClause;
 use crate::engine::ast::dml::parts::join::{JoinClause, JoinType};
-use crate::engine::ast::dml::parts::order_by::{OrderByItem, OrderByNulls, OrderByType};
-use crate::engine::ast::dml::parts::select_item::{SelectItem, SelectWildCard};
+use crate::engine::ast::dml::parts::locking::{LockWaitPolicy, LockingClause, RowLockMode};
+use crate::engine::ast::dml::parts::order_by::{
+    OrderByClause, OrderByItem, OrderByNulls, OrderByType,
+};
+use crate::engine::ast::dml::parts::select_item::{SelectItem, SelectKind, SelectWildCard};
+use crate::engine::ast::dml::parts::set_operation::SetOperator;
+use crate::engine::ast::dml::parts::with::CommonTableExpression;
 use crate::engine::ast::dml::select::SelectQuery;
 use crate::engine::ast::types::{
     AggregateFunction, BuiltInFunction, Function, SQLExpression, SelectColumn, TableName,
+    WindowFunction,
 };
+use crate::engine::ast::{DMLStatement, SQLStatement};
 use crate::engine::lexer::predule::OperatorToken;
 use crate::engine::lexer::tokens::Token;
-use crate::engine::parser::predule::Parser;
+use crate::engine::parser::predule::{Parser, ParserContext};
 
 #[test]
 fn test_select_query() {
@@ -1365,6 +1377,7 @@ fn test_select_query() {
                                 AggregateFunction::Count,
                             )),
                             arguments: vec![SelectColumn::new(Some("p".into()), "a".into()).into()],
+                            distinct: false,
                         }))
                         .build(),
                 )
@@ -1796,7 +1809,7 @@ fn test_select_query() {
         );
 
         if let Ok(statements) = got {
-            assert_eq!(statements, t.expected.into(), "TC: {}", t.name);
+            assert_eq!(statements, t.expected, "TC: {}", t.name);
         }
     }
 }
@@ -2558,3 +2571,636 @@ fn test_parse_group_by_all_with_only_aggregates() {
     assert!(group_by.group_by_items.is_empty());
     assert!(!group_by.group_by_all);
 }
+
+#[test]
+fn test_parse_select_distinct() {
+    let tokens = vec![
+        Token::Select,
+        Token::Distinct,
+        Token::Identifier("a".into()),
+        Token::Comma,
+        Token::Identifier("b".into()),
+        Token::From,
+        Token::Identifier("t".into()),
+    ];
+
+    let mut parser = Parser::new(tokens);
+    let select = parser.handle_select_query(Default::default()).unwrap();
+
+    assert_eq!(select.distinct, Some(DistinctClause { on: None }));
+    assert_eq!(select.select_items.len(), 2);
+}
+
+#[test]
+fn test_parse_select_distinct_on() {
+    let tokens = vec![
+        Token::Select,
+        Token::Distinct,
+        Token::On,
+        Token::LeftParentheses,
+        Token::Identifier("a".into()),
+        Token::Comma,
+        Token::Identifier("b".into()),
+        Token::RightParentheses,
+        Token::Identifier("c".into()),
+        Token::From,
+        Token::Identifier("t".into()),
+        Token::Order,
+        Token::By,
+        Token::Identifier("b".into()),
+        Token::Comma,
+        Token::Identifier("a".into()),
+        Token::Comma,
+        Token::Identifier("c".into()),
+        Token::Desc,
+    ];
+
+    let mut parser = Parser::new(tokens);
+    let select = parser.handle_select_query(Default::default()).unwrap();
+
+    assert_eq!(
+        select.distinct,
+        Some(DistinctClause {
+            on: Some(vec![
+                SelectColumn::new(None, "a".into()).into(),
+                SelectColumn::new(None, "b".into()).into(),
+            ]),
+        })
+    );
+    assert_eq!(select.select_items.len(), 1);
+}
+
+#[test]
+fn test_parse_select_distinct_on_must_match_order_by() {
+    let tokens = vec![
+        Token::Select,
+        Token::Distinct,
+        Token::On,
+        Token::LeftParentheses,
+        Token::Identifier("a".into()),
+        Token::RightParentheses,
+        Token::Identifier("b".into()),
+        Token::From,
+        Token::Identifier("t".into()),
+        Token::Order,
+        Token::By,
+        Token::Identifier("b".into()),
+        Token::Comma,
+        Token::Identifier("a".into()),
+        Token::SemiColon,
+    ];
+
+    let mut parser = Parser::new(tokens);
+    let got = parser.handle_select_query(Default::default());
+
+    assert_eq!(
+        got.err().unwrap().to_string(),
+        "parsing error: SELECT DISTINCT ON expressions must match initial ORDER BY expressions"
+    );
+}
+
+#[test]
+fn test_parse_select_distinct_on_requires_expression() {
+    let tokens = vec![
+        Token::Select,
+        Token::Distinct,
+        Token::On,
+        Token::LeftParentheses,
+        Token::RightParentheses,
+        Token::Identifier("a".into()),
+        Token::From,
+        Token::Identifier("t".into()),
+    ];
+
+    let mut parser = Parser::new(tokens);
+    let got = parser.handle_select_query(Default::default());
+
+    assert!(got.is_err());
+}
+
+fn parse_select(text: &str) -> crate::errors::Result<SelectQuery> {
+    let mut parser = Parser::with_string(text.to_owned()).unwrap();
+
+    match parser.parse(ParserContext::default())?.remove(0) {
+        SQLStatement::DML(DMLStatement::SelectQuery(query)) => Ok(query),
+        other => panic!("expected select query, got {:?}", other),
+    }
+}
+
+fn select_block(column: &str, table: &str) -> SelectQuery {
+    SelectQuery::builder()
+        .add_select_item(
+            SelectItem::builder()
+                .set_item(SelectColumn::new(None, column.into()).into())
+                .build(),
+        )
+        .set_from_table(TableName::new(None, table.into()))
+        .build()
+}
+
+#[test]
+fn test_parse_union_applies_order_by_and_limit_to_the_whole_result() {
+    let query =
+        parse_select("SELECT a FROM t UNION ALL SELECT b FROM u ORDER BY a DESC LIMIT 3;").unwrap();
+
+    let mut expected = SelectQuery::set_operation(
+        SetOperator::Union,
+        true,
+        select_block("a", "t"),
+        select_block("b", "u"),
+    );
+    expected.order_by_clause = Some(OrderByClause {
+        order_by_items: vec![OrderByItem {
+            item: SelectColumn::new(None, "a".into()).into(),
+            order_type: OrderByType::Desc,
+            nulls: OrderByNulls::First,
+        }],
+    });
+    expected.limit = Some(3);
+
+    assert_eq!(query, expected);
+}
+
+#[test]
+fn test_parse_intersect_binds_tighter_than_union_and_except() {
+    let query = parse_select(
+        "SELECT a FROM t UNION SELECT a FROM u INTERSECT ALL SELECT a FROM v EXCEPT DISTINCT SELECT a FROM w;",
+    )
+    .unwrap();
+
+    let expected = SelectQuery::set_operation(
+        SetOperator::Except,
+        false,
+        SelectQuery::set_operation(
+            SetOperator::Union,
+            false,
+            select_block("a", "t"),
+            SelectQuery::set_operation(
+                SetOperator::Intersect,
+                true,
+                select_block("a", "u"),
+                select_block("a", "v"),
+            ),
+        ),
+        select_block("a", "w"),
+    );
+
+    assert_eq!(query, expected);
+}
+
+#[test]
+fn test_parse_parenthesized_set_operands() {
+    let query =
+        parse_select("SELECT * FROM ((SELECT a FROM t LIMIT 1) UNION (SELECT a FROM u)) x;")
+            .unwrap();
+
+    let Some(FromTarget::Subquery(subquery)) = query.from_table.map(|from| from.from) else {
+        panic!("expected derived table");
+    };
+
+    let mut left = select_block("a", "t");
+    left.limit = Some(1);
+
+    assert_eq!(
+        subquery.into_query(),
+        SelectQuery::set_operation(SetOperator::Union, false, left, select_block("a", "u"))
+    );
+}
+
+#[test]
+fn test_parse_order_by_before_set_operator_fails() {
+    let got = parse_select("SELECT a FROM t ORDER BY a UNION SELECT a FROM u;");
+
+    assert_eq!(
+        got.err().unwrap().to_string(),
+        "parsing error: ORDER BY, LIMIT and OFFSET must follow the last query of UNION. wrap the query in parentheses"
+    );
+}
+
+#[test]
+fn test_parse_with_binds_common_table_names() {
+    let query =
+        parse_select("WITH t(x) AS (SELECT a FROM users) SELECT x FROM t JOIN users ON true;")
+            .unwrap();
+
+    let with_clause = query.with_clause.clone().unwrap();
+    assert!(!with_clause.recursive);
+    assert_eq!(with_clause.common_tables.len(), 1);
+
+    let CommonTableExpression {
+        name,
+        binding,
+        columns,
+        query: common_table_query,
+    } = with_clause.common_tables[0].clone();
+    assert_eq!(name, "t");
+    assert!(binding.is_common_table());
+    assert_eq!(columns, vec!["x".to_string()]);
+    assert_eq!(*common_table_query, select_block("a", "users"));
+
+    // FROM 절의 t는 WITH 절 테이블로 바뀌고, 별칭이 없으면 원래 이름을 씁니다.
+    let from = query.from_table.unwrap();
+    assert_eq!(from.from, FromTarget::Table(binding));
+    assert_eq!(from.alias, Some("t".into()));
+
+    // 같은 이름이 아닌 테이블은 그대로입니다.
+    assert_eq!(
+        query.join_clause[0].right,
+        TableName::new(None, "users".into())
+    );
+}
+
+#[test]
+fn test_parse_with_recursive_binds_its_own_name() {
+    let query = parse_select(
+        "WITH RECURSIVE t(n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM t WHERE n < 3) SELECT n FROM t;",
+    )
+    .unwrap();
+
+    let with_clause = query.with_clause.unwrap();
+    assert!(with_clause.recursive);
+
+    let common_table = &with_clause.common_tables[0];
+    assert!(common_table.query.references_table(&common_table.binding));
+
+    // RECURSIVE가 없으면 쿼리 본문의 t는 실제 테이블을 가리킵니다.
+    let query = parse_select("WITH t AS (SELECT a FROM t) SELECT a FROM t;").unwrap();
+    let common_table = &query.with_clause.as_ref().unwrap().common_tables[0];
+    assert!(!common_table.query.references_table(&common_table.binding));
+    assert!(
+        common_table
+            .query
+            .references_table(&TableName::new(None, "t".into()))
+    );
+    assert!(query.references_table(&common_table.binding));
+
+    // RECURSIVE는 WITH 바로 뒤가 아니면 식별자로 읽습니다.
+    let query = parse_select(
+        "WITH RECURSIVE t(recursive) AS (SELECT 1 UNION ALL SELECT recursive + 1 FROM t WHERE recursive < 3) SELECT recursive FROM t;",
+    )
+    .unwrap();
+    let with_clause = query.with_clause.unwrap();
+    assert!(with_clause.recursive);
+    assert_eq!(
+        with_clause.common_tables[0].columns,
+        vec!["recursive".to_string()]
+    );
+}
+
+#[test]
+fn test_parse_nested_with_shadows_outer_common_table() {
+    let query = parse_select(
+        "WITH t AS (SELECT a FROM u) SELECT a FROM (WITH t AS (SELECT a FROM t) SELECT a FROM t) x;",
+    )
+    .unwrap();
+
+    let outer = query.with_clause.as_ref().unwrap().common_tables[0].clone();
+
+    let Some(FromTarget::Subquery(subquery)) = query.from_table.map(|from| from.from) else {
+        panic!("expected derived table");
+    };
+    let subquery = subquery.into_query();
+    let inner = subquery.with_clause.as_ref().unwrap().common_tables[0].clone();
+
+    assert_ne!(outer.binding, inner.binding);
+    // 안쪽 WITH 쿼리는 바깥 t를, 안쪽 본문은 안쪽 t를 읽습니다.
+    assert!(inner.query.references_table(&outer.binding));
+    assert_eq!(
+        subquery.from_table.unwrap().from,
+        FromTarget::Table(inner.binding)
+    );
+}
+
+#[test]
+fn test_parse_with_before_dml_statements() {
+    let mut parser = Parser::with_string(
+        "WITH t AS (SELECT a FROM u) DELETE FROM v WHERE a IN (SELECT a FROM t);".to_owned(),
+    )
+    .unwrap();
+
+    let SQLStatement::DML(DMLStatement::DeleteQuery(query)) =
+        parser.parse(ParserContext::default()).unwrap().remove(0)
+    else {
+        panic!("expected delete query");
+    };
+
+    assert_eq!(query.with_clause.unwrap().common_tables[0].name, "t");
+}
+
+#[test]
+fn test_parse_with_duplicate_name_fails() {
+    let got = parse_select("WITH t AS (SELECT 1), t AS (SELECT 2) SELECT * FROM t;");
+
+    assert_eq!(
+        got.err().unwrap().to_string(),
+        "parsing error: WITH query name 't' specified more than once"
+    );
+}
+
+fn first_select_expression(query: &SelectQuery) -> SQLExpression {
+    match &query.select_items[0] {
+        crate::engine::ast::dml::parts::select_item::SelectKind::SelectItem(item) => {
+            item.item.clone().unwrap()
+        }
+        other => panic!("expected select item, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_parse_window_function_with_partition_order_and_frame() {
+    let query = parse_select(
+        "SELECT rank() OVER (PARTITION BY a, c ORDER BY b DESC ROWS BETWEEN 1 PRECEDING AND UNBOUNDED FOLLOWING) FROM t;",
+    )
+    .unwrap();
+
+    assert_eq!(
+        first_select_expression(&query),
+        WindowExpression {
+            call: CallExpression {
+                function: WindowFunction::Rank.into(),
+                arguments: vec![],
+                distinct: false,
+            },
+            partition_by: vec![
+                SelectColumn::new(None, "a".into()).into(),
+                SelectColumn::new(None, "c".into()).into(),
+            ],
+            order_by: vec![OrderByItem {
+                item: SelectColumn::new(None, "b".into()).into(),
+                order_type: OrderByType::Desc,
+                nulls: OrderByNulls::First,
+            }],
+            frame: Some(WindowFrame {
+                unit: WindowFrameUnit::Rows,
+                start: WindowFrameBound::Preceding(1),
+                end: WindowFrameBound::UnboundedFollowing,
+            }),
+        }
+        .into()
+    );
+}
+
+#[test]
+fn test_parse_aggregate_over_window_does_not_group() {
+    let query = parse_select("SELECT a, sum(b) OVER (RANGE CURRENT ROW) - 1 FROM t;").unwrap();
+
+    assert!(!query.has_aggregate);
+
+    let SQLExpression::Binary(binary) = (match &query.select_items[1] {
+        crate::engine::ast::dml::parts::select_item::SelectKind::SelectItem(item) => {
+            item.item.clone().unwrap()
+        }
+        other => panic!("expected select item, got {:?}", other),
+    }) else {
+        panic!("expected binary expression");
+    };
+
+    assert_eq!(
+        binary.lhs,
+        WindowExpression {
+            call: CallExpression {
+                function: AggregateFunction::Sum.into(),
+                arguments: vec![SelectColumn::new(None, "b".into()).into()],
+                distinct: false,
+            },
+            partition_by: vec![],
+            order_by: vec![],
+            frame: Some(WindowFrame {
+                unit: WindowFrameUnit::Range,
+                start: WindowFrameBound::CurrentRow,
+                end: WindowFrameBound::CurrentRow,
+            }),
+        }
+        .into()
+    );
+
+    // 윈도우 인자 안의 집계는 GROUP BY 검증 대상입니다.
+    let query =
+        parse_select("SELECT a, sum(count(*)) OVER () FROM t GROUP BY a ORDER BY a;").unwrap();
+    assert!(query.has_aggregate);
+}
+
+#[test]
+fn test_parse_invalid_window_functions_fail() {
+    let cases = vec![
+        (
+            "SELECT row_number() FROM t;",
+            "window function row_number() requires an OVER clause",
+        ),
+        (
+            "SELECT a FROM t WHERE rank() OVER (ORDER BY a) = 1;",
+            "window functions are not allowed in WHERE",
+        ),
+        (
+            "SELECT sum(rank() OVER ()) OVER () FROM t;",
+            "window function calls cannot be nested",
+        ),
+        (
+            "SELECT sum(rank() OVER ()) FROM t;",
+            "aggregate function calls cannot contain window function calls",
+        ),
+        (
+            "SELECT count(DISTINCT a) OVER () FROM t;",
+            "DISTINCT is not implemented for window functions",
+        ),
+        (
+            "SELECT sum(a) OVER (ROWS BETWEEN UNBOUNDED FOLLOWING AND CURRENT ROW) FROM t;",
+            "frame start cannot be UNBOUNDED FOLLOWING",
+        ),
+        (
+            "SELECT sum(a) OVER (RANGE 1 PRECEDING) FROM t;",
+            "RANGE with offset PRECEDING/FOLLOWING requires exactly one ORDER BY column",
+        ),
+        (
+            "SELECT a, sum(count(*)) OVER (ORDER BY a) FROM t GROUP BY b ORDER BY b;",
+            "column 'SelectColumn { table_name: None, column_name: \"a\" }' must be in a GROUP BY clause or used within an aggregate function",
+        ),
+    ];
+
+    for (sql, message) in cases {
+        let got = parse_select(sql);
+
+        assert_eq!(
+            got.err().map(|error| error.to_string()),
+            Some(format!("parsing error: {message}")),
+            "{sql}"
+        );
+    }
+}
+
+#[test]
+fn test_parse_locking_clauses() {
+    let cases = [
+        (
+            "SELECT a FROM t FOR UPDATE;",
+            RowLockMode::Update,
+            LockWaitPolicy::Wait,
+        ),
+        (
+            "SELECT a FROM t FOR NO KEY UPDATE NOWAIT;",
+            RowLockMode::NoKeyUpdate,
+            LockWaitPolicy::NoWait,
+        ),
+        (
+            "select a from t for share skip locked",
+            RowLockMode::Share,
+            LockWaitPolicy::SkipLocked,
+        ),
+    ];
+
+    for (sql, mode, wait_policy) in cases {
+        let query = parse_select(sql).unwrap();
+
+        assert_eq!(
+            query,
+            select_block("a", "t").set_locking(LockingClause { mode, wait_policy }),
+            "{sql}"
+        );
+    }
+}
+
+#[test]
+fn test_parse_locking_clause_after_where_order_by_and_limit() {
+    let query = parse_select(
+        "SELECT a FROM t WHERE a = 1 GROUP BY a ORDER BY a LIMIT 1 OFFSET 2 FOR UPDATE SKIP LOCKED;",
+    )
+    .unwrap();
+
+    assert_eq!(
+        query.locking_clause,
+        Some(LockingClause {
+            mode: RowLockMode::Update,
+            wait_policy: LockWaitPolicy::SkipLocked,
+        })
+    );
+    assert!(query.where_clause.is_some());
+    assert!(query.group_by_clause.is_some());
+    assert!(query.order_by_clause.is_some());
+    assert_eq!(query.limit, Some(1));
+    assert_eq!(query.offset, Some(2));
+}
+
+#[test]
+fn test_parse_invalid_locking_clauses_fail() {
+    let cases = [
+        (
+            "SELECT a FROM t FOR DELETE;",
+            "expected 'UPDATE', 'NO KEY UPDATE' or 'SHARE' after 'FOR'. but your input word is 'Delete'",
+        ),
+        (
+            "SELECT a FROM t FOR NO UPDATE;",
+            "expected 'KEY' after 'FOR NO'",
+        ),
+        (
+            "SELECT a FROM t FOR UPDATE SKIP;",
+            "expected 'LOCKED'. but your input word is 'SemiColon'",
+        ),
+        (
+            "SELECT a FROM t FOR UPDATE UNION SELECT a FROM u;",
+            "FOR UPDATE is not allowed with UNION/INTERSECT/EXCEPT",
+        ),
+        (
+            "SELECT a FROM t UNION SELECT a FROM u FOR UPDATE;",
+            "FOR UPDATE is not allowed with UNION/INTERSECT/EXCEPT",
+        ),
+    ];
+
+    for (sql, message) in cases {
+        let got = parse_select(sql);
+
+        assert_eq!(
+            got.err().map(|error| error.to_string()),
+            Some(format!("parsing error: {message}")),
+            "{sql}"
+        );
+    }
+}
+
+// PostgreSQL의 non-reserved keyword는 식별자 자리에서 식별자로 읽습니다.
+#[test]
+fn test_parse_unreserved_keywords_as_identifiers() {
+    let query = parse_select(
+        "SELECT range, t.rows, partition AS row, end FROM t WHERE current = unbounded ORDER BY preceding, following;",
+    )
+    .unwrap();
+
+    assert_eq!(
+        query.select_items,
+        vec![
+            SelectKind::SelectItem(
+                SelectItem::builder()
+                    .set_item(SelectColumn::new(None, "range".into()).into())
+                    .build()
+            ),
+            SelectKind::SelectItem(
+                SelectItem::builder()
+                    .set_item(SelectColumn::new(Some("t".into()), "rows".into()).into())
+                    .build()
+            ),
+            SelectKind::SelectItem(
+                SelectItem::builder()
+                    .set_item(SelectColumn::new(None, "partition".into()).into())
+                    .set_alias("row".into())
+                    .build()
+            ),
+            SelectKind::SelectItem(
+                SelectItem::builder()
+                    .set_item(SelectColumn::new(None, "end".into()).into())
+                    .build()
+            ),
+        ]
+    );
+    assert_eq!(
+        query.where_clause,
+        Some(WhereClause {
+            expression: BinaryOperatorExpression {
+                operator: BinaryOperator::Eq,
+                lhs: SelectColumn::new(None, "current".into()).into(),
+                rhs: SelectColumn::new(None, "unbounded".into()).into(),
+            }
+            .into(),
+        })
+    );
+    assert_eq!(
+        query
+            .order_by_clause
+            .unwrap()
+            .order_by_items
+            .into_iter()
+            .map(|item| item.item)
+            .collect::<Vec<_>>(),
+        vec![
+            SelectColumn::new(None, "preceding".into()).into(),
+            SelectColumn::new(None, "following".into()).into(),
+        ]
+    );
+
+    // 윈도우 정의 안에서는 키워드로 읽습니다.
+    let query = parse_select(
+        "SELECT sum(range) OVER (PARTITION BY rows ORDER BY range ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW) FROM t;",
+    )
+    .unwrap();
+
+    assert_eq!(
+        first_select_expression(&query),
+        WindowExpression {
+            call: CallExpression {
+                function: AggregateFunction::Sum.into(),
+                arguments: vec![SelectColumn::new(None, "range".into()).into()],
+                distinct: false,
+            },
+            partition_by: vec![SelectColumn::new(None, "rows".into()).into()],
+            order_by: vec![OrderByItem {
+                item: SelectColumn::new(None, "range".into()).into(),
+                order_type: OrderByType::Asc,
+                nulls: OrderByNulls::First,
+            }],
+            frame: Some(WindowFrame {
+                unit: WindowFrameUnit::Rows,
+                start: WindowFrameBound::UnboundedPreceding,
+                end: WindowFrameBound::CurrentRow,
+            }),
+        }
+        .into()
+    );
+}
@@ -1,7 +1,10 @@
 #![cfg(test)]
 
 use crate::engine::ast::SQLStatement;
-use crate::engine::ast::tcl::{BeginTransactionQuery, CommitQuery, RollbackQuery};
+use crate::engine::ast::tcl::{
+    BeginTransactionQuery, CommitQuery, IsolationLevel, ReleaseSavepointQuery, RollbackQuery,
+    RollbackToSavepointQuery, SavepointQuery,
+};
 use crate::engine::parser::predule::{Parser, ParserContext};
 
 #[test]
@@ -17,12 +20,34 @@ pub fn begin_transaction() {
         TestCase {
             name: "정상적인 트랜잭션 시작".to_owned(),
             input: "BEGIN TRANSACTION;".to_owned(),
-            expected: BeginTransactionQuery {}.into(),
+            expected: BeginTransactionQuery::builder().build(),
             want_err: false,
         },
         TestCase {
             name: "begin만 있는 경우".to_owned(),
             input: "BEGIN;".to_owned(),
+            expected: BeginTransactionQuery::builder().build(),
+            want_err: false,
+        },
+        TestCase {
+            name: "격리 수준 지정".to_owned(),
+            input: "BEGIN TRANSACTION ISOLATION LEVEL REPEATABLE READ;".to_owned(),
+            expected: BeginTransactionQuery::builder()
+                .set_isolation_level(IsolationLevel::RepeatableRead)
+                .build(),
+            want_err: false,
+        },
+        TestCase {
+            name: "TRANSACTION 없이 격리 수준 지정".to_owned(),
+            input: "begin isolation level read committed;".to_owned(),
+            expected: BeginTransactionQuery::builder()
+                .set_isolation_level(IsolationLevel::ReadCommitted)
+                .build(),
+            want_err: false,
+        },
+        TestCase {
+            name: "알 수 없는 격리 수준".to_owned(),
+            input: "BEGIN ISOLATION LEVEL READ ONLY;".to_owned(),
             expected: Default::default(),
             want_err: true,
         },
@@ -97,12 +122,163 @@ pub fn rollback() {
         want_err: bool,
     }
 
-    let test_cases = vec![TestCase {
-        name: "정상적인 ROLLBACK 명령".to_owned(),
-        input: "ROLLBACK;".to_owned(),
-        expected: RollbackQuery {}.into(),
-        want_err: false,
-    }];
+    let test_cases = vec![
+        TestCase {
+            name: "정상적인 ROLLBACK 명령".to_owned(),
+            input: "ROLLBACK;".to_owned(),
+            expected: RollbackQuery {}.into(),
+            want_err: false,
+        },
+        TestCase {
+            name: "세이브포인트로 롤백".to_owned(),
+            input: "ROLLBACK TO SAVEPOINT sp1;".to_owned(),
+            expected: RollbackToSavepointQuery {
+                name: "sp1".to_owned(),
+            }
+            .into(),
+            want_err: false,
+        },
+        TestCase {
+            name: "SAVEPOINT 키워드 없이 롤백".to_owned(),
+            input: "rollback to sp1;".to_owned(),
+            expected: RollbackToSavepointQuery {
+                name: "sp1".to_owned(),
+            }
+            .into(),
+            want_err: false,
+        },
+        TestCase {
+            name: "세이브포인트 이름이 없는 경우".to_owned(),
+            input: "ROLLBACK TO SAVEPOINT;".to_owned(),
+            expected: Default::default(),
+            want_err: true,
+        },
+    ];
+
+    for tc in test_cases {
+        let mut parser = Parser::with_string(tc.input).unwrap();
+
+        let result = parser.parse(ParserContext::default());
+
+        if tc.want_err {
+            assert!(
+                result.is_err(),
+                "{} - expected error, got {:?}",
+                tc.name,
+                result
+            );
+            continue;
+        }
+
+        assert_eq!(result.unwrap(), vec![tc.expected], "{}", tc.name);
+    }
+}
+
+#[test]
+pub fn savepoint() {
+    struct TestCase {
+        name: String,
+        input: String,
+        expected: SQLStatement,
+        want_err: bool,
+    }
+
+    let test_cases = vec![
+        TestCase {
+            name: "정상적인 SAVEPOINT 명령".to_owned(),
+            input: "SAVEPOINT sp1;".to_owned(),
+            expected: SavepointQuery {
+                name: "sp1".to_owned(),
+            }
+            .into(),
+            want_err: false,
+        },
+        TestCase {
+            name: "키워드 이름의 세이브포인트".to_owned(),
+            input: "SAVEPOINT release;".to_owned(),
+            expected: SavepointQuery {
+                name: "release".to_owned(),
+            }
+            .into(),
+            want_err: false,
+        },
+        TestCase {
+            name: "세이브포인트 이름이 없는 경우".to_owned(),
+            input: "SAVEPOINT;".to_owned(),
+            expected: Default::default(),
+            want_err: true,
+        },
+        TestCase {
+            name: "이름 뒤에 기대하지 않은 입력이 있는 경우".to_owned(),
+            input: "SAVEPOINT sp1 sp2;".to_owned(),
+            expected: Default::default(),
+            want_err: true,
+        },
+    ];
+
+    for tc in test_cases {
+        let mut parser = Parser::with_string(tc.input).unwrap();
+
+        let result = parser.parse(ParserContext::default());
+
+        if tc.want_err {
+            assert!(
+                result.is_err(),
+                "{} - expected error, got {:?}",
+                tc.name,
+                result
+            );
+            continue;
+        }
+
+        assert_eq!(result.unwrap(), vec![tc.expected], "{}", tc.name);
+    }
+}
+
+#[test]
+pub fn release_savepoint() {
+    struct TestCase {
+        name: String,
+        input: String,
+        expected: SQLStatement,
+        want_err: bool,
+    }
+
+    let test_cases = vec![
+        TestCase {
+            name: "정상적인 RELEASE SAVEPOINT 명령".to_owned(),
+            input: "RELEASE SAVEPOINT sp1;".to_owned(),
+            expected: ReleaseSavepointQuery {
+                name: "sp1".to_owned(),
+            }
+            .into(),
+            want_err: false,
+        },
+        TestCase {
+            name: "SAVEPOINT 키워드 없이 해제".to_owned(),
+            input: "release sp1;".to_owned(),
+            expected: ReleaseSavepointQuery {
+                name: "sp1".to_owned(),
+            }
+            .into(),
+            want_err: false,
+        },
+        TestCase {
+            name: "키워드 이름의 세이브포인트 해제".to_owned(),
+            input: "RELEASE SAVEPOINT savepoint;".to_owned(),
+            expected: ReleaseSavepointQuery {
+                name: "savepoint".to_owned(),
+            }
+            .into(),
+            want_err: false,
+        },
+        TestCase {
+            name: "세이브포인트 이름이 없는 경우".to_owned(),
+            input: "RELEASE;".to_owned(),
+            expected: Default::default(),
+            want_err: true,
+        },
+    ];
 
     for tc in test_cases {
         let mut parser = Parser::with_string(tc.input).unwrap();
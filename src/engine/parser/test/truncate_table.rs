@@ -0,0 +1,95 @@
+#![cfg(test)]
+
+use crate::engine::ast::ddl::truncate_table::TruncateTableQuery;
+use crate::engine::ast::types::TableName;
+use crate::engine::parser::context::ParserContext;
+use crate::engine::parser::predule::Parser;
+
+#[test]
+pub fn truncate_table() {
+    let text = r#"
+        truncate table "foo_db".foo;
+    "#
+    .to_owned();
+
+    let mut parser = Parser::with_string(text).unwrap();
+
+    let expected = TruncateTableQuery::builder()
+        .add_table(TableName::new(Some("foo_db".to_owned()), "foo".to_owned()))
+        .build();
+
+    assert_eq!(
+        parser.parse(ParserContext::default()).unwrap(),
+        vec![expected],
+    );
+}
+
+#[test]
+pub fn truncate_multiple_tables_restart_identity() {
+    let text = r#"
+        TRUNCATE foo, "bar_db".bar RESTART IDENTITY;
+        TRUNCATE TABLE foo CONTINUE IDENTITY
+    "#
+    .to_owned();
+
+    let mut parser = Parser::with_string(text).unwrap();
+
+    let expected = vec![
+        TruncateTableQuery::builder()
+            .add_table(TableName::new(Some("rrdb".to_owned()), "foo".to_owned()))
+            .add_table(TableName::new(Some("bar_db".to_owned()), "bar".to_owned()))
+            .set_restart_identity(true)
+            .build(),
+        TruncateTableQuery::builder()
+            .add_table(TableName::new(Some("rrdb".to_owned()), "foo".to_owned()))
+            .build(),
+    ];
+
+    assert_eq!(
+        parser
+            .parse(ParserContext::default().set_default_database("rrdb".to_owned()))
+            .unwrap(),
+        expected,
+    );
+}
+
+#[test]
+pub fn truncate_table_named_truncate() {
+    let text = "TRUNCATE truncate;".to_owned();
+
+    let mut parser = Parser::with_string(text).unwrap();
+
+    let expected = TruncateTableQuery::builder()
+        .add_table(TableName::new(
+            Some("rrdb".to_owned()),
+            "truncate".to_owned(),
+        ))
+        .build();
+
+    assert_eq!(
+        parser
+            .parse(ParserContext::default().set_default_database("rrdb".to_owned()))
+            .unwrap(),
+        vec![expected],
+    );
+}
+
+#[test]
+pub fn truncate_table_errors() {
+    for text in [
+        "truncate table;",
+        "truncate foo,;",
+        "truncate foo restart;",
+        "truncate foo restart sequence;",
+        "truncate foo cascade;",
+    ] {
+        let mut parser = Parser::with_string(text.to_owned()).unwrap();
+
+        assert!(
+            parser
+                .parse(ParserContext::default().set_default_database("rrdb".to_owned()))
+                .is_err(),
+            "{text}"
+        );
+    }
+}
@@ -3,6 +3,7 @@
 use crate::engine::ast::dml::expressions::binary::BinaryOperatorExpression;
 use crate::engine::ast::dml::expressions::operators::BinaryOperator;
 use crate::engine::ast::dml::parts::_where::WhereClause;
+use crate::engine::ast::dml::parts::target::UpdateTarget;
 use crate::engine::ast::dml::parts::update_item::UpdateItem;
 use crate::engine::ast::dml::update::UpdateQuery;
 use crate::engine::ast::types::{SQLExpression, SelectColumn, TableName};
@@ -225,6 +226,109 @@ fn test_update_query() {
                 .build(),
             want_error: false,
         },
+        TestCase {
+            name: "Update foo.bar b set a = c.x from foo.baz c, foo.qux where b.id = c.id"
+                .to_owned(),
+            input: vec![
+                Token::Update,
+                Token::Identifier("foo".to_owned()),
+                Token::Period,
+                Token::Identifier("bar".to_owned()),
+                Token::Identifier("b".to_owned()),
+                Token::Set,
+                Token::Identifier("a".to_owned()),
+                Token::Operator(OperatorToken::Eq),
+                Token::Identifier("c".to_owned()),
+                Token::Period,
+                Token::Identifier("x".to_owned()),
+                Token::From,
+                Token::Identifier("foo".to_owned()),
+                Token::Period,
+                Token::Identifier("baz".to_owned()),
+                Token::Identifier("c".to_owned()),
+                Token::Comma,
+                Token::Identifier("foo".to_owned()),
+                Token::Period,
+                Token::Identifier("qux".to_owned()),
+                Token::Where,
+                Token::Identifier("b".to_owned()),
+                Token::Period,
+                Token::Identifier("id".to_owned()),
+                Token::Operator(OperatorToken::Eq),
+                Token::Identifier("c".to_owned()),
+                Token::Period,
+                Token::Identifier("id".to_owned()),
+            ],
+            expected: UpdateQuery::builder()
+                .set_target_table(TableName {
+                    database_name: Some("foo".into()),
+                    table_name: "bar".into(),
+                })
+                .set_target_alias("b".into())
+                .add_update_item(UpdateItem {
+                    column: "a".into(),
+                    value: SelectColumn::new(Some("c".into()), "x".into()).into(),
+                })
+                .add_from_table(UpdateTarget {
+                    table: TableName {
+                        database_name: Some("foo".into()),
+                        table_name: "baz".into(),
+                    },
+                    alias: Some("c".into()),
+                })
+                .add_from_table(
+                    TableName {
+                        database_name: Some("foo".into()),
+                        table_name: "qux".into(),
+                    }
+                    .into(),
+                )
+                .set_where(WhereClause {
+                    expression: BinaryOperatorExpression {
+                        operator: BinaryOperator::Eq,
+                        lhs: SelectColumn::new(Some("b".into()), "id".into()).into(),
+                        rhs: SelectColumn::new(Some("c".into()), "id".into()).into(),
+                    }
+                    .into(),
+                })
+                .build(),
+            want_error: false,
+        },
+        TestCase {
+            name: "실패: Update foo.bar set a = 1 from foo.bar".to_owned(),
+            input: vec![
+                Token::Update,
+                Token::Identifier("foo".to_owned()),
+                Token::Period,
+                Token::Identifier("bar".to_owned()),
+                Token::Set,
+                Token::Identifier("a".to_owned()),
+                Token::Operator(OperatorToken::Eq),
+                Token::Integer(1),
+                Token::From,
+                Token::Identifier("foo".to_owned()),
+                Token::Period,
+                Token::Identifier("bar".to_owned()),
+            ],
+            expected: Default::default(),
+            want_error: true,
+        },
+        TestCase {
+            name: "실패: Update foo.bar set a = 1 from".to_owned(),
+            input: vec![
+                Token::Update,
+                Token::Identifier("foo".to_owned()),
+                Token::Period,
+                Token::Identifier("bar".to_owned()),
+                Token::Set,
+                Token::Identifier("a".to_owned()),
+                Token::Operator(OperatorToken::Eq),
+                Token::Integer(1),
+                Token::From,
+            ],
+            expected: Default::default(),
+            want_error: true,
+        },
         TestCase {
             name: "실패: 빈 토큰".to_owned(),
             input: vec![],
@@ -0,0 +1,288 @@
+use crate::errors;
+use crate::errors::execute_error::ExecuteError;
+
+// LIKE 패턴의 기본 escape 문자
+pub const DEFAULT_LIKE_ESCAPE: char = '\\';
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum PatternToken {
+    Literal(char),
+    AnyString, // %
+    AnyChar,   // _
+}
+
+// 기본 escape 문자(\) 기준으로 패턴을 토큰 목록으로 분해합니다.
+fn tokenize_pattern(pattern: &str) -> errors::Result<Vec<PatternToken>> {
+    let mut tokens = vec![];
+    let mut chars = pattern.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '%' => tokens.push(PatternToken::AnyString),
+            '_' => tokens.push(PatternToken::AnyChar),
+            DEFAULT_LIKE_ESCAPE => match chars.next() {
+                Some(escaped) => tokens.push(PatternToken::Literal(escaped)),
+                None => {
+                    return Err(ExecuteError::wrap(
+                        "LIKE pattern must not end with escape character",
+                    ));
+                }
+            },
+            _ => tokens.push(PatternToken::Literal(c)),
+        }
+    }
+
+    Ok(tokens)
+}
+
+// SQL LIKE 매칭
+// %는 0개 이상의 임의 문자열, _는 임의의 한 문자와 일치합니다.
+// case_insensitive가 true라면 ILIKE로 동작합니다.
+pub fn like_match(value: &str, pattern: &str, case_insensitive: bool) -> errors::Result<bool> {
+    let (value, pattern) = if case_insensitive {
+        (value.to_lowercase(), pattern.to_lowercase())
+    } else {
+        (value.to_owned(), pattern.to_owned())
+    };
+
+    let tokens = tokenize_pattern(&pattern)?;
+    let value = value.chars().collect::<Vec<_>>();
+
+    // 마지막으로 만난 %의 위치와, 그 %가 흡수하기 시작한 value 위치를 기억해두고 실패 시 되돌아갑니다.
+    let mut value_index = 0;
+    let mut token_index = 0;
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while value_index < value.len() {
+        match tokens.get(token_index) {
+            Some(PatternToken::AnyString) => {
+                backtrack = Some((token_index, value_index));
+                token_index += 1;
+                continue;
+            }
+            Some(PatternToken::AnyChar) => {
+                value_index += 1;
+                token_index += 1;
+                continue;
+            }
+            Some(PatternToken::Literal(c)) if *c == value[value_index] => {
+                value_index += 1;
+                token_index += 1;
+                continue;
+            }
+            _ => {}
+        }
+
+        match backtrack {
+            Some((any_token_index, any_value_index)) => {
+                token_index = any_token_index + 1;
+                value_index = any_value_index + 1;
+                backtrack = Some((any_token_index, any_value_index + 1));
+            }
+            None => return Ok(false),
+        }
+    }
+
+    Ok(tokens[token_index..]
+        .iter()
+        .all(|token| *token == PatternToken::AnyString))
+}
+
+// LIKE ... ESCAPE 'c'로 지정된 escape 문자를 기본 escape 문자(\) 기준 패턴으로 바꿔씁니다.
+// 빈 문자열을 지정하면 escape를 사용하지 않습니다.
+pub fn like_escape(pattern: &str, escape: &str) -> errors::Result<String> {
+    let mut escape_chars = escape.chars();
+    let escape = escape_chars.next();
+
+    if escape_chars.next().is_some() {
+        return Err(ExecuteError::wrap(
+            "invalid escape string. Escape string must be empty or one character.",
+        ));
+    }
+
+    if escape == Some(DEFAULT_LIKE_ESCAPE) {
+        return Ok(pattern.to_owned());
+    }
+
+    let mut result = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars();
+
+    while let Some(c) = chars.next() {
+        if Some(c) == escape {
+            match chars.next() {
+                Some(escaped) => {
+                    result.push(DEFAULT_LIKE_ESCAPE);
+                    result.push(escaped);
+                }
+                None => {
+                    return Err(ExecuteError::wrap(
+                        "LIKE pattern must not end with escape character",
+                    ));
+                }
+            }
+        } else if c == DEFAULT_LIKE_ESCAPE {
+            // 다른 escape 문자를 쓰는 중이라면 \는 일반 문자입니다.
+            result.push(DEFAULT_LIKE_ESCAPE);
+            result.push(DEFAULT_LIKE_ESCAPE);
+        } else {
+            result.push(c);
+        }
+    }
+
+    Ok(result)
+}
+
+// 패턴의 첫 와일드카드 앞까지의 고정 접두사를 구합니다.
+// 와일드카드가 없다면 두번째 값이 true입니다. (패턴 자체가 등호 비교와 같음)
+pub fn like_prefix(pattern: &str) -> Option<(String, bool)> {
+    let tokens = tokenize_pattern(pattern).ok()?;
+
+    let mut prefix = String::new();
+
+    for token in tokens {
+        match token {
+            PatternToken::Literal(c) => prefix.push(c),
+            _ => return Some((prefix, false)),
+        }
+    }
+
+    Some((prefix, true))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_like_match() {
+        struct TestCase {
+            name: String,
+            value: &'static str,
+            pattern: &'static str,
+            case_insensitive: bool,
+            expected: bool,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                name: "exact".into(),
+                value: "abc",
+                pattern: "abc",
+                case_insensitive: false,
+                expected: true,
+            },
+            TestCase {
+                name: "prefix".into(),
+                value: "abcdef",
+                pattern: "abc%",
+                case_insensitive: false,
+                expected: true,
+            },
+            TestCase {
+                name: "suffix".into(),
+                value: "abcdef",
+                pattern: "%def",
+                case_insensitive: false,
+                expected: true,
+            },
+            TestCase {
+                name: "percent matches empty".into(),
+                value: "abc",
+                pattern: "abc%",
+                case_insensitive: false,
+                expected: true,
+            },
+            TestCase {
+                name: "underscore needs one char".into(),
+                value: "ab",
+                pattern: "ab_",
+                case_insensitive: false,
+                expected: false,
+            },
+            TestCase {
+                name: "backtracking".into(),
+                value: "abXbYc",
+                pattern: "a%b_c",
+                case_insensitive: false,
+                expected: true,
+            },
+            TestCase {
+                name: "mismatch".into(),
+                value: "abd",
+                pattern: "a%c",
+                case_insensitive: false,
+                expected: false,
+            },
+            TestCase {
+                name: "escaped percent".into(),
+                value: "100%",
+                pattern: "100\\%",
+                case_insensitive: false,
+                expected: true,
+            },
+            TestCase {
+                name: "escaped percent is literal".into(),
+                value: "1000",
+                pattern: "100\\%",
+                case_insensitive: false,
+                expected: false,
+            },
+            TestCase {
+                name: "case sensitive".into(),
+                value: "ABC",
+                pattern: "abc",
+                case_insensitive: false,
+                expected: false,
+            },
+            TestCase {
+                name: "ilike".into(),
+                value: "ABC",
+                pattern: "a_c",
+                case_insensitive: true,
+                expected: true,
+            },
+            TestCase {
+                name: "multibyte".into(),
+                value: "한글테스트",
+                pattern: "한_테%",
+                case_insensitive: false,
+                expected: true,
+            },
+        ];
+
+        for t in test_cases {
+            let got = like_match(t.value, t.pattern, t.case_insensitive).unwrap();
+            assert_eq!(got, t.expected, "TC: {}", t.name);
+        }
+    }
+
+    #[test]
+    fn test_like_match_trailing_escape() {
+        assert!(like_match("abc", "abc\\", false).is_err());
+    }
+
+    #[test]
+    fn test_like_escape() {
+        assert_eq!(like_escape("a!%b", "!").unwrap(), "a\\%b");
+        assert_eq!(like_escape("a\\b%", "!").unwrap(), "a\\\\b%");
+        assert_eq!(like_escape("a!!", "!").unwrap(), "a\\!");
+        assert_eq!(like_escape("a\\%", "\\").unwrap(), "a\\%");
+        assert_eq!(like_escape("a\\%", "").unwrap(), "a\\\\%");
+        assert!(like_escape("a!", "!").is_err());
+        assert!(like_escape("a", "!!").is_err());
+
+        let pattern = like_escape("10!%%", "!").unwrap();
+        assert!(like_match("10%ab", &pattern, false).unwrap());
+        assert!(!like_match("10ab", &pattern, false).unwrap());
+    }
+
+    #[test]
+    fn test_like_prefix() {
+        assert_eq!(like_prefix("abc%"), Some(("abc".to_owned(), false)));
+        assert_eq!(like_prefix("ab_c"), Some(("ab".to_owned(), false)));
+        assert_eq!(like_prefix("%abc"), Some(("".to_owned(), false)));
+        assert_eq!(like_prefix("abc"), Some(("abc".to_owned(), true)));
+        assert_eq!(like_prefix("a\\%b%"), Some(("a%b".to_owned(), false)));
+        assert_eq!(like_prefix("abc\\"), None);
+    }
+}
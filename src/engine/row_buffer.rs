@@ -3,25 +3,61 @@ use std::path::PathBuf;
 
 use crate::engine::encoder::schema_encoder::StorageEncoder;
 use crate::engine::schema::row::TableDataRow;
+use crate::engine::transaction::{FROZEN_TRANSACTION_ID, Snapshot};
 use crate::errors;
 use crate::errors::execute_error::ExecuteError;
 
 pub(crate) const ROW_FRAME_LIVE: u8 = 0;
 pub(crate) const ROW_FRAME_TOMBSTONE: u8 = 1;
+/// `[u64 xmin][u64 xmax][row]`. xmax가 0이면 지워지지 않은 버전입니다.
+pub(crate) const ROW_FRAME_VERSIONED: u8 = 2;
+
+/// 행의 한 버전. 쓰기는 행을 제자리에서 고치지 않고 새 버전을 쌓으며(MVCC),
+/// 읽기는 자기 스냅샷에 보이는 버전을 고릅니다.
+#[derive(Clone, Debug)]
+pub(crate) struct RowVersion {
+    /// 이 버전을 만든 transaction id
+    pub(crate) xmin: u64,
+    /// 이 버전을 지우거나 새 버전으로 바꾼 transaction id
+    pub(crate) xmax: Option<u64>,
+    pub(crate) row: TableDataRow,
+}
+
+/// row index 하나의 버전 목록 (오래된 것부터). 비어 있으면 완전히 지워진 행입니다.
+pub(crate) type RowSlot = Vec<RowVersion>;
 
 #[derive(Default)]
 pub(crate) struct RowBufferPool {
     segments: HashMap<PathBuf, RowSegmentBuffer>,
     unsynced_segments: HashSet<PathBuf>,
+    /// 마지막 flush 뒤에도 버퍼에 남은 바이트 (열린 트랜잭션의 변경).
+    /// 다음 flush는 이보다 새로 쌓인 양을 기준으로 정합니다.
+    retained_dirty_bytes: usize,
 }
 
 #[derive(Default)]
 struct RowSegmentBuffer {
-    persisted_rows: Option<Vec<Option<TableDataRow>>>,
+    persisted_rows: Option<Vec<RowSlot>>,
     persisted_row_count: Option<usize>,
-    pending_append_rows: Vec<TableDataRow>,
+    pending_append_rows: Vec<RowVersion>,
     pending_append_bytes: Vec<u8>,
     rewrite_required: bool,
+    /// TRUNCATE한 transaction id와 그 직전의 행들 (오래된 것부터).
+    /// TRUNCATE가 보이지 않는 스냅샷은 이 행들을 읽습니다.
+    truncated_generations: Vec<(u64, Vec<RowSlot>)>,
+    /// 커밋된 상태만 파일에 써 둔 열린 트랜잭션. 그 트랜잭션이 끝나기 전에는 이 테이블의
+    /// 커밋된 상태가 바뀌지 않으므로(테이블 쓰기 락) 다시 쓰지 않습니다.
+    committed_flush: Option<u64>,
+}
+
+/// SAVEPOINT 시점의 테이블 상태. 트랜잭션이 그때까지 버전을 쌓은 슬롯만 담고,
+/// 나머지 슬롯은 트랜잭션의 버전을 걷어내면 그때의 상태가 됩니다.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct RowSavepoint {
+    row_count: usize,
+    /// 그때까지 트랜잭션이 한 TRUNCATE 횟수
+    truncate_count: usize,
+    slots: Vec<(usize, RowSlot)>,
 }
 
 pub(crate) struct RowBufferWrite {
@@ -33,8 +69,16 @@ pub(crate) struct RowBufferWrite {
 }
 
 enum RowBufferWriteKind {
-    Append { rows: Vec<TableDataRow> },
-    Rewrite { rows: Vec<Option<TableDataRow>> },
+    Append {
+        rows: Vec<RowVersion>,
+    },
+    Rewrite {
+        rows: Vec<RowSlot>,
+    },
+    /// 열린 트랜잭션의 버전을 뺀 커밋된 상태. 버퍼는 그대로 둡니다.
+    Committed {
+        open_writer: u64,
+    },
 }
 
 impl RowBufferPool {
@@ -46,40 +90,198 @@ impl RowBufferPool {
     pub(crate) fn append_rows(
         &mut self,
         segment_path: PathBuf,
-        rows: &[TableDataRow],
+        rows: Vec<RowVersion>,
         encoded_rows: Vec<u8>,
     ) -> usize {
         let segment = self.segments.entry(segment_path).or_default();
-        segment.pending_append_rows.extend_from_slice(rows);
-        segment.pending_append_bytes.extend_from_slice(&encoded_rows);
-        self.dirty_bytes()
+        segment.pending_append_rows.extend(rows);
+        segment
+            .pending_append_bytes
+            .extend_from_slice(&encoded_rows);
+        self.dirty_bytes().saturating_sub(self.retained_dirty_bytes)
     }
 
+    /// 최신 버전 기준의 행 목록을 반환합니다. 아직 적재하지 않았으면 `load_disk_rows`로 적재합니다.
     pub(crate) fn read_rows(
         &mut self,
         segment_path: PathBuf,
-        load_disk_rows: impl FnOnce() -> Vec<Option<TableDataRow>>,
+        load_disk_rows: impl FnOnce() -> Vec<RowSlot>,
     ) -> Vec<Option<TableDataRow>> {
+        self.load_rows(segment_path.clone(), load_disk_rows);
+        self.segments[&segment_path].latest_rows()
+    }
+
+    /// 아직 적재하지 않은 세그먼트를 `load_disk_rows`로 적재합니다.
+    pub(crate) fn load_rows(
+        &mut self,
+        segment_path: PathBuf,
+        load_disk_rows: impl FnOnce() -> Vec<RowSlot>,
+    ) {
         let segment = self.segments.entry(segment_path).or_default();
         let persisted_rows = segment.persisted_rows.get_or_insert_with(load_disk_rows);
         segment.persisted_row_count = Some(persisted_rows.len());
-        let mut rows = persisted_rows.clone();
+    }
+
+    /// `start`부터 최대 `count`개 row index의 행을 반환합니다. 스냅샷이 있으면 그 스냅샷에
+    /// 보이는 버전을, 없으면 최신 버전을 읽습니다. `start`가 끝을 넘으면 빈 목록이고,
+    /// 세그먼트를 아직 적재하지 않았으면 `None`입니다.
+    pub(crate) fn rows_range(
+        &self,
+        segment_path: &PathBuf,
+        snapshot: Option<&Snapshot>,
+        start: usize,
+        count: usize,
+    ) -> Option<Vec<Option<TableDataRow>>> {
+        let segment = self.segments.get(segment_path)?;
+        let persisted_rows = segment.persisted_rows.as_ref()?;
+
+        let slot_row = |slot: &RowSlot| {
+            match snapshot {
+                Some(snapshot) => visible_version(slot, snapshot),
+                None => latest_version(slot),
+            }
+            .map(|version| version.row.clone())
+        };
+
+        if let Some(snapshot) = snapshot
+            && let Some((_, slots)) = segment
+                .truncated_generations
+                .iter()
+                .find(|(transaction_id, _)| !snapshot.sees(*transaction_id))
+        {
+            return Some(slots.iter().skip(start).take(count).map(slot_row).collect());
+        }
+
+        let mut rows: Vec<_> = persisted_rows
+            .iter()
+            .skip(start)
+            .take(count)
+            .map(slot_row)
+            .collect();
+        let pending_rows = segment
+            .pending_append_rows
+            .iter()
+            .skip(start.saturating_sub(persisted_rows.len()))
+            .take(count - rows.len())
+            .map(|version| {
+                snapshot
+                    .is_none_or(|snapshot| snapshot.sees(version.xmin))
+                    .then(|| version.row.clone())
+            });
+        rows.extend(pending_rows);
+
+        Some(rows)
+    }
+
+    pub(crate) fn cached_rows(&self, segment_path: &PathBuf) -> Option<Vec<Option<TableDataRow>>> {
+        let segment = self.segments.get(segment_path)?;
+        segment.persisted_rows.as_ref()?;
+        Some(segment.latest_rows())
+    }
+
+    /// 스냅샷에 보이는 행 목록과, 그중 최신 버전이 아닌 행의 row index를 반환합니다.
+    /// 인덱스는 최신 버전만 가리키므로 인덱스 스캔은 뒤의 목록을 후보에 더해야 합니다.
+    pub(crate) fn snapshot_rows(
+        &self,
+        segment_path: &PathBuf,
+        snapshot: &Snapshot,
+    ) -> Option<(Vec<Option<TableDataRow>>, Vec<usize>)> {
+        let segment = self.segments.get(segment_path)?;
+        let persisted_rows = segment.persisted_rows.as_ref()?;
+
+        if let Some((_, slots)) = segment
+            .truncated_generations
+            .iter()
+            .find(|(transaction_id, _)| !snapshot.sees(*transaction_id))
+        {
+            let rows: Vec<_> = slots
+                .iter()
+                .map(|slot| visible_version(slot, snapshot).map(|version| version.row.clone()))
+                .collect();
+            let diverged = (0..rows.len())
+                .filter(|&row_index| rows[row_index].is_some())
+                .collect();
+            return Some((rows, diverged));
+        }
+
+        let mut rows = Vec::with_capacity(persisted_rows.len() + segment.pending_append_rows.len());
+        let mut diverged = Vec::new();
+
+        for (row_index, slot) in persisted_rows.iter().enumerate() {
+            let visible = visible_version(slot, snapshot);
+            let is_newest = |version| slot.last().is_some_and(|last| std::ptr::eq(version, last));
+            if visible.is_some_and(|version| !is_newest(version)) {
+                diverged.push(row_index);
+            }
+            rows.push(visible.map(|version| version.row.clone()));
+        }
+
         rows.extend(
             segment
                 .pending_append_rows
                 .iter()
-                .cloned()
-                .map(Some),
+                .map(|version| snapshot.sees(version.xmin).then(|| version.row.clone())),
         );
-        rows
+
+        Some((rows, diverged))
     }
 
-    pub(crate) fn cached_rows(&self, segment_path: &PathBuf) -> Option<Vec<Option<TableDataRow>>> {
+    /// 스냅샷을 찍은 뒤 다른 트랜잭션이 이 행을 바꾸거나 지웠는지 확인합니다.
+    pub(crate) fn changed_since_snapshot(
+        &self,
+        segment_path: &PathBuf,
+        row_index: usize,
+        snapshot: &Snapshot,
+    ) -> bool {
+        let Some(segment) = self.segments.get(segment_path) else {
+            return false;
+        };
+
+        if segment
+            .truncated_generations
+            .iter()
+            .any(|(transaction_id, _)| !snapshot.sees(*transaction_id))
+        {
+            return true;
+        }
+
+        let persisted_len = segment.persisted_row_count.unwrap_or_default();
+        let newest = match row_index.checked_sub(persisted_len) {
+            Some(offset) => segment.pending_append_rows.get(offset),
+            None => segment
+                .persisted_rows
+                .as_ref()
+                .and_then(|rows| rows.get(row_index))
+                .and_then(|slot| slot.last()),
+        };
+
+        newest.is_some_and(|version| {
+            !snapshot.sees(version.xmin)
+                || version
+                    .xmax
+                    .is_some_and(|transaction_id| !snapshot.sees(transaction_id))
+        })
+    }
+
+    /// row index의 지워지지 않은 최신 버전. 지워졌거나 버퍼에 없으면 None입니다.
+    pub(crate) fn latest_row(
+        &self,
+        segment_path: &PathBuf,
+        row_index: usize,
+    ) -> Option<TableDataRow> {
         let segment = self.segments.get(segment_path)?;
-        let persisted_rows = segment.persisted_rows.as_ref()?;
-        let mut rows = persisted_rows.clone();
-        rows.extend(segment.pending_append_rows.iter().cloned().map(Some));
-        Some(rows)
+
+        let persisted_len = segment.persisted_row_count.unwrap_or_default();
+        let newest = match row_index.checked_sub(persisted_len) {
+            Some(offset) => segment.pending_append_rows.get(offset),
+            None => segment
+                .persisted_rows
+                .as_ref()?
+                .get(row_index)
+                .and_then(latest_version),
+        };
+
+        newest.map(|version| version.row.clone())
     }
 
     pub(crate) fn cached_row_count(&self, segment_path: &PathBuf) -> Option<usize> {
@@ -88,23 +290,250 @@ impl RowBufferPool {
         Some(persisted_len + segment.pending_append_rows.len())
     }
 
-    pub(crate) fn replace_rows(&mut self, segment_path: PathBuf, rows: Vec<Option<TableDataRow>>) {
+    /// 최신 버전 기준의 행 목록(`rows`)과 달라진 행마다 `transaction_id`의 버전을 쌓습니다.
+    ///
+    /// `horizon`보다 먼저 지워진 버전은 어느 스냅샷에도 보이지 않으므로 함께 정리합니다.
+    /// 트랜잭션 밖(WAL replay)의 쓰기는 버전을 쌓지 않고 행을 바로 바꿉니다.
+    pub(crate) fn replace_rows(
+        &mut self,
+        segment_path: PathBuf,
+        rows: Vec<Option<TableDataRow>>,
+        transaction_id: u64,
+        horizon: u64,
+    ) {
         let segment = self.segments.entry(segment_path).or_default();
-        segment.persisted_row_count = Some(rows.len());
-        segment.persisted_rows = Some(rows);
-        segment.pending_append_rows.clear();
-        segment.pending_append_bytes.clear();
+        segment.merge_pending_rows();
+
+        let slots = segment.persisted_rows.get_or_insert_with(Vec::new);
+        if slots.len() < rows.len() {
+            slots.resize_with(rows.len(), Vec::new);
+        }
+
+        for (slot, row) in slots.iter_mut().zip(rows) {
+            if latest_version(slot).map(|version| &version.row) != row.as_ref() {
+                write_version(slot, row, transaction_id);
+            }
+            slot.retain(|version| version.xmax.is_none_or(|xmax| xmax >= horizon));
+        }
+
+        segment.persisted_row_count = Some(slots.len());
+        segment
+            .truncated_generations
+            .retain(|(transaction_id, _)| *transaction_id >= horizon);
         segment.rewrite_required = true;
     }
 
-    pub(crate) fn drain_writes(&mut self) -> errors::Result<Vec<RowBufferWrite>> {
+    /// TRUNCATE: 행을 모두 비우고 row index를 0부터 다시 씁니다. 비우기 전의 행은
+    /// TRUNCATE가 아직 보이지 않는 스냅샷을 위해 남겨 둡니다.
+    ///
+    /// `files_reset`이면 호출자가 이미 세그먼트 파일을 비웠으므로 쌓여 있던 쓰기를 버리고,
+    /// 아니면 다음 flush에서 파일을 다시 씁니다.
+    pub(crate) fn truncate_rows(
+        &mut self,
+        segment_path: PathBuf,
+        transaction_id: u64,
+        files_reset: bool,
+    ) {
+        if files_reset {
+            self.unsynced_segments.remove(&segment_path);
+        }
+
+        let segment = self.segments.entry(segment_path).or_default();
+        segment.merge_pending_rows();
+        let slots = segment.persisted_rows.replace(vec![]).unwrap_or_default();
+
+        if transaction_id != FROZEN_TRANSACTION_ID {
+            segment.truncated_generations.push((transaction_id, slots));
+        }
+
+        segment.persisted_row_count = Some(0);
+        segment.rewrite_required = !files_reset;
+    }
+
+    /// ROLLBACK: `transaction_id`가 쌓은 버전과 TRUNCATE를 걷어내고, 트랜잭션이 처음
+    /// 건드릴 때의 행 개수(`base_row_count`)로 되돌립니다. 커밋되지 않은 버전은 디스크에
+    /// 내려가지 않으므로 버퍼만 되돌리면 됩니다.
+    pub(crate) fn abort_transaction(
+        &mut self,
+        segment_path: PathBuf,
+        transaction_id: u64,
+        base_row_count: usize,
+    ) {
+        let savepoint = RowSavepoint {
+            row_count: base_row_count,
+            ..Default::default()
+        };
+        self.rollback_to_savepoint(segment_path, transaction_id, &savepoint);
+    }
+
+    /// SAVEPOINT: 지금까지 `transaction_id`가 바꾼 슬롯을 복사해 둡니다.
+    pub(crate) fn savepoint_rows(
+        &self,
+        segment_path: &PathBuf,
+        transaction_id: u64,
+    ) -> RowSavepoint {
+        let Some(segment) = self.segments.get(segment_path) else {
+            return RowSavepoint::default();
+        };
+
+        let touched = |version: &RowVersion| {
+            version.xmin == transaction_id || version.xmax == Some(transaction_id)
+        };
+        let persisted_len = segment.persisted_rows.as_ref().map_or(0, Vec::len);
+
+        let mut slots: Vec<_> = segment
+            .persisted_rows
+            .iter()
+            .flatten()
+            .enumerate()
+            .filter(|(_, slot)| slot.iter().any(touched))
+            .map(|(row_index, slot)| (row_index, slot.clone()))
+            .collect();
+        slots.extend(
+            segment
+                .pending_append_rows
+                .iter()
+                .enumerate()
+                .filter(|(_, version)| touched(version))
+                .map(|(offset, version)| (persisted_len + offset, vec![version.clone()])),
+        );
+
+        RowSavepoint {
+            row_count: persisted_len + segment.pending_append_rows.len(),
+            truncate_count: segment
+                .truncated_generations
+                .iter()
+                .filter(|(truncated_by, _)| *truncated_by == transaction_id)
+                .count(),
+            slots,
+        }
+    }
+
+    /// ROLLBACK TO SAVEPOINT: `transaction_id`가 쌓은 버전을 걷어낸 뒤 `savepoint`에
+    /// 복사해 둔 슬롯을 되돌려 놓습니다. 테이블 쓰기 락 때문에 그 사이에 다른 트랜잭션이
+    /// 이 테이블의 행을 바꾸지는 못합니다.
+    pub(crate) fn rollback_to_savepoint(
+        &mut self,
+        segment_path: PathBuf,
+        transaction_id: u64,
+        savepoint: &RowSavepoint,
+    ) {
+        let Some(segment) = self.segments.get_mut(&segment_path) else {
+            return;
+        };
+
+        match segment
+            .truncated_generations
+            .iter()
+            .enumerate()
+            .filter(|(_, (truncated_by, _))| *truncated_by == transaction_id)
+            .nth(savepoint.truncate_count)
+            .map(|(position, _)| position)
+        {
+            Some(position) => {
+                // 세이브포인트 뒤의 첫 TRUNCATE 직전으로 돌아가고, 뒤의 TRUNCATE는 모두 버립니다.
+                let slots = segment
+                    .truncated_generations
+                    .drain(position..)
+                    .next()
+                    .map(|(_, slots)| slots);
+                segment.persisted_rows = slots;
+                segment.pending_append_rows.clear();
+                segment.pending_append_bytes.clear();
+            }
+            None => segment.merge_pending_rows(),
+        }
+
+        let slots = segment.persisted_rows.get_or_insert_with(Vec::new);
+        slots.resize_with(savepoint.row_count, Vec::new);
+        for slot in slots.iter_mut() {
+            slot.retain(|version| version.xmin != transaction_id);
+            for version in slot.iter_mut() {
+                if version.xmax == Some(transaction_id) {
+                    version.xmax = None;
+                }
+            }
+        }
+        for (row_index, slot) in &savepoint.slots {
+            slots[*row_index] = slot.clone();
+        }
+
+        segment.persisted_row_count = Some(slots.len());
+        segment.rewrite_required = true;
+    }
+
+    /// 파일에 쓸 내용을 꺼냅니다. `open_transactions`가 쌓은 버전과 TRUNCATE는 빼고
+    /// 커밋된 상태만 쓰며, 그 버전은 트랜잭션이 끝날 때까지 버퍼에 남습니다.
+    pub(crate) fn drain_writes(
+        &mut self,
+        open_transactions: &HashSet<u64>,
+    ) -> errors::Result<Vec<RowBufferWrite>> {
         let mut writes = Vec::new();
 
         for (segment_path, segment) in self.segments.iter_mut() {
+            let open_writer = (segment.rewrite_required || !segment.pending_append_rows.is_empty())
+                .then(|| segment.open_writer(open_transactions))
+                .flatten();
+
+            if let Some(open_writer) = open_writer {
+                if segment.rewrite_required {
+                    if segment.committed_flush == Some(open_writer) {
+                        continue;
+                    }
+
+                    let rows = segment.committed_slots(open_transactions);
+                    writes.push(RowBufferWrite {
+                        segment_path: segment_path.clone(),
+                        content: encode_row_slots(&rows)?,
+                        replace_existing: true,
+                        next_row_index: rows.len(),
+                        kind: RowBufferWriteKind::Committed { open_writer },
+                    });
+                    continue;
+                }
+
+                // 추가만 한 세그먼트: 열린 트랜잭션이 추가한 뒤쪽 행은 버퍼에 남기고
+                // 그 앞의 커밋된 행만 덧붙입니다.
+                let committed_rows = segment
+                    .pending_append_rows
+                    .iter()
+                    .position(|version| open_transactions.contains(&version.xmin))
+                    .unwrap_or(segment.pending_append_rows.len());
+                if committed_rows == 0 {
+                    continue;
+                }
+
+                let committed_bytes = frames_len(&segment.pending_append_bytes, committed_rows)?;
+                let rows: Vec<_> = segment
+                    .pending_append_rows
+                    .drain(..committed_rows)
+                    .collect();
+                let content: Vec<_> = segment
+                    .pending_append_bytes
+                    .drain(..committed_bytes)
+                    .collect();
+                let base_count = segment.persisted_row_count.unwrap_or_default();
+                writes.push(RowBufferWrite {
+                    segment_path: segment_path.clone(),
+                    content,
+                    replace_existing: false,
+                    next_row_index: base_count + rows.len(),
+                    kind: RowBufferWriteKind::Append { rows },
+                });
+                continue;
+            }
+
             if segment.rewrite_required {
+                segment.committed_flush = None;
                 let mut rows = segment.persisted_rows.clone().unwrap_or_default();
-                rows.extend(segment.pending_append_rows.iter().cloned().map(Some));
-                let content = encode_row_frames(&rows)?;
+                rows.extend(
+                    segment
+                        .pending_append_rows
+                        .iter()
+                        .cloned()
+                        .map(|row| vec![row]),
+                );
+                let content = encode_row_slots(&rows)?;
                 segment.rewrite_required = false;
                 segment.pending_append_rows.clear();
                 segment.pending_append_bytes.clear();
@@ -132,6 +561,7 @@ impl RowBufferPool {
             }
         }
 
+        self.retained_dirty_bytes = self.dirty_bytes();
         Ok(writes)
     }
 
@@ -141,7 +571,7 @@ impl RowBufferPool {
         match write.kind {
             RowBufferWriteKind::Append { rows } => {
                 if let Some(persisted_rows) = &mut segment.persisted_rows {
-                    persisted_rows.extend(rows.into_iter().map(Some));
+                    persisted_rows.extend(rows.into_iter().map(|row| vec![row]));
                 }
                 segment.persisted_row_count = Some(write.next_row_index);
             }
@@ -149,6 +579,9 @@ impl RowBufferPool {
                 segment.persisted_row_count = Some(rows.len());
                 segment.persisted_rows = Some(rows);
             }
+            RowBufferWriteKind::Committed { open_writer } => {
+                segment.committed_flush = Some(open_writer);
+            }
         }
 
         if durable {
@@ -177,18 +610,10 @@ impl RowBufferPool {
                 segment.pending_append_bytes.clear();
                 segment.rewrite_required = true;
             }
+            RowBufferWriteKind::Committed { .. } => {}
         }
     }
 
-    #[cfg(test)]
-    pub(crate) fn is_dirty_empty(&self) -> bool {
-        self.segments.values().all(|segment| {
-            !segment.rewrite_required
-                && segment.pending_append_rows.is_empty()
-                && segment.pending_append_bytes.is_empty()
-        })
-    }
-
     pub(crate) fn drain_unsynced_segments(&mut self) -> Vec<PathBuf> {
         self.unsynced_segments.drain().collect()
     }
@@ -221,35 +646,243 @@ impl RowBufferPool {
     }
 }
 
-pub(crate) fn encode_live_row_frames(rows: &[TableDataRow]) -> errors::Result<Vec<u8>> {
-    let rows = rows.iter().cloned().map(Some).collect::<Vec<_>>();
-    encode_row_frames(&rows)
+impl RowSegmentBuffer {
+    /// 이 세그먼트에 버전을 쌓거나 TRUNCATE한 열린 트랜잭션. 테이블 쓰기 락 때문에
+    /// 많아야 하나입니다.
+    fn open_writer(&self, open_transactions: &HashSet<u64>) -> Option<u64> {
+        let open_of = |version: &RowVersion| {
+            [Some(version.xmin), version.xmax]
+                .into_iter()
+                .flatten()
+                .find(|transaction_id| open_transactions.contains(transaction_id))
+        };
+
+        self.truncated_generations
+            .iter()
+            .map(|(transaction_id, _)| *transaction_id)
+            .find(|transaction_id| open_transactions.contains(transaction_id))
+            // 다시 쓸 필요가 없는 슬롯은 디스크에서 읽었거나 커밋된 상태로 쓴 것입니다.
+            .or_else(|| {
+                self.persisted_rows
+                    .iter()
+                    .filter(|_| self.rewrite_required)
+                    .flatten()
+                    .flatten()
+                    .find_map(open_of)
+            })
+            .or_else(|| self.pending_append_rows.iter().find_map(open_of))
+    }
+
+    /// `open_transactions`의 변경을 뺀 슬롯 목록. 슬롯마다 커밋된 버전을 하나만 담고,
+    /// 열린 트랜잭션이 끝에 추가한 슬롯은 뺍니다.
+    fn committed_slots(&self, open_transactions: &HashSet<u64>) -> Vec<RowSlot> {
+        let is_open = |version: &RowVersion| open_transactions.contains(&version.xmin);
+
+        // 열린 트랜잭션이 TRUNCATE했다면 그 직전의 행이 커밋된 상태입니다.
+        let mut slots: Vec<&[RowVersion]> = match self
+            .truncated_generations
+            .iter()
+            .find(|(transaction_id, _)| open_transactions.contains(transaction_id))
+        {
+            Some((_, slots)) => slots.iter().map(Vec::as_slice).collect(),
+            None => self
+                .persisted_rows
+                .iter()
+                .flatten()
+                .map(Vec::as_slice)
+                .chain(self.pending_append_rows.iter().map(std::slice::from_ref))
+                .collect(),
+        };
+
+        while slots
+            .last()
+            .is_some_and(|slot| !slot.is_empty() && slot.iter().all(is_open))
+        {
+            slots.pop();
+        }
+
+        slots
+            .into_iter()
+            .map(|slot| {
+                committed_version(slot, open_transactions)
+                    .into_iter()
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn latest_rows(&self) -> Vec<Option<TableDataRow>> {
+        let persisted_rows = self.persisted_rows.iter().flatten();
+
+        persisted_rows
+            .map(|slot| latest_version(slot).map(|version| version.row.clone()))
+            .chain(
+                self.pending_append_rows
+                    .iter()
+                    .map(|version| Some(version.row.clone())),
+            )
+            .collect()
+    }
+
+    // 버전을 고치려면 추가만 해 둔 행도 슬롯으로 옮기고 세그먼트 전체를 다시 써야 합니다.
+    fn merge_pending_rows(&mut self) {
+        let pending_rows = std::mem::take(&mut self.pending_append_rows);
+        self.pending_append_bytes.clear();
+
+        if !pending_rows.is_empty() {
+            let persisted_rows = self.persisted_rows.get_or_insert_with(Vec::new);
+            persisted_rows.extend(pending_rows.into_iter().map(|row| vec![row]));
+            self.persisted_row_count = Some(persisted_rows.len());
+            self.rewrite_required = true;
+        }
+    }
 }
 
-pub(crate) fn encode_row_frames(rows: &[Option<TableDataRow>]) -> errors::Result<Vec<u8>> {
-    let encoder = StorageEncoder::new();
-    let mut content = Vec::new();
+/// 지워지지 않은 최신 버전
+fn latest_version(slot: &RowSlot) -> Option<&RowVersion> {
+    slot.last().filter(|version| version.xmax.is_none())
+}
 
-    for row in rows {
-        match row {
-            Some(row) => {
-                content.push(ROW_FRAME_LIVE);
-                let len_offset = content.len();
-                content.extend_from_slice(&0u32.to_le_bytes());
-                encoder
-                    .encode_into(&mut content, row)
-                    .map_err(|error| ExecuteError::wrap(error.to_string()))?;
-                let frame_len = u32::try_from(content.len() - len_offset - size_of::<u32>())
-                    .map_err(|_| ExecuteError::wrap("row frame is too large".to_string()))?;
-                content[len_offset..len_offset + size_of::<u32>()]
-                    .copy_from_slice(&frame_len.to_le_bytes());
-            }
+/// 열린 트랜잭션이 만든 버전을 건너뛴 가장 새 버전. 열린 트랜잭션이 지운 버전은
+/// 지워지지 않은 것으로 봅니다.
+fn committed_version(slot: &[RowVersion], open_transactions: &HashSet<u64>) -> Option<RowVersion> {
+    let version = slot
+        .iter()
+        .rev()
+        .find(|version| !open_transactions.contains(&version.xmin))?;
+
+    match version.xmax {
+        Some(xmax) if !open_transactions.contains(&xmax) => None,
+        _ => Some(RowVersion {
+            xmax: None,
+            ..version.clone()
+        }),
+    }
+}
+
+/// 스냅샷에 보이는 버전. 만든 트랜잭션이 보이는 가장 새 버전을 고르고, 그 버전을 지운
+/// 트랜잭션까지 보이면 지워진 행입니다.
+fn visible_version<'a>(slot: &'a RowSlot, snapshot: &Snapshot) -> Option<&'a RowVersion> {
+    let version = slot
+        .iter()
+        .rev()
+        .find(|version| snapshot.sees(version.xmin))?;
+
+    match version.xmax {
+        Some(xmax) if snapshot.sees(xmax) => None,
+        _ => Some(version),
+    }
+}
+
+fn write_version(slot: &mut RowSlot, row: Option<TableDataRow>, transaction_id: u64) {
+    if transaction_id == FROZEN_TRANSACTION_ID {
+        *slot = row
+            .map(|row| {
+                vec![RowVersion {
+                    xmin: FROZEN_TRANSACTION_ID,
+                    xmax: None,
+                    row,
+                }]
+            })
+            .unwrap_or_default();
+        return;
+    }
+
+    match slot.last_mut() {
+        // 같은 트랜잭션이 만든 버전은 다른 스냅샷에 보이지 않으므로 제자리에서 고칩니다.
+        Some(version) if version.xmax.is_none() && version.xmin == transaction_id => match row {
+            Some(row) => version.row = row,
             None => {
-                content.push(ROW_FRAME_TOMBSTONE);
-                content.extend_from_slice(&0u32.to_le_bytes());
+                slot.pop();
+            }
+        },
+        Some(version) if version.xmax.is_none() => {
+            version.xmax = Some(transaction_id);
+            if let Some(row) = row {
+                slot.push(RowVersion {
+                    xmin: transaction_id,
+                    xmax: None,
+                    row,
+                });
             }
         }
+        _ => {
+            if let Some(row) = row {
+                slot.push(RowVersion {
+                    xmin: transaction_id,
+                    xmax: None,
+                    row,
+                });
+            }
+        }
+    }
+}
+
+pub(crate) fn encode_row_versions(rows: &[RowVersion]) -> errors::Result<Vec<u8>> {
+    let encoder = StorageEncoder::new();
+    let mut content = Vec::new();
+
+    for row in rows {
+        encode_row_frame(&encoder, &mut content, Some(row))?;
     }
 
     Ok(content)
 }
+
+/// 슬롯마다 최신 버전 하나만 씁니다. 지난 버전은 실행 중인 스냅샷만 필요로 하므로
+/// 재시작 뒤에는 쓸모가 없습니다.
+pub(crate) fn encode_row_slots(rows: &[RowSlot]) -> errors::Result<Vec<u8>> {
+    let encoder = StorageEncoder::new();
+    let mut content = Vec::new();
+
+    for slot in rows {
+        encode_row_frame(&encoder, &mut content, latest_version(slot))?;
+    }
+
+    Ok(content)
+}
+
+/// 앞에서부터 `frame_count`개 행 프레임의 바이트 길이
+fn frames_len(content: &[u8], frame_count: usize) -> errors::Result<usize> {
+    let mut offset = 0;
+
+    for _ in 0..frame_count {
+        let header = content
+            .get(offset + size_of::<u8>()..offset + size_of::<u8>() + size_of::<u32>())
+            .ok_or_else(|| ExecuteError::wrap("truncated row frame header".to_string()))?;
+        let frame_len = u32::from_le_bytes(
+            header
+                .try_into()
+                .map_err(|error| ExecuteError::wrap(format!("{:?}", error)))?,
+        ) as usize;
+        offset += size_of::<u8>() + size_of::<u32>() + frame_len;
+    }
+
+    Ok(offset)
+}
+
+fn encode_row_frame(
+    encoder: &StorageEncoder,
+    content: &mut Vec<u8>,
+    version: Option<&RowVersion>,
+) -> errors::Result<()> {
+    let Some(version) = version else {
+        content.push(ROW_FRAME_TOMBSTONE);
+        content.extend_from_slice(&0u32.to_le_bytes());
+        return Ok(());
+    };
+
+    content.push(ROW_FRAME_VERSIONED);
+    let len_offset = content.len();
+    content.extend_from_slice(&0u32.to_le_bytes());
+    content.extend_from_slice(&version.xmin.to_le_bytes());
+    content.extend_from_slice(&version.xmax.unwrap_or_default().to_le_bytes());
+    encoder
+        .encode_into(&mut *content, &version.row)
+        .map_err(|error| ExecuteError::wrap(error.to_string()))?;
+    let frame_len = u32::try_from(content.len() - len_offset - size_of::<u32>())
+        .map_err(|_| ExecuteError::wrap("row frame is too large".to_string()))?;
+    content[len_offset..len_offset + size_of::<u32>()].copy_from_slice(&frame_len.to_le_bytes());
+
+    Ok(())
+}
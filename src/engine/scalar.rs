@@ -0,0 +1,924 @@
+//! 스칼라 함수 라이브러리
+//!
+//! 문자열, 수학 함수와 조건 함수의 시그니처와 계산을 담당합니다.
+//! 시그니처는 reduce_type에서 인자 타입을 검사하고 결과 타입을 정하는 데 쓰이며,
+//! 실행할 때도 실제 값의 타입으로 같은 검사를 거칩니다.
+//!
+//! 인자 중 NULL이 있으면 결과도 NULL입니다. (CONCAT과 조건 함수 제외, PostgreSQL과 동일)
+
+use itertools::Itertools;
+
+use crate::engine::ast::types::{ConditionalFunction, ScalarFunction};
+use crate::engine::schema::row::TableDataFieldType;
+use crate::engine::types::ExecuteColumnType;
+use crate::errors;
+use crate::errors::type_error::TypeError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParameterType {
+    Any,
+    String,
+    Integer,
+    Numeric, // 정수 또는 실수
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ResultType {
+    Bool,
+    Integer,
+    Float,
+    String,
+    SameAsFirst, // 첫 번째 인자의 타입
+    Common,      // 모든 인자의 공통 타입
+}
+
+/// 함수 시그니처
+/// 필수 인자, 생략 가능한 인자, 개수 제한 없는 나머지 인자 순서로 받습니다.
+#[derive(Clone, Copy, Debug)]
+pub struct Signature {
+    pub parameters: &'static [ParameterType],
+    pub optional: &'static [ParameterType],
+    pub variadic: Option<ParameterType>,
+    pub result: ResultType,
+}
+
+impl Signature {
+    const fn fixed(parameters: &'static [ParameterType], result: ResultType) -> Self {
+        Self {
+            parameters,
+            optional: &[],
+            variadic: None,
+            result,
+        }
+    }
+
+    /// 인자 타입이 시그니처에 맞는지 확인하고 결과 타입을 반환합니다.
+    pub fn check(
+        &self,
+        name: &str,
+        argument_types: &[ExecuteColumnType],
+    ) -> errors::Result<ExecuteColumnType> {
+        let max_arguments = self.parameters.len() + self.optional.len();
+
+        let parameter_types = self.parameters.iter().chain(self.optional).copied();
+        let parameter_types = parameter_types.chain(std::iter::repeat_n(
+            self.variadic.unwrap_or(ParameterType::Any),
+            argument_types.len().saturating_sub(max_arguments),
+        ));
+
+        let matches = argument_types.len() >= self.parameters.len()
+            && (self.variadic.is_some() || argument_types.len() <= max_arguments)
+            && argument_types
+                .iter()
+                .zip(parameter_types)
+                .all(|(argument_type, parameter_type)| {
+                    matches!(
+                        (parameter_type, argument_type),
+                        (_, ExecuteColumnType::Null)
+                            | (ParameterType::Any, _)
+                            | (ParameterType::String, ExecuteColumnType::String)
+                            | (ParameterType::Integer, ExecuteColumnType::Integer)
+                            | (
+                                ParameterType::Numeric,
+                                ExecuteColumnType::Integer | ExecuteColumnType::Float
+                            )
+                    )
+                });
+
+        if !matches {
+            return Err(TypeError::wrap(format!(
+                "function {}({}) does not exist",
+                name,
+                argument_types
+                    .iter()
+                    .map(ExecuteColumnType::name)
+                    .join(", ")
+            )));
+        }
+
+        match self.result {
+            ResultType::Bool => Ok(ExecuteColumnType::Bool),
+            ResultType::Integer => Ok(ExecuteColumnType::Integer),
+            ResultType::Float => Ok(ExecuteColumnType::Float),
+            ResultType::String => Ok(ExecuteColumnType::String),
+            ResultType::SameAsFirst => Ok(argument_types
+                .first()
+                .cloned()
+                .unwrap_or(ExecuteColumnType::Null)),
+            ResultType::Common => argument_types.iter().cloned().try_fold(
+                ExecuteColumnType::Null,
+                |result_type, argument_type| {
+                    result_type
+                        .clone()
+                        .common_type(argument_type.clone())
+                        .ok_or_else(|| {
+                            TypeError::wrap(format!(
+                                "{} types {} and {} cannot be matched",
+                                name.to_uppercase(),
+                                result_type.name(),
+                                argument_type.name()
+                            ))
+                        })
+                },
+            ),
+        }
+    }
+}
+
+impl ScalarFunction {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ScalarFunction::Lower => "lower",
+            ScalarFunction::Upper => "upper",
+            ScalarFunction::Length => "length",
+            ScalarFunction::Substring => "substring",
+            ScalarFunction::Trim => "trim",
+            ScalarFunction::Replace => "replace",
+            ScalarFunction::Concat => "concat",
+            ScalarFunction::Position => "position",
+            ScalarFunction::SplitPart => "split_part",
+            ScalarFunction::Abs => "abs",
+            ScalarFunction::Round => "round",
+            ScalarFunction::Floor => "floor",
+            ScalarFunction::Ceil => "ceil",
+            ScalarFunction::Mod => "mod",
+            ScalarFunction::Power => "power",
+            ScalarFunction::Sqrt => "sqrt",
+            ScalarFunction::Random => "random",
+        }
+    }
+
+    pub fn signature(&self) -> Signature {
+        use ParameterType::{Any, Integer, Numeric, String};
+
+        match self {
+            ScalarFunction::Lower | ScalarFunction::Upper => {
+                Signature::fixed(&[String], ResultType::String)
+            }
+            ScalarFunction::Length => Signature::fixed(&[String], ResultType::Integer),
+            // SUBSTRING(문자열, 시작 위치 [, 길이])
+            ScalarFunction::Substring => Signature {
+                parameters: &[String, Integer],
+                optional: &[Integer],
+                variadic: None,
+                result: ResultType::String,
+            },
+            // TRIM(문자열 [, 제거할 문자들])
+            ScalarFunction::Trim => Signature {
+                parameters: &[String],
+                optional: &[String],
+                variadic: None,
+                result: ResultType::String,
+            },
+            ScalarFunction::Replace => {
+                Signature::fixed(&[String, String, String], ResultType::String)
+            }
+            ScalarFunction::Concat => Signature {
+                parameters: &[Any],
+                optional: &[],
+                variadic: Some(Any),
+                result: ResultType::String,
+            },
+            // POSITION(찾을 문자열, 문자열), POSITION(찾을 문자열 IN 문자열)
+            ScalarFunction::Position => Signature::fixed(&[String, String], ResultType::Integer),
+            ScalarFunction::SplitPart => {
+                Signature::fixed(&[String, String, Integer], ResultType::String)
+            }
+            ScalarFunction::Abs | ScalarFunction::Floor | ScalarFunction::Ceil => {
+                Signature::fixed(&[Numeric], ResultType::SameAsFirst)
+            }
+            // ROUND(수 [, 소수점 자리수])
+            ScalarFunction::Round => Signature {
+                parameters: &[Numeric],
+                optional: &[Integer],
+                variadic: None,
+                result: ResultType::SameAsFirst,
+            },
+            ScalarFunction::Mod => Signature::fixed(&[Numeric, Numeric], ResultType::Common),
+            ScalarFunction::Power => Signature::fixed(&[Numeric, Numeric], ResultType::Float),
+            ScalarFunction::Sqrt => Signature::fixed(&[Numeric], ResultType::Float),
+            ScalarFunction::Random => Signature::fixed(&[], ResultType::Float),
+        }
+    }
+
+    /// 함수를 계산합니다. 인자 타입은 시그니처로 검사한 뒤 계산합니다.
+    pub fn call(&self, arguments: Vec<TableDataFieldType>) -> errors::Result<TableDataFieldType> {
+        let argument_types = arguments
+            .iter()
+            .map(ExecuteColumnType::from)
+            .collect::<Vec<_>>();
+
+        let result_type = self.signature().check(self.name(), &argument_types)?;
+
+        if *self == ScalarFunction::Concat {
+            return Ok(TableDataFieldType::String(
+                arguments
+                    .iter()
+                    .filter(|argument| !argument.is_null())
+                    .map(|argument| argument.to_string())
+                    .collect(),
+            ));
+        }
+
+        if arguments.iter().any(TableDataFieldType::is_null) {
+            return Ok(TableDataFieldType::Null);
+        }
+
+        // 공통 타입이 실수라면 정수 인자도 실수로 바꿔서 계산합니다.
+        let arguments = if self.signature().result == ResultType::Common
+            && result_type == ExecuteColumnType::Float
+        {
+            arguments
+                .into_iter()
+                .map(|argument| match argument {
+                    TableDataFieldType::Integer(value) => {
+                        TableDataFieldType::Float((value as f64).into())
+                    }
+                    argument => argument,
+                })
+                .collect()
+        } else {
+            arguments
+        };
+
+        let mut arguments = arguments.into_iter();
+        let mut next = || arguments.next();
+
+        match self {
+            ScalarFunction::Lower => Ok(TableDataFieldType::String(
+                expect_string(next())?.to_lowercase(),
+            )),
+            ScalarFunction::Upper => Ok(TableDataFieldType::String(
+                expect_string(next())?.to_uppercase(),
+            )),
+            ScalarFunction::Length => Ok(TableDataFieldType::Integer(
+                expect_string(next())?.chars().count() as i64,
+            )),
+            ScalarFunction::Substring => {
+                let value = expect_string(next())?;
+                let start = expect_integer(next())?;
+                let length = next()
+                    .map(|length| expect_integer(Some(length)))
+                    .transpose()?;
+
+                substring(&value, start, length).map(TableDataFieldType::String)
+            }
+            ScalarFunction::Trim => {
+                let value = expect_string(next())?;
+                let characters = match next() {
+                    Some(characters) => expect_string(Some(characters))?,
+                    None => " ".to_owned(),
+                };
+
+                Ok(TableDataFieldType::String(
+                    value.trim_matches(|c| characters.contains(c)).to_owned(),
+                ))
+            }
+            ScalarFunction::Replace => {
+                let value = expect_string(next())?;
+                let from = expect_string(next())?;
+                let to = expect_string(next())?;
+
+                // 빈 문자열을 바꾸면 글자 사이마다 끼워넣게 되므로 그대로 둡니다.
+                if from.is_empty() {
+                    return Ok(TableDataFieldType::String(value));
+                }
+
+                Ok(TableDataFieldType::String(value.replace(&from, &to)))
+            }
+            ScalarFunction::Concat => unreachable!(),
+            ScalarFunction::Position => {
+                let needle = expect_string(next())?;
+                let value = expect_string(next())?;
+
+                // 위치는 1부터 세는 글자 위치이며, 없으면 0입니다.
+                let position = value
+                    .find(&needle)
+                    .map(|byte_index| value[..byte_index].chars().count() as i64 + 1)
+                    .unwrap_or(0);
+
+                Ok(TableDataFieldType::Integer(position))
+            }
+            ScalarFunction::SplitPart => {
+                let value = expect_string(next())?;
+                let delimiter = expect_string(next())?;
+                let field = expect_integer(next())?;
+
+                split_part(&value, &delimiter, field).map(TableDataFieldType::String)
+            }
+            ScalarFunction::Abs => match next() {
+                Some(TableDataFieldType::Integer(value)) => value
+                    .checked_abs()
+                    .map(TableDataFieldType::Integer)
+                    .ok_or_else(|| TypeError::wrap("integer out of range")),
+                Some(TableDataFieldType::Float(value)) => {
+                    Ok(TableDataFieldType::Float(f64::from(value).abs().into()))
+                }
+                _ => unreachable!(),
+            },
+            ScalarFunction::Round => {
+                let value = next();
+                let digits = next()
+                    .map(|digits| expect_integer(Some(digits)))
+                    .transpose()?;
+
+                round(value, digits.unwrap_or(0))
+            }
+            ScalarFunction::Floor | ScalarFunction::Ceil => match next() {
+                Some(TableDataFieldType::Float(value)) => {
+                    let value = f64::from(value);
+                    let value = if *self == ScalarFunction::Floor {
+                        value.floor()
+                    } else {
+                        value.ceil()
+                    };
+
+                    Ok(TableDataFieldType::Float(value.into()))
+                }
+                // 정수는 그대로입니다.
+                Some(value) => Ok(value),
+                None => unreachable!(),
+            },
+            ScalarFunction::Mod => match (next(), next()) {
+                (Some(TableDataFieldType::Integer(_)), Some(TableDataFieldType::Integer(0))) => {
+                    Err(TypeError::wrap("division by zero"))
+                }
+                (
+                    Some(TableDataFieldType::Integer(lhs)),
+                    Some(TableDataFieldType::Integer(rhs)),
+                ) => Ok(TableDataFieldType::Integer(lhs.wrapping_rem(rhs))),
+                (Some(TableDataFieldType::Float(lhs)), Some(TableDataFieldType::Float(rhs))) => {
+                    if f64::from(rhs) == 0.0 {
+                        return Err(TypeError::wrap("division by zero"));
+                    }
+
+                    Ok(TableDataFieldType::Float(
+                        (f64::from(lhs) % f64::from(rhs)).into(),
+                    ))
+                }
+                _ => unreachable!(),
+            },
+            ScalarFunction::Power => {
+                let base = expect_float(next())?;
+                let exponent = expect_float(next())?;
+
+                if base == 0.0 && exponent < 0.0 {
+                    return Err(TypeError::wrap(
+                        "zero raised to a negative power is undefined",
+                    ));
+                }
+
+                if base < 0.0 && exponent.fract() != 0.0 {
+                    return Err(TypeError::wrap(
+                        "a negative number raised to a non-integer power yields a complex result",
+                    ));
+                }
+
+                Ok(TableDataFieldType::Float(base.powf(exponent).into()))
+            }
+            ScalarFunction::Sqrt => {
+                let value = expect_float(next())?;
+
+                if value < 0.0 {
+                    return Err(TypeError::wrap(
+                        "cannot take square root of a negative number",
+                    ));
+                }
+
+                Ok(TableDataFieldType::Float(value.sqrt().into()))
+            }
+            ScalarFunction::Random => Ok(TableDataFieldType::Float(random().into())),
+        }
+    }
+}
+
+impl ConditionalFunction {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ConditionalFunction::NullIf => "nullif",
+            ConditionalFunction::Coalesce => "coalesce",
+            ConditionalFunction::Greatest => "greatest",
+            ConditionalFunction::Least => "least",
+        }
+    }
+
+    pub fn signature(&self) -> Signature {
+        match self {
+            // NULLIF(값, 비교 값): 두 값이 같으면 NULL, 아니면 첫 번째 값
+            ConditionalFunction::NullIf => Signature::fixed(
+                &[ParameterType::Any, ParameterType::Any],
+                ResultType::SameAsFirst,
+            ),
+            ConditionalFunction::Coalesce
+            | ConditionalFunction::Greatest
+            | ConditionalFunction::Least => Signature {
+                parameters: &[ParameterType::Any],
+                optional: &[],
+                variadic: Some(ParameterType::Any),
+                result: ResultType::Common,
+            },
+        }
+    }
+
+    /// 이미 계산된 인자 값들로 결과를 구합니다. (COALESCE의 지연 평가는 호출하는 쪽에서 처리합니다)
+    pub fn call(&self, arguments: Vec<TableDataFieldType>) -> errors::Result<TableDataFieldType> {
+        let argument_types = arguments
+            .iter()
+            .map(ExecuteColumnType::from)
+            .collect::<Vec<_>>();
+
+        let result_type = self.signature().check(self.name(), &argument_types)?;
+
+        // 공통 타입이 실수라면 정수 값도 실수로 맞춥니다.
+        let widen = |value: TableDataFieldType| match value {
+            TableDataFieldType::Integer(value) if result_type == ExecuteColumnType::Float => {
+                TableDataFieldType::Float((value as f64).into())
+            }
+            value => value,
+        };
+
+        let mut arguments = arguments.into_iter().map(widen);
+
+        match self {
+            ConditionalFunction::NullIf => {
+                let value = arguments.next().unwrap_or(TableDataFieldType::Null);
+                let other = arguments.next().unwrap_or(TableDataFieldType::Null);
+
+                if !value.is_null() && !other.is_null() && value.type_code() != other.type_code() {
+                    return Err(TypeError::wrap(format!(
+                        "operator does not exist: {} = {}",
+                        ExecuteColumnType::from(&value).name(),
+                        ExecuteColumnType::from(&other).name()
+                    )));
+                }
+
+                if !value.is_null() && value == other {
+                    Ok(TableDataFieldType::Null)
+                } else {
+                    Ok(value)
+                }
+            }
+            ConditionalFunction::Coalesce => Ok(arguments
+                .find(|argument| !argument.is_null())
+                .unwrap_or(TableDataFieldType::Null)),
+            // NULL은 무시하고, 모두 NULL이면 NULL입니다.
+            ConditionalFunction::Greatest => Ok(arguments
+                .filter(|argument| !argument.is_null())
+                .max_by(|lhs, rhs| lhs.partial_cmp(rhs).unwrap_or(std::cmp::Ordering::Equal))
+                .unwrap_or(TableDataFieldType::Null)),
+            ConditionalFunction::Least => Ok(arguments
+                .filter(|argument| !argument.is_null())
+                .min_by(|lhs, rhs| lhs.partial_cmp(rhs).unwrap_or(std::cmp::Ordering::Equal))
+                .unwrap_or(TableDataFieldType::Null)),
+        }
+    }
+}
+
+fn expect_string(value: Option<TableDataFieldType>) -> errors::Result<String> {
+    match value {
+        Some(TableDataFieldType::String(value)) => Ok(value),
+        value => Err(TypeError::wrap(format!(
+            "expected string. but got {:?}",
+            value
+        ))),
+    }
+}
+
+fn expect_integer(value: Option<TableDataFieldType>) -> errors::Result<i64> {
+    match value {
+        Some(TableDataFieldType::Integer(value)) => Ok(value),
+        value => Err(TypeError::wrap(format!(
+            "expected integer. but got {:?}",
+            value
+        ))),
+    }
+}
+
+fn expect_float(value: Option<TableDataFieldType>) -> errors::Result<f64> {
+    match value {
+        Some(TableDataFieldType::Float(value)) => Ok(f64::from(value)),
+        Some(TableDataFieldType::Integer(value)) => Ok(value as f64),
+        value => Err(TypeError::wrap(format!(
+            "expected number. but got {:?}",
+            value
+        ))),
+    }
+}
+
+// 1부터 세는 시작 위치에서 length 글자를 잘라냅니다.
+// 시작 위치가 1보다 작으면 그만큼 앞의 없는 글자도 길이에 포함됩니다. (PostgreSQL과 동일)
+fn substring(value: &str, start: i64, length: Option<i64>) -> errors::Result<String> {
+    let end = match length {
+        Some(length) if length < 0 => {
+            return Err(TypeError::wrap("negative substring length not allowed"));
+        }
+        Some(length) => Some(start.saturating_add(length)),
+        None => None,
+    };
+
+    Ok(value
+        .chars()
+        .zip(1_i64..)
+        .filter(|(_, position)| *position >= start && end.is_none_or(|end| *position < end))
+        .map(|(c, _)| c)
+        .collect())
+}
+
+// 구분자로 나눈 field번째 조각 (1부터, 음수면 뒤에서부터)
+fn split_part(value: &str, delimiter: &str, field: i64) -> errors::Result<String> {
+    if field == 0 {
+        return Err(TypeError::wrap("field position must not be zero"));
+    }
+
+    let parts = if delimiter.is_empty() {
+        vec![value]
+    } else {
+        value.split(delimiter).collect::<Vec<_>>()
+    };
+
+    let index = if field > 0 {
+        usize::try_from(field - 1).ok()
+    } else {
+        usize::try_from(-field)
+            .ok()
+            .and_then(|from_end| parts.len().checked_sub(from_end))
+    };
+
+    Ok(index
+        .and_then(|index| parts.get(index))
+        .map(|part| part.to_string())
+        .unwrap_or_default())
+}
+
+// 소수점 digits 자리로 반올림합니다. (0.5는 0에서 먼 쪽으로)
+// 음수 자리수는 정수 부분을 반올림합니다. round(1234, -2) = 1200
+fn round(value: Option<TableDataFieldType>, digits: i64) -> errors::Result<TableDataFieldType> {
+    match value {
+        Some(TableDataFieldType::Float(value)) => {
+            let value = f64::from(value);
+            let scale = 10_f64.powi(digits.clamp(-308, 308) as i32);
+            let rounded = (value * scale).round() / scale;
+
+            // 자리수가 너무 커서 계산이 넘치면 원래 값을 그대로 둡니다.
+            Ok(TableDataFieldType::Float(
+                if rounded.is_finite() { rounded } else { value }.into(),
+            ))
+        }
+        Some(TableDataFieldType::Integer(value)) if digits >= 0 => {
+            Ok(TableDataFieldType::Integer(value))
+        }
+        Some(TableDataFieldType::Integer(value)) => {
+            let Some(scale) = u32::try_from(-digits)
+                .ok()
+                .and_then(|exponent| 10_i64.checked_pow(exponent))
+            else {
+                return Ok(TableDataFieldType::Integer(0));
+            };
+
+            let remainder = value % scale;
+            let truncated = value - remainder;
+
+            let rounded = if remainder.abs() * 2 >= scale {
+                truncated.checked_add(remainder.signum() * scale)
+            } else {
+                Some(truncated)
+            };
+
+            rounded
+                .map(TableDataFieldType::Integer)
+                .ok_or_else(|| TypeError::wrap("integer out of range"))
+        }
+        value => Err(TypeError::wrap(format!(
+            "expected number. but got {:?}",
+            value
+        ))),
+    }
+}
+
+// [0, 1) 범위의 난수
+// 별도 난수 의존성 없이 UUID v4의 무작위 상위 48비트를 사용합니다.
+fn random() -> f64 {
+    let bits = uuid::Uuid::new_v4().as_u128() >> 80;
+
+    bits as f64 / (1_u64 << 48) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn string(value: &str) -> TableDataFieldType {
+        TableDataFieldType::String(value.into())
+    }
+
+    #[test]
+    fn test_signature_check() {
+        let signature = ScalarFunction::Substring.signature();
+
+        assert_eq!(
+            signature
+                .check(
+                    "substring",
+                    &[ExecuteColumnType::String, ExecuteColumnType::Integer]
+                )
+                .ok(),
+            Some(ExecuteColumnType::String)
+        );
+        assert_eq!(
+            signature
+                .check(
+                    "substring",
+                    &[ExecuteColumnType::String, ExecuteColumnType::Null]
+                )
+                .ok(),
+            Some(ExecuteColumnType::String)
+        );
+        assert!(
+            signature
+                .check("substring", &[ExecuteColumnType::String])
+                .is_err()
+        );
+        assert!(
+            signature
+                .check(
+                    "substring",
+                    &[ExecuteColumnType::Integer, ExecuteColumnType::Integer]
+                )
+                .unwrap_err()
+                .to_string()
+                .contains("function substring(integer, integer) does not exist")
+        );
+
+        let signature = ConditionalFunction::Coalesce.signature();
+        assert_eq!(
+            signature
+                .check(
+                    "coalesce",
+                    &[
+                        ExecuteColumnType::Null,
+                        ExecuteColumnType::Integer,
+                        ExecuteColumnType::Float
+                    ]
+                )
+                .ok(),
+            Some(ExecuteColumnType::Float)
+        );
+        assert!(
+            signature
+                .check(
+                    "coalesce",
+                    &[ExecuteColumnType::Integer, ExecuteColumnType::String]
+                )
+                .unwrap_err()
+                .to_string()
+                .contains("COALESCE types integer and varchar cannot be matched")
+        );
+    }
+
+    #[test]
+    fn test_string_functions() {
+        let test_cases = vec![
+            (ScalarFunction::Lower, vec![string("AbC")], string("abc")),
+            (ScalarFunction::Upper, vec![string("AbC")], string("ABC")),
+            (
+                ScalarFunction::Length,
+                vec![string("한글ab")],
+                TableDataFieldType::Integer(4),
+            ),
+            (
+                ScalarFunction::Substring,
+                vec![string("hello"), TableDataFieldType::Integer(2)],
+                string("ello"),
+            ),
+            (
+                ScalarFunction::Substring,
+                vec![
+                    string("hello"),
+                    TableDataFieldType::Integer(0),
+                    TableDataFieldType::Integer(3),
+                ],
+                string("he"),
+            ),
+            (ScalarFunction::Trim, vec![string("  a b  ")], string("a b")),
+            (
+                ScalarFunction::Trim,
+                vec![string("xxaxyx"), string("xy")],
+                string("a"),
+            ),
+            (
+                ScalarFunction::Replace,
+                vec![string("abcabc"), string("b"), string("")],
+                string("acac"),
+            ),
+            (
+                ScalarFunction::Replace,
+                vec![string("abc"), string(""), string("x")],
+                string("abc"),
+            ),
+            (
+                ScalarFunction::Concat,
+                vec![
+                    string("a"),
+                    TableDataFieldType::Null,
+                    TableDataFieldType::Integer(1),
+                    TableDataFieldType::Boolean(true),
+                ],
+                string("a1true"),
+            ),
+            (
+                ScalarFunction::Position,
+                vec![string("글"), string("한글")],
+                TableDataFieldType::Integer(2),
+            ),
+            (
+                ScalarFunction::Position,
+                vec![string("z"), string("abc")],
+                TableDataFieldType::Integer(0),
+            ),
+            (
+                ScalarFunction::SplitPart,
+                vec![string("a,b,c"), string(","), TableDataFieldType::Integer(2)],
+                string("b"),
+            ),
+            (
+                ScalarFunction::SplitPart,
+                vec![
+                    string("a,b,c"),
+                    string(","),
+                    TableDataFieldType::Integer(-1),
+                ],
+                string("c"),
+            ),
+            (
+                ScalarFunction::SplitPart,
+                vec![string("a,b,c"), string(","), TableDataFieldType::Integer(5)],
+                string(""),
+            ),
+            (
+                ScalarFunction::Lower,
+                vec![TableDataFieldType::Null],
+                TableDataFieldType::Null,
+            ),
+        ];
+
+        for (function, arguments, expected) in test_cases {
+            assert_eq!(
+                function.call(arguments.clone()).ok(),
+                Some(expected),
+                "{:?}({:?})",
+                function,
+                arguments
+            );
+        }
+    }
+
+    #[test]
+    fn test_math_functions() {
+        let float = |value: f64| TableDataFieldType::Float(value.into());
+        let integer = TableDataFieldType::Integer;
+
+        let test_cases = vec![
+            (ScalarFunction::Abs, vec![integer(-3)], integer(3)),
+            (ScalarFunction::Abs, vec![float(-1.5)], float(1.5)),
+            (ScalarFunction::Round, vec![float(2.5)], float(3.0)),
+            (ScalarFunction::Round, vec![float(-2.5)], float(-3.0)),
+            (
+                ScalarFunction::Round,
+                vec![float(1.23456), integer(2)],
+                float(1.23),
+            ),
+            (
+                ScalarFunction::Round,
+                vec![integer(1250), integer(-2)],
+                integer(1300),
+            ),
+            (
+                ScalarFunction::Round,
+                vec![integer(-1249), integer(-2)],
+                integer(-1200),
+            ),
+            (ScalarFunction::Floor, vec![float(-1.5)], float(-2.0)),
+            (ScalarFunction::Ceil, vec![float(1.2)], float(2.0)),
+            (ScalarFunction::Ceil, vec![integer(7)], integer(7)),
+            (
+                ScalarFunction::Mod,
+                vec![integer(-7), integer(3)],
+                integer(-1),
+            ),
+            (
+                ScalarFunction::Mod,
+                vec![integer(7), float(2.5)],
+                float(2.0),
+            ),
+            (
+                ScalarFunction::Power,
+                vec![integer(2), integer(10)],
+                float(1024.0),
+            ),
+            (ScalarFunction::Sqrt, vec![integer(16)], float(4.0)),
+        ];
+
+        for (function, arguments, expected) in test_cases {
+            assert_eq!(
+                function.call(arguments.clone()).ok(),
+                Some(expected),
+                "{:?}({:?})",
+                function,
+                arguments
+            );
+        }
+
+        for (function, arguments) in [
+            (ScalarFunction::Abs, vec![integer(i64::MIN)]),
+            (ScalarFunction::Mod, vec![integer(1), integer(0)]),
+            (ScalarFunction::Sqrt, vec![integer(-1)]),
+            (ScalarFunction::Power, vec![integer(0), integer(-1)]),
+            (ScalarFunction::Power, vec![integer(-8), float(0.5)]),
+            (ScalarFunction::Abs, vec![string("1")]),
+        ] {
+            assert!(
+                function.call(arguments.clone()).is_err(),
+                "{:?}({:?})",
+                function,
+                arguments
+            );
+        }
+
+        for _ in 0..100 {
+            let Ok(TableDataFieldType::Float(value)) = ScalarFunction::Random.call(vec![]) else {
+                panic!("random() must return float");
+            };
+            assert!((0.0..1.0).contains(&f64::from(value)));
+        }
+    }
+
+    #[test]
+    fn test_conditional_functions() {
+        let integer = TableDataFieldType::Integer;
+        let null = TableDataFieldType::Null;
+
+        let test_cases = vec![
+            (
+                ConditionalFunction::NullIf,
+                vec![integer(1), integer(1)],
+                null.clone(),
+            ),
+            (
+                ConditionalFunction::NullIf,
+                vec![integer(1), integer(2)],
+                integer(1),
+            ),
+            (
+                ConditionalFunction::NullIf,
+                vec![null.clone(), integer(2)],
+                null.clone(),
+            ),
+            (
+                ConditionalFunction::Coalesce,
+                vec![null.clone(), integer(2), integer(3)],
+                integer(2),
+            ),
+            (
+                ConditionalFunction::Coalesce,
+                vec![null.clone(), null.clone()],
+                null.clone(),
+            ),
+            (
+                ConditionalFunction::Greatest,
+                vec![integer(1), null.clone(), integer(3)],
+                integer(3),
+            ),
+            (
+                ConditionalFunction::Least,
+                vec![integer(1), TableDataFieldType::Float(0.5.into())],
+                TableDataFieldType::Float(0.5.into()),
+            ),
+            (
+                ConditionalFunction::Greatest,
+                vec![string("a"), string("b")],
+                string("b"),
+            ),
+        ];
+
+        for (function, arguments, expected) in test_cases {
+            assert_eq!(
+                function.call(arguments.clone()).ok(),
+                Some(expected),
+                "{:?}({:?})",
+                function,
+                arguments
+            );
+        }
+
+        assert!(
+            ConditionalFunction::NullIf
+                .call(vec![integer(1), string("1")])
+                .is_err()
+        );
+        assert!(
+            ConditionalFunction::Greatest
+                .call(vec![integer(1), string("1")])
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+
+use crate::engine::ast::{
+    ddl::create_function::{CreateFunctionQuery, FunctionParameter},
+    types::{DataType, UserDefinedFunction},
+};
+
+// 사용자 정의 함수의 저장 형태입니다. (데이터베이스 경로의 functions/{함수명}/function.config)
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct FunctionSchema {
+    pub function: UserDefinedFunction,
+    pub parameters: Vec<FunctionParameter>,
+    pub return_type: DataType,
+    pub body: String,
+}
+
+impl FunctionSchema {
+    // 본문의 매개변수 참조(이름 또는 $n)가 몇번째 인자인지 찾습니다.
+    pub fn parameter_index(&self, name: &str) -> Option<usize> {
+        if let Some(position) = name.strip_prefix('$') {
+            return position
+                .parse::<usize>()
+                .ok()
+                .filter(|position| (1..=self.parameters.len()).contains(position))
+                .map(|position| position - 1);
+        }
+
+        self.parameters
+            .iter()
+            .position(|parameter| parameter.name.as_deref() == Some(name))
+    }
+
+    pub fn parameter_types(&self) -> Vec<DataType> {
+        self.parameters
+            .iter()
+            .map(|parameter| parameter.data_type.clone())
+            .collect()
+    }
+
+    // 오류 메시지용 시그니처 표기. add_one(integer, varchar(10))
+    pub fn signature(&self) -> String {
+        format!(
+            "{}({})",
+            self.function.function_name,
+            self.parameter_types()
+                .into_iter()
+                .map(String::from)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+impl From<CreateFunctionQuery> for FunctionSchema {
+    fn from(query: CreateFunctionQuery) -> Self {
+        Self {
+            function: query.function,
+            parameters: query.parameters,
+            return_type: query.return_type,
+            body: query.body,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_function_schema_parameter_index() {
+        let schema = FunctionSchema {
+            function: UserDefinedFunction {
+                database_name: Some("db".into()),
+                function_name: "f".into(),
+            },
+            parameters: vec![
+                FunctionParameter {
+                    name: Some("a".into()),
+                    data_type: DataType::Int,
+                },
+                FunctionParameter {
+                    name: None,
+                    data_type: DataType::Varchar(10),
+                },
+            ],
+            return_type: DataType::Int,
+            body: "SELECT a".into(),
+        };
+
+        assert_eq!(schema.parameter_index("a"), Some(0));
+        assert_eq!(schema.parameter_index("$1"), Some(0));
+        assert_eq!(schema.parameter_index("$2"), Some(1));
+        assert_eq!(schema.parameter_index("$3"), None);
+        assert_eq!(schema.parameter_index("$0"), None);
+        assert_eq!(schema.parameter_index("b"), None);
+        assert_eq!(schema.signature(), "f(integer, varchar(10))");
+    }
+}
@@ -1,3 +1,4 @@
 pub mod database;
+pub mod function;
 pub mod row;
 pub mod table;
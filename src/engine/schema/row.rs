@@ -1,7 +1,9 @@
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 
-use crate::engine::ast::types::TableName;
+use crate::engine::ast::types::{DataType, TableName};
+use crate::errors;
+use crate::errors::type_error::TypeError;
 use crate::utils::float::Float64;
 
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq, PartialOrd, Eq, Hash)]
@@ -46,6 +48,127 @@ impl TableDataFieldType {
     pub fn is_array(&self) -> bool {
         self.type_code() == 5
     }
+
+    /// 컬럼에 저장하기 위한 할당 변환 (INSERT ... SELECT 등)
+    /// 숫자끼리는 서로 변환되고, 모든 값은 문자열 컬럼에 저장될 수 있습니다.
+    /// 변환할 수 없으면 None을 반환합니다.
+    pub fn assign_to(self, data_type: &DataType) -> Option<Self> {
+        match (self, data_type) {
+            (TableDataFieldType::Null, _) => Some(TableDataFieldType::Null),
+            (TableDataFieldType::Array(_), _) => None,
+            (TableDataFieldType::Float(value), DataType::Int) => {
+                let value = f64::from(value).round();
+
+                if value.is_finite() && value >= i64::MIN as f64 && value <= i64::MAX as f64 {
+                    Some(TableDataFieldType::Integer(value as i64))
+                } else {
+                    None
+                }
+            }
+            (TableDataFieldType::Integer(value), DataType::Float) => {
+                Some(TableDataFieldType::Float((value as f64).into()))
+            }
+            (value, DataType::Varchar(_)) => Some(TableDataFieldType::String(value.to_string())),
+            (value, data_type) if value.type_code() == data_type.type_code() => Some(value),
+            _ => None,
+        }
+    }
+
+    /// 명시적 형변환 (CAST(... AS type), ...::type)
+    /// 할당 변환보다 넓어서 문자열 파싱과 정수/논리값 사이의 변환도 허용합니다.
+    /// PostgreSQL처럼 실수와 논리값 사이의 변환은 허용하지 않습니다.
+    pub fn cast_to(self, data_type: &DataType) -> errors::Result<Self> {
+        match (self, data_type) {
+            (TableDataFieldType::Null, _) => Ok(TableDataFieldType::Null),
+            (TableDataFieldType::Array(array), data_type) => Ok(TableDataFieldType::Array(
+                array
+                    .into_iter()
+                    .map(|e| e.cast_to(data_type))
+                    .collect::<errors::Result<Vec<_>>>()?,
+            )),
+            (TableDataFieldType::Float(value), DataType::Int) => {
+                let value = f64::from(value).round();
+
+                if value.is_finite() && value >= i64::MIN as f64 && value <= i64::MAX as f64 {
+                    Ok(TableDataFieldType::Integer(value as i64))
+                } else {
+                    Err(TypeError::wrap("integer out of range"))
+                }
+            }
+            (TableDataFieldType::Integer(value), DataType::Float) => {
+                Ok(TableDataFieldType::Float((value as f64).into()))
+            }
+            (TableDataFieldType::Integer(value), DataType::Boolean) => {
+                Ok(TableDataFieldType::Boolean(value != 0))
+            }
+            (TableDataFieldType::Boolean(value), DataType::Int) => {
+                Ok(TableDataFieldType::Integer(value as i64))
+            }
+            (TableDataFieldType::String(value), DataType::Int) => value
+                .trim()
+                .parse::<i64>()
+                .map(TableDataFieldType::Integer)
+                .map_err(|_| {
+                    TypeError::wrap(format!(
+                        "invalid input syntax for type integer: \"{}\"",
+                        value
+                    ))
+                }),
+            (TableDataFieldType::String(value), DataType::Float) => value
+                .trim()
+                .parse::<f64>()
+                .map(|parsed| TableDataFieldType::Float(parsed.into()))
+                .map_err(|_| {
+                    TypeError::wrap(format!(
+                        "invalid input syntax for type float: \"{}\"",
+                        value
+                    ))
+                }),
+            (TableDataFieldType::String(value), DataType::Boolean) => {
+                match value.trim().to_lowercase().as_str() {
+                    "t" | "true" | "y" | "yes" | "on" | "1" => {
+                        Ok(TableDataFieldType::Boolean(true))
+                    }
+                    "f" | "false" | "n" | "no" | "off" | "0" => {
+                        Ok(TableDataFieldType::Boolean(false))
+                    }
+                    _ => Err(TypeError::wrap(format!(
+                        "invalid input syntax for type boolean: \"{}\"",
+                        value
+                    ))),
+                }
+            }
+            (value, DataType::Varchar(length)) => {
+                let value = value.to_string();
+
+                // 길이 제한보다 긴 문자열은 PostgreSQL처럼 잘라냅니다.
+                match usize::try_from(*length) {
+                    Ok(length) if value.chars().count() > length => Ok(TableDataFieldType::String(
+                        value.chars().take(length).collect(),
+                    )),
+                    _ => Ok(TableDataFieldType::String(value)),
+                }
+            }
+            (value, data_type) if value.type_code() == data_type.type_code() => Ok(value),
+            (value, data_type) => Err(TypeError::wrap(format!(
+                "cannot cast type {} to {}",
+                value.type_name(),
+                String::from(data_type.to_owned())
+            ))),
+        }
+    }
+
+    // 오류 메세지에 쓰는 값의 타입 이름
+    fn type_name(&self) -> &'static str {
+        match self {
+            TableDataFieldType::Integer(_) => "integer",
+            TableDataFieldType::Float(_) => "float",
+            TableDataFieldType::Boolean(_) => "boolean",
+            TableDataFieldType::String(_) => "varchar",
+            TableDataFieldType::Array(_) => "array",
+            TableDataFieldType::Null => "unknown",
+        }
+    }
 }
 
 impl ToString for TableDataFieldType {
@@ -91,7 +214,118 @@ impl TableDataField {
     }
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 pub struct TableDataRow {
     pub fields: Vec<TableDataField>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::TableDataFieldType;
+    use crate::engine::ast::types::DataType;
+
+    #[test]
+    fn test_cast_to() {
+        let test_cases = vec![
+            (
+                TableDataFieldType::Null,
+                DataType::Int,
+                Some(TableDataFieldType::Null),
+            ),
+            (
+                TableDataFieldType::Integer(3),
+                DataType::Float,
+                Some(TableDataFieldType::Float(3.0.into())),
+            ),
+            (
+                TableDataFieldType::Float(2.5.into()),
+                DataType::Int,
+                Some(TableDataFieldType::Integer(3)),
+            ),
+            (
+                TableDataFieldType::Float(f64::INFINITY.into()),
+                DataType::Int,
+                None,
+            ),
+            (
+                TableDataFieldType::Integer(0),
+                DataType::Boolean,
+                Some(TableDataFieldType::Boolean(false)),
+            ),
+            (
+                TableDataFieldType::Boolean(true),
+                DataType::Int,
+                Some(TableDataFieldType::Integer(1)),
+            ),
+            (TableDataFieldType::Boolean(true), DataType::Float, None),
+            (
+                TableDataFieldType::Float(1.0.into()),
+                DataType::Boolean,
+                None,
+            ),
+            (
+                TableDataFieldType::String(" 42 ".into()),
+                DataType::Int,
+                Some(TableDataFieldType::Integer(42)),
+            ),
+            (TableDataFieldType::String("4x".into()), DataType::Int, None),
+            (
+                TableDataFieldType::String("1.5".into()),
+                DataType::Float,
+                Some(TableDataFieldType::Float(1.5.into())),
+            ),
+            (
+                TableDataFieldType::String("Yes".into()),
+                DataType::Boolean,
+                Some(TableDataFieldType::Boolean(true)),
+            ),
+            (
+                TableDataFieldType::String("maybe".into()),
+                DataType::Boolean,
+                None,
+            ),
+            (
+                TableDataFieldType::Boolean(false),
+                DataType::Varchar(i64::MAX),
+                Some(TableDataFieldType::String("false".into())),
+            ),
+            (
+                TableDataFieldType::String("abcdef".into()),
+                DataType::Varchar(3),
+                Some(TableDataFieldType::String("abc".into())),
+            ),
+            (
+                TableDataFieldType::Array(vec![
+                    TableDataFieldType::String("1".into()),
+                    TableDataFieldType::Null,
+                ]),
+                DataType::Int,
+                Some(TableDataFieldType::Array(vec![
+                    TableDataFieldType::Integer(1),
+                    TableDataFieldType::Null,
+                ])),
+            ),
+        ];
+
+        for (value, data_type, expected) in test_cases {
+            let got = value.clone().cast_to(&data_type);
+
+            assert_eq!(got.ok(), expected, "{:?}::{:?}", value, data_type);
+        }
+    }
+
+    #[test]
+    fn test_cast_to_error_message() {
+        let error = TableDataFieldType::String("abc".into())
+            .cast_to(&DataType::Int)
+            .unwrap_err();
+
+        assert!(
+            error
+                .to_string()
+                .contains("invalid input syntax for type integer: \"abc\""),
+            "{}",
+            error
+        );
+    }
+}
@@ -6,6 +6,7 @@ use crate::engine::ast::{
     ddl::create_table::CreateTableQuery,
     types::{Column, ForeignKey, TableName, UniqueKey},
 };
+use crate::engine::types::ExecuteColumn;
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct TableSchema {
@@ -28,6 +29,25 @@ impl TableSchema {
             .cloned()
             .collect()
     }
+
+    /// 파생 테이블(FROM 절 서브쿼리)의 결과 컬럼을 alias 이름의 테이블 스키마로 만듭니다.
+    pub fn derived(table: TableName, columns: &[ExecuteColumn]) -> Self {
+        TableSchema {
+            table,
+            columns: columns
+                .iter()
+                .map(|column| {
+                    Column::builder()
+                        .set_name(column.name.clone())
+                        .set_data_type(column.data_type.clone().into())
+                        .build()
+                })
+                .collect(),
+            primary_key: vec![],
+            foreign_keys: vec![],
+            unique_keys: vec![],
+        }
+    }
 }
 
 impl From<CreateTableQuery> for TableSchema {
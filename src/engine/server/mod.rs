@@ -41,12 +41,29 @@ fn spawn_durability_flush_loop(
         loop {
             interval.tick().await;
 
-            if let Err(error) = engine.flush_row_buffers_durable().await {
+            // 쓰기 명령이 실행 중이면 WAL에만 기록된 변경이 있을 수 있으므로 이번 주기는 건너뜁니다.
+            let Some(_writer_guard) = engine.transaction_manager.try_lock_writers() else {
+                log::debug!("skipping durability flush while a write is in progress");
+                continue;
+            };
+
+            // 열린 트랜잭션의 행은 빼고 내리므로, 그 WAL 엔트리는 체크포인트 뒤에 다시 남깁니다.
+            // 그 사이에 커밋된 트랜잭션도 같은 목록을 써야 엔트리를 잃지 않습니다.
+            let open_transactions = engine.transaction_manager.open_writers();
+            if let Err(error) = engine
+                .flush_committed_row_buffers_durable(&open_transactions)
+                .await
+            {
                 log::error!("failed to flush row buffers durably: {}", error);
                 continue;
             }
 
-            if let Err(error) = wal_manager.lock().await.flush().await {
+            if let Err(error) = wal_manager
+                .lock()
+                .await
+                .flush_keeping(&open_transactions)
+                .await
+            {
                 log::error!("failed to flush WAL: {}", error);
             }
         }
@@ -100,8 +117,7 @@ impl Server {
         }
 
         let wal_manager = Arc::new(Mutex::new(wal_manager));
-        let _wal_sync_task =
-            spawn_wal_sync_loop(wal_manager.clone(), DEFAULT_WAL_SYNC_INTERVAL);
+        let _wal_sync_task = spawn_wal_sync_loop(wal_manager.clone(), DEFAULT_WAL_SYNC_INTERVAL);
         let _durability_flush_task = spawn_durability_flush_loop(
             engine.clone(),
             wal_manager.clone(),
@@ -150,6 +166,8 @@ impl Server {
 
                 tokio::spawn(async move {
                     let connection_id = shared_state.client_info.connection_id.clone();
+                    let engine = shared_state.engine.clone();
+                    let wal_manager = shared_state.wal_manager.clone();
                     let mut conn = Connection::new(shared_state);
                     if let Err(error) = conn.run(stream).await {
                         if is_expected_disconnect(&error) {
@@ -160,6 +178,14 @@ impl Server {
                     } else {
                         log::debug!("connection {} closed", connection_id);
                     }
+
+                    if let Err(error) = engine.close_connection(&connection_id, wal_manager).await {
+                        log::error!(
+                            "failed to roll back transaction of connection {}: {}",
+                            connection_id,
+                            error
+                        );
+                    }
                 });
             }
         });
@@ -321,9 +347,11 @@ mod tests {
                     .iter()
                     .any(|entry| matches!(entry.entry_type, EntryType::Insert))
                 {
-                    assert!(entries
-                        .iter()
-                        .all(|entry| !matches!(entry.entry_type, EntryType::Checkpoint)));
+                    assert!(
+                        entries
+                            .iter()
+                            .all(|entry| !matches!(entry.entry_type, EntryType::Checkpoint))
+                    );
                     break;
                 }
 
@@ -0,0 +1,757 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::io::ErrorKind as IOErrorKind;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex as StdMutex, MutexGuard, PoisonError, Weak};
+
+use tokio::sync::{Mutex, OwnedRwLockReadGuard, OwnedRwLockWriteGuard, RwLock};
+
+use crate::engine::ast::tcl::IsolationLevel;
+use crate::engine::ast::types::TableName;
+use crate::engine::encoder::schema_encoder::StorageEncoder;
+use crate::engine::lock::LockManager;
+use crate::engine::row_buffer::RowSavepoint;
+use crate::errors;
+use crate::errors::execute_error::ExecuteError;
+
+/// 모든 스냅샷에 보이는 transaction id. WAL replay처럼 명령 밖에서 쓴 행과,
+/// xmin을 남기지 않던 이전 형식의 행이 이 값을 가집니다.
+pub(crate) const FROZEN_TRANSACTION_ID: u64 = 0;
+
+const TRANSACTION_ID_FILENAME: &str = "transaction_id.bin";
+/// 파일에 한 번에 예약하는 transaction id 개수. 재시작하면 쓰지 않은 id는 건너뜁니다.
+const TRANSACTION_ID_RESERVATION: u64 = 1024;
+
+tokio::task_local! {
+    static STATEMENT_CONTEXT: StatementContext;
+}
+
+/// 실행 중인 명령의 트랜잭션 정보. 행을 읽고 쓰는 함수가 인자로 받지 않고 꺼내 씁니다.
+#[derive(Clone)]
+pub(crate) struct StatementContext {
+    /// 이 명령이 쓰는 행 버전에 남길 transaction id
+    pub(crate) transaction_id: Option<u64>,
+    pub(crate) snapshot: Arc<Snapshot>,
+    pub(crate) isolation_level: IsolationLevel,
+}
+
+impl StatementContext {
+    /// 명령 밖(WAL replay, 내부 호출)에서는 None이며, 이때는 최신 버전을 읽고 씁니다.
+    pub(crate) fn current() -> Option<StatementContext> {
+        STATEMENT_CONTEXT.try_with(Clone::clone).ok()
+    }
+
+    pub(crate) async fn scope<F: Future>(self, future: F) -> F::Output {
+        STATEMENT_CONTEXT.scope(self, future).await
+    }
+}
+
+/// 어떤 트랜잭션의 결과가 보이는지 정하는 스냅샷.
+///
+/// 스냅샷을 찍을 때 이미 끝난 트랜잭션과 자기 트랜잭션(`own`)만 보입니다.
+pub(crate) struct Snapshot {
+    /// 이 id부터는 스냅샷 뒤에 시작한 트랜잭션입니다.
+    xmax: u64,
+    active: HashSet<u64>,
+    own: Option<u64>,
+    serial: u64,
+    state: Weak<StdMutex<TransactionState>>,
+}
+
+impl Snapshot {
+    pub(crate) fn sees(&self, transaction_id: u64) -> bool {
+        transaction_id == FROZEN_TRANSACTION_ID
+            || Some(transaction_id) == self.own
+            || (transaction_id < self.xmax && !self.active.contains(&transaction_id))
+    }
+}
+
+impl Drop for Snapshot {
+    fn drop(&mut self) {
+        if let Some(state) = self.state.upgrade() {
+            lock(&state).snapshots.remove(&self.serial);
+        }
+    }
+}
+
+/// 연결별 트랜잭션 상태. drop되면 진행 중인 트랜잭션 목록에서 빠지므로,
+/// 커밋/롤백 처리를 마친 뒤에 놓아야 합니다.
+pub(crate) struct Transaction {
+    pub(crate) transaction_id: u64,
+    pub(crate) isolation_level: IsolationLevel,
+    /// 실행 중 오류가 난 트랜잭션. COMMIT/ROLLBACK 전까지 다른 명령을 거부합니다.
+    pub(crate) failed: bool,
+    /// REPEATABLE READ에서 첫 명령이 찍은 스냅샷
+    snapshot: Option<Arc<Snapshot>>,
+    /// 한 번이라도 행을 바꾼 트랜잭션. 잡은 쓰기 락은 COMMIT/ROLLBACK까지 유지합니다.
+    is_writer: bool,
+    /// SAVEPOINT 스택 (오래된 것부터)
+    savepoints: Vec<Savepoint>,
+    next_savepoint_id: u64,
+    state: Weak<StdMutex<TransactionState>>,
+    locks: Weak<LockManager>,
+}
+
+impl Drop for Transaction {
+    fn drop(&mut self) {
+        if let Some(state) = self.state.upgrade() {
+            lock(&state).active.remove(&self.transaction_id);
+        }
+        // 커밋/롤백이 다른 스냅샷에 보이게 된 뒤에 행 락을 풉니다.
+        if let Some(locks) = self.locks.upgrade() {
+            locks.release_all(self.transaction_id);
+        }
+    }
+}
+
+/// 트랜잭션 안에서 되돌아갈 수 있는 지점
+#[derive(Clone)]
+pub(crate) struct Savepoint {
+    pub(crate) name: String,
+    /// WAL 마커에 남기는 번호. 이름은 겹칠 수 있으므로 트랜잭션 안에서 따로 매깁니다.
+    pub(crate) savepoint_id: u64,
+    /// SAVEPOINT 때 이미 건드린 테이블의 상태. 그 뒤에 처음 건드린 테이블은
+    /// 트랜잭션이 건드리기 전으로 되돌립니다.
+    pub(crate) tables: HashMap<TableName, RowSavepoint>,
+}
+
+/// 트랜잭션 블록 밖의 쓰기 명령. 명령이 끝나 drop되면 커밋된 것으로 보고 쓰기 락을 풉니다.
+pub(crate) struct AutocommitTransaction {
+    pub(crate) transaction_id: u64,
+    state: Weak<StdMutex<TransactionState>>,
+    locks: Weak<LockManager>,
+}
+
+impl Drop for AutocommitTransaction {
+    fn drop(&mut self) {
+        if let Some(state) = self.state.upgrade() {
+            lock(&state).active.remove(&self.transaction_id);
+        }
+        if let Some(locks) = self.locks.upgrade() {
+            locks.release_all(self.transaction_id);
+        }
+    }
+}
+
+/// 행을 바꾼 트랜잭션 블록
+pub(crate) struct TransactionWriter {
+    pub(crate) transaction_id: u64,
+    /// 처음 건드린 테이블과 그때의 행 개수. ROLLBACK은 이 범위로 되돌립니다.
+    pub(crate) tables: HashMap<TableName, usize>,
+    /// 버퍼에 쌓은 행 버전의 대략적인 크기 (바이트)
+    buffered_bytes: usize,
+}
+
+pub(crate) struct TransactionStatus {
+    pub(crate) transaction_id: u64,
+    pub(crate) isolation_level: IsolationLevel,
+    pub(crate) failed: bool,
+    pub(crate) is_writer: bool,
+}
+
+#[derive(Default)]
+struct TransactionState {
+    next_transaction_id: u64,
+    /// 파일에 예약해 둔 transaction id의 끝
+    reserved_transaction_id: u64,
+    transaction_ids_loaded: bool,
+    /// 시작했지만 끝나지 않은 트랜잭션
+    active: BTreeSet<u64>,
+    /// 살아 있는 스냅샷마다 보이지 않을 수 있는 가장 작은 transaction id
+    snapshots: HashMap<u64, u64>,
+    next_snapshot_serial: u64,
+    transactions: HashMap<String, Transaction>,
+    /// 행을 바꾼 트랜잭션 블록 (transaction id별)
+    writers: HashMap<u64, TransactionWriter>,
+}
+
+/// 트랜잭션 관리자 (MVCC).
+///
+/// - 쓰기 명령은 바꿀 테이블마다 쓰기 락을 잡습니다 (`LockManager`). 트랜잭션 블록은
+///   COMMIT/ROLLBACK까지, 블록 밖의 쓰기 명령은 명령이 끝날 때까지 쥡니다. 다른 테이블에
+///   쓰는 트랜잭션은 서로 기다리지 않습니다.
+/// - 읽기는 락을 잡지 않고 스냅샷에 보이는 행 버전만 읽습니다. 그래서 커밋되지 않은
+///   변경은 다른 연결에 보이지 않고, 쓰기 트랜잭션이 읽기를 막지 않습니다.
+/// - SELECT ... FOR UPDATE / FOR SHARE와 UPDATE, DELETE는 대상 행에 행 락을,
+///   TRUNCATE는 테이블 락을 잡고 트랜잭션이 끝날 때 풉니다.
+/// - 행 버퍼를 디스크에 내릴 때는 열린 트랜잭션의 버전을 빼고 커밋된 상태만 씁니다.
+///   열린 트랜잭션이 버퍼에 쌓을 수 있는 양은 `transaction_memory_limit`으로 제한합니다.
+///
+/// 행 버전에 남긴 transaction id가 재시작 뒤에도 커지도록, id는 데이터 디렉토리의
+/// 파일에 미리 예약해 둔 범위에서 나눠 줍니다.
+///
+/// # 제약
+///
+/// 쓰기 락은 행이 아니라 테이블 단위입니다. WAL replay가 INSERT의 시작 행 번호와
+/// UPDATE/DELETE 재실행에 기대고 있어서, 한 테이블에 두 트랜잭션의 쓰기가 섞이면
+/// 복구 결과가 달라지기 때문입니다. 그래서 서로 다른 행을 바꾸더라도 같은 테이블에
+/// 쓰는 트랜잭션은 먼저 쓴 트랜잭션이 끝날 때까지 기다립니다.
+///
+/// 주기적인 durability flush(`try_lock_writers`)는 실행 중인 쓰기 명령이 없을 때만
+/// 돌고, 열린 트랜잭션은 기다리지 않습니다.
+pub struct TransactionManager {
+    transaction_id_path: PathBuf,
+    /// 쓰기 명령은 실행되는 동안 읽기 락을, durability flush는 쓰기 락을 잡습니다.
+    statement_lock: Arc<RwLock<()>>,
+    reservation_lock: Mutex<()>,
+    state: Arc<StdMutex<TransactionState>>,
+    lock_manager: Arc<LockManager>,
+}
+
+impl TransactionManager {
+    pub fn new(data_directory: PathBuf) -> Self {
+        Self {
+            transaction_id_path: data_directory.join(TRANSACTION_ID_FILENAME),
+            statement_lock: Arc::new(RwLock::new(())),
+            reservation_lock: Mutex::new(()),
+            state: Arc::new(StdMutex::new(TransactionState {
+                next_transaction_id: FROZEN_TRANSACTION_ID + 1,
+                ..Default::default()
+            })),
+            lock_manager: Arc::new(LockManager::default()),
+        }
+    }
+
+    pub(crate) fn lock_manager(&self) -> &LockManager {
+        &self.lock_manager
+    }
+
+    /// 새 트랜잭션을 엽니다. 이미 열려 있으면 false를 반환합니다.
+    pub(crate) async fn begin(
+        &self,
+        connection_id: &str,
+        isolation_level: IsolationLevel,
+    ) -> errors::Result<bool> {
+        if lock(&self.state).transactions.contains_key(connection_id) {
+            return Ok(false);
+        }
+
+        let transaction = Transaction {
+            transaction_id: self.allocate_transaction_id().await?,
+            isolation_level,
+            failed: false,
+            snapshot: None,
+            is_writer: false,
+            savepoints: Vec::new(),
+            next_savepoint_id: 0,
+            state: Arc::downgrade(&self.state),
+            locks: Arc::downgrade(&self.lock_manager),
+        };
+
+        let replaced = lock(&self.state)
+            .transactions
+            .insert(connection_id.to_string(), transaction);
+        drop(replaced);
+
+        Ok(true)
+    }
+
+    pub(crate) fn status(&self, connection_id: &str) -> Option<TransactionStatus> {
+        lock(&self.state)
+            .transactions
+            .get(connection_id)
+            .map(|transaction| TransactionStatus {
+                transaction_id: transaction.transaction_id,
+                isolation_level: transaction.isolation_level,
+                failed: transaction.failed,
+                is_writer: transaction.is_writer,
+            })
+    }
+
+    pub(crate) fn mark_failed(&self, connection_id: &str) {
+        if let Some(transaction) = lock(&self.state).transactions.get_mut(connection_id) {
+            transaction.failed = true;
+        }
+    }
+
+    /// SAVEPOINT를 스택에 쌓고 WAL 마커에 쓸 번호를 반환합니다.
+    pub(crate) fn add_savepoint(
+        &self,
+        connection_id: &str,
+        name: String,
+        tables: HashMap<TableName, RowSavepoint>,
+    ) -> Option<u64> {
+        let mut state = lock(&self.state);
+        let transaction = state.transactions.get_mut(connection_id)?;
+
+        let savepoint_id = transaction.next_savepoint_id;
+        transaction.next_savepoint_id += 1;
+        transaction.savepoints.push(Savepoint {
+            name,
+            savepoint_id,
+            tables,
+        });
+
+        Some(savepoint_id)
+    }
+
+    /// 이름이 같은 세이브포인트가 여럿이면 가장 최근 것을 찾습니다.
+    pub(crate) fn find_savepoint(&self, connection_id: &str, name: &str) -> Option<Savepoint> {
+        lock(&self.state)
+            .transactions
+            .get(connection_id)?
+            .savepoints
+            .iter()
+            .rev()
+            .find(|savepoint| savepoint.name == name)
+            .cloned()
+    }
+
+    /// ROLLBACK TO SAVEPOINT를 마친 뒤 호출합니다. 그 뒤에 만든 세이브포인트를 버리고
+    /// (자신은 남깁니다), 오류로 중단된 트랜잭션을 다시 쓸 수 있게 합니다.
+    pub(crate) fn rollback_to_savepoint(&self, connection_id: &str, savepoint_id: u64) {
+        if let Some(transaction) = lock(&self.state).transactions.get_mut(connection_id) {
+            transaction
+                .savepoints
+                .retain(|savepoint| savepoint.savepoint_id <= savepoint_id);
+            transaction.failed = false;
+        }
+    }
+
+    /// 세이브포인트와 그 뒤에 만든 세이브포인트를 버립니다. 없으면 false를 반환합니다.
+    pub(crate) fn release_savepoint(&self, connection_id: &str, name: &str) -> bool {
+        let mut state = lock(&self.state);
+        let Some(transaction) = state.transactions.get_mut(connection_id) else {
+            return false;
+        };
+
+        match transaction
+            .savepoints
+            .iter()
+            .rposition(|savepoint| savepoint.name == name)
+        {
+            Some(position) => {
+                transaction.savepoints.truncate(position);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// 트랜잭션을 연결에서 떼어 내 돌려줍니다. 반환된 트랜잭션이 drop되어야
+    /// 다른 스냅샷에 끝난 것으로 보이고 쓰기 락이 풀립니다.
+    pub(crate) fn end(
+        &self,
+        connection_id: &str,
+    ) -> Option<(Transaction, Option<TransactionWriter>)> {
+        let mut state = lock(&self.state);
+        let transaction = state.transactions.remove(connection_id)?;
+
+        let writer = if transaction.is_writer {
+            state.writers.remove(&transaction.transaction_id)
+        } else {
+            None
+        };
+
+        drop(state);
+        Some((transaction, writer))
+    }
+
+    /// 트랜잭션 블록이 `tables`에 쓰기 락을 잡습니다. 다른 트랜잭션이 그 테이블에 쓰고
+    /// 있으면 끝날 때까지 기다리며, 기다리면 교착 상태가 되면 오류를 반환합니다.
+    pub(crate) async fn acquire_writer(
+        &self,
+        connection_id: &str,
+        tables: &[TableName],
+    ) -> errors::Result<Option<u64>> {
+        let Some(transaction_id) = self
+            .status(connection_id)
+            .map(|status| status.transaction_id)
+        else {
+            return Ok(None);
+        };
+
+        for table_name in tables {
+            self.lock_manager
+                .lock_table_for_write(transaction_id, table_name)
+                .await?;
+        }
+
+        let mut state = lock(&self.state);
+        let state = &mut *state;
+        let Some(transaction) = state.transactions.get_mut(connection_id) else {
+            return Ok(None);
+        };
+        transaction.is_writer = true;
+
+        state
+            .writers
+            .entry(transaction_id)
+            .or_insert_with(|| TransactionWriter {
+                transaction_id,
+                tables: HashMap::new(),
+                buffered_bytes: 0,
+            });
+
+        Ok(Some(transaction_id))
+    }
+
+    /// 트랜잭션 블록 밖의 쓰기 명령을 시작합니다. `tables`에 쓰는 다른 트랜잭션이
+    /// 끝날 때까지 기다립니다.
+    pub(crate) async fn begin_autocommit(
+        &self,
+        tables: &[TableName],
+    ) -> errors::Result<AutocommitTransaction> {
+        let transaction_id = self.allocate_transaction_id().await?;
+
+        // 락을 기다리다 실패하면 drop되면서 이미 잡은 락을 풉니다.
+        let autocommit = AutocommitTransaction {
+            transaction_id,
+            state: Arc::downgrade(&self.state),
+            locks: Arc::downgrade(&self.lock_manager),
+        };
+        for table_name in tables {
+            self.lock_manager
+                .lock_table_for_write(transaction_id, table_name)
+                .await?;
+        }
+
+        Ok(autocommit)
+    }
+
+    /// 쓰기 명령을 실행하는 동안 쥐는 가드. durability flush가 명령 중간의 상태를
+    /// 디스크에 내리지 않도록 합니다.
+    pub(crate) async fn begin_write_statement(&self) -> OwnedRwLockReadGuard<()> {
+        self.statement_lock.clone().read_owned().await
+    }
+
+    /// 실행 중인 쓰기 명령이 없을 때만 락을 잡습니다. 주기적인 flush가 명령 중간의 행과
+    /// WAL을 체크포인트하지 않도록 할 때 사용합니다. 열린 트랜잭션은 기다리지 않습니다.
+    pub(crate) fn try_lock_writers(&self) -> Option<OwnedRwLockWriteGuard<()>> {
+        self.statement_lock.clone().try_write_owned().ok()
+    }
+
+    /// 지금까지 커밋된 트랜잭션이 보이는 스냅샷을 찍습니다.
+    pub(crate) async fn snapshot(&self, own: Option<u64>) -> errors::Result<Arc<Snapshot>> {
+        self.load_transaction_ids().await?;
+
+        let mut state = lock(&self.state);
+        Ok(self.take_snapshot(&mut state, own))
+    }
+
+    /// REPEATABLE READ 트랜잭션의 스냅샷. 첫 호출에서 찍고 트랜잭션이 끝날 때까지 씁니다.
+    pub(crate) fn transaction_snapshot(&self, connection_id: &str) -> Option<Arc<Snapshot>> {
+        // 트랜잭션을 시작하며 id를 나눠 받았으므로 파일은 이미 읽었습니다.
+        let mut state = lock(&self.state);
+        let transaction = state.transactions.get(connection_id)?;
+
+        if let Some(snapshot) = &transaction.snapshot {
+            return Some(snapshot.clone());
+        }
+
+        let own = Some(transaction.transaction_id);
+        let snapshot = self.take_snapshot(&mut state, own);
+        state.transactions.get_mut(connection_id)?.snapshot = Some(snapshot.clone());
+
+        Some(snapshot)
+    }
+
+    /// 이보다 작은 id가 지운 버전은 어느 스냅샷에도 보이지 않습니다.
+    pub(crate) fn horizon(&self) -> u64 {
+        let state = lock(&self.state);
+
+        [
+            Some(state.next_transaction_id),
+            state.active.first().copied(),
+            state.snapshots.values().min().copied(),
+        ]
+        .into_iter()
+        .flatten()
+        .min()
+        .unwrap_or(state.next_transaction_id)
+    }
+
+    /// 실행 중인 명령이 트랜잭션 블록의 쓰기이면 WAL 엔트리에 남길 transaction id를
+    /// 반환합니다. 블록 밖의 쓰기 명령은 항상 커밋되므로 id 없이 기록합니다.
+    pub(crate) fn writer_transaction_id(&self) -> Option<u64> {
+        let transaction_id = StatementContext::current()?.transaction_id?;
+
+        lock(&self.state)
+            .writers
+            .contains_key(&transaction_id)
+            .then_some(transaction_id)
+    }
+
+    /// 행을 바꾼 뒤 아직 끝나지 않은 트랜잭션 블록. 이 트랜잭션들의 행 버전은 디스크에
+    /// 내리지 않고, WAL 체크포인트 뒤에도 엔트리를 남겨 둡니다.
+    pub(crate) fn open_writers(&self) -> HashSet<u64> {
+        lock(&self.state).writers.keys().copied().collect()
+    }
+
+    /// 트랜잭션 블록이 지금까지 건드린 테이블과 그때의 행 개수
+    pub(crate) fn writer_tables(&self, transaction_id: u64) -> HashMap<TableName, usize> {
+        lock(&self.state)
+            .writers
+            .get(&transaction_id)
+            .map(|writer| writer.tables.clone())
+            .unwrap_or_default()
+    }
+
+    /// 트랜잭션 블록이 이 테이블을 아직 건드리지 않았는지 확인합니다.
+    pub(crate) fn is_untouched_table(&self, transaction_id: u64, table_name: &TableName) -> bool {
+        lock(&self.state)
+            .writers
+            .get(&transaction_id)
+            .is_some_and(|writer| !writer.tables.contains_key(table_name))
+    }
+
+    pub(crate) fn record_touched_table(
+        &self,
+        transaction_id: u64,
+        table_name: TableName,
+        row_count: usize,
+    ) {
+        if let Some(writer) = lock(&self.state).writers.get_mut(&transaction_id) {
+            writer.tables.entry(table_name).or_insert(row_count);
+        }
+    }
+
+    /// 트랜잭션 블록이 버퍼에 `bytes`만큼 더 쌓아도 `limit`을 넘지 않으면 더하고 true를
+    /// 반환합니다. 블록 밖의 쓰기 명령은 바로 디스크에 내려갈 수 있으므로 세지 않습니다.
+    pub(crate) fn reserve_buffered_bytes(
+        &self,
+        transaction_id: u64,
+        bytes: usize,
+        limit: usize,
+    ) -> bool {
+        let mut state = lock(&self.state);
+        let Some(writer) = state.writers.get_mut(&transaction_id) else {
+            return true;
+        };
+
+        if writer.buffered_bytes + bytes > limit {
+            return false;
+        }
+
+        writer.buffered_bytes += bytes;
+        true
+    }
+
+    fn take_snapshot(&self, state: &mut TransactionState, own: Option<u64>) -> Arc<Snapshot> {
+        let xmax = state.next_transaction_id;
+        let active: HashSet<u64> = state
+            .active
+            .iter()
+            .copied()
+            .filter(|transaction_id| Some(*transaction_id) != own)
+            .collect();
+        let xmin = active.iter().copied().min().unwrap_or(xmax);
+
+        let serial = state.next_snapshot_serial;
+        state.next_snapshot_serial += 1;
+        state.snapshots.insert(serial, xmin);
+
+        Arc::new(Snapshot {
+            xmax,
+            active,
+            own,
+            serial,
+            state: Arc::downgrade(&self.state),
+        })
+    }
+
+    /// 재시작 뒤 처음 호출되면, 지난번에 예약해 둔 id 다음부터 나눠 주도록 파일을 읽습니다.
+    /// 그 전에 찍은 스냅샷은 디스크에 남은 행 버전을 아직 시작하지 않은 트랜잭션의 것으로 봅니다.
+    async fn load_transaction_ids(&self) -> errors::Result<()> {
+        if lock(&self.state).transaction_ids_loaded {
+            return Ok(());
+        }
+
+        let _reservation = self.reservation_lock.lock().await;
+        if lock(&self.state).transaction_ids_loaded {
+            return Ok(());
+        }
+
+        let reserved_transaction_id = self.read_reserved_transaction_id().await?;
+
+        let mut state = lock(&self.state);
+        state.next_transaction_id = state.next_transaction_id.max(reserved_transaction_id);
+        state.transaction_ids_loaded = true;
+
+        Ok(())
+    }
+
+    async fn allocate_transaction_id(&self) -> errors::Result<u64> {
+        self.load_transaction_ids().await?;
+        let _reservation = self.reservation_lock.lock().await;
+
+        let (next_transaction_id, reserved_transaction_id) = {
+            let state = lock(&self.state);
+            (state.next_transaction_id, state.reserved_transaction_id)
+        };
+
+        if next_transaction_id >= reserved_transaction_id {
+            let reserved_transaction_id = next_transaction_id + TRANSACTION_ID_RESERVATION;
+            self.write_reserved_transaction_id(reserved_transaction_id)
+                .await?;
+            lock(&self.state).reserved_transaction_id = reserved_transaction_id;
+        }
+
+        let mut state = lock(&self.state);
+        let transaction_id = state.next_transaction_id;
+        state.next_transaction_id += 1;
+        state.active.insert(transaction_id);
+
+        Ok(transaction_id)
+    }
+
+    async fn read_reserved_transaction_id(&self) -> errors::Result<u64> {
+        match tokio::fs::read(&self.transaction_id_path).await {
+            Ok(content) => StorageEncoder::new()
+                .decode::<u64>(&content)
+                .map_err(|error| {
+                    ExecuteError::wrap(format!("invalid transaction id file: {}", error))
+                }),
+            Err(error) if error.kind() == IOErrorKind::NotFound => Ok(0),
+            Err(error) => Err(ExecuteError::wrap(error.to_string())),
+        }
+    }
+
+    async fn write_reserved_transaction_id(&self, transaction_id: u64) -> errors::Result<()> {
+        if let Some(parent) = self.transaction_id_path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|error| ExecuteError::wrap(error.to_string()))?;
+        }
+
+        let temp_path = self.transaction_id_path.with_extension("bin.tmp");
+        tokio::fs::write(&temp_path, StorageEncoder::new().encode(transaction_id))
+            .await
+            .map_err(|error| ExecuteError::wrap(error.to_string()))?;
+        tokio::fs::rename(&temp_path, &self.transaction_id_path)
+            .await
+            .map_err(|error| ExecuteError::wrap(error.to_string()))
+    }
+}
+
+// 상태를 고치는 중에 패닉이 나도 남은 값은 일관되므로 poison은 무시합니다.
+fn lock(state: &StdMutex<TransactionState>) -> MutexGuard<'_, TransactionState> {
+    state.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn manager(test_name: &str) -> (TransactionManager, PathBuf) {
+        let data_directory = PathBuf::from("target/test_transaction_manager").join(test_name);
+        if data_directory.exists() {
+            tokio::fs::remove_dir_all(&data_directory).await.unwrap();
+        }
+
+        (
+            TransactionManager::new(data_directory.clone()),
+            data_directory,
+        )
+    }
+
+    #[tokio::test]
+    async fn snapshot_sees_only_transactions_finished_before_it() {
+        let (manager, _) = manager("snapshot_visibility").await;
+
+        assert!(
+            manager
+                .begin("a", IsolationLevel::ReadCommitted)
+                .await
+                .unwrap()
+        );
+        assert!(
+            !manager
+                .begin("a", IsolationLevel::ReadCommitted)
+                .await
+                .unwrap()
+        );
+        let transaction_id = manager.status("a").unwrap().transaction_id;
+
+        let before_commit = manager.snapshot(None).await.unwrap();
+        assert!(!before_commit.sees(transaction_id));
+        assert!(before_commit.sees(FROZEN_TRANSACTION_ID));
+        assert!(
+            manager
+                .snapshot(Some(transaction_id))
+                .await
+                .unwrap()
+                .sees(transaction_id)
+        );
+        assert_eq!(manager.horizon(), transaction_id);
+
+        let (transaction, writer) = manager.end("a").unwrap();
+        assert!(writer.is_none());
+        drop(transaction);
+
+        assert!(manager.snapshot(None).await.unwrap().sees(transaction_id));
+        assert!(!before_commit.sees(transaction_id));
+        // 먼저 찍은 스냅샷이 남아 있는 동안에는 지난 버전을 정리하지 않습니다.
+        assert_eq!(manager.horizon(), transaction_id);
+        drop(before_commit);
+        assert!(manager.horizon() > transaction_id);
+    }
+
+    #[tokio::test]
+    async fn transaction_ids_keep_increasing_across_restarts() {
+        let (manager, data_directory) = manager("persisted_ids").await;
+
+        let first = manager.begin_autocommit(&[]).await.unwrap().transaction_id;
+        let second = manager.begin_autocommit(&[]).await.unwrap().transaction_id;
+        assert!(second > first);
+
+        let restarted = TransactionManager::new(data_directory);
+        let third = restarted
+            .begin_autocommit(&[])
+            .await
+            .unwrap()
+            .transaction_id;
+        assert!(third > second);
+        assert!(restarted.snapshot(None).await.unwrap().sees(second));
+    }
+
+    #[tokio::test]
+    async fn table_write_locks_are_held_until_the_transaction_ends() {
+        let (manager, _) = manager("writer_lock").await;
+        let users = TableName::new(Some("rrdb".to_string()), "users".to_string());
+        let orders = TableName::new(Some("rrdb".to_string()), "orders".to_string());
+
+        for connection_id in ["a", "b"] {
+            manager
+                .begin(connection_id, IsolationLevel::RepeatableRead)
+                .await
+                .unwrap();
+        }
+        let transaction_id = manager
+            .acquire_writer("a", std::slice::from_ref(&users))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(manager.open_writers(), HashSet::from([transaction_id]));
+
+        // 다른 테이블에는 바로 쓰고, 같은 테이블은 트랜잭션 a가 끝날 때까지 기다립니다.
+        manager
+            .acquire_writer("b", std::slice::from_ref(&orders))
+            .await
+            .unwrap()
+            .unwrap();
+        let waiter = manager.acquire_writer("b", std::slice::from_ref(&users));
+        tokio::pin!(waiter);
+        assert!(
+            tokio::time::timeout(std::time::Duration::from_millis(50), &mut waiter)
+                .await
+                .is_err()
+        );
+        // 열린 트랜잭션이 있어도 실행 중인 쓰기 명령이 없으면 flush할 수 있습니다.
+        assert!(manager.try_lock_writers().is_some());
+
+        assert!(manager.is_untouched_table(transaction_id, &users));
+        manager.record_touched_table(transaction_id, users.clone(), 2);
+        manager.record_touched_table(transaction_id, users.clone(), 5);
+        assert!(!manager.is_untouched_table(transaction_id, &users));
+        assert!(manager.reserve_buffered_bytes(transaction_id, 60, 100));
+        assert!(!manager.reserve_buffered_bytes(transaction_id, 60, 100));
+
+        let (transaction, writer) = manager.end("a").unwrap();
+        assert_eq!(writer.unwrap().tables[&users], 2);
+        assert!(manager.writer_tables(transaction_id).is_empty());
+
+        drop(transaction);
+        waiter.await.unwrap().unwrap();
+        assert!(!manager.open_writers().contains(&transaction_id));
+    }
+}
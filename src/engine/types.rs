@@ -1,5 +1,3 @@
-use itertools::Itertools;
-
 use crate::engine::ast::types::DataType;
 use crate::engine::schema::row::TableDataFieldType;
 use crate::pgwire::protocol::DataTypeOid;
@@ -50,9 +48,66 @@ pub enum ExecuteColumnType {
     Integer,
     Float,
     String,
+    // 요소 타입의 1차원 배열 (ARRAY_AGG 결과)
+    Array(Box<ExecuteColumnType>),
     Null,
 }
 
+impl ExecuteColumnType {
+    // 오류 메세지에 쓰는 타입 이름
+    pub fn name(&self) -> &'static str {
+        match self {
+            ExecuteColumnType::Bool => "boolean",
+            ExecuteColumnType::Integer => "integer",
+            ExecuteColumnType::Float => "float",
+            ExecuteColumnType::String => "varchar",
+            ExecuteColumnType::Array(element) => match element.as_ref() {
+                ExecuteColumnType::Bool => "boolean[]",
+                ExecuteColumnType::Integer => "integer[]",
+                ExecuteColumnType::Float => "float[]",
+                ExecuteColumnType::String => "varchar[]",
+                _ => "array",
+            },
+            ExecuteColumnType::Null => "unknown",
+        }
+    }
+
+    // CASE, COALESCE 등 여러 값 중 하나를 돌려주는 식의 공통 타입
+    // NULL은 어느 타입과도 어울리고, 정수와 실수는 실수가 됩니다. 그 외에는 같은 타입끼리만 어울립니다.
+    pub fn common_type(self, other: ExecuteColumnType) -> Option<ExecuteColumnType> {
+        match (self, other) {
+            (ExecuteColumnType::Null, other) => Some(other),
+            (this, ExecuteColumnType::Null) => Some(this),
+            (ExecuteColumnType::Integer, ExecuteColumnType::Float)
+            | (ExecuteColumnType::Float, ExecuteColumnType::Integer) => {
+                Some(ExecuteColumnType::Float)
+            }
+            (this, other) if this == other => Some(this),
+            _ => None,
+        }
+    }
+}
+
+impl From<&TableDataFieldType> for ExecuteColumnType {
+    fn from(value: &TableDataFieldType) -> ExecuteColumnType {
+        match value {
+            TableDataFieldType::Integer(_) => ExecuteColumnType::Integer,
+            TableDataFieldType::Float(_) => ExecuteColumnType::Float,
+            TableDataFieldType::Boolean(_) => ExecuteColumnType::Bool,
+            TableDataFieldType::String(_) => ExecuteColumnType::String,
+            // 요소 타입은 NULL이 아닌 첫 요소로 정합니다.
+            TableDataFieldType::Array(array) => ExecuteColumnType::Array(Box::new(
+                array
+                    .iter()
+                    .find(|e| !e.is_null())
+                    .map(ExecuteColumnType::from)
+                    .unwrap_or(ExecuteColumnType::Null),
+            )),
+            TableDataFieldType::Null => ExecuteColumnType::Null,
+        }
+    }
+}
+
 impl From<ExecuteColumnType> for DataTypeOid {
     fn from(value: ExecuteColumnType) -> DataTypeOid {
         match value {
@@ -60,11 +115,32 @@ impl From<ExecuteColumnType> for DataTypeOid {
             ExecuteColumnType::Integer => DataTypeOid::Int8,
             ExecuteColumnType::Float => DataTypeOid::Float8,
             ExecuteColumnType::String => DataTypeOid::Text,
+            ExecuteColumnType::Array(element) => match *element {
+                ExecuteColumnType::Bool => DataTypeOid::BoolArray,
+                ExecuteColumnType::Integer => DataTypeOid::Int8Array,
+                ExecuteColumnType::Float => DataTypeOid::Float8Array,
+                _ => DataTypeOid::TextArray,
+            },
             ExecuteColumnType::Null => DataTypeOid::Unspecified,
         }
     }
 }
 
+impl From<ExecuteColumnType> for DataType {
+    fn from(value: ExecuteColumnType) -> DataType {
+        match value {
+            ExecuteColumnType::Bool => DataType::Boolean,
+            ExecuteColumnType::Integer => DataType::Int,
+            ExecuteColumnType::Float => DataType::Float,
+            // 타입을 알 수 없는 NULL 컬럼은 PostgreSQL처럼 문자열로 취급합니다.
+            // 배열 컬럼 타입은 없으므로 배열도 문자열 컬럼으로 저장합니다.
+            ExecuteColumnType::String | ExecuteColumnType::Array(_) | ExecuteColumnType::Null => {
+                DataType::Varchar(i64::MAX)
+            }
+        }
+    }
+}
+
 impl From<DataType> for ExecuteColumnType {
     fn from(value: DataType) -> ExecuteColumnType {
         match value {
@@ -82,9 +158,25 @@ pub enum ExecuteField {
     Integer(i64),
     Float(f64),
     String(String),
+    Array(Vec<ExecuteField>),
     Null,
 }
 
+impl From<ExecuteField> for TableDataFieldType {
+    fn from(value: ExecuteField) -> TableDataFieldType {
+        match value {
+            ExecuteField::Bool(value) => TableDataFieldType::Boolean(value),
+            ExecuteField::Integer(value) => TableDataFieldType::Integer(value),
+            ExecuteField::Float(value) => TableDataFieldType::Float(value.into()),
+            ExecuteField::String(value) => TableDataFieldType::String(value),
+            ExecuteField::Array(value) => {
+                TableDataFieldType::Array(value.into_iter().map(Into::into).collect())
+            }
+            ExecuteField::Null => TableDataFieldType::Null,
+        }
+    }
+}
+
 impl From<TableDataFieldType> for ExecuteField {
     fn from(value: TableDataFieldType) -> ExecuteField {
         #[allow(unstable_name_collisions)]
@@ -93,13 +185,9 @@ impl From<TableDataFieldType> for ExecuteField {
             TableDataFieldType::Integer(value) => ExecuteField::Integer(value),
             TableDataFieldType::Float(value) => ExecuteField::Float(value.into()),
             TableDataFieldType::String(value) => ExecuteField::String(value),
-            TableDataFieldType::Array(value) => ExecuteField::String(
-                value
-                    .iter()
-                    .map(|e| e.to_string())
-                    .intersperse(", ".to_owned())
-                    .collect(),
-            ),
+            TableDataFieldType::Array(value) => {
+                ExecuteField::Array(value.into_iter().map(Into::into).collect())
+            }
             TableDataFieldType::Null => ExecuteField::Null,
         }
     }
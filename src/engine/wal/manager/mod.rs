@@ -1,5 +1,6 @@
 pub mod builder;
 
+use std::collections::HashSet;
 use std::fs::OpenOptions;
 use std::path::PathBuf;
 use std::time::SystemTime;
@@ -28,7 +29,8 @@ where
 {
     /// The sequence number of the WAL file
     sequence: usize,
-    /// The buffer of the WAL file
+    /// Entries appended since the last checkpoint (possibly spanning
+    /// several segments)
     buffers: Vec<WALEntry>,
     /// The page size of the WAL file
     page_size: usize,
@@ -79,7 +81,7 @@ where
         }
     }
 
-    /// Entries written to the current segment but not yet checkpointed.
+    /// Entries written since the last checkpoint.
     /// Used at startup to replay operations that may not have been applied
     /// before a crash.
     pub fn pending_entries(&self) -> &[WALEntry] {
@@ -146,7 +148,6 @@ where
             self.current_segment = None;
             self.unsynced_bytes = 0;
             self.sequence += 1;
-            self.buffers.clear();
             self.current_offset = 0;
         }
 
@@ -242,9 +243,19 @@ where
         Ok(())
     }
 
-    async fn checkpoint(&mut self) -> errors::Result<()> {
+    async fn checkpoint(&mut self, open_transactions: &HashSet<u64>) -> errors::Result<()> {
         let entry_count = self.buffers.len();
         let sequence = self.sequence;
+        let carried: Vec<WALEntry> = self
+            .buffers
+            .iter()
+            .filter(|entry| {
+                entry
+                    .transaction_id
+                    .is_some_and(|transaction_id| open_transactions.contains(&transaction_id))
+            })
+            .cloned()
+            .collect();
 
         self.append_record(EntryType::Checkpoint, None, None)
             .await?;
@@ -256,21 +267,33 @@ where
         self.current_offset = 0;
 
         log::debug!(
-            "WAL checkpoint written for segment {} ({} buffered entries); rotating to segment {}",
+            "WAL checkpoint written for segment {} ({} buffered entries, {} carried over); rotating to segment {}",
             sequence,
             entry_count,
+            carried.len(),
             self.sequence
         );
 
+        for entry in carried {
+            self.write_entry(entry).await?;
+        }
+
         Ok(())
     }
 
     pub async fn flush(&mut self) -> errors::Result<()> {
+        self.flush_keeping(&HashSet::new()).await
+    }
+
+    /// Checkpoint like `flush`, but re-append the entries of transactions
+    /// that are still open after the checkpoint record. Their row changes
+    /// are not on disk yet, so replay still needs them if they commit later.
+    pub async fn flush_keeping(&mut self, open_transactions: &HashSet<u64>) -> errors::Result<()> {
         if self.buffers.is_empty() && self.current_segment.is_none() {
             return Ok(());
         }
 
-        self.checkpoint().await?;
+        self.checkpoint(open_transactions).await?;
         Ok(())
     }
 
@@ -320,6 +343,8 @@ mod tests {
             wal_directory: wal_dir_path.to_str().unwrap().to_string(),
             wal_segment_size: 1024,
             wal_extension: "waltest".to_string(),
+            aggregate_memory_limit: 1024,
+            transaction_memory_limit: 1024,
         }
     }
 
@@ -771,7 +796,10 @@ mod tests {
                 .build(BincodeDecoder::new(), BincodeEncoder::new())
                 .await
                 .unwrap_or_else(|error| {
-                    panic!("current behaviour: cut={} was expected to be accepted, got {}", cut, error)
+                    panic!(
+                        "current behaviour: cut={} was expected to be accepted, got {}",
+                        cut, error
+                    )
                 });
             assert_eq!(
                 reopened.pending_entries().len(),
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 
 use crate::engine::ast::dml::insert::InsertQuery;
+use crate::engine::ast::types::TableName;
+use crate::engine::schema::row::TableDataRow;
 
 #[derive(Default, Clone, Debug, Deserialize, Serialize)]
 pub struct WALEntry {
@@ -41,6 +43,58 @@ pub struct InsertWALPayload {
     pub row_count: usize,
 }
 
+/// Payload for `EntryType::Upsert` (`INSERT ... ON CONFLICT`).
+///
+/// Replaying the query would re-run the conflict checks against a table that
+/// may already contain the outcome, and `DO UPDATE SET n = n + 1` would apply
+/// twice. The payload therefore records the resolved changes instead: full row
+/// images for updated rows (re-applying them is a no-op) and the appended rows
+/// with their `start_row_index`, skipped on replay the same way as
+/// `InsertWALPayload`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct UpsertWALPayload {
+    pub table_name: TableName,
+    pub start_row_index: usize,
+    pub inserted_rows: Vec<TableDataRow>,
+    pub updated_rows: Vec<(usize, TableDataRow)>,
+}
+
+/// Payload for `EntryType::UpdateRows` (`UPDATE ... FROM`).
+///
+/// Which target rows match depends on the joined tables at execution time, so
+/// the payload records the row locations the join resolved together with the
+/// full new row images. Re-applying an image is a no-op, and rows deleted later
+/// in the log are left deleted.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct UpdateRowsWALPayload {
+    pub table_name: TableName,
+    pub updated_rows: Vec<(usize, TableDataRow)>,
+}
+
+/// Payload for `EntryType::DeleteRows` (`DELETE ... USING`).
+///
+/// Records the row locations the join resolved; replay tombstones the ones
+/// that are still live and skips the rest.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DeleteRowsWALPayload {
+    pub table_name: TableName,
+    pub row_indexes: Vec<usize>,
+}
+
+/// Payload for `EntryType::Savepoint` and `EntryType::RollbackToSavepoint`.
+///
+/// Savepoint names can repeat within a transaction, so the markers refer to
+/// savepoints by `savepoint_id`, numbered per transaction. Replay drops the
+/// transaction's entries between a `Savepoint` marker and a later
+/// `RollbackToSavepoint` marker with the same id. A savepoint taken before the
+/// transaction's first write has no marker; rolling back to it drops every
+/// earlier entry of the transaction.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct SavepointWALPayload {
+    pub savepoint_id: u64,
+    pub name: String,
+}
+
 #[derive(Default, Clone, Debug, Deserialize, Serialize)]
 pub enum EntryType {
     #[default]
@@ -55,4 +109,12 @@ pub enum EntryType {
     TransactionBegin,
     TransactionCommit,
     TransactionRollback,
+
+    // 기존 WAL의 variant 번호가 바뀌지 않도록 뒤에 추가합니다.
+    Upsert,
+    UpdateRows,
+    DeleteRows,
+    TruncateTable,
+    Savepoint,
+    RollbackToSavepoint,
 }
@@ -5,21 +5,24 @@ use std::collections::HashMap;
 use tokio::io::{AsyncRead, AsyncWrite};
 use tokio_util::codec::Framed;
 
+use crate::engine::ast::dml::delete::DeleteQuery;
 use crate::engine::ast::dml::insert::InsertQuery;
 use crate::engine::ast::dml::parts::insert_values::InsertValue;
+use crate::engine::ast::dml::update::UpdateQuery;
 use crate::engine::ast::types::{DataType, SQLExpression, TableName};
-use crate::engine::ast::{DDLStatement, DMLStatement, OtherStatement, SQLStatement};
+use crate::engine::ast::{DDLStatement, DMLStatement, OtherStatement, SQLStatement, TCLStatement};
 use crate::engine::lexer::predule::Tokenizer;
 use crate::engine::parser::context::ParserContext;
 use crate::engine::parser::predule::Parser;
 use crate::engine::server::shared_state::SharedState;
+use crate::engine::transaction::TransactionStatus;
 use crate::engine::types::{ExecuteColumn, ExecuteResult};
 use crate::pgwire::connection::{BoundPortal, ConnectionError, ConnectionState, PreparedStatement};
 use crate::pgwire::engine::{Engine, Portal, RRDBEngine};
 use crate::pgwire::protocol::backend::{
     AuthenticationOk, BindComplete, CloseComplete, CommandComplete, EmptyQueryResponse,
-    ErrorResponse, NoData, ParameterDescription, ParameterStatus, ParseComplete, ReadyForQuery,
-    RowDescription,
+    ErrorResponse, NoData, NoticeResponse, ParameterDescription, ParameterStatus, ParseComplete,
+    ReadyForQuery, RowDescription,
 };
 use crate::pgwire::protocol::client::{BindFormat, ClientMessage, Close, Describe};
 use crate::pgwire::protocol::{ConnectionCodec, DataRowBatch, FormatCode, Severity, SqlState};
@@ -302,6 +305,18 @@ impl Connection {
         matches!(
             statement,
             SQLStatement::DML(DMLStatement::SelectQuery(_))
+                | SQLStatement::DML(DMLStatement::InsertQuery(InsertQuery {
+                    returning: Some(_),
+                    ..
+                }))
+                | SQLStatement::DML(DMLStatement::UpdateQuery(UpdateQuery {
+                    returning: Some(_),
+                    ..
+                }))
+                | SQLStatement::DML(DMLStatement::DeleteQuery(DeleteQuery {
+                    returning: Some(_),
+                    ..
+                }))
                 | SQLStatement::Other(OtherStatement::ShowDatabases(_))
                 | SQLStatement::Other(OtherStatement::ShowTables(_))
                 | SQLStatement::Other(OtherStatement::DescTable(_))
@@ -323,13 +338,83 @@ impl Connection {
             SQLStatement::DDL(DDLStatement::CreateTableQuery(_)) => "CREATE TABLE".to_string(),
             SQLStatement::DDL(DDLStatement::DropDatabaseQuery(_)) => "DROP DATABASE".to_string(),
             SQLStatement::DDL(DDLStatement::DropTableQuery(_)) => "DROP TABLE".to_string(),
+            SQLStatement::DDL(DDLStatement::TruncateTableQuery(_)) => "TRUNCATE TABLE".to_string(),
             SQLStatement::DDL(DDLStatement::AlterDatabase(_)) => "ALTER DATABASE".to_string(),
             SQLStatement::DDL(DDLStatement::AlterTableQuery(_)) => "ALTER TABLE".to_string(),
             SQLStatement::DDL(DDLStatement::CreateIndexQuery(_)) => "CREATE INDEX".to_string(),
+            SQLStatement::DDL(DDLStatement::CreateFunctionQuery(_)) => {
+                "CREATE FUNCTION".to_string()
+            }
+            SQLStatement::DDL(DDLStatement::DropFunctionQuery(_)) => "DROP FUNCTION".to_string(),
+            SQLStatement::TCL(TCLStatement::BeginTransaction(_)) => "BEGIN".to_string(),
+            SQLStatement::TCL(TCLStatement::Commit(_)) => "COMMIT".to_string(),
+            SQLStatement::TCL(TCLStatement::Rollback(_)) => "ROLLBACK".to_string(),
+            SQLStatement::TCL(TCLStatement::Savepoint(_)) => "SAVEPOINT".to_string(),
+            SQLStatement::TCL(TCLStatement::RollbackToSavepoint(_)) => "ROLLBACK".to_string(),
+            SQLStatement::TCL(TCLStatement::ReleaseSavepoint(_)) => "RELEASE".to_string(),
             _ => format!("SELECT {}", num_rows),
         }
     }
 
+    fn transaction_status(&self) -> Option<TransactionStatus> {
+        self.engine
+            .shared_state
+            .engine
+            .transaction_manager
+            .status(&self.engine.shared_state.client_info.connection_id)
+    }
+
+    fn ready_for_query(&self) -> ReadyForQuery {
+        match self.transaction_status() {
+            None => ReadyForQuery::IDLE,
+            Some(status) if status.failed => ReadyForQuery::FAILED_TRANSACTION,
+            Some(_) => ReadyForQuery::IN_TRANSACTION,
+        }
+    }
+
+    // 실행 전의 트랜잭션 상태로 PostgreSQL과 같은 경고를 만듭니다.
+    fn transaction_notice(&self, statement: &SQLStatement) -> Option<NoticeResponse> {
+        let in_transaction = self.transaction_status().is_some();
+
+        match statement {
+            SQLStatement::TCL(TCLStatement::BeginTransaction(_)) if in_transaction => {
+                Some(NoticeResponse::warning(
+                    SqlState::ACTIVE_SQL_TRANSACTION,
+                    "there is already a transaction in progress",
+                ))
+            }
+            SQLStatement::TCL(TCLStatement::Commit(_) | TCLStatement::Rollback(_))
+                if !in_transaction =>
+            {
+                Some(NoticeResponse::warning(
+                    SqlState::NO_ACTIVE_SQL_TRANSACTION,
+                    "there is no transaction in progress",
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    // 실패한 트랜잭션의 COMMIT은 롤백되므로 ROLLBACK 태그로 응답합니다.
+    fn commits_failed_transaction(&self, statement: &SQLStatement) -> bool {
+        matches!(statement, SQLStatement::TCL(TCLStatement::Commit(_)))
+            && self
+                .transaction_status()
+                .is_some_and(|status| status.failed)
+    }
+
+    fn completion_tag(
+        statement: &SQLStatement,
+        result: &ExecuteResult,
+        commits_failed_transaction: bool,
+    ) -> String {
+        if commits_failed_transaction {
+            "ROLLBACK".to_string()
+        } else {
+            Self::command_tag(statement, result)
+        }
+    }
+
     fn row_description_fields(
         columns: &[ExecuteColumn],
     ) -> Vec<crate::pgwire::protocol::backend::FieldDescription> {
@@ -437,7 +522,7 @@ impl Connection {
                     framed.feed(ParameterStatus::new(param, status)).await?;
                 }
 
-                framed.send(ReadyForQuery).await?;
+                framed.send(self.ready_for_query()).await?;
                 Ok(Some(ConnectionState::Idle))
             }
             ConnectionState::Idle => {
@@ -574,36 +659,63 @@ impl Connection {
                         futures::SinkExt::<NoData>::flush(framed).await?;
                     }
                     ClientMessage::Sync => {
-                        framed.send(ReadyForQuery).await?;
+                        framed.send(self.ready_for_query()).await?;
                     }
-                    ClientMessage::Execute(exec) => match self.portal_mut(&exec.portal)? {
-                        Some(bound) => {
-                            let result = bound.portal.execute().await?;
-                            let statement = bound.statement.clone();
-
-                            if !bound.row_desc.fields.is_empty() {
-                                let mut batch_writer = DataRowBatch::from_row_desc(&bound.row_desc);
-                                bound.portal.fetch(&mut batch_writer).await?;
-                                framed.send(batch_writer).await?;
-                            }
+                    ClientMessage::Execute(exec) => {
+                        let (notice, commits_failed_transaction) =
+                            match self.portal(&exec.portal)? {
+                                Some(bound) => (
+                                    self.transaction_notice(&bound.statement),
+                                    self.commits_failed_transaction(&bound.statement),
+                                ),
+                                None => (None, false),
+                            };
+
+                        match self.portal_mut(&exec.portal)? {
+                            Some(bound) => {
+                                let result = bound.portal.execute().await?;
+                                let statement = bound.statement.clone();
+
+                                if let Some(notice) = notice {
+                                    framed.send(notice).await?;
+                                }
 
-                            framed
-                                .send(CommandComplete {
-                                    command_tag: Self::command_tag(&statement, &result),
-                                })
-                                .await?;
+                                if !bound.row_desc.fields.is_empty() {
+                                    let mut batch_writer =
+                                        DataRowBatch::from_row_desc(&bound.row_desc);
+                                    bound.portal.fetch(&mut batch_writer).await?;
+                                    framed.send(batch_writer).await?;
+                                }
 
-                            self.apply_statement_side_effects(&statement);
-                        }
-                        None => {
-                            framed.send(EmptyQueryResponse).await?;
+                                framed
+                                    .send(CommandComplete {
+                                        command_tag: Self::completion_tag(
+                                            &statement,
+                                            &result,
+                                            commits_failed_transaction,
+                                        ),
+                                    })
+                                    .await?;
+
+                                self.apply_statement_side_effects(&statement);
+                            }
+                            None => {
+                                framed.send(EmptyQueryResponse).await?;
+                            }
                         }
-                    },
+                    }
                     ClientMessage::Query(query) => {
                         if let Some(parsed) = self.parse_statement(&query)? {
                             let returns_rows = Self::returns_rows(&parsed);
+                            let notice = self.transaction_notice(&parsed);
+                            let commits_failed_transaction =
+                                self.commits_failed_transaction(&parsed);
                             let result = self.engine.execute_statement(&parsed).await?;
 
+                            if let Some(notice) = notice {
+                                framed.send(notice).await?;
+                            }
+
                             if returns_rows {
                                 let row_desc = RowDescription {
                                     fields: Self::row_description_fields(&result.columns),
@@ -619,7 +731,11 @@ impl Connection {
 
                             framed
                                 .send(CommandComplete {
-                                    command_tag: Self::command_tag(&parsed, &result),
+                                    command_tag: Self::completion_tag(
+                                        &parsed,
+                                        &result,
+                                        commits_failed_transaction,
+                                    ),
                                 })
                                 .await?;
 
@@ -627,7 +743,7 @@ impl Connection {
                         } else {
                             framed.send(EmptyQueryResponse).await?;
                         }
-                        framed.send(ReadyForQuery).await?;
+                        framed.send(self.ready_for_query()).await?;
                     }
                     ClientMessage::Terminate => {
                         return Ok(None);
@@ -667,7 +783,14 @@ impl Connection {
                         return Err(err_info.into());
                     }
 
-                    framed.send(ReadyForQuery).await?;
+                    // 구문 오류처럼 엔진에 닿기 전에 난 오류도 트랜잭션 블록을 중단시킵니다.
+                    self.engine
+                        .shared_state
+                        .engine
+                        .transaction_manager
+                        .mark_failed(&self.engine.shared_state.client_info.connection_id);
+
+                    framed.send(self.ready_for_query()).await?;
                     ConnectionState::Idle
                 }
                 Err(err) => {
@@ -692,6 +815,7 @@ mod tests {
     use std::path::PathBuf;
     use std::sync::Arc;
 
+    use tokio::io::{AsyncReadExt, AsyncWriteExt, DuplexStream};
     use tokio::sync::Mutex;
 
     use crate::config::launch_config::LaunchConfig;
@@ -703,6 +827,9 @@ mod tests {
     use crate::engine::parser::predule::Parser;
     use crate::engine::server::client::ClientInfo;
     use crate::engine::server::shared_state::SharedState;
+    use crate::engine::types::{
+        ExecuteColumn, ExecuteColumnType, ExecuteField, ExecuteResult, ExecuteRow,
+    };
     use crate::engine::wal::endec::implements::bincode::{BincodeDecoder, BincodeEncoder};
     use crate::engine::wal::manager::builder::WALBuilder;
 
@@ -745,6 +872,94 @@ mod tests {
         assert!(Connection::query_has_parameters("select '$1', $1"));
     }
 
+    #[test]
+    fn dml_with_returning_returns_rows_and_keeps_command_tag() {
+        let result = ExecuteResult::with_affected_rows(
+            vec![ExecuteColumn {
+                name: "id".into(),
+                data_type: ExecuteColumnType::Integer,
+            }],
+            vec![
+                ExecuteRow {
+                    fields: vec![ExecuteField::Integer(1)],
+                },
+                ExecuteRow {
+                    fields: vec![ExecuteField::Integer(2)],
+                },
+            ],
+            2,
+        );
+
+        for (sql, returns_rows, command_tag) in [
+            (
+                "insert into t (id) values (1), (2) returning id",
+                true,
+                "INSERT 0 2",
+            ),
+            ("insert into t (id) values (1), (2)", false, "INSERT 0 2"),
+            ("update t set id = 1 returning *", true, "UPDATE 2"),
+            ("update t set id = 1", false, "UPDATE 2"),
+            ("delete from t returning id", true, "DELETE 2"),
+            ("delete from t", false, "DELETE 2"),
+        ] {
+            let statement = parse_statement(sql);
+
+            assert_eq!(Connection::returns_rows(&statement), returns_rows, "{sql}");
+            assert_eq!(
+                Connection::command_tag(&statement, &result),
+                command_tag,
+                "{sql}"
+            );
+        }
+    }
+
+    #[test]
+    fn function_statements_have_their_own_command_tag() {
+        let result = ExecuteResult::new(vec![], vec![ExecuteRow { fields: vec![] }]);
+
+        for (sql, command_tag) in [
+            (
+                "create function add_one(a integer) returns integer as $$ select a + 1 $$",
+                "CREATE FUNCTION",
+            ),
+            (
+                "create or replace function add_one(a integer) returns integer as $$ select a $$",
+                "CREATE FUNCTION",
+            ),
+            ("drop function add_one(integer)", "DROP FUNCTION"),
+            ("drop function if exists add_one", "DROP FUNCTION"),
+        ] {
+            let statement = parse_statement(sql);
+
+            assert_eq!(
+                Connection::command_tag(&statement, &result),
+                command_tag,
+                "{sql}"
+            );
+        }
+    }
+
+    #[test]
+    fn savepoint_statements_have_their_own_command_tag() {
+        let result = ExecuteResult::new(vec![], vec![ExecuteRow { fields: vec![] }]);
+
+        for (sql, command_tag) in [
+            ("savepoint before_update", "SAVEPOINT"),
+            ("rollback to savepoint before_update", "ROLLBACK"),
+            ("rollback to before_update", "ROLLBACK"),
+            ("release savepoint before_update", "RELEASE"),
+            ("release before_update", "RELEASE"),
+        ] {
+            let statement = parse_statement(sql);
+
+            assert_eq!(
+                Connection::command_tag(&statement, &result),
+                command_tag,
+                "{sql}"
+            );
+        }
+    }
+
     fn parse_statement(sql: &str) -> SQLStatement {
         let mut parser = Parser::with_string(sql.to_owned()).unwrap();
         parser
@@ -883,4 +1098,162 @@ mod tests {
         assert_eq!(values[2], Some(SQLExpression::Float(3.5)));
         assert_eq!(values[3], Some(SQLExpression::String("007".to_string())));
     }
+
+    // 클라이언트 쪽에서 ReadyForQuery까지의 (태그, 본문) 메시지를 읽습니다.
+    async fn read_until_ready(client: &mut DuplexStream) -> Vec<(u8, Vec<u8>)> {
+        let mut messages = vec![];
+
+        loop {
+            let tag = client.read_u8().await.unwrap();
+            let length = client.read_i32().await.unwrap() as usize;
+            let mut body = vec![0; length - 4];
+            client.read_exact(&mut body).await.unwrap();
+
+            messages.push((tag, body));
+            if tag == b'Z' {
+                return messages;
+            }
+        }
+    }
+
+    async fn start_session(connection: Connection) -> DuplexStream {
+        let (mut client, server) = tokio::io::duplex(64 * 1024);
+        let mut connection = connection;
+        tokio::spawn(async move { connection.run(server).await });
+
+        let parameters = b"user\0rrdb\0\0";
+        client
+            .write_i32((8 + parameters.len()) as i32)
+            .await
+            .unwrap();
+        client.write_i32(196608).await.unwrap();
+        client.write_all(parameters).await.unwrap();
+
+        let messages = read_until_ready(&mut client).await;
+        assert_eq!(messages.last().unwrap(), &(b'Z', b"I".to_vec()));
+
+        client
+    }
+
+    async fn simple_query(client: &mut DuplexStream, sql: &str) -> Vec<(u8, Vec<u8>)> {
+        client.write_u8(b'Q').await.unwrap();
+        client.write_i32((4 + sql.len() + 1) as i32).await.unwrap();
+        client.write_all(sql.as_bytes()).await.unwrap();
+        client.write_u8(0).await.unwrap();
+
+        read_until_ready(client).await
+    }
+
+    fn message_body(messages: &[(u8, Vec<u8>)], tag: u8) -> Option<String> {
+        messages
+            .iter()
+            .find(|(message_tag, _)| *message_tag == tag)
+            .map(|(_, body)| String::from_utf8_lossy(body).into_owned())
+    }
+
+    #[tokio::test]
+    async fn ready_for_query_reports_transaction_status() {
+        let connection = build_test_connection("test_pgwire_ready_for_query_status").await;
+        execute_sql(&connection, "create database rrdb").await;
+        execute_sql(&connection, "create table rrdb.items (id integer)").await;
+        let mut client = start_session(connection).await;
+
+        let messages = simple_query(&mut client, "begin").await;
+        assert_eq!(message_body(&messages, b'C').unwrap(), "BEGIN\0");
+        assert_eq!(message_body(&messages, b'Z').unwrap(), "T");
+
+        let messages = simple_query(&mut client, "insert into items (id) values (1)").await;
+        assert_eq!(message_body(&messages, b'Z').unwrap(), "T");
+
+        let messages = simple_query(&mut client, "select * from missing_table").await;
+        assert!(message_body(&messages, b'E').is_some());
+        assert_eq!(message_body(&messages, b'Z').unwrap(), "E");
+
+        // 실패한 트랜잭션의 COMMIT은 롤백으로 끝납니다.
+        let messages = simple_query(&mut client, "commit").await;
+        assert_eq!(message_body(&messages, b'C').unwrap(), "ROLLBACK\0");
+        assert_eq!(message_body(&messages, b'Z').unwrap(), "I");
+
+        let messages = simple_query(&mut client, "select count(1) from items").await;
+        assert_eq!(message_body(&messages, b'C').unwrap(), "SELECT 1\0");
+        assert!(message_body(&messages, b'D').unwrap().ends_with("0"));
+        assert_eq!(message_body(&messages, b'Z').unwrap(), "I");
+
+        let messages = simple_query(&mut client, "begin").await;
+        simple_query(&mut client, "insert into items (id) values (2)").await;
+        let committed = simple_query(&mut client, "commit").await;
+        assert_eq!(message_body(&messages, b'Z').unwrap(), "T");
+        assert_eq!(message_body(&committed, b'C').unwrap(), "COMMIT\0");
+        assert_eq!(message_body(&committed, b'Z').unwrap(), "I");
+    }
+
+    #[tokio::test]
+    async fn parse_error_aborts_the_transaction_block() {
+        let connection = build_test_connection("test_pgwire_parse_error_aborts_block").await;
+        execute_sql(&connection, "create database rrdb").await;
+        let mut client = start_session(connection).await;
+
+        simple_query(&mut client, "begin").await;
+        let messages = simple_query(&mut client, "savepoint first").await;
+        assert!(message_body(&messages, b'E').is_some());
+        assert_eq!(message_body(&messages, b'Z').unwrap(), "E");
+
+        let messages = simple_query(&mut client, "commit").await;
+        assert_eq!(message_body(&messages, b'C').unwrap(), "ROLLBACK\0");
+        assert_eq!(message_body(&messages, b'Z').unwrap(), "I");
+
+        // 블록 밖의 오류는 상태를 바꾸지 않습니다.
+        let messages = simple_query(&mut client, "savepoint first").await;
+        assert!(message_body(&messages, b'E').is_some());
+        assert_eq!(message_body(&messages, b'Z').unwrap(), "I");
+    }
+
+    #[tokio::test]
+    async fn nested_begin_sends_a_warning_notice() {
+        let connection = build_test_connection("test_pgwire_nested_begin_warning").await;
+        execute_sql(&connection, "create database rrdb").await;
+        let mut client = start_session(connection).await;
+
+        let messages = simple_query(&mut client, "begin").await;
+        assert!(message_body(&messages, b'N').is_none());
+
+        let messages = simple_query(&mut client, "begin").await;
+        let notice = message_body(&messages, b'N').unwrap();
+        assert!(notice.contains("SWARNING\0"));
+        assert!(notice.contains("C25001\0"));
+        assert!(notice.contains("there is already a transaction in progress"));
+        assert_eq!(message_body(&messages, b'C').unwrap(), "BEGIN\0");
+        assert_eq!(message_body(&messages, b'Z').unwrap(), "T");
+
+        simple_query(&mut client, "commit").await;
+        let messages = simple_query(&mut client, "commit").await;
+        let notice = message_body(&messages, b'N').unwrap();
+        assert!(notice.contains("there is no transaction in progress"));
+        assert_eq!(message_body(&messages, b'Z').unwrap(), "I");
+    }
+
+    #[tokio::test]
+    async fn savepoint_statements_complete_with_their_command_tag() {
+        let connection = build_test_connection("test_pgwire_savepoint_command_tag").await;
+        execute_sql(&connection, "create database rrdb").await;
+        execute_sql(&connection, "create table rrdb.items (id integer)").await;
+        let mut client = start_session(connection).await;
+
+        simple_query(&mut client, "begin").await;
+        for (sql, command_tag) in [
+            ("savepoint before_insert", "SAVEPOINT\0"),
+            ("insert into items (id) values (1)", "INSERT 0 1\0"),
+            ("rollback to savepoint before_insert", "ROLLBACK\0"),
+            ("release savepoint before_insert", "RELEASE\0"),
+        ] {
+            let messages = simple_query(&mut client, sql).await;
+
+            assert_eq!(message_body(&messages, b'C').unwrap(), command_tag, "{sql}");
+            assert_eq!(message_body(&messages, b'Z').unwrap(), "T", "{sql}");
+        }
+
+        let messages = simple_query(&mut client, "commit").await;
+        assert_eq!(message_body(&messages, b'C').unwrap(), "COMMIT\0");
+        assert_eq!(message_body(&messages, b'Z').unwrap(), "I");
+    }
 }
@@ -1,11 +1,17 @@
 use async_trait::async_trait;
 
+use crate::engine::ast::dml::delete::DeleteQuery;
+use crate::engine::ast::dml::insert::InsertQuery;
+use crate::engine::ast::dml::parts::returning::ReturningClause;
+use crate::engine::ast::dml::parts::target::UpdateTarget;
+use crate::engine::ast::dml::update::UpdateQuery;
 use crate::engine::ast::{DMLStatement, OtherStatement, SQLStatement};
+use crate::engine::schema::row::TableDataFieldType;
 use crate::engine::server::shared_state::SharedState;
 use crate::engine::types::{ExecuteColumn, ExecuteField, ExecuteResult};
 use crate::pgwire::engine::{Engine, Portal};
 use crate::pgwire::protocol::backend::{ErrorResponse, FieldDescription};
-use crate::pgwire::protocol::{DataRowBatch, SqlState};
+use crate::pgwire::protocol::{DataRowBatch, DataTypeOid, FormatCode, SqlState};
 
 #[derive(Clone)]
 pub struct RRDBPortal {
@@ -41,11 +47,41 @@ impl RRDBPortal {
     }
 }
 
+// 배열 요소의 타입 (NULL이 아닌 첫 요소 기준, 모두 NULL이면 text)
+fn array_element_type(elements: &[ExecuteField]) -> DataTypeOid {
+    match elements.iter().find(|e| **e != ExecuteField::Null) {
+        Some(ExecuteField::Bool(_)) => DataTypeOid::Bool,
+        Some(ExecuteField::Integer(_)) => DataTypeOid::Int8,
+        Some(ExecuteField::Float(_)) => DataTypeOid::Float8,
+        _ => DataTypeOid::Text,
+    }
+}
+
+// 배열 요소 하나를 결과 포맷으로 인코딩합니다. 중첩 배열은 텍스트로 보냅니다.
+fn array_element(element: ExecuteField, format_code: FormatCode) -> Option<Vec<u8>> {
+    let binary = matches!(format_code, FormatCode::Binary);
+
+    Some(match element {
+        ExecuteField::Bool(data) if binary => vec![data as u8],
+        ExecuteField::Bool(data) => if data { "t" } else { "f" }.into(),
+        ExecuteField::Integer(data) if binary => data.to_be_bytes().to_vec(),
+        ExecuteField::Integer(data) => data.to_string().into_bytes(),
+        ExecuteField::Float(data) if binary => data.to_be_bytes().to_vec(),
+        ExecuteField::Float(data) => data.to_string().into_bytes(),
+        ExecuteField::String(data) => data.into_bytes(),
+        ExecuteField::Array(data) => TableDataFieldType::from(ExecuteField::Array(data))
+            .to_string()
+            .into_bytes(),
+        ExecuteField::Null => return None,
+    })
+}
+
 #[async_trait]
 impl Portal for RRDBPortal {
     // 실제 결과 데이터 리스트 전송
     async fn fetch(&mut self, batch: &mut DataRowBatch) -> Result<(), ErrorResponse> {
         let result = self.execute().await?;
+        let format_code = batch.format_code;
 
         for row in result.rows {
             let mut writer = batch.create_row();
@@ -64,6 +100,15 @@ impl Portal for RRDBPortal {
                     ExecuteField::String(data) => {
                         writer.write_string(&data);
                     }
+                    ExecuteField::Array(data) => {
+                        let element_type = array_element_type(&data);
+                        let elements = data
+                            .into_iter()
+                            .map(|element| array_element(element, format_code))
+                            .collect::<Vec<_>>();
+
+                        writer.write_array(element_type, &elements);
+                    }
                     ExecuteField::Null => {
                         writer.write_null();
                     }
@@ -84,6 +129,18 @@ impl RRDBEngine {
         matches!(
             statement,
             SQLStatement::DML(DMLStatement::SelectQuery(_))
+                | SQLStatement::DML(DMLStatement::InsertQuery(InsertQuery {
+                    returning: Some(_),
+                    ..
+                }))
+                | SQLStatement::DML(DMLStatement::UpdateQuery(UpdateQuery {
+                    returning: Some(_),
+                    ..
+                }))
+                | SQLStatement::DML(DMLStatement::DeleteQuery(DeleteQuery {
+                    returning: Some(_),
+                    ..
+                }))
                 | SQLStatement::Other(OtherStatement::ShowDatabases(_))
                 | SQLStatement::Other(OtherStatement::ShowTables(_))
                 | SQLStatement::Other(OtherStatement::DescTable(_))
@@ -91,6 +148,21 @@ impl RRDBEngine {
         )
     }
 
+    async fn describe_returning(
+        &self,
+        targets: &[UpdateTarget],
+        returning: &ReturningClause,
+    ) -> Result<Vec<FieldDescription>, ErrorResponse> {
+        let columns = self
+            .shared_state
+            .engine
+            .describe_returning_columns(targets, returning)
+            .await
+            .map_err(|error| ErrorResponse::error(SqlState::SYNTAX_ERROR, error.to_string()))?;
+
+        Ok(columns_to_fields(&columns))
+    }
+
     pub async fn execute_statement(
         &mut self,
         statement: &SQLStatement,
@@ -128,6 +200,34 @@ impl Engine for RRDBEngine {
                     })?;
                 return Ok(columns_to_fields(&columns));
             }
+            // RETURNING이 있는 DML은 실행하지 않고 결과 컬럼만 계산합니다.
+            SQLStatement::DML(DMLStatement::InsertQuery(InsertQuery {
+                into_table: Some(table),
+                returning: Some(returning),
+                ..
+            })) => {
+                return self
+                    .describe_returning(&[table.clone().into()], returning)
+                    .await;
+            }
+            SQLStatement::DML(DMLStatement::UpdateQuery(UpdateQuery {
+                target_table: Some(target),
+                from_tables: sources,
+                returning: Some(returning),
+                ..
+            }))
+            | SQLStatement::DML(DMLStatement::DeleteQuery(DeleteQuery {
+                from_table: Some(target),
+                using_tables: sources,
+                returning: Some(returning),
+                ..
+            })) => {
+                let targets = std::iter::once(target.clone())
+                    .chain(sources.iter().cloned())
+                    .collect::<Vec<_>>();
+
+                return self.describe_returning(&targets, returning).await;
+            }
             statement if !Self::statement_returns_rows(statement) => {
                 return Ok(Vec::new());
             }
@@ -167,7 +267,8 @@ mod tests {
     use crate::engine::types::ExecuteField;
     use crate::engine::wal::endec::implements::bincode::{BincodeDecoder, BincodeEncoder};
     use crate::engine::wal::manager::builder::WALBuilder;
-    use crate::pgwire::engine::{Engine, RRDBEngine};
+    use crate::pgwire::engine::{Engine, Portal, RRDBEngine};
+    use crate::pgwire::protocol::{DataRowBatch, DataTypeOid, FormatCode};
 
     async fn build_test_engine(test_name: &str) -> RRDBEngine {
         let test_binary = std::env::current_exe()
@@ -276,4 +377,102 @@ mod tests {
             .unwrap();
         assert_eq!(count.rows[0].fields[0], ExecuteField::Integer(2));
     }
+
+    #[tokio::test]
+    async fn prepare_dml_returning_describes_columns_without_executing() {
+        let mut engine = build_test_engine("test_rrdb_engine/prepare_dml_returning").await;
+        execute_sql(&mut engine, "create database rrdb").await;
+        execute_sql(
+            &mut engine,
+            "create table key_value (key varchar(255), value int)",
+        )
+        .await;
+        execute_sql(
+            &mut engine,
+            "insert into key_value (key, value) values ('a', 1)",
+        )
+        .await;
+
+        let statement =
+            parse_statement("delete from key_value as kv returning kv.value, value * 2");
+        let fields = engine.prepare(&statement).await.unwrap();
+
+        assert_eq!(
+            fields
+                .iter()
+                .map(|field| field.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["value", "?column?"]
+        );
+
+        let count = engine
+            .execute_statement(&parse_statement("select count(1) from key_value"))
+            .await
+            .unwrap();
+        assert_eq!(count.rows[0].fields[0], ExecuteField::Integer(1));
+
+        // RETURNING이 없는 DML은 결과 컬럼이 없습니다.
+        let statement = parse_statement("delete from key_value");
+        assert!(engine.prepare(&statement).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn array_agg_is_sent_as_an_array() {
+        let mut engine = build_test_engine("test_rrdb_engine/array_agg").await;
+        execute_sql(&mut engine, "create database rrdb").await;
+        execute_sql(
+            &mut engine,
+            "create table key_value (name varchar(255), value int)",
+        )
+        .await;
+        execute_sql(
+            &mut engine,
+            "insert into key_value (name, value) values ('a b', 1), ('c', null), ('d', 2)",
+        )
+        .await;
+
+        let statement = parse_statement("select array_agg(value), array_agg(name) from key_value");
+        let fields = engine.prepare(&statement).await.unwrap();
+
+        assert_eq!(
+            fields
+                .iter()
+                .map(|field| u32::from(field.data_type))
+                .collect::<Vec<_>>(),
+            vec![
+                u32::from(DataTypeOid::Int8Array),
+                u32::from(DataTypeOid::TextArray)
+            ]
+        );
+
+        // 텍스트 포맷은 배열 리터럴로 보냅니다.
+        let mut portal = engine.create_portal(&statement).await.unwrap();
+        let mut batch = DataRowBatch::new(FormatCode::Text, 2);
+        portal.fetch(&mut batch).await.unwrap();
+
+        let data = String::from_utf8_lossy(&batch.data);
+        assert!(data.contains("{1,NULL,2}"), "{data}");
+        assert!(data.contains(r#"{"a b",c,d}"#), "{data}");
+
+        // 바이너리 포맷은 차원, NULL 여부, 요소 타입, 길이, 하한, 요소 순서입니다.
+        let mut portal = engine.create_portal(&statement).await.unwrap();
+        let mut batch = DataRowBatch::new(FormatCode::Binary, 2);
+        portal.fetch(&mut batch).await.unwrap();
+
+        let mut expected = vec![];
+        for value in [1, 1, 20, 3, 1, 8] {
+            expected.extend_from_slice(&i32::to_be_bytes(value));
+        }
+        expected.extend_from_slice(&1i64.to_be_bytes());
+        expected.extend_from_slice(&(-1i32).to_be_bytes());
+        expected.extend_from_slice(&8i32.to_be_bytes());
+        expected.extend_from_slice(&2i64.to_be_bytes());
+
+        assert!(
+            batch
+                .data
+                .windows(expected.len())
+                .any(|window| window == expected.as_slice())
+        );
+    }
 }
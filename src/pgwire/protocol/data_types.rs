@@ -66,4 +66,9 @@ data_types! {
     Timestamp = 1114, 8
 
     Text = 25, -1
+
+    BoolArray = 1000, -1
+    Int8Array = 1016, -1
+    Float8Array = 1022, -1
+    TextArray = 1009, -1
 }
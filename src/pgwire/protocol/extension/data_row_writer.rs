@@ -1,7 +1,7 @@
 use bytes::BufMut;
 use chrono::{NaiveDate, NaiveDateTime};
 
-use crate::pgwire::protocol::FormatCode;
+use crate::pgwire::protocol::{DataTypeOid, FormatCode};
 
 use super::DataRowBatch;
 
@@ -60,6 +60,64 @@ impl<'a> DataRowWriter<'a> {
         };
     }
 
+    /// Writes a one-dimensional array value for the next column.
+    ///
+    /// Each element must already be encoded in the batch's format; `None` is a NULL element.
+    pub fn write_array(&mut self, element_type: DataTypeOid, elements: &[Option<Vec<u8>>]) {
+        match self.parent.format_code {
+            FormatCode::Text => {
+                let literal = elements
+                    .iter()
+                    .map(|element| match element {
+                        Some(element) => Self::array_text_element(element),
+                        None => "NULL".to_string(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(",");
+
+                self.write_string(&format!("{{{literal}}}"));
+            }
+            FormatCode::Binary => {
+                let mut data = vec![];
+                data.put_i32(1); // ndim
+                data.put_i32(elements.iter().any(Option::is_none) as i32);
+                data.put_u32(element_type.into());
+                data.put_i32(elements.len() as i32);
+                data.put_i32(1); // lower bound
+
+                for element in elements {
+                    match element {
+                        Some(element) => {
+                            data.put_i32(element.len() as i32);
+                            data.put_slice(element);
+                        }
+                        None => data.put_i32(-1),
+                    }
+                }
+
+                self.write_value(&data);
+            }
+        }
+    }
+
+    /// Quotes an array literal element when it is empty, could be read as NULL,
+    /// or contains delimiters, quotes, backslashes or whitespace.
+    fn array_text_element(element: &[u8]) -> String {
+        let text = String::from_utf8_lossy(element);
+
+        let needs_quote = text.is_empty()
+            || text.eq_ignore_ascii_case("NULL")
+            || text
+                .chars()
+                .any(|c| matches!(c, '{' | '}' | ',' | '"' | '\\') || c.is_whitespace());
+
+        if needs_quote {
+            format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+        } else {
+            text.into_owned()
+        }
+    }
+
     fn pg_date_epoch() -> NaiveDate {
         NaiveDate::from_ymd_opt(2000, 1, 1).unwrap()
     }
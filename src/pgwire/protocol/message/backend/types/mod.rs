@@ -36,3 +36,6 @@ pub use parameter_status::*;
 
 pub mod error_response;
 pub use error_response::*;
+
+pub mod notice_response;
+pub use notice_response::*;
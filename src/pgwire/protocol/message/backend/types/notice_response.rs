@@ -0,0 +1,38 @@
+use bytes::{BufMut, BytesMut};
+
+use crate::pgwire::protocol::{Severity, SqlState, backend::BackendMessage};
+
+#[derive(Debug, Clone)]
+pub struct NoticeResponse {
+    pub sql_state: SqlState,
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl NoticeResponse {
+    pub fn warning(sql_state: SqlState, message: impl Into<String>) -> Self {
+        NoticeResponse {
+            sql_state,
+            severity: Severity::WARNING,
+            message: message.into(),
+        }
+    }
+}
+
+impl BackendMessage for NoticeResponse {
+    const TAG: u8 = b'N';
+
+    fn encode(&self, dst: &mut BytesMut) {
+        dst.put_u8(b'C');
+        dst.put_slice(self.sql_state.0.as_bytes());
+        dst.put_u8(0);
+        dst.put_u8(b'S');
+        dst.put_slice(self.severity.0.as_bytes());
+        dst.put_u8(0);
+        dst.put_u8(b'M');
+        dst.put_slice(self.message.as_bytes());
+        dst.put_u8(0);
+
+        dst.put_u8(0); // tag
+    }
+}
@@ -2,13 +2,24 @@ use bytes::{BufMut, BytesMut};
 
 use crate::pgwire::protocol::backend::BackendMessage;
 
-#[derive(Debug)]
-pub struct ReadyForQuery;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReadyForQuery {
+    pub status: u8,
+}
+
+impl ReadyForQuery {
+    // 트랜잭션 블록 밖
+    pub const IDLE: ReadyForQuery = ReadyForQuery { status: b'I' };
+    // 트랜잭션 블록 안
+    pub const IN_TRANSACTION: ReadyForQuery = ReadyForQuery { status: b'T' };
+    // 오류로 중단된 트랜잭션 블록 안
+    pub const FAILED_TRANSACTION: ReadyForQuery = ReadyForQuery { status: b'E' };
+}
 
 impl BackendMessage for ReadyForQuery {
     const TAG: u8 = b'Z';
 
     fn encode(&self, dst: &mut BytesMut) {
-        dst.put_u8(b'I');
+        dst.put_u8(self.status);
     }
 }
@@ -2,6 +2,7 @@
 pub struct Severity(pub &'static str);
 
 impl Severity {
+    pub const WARNING: Severity = Severity("WARNING");
     pub const ERROR: Severity = Severity("ERROR");
     pub const FATAL: Severity = Severity("FATAL");
 }
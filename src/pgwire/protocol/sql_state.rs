@@ -11,4 +11,6 @@ impl SqlState {
     pub const PROTOCOL_VIOLATION: SqlState = SqlState("08P01");
     pub const SYNTAX_ERROR: SqlState = SqlState("42601");
     pub const INVALID_DATETIME_FORMAT: SqlState = SqlState("22007");
+    pub const ACTIVE_SQL_TRANSACTION: SqlState = SqlState("25001");
+    pub const NO_ACTIVE_SQL_TRANSACTION: SqlState = SqlState("25P01");
 }